@@ -25,7 +25,7 @@ impl ConnectorError {
 
         ConnectorError {
             user_facing_error: Some(known),
-            kind: ErrorKind::InvalidDatabaseUrl(format!("{} in `{}`)", err, url)),
+            kind: ErrorKind::InvalidDatabaseUrl(format!("{} in `{}`)", err, datasource_url::redact_url(url))),
         }
     }
 
@@ -81,4 +81,7 @@ pub enum ErrorKind {
 
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
+
+    #[error("Could not open the SSH tunnel through `{}`: {}", ssh_host, message)]
+    SshTunnelError { ssh_host: String, message: String },
 }
@@ -14,8 +14,14 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_metadata(&self) -> ConnectorResult<DatabaseMetadata>;
 
+    /// Approximate row counts and sizes of the tables, as estimated by the database.
+    async fn get_statistics(&self) -> ConnectorResult<Vec<TableStatistics>>;
+
     async fn get_database_description(&self) -> ConnectorResult<String>;
 
+    /// The described database schema, serialized as JSON.
+    async fn describe_database(&self) -> ConnectorResult<Value>;
+
     async fn get_database_version(&self) -> ConnectorResult<String>;
 
     async fn introspect(
@@ -31,6 +37,17 @@ pub struct DatabaseMetadata {
     pub size_in_bytes: usize,
 }
 
+/// The statistics of a table. Read from the metadata the database keeps for its query planner, so
+/// they are approximate and missing where the database has no estimate, e.g. on SQLite.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStatistics {
+    pub table: String,
+    pub row_count: Option<u64>,
+    pub data_size_in_bytes: Option<u64>,
+    pub index_size_in_bytes: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum Version {
     NonPrisma,
@@ -97,10 +97,14 @@ mod tests {
                         .to_string(),
                 ),
                 is_embedded: false,
+                is_join_table: false,
                 is_generated: false,
                 is_commented_out: true,
                 indices: vec![],
                 id_fields: vec![],
+                id_clustered: None,
+                engine: None,
+                row_format: None,
                 fields: col_types
                     .iter()
                     .map(|col_type| {
@@ -126,12 +130,16 @@ mod tests {
                             documentation,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out,
                         })
                     })
                     .collect(),
             }],
             enums: vec![],
+                    composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -148,14 +156,18 @@ mod tests {
                         ),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     })
                     .collect(),
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -171,6 +183,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_join_table: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -189,6 +202,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -200,8 +216,12 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_clustered: None,
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -213,18 +233,21 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        computed: false,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                 ],
                 indices: vec![],
@@ -232,11 +255,15 @@ mod tests {
                     columns: vec!["required".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -252,6 +279,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_join_table: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -270,6 +298,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -283,6 +314,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -296,6 +330,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -309,6 +346,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                 ],
@@ -317,10 +357,17 @@ mod tests {
                     name: Some("unique".into()),
                     fields: vec!["no_default".into(), "int_default".into()],
                     tpe: dml::IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }],
                 id_fields: vec![],
+                id_clustered: None,
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -332,42 +379,53 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "int_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: Some(DefaultValue::value(PrismaValue::Int(1))),
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "bool_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Boolean, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::Boolean(true))),
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "float_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::new_float(1.0))),
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "string_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::String("default".to_string()))),
                         auto_increment: false,
+                        computed: false,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["no_default".into(), "int_default".into()],
                     tpe: IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -385,6 +443,7 @@ mod tests {
                     name: "Table1".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -397,11 +456,17 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -409,6 +474,7 @@ mod tests {
                     name: "Table2".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -421,11 +487,17 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -433,6 +505,7 @@ mod tests {
                     name: "Table3".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -445,14 +518,21 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -471,14 +551,18 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        computed: false,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
                         columns: vec!["primary".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -494,14 +578,18 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
                         columns: vec!["primary".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -517,6 +605,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        computed: false,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -525,12 +614,16 @@ mod tests {
                             name: "sequence".to_string(),
                         }),
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -546,6 +639,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_join_table: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -564,14 +658,21 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_clustered: None,
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -583,24 +684,32 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["unique".to_string()],
                     tpe: IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -617,6 +726,7 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -630,6 +740,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -646,18 +759,24 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                deferrable: false,
+                                map: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -671,6 +790,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -684,6 +806,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -697,6 +822,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::RelationField(RelationField::new(
@@ -708,15 +836,21 @@ mod tests {
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                deferrable: false,
+                                map: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -737,6 +871,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            computed: false,
                         },
                         Column {
                             name: "name".to_string(),
@@ -751,6 +886,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            computed: false,
                         },
                     ],
                     indices: vec![],
@@ -758,8 +894,11 @@ mod tests {
                         columns: vec!["id".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -777,6 +916,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            computed: false,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -791,6 +931,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            computed: false,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -805,6 +946,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            computed: false,
                         },
                     ],
                     indices: vec![],
@@ -812,6 +954,7 @@ mod tests {
                         columns: vec!["id".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![ForeignKey {
                         // what does this mean? the from columns are not targeting a specific to column?
@@ -821,11 +964,15 @@ mod tests {
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
+                        deferrable: false,
                     }],
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -841,6 +988,7 @@ mod tests {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_join_table: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
@@ -854,6 +1002,9 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        is_encrypted: false,
+                        validations: Vec::new(),
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -872,10 +1023,17 @@ mod tests {
                     name: Some("name_last_name_unique".to_string()),
                     fields: vec!["name".to_string(), "lastname".to_string()],
                     tpe: datamodel::dml::IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }],
                 id_fields: vec![],
+                id_clustered: None,
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -895,6 +1053,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        computed: false,
                     },
                     Column {
                         name: "name".to_string(),
@@ -909,6 +1068,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -923,22 +1083,30 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                 ],
                 indices: vec![Index {
                     name: "name_last_name_unique".to_string(),
                     columns: vec!["name".to_string(), "lastname".to_string()],
                     tpe: IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                row_format: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -955,6 +1123,7 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -968,6 +1137,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -984,18 +1156,24 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                deferrable: false,
+                                map: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_join_table: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -1009,6 +1187,9 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            is_encrypted: false,
+                            validations: Vec::new(),
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -1025,15 +1206,21 @@ mod tests {
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                deferrable: false,
+                                map: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_clustered: None,
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -1054,6 +1241,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            computed: false,
                         },
                         Column {
                             name: "name".to_string(),
@@ -1068,6 +1256,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            computed: false,
                         },
                     ],
                     indices: vec![],
@@ -1075,8 +1264,11 @@ mod tests {
                         columns: vec!["id".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -1094,6 +1286,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            computed: false,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -1108,6 +1301,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            computed: false,
                         },
                     ],
                     indices: vec![],
@@ -1115,6 +1309,7 @@ mod tests {
                         columns: vec!["id".to_string()],
                         sequence: None,
                         constraint_name: None,
+                        clustered: None,
                     }),
                     foreign_keys: vec![ForeignKey {
                         constraint_name: None,
@@ -1123,11 +1318,15 @@ mod tests {
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
+                        deferrable: false,
                     }],
+                    engine: None,
+                    row_format: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -1159,6 +1358,7 @@ mod tests {
                     },
                 ],
             }],
+            composite_types: vec![],
         };
 
         let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1169,6 +1369,7 @@ mod tests {
                 values: enum_values,
             }],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
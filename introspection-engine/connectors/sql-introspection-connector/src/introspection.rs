@@ -1,7 +1,7 @@
 use crate::introspection_helpers::{
     calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_new_migration_table, is_old_migration_table, is_prisma_1_point_0_join_table,
-    is_prisma_1_point_1_or_2_join_table, is_relay_table,
+    calculate_scalar_field, is_audit_log_table, is_new_migration_table, is_old_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::Dedup;
@@ -23,6 +23,7 @@ pub fn introspect(
         .iter()
         .filter(|table| !is_old_migration_table(&table))
         .filter(|table| !is_new_migration_table(&table))
+        .filter(|table| !is_audit_log_table(&table))
         .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
         .filter(|table| !is_prisma_1_point_0_join_table(&table))
         .filter(|table| !is_relay_table(&table))
@@ -57,15 +58,25 @@ pub fn introspect(
         for index in table
             .indices
             .iter()
-            .filter(|i| !(i.columns.len() == 1 && i.is_unique()))
+            .filter(|i| !(i.columns.len() == 1 && i.is_unique() && !i.deferrable))
         {
             model.add_index(calculate_index(index));
         }
 
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
+            // Primary keys are clustered by default, only the deviation is rendered.
+            model.id_clustered = table
+                .primary_key
+                .as_ref()
+                .and_then(|pk| pk.clustered)
+                .filter(|clustered| !clustered);
         }
 
+        // Only described on MySQL, for engines other than InnoDB and explicit row formats.
+        model.engine = table.engine.clone();
+        model.row_format = table.row_format.clone();
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.has_p1_compatible_primary_key_column(table);
 
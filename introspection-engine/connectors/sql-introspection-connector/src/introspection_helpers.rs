@@ -8,7 +8,9 @@ use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::DefaultKind;
-use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SqlSchema, Table};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexMethod, IndexType, SqlSchema, Table,
+};
 use tracing::debug;
 
 //checks
@@ -39,6 +41,16 @@ pub fn is_new_migration_table(table: &Table) -> bool {
         && table.columns.iter().any(|c| c.name == "applied_steps_count")
 }
 
+/// The table the query engine records its writes in when the datasource enables `auditLog`.
+pub(crate) fn is_audit_log_table(table: &Table) -> bool {
+    table.name == "_prisma_audit_log"
+        && table.columns.iter().any(|c| c.name == "id")
+        && table.columns.iter().any(|c| c.name == "model")
+        && table.columns.iter().any(|c| c.name == "operation")
+        && table.columns.iter().any(|c| c.name == "changed_fields")
+        && table.columns.iter().any(|c| c.name == "timestamp")
+}
+
 pub(crate) fn is_relay_table(table: &Table) -> bool {
     table.name == "_RelayId"
         && table.columns[0].name == "id"
@@ -108,6 +120,8 @@ pub fn calculate_many_to_many_field(
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        deferrable: false,
+        map: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -131,6 +145,17 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        // Indexes are nonclustered by default, only the deviation is rendered.
+        clustered: index.clustered.filter(|clustered| *clustered),
+        deferrable: index.deferrable,
+        method: index.method.map(|method| match method {
+            IndexMethod::BTree => datamodel::dml::IndexMethod::BTree,
+            IndexMethod::Hash => datamodel::dml::IndexMethod::Hash,
+            IndexMethod::Gist => datamodel::dml::IndexMethod::Gist,
+            IndexMethod::SpGist => datamodel::dml::IndexMethod::SpGist,
+            IndexMethod::Gin => datamodel::dml::IndexMethod::Gin,
+            IndexMethod::Brin => datamodel::dml::IndexMethod::Brin,
+        }),
     }
 }
 
@@ -142,7 +167,10 @@ pub(crate) fn calculate_scalar_field(
 ) -> ScalarField {
     debug!("Handling column {:?}", column);
 
-    let field_type = if native_types {
+    let field_type = if column.computed {
+        // Computed columns are read-only, they are kept in the datamodel as unsupported fields.
+        FieldType::Unsupported(column.tpe.full_data_type.clone())
+    } else if native_types {
         calculate_scalar_field_type_with_native_types(column, family)
     } else {
         calculate_scalar_field_type(column, family)
@@ -158,6 +186,10 @@ pub(crate) fn calculate_scalar_field(
     let (default_value, dbgenerated_string) = calculate_default(table, &column, &arity);
 
     let (is_commented_out, documentation) = match (&field_type, dbgenerated_string) {
+        _ if column.computed => (
+            true,
+            Some("This field is a computed column, its value is generated by the database.".to_string()),
+        ),
         (FieldType::Unsupported(_), None) => (true, Some("This type is currently not supported.".to_string())),
         (FieldType::Unsupported(_), Some(default)) => (
             true,
@@ -176,7 +208,12 @@ pub(crate) fn calculate_scalar_field(
         _ => (false, None),
     };
 
-    let is_unique = table.is_column_unique(&column.name) && !is_id;
+    // Deferrable unique constraints can only be expressed with `@@unique`.
+    let is_unique = table
+        .indices
+        .iter()
+        .any(|index| index.is_unique() && !index.deferrable && index.columns == [column.name.as_str()])
+        && !is_id;
 
     ScalarField {
         name: column.name.clone(),
@@ -189,6 +226,9 @@ pub(crate) fn calculate_scalar_field(
         documentation,
         is_generated: false,
         is_updated_at: false,
+        is_version: false,
+        is_encrypted: false,
+        validations: Vec::new(),
         is_commented_out,
     }
 }
@@ -206,6 +246,8 @@ pub(crate) fn calculate_relation_field(
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        deferrable: foreign_key.deferrable,
+        map: None,
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -240,6 +282,8 @@ pub(crate) fn calculate_backrelation_field(
                 fields: vec![],
                 references: vec![],
                 on_delete: OnDeleteStrategy::None,
+                deferrable: false,
+                map: None,
             };
 
             // unique or id
@@ -317,7 +361,6 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
     //this is not called for prisma many to many relations. for them the name is just the name of the join table.
     let referenced_model = &fk.referenced_table;
     let model_with_fk = &table.name;
-    let fk_column_name = fk.columns.join("_");
 
     let mut fk_to_same_model: Vec<ForeignKey> = table
         .foreign_keys
@@ -333,23 +376,66 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
             explanation: format!("Table {} not found.", table_name),
         }),
         Ok(other_table) => {
-            let fk_from_other_model_to_this: Vec<&ForeignKey> = other_table
+            let mut fk_from_other_model_to_this: Vec<ForeignKey> = other_table
                 .foreign_keys
                 .iter()
                 .filter(|fk| &fk.referenced_table == model_with_fk)
+                .cloned()
                 .collect();
 
-            let name = if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.is_empty() {
-                RelationNames::name_for_unambiguous_relation(model_with_fk, referenced_model)
-            } else {
-                RelationNames::name_for_ambiguous_relation(model_with_fk, referenced_model, &fk_column_name)
-            };
+            fk_from_other_model_to_this.clear_duplicates();
+
+            if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.is_empty() {
+                return Ok(RelationNames::name_for_unambiguous_relation(
+                    model_with_fk,
+                    referenced_model,
+                ));
+            }
+
+            let name = ambiguous_relation_name(model_with_fk, fk);
+
+            // The names of composite foreign keys are built by joining their columns, so they can
+            // collide with each other, or with foreign keys in the opposite direction. All the
+            // colliding foreign keys get a suffix, in an order that does not depend on the side
+            // the relation is calculated from.
+            let mut colliding_fks: Vec<(&str, &ForeignKey)> = fk_to_same_model
+                .iter()
+                .map(|fk| (model_with_fk.as_str(), fk))
+                .chain(
+                    fk_from_other_model_to_this
+                        .iter()
+                        .filter(|_| other_table.name != table.name)
+                        .map(|fk| (other_table.name.as_str(), fk)),
+                )
+                .filter(|(table_name, other_fk)| ambiguous_relation_name(table_name, other_fk) == name)
+                .collect();
+
+            if colliding_fks.len() < 2 {
+                return Ok(name);
+            }
+
+            colliding_fks.sort_by(|(table_a, fk_a), (table_b, fk_b)| {
+                (table_a, &fk_a.columns, &fk_a.referenced_columns).cmp(&(
+                    table_b,
+                    &fk_b.columns,
+                    &fk_b.referenced_columns,
+                ))
+            });
+
+            let position = colliding_fks
+                .iter()
+                .position(|(table_name, other_fk)| *table_name == model_with_fk && *other_fk == fk)
+                .unwrap();
 
-            Ok(name)
+            Ok(format!("{}_{}", name, position + 1))
         }
     }
 }
 
+fn ambiguous_relation_name(model_with_fk: &str, fk: &ForeignKey) -> String {
+    RelationNames::name_for_ambiguous_relation(model_with_fk, &fk.referenced_table, &fk.columns.join("_"))
+}
+
 pub(crate) fn calculate_scalar_field_type(column: &Column, family: &SqlFamily) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
     let fdt = column.tpe.full_data_type.to_owned();
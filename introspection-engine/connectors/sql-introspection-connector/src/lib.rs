@@ -7,24 +7,29 @@ mod prisma_1_defaults;
 mod re_introspection;
 mod sanitize_datamodel_names;
 mod schema_describer_loading;
+mod table_statistics;
 mod version_checker;
 mod warnings;
 
-use datamodel::Datamodel;
+use datamodel::{Datamodel, RetryPolicy};
 pub use error::*;
 use introspection_connector::{
-    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
+    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult, TableStatistics,
 };
 use quaint::prelude::ConnectionInfo;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
+use ssh_tunnel::{SshTunnel, TunnelError};
 use std::{fmt, future::Future};
 use tracing_futures::Instrument;
+use user_facing_errors::{common::SshTunnelError, KnownError};
 
 pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// The SSH tunnel the connection of the describer goes through, if the URL asks for one.
+    _tunnel: Option<SshTunnel>,
 }
 
 impl fmt::Debug for SqlIntrospectionConnector {
@@ -38,7 +43,17 @@ impl fmt::Debug for SqlIntrospectionConnector {
 
 impl SqlIntrospectionConnector {
     pub async fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
-        let (describer, connection_info) = schema_describer_loading::load_describer(&url)
+        Self::new_with_retry_policy(url, RetryPolicy::default()).await
+    }
+
+    /// Connect to the database, retrying on transient errors according to the provided policy.
+    pub async fn new_with_retry_policy(
+        url: &str,
+        retry_policy: RetryPolicy,
+    ) -> ConnectorResult<SqlIntrospectionConnector> {
+        let (url, tunnel) = connection_url(url)?;
+        let url = url.as_str();
+        let (describer, connection_info) = schema_describer_loading::load_describer(&url, retry_policy)
             .instrument(tracing::debug_span!("Loading describer"))
             .await
             .map_err(|error| {
@@ -52,6 +67,7 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            _tunnel: tunnel,
         })
     }
 
@@ -73,6 +89,23 @@ impl SqlIntrospectionConnector {
         Ok(db_metadate)
     }
 
+    async fn get_statistics_internal(&self) -> SqlIntrospectionResult<Vec<TableStatistics>> {
+        let statistics = self
+            .describer
+            .get_statistics(self.connection_info.schema_name())
+            .await?;
+
+        Ok(statistics
+            .into_iter()
+            .map(|table| TableStatistics {
+                table: table.table,
+                row_count: table.row_count,
+                data_size_in_bytes: table.data_size_in_bytes,
+                index_size_in_bytes: table.index_size_in_bytes,
+            })
+            .collect())
+    }
+
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
         Ok(self.describer.describe(self.connection_info.schema_name()).await?)
     }
@@ -86,6 +119,24 @@ impl SqlIntrospectionConnector {
     }
 }
 
+/// The URL to connect to the database with: a Unix domain socket is rewritten to the form Quaint
+/// expects, and the `ssh_*` parameters open an SSH tunnel the URL then points to.
+fn connection_url(url: &str) -> ConnectorResult<(String, Option<SshTunnel>)> {
+    let normalized = datasource_url::normalize(url).map_err(|err| ConnectorError::url_parse_error(err, url))?;
+
+    match ssh_tunnel::open(&normalized) {
+        Ok((tunneled_url, tunnel)) => Ok((tunneled_url.into_owned(), tunnel)),
+        Err(TunnelError::InvalidParameters(details)) => Err(ConnectorError::url_parse_error(details, url)),
+        Err(TunnelError::Connection { ssh_host, message }) => Err(ConnectorError {
+            user_facing_error: Some(KnownError::new(SshTunnelError {
+                ssh_host: ssh_host.clone(),
+                message: message.clone(),
+            })),
+            kind: introspection_connector::ErrorKind::SshTunnelError { ssh_host, message },
+        }),
+    }
+}
+
 #[async_trait::async_trait]
 impl IntrospectionConnector for SqlIntrospectionConnector {
     async fn list_databases(&self) -> ConnectorResult<Vec<String>> {
@@ -96,6 +147,10 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(self.catch(self.get_metadata_internal()).await?)
     }
 
+    async fn get_statistics(&self) -> ConnectorResult<Vec<TableStatistics>> {
+        Ok(self.catch(self.get_statistics_internal()).await?)
+    }
+
     async fn get_database_description(&self) -> ConnectorResult<String> {
         let sql_schema = self.catch(self.describe()).await?;
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
@@ -103,6 +158,12 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(description)
     }
 
+    async fn describe_database(&self) -> ConnectorResult<serde_json::Value> {
+        let sql_schema = self.catch(self.describe()).await?;
+        let description = serde_json::to_value(&sql_schema).unwrap();
+        Ok(description)
+    }
+
     async fn get_database_version(&self) -> ConnectorResult<String> {
         let sql_schema = self.catch(self.version()).await?;
         tracing::debug!("Fetched db version for: {:?}", sql_schema);
@@ -119,7 +180,7 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result =
+        let mut introspection_result =
             calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model, native_types)
                 .map_err(|sql_introspection_error| {
                     sql_introspection_error.into_connector_error(&self.connection_info)
@@ -127,6 +188,20 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
         tracing::debug!("Calculating datamodel is done: {:?}", introspection_result.data_model);
 
+        // The statistics are only informative, failing to read them must not fail the introspection.
+        let statistics = match self.describer.get_statistics(self.connection_info.schema_name()).await {
+            Ok(statistics) => statistics,
+            Err(err) => {
+                tracing::warn!("Could not read the table statistics: {}", err);
+                Vec::new()
+            }
+        };
+
+        if let Some(warning) = table_statistics::table_statistics_warning(&introspection_result.data_model, &statistics)
+        {
+            introspection_result.warnings.push(warning);
+        }
+
         Ok(introspection_result)
     }
 }
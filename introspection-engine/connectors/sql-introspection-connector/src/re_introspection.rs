@@ -292,6 +292,9 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
     let mut re_introspected_prisma_level_cuids = vec![];
     let mut re_introspected_prisma_level_uuids = vec![];
     let mut re_introspected_updated_at = vec![];
+    let mut re_introspected_versions = vec![];
+    let mut re_introspected_encrypted = vec![];
+    let mut re_introspected_validations = vec![];
     {
         for model in new_data_model.models() {
             for field in model.scalar_fields() {
@@ -312,6 +315,28 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
                         if field.field_type == FieldType::Base(ScalarType::DateTime, None) && old_field.is_updated_at {
                             re_introspected_updated_at.push(ModelAndField::new(&model.name, &field.name));
                         }
+
+                        if field.field_type == FieldType::Base(ScalarType::Int, None) && old_field.is_version {
+                            re_introspected_versions.push(ModelAndField::new(&model.name, &field.name));
+                        }
+
+                        if old_field.is_encrypted
+                            && matches!(
+                                field.field_type.scalar_type(),
+                                Some(ScalarType::String) | Some(ScalarType::Bytes)
+                            )
+                        {
+                            re_introspected_encrypted.push(ModelAndField::new(&model.name, &field.name));
+                        }
+
+                        if !old_field.validations.is_empty()
+                            && field.field_type.scalar_type() == old_field.field_type.scalar_type()
+                        {
+                            re_introspected_validations.push((
+                                ModelAndField::new(&model.name, &field.name),
+                                old_field.validations.clone(),
+                            ));
+                        }
                     }
                 }
             }
@@ -334,6 +359,24 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
                 .find_scalar_field_mut(&updated_at.model, &updated_at.field)
                 .is_updated_at = true;
         }
+
+        for version in &re_introspected_versions {
+            new_data_model
+                .find_scalar_field_mut(&version.model, &version.field)
+                .is_version = true;
+        }
+
+        for encrypted in &re_introspected_encrypted {
+            new_data_model
+                .find_scalar_field_mut(&encrypted.model, &encrypted.field)
+                .is_encrypted = true;
+        }
+
+        for (field, validations) in re_introspected_validations {
+            new_data_model
+                .find_scalar_field_mut(&field.model, &field.field)
+                .validations = validations;
+        }
     }
 
     // comments - we do NOT generate warnings for comments
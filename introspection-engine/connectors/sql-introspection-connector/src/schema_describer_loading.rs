@@ -1,25 +1,25 @@
 use crate::SqlError;
-use quaint::error::ErrorKind;
+use datamodel::RetryPolicy;
+use quaint::error::{Error as QuaintError, ErrorKind};
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
 use sql_schema_describer::SqlSchemaDescriberBackend;
-use std::time::Duration;
+use std::{fmt, time::Duration};
+use user_facing_errors::quaint::{classify_transient_error, TransientError};
 
 const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
-pub async fn load_describer(url: &str) -> Result<(Box<dyn SqlSchemaDescriberBackend>, ConnectionInfo), SqlError> {
-    let wrapper_fut = async {
-        let connection = Quaint::new(&url).await?;
-        connection.query_raw("SELECT 1", &[]).await?;
-        Result::Ok::<_, SqlError>(connection)
-    };
-
-    let wrapper = match tokio::time::timeout(CONNECTION_TIMEOUT, wrapper_fut).await {
-        Ok(result) => result?,
-        Err(_elapsed) => return Err(SqlError::from(ErrorKind::ConnectTimeout("Tokio timer".into()))),
-    };
+/// Connect to the database, retrying on transient errors according to the `retry_policy`. Each
+/// attempt times out after `CONNECTION_TIMEOUT`.
+pub async fn load_describer(
+    url: &str,
+    retry_policy: RetryPolicy,
+) -> Result<(Box<dyn SqlSchemaDescriberBackend>, ConnectionInfo), SqlError> {
+    let wrapper = retry_policy
+        .retry(|| connect(url), ConnectError::transient, tokio::time::delay_for)
+        .await?;
 
     let connection_info = wrapper.connection_info().to_owned();
 
@@ -32,3 +32,51 @@ pub async fn load_describer(url: &str) -> Result<(Box<dyn SqlSchemaDescriberBack
 
     Ok((describer, connection_info))
 }
+
+async fn connect(url: &str) -> Result<Quaint, ConnectError> {
+    let wrapper_fut = async {
+        let connection = Quaint::new(url).await?;
+        connection.query_raw("SELECT 1", &[]).await?;
+        Ok(connection)
+    };
+
+    match tokio::time::timeout(CONNECTION_TIMEOUT, wrapper_fut).await {
+        Ok(result) => result.map_err(ConnectError::Quaint),
+        Err(_elapsed) => Err(ConnectError::TimedOut),
+    }
+}
+
+/// A failed connection attempt. The quaint error is kept until the attempts are over, so it can
+/// be classified by its database error code.
+#[derive(Debug)]
+enum ConnectError {
+    Quaint(QuaintError),
+    TimedOut,
+}
+
+impl ConnectError {
+    fn transient(&self) -> Option<&'static str> {
+        match self {
+            ConnectError::Quaint(err) => classify_transient_error(err).map(|transient_error| transient_error.as_str()),
+            ConnectError::TimedOut => Some(TransientError::ConnectionReset.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectError::Quaint(err) => fmt::Display::fmt(err, f),
+            ConnectError::TimedOut => f.write_str("Connect timed out"),
+        }
+    }
+}
+
+impl From<ConnectError> for SqlError {
+    fn from(err: ConnectError) -> Self {
+        match err {
+            ConnectError::Quaint(err) => err.into(),
+            ConnectError::TimedOut => SqlError::from(ErrorKind::ConnectTimeout("Tokio timer".into())),
+        }
+    }
+}
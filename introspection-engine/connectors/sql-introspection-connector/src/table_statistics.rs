@@ -0,0 +1,45 @@
+use crate::warnings::{warning_table_statistics, FieldStatistics, ModelStatistics};
+use datamodel::{Datamodel, WithDatabaseName};
+use introspection_connector::Warning;
+use sql_schema_describer::TableStatistics;
+
+/// Summarizes the statistics of the tables backing the introspected models, so users can see
+/// e.g. which optional fields do not contain any null values. Empty tables are left out.
+pub(crate) fn table_statistics_warning(data_model: &Datamodel, statistics: &[TableStatistics]) -> Option<Warning> {
+    let affected: Vec<ModelStatistics> = data_model
+        .models()
+        .filter(|model| !model.is_commented_out)
+        .filter_map(|model| {
+            let table_statistics = statistics
+                .iter()
+                .find(|table_statistics| table_statistics.table == model.final_database_name())?;
+
+            let row_count = table_statistics.row_count.filter(|row_count| *row_count > 0)?;
+
+            let fields = model
+                .scalar_fields()
+                .filter(|field| field.is_optional() && !field.is_commented_out)
+                .filter_map(|field| {
+                    let null_fraction = table_statistics.null_fractions.get(field.final_database_name())?;
+
+                    Some(FieldStatistics {
+                        field: field.name.clone(),
+                        null_fraction: *null_fraction,
+                    })
+                })
+                .collect();
+
+            Some(ModelStatistics {
+                model: model.name.clone(),
+                row_count,
+                fields,
+            })
+        })
+        .collect();
+
+    if affected.is_empty() {
+        None
+    } else {
+        Some(warning_table_statistics(&affected))
+    }
+}
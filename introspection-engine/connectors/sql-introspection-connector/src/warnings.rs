@@ -62,6 +62,21 @@ impl EnumAndValue {
     }
 }
 
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelStatistics {
+    pub(crate) model: String,
+    pub(crate) row_count: u64,
+    pub(crate) fields: Vec<FieldStatistics>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldStatistics {
+    pub(crate) field: String,
+    pub(crate) null_fraction: f64,
+}
+
 pub fn warning_models_without_identifier(affected: &[Model]) -> Warning {
     Warning {
         code: 1,
@@ -186,3 +201,11 @@ pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+pub fn warning_table_statistics(affected: &[ModelStatistics]) -> Warning {
+    Warning {
+        code: 15,
+        message: "These models have the following approximate row counts, as estimated by the database. For their optional fields, the fraction of null values in the sampled rows is listed. Optional fields without null values could possibly be required.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
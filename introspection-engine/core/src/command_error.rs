@@ -1,3 +1,4 @@
+use datamodel::diagnostics::Diagnostics;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -5,9 +6,10 @@ pub enum CommandError {
     /// When there are no models or enums detected.
     #[error("The introspected database was empty: {0} .")]
     IntrospectionResultEmpty(String),
-    /// When there was a bad datamodel as part of the input.
+    /// When there was a bad datamodel as part of the input. Holds the rendered errors and the
+    /// diagnostics they were rendered from.
     #[error("{0}")]
-    ReceivedBadDatamodel(String),
+    ReceivedBadDatamodel(String, Diagnostics),
     #[error("Generic error. (error: {0})")]
     Generic(anyhow::Error),
 }
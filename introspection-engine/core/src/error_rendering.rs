@@ -14,8 +14,12 @@ pub fn render_error(crate_error: Error) -> UserFacingError {
         Error::CommandError(CommandError::IntrospectionResultEmpty(connection_string)) => {
             KnownError::new(IntrospectionResultEmpty { connection_string }).into()
         }
-        Error::CommandError(CommandError::ReceivedBadDatamodel(full_error)) => {
-            KnownError::new(SchemaParserError { full_error }).into()
+        Error::CommandError(CommandError::ReceivedBadDatamodel(full_error, diagnostics)) => {
+            KnownError::new(SchemaParserError {
+                full_error,
+                diagnostics: serde_json::to_value(diagnostics.to_structured()).unwrap(),
+            })
+            .into()
         }
         _ => UserFacingError::from_dyn_error(&crate_error),
     }
@@ -3,7 +3,9 @@ use crate::error::Error;
 use datamodel::configuration::preview_features::PreviewFeatures;
 use datamodel::{Configuration, Datamodel, FieldArity};
 use futures::{FutureExt, TryFutureExt};
-use introspection_connector::{ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput};
+use introspection_connector::{
+    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput, TableStatistics,
+};
 use jsonrpc_derive::rpc;
 use serde_derive::*;
 use sql_introspection_connector::SqlIntrospectionConnector;
@@ -20,9 +22,15 @@ pub trait Rpc {
     #[rpc(name = "getDatabaseMetadata")]
     fn get_database_metadata(&self, input: IntrospectionInput) -> RpcFutureResult<DatabaseMetadata>;
 
+    #[rpc(name = "getDatabaseStatistics")]
+    fn get_database_statistics(&self, input: IntrospectionInput) -> RpcFutureResult<Vec<TableStatistics>>;
+
     #[rpc(name = "getDatabaseDescription")]
     fn get_database_description(&self, input: IntrospectionInput) -> RpcFutureResult<String>;
 
+    #[rpc(name = "describeDatabase")]
+    fn describe_database(&self, input: DescribeDatabaseInput) -> RpcFutureResult<serde_json::Value>;
+
     #[rpc(name = "getDatabaseVersion")]
     fn get_database_version(&self, input: IntrospectionInput) -> RpcFutureResult<String>;
 
@@ -44,10 +52,18 @@ impl Rpc for RpcImpl {
         Box::new(Self::get_database_metadata_internal(input.schema).boxed().compat())
     }
 
+    fn get_database_statistics(&self, input: IntrospectionInput) -> RpcFutureResult<Vec<TableStatistics>> {
+        Box::new(Self::get_database_statistics_internal(input.schema).boxed().compat())
+    }
+
     fn get_database_description(&self, input: IntrospectionInput) -> RpcFutureResult<String> {
         Box::new(Self::get_database_description_internal(input.schema).boxed().compat())
     }
 
+    fn describe_database(&self, input: DescribeDatabaseInput) -> RpcFutureResult<serde_json::Value> {
+        Box::new(Self::describe_database_internal(input.url).boxed().compat())
+    }
+
     fn get_database_version(&self, input: IntrospectionInput) -> RpcFutureResult<String> {
         Box::new(Self::get_database_version_internal(input.schema).boxed().compat())
     }
@@ -69,20 +85,16 @@ impl RpcImpl {
     async fn load_connector(schema: &str) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)?;
 
-        let url = config
+        let datasource = config
             .subject
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
 
-        Ok((
-            config.subject,
-            url.clone(),
-            Box::new(SqlIntrospectionConnector::new(&url).await?),
-        ))
+        let url = datasource.direct_url_or_url().to_owned().value;
+        let connector = SqlIntrospectionConnector::new_with_retry_policy(&url, datasource.retry_policy).await?;
+
+        Ok((config.subject, url, Box::new(connector)))
     }
 
     pub async fn catch<O>(fut: impl std::future::Future<Output = ConnectorResult<O>>) -> RpcResult<O> {
@@ -140,6 +152,7 @@ impl RpcImpl {
         let mut dm_that_needs_fixing = datamodel::parse_datamodel_without_validation(&schema).map_err(|err| {
             Error::from(CommandError::ReceivedBadDatamodel(
                 err.to_pretty_string("schema.prisma", &schema),
+                err,
             ))
         })?;
 
@@ -168,6 +181,7 @@ impl RpcImpl {
             .map_err(|err| {
                 Error::from(CommandError::ReceivedBadDatamodel(
                     err.to_pretty_string("schema.prisma", &schema),
+                    err,
                 ))
             })?;
 
@@ -184,6 +198,12 @@ impl RpcImpl {
         RpcImpl::catch(connector.get_database_description()).await
     }
 
+    /// Describes the database behind a connection string, without going through a Prisma schema.
+    pub async fn describe_database_internal(url: String) -> RpcResult<serde_json::Value> {
+        let connector = RpcImpl::catch(SqlIntrospectionConnector::new(&url)).await?;
+        RpcImpl::catch(connector.describe_database()).await
+    }
+
     pub async fn get_database_version_internal(schema: String) -> RpcResult<String> {
         let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
         RpcImpl::catch(connector.get_database_version()).await
@@ -194,6 +214,11 @@ impl RpcImpl {
         RpcImpl::catch(connector.get_metadata()).await
     }
 
+    pub async fn get_database_statistics_internal(schema: String) -> RpcResult<Vec<TableStatistics>> {
+        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
+        RpcImpl::catch(connector.get_statistics()).await
+    }
+
     pub async fn debug_panic() -> RpcResult<()> {
         panic!("This is the debugPanic artificial panic")
     }
@@ -206,6 +231,11 @@ pub struct IntrospectionInput {
     pub(crate) force: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDatabaseInput {
+    pub(crate) url: String,
+}
+
 fn default_false() -> bool {
     false
 }
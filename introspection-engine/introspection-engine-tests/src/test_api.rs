@@ -3,7 +3,7 @@ use datamodel::Configuration;
 use datamodel::{preview_features::PreviewFeatures, Datamodel};
 use enumflags2::BitFlags;
 use eyre::{Report, Result};
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector, Version};
+use introspection_connector::{DatabaseMetadata, IntrospectionConnector, TableStatistics, Version};
 use introspection_core::rpc::RpcImpl;
 use quaint::{
     prelude::{ConnectionInfo, SqlFamily},
@@ -123,10 +123,18 @@ impl TestApi {
         Ok(self.introspection_connector.get_metadata().await?)
     }
 
+    pub async fn get_statistics(&self) -> Result<Vec<TableStatistics>> {
+        Ok(self.introspection_connector.get_statistics().await?)
+    }
+
     pub async fn get_database_description(&self) -> Result<String> {
         Ok(self.introspection_connector.get_database_description().await?)
     }
 
+    pub async fn describe_database(&self) -> Result<serde_json::Value> {
+        Ok(self.introspection_connector.describe_database().await?)
+    }
+
     pub async fn get_database_version(&self) -> Result<String> {
         Ok(self.introspection_connector.get_database_version().await?)
     }
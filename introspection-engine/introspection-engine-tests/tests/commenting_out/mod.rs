@@ -2,6 +2,7 @@ use barrel::types;
 use indoc::indoc;
 use introspection_engine_tests::{assert_eq_json, test_api::*};
 use pretty_assertions::assert_eq;
+use quaint::prelude::Queryable;
 use serde_json::json;
 use test_macros::test_each_connector;
 
@@ -291,3 +292,27 @@ async fn commenting_out_a_table_without_columns(api: &TestApi) -> crate::TestRes
 
     Ok(())
 }
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn commenting_out_a_computed_column(api: &TestApi) -> crate::TestResult {
+    let sql = format!(
+        "CREATE TABLE [{}].[Test] (id INT PRIMARY KEY, price INT NOT NULL, quantity INT NOT NULL, total AS price * quantity)",
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let dm = indoc! {r#"
+        model Test {
+          id       Int @id
+          price    Int
+          quantity Int
+          // This field is a computed column, its value is generated by the database.
+          // total int
+        }
+    "#};
+
+    assert_eq!(dm, &api.introspect().await?);
+
+    Ok(())
+}
@@ -26,7 +26,7 @@ async fn connection_string_problems_give_a_nice_error() {
                     Please refer to the documentation in https://www.prisma.io/docs/reference/database-reference/connection-urls
                     for constructing a correct connection string. In some cases, certain characters must be escaped.
                     Please check the string for any illegal characters.",
-                    connection_string = provider.1
+                    connection_string = provider.1.replace("password-with-#", "***")
                 ).replace('\n', " ")
             },
             _ => {
@@ -35,7 +35,7 @@ async fn connection_string_problems_give_a_nice_error() {
                     Please refer to the documentation in https://www.prisma.io/docs/reference/database-reference/connection-urls
                     for constructing a correct connection string. In some cases, certain characters must be escaped.
                     Please check the string for any illegal characters.",
-                    connection_string = provider.1
+                    connection_string = provider.1.replace("password-with-#", "***")
                 ).replace('\n', " ")
             }
         };
@@ -1,3 +1,4 @@
 mod mssql;
 mod mysql;
 mod postgres;
+mod sqlite;
@@ -31,6 +31,10 @@ const TYPES: &[(&str, &str)] = &[
     ("xml", "Xml"),
     ("json", "Json"),
     ("jsonb", "JsonB"),
+    ("inet", "Inet"),
+    ("oid", "Oid"),
+    ("money", "Money"),
+    ("interval", "Interval"),
 ];
 
 #[test_each_connector(tags("postgres"))]
@@ -94,6 +98,10 @@ async fn native_type_columns_feature_on(api: &TestApi) -> crate::TestResult {
             xml             String   @postgres.Xml
             json            Json     @postgres.Json
             jsonb           Json     @postgres.JsonB
+            inet            String   @postgres.Inet
+            oid             Int      @postgres.Oid
+            money           Decimal  @postgres.Money
+            interval        String   @postgres.Interval
           }
     "#};
 
@@ -167,6 +175,10 @@ async fn native_type_columns_feature_off(api: &TestApi) -> crate::TestResult {
             xml             String
             json            Json
             jsonb           Json
+            inet            String
+            oid             Int
+            money           Float
+            interval        String
         }
     "#};
 
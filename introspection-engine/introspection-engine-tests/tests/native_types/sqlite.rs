@@ -0,0 +1,76 @@
+use indoc::indoc;
+use introspection_engine_tests::test_api::*;
+use test_macros::test_each_connector;
+
+const TYPES: &[(&str, &str)] = &[
+    //fieldname, db datatype
+    ("integer", "Integer"),
+    ("real", "Real"),
+    ("text", "Text"),
+    ("blob", "Blob"),
+    ("numeric", "Numeric"),
+    ("decimal", "Decimal(4, 2)"),
+    ("varChar", "VarChar(200)"),
+    ("bool", "Boolean"),
+    ("dateTime", "DateTime"),
+    ("int", "Int"),
+];
+
+#[test_each_connector(tags("sqlite"))]
+async fn native_type_columns_feature_on(api: &TestApi) -> crate::TestResult {
+    let columns: Vec<String> = TYPES
+        .iter()
+        .map(|(name, db_type)| format!("\"{}\" {} Not Null", name, db_type))
+        .collect();
+
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                for column in &columns {
+                    t.inject_custom(column);
+                }
+            });
+        })
+        .await?;
+
+    let mut dm = indoc! {r#"
+        datasource sqlite {
+            provider        = "sqlite"
+            url             = "file:dev.db"
+        }
+
+        generator client {
+          provider = "prisma-client-js"
+          previewFeatures = ["nativeTypes"]
+        }
+    "#}
+    .to_string();
+
+    let types = indoc! {r#"
+        model Blog {
+            id       Int      @id @default(autoincrement()) @sqlite.Integer
+            integer  Int      @sqlite.Integer
+            real     Decimal  @sqlite.Real
+            text     String   @sqlite.Text
+            blob     Bytes    @sqlite.Blob
+            numeric  Decimal  @sqlite.Numeric
+            decimal  Decimal  @sqlite.Decimal(4, 2)
+            varChar  String   @sqlite.VarChar(200)
+            bool     Boolean  @sqlite.Boolean
+            dateTime DateTime @sqlite.DateTime
+            int      Int
+          }
+    "#};
+
+    let result = api.re_introspect(&dm).await?;
+
+    dm.push_str(types);
+
+    println!("EXPECTATION: \n {:#}", dm);
+    println!("RESULT: \n {:#}", result);
+
+    assert!(result.replace(" ", "").contains(&types.replace(" ", "")));
+
+    Ok(())
+}
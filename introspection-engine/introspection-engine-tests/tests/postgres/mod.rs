@@ -1,5 +1,7 @@
+use barrel::types;
 use indoc::indoc;
 use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use serde_json::json;
 use test_macros::test_each_connector;
 
 #[test_each_connector(tags("postgres"))]
@@ -48,3 +50,113 @@ async fn sequences_should_work(api: &TestApi) -> crate::TestResult {
     assert_eq_datamodels!(&result, &dm);
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn table_statistics_are_part_of_the_warnings(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Cat", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text().nullable(true));
+                t.add_column("nickname", types::text().nullable(true));
+            });
+
+            migration.create_table("Dog", move |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let schema_name = api.schema_name();
+
+    api.database()
+        .raw_cmd(&format!(
+            "INSERT INTO \"{0}\".\"Cat\" (name, nickname) VALUES ('Mittens', NULL), ('Felix', NULL), ('Garfield', 'Garfy'), ('Tom', NULL); ANALYZE \"{0}\".\"Cat\"",
+            schema_name
+        ))
+        .await?;
+
+    let expected = json!([{
+        "code": 15,
+        "message": "These models have the following approximate row counts, as estimated by the database. For their optional fields, the fraction of null values in the sampled rows is listed. Optional fields without null values could possibly be required.",
+        "affected": [{
+            "model": "Cat",
+            "rowCount": 4,
+            "fields": [
+                { "field": "name", "nullFraction": 0.0 },
+                { "field": "nickname", "nullFraction": 0.75 },
+            ]
+        }]
+    }]);
+
+    let warnings: serde_json::Value = serde_json::from_str(&api.introspection_warnings().await?)?;
+
+    assert_eq!(expected, warnings);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_constraints_should_be_introspected(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("email Text Not Null");
+                t.inject_custom("Constraint \"User_email_key\" Unique (email) Deferrable Initially Deferred");
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("user_id Integer Not Null References \"User\" (id) Deferrable Initially Deferred");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Post {
+          id      Int  @id @default(autoincrement())
+          user_id Int
+          User    User @relation(fields: [user_id], references: [id], deferrable: true)
+        }
+
+        model User {
+          id    Int    @id @default(autoincrement())
+          email String
+          Post  Post[]
+
+          @@unique([email], name: "User_email_key", deferrable: true)
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_methods_should_be_introspected(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Cat", move |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("data Jsonb Not Null");
+            });
+
+            migration.inject_custom("Create Index \"Cat_data_idx\" On \"Cat\" Using Gin (data)");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Cat {
+          id   Int  @id @default(autoincrement())
+          data Json
+
+          @@index([data], name: "Cat_data_idx", type: Gin)
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
@@ -605,3 +605,42 @@ async fn compound_foreign_keys_for_duplicate_one_to_many_relations(api: &TestApi
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn compound_foreign_keys_for_multiple_self_relations_with_colliding_names(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Person", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("age", types::integer());
+                t.add_column("partner_id", types::integer().nullable(true));
+                t.add_column("partner_age", types::integer().nullable(true));
+                t.add_column("partner_id_partner_age", types::integer().nullable(true));
+
+                t.add_foreign_key(&["partner_id", "partner_age"], "Person", &["id", "age"]);
+                t.add_foreign_key(&["partner_id_partner_age"], "Person", &["id"]);
+                t.add_constraint("person_unique", types::unique_constraint(vec!["id", "age"]));
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Person {
+            id                                                         Int      @id @default(autoincrement())
+            age                                                        Int
+            partner_id                                                 Int?
+            partner_age                                                Int?
+            partner_id_partner_age                                     Int?
+            Person_PersonToPerson_partner_id_partner_age_1             Person?  @relation("PersonToPerson_partner_id_partner_age_1", fields: [partner_id, partner_age], references: [id, age])
+            Person_PersonToPerson_partner_id_partner_age_2             Person?  @relation("PersonToPerson_partner_id_partner_age_2", fields: [partner_id_partner_age], references: [id])
+            other_Person_PersonToPerson_partner_id_partner_age_1       Person[] @relation("PersonToPerson_partner_id_partner_age_1")
+            other_Person_PersonToPerson_partner_id_partner_age_2       Person[] @relation("PersonToPerson_partner_id_partner_age_2")
+
+            @@unique([id, age], name: "person_unique")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
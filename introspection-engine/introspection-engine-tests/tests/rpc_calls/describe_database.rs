@@ -0,0 +1,125 @@
+use barrel::types;
+use introspection_engine_tests::{test_api::*, BarrelMigrationExecutor};
+use serde_json::json;
+use sql_schema_describer::SqlSchema;
+use test_macros::test_each_connector;
+
+async fn setup_blog(barrel: &BarrelMigrationExecutor) -> crate::TestResult {
+    barrel
+        .execute(|migration| {
+            migration.create_table("Blog", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("string", types::text());
+            });
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn describe_database_returns_the_described_schema(api: &TestApi) -> crate::TestResult {
+    setup_blog(&api.barrel()).await?;
+
+    let description: SqlSchema = serde_json::from_value(api.describe_database().await?)?;
+
+    assert_eq!(description, api.describe_schema().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn describe_database_for_sqlite_should_work(api: &TestApi) -> crate::TestResult {
+    setup_blog(&api.barrel()).await?;
+
+    let expected = json!({
+        "tables": [
+            {
+                "name": "Blog",
+                "columns": [
+                    {
+                        "name": "id",
+                        "tpe": {
+                            "dataType": "INTEGER",
+                            "fullDataType": "INTEGER",
+                            "characterMaximumLength": null,
+                            "family": "int",
+                            "arity": "required",
+                            "nativeType": null,
+                        },
+                        "default": null,
+                        "autoIncrement": true,
+                        "computed": false,
+                    },
+                    {
+                        "name": "string",
+                        "tpe": {
+                            "dataType": "TEXT",
+                            "fullDataType": "TEXT",
+                            "characterMaximumLength": null,
+                            "family": "string",
+                            "arity": "required",
+                            "nativeType": null,
+                        },
+                        "default": null,
+                        "autoIncrement": false,
+                        "computed": false,
+                    },
+                ],
+                "indices": [],
+                "primaryKey": {
+                    "columns": ["id"],
+                    "sequence": null,
+                    "constraintName": null,
+                    "clustered": null,
+                },
+                "foreignKeys": [],
+                "engine": null,
+                "rowFormat": null,
+            },
+        ],
+        "enums": [],
+        "sequences": [],
+        "views": [],
+    });
+
+    assert_eq!(expected, api.describe_database().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn describe_database_returns_sqlite_views(api: &TestApi) -> crate::TestResult {
+    setup_blog(&api.barrel()).await?;
+
+    let sql = r#"CREATE VIEW "BlogStrings" AS SELECT string FROM "Blog""#;
+    api.database().execute_raw(sql, &[]).await?;
+
+    let description = api.describe_database().await?;
+
+    let expected = json!([{
+        "name": "BlogStrings",
+        "definition": r#"CREATE VIEW "BlogStrings" AS SELECT string FROM "Blog""#,
+    }]);
+
+    assert_eq!(expected, description["views"]);
+    assert_eq!(1, description["tables"].as_array().unwrap().len());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn describe_database_returns_postgres_views(api: &TestApi) -> crate::TestResult {
+    setup_blog(&api.barrel()).await?;
+
+    let sql = r#"CREATE VIEW "BlogStrings" AS SELECT string FROM "Blog""#;
+    api.database().execute_raw(sql, &[]).await?;
+
+    let description: SqlSchema = serde_json::from_value(api.describe_database().await?)?;
+    let view = description.get_view("BlogStrings").unwrap();
+
+    assert!(view.definition.as_ref().unwrap().contains("SELECT"));
+    assert!(description.get_table("BlogStrings").is_none());
+
+    Ok(())
+}
@@ -41,6 +41,7 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                         name: "string",
@@ -60,6 +61,7 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: [],
@@ -70,13 +72,17 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                     ],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 },
             ),
             foreign_keys: [],
+            engine: None,
+            row_format: None,
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -110,6 +116,7 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                     },
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                         name: "string",
@@ -129,6 +136,7 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: [],
@@ -139,13 +147,17 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                     ],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 },
             ),
             foreign_keys: [],
+            engine: None,
+            row_format: None,
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -183,9 +195,11 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                                     "Blog_id_seq",
                             ),
                             constraint_name: None,
+                            on_update: false,
                         },
                     ),
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                         name: "string",
@@ -203,6 +217,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: [],
@@ -219,9 +234,12 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                     constraint_name: Some(
                             "Blog_pkey",
                     ),
+                    clustered: None,
                 },
             ),
             foreign_keys: [],
+            engine: None,
+            row_format: None,
         },
     ],
     enums: [],
@@ -230,6 +248,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                 name: "Blog_id_seq",
         },
     ],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -259,6 +278,7 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                     },
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                         name: "string",
@@ -272,6 +292,7 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: [],
@@ -282,13 +303,17 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                     ],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 },
             ),
             foreign_keys: [],
+            engine: None,
+            row_format: None,
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -0,0 +1,72 @@
+use barrel::types;
+use introspection_engine_tests::{test_api::*, BarrelMigrationExecutor};
+use pretty_assertions::assert_eq;
+use test_macros::test_each_connector;
+
+#[test_each_connector(tags("postgres"))]
+async fn statistics_for_postgres_should_work(api: &TestApi) -> crate::TestResult {
+    setup(&api.barrel(), api.schema_name()).await?;
+
+    api.database()
+        .raw_cmd(&format!(
+            "INSERT INTO \"{0}\".\"Cat\" (name) VALUES ('Mittens'), ('Felix'), ('Garfield'); ANALYZE \"{0}\".\"Cat\"",
+            api.schema_name()
+        ))
+        .await?;
+
+    let statistics = api.get_statistics().await?;
+    let tables: Vec<&str> = statistics.iter().map(|table| table.table.as_str()).collect();
+
+    assert_eq!(tables, &["Cat", "Dog"]);
+
+    let cat = &statistics[0];
+
+    assert_eq!(cat.row_count, Some(3));
+    assert!(cat.data_size_in_bytes.unwrap() > 0);
+    assert!(cat.index_size_in_bytes.unwrap() > 0);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn statistics_for_mysql_should_work(api: &TestApi) -> crate::TestResult {
+    setup(&api.barrel(), api.db_name()).await?;
+
+    let statistics = api.get_statistics().await?;
+    let tables: Vec<&str> = statistics.iter().map(|table| table.table.as_str()).collect();
+
+    assert_eq!(tables, &["Cat", "Dog"]);
+    assert!(statistics.iter().all(|table| table.data_size_in_bytes.is_some()));
+    assert!(statistics.iter().all(|table| table.index_size_in_bytes.is_some()));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn statistics_for_sqlite_are_empty(api: &TestApi) -> crate::TestResult {
+    setup(&api.barrel(), api.schema_name()).await?;
+
+    assert!(api.get_statistics().await?.is_empty());
+
+    Ok(())
+}
+
+async fn setup(barrel: &BarrelMigrationExecutor, db_name: &str) -> crate::TestResult {
+    barrel
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Cat", |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("name", types::text());
+                });
+
+                migration.create_table("Dog", |t| {
+                    t.add_column("id", types::primary());
+                });
+            },
+            db_name,
+        )
+        .await?;
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
+mod describe_database;
 mod get_database_description;
 mod get_database_version;
 mod get_metadata_command_for_empty_db_tests;
 mod get_metadata_command_tests;
+mod get_statistics_command_tests;
 mod list_databases_command_tests;
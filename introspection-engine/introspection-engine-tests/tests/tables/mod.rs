@@ -442,6 +442,37 @@ async fn my_default_value_as_dbgenerated(api: &TestApi) -> crate::TestResult {
     Ok(())
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn table_options_should_be_introspected(api: &TestApi) -> crate::TestResult {
+    api.database()
+        .execute_raw(
+            "CREATE TABLE `Cat` (`id` INTEGER NOT NULL, PRIMARY KEY (`id`)) ENGINE = MyISAM ROW_FORMAT = FIXED",
+            &[],
+        )
+        .await?;
+
+    api.database()
+        .execute_raw("CREATE TABLE `Dog` (`id` INTEGER NOT NULL, PRIMARY KEY (`id`))", &[])
+        .await?;
+
+    let dm = indoc! {r#"
+        model Cat {
+            id Int @id
+
+            @@engine("MyISAM")
+            @@rowFormat("FIXED")
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql_8"))]
 async fn a_table_with_an_index_that_contains_expressions_should_be_ignored(api: &TestApi) -> crate::TestResult {
     api.barrel()
@@ -6,6 +6,8 @@ use dml::model::Model;
 
 pub struct CombinedConnector {
     capabilities: Vec<ConnectorCapability>,
+    max_identifier_length: usize,
+    reserved_words: Vec<&'static str>,
 }
 
 impl CombinedConnector {
@@ -23,8 +25,24 @@ impl CombinedConnector {
             }
         }
 
+        let max_identifier_length = connectors
+            .iter()
+            .map(|connector| connector.max_identifier_length())
+            .min()
+            .unwrap_or(usize::MAX);
+
+        // a word reserved by any of the databases can not be used unquoted on all of them
+        let mut reserved_words: Vec<&'static str> = connectors
+            .iter()
+            .flat_map(|connector| connector.reserved_words().iter().copied())
+            .collect();
+        reserved_words.sort_unstable();
+        reserved_words.dedup();
+
         CombinedConnector {
             capabilities: combined_capabilities,
+            max_identifier_length,
+            reserved_words,
         }
     }
 }
@@ -34,6 +52,14 @@ impl Connector for CombinedConnector {
         &self.capabilities
     }
 
+    fn max_identifier_length(&self) -> usize {
+        self.max_identifier_length
+    }
+
+    fn reserved_words(&self) -> &[&'static str] {
+        &self.reserved_words
+    }
+
     fn validate_field(&self, _field: &Field) -> Result<(), ConnectorError> {
         Ok(())
     }
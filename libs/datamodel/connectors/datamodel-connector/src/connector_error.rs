@@ -82,6 +82,13 @@ impl ConnectorError {
         })
     }
 
+    pub fn new_incompatible_native_type_on_list_field_error(native_type: &str, connector_name: &str) -> ConnectorError {
+        ConnectorError::from_kind(ErrorKind::IncompatibleNativeTypeOnListField {
+            native_type: String::from(native_type),
+            connector_name: String::from(connector_name),
+        })
+    }
+
     pub fn new_argument_m_out_of_range_error(message: &str, native_type: &str, connector_name: &str) -> ConnectorError {
         ConnectorError::from_kind(ErrorKind::ArgumentOutOfRangeError {
             native_type: String::from(native_type),
@@ -216,6 +223,12 @@ pub enum ErrorKind {
         connector_name: String,
     },
 
+    #[error("Native type {} of {} can not be used on list fields.", native_type, connector_name)]
+    IncompatibleNativeTypeOnListField {
+        native_type: String,
+        connector_name: String,
+    },
+
     #[error(
         "Argument M is out of range for Native type {} of {}: {}",
         native_type,
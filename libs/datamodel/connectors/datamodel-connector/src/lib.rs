@@ -75,6 +75,39 @@ pub trait Connector: Send + Sync {
         self.has_capability(ConnectorCapability::RelationFieldsInArbitraryOrder)
     }
 
+    fn supports_clustering_setting(&self) -> bool {
+        self.has_capability(ConnectorCapability::ClusteringSetting)
+    }
+
+    fn supports_table_options(&self) -> bool {
+        self.has_capability(ConnectorCapability::TableOptions)
+    }
+
+    fn supports_deferrable_constraints(&self) -> bool {
+        self.has_capability(ConnectorCapability::DeferrableConstraints)
+    }
+
+    fn supports_index_methods(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexMethods)
+    }
+
+    /// The maximum length of identifiers (table, column and constraint names) on the database.
+    fn max_identifier_length(&self) -> usize {
+        usize::MAX
+    }
+
+    /// The words the database reserves, in upper case. Tables and columns with these names only work
+    /// when their names are quoted.
+    fn reserved_words(&self) -> &[&'static str] {
+        &[]
+    }
+
+    fn is_reserved_word(&self, name: &str) -> bool {
+        let name = name.to_uppercase();
+
+        self.reserved_words().iter().any(|word| *word == name)
+    }
+
     fn wrap_in_argument_count_mismatch_error(
         &self,
         native_type: &str,
@@ -108,8 +141,19 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationFieldsInArbitraryOrder,
+    ClusteringSetting,
+    TableOptions,
+    DeferrableConstraints,
+    IndexMethods,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    NativeUpsert,
+    RelationJoins,
+    BitwiseFilters,
+    StatisticalAggregations,
+    PercentileAggregations,
+    GroupByRollup,
+    WindowFunctions,
 }
 
 /// Contains all capabilities that the connector is able to serve.
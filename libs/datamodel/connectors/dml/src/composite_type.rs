@@ -0,0 +1,73 @@
+use crate::field::{FieldArity, FieldType};
+use crate::traits::WithName;
+
+/// Represents a composite type in the datamodel: a named group of fields that is embedded into
+/// the models using it, instead of being stored in a table of its own.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompositeType {
+    /// Name of the composite type.
+    pub name: String,
+    /// Fields of the composite type.
+    pub fields: Vec<CompositeTypeField>,
+    /// Comments for this composite type.
+    pub documentation: Option<String>,
+}
+
+impl CompositeType {
+    /// Creates a new composite type with the given name and no fields.
+    pub fn new(name: &str) -> CompositeType {
+        CompositeType {
+            name: String::from(name),
+            fields: vec![],
+            documentation: None,
+        }
+    }
+
+    pub fn add_field(&mut self, field: CompositeTypeField) {
+        self.fields.push(field)
+    }
+
+    /// Gets an iterator over all fields.
+    pub fn fields(&self) -> std::slice::Iter<CompositeTypeField> {
+        self.fields.iter()
+    }
+
+    pub fn find_field(&self, name: &str) -> Option<&CompositeTypeField> {
+        self.fields().find(|field| field.name == name)
+    }
+}
+
+impl WithName for CompositeType {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn set_name(&mut self, name: &str) {
+        self.name = String::from(name)
+    }
+}
+
+/// Represents a field of a composite type. The type is a scalar, an enum or another composite type.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompositeTypeField {
+    /// Name of the field.
+    pub name: String,
+    /// The field's type.
+    pub field_type: FieldType,
+    /// The field's arity.
+    pub arity: FieldArity,
+    /// Comments associated with this field.
+    pub documentation: Option<String>,
+}
+
+impl CompositeTypeField {
+    /// Creates a new field with the given name and type.
+    pub fn new(name: &str, arity: FieldArity, field_type: FieldType) -> CompositeTypeField {
+        CompositeTypeField {
+            name: String::from(name),
+            field_type,
+            arity,
+            documentation: None,
+        }
+    }
+}
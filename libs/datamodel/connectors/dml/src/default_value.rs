@@ -37,7 +37,7 @@ pub struct ValueGenerator {
 
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
-        let generator = ValueGeneratorFn::new(name.as_ref())?;
+        let generator = ValueGeneratorFn::new(name.as_ref(), &args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -62,6 +62,18 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_ulid() -> Self {
+        ValueGenerator::new("ulid".to_owned(), vec![]).unwrap()
+    }
+
+    pub fn new_nanoid(length: Option<u8>) -> Self {
+        let args = length
+            .map(|length| vec![PrismaValue::Int(length.into())])
+            .unwrap_or_default();
+
+        ValueGenerator::new("nanoid".to_owned(), args).unwrap()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -74,6 +86,12 @@ impl ValueGenerator {
         self.generator.invoke()
     }
 
+    /// Whether the value is generated by the query engine when it is not provided, as opposed to
+    /// being left to the database.
+    pub fn is_engine_generated(&self) -> bool {
+        self.generator.is_engine_generated()
+    }
+
     pub fn check_compatibility_with_scalar_type(&self, scalar_type: ScalarType) -> std::result::Result<(), String> {
         if self.generator.can_handle(scalar_type) {
             Ok(())
@@ -91,16 +109,32 @@ impl ValueGenerator {
 pub enum ValueGeneratorFn {
     UUID,
     CUID,
+    ULID,
+    /// A nanoid, with an optional length. The default length is 21 characters.
+    Nanoid(Option<u8>),
     Now,
     Autoincrement,
     DbGenerated,
 }
 
 impl ValueGeneratorFn {
-    fn new(name: &str) -> std::result::Result<Self, String> {
+    fn new(name: &str, args: &[PrismaValue]) -> std::result::Result<Self, String> {
+        if name == "nanoid" {
+            return match args {
+                [] => Ok(Self::Nanoid(None)),
+                [PrismaValue::Int(length)] if (1..=255).contains(length) => Ok(Self::Nanoid(Some(*length as u8))),
+                _ => Err("The function `nanoid()` takes an optional length between 1 and 255.".to_owned()),
+            };
+        }
+
+        if !args.is_empty() {
+            return Err(format!("The function `{}()` does not take any arguments.", name));
+        }
+
         match name {
             "cuid" => Ok(Self::CUID),
             "uuid" => Ok(Self::UUID),
+            "ulid" => Ok(Self::ULID),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
@@ -112,16 +146,24 @@ impl ValueGeneratorFn {
         match self {
             Self::UUID => Self::generate_uuid(),
             Self::CUID => Self::generate_cuid(),
+            Self::ULID => Self::generate_ulid(),
+            Self::Nanoid(length) => Self::generate_nanoid(*length),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
         }
     }
 
+    fn is_engine_generated(&self) -> bool {
+        !matches!(self, Self::Autoincrement | Self::DbGenerated)
+    }
+
     fn can_handle(&self, scalar_type: ScalarType) -> bool {
         match (self, scalar_type) {
             (Self::UUID, ScalarType::String) => true,
             (Self::CUID, ScalarType::String) => true,
+            (Self::ULID, ScalarType::String) => true,
+            (Self::Nanoid(_), ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
             (Self::Autoincrement, ScalarType::BigInt) => true,
@@ -138,6 +180,16 @@ impl ValueGeneratorFn {
         Some(PrismaValue::Uuid(Uuid::new_v4()))
     }
 
+    fn generate_ulid() -> Option<PrismaValue> {
+        Some(PrismaValue::String(ulid::Ulid::new().to_string()))
+    }
+
+    fn generate_nanoid(length: Option<u8>) -> Option<PrismaValue> {
+        let length = length.map(usize::from).unwrap_or(21);
+
+        Some(PrismaValue::String(nanoid::nanoid!(length)))
+    }
+
     fn generate_now() -> Option<PrismaValue> {
         Some(PrismaValue::DateTime(Utc::now().into()))
     }
@@ -3,6 +3,7 @@ use crate::default_value::{DefaultValue, ValueGenerator};
 use crate::native_type_instance::NativeTypeInstance;
 use crate::scalars::ScalarType;
 use crate::traits::{WithDatabaseName, WithName};
+use prisma_value::PrismaValue;
 use std::hash::Hash;
 
 /// Arity of a Field in a Model.
@@ -35,6 +36,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field embedding the composite type of the given name.
+    CompositeType(String),
     /// native field type.
     NativeType(ScalarType, NativeTypeInstance),
     /// This is a field with an unsupported datatype - used by introspection only.
@@ -138,6 +141,27 @@ impl Field {
         }
     }
 
+    pub fn is_version(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_version,
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_encrypted,
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn validations(&self) -> &[FieldValidation] {
+        match &self {
+            Field::ScalarField(sf) => &sf.validations,
+            Field::RelationField(_) => &[],
+        }
+    }
+
     pub fn is_unique(&self) -> bool {
         match &self {
             Field::ScalarField(sf) => sf.is_unique,
@@ -260,6 +284,28 @@ impl RelationField {
     }
 }
 
+/// A validation of the values of a scalar field. The engines do not enforce validations, they are
+/// exposed in the DMMF for clients to validate values before sending them.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldValidation {
+    /// `@length(min, max)`: the bounds of the number of characters of a String field, inclusive.
+    Length { min: Option<u32>, max: Option<u32> },
+    /// `@range(min, max)`: the bounds of the values of a numeric field, inclusive.
+    Range {
+        min: Option<PrismaValue>,
+        max: Option<PrismaValue>,
+    },
+}
+
+impl FieldValidation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldValidation::Length { .. } => "length",
+            FieldValidation::Range { .. } => "range",
+        }
+    }
+}
+
 /// Represents a scalar field in a model.
 #[derive(Debug, PartialEq, Clone)]
 pub struct ScalarField {
@@ -294,6 +340,17 @@ pub struct ScalarField {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// If set, signals that this field holds the version of the record, which is
+    /// checked and incremented by every update and delete.
+    pub is_version: bool,
+
+    /// If set, signals that the values of this field are encrypted and decrypted by the
+    /// query engine embedder before they are written and after they are read.
+    pub is_encrypted: bool,
+
+    /// The validations of the values of this field, from `@length` and `@range`.
+    pub validations: Vec<FieldValidation>,
+
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
 }
@@ -312,6 +369,9 @@ impl ScalarField {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_version: false,
+            is_encrypted: false,
+            validations: Vec::new(),
             is_commented_out: false,
         }
     }
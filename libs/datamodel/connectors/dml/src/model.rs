@@ -15,10 +15,18 @@ pub struct Model {
     pub database_name: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Whether the model is the join model of a many-to-many relation, marked with `@@joinTable`.
+    pub is_join_table: bool,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// Whether the primary key defined with `@@id` is clustered. Only supported on SQL Server.
+    pub id_clustered: Option<bool>,
+    /// The storage engine of the table, defined with `@@engine`. Only supported on MySQL.
+    pub engine: Option<String>,
+    /// The row format of the table, defined with `@@rowFormat`. Only supported on MySQL.
+    pub row_format: Option<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
@@ -31,6 +39,12 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// Whether the index is clustered. Only supported on SQL Server.
+    pub clustered: Option<bool>,
+    /// Whether the unique constraint is `DEFERRABLE INITIALLY DEFERRED`. Only supported on Postgres.
+    pub deferrable: bool,
+    /// The index method, defined with the `type` argument of `@@index`. Only supported on Postgres.
+    pub method: Option<IndexMethod>,
 }
 
 impl IndexDefinition {
@@ -45,6 +59,40 @@ pub enum IndexType {
     Normal,
 }
 
+/// The method (access method on Postgres) of an index.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexMethod {
+    BTree,
+    Hash,
+    Gist,
+    SpGist,
+    Gin,
+    Brin,
+}
+
+impl IndexMethod {
+    pub const ALL: &'static [IndexMethod] = &[
+        IndexMethod::BTree,
+        IndexMethod::Hash,
+        IndexMethod::Gist,
+        IndexMethod::SpGist,
+        IndexMethod::Gin,
+        IndexMethod::Brin,
+    ];
+
+    /// The name of the method in the datamodel.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndexMethod::BTree => "BTree",
+            IndexMethod::Hash => "Hash",
+            IndexMethod::Gist => "Gist",
+            IndexMethod::SpGist => "SpGist",
+            IndexMethod::Gin => "Gin",
+            IndexMethod::Brin => "Brin",
+        }
+    }
+}
+
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
 #[derive(Debug)]
 pub struct UniqueCriteria<'a> {
@@ -65,9 +113,13 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            id_clustered: None,
+            engine: None,
+            row_format: None,
             documentation: None,
             database_name,
             is_embedded: false,
+            is_join_table: false,
             is_generated: false,
             is_commented_out: false,
         }
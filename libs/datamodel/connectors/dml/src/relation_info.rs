@@ -12,6 +12,11 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// Whether the foreign key is `DEFERRABLE INITIALLY DEFERRED`. Only supported on Postgres.
+    pub deferrable: bool,
+    /// The database name of the join table of an implicit many-to-many relation, if it is
+    /// mapped with `@relation(map: ...)`.
+    pub map: Option<String>,
 }
 
 impl PartialEq for RelationInfo {
@@ -21,6 +26,8 @@ impl PartialEq for RelationInfo {
             && self.fields == other.fields
             && self.references == other.references
             && self.on_delete == other.on_delete
+            && self.deferrable == other.deferrable
+            && self.map == other.map
     }
 }
 
@@ -34,6 +41,8 @@ impl RelationInfo {
             references: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            deferrable: false,
+            map: None,
         }
     }
 }
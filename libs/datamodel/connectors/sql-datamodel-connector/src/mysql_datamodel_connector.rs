@@ -53,6 +53,92 @@ const NATIVE_TYPES_THAT_CAN_NOT_BE_USED_IN_KEY_SPECIFICATION: &[&str] = &[
     LONG_BLOB_TYPE_NAME,
 ];
 
+/// The reserved keywords of MySQL.
+const RESERVED_WORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CHANGE",
+    "CHECK",
+    "COLUMN",
+    "CONDITION",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "DATABASE",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DESCRIBE",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "EXISTS",
+    "FALSE",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "GROUP",
+    "GROUPS",
+    "HAVING",
+    "IN",
+    "INDEX",
+    "INSERT",
+    "INTERVAL",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "KEYS",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCK",
+    "MATCH",
+    "NOT",
+    "NULL",
+    "ON",
+    "OPTION",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PRIMARY",
+    "RANGE",
+    "RANK",
+    "READ",
+    "REFERENCES",
+    "RELEASE",
+    "RIGHT",
+    "ROW",
+    "ROWS",
+    "SCHEMA",
+    "SELECT",
+    "SET",
+    "SHOW",
+    "SYSTEM",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USAGE",
+    "USE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WITH",
+    "WRITE",
+];
+
 pub struct MySqlDatamodelConnector {
     capabilities: Vec<ConnectorCapability>,
     constructors: Vec<NativeTypeConstructor>,
@@ -67,6 +153,11 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::TableOptions,
+            ConnectorCapability::NativeUpsert,
+            ConnectorCapability::RelationJoins,
+            ConnectorCapability::BitwiseFilters,
+            ConnectorCapability::StatisticalAggregations,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -155,6 +246,14 @@ impl Connector for MySqlDatamodelConnector {
         &self.capabilities
     }
 
+    fn reserved_words(&self) -> &[&'static str] {
+        RESERVED_WORDS
+    }
+
+    fn max_identifier_length(&self) -> usize {
+        64
+    }
+
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
         if let FieldType::NativeType(_, native_type_instance) = field.field_type() {
             let native_type_name = native_type_instance.name.as_str();
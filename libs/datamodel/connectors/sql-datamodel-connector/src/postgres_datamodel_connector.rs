@@ -35,6 +35,95 @@ const UUID_TYPE_NAME: &str = "Uuid";
 const XML_TYPE_NAME: &str = "Xml";
 const JSON_TYPE_NAME: &str = "Json";
 const JSON_B_TYPE_NAME: &str = "JsonB";
+const CITEXT_TYPE_NAME: &str = "Citext";
+const INET_TYPE_NAME: &str = "Inet";
+const OID_TYPE_NAME: &str = "Oid";
+const MONEY_TYPE_NAME: &str = "Money";
+const INTERVAL_TYPE_NAME: &str = "Interval";
+
+const POSTGRES_MAX_CHARACTER_LENGTH: u32 = 10_485_760;
+const POSTGRES_MAX_BIT_LENGTH: u32 = 83_886_080;
+
+/// The reserved keywords of PostgreSQL.
+const RESERVED_WORDS: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INITIALLY",
+    "INTERSECT",
+    "INTO",
+    "LATERAL",
+    "LEADING",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "SELECT",
+    "SESSION_USER",
+    "SOME",
+    "SYMMETRIC",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
 
 pub struct PostgresDatamodelConnector {
     capabilities: Vec<ConnectorCapability>,
@@ -52,6 +141,15 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::InsensitiveFilters,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::DeferrableConstraints,
+            ConnectorCapability::IndexMethods,
+            ConnectorCapability::NativeUpsert,
+            ConnectorCapability::RelationJoins,
+            ConnectorCapability::BitwiseFilters,
+            ConnectorCapability::WindowFunctions,
+            ConnectorCapability::StatisticalAggregations,
+            ConnectorCapability::PercentileAggregations,
+            ConnectorCapability::GroupByRollup,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -81,6 +179,11 @@ impl PostgresDatamodelConnector {
         let xml = NativeTypeConstructor::without_args(XML_TYPE_NAME, vec![ScalarType::String]);
         let json = NativeTypeConstructor::without_args(JSON_TYPE_NAME, vec![ScalarType::Json]);
         let json_b = NativeTypeConstructor::without_args(JSON_B_TYPE_NAME, vec![ScalarType::Json]);
+        let citext = NativeTypeConstructor::without_args(CITEXT_TYPE_NAME, vec![ScalarType::String]);
+        let inet = NativeTypeConstructor::without_args(INET_TYPE_NAME, vec![ScalarType::String]);
+        let oid = NativeTypeConstructor::without_args(OID_TYPE_NAME, vec![ScalarType::Int]);
+        let money = NativeTypeConstructor::without_args(MONEY_TYPE_NAME, vec![ScalarType::Decimal]);
+        let interval = NativeTypeConstructor::without_args(INTERVAL_TYPE_NAME, vec![ScalarType::String]);
 
         let constructors = vec![
             small_int,
@@ -109,6 +212,11 @@ impl PostgresDatamodelConnector {
             xml,
             json,
             json_b,
+            citext,
+            inet,
+            oid,
+            money,
+            interval,
         ];
 
         PostgresDatamodelConnector {
@@ -123,6 +231,14 @@ impl Connector for PostgresDatamodelConnector {
         &self.capabilities
     }
 
+    fn reserved_words(&self) -> &[&'static str] {
+        RESERVED_WORDS
+    }
+
+    fn max_identifier_length(&self) -> usize {
+        63
+    }
+
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
         if let FieldType::NativeType(_scalar_type, native_type_instance) = field.field_type() {
             let native_type_name = native_type_instance.name.as_str();
@@ -150,23 +266,39 @@ impl Connector for PostgresDatamodelConnector {
                 _ => {}
             }
 
-            let length = match native_type {
-                PostgresType::Bit(l) => l,
-                PostgresType::VarBit(l) => l,
-                _ => None,
-            };
-            if length == Some(0) {
-                return Err(ConnectorError::new_argument_m_out_of_range_error(
-                    "M must be a positive integer.",
-                    native_type_name,
-                    "Postgres",
-                ));
+            match native_type {
+                PostgresType::VarChar(Some(length)) | PostgresType::Char(Some(length))
+                    if length == 0 || length > POSTGRES_MAX_CHARACTER_LENGTH =>
+                {
+                    return Err(ConnectorError::new_argument_m_out_of_range_error(
+                        "M can range from 1 to 10,485,760.",
+                        native_type_name,
+                        "Postgres",
+                    ));
+                }
+                PostgresType::Bit(Some(length)) | PostgresType::VarBit(Some(length))
+                    if length == 0 || length > POSTGRES_MAX_BIT_LENGTH =>
+                {
+                    return Err(ConnectorError::new_argument_m_out_of_range_error(
+                        "M can range from 1 to 83,886,080.",
+                        native_type_name,
+                        "Postgres",
+                    ));
+                }
+                _ => {}
             }
 
             if matches!(
                 native_type,
                 PostgresType::SmallSerial | PostgresType::Serial | PostgresType::BigSerial
             ) {
+                if field.arity().is_list() {
+                    return Err(ConnectorError::new_incompatible_native_type_on_list_field_error(
+                        native_type_name,
+                        "Postgres",
+                    ));
+                }
+
                 if let Some(DefaultValue::Single(_)) = field.default_value() {
                     return Err(
                         ConnectorError::new_incompatible_sequential_type_with_static_default_value_error(
@@ -177,22 +309,20 @@ impl Connector for PostgresDatamodelConnector {
                 }
             }
 
-            let time_precision = match native_type {
-                PostgresType::Timestamp(p) => p,
-                PostgresType::Timestamptz(p) => p,
-                PostgresType::Time(p) => p,
-                PostgresType::Timetz(p) => p,
-                _ => None,
-            };
-
-            if let Some(precision) = time_precision {
-                if precision > 6 {
+            match native_type {
+                PostgresType::Timestamp(Some(precision))
+                | PostgresType::Timestamptz(Some(precision))
+                | PostgresType::Time(Some(precision))
+                | PostgresType::Timetz(Some(precision))
+                    if precision > 6 =>
+                {
                     return Err(ConnectorError::new_argument_m_out_of_range_error(
                         "M can range from 0 to 6.",
                         native_type_name,
                         "Postgres",
                     ));
                 }
+                _ => {}
             }
         }
         Ok(())
@@ -214,7 +344,7 @@ impl Connector for PostgresDatamodelConnector {
             INTEGER_TYPE_NAME => PostgresType::Integer,
             BIG_INT_TYPE_NAME => PostgresType::BigInt,
             DECIMAL_TYPE_NAME => PostgresType::Decimal(parse_two_opt_u32(args, DECIMAL_TYPE_NAME)?),
-            NUMERIC_TYPE_NAME => PostgresType::Decimal(parse_two_opt_u32(args, NUMERIC_TYPE_NAME)?),
+            NUMERIC_TYPE_NAME => PostgresType::Numeric(parse_two_opt_u32(args, NUMERIC_TYPE_NAME)?),
             REAL_TYPE_NAME => PostgresType::Real,
             DOUBLE_PRECISION_TYPE_NAME => PostgresType::DoublePrecision,
             SMALL_SERIAL_TYPE_NAME => PostgresType::SmallSerial,
@@ -228,7 +358,7 @@ impl Connector for PostgresDatamodelConnector {
             TIMESTAMP_TZ_TYPE_NAME => PostgresType::Timestamptz(parse_one_opt_u32(args, TIMESTAMP_TZ_TYPE_NAME)?),
             DATE_TYPE_NAME => PostgresType::Date,
             TIME_TYPE_NAME => PostgresType::Time(parse_one_opt_u32(args, TIME_TYPE_NAME)?),
-            TIME_TZ_TYPE_NAME => PostgresType::Time(parse_one_opt_u32(args, TIME_TZ_TYPE_NAME)?),
+            TIME_TZ_TYPE_NAME => PostgresType::Timetz(parse_one_opt_u32(args, TIME_TZ_TYPE_NAME)?),
             BOOLEAN_TYPE_NAME => PostgresType::Boolean,
             BIT_TYPE_NAME => PostgresType::Bit(parse_one_opt_u32(args, BIT_TYPE_NAME)?),
             VAR_BIT_TYPE_NAME => PostgresType::VarBit(parse_one_opt_u32(args, VAR_BIT_TYPE_NAME)?),
@@ -236,6 +366,11 @@ impl Connector for PostgresDatamodelConnector {
             XML_TYPE_NAME => PostgresType::Xml,
             JSON_TYPE_NAME => PostgresType::JSON,
             JSON_B_TYPE_NAME => PostgresType::JSONB,
+            CITEXT_TYPE_NAME => PostgresType::Citext,
+            INET_TYPE_NAME => PostgresType::Inet,
+            OID_TYPE_NAME => PostgresType::Oid,
+            MONEY_TYPE_NAME => PostgresType::Money,
+            INTERVAL_TYPE_NAME => PostgresType::Interval,
             _ => unreachable!("This code is unreachable as the core must guarantee to just call with known names."),
         };
 
@@ -253,7 +388,7 @@ impl Connector for PostgresDatamodelConnector {
             PostgresType::Real => (REAL_TYPE_NAME, vec![]),
             PostgresType::DoublePrecision => (DOUBLE_PRECISION_TYPE_NAME, vec![]),
             PostgresType::SmallSerial => (SMALL_SERIAL_TYPE_NAME, vec![]),
-            PostgresType::Serial => (SMALL_SERIAL_TYPE_NAME, vec![]),
+            PostgresType::Serial => (SERIAL_TYPE_NAME, vec![]),
             PostgresType::BigSerial => (BIG_SERIAL_TYPE_NAME, vec![]),
             PostgresType::VarChar(x) => (VARCHAR_TYPE_NAME, arg_vec_from_opt(x)),
             PostgresType::Char(x) => (CHAR_TYPE_NAME, arg_vec_from_opt(x)),
@@ -271,6 +406,11 @@ impl Connector for PostgresDatamodelConnector {
             PostgresType::Xml => (XML_TYPE_NAME, vec![]),
             PostgresType::JSON => (JSON_TYPE_NAME, vec![]),
             PostgresType::JSONB => (JSON_B_TYPE_NAME, vec![]),
+            PostgresType::Citext => (CITEXT_TYPE_NAME, vec![]),
+            PostgresType::Inet => (INET_TYPE_NAME, vec![]),
+            PostgresType::Oid => (OID_TYPE_NAME, vec![]),
+            PostgresType::Money => (MONEY_TYPE_NAME, vec![]),
+            PostgresType::Interval => (INTERVAL_TYPE_NAME, vec![]),
         };
 
         if let Some(constructor) = self.find_native_type_constructor(constructor_name) {
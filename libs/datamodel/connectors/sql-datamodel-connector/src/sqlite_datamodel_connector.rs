@@ -1,9 +1,82 @@
 use datamodel_connector::connector_error::{ConnectorError, ErrorKind};
+use datamodel_connector::helper::{arg_vec_from_opt, args_vec_from_opt, parse_one_opt_u32, parse_two_opt_u32};
 use datamodel_connector::{Connector, ConnectorCapability};
-use dml::field::Field;
+use dml::field::{Field, FieldType};
 use dml::model::Model;
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::scalars::ScalarType;
+use native_types::SqliteType;
+
+const INTEGER_TYPE_NAME: &str = "Integer";
+const REAL_TYPE_NAME: &str = "Real";
+const TEXT_TYPE_NAME: &str = "Text";
+const BLOB_TYPE_NAME: &str = "Blob";
+const NUMERIC_TYPE_NAME: &str = "Numeric";
+const DECIMAL_TYPE_NAME: &str = "Decimal";
+const VARCHAR_TYPE_NAME: &str = "VarChar";
+const BOOLEAN_TYPE_NAME: &str = "Boolean";
+const DATE_TIME_TYPE_NAME: &str = "DateTime";
+
+/// The reserved keywords of SQLite.
+const RESERVED_WORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "AUTOINCREMENT",
+    "BETWEEN",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COMMIT",
+    "CONSTRAINT",
+    "CREATE",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DELETE",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "ESCAPE",
+    "EXCEPT",
+    "EXISTS",
+    "FOREIGN",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INDEX",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LIMIT",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "ON",
+    "OR",
+    "ORDER",
+    "PRIMARY",
+    "REFERENCES",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+];
 
 pub struct SqliteDatamodelConnector {
     capabilities: Vec<ConnectorCapability>,
@@ -12,8 +85,32 @@ pub struct SqliteDatamodelConnector {
 
 impl SqliteDatamodelConnector {
     pub fn new() -> SqliteDatamodelConnector {
-        let capabilities = vec![ConnectorCapability::RelationFieldsInArbitraryOrder];
-        let constructors: Vec<NativeTypeConstructor> = vec![];
+        let capabilities = vec![
+            ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::NativeUpsert,
+            ConnectorCapability::RelationJoins,
+            ConnectorCapability::BitwiseFilters,
+            ConnectorCapability::WindowFunctions,
+        ];
+
+        let integer = NativeTypeConstructor::without_args(INTEGER_TYPE_NAME, vec![ScalarType::Int, ScalarType::BigInt]);
+        let real = NativeTypeConstructor::without_args(REAL_TYPE_NAME, vec![ScalarType::Float, ScalarType::Decimal]);
+        let text = NativeTypeConstructor::without_args(TEXT_TYPE_NAME, vec![ScalarType::String]);
+        let blob = NativeTypeConstructor::without_args(BLOB_TYPE_NAME, vec![ScalarType::Bytes]);
+        let numeric = NativeTypeConstructor::without_args(
+            NUMERIC_TYPE_NAME,
+            vec![ScalarType::Decimal, ScalarType::Float, ScalarType::Int],
+        );
+        let decimal = NativeTypeConstructor::with_optional_args(
+            DECIMAL_TYPE_NAME,
+            2,
+            vec![ScalarType::Decimal, ScalarType::Float],
+        );
+        let varchar = NativeTypeConstructor::with_optional_args(VARCHAR_TYPE_NAME, 1, vec![ScalarType::String]);
+        let boolean = NativeTypeConstructor::without_args(BOOLEAN_TYPE_NAME, vec![ScalarType::Boolean]);
+        let date_time = NativeTypeConstructor::without_args(DATE_TIME_TYPE_NAME, vec![ScalarType::DateTime]);
+
+        let constructors = vec![integer, real, text, blob, numeric, decimal, varchar, boolean, date_time];
 
         SqliteDatamodelConnector {
             capabilities,
@@ -27,7 +124,33 @@ impl Connector for SqliteDatamodelConnector {
         &self.capabilities
     }
 
-    fn validate_field(&self, _field: &Field) -> Result<(), ConnectorError> {
+    fn reserved_words(&self) -> &[&'static str] {
+        RESERVED_WORDS
+    }
+
+    fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
+        if let FieldType::NativeType(_scalar_type, native_type_instance) = field.field_type() {
+            let native_type_name = native_type_instance.name.as_str();
+            let native_type: SqliteType = native_type_instance.deserialize_native_type();
+
+            match native_type {
+                SqliteType::Decimal(Some((precision, scale))) if scale > precision => {
+                    return Err(ConnectorError::new_scale_larger_than_precision_error(
+                        native_type_name,
+                        "SQLite",
+                    ));
+                }
+                SqliteType::Decimal(Some((0, _))) | SqliteType::VarChar(Some(0)) => {
+                    return Err(ConnectorError::new_argument_m_out_of_range_error(
+                        "M must be positive.",
+                        native_type_name,
+                        "SQLite",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
         Ok(())
     }
 
@@ -39,20 +162,47 @@ impl Connector for SqliteDatamodelConnector {
         &self.constructors
     }
 
-    fn parse_native_type(&self, _name: &str, _args: Vec<String>) -> Result<NativeTypeInstance, ConnectorError> {
-        Err(ConnectorError::from_kind(
-            ErrorKind::ConnectorNotSupportedForNativeTypes {
-                connector_name: "sqlite".to_string(),
-            },
-        ))
+    fn parse_native_type(&self, name: &str, args: Vec<String>) -> Result<NativeTypeInstance, ConnectorError> {
+        let cloned_args = args.clone();
+
+        let native_type = match name {
+            INTEGER_TYPE_NAME => SqliteType::Integer,
+            REAL_TYPE_NAME => SqliteType::Real,
+            TEXT_TYPE_NAME => SqliteType::Text,
+            BLOB_TYPE_NAME => SqliteType::Blob,
+            NUMERIC_TYPE_NAME => SqliteType::Numeric,
+            DECIMAL_TYPE_NAME => SqliteType::Decimal(parse_two_opt_u32(args, DECIMAL_TYPE_NAME)?),
+            VARCHAR_TYPE_NAME => SqliteType::VarChar(parse_one_opt_u32(args, VARCHAR_TYPE_NAME)?),
+            BOOLEAN_TYPE_NAME => SqliteType::Boolean,
+            DATE_TIME_TYPE_NAME => SqliteType::DateTime,
+            _ => unreachable!("This code is unreachable as the core must guarantee to just call with known names."),
+        };
+
+        Ok(NativeTypeInstance::new(name, cloned_args, &native_type))
     }
 
-    fn introspect_native_type(&self, _native_type: serde_json::Value) -> Result<NativeTypeInstance, ConnectorError> {
-        Err(ConnectorError::from_kind(
-            ErrorKind::ConnectorNotSupportedForNativeTypes {
-                connector_name: "sqlite".to_string(),
-            },
-        ))
+    fn introspect_native_type(&self, native_type: serde_json::Value) -> Result<NativeTypeInstance, ConnectorError> {
+        let native_type: SqliteType = serde_json::from_value(native_type).unwrap();
+        let (constructor_name, args) = match native_type {
+            SqliteType::Integer => (INTEGER_TYPE_NAME, vec![]),
+            SqliteType::Real => (REAL_TYPE_NAME, vec![]),
+            SqliteType::Text => (TEXT_TYPE_NAME, vec![]),
+            SqliteType::Blob => (BLOB_TYPE_NAME, vec![]),
+            SqliteType::Numeric => (NUMERIC_TYPE_NAME, vec![]),
+            SqliteType::Decimal(x) => (DECIMAL_TYPE_NAME, args_vec_from_opt(x)),
+            SqliteType::VarChar(x) => (VARCHAR_TYPE_NAME, arg_vec_from_opt(x)),
+            SqliteType::Boolean => (BOOLEAN_TYPE_NAME, vec![]),
+            SqliteType::DateTime => (DATE_TIME_TYPE_NAME, vec![]),
+        };
+
+        if let Some(constructor) = self.find_native_type_constructor(constructor_name) {
+            Ok(NativeTypeInstance::new(constructor.name.as_str(), args, &native_type))
+        } else {
+            Err(ConnectorError::from_kind(ErrorKind::NativeTypeNameUnknown {
+                native_type: constructor_name.parse().unwrap(),
+                connector_name: "SQLite".parse().unwrap(),
+            }))
+        }
     }
 }
 
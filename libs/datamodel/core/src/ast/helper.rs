@@ -14,3 +14,23 @@ pub fn get_sort_index_of_attribute(is_field_attribute: bool, attribute_name: &st
         usize::MAX
     }
 }
+
+pub fn get_sort_index_of_attribute_argument(argument_name: &str) -> usize {
+    // unnamed arguments come first, named arguments in the order they are rendered in by the attributes in libs/datamodel/core/src/transform/attributes
+    let correct_order = [
+        "",
+        "name",
+        "fields",
+        "references",
+        "onDelete",
+        "clustered",
+        "type",
+        "deferrable",
+        "map",
+    ];
+
+    correct_order
+        .iter()
+        .position(|p| *p == argument_name)
+        .unwrap_or(usize::MAX)
+}
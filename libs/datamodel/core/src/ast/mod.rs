@@ -6,6 +6,7 @@
 mod argument;
 mod attribute;
 mod comment;
+mod composite_type;
 mod r#enum;
 mod expression;
 mod field;
@@ -25,6 +26,7 @@ pub mod renderer;
 pub use argument::Argument;
 pub use attribute::Attribute;
 pub use comment::Comment;
+pub use composite_type::CompositeType;
 pub use expression::Expression;
 pub use field::{Field, FieldArity};
 pub use generator_config::GeneratorConfig;
@@ -48,7 +50,7 @@ pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdent
 /// Schema = Datamodel + Generators + Datasources
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAst {
-    /// All models, enums, datasources, generators or type aliases
+    /// All models, enums, composite types, datasources, generators or type aliases
     pub tops: Vec<Top>,
 }
 
@@ -90,6 +92,10 @@ impl SchemaAst {
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&CompositeType> {
+        self.composite_types().into_iter().find(|t| t.name.name == type_name)
+    }
+
     pub fn find_enum(&self, enum_name: &str) -> Option<&Enum> {
         self.enums().into_iter().find(|e| e.name.name == enum_name)
     }
@@ -134,6 +140,16 @@ impl SchemaAst {
             .collect()
     }
 
+    pub fn composite_types(&self) -> Vec<&CompositeType> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::CompositeType(x) => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn models(&self) -> Vec<&Model> {
         self.tops
             .iter()
@@ -1,6 +1,7 @@
 mod helpers;
 mod parse_attribute;
 mod parse_comments;
+mod parse_composite_type;
 mod parse_enum;
 mod parse_expression;
 mod parse_field;
@@ -0,0 +1,47 @@
+use super::{
+    helpers::{parsing_catch_all, ToIdentifier, Token, TokenExtensions},
+    parse_comments::*,
+    parse_field::parse_field,
+    Rule,
+};
+use crate::ast::*;
+use crate::diagnostics::{DatamodelError, Diagnostics};
+
+pub fn parse_composite_type(token: &Token) -> Result<CompositeType, Diagnostics> {
+    let mut errors = Diagnostics::new();
+    let mut name: Option<Identifier> = None;
+    let mut fields: Vec<Field> = vec![];
+    let mut comment: Option<Comment> = None;
+
+    for current in token.relevant_children() {
+        match current.as_rule() {
+            Rule::TYPE_KEYWORD => {}
+            Rule::non_empty_identifier => name = Some(current.to_id()),
+            Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current) {
+                Ok(field) => fields.push(field),
+                Err(err) => errors.push_error(err),
+            },
+            Rule::comment_block => comment = Some(parse_comment_block(&current)),
+            Rule::BLOCK_LEVEL_CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
+                "This line is not a valid field definition.",
+                Span::from_pest(current.as_span()),
+            )),
+            _ => parsing_catch_all(&current, "composite type"),
+        }
+    }
+
+    errors.to_result()?;
+
+    match name {
+        Some(name) => Ok(CompositeType {
+            name,
+            fields,
+            documentation: comment,
+            span: Span::from_pest(token.as_span()),
+        }),
+        _ => panic!(
+            "Encountered impossible composite type declaration during parsing: {:?}",
+            token.as_str()
+        ),
+    }
+}
@@ -2,6 +2,7 @@ use pest::Parser;
 
 use super::{
     helpers::{parsing_catch_all, TokenExtensions},
+    parse_composite_type::parse_composite_type,
     parse_enum::parse_enum,
     parse_model::parse_model,
     parse_source_and_generator::{parse_generator, parse_source},
@@ -26,6 +27,10 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, Diagnostics> {
                         Ok(model) => top_level_definitions.push(Top::Model(model)),
                         Err(mut err) => errors.append(&mut err),
                     },
+                    Rule::composite_type_declaration => match parse_composite_type(&current) {
+                        Ok(composite_type) => top_level_definitions.push(Top::CompositeType(composite_type)),
+                        Err(mut err) => errors.append(&mut err),
+                    },
                     Rule::enum_declaration => match parse_enum(&current) {
                         Ok(enm) => top_level_definitions.push(Top::Enum(enm)),
                         Err(mut err) => errors.append(&mut err),
@@ -87,6 +92,7 @@ fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
 fn rule_to_string(rule: Rule) -> &'static str {
     match rule {
         Rule::model_declaration => "model declaration",
+        Rule::composite_type_declaration => "composite type declaration",
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
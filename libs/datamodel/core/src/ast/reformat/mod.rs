@@ -1,5 +1,6 @@
 mod helpers;
 mod reformatter;
 
+pub use reformatter::FormatOptions;
 pub use reformatter::MissingField;
 pub use reformatter::Reformatter;
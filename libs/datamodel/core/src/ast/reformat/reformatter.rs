@@ -4,11 +4,30 @@ use pest::Parser;
 // We have to use RefCell as rust cannot
 // do multiple mutable borrows inside a match statement.
 use super::helpers::*;
-use crate::ast::helper::get_sort_index_of_attribute;
+use crate::ast::helper::{get_sort_index_of_attribute, get_sort_index_of_attribute_argument};
 use crate::common::WritableString;
 use crate::diagnostics::ValidatedMissingFields;
 use pest::iterators::Pair;
 
+/// How the reformatter lays out a schema.
+#[derive(Debug, Clone, Copy)]
+pub struct FormatOptions {
+    /// The number of spaces per indentation level.
+    pub indent_width: usize,
+    /// Whether the names, types and attributes of the fields in a block are aligned in columns.
+    /// Otherwise they are separated by a single space.
+    pub align_columns: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            indent_width: 2,
+            align_columns: true,
+        }
+    }
+}
+
 pub struct Reformatter<'a> {
     input: &'a str,
     missing_fields: Result<ValidatedMissingFields, crate::diagnostics::Diagnostics>,
@@ -97,18 +116,32 @@ impl<'a> Reformatter<'a> {
     }
 
     pub fn reformat_to(&self, output: &mut dyn std::io::Write, ident_width: usize) {
-        let result = self.reformat_internal(ident_width);
+        let options = FormatOptions {
+            indent_width: ident_width,
+            ..Default::default()
+        };
+
+        self.reformat_to_with_options(output, &options)
+    }
+
+    pub fn reformat_to_with_options(&self, output: &mut dyn std::io::Write, options: &FormatOptions) {
+        let result = self.reformat_internal(options);
         write!(output, "{}", result).unwrap()
     }
 
     pub fn reformat_to_string(&self) -> String {
-        self.reformat_internal(2)
+        self.reformat_internal(&FormatOptions::default())
+    }
+
+    pub fn reformat_to_string_with_options(&self, options: &FormatOptions) -> String {
+        self.reformat_internal(options)
     }
 
-    fn reformat_internal(&self, ident_width: usize) -> String {
+    fn reformat_internal(&self, options: &FormatOptions) -> String {
         let mut ast = PrismaDatamodelParser::parse(Rule::schema, self.input).unwrap(); // TODO: Handle error.
         let mut target_string = WritableString::new();
-        let mut renderer = Renderer::new(&mut target_string, ident_width);
+        let mut renderer =
+            Renderer::new(&mut target_string, options.indent_width).with_column_alignment(options.align_columns);
         self.reformat_top(&mut renderer, &ast.next().unwrap());
         let result = target_string.into();
         // all schemas must end with a newline
@@ -165,6 +198,7 @@ impl<'a> Reformatter<'a> {
                     }
                 }
                 Rule::model_declaration => self.reformat_model(target, &current),
+                Rule::composite_type_declaration => self.reformat_composite_type(target, &current),
                 Rule::enum_declaration => self.reformat_enum(target, &current),
                 Rule::source_block => self.reformat_datasource(target, &current),
                 Rule::generator_block => self.reformat_generator(target, &current),
@@ -245,6 +279,7 @@ impl<'a> Reformatter<'a> {
                         // model level attributes reset the table. -> .render() does that
                         table.render(renderer);
                         Self::reformat_attribute(renderer, &token, "@@");
+                        Self::reformat_end_of_line_comment(renderer, &token);
                     }
                     Rule::field_declaration => self.reformat_field(table, &token, model_name),
                     _ => Self::reformat_generic_token(table, &token),
@@ -263,6 +298,19 @@ impl<'a> Reformatter<'a> {
         );
     }
 
+    fn reformat_composite_type(&self, target: &mut Renderer, token: &Token) {
+        self.reformat_block_element(
+            "type",
+            target,
+            token,
+            Box::new(|table, _, token, type_name| match token.as_rule() {
+                Rule::TYPE_KEYWORD => {}
+                Rule::field_declaration => self.reformat_field(table, &token, type_name),
+                _ => Self::reformat_generic_token(table, &token),
+            }),
+        );
+    }
+
     fn reformat_block_element(
         &self,
         block_type: &'static str,
@@ -381,6 +429,7 @@ impl<'a> Reformatter<'a> {
                     Rule::block_level_attribute => {
                         table.render(target);
                         Self::reformat_attribute(target, token, "@@");
+                        Self::reformat_end_of_line_comment(target, token);
                         table.end_line();
                     }
                     Rule::enum_value_declaration => Self::reformat_enum_entry(table, token),
@@ -548,6 +597,16 @@ impl<'a> Reformatter<'a> {
         }
     }
 
+    /// Block attributes are rendered outside of the table, so their comments cannot be a suffix
+    /// of a row like the ones of fields.
+    fn reformat_end_of_line_comment(target: &mut dyn LineWriteable, token: &Token) {
+        for current in token.clone().into_inner() {
+            if let Rule::doc_comment | Rule::comment = current.as_rule() {
+                target.write(&format!(" {}", current.as_str().trim()));
+            }
+        }
+    }
+
     fn unpack_token_to_find_matching_rule(token: Token, rule: Rule) -> Token {
         if token.as_rule() == rule {
             token
@@ -564,23 +623,11 @@ impl<'a> Reformatter<'a> {
 
     fn reformat_attribute_args(target: &mut dyn LineWriteable, token: &Token) {
         let mut builder = StringBuilder::new();
+        let mut arguments = Vec::new();
 
         for current in token.clone().into_inner() {
             match current.as_rule() {
-                // This is a named arg.
-                Rule::argument => {
-                    if !builder.line_empty() {
-                        builder.write(", ");
-                    }
-                    Self::reformat_attribute_arg(&mut builder, &current);
-                }
-                // This is a an unnamed arg.
-                Rule::argument_value => {
-                    if !builder.line_empty() {
-                        builder.write(", ");
-                    }
-                    Self::reformat_arg_value(&mut builder, &current);
-                }
+                Rule::argument | Rule::argument_value => arguments.push(current),
                 Rule::doc_comment | Rule::doc_comment_and_new_line => {
                     panic!("Comments inside attribute argument list not supported yet.")
                 }
@@ -588,6 +635,22 @@ impl<'a> Reformatter<'a> {
             };
         }
 
+        // sort arguments, so the output does not depend on the order they were written in
+        arguments.sort_by_key(|argument| get_sort_index_of_attribute_argument(Self::get_argument_name(argument)));
+
+        for current in arguments {
+            if !builder.line_empty() {
+                builder.write(", ");
+            }
+
+            match current.as_rule() {
+                // This is a named arg.
+                Rule::argument => Self::reformat_attribute_arg(&mut builder, &current),
+                // This is a an unnamed arg.
+                _ => Self::reformat_arg_value(&mut builder, &current),
+            }
+        }
+
         if !builder.line_empty() {
             target.write("(");
             target.write(&builder.to_string());
@@ -595,6 +658,15 @@ impl<'a> Reformatter<'a> {
         }
     }
 
+    fn get_argument_name<'i>(token: &Token<'i>) -> &'i str {
+        token
+            .clone()
+            .into_inner()
+            .find(|current| current.as_rule() == Rule::argument_name)
+            .map(|current| current.as_str())
+            .unwrap_or("")
+    }
+
     fn reformat_attribute_arg(target: &mut dyn LineWriteable, token: &Token) {
         for current in token.clone().into_inner() {
             match current.as_rule() {
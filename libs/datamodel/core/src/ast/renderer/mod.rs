@@ -22,6 +22,7 @@ pub struct Renderer<'a> {
     is_new: bool,
     maybe_new_line: usize,
     indent_width: usize,
+    align_columns: bool,
 }
 
 impl<'a> Renderer<'a> {
@@ -33,9 +34,20 @@ impl<'a> Renderer<'a> {
             new_line: 0,
             maybe_new_line: 0,
             is_new: true,
+            align_columns: true,
         }
     }
 
+    /// Whether tables, like the fields of a model, are rendered with their columns aligned.
+    pub fn with_column_alignment(mut self, align_columns: bool) -> Self {
+        self.align_columns = align_columns;
+        self
+    }
+
+    pub(crate) fn aligns_columns(&self) -> bool {
+        self.align_columns
+    }
+
     pub fn render(&mut self, datamodel: &ast::SchemaAst) {
         let mut type_renderer: Option<TableFormat> = None;
 
@@ -67,6 +79,7 @@ impl<'a> Renderer<'a> {
 
                     match other {
                         ast::Top::Model(model) => self.render_model(model),
+                        ast::Top::CompositeType(composite_type) => self.render_composite_type(composite_type),
                         ast::Top::Enum(enm) => self.render_enum(enm),
                         ast::Top::Source(source) => self.render_source_block(source),
                         ast::Top::Generator(generator) => self.render_generator_block(generator),
@@ -204,6 +217,28 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    fn render_composite_type(&mut self, composite_type: &ast::CompositeType) {
+        Self::render_documentation(self, composite_type);
+
+        self.write("type ");
+        self.write(&composite_type.name.name);
+        self.write(" {");
+        self.end_line();
+        self.indent_up();
+
+        let mut field_formatter = TableFormat::new();
+
+        for field in &composite_type.fields {
+            Self::render_field(&mut field_formatter, &field, false);
+        }
+
+        field_formatter.render(self);
+
+        self.indent_down();
+        self.write("}");
+        self.end_line();
+    }
+
     fn sort_attributes(mut attributes: Vec<Attribute>, is_field_attribute: bool) -> Vec<Attribute> {
         // sort attributes
         attributes.sort_by(|a, b| {
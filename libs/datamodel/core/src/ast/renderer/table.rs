@@ -1,4 +1,5 @@
 use super::LineWriteable;
+use super::Renderer;
 use super::StringBuilder;
 use std::cmp::max;
 
@@ -122,7 +123,7 @@ impl TableFormat {
         self.row += 1;
     }
 
-    pub fn render(&mut self, target: &mut dyn LineWriteable) {
+    pub fn render(&mut self, target: &mut Renderer<'_>) {
         // First, measure cols
         let mut max_number_of_columns = 0;
 
@@ -149,8 +150,10 @@ impl TableFormat {
                     for (i, col) in row.iter().enumerate() {
                         let spacing = if i == row.len() - 1 {
                             0 // Do not space last column.
-                        } else {
+                        } else if target.aligns_columns() {
                             max_widths_for_each_column[i] - col.len() + COLUMN_SPACING
+                        } else {
+                            COLUMN_SPACING
                         };
                         target.write(&format!("{}{}", col, " ".repeat(spacing)));
                     }
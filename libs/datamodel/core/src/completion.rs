@@ -0,0 +1,377 @@
+//! Autocompletion metadata for editors.
+//!
+//! Given a schema string and a cursor offset, `completions` returns the attributes, native types and
+//! attribute arguments that are valid at that position. The schema does not need to parse, since it
+//! is usually incomplete while it is being edited: the context is determined from the text before
+//! the cursor.
+
+use crate::transform::attributes::{AllAttributes, AttributeListValidator};
+use crate::{dml::ScalarType, Datasource, NativeTypeConstructor};
+use serde::Serialize;
+
+/// An argument accepted by an attribute.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArgumentSignature {
+    pub name: &'static str,
+    /// Whether the argument can be given without its name, as the first argument.
+    pub is_default: bool,
+    pub is_optional: bool,
+}
+
+impl ArgumentSignature {
+    /// An argument that can be given without its name.
+    pub fn default_arg(name: &'static str) -> Self {
+        ArgumentSignature {
+            name,
+            is_default: true,
+            is_optional: false,
+        }
+    }
+
+    /// An argument that must be given by name.
+    pub fn named(name: &'static str) -> Self {
+        ArgumentSignature {
+            name,
+            is_default: false,
+            is_optional: false,
+        }
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.is_optional = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionItemKind {
+    /// A field or enum value attribute, like `@id`.
+    Attribute,
+    /// A model or enum attribute, like `@@index`.
+    BlockAttribute,
+    /// A native type attribute of the active connector, like `@db.VarChar`.
+    NativeType,
+    /// A named argument of the attribute the cursor is in.
+    Argument,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    /// The text to insert, without the leading `@`, `@@` or the trailing `:`.
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// A human-readable signature, like `@@index(fields, name?)`.
+    pub detail: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<ArgumentSignature>,
+}
+
+/// Returns the completion items that are valid at the given byte offset of the schema.
+pub fn completions(schema: &str, offset: usize) -> Vec<CompletionItem> {
+    let offset = floor_char_boundary(schema, offset.min(schema.len()));
+    let before_cursor = &schema[..offset];
+    let line = line_up_to(schema, offset);
+    let attributes = AllAttributes::new();
+
+    let block = match enclosing_block(before_cursor) {
+        Some(block) => block,
+        None => return Vec::new(),
+    };
+
+    if let Some((attribute_name, arguments)) = open_attribute_arguments(line) {
+        return argument_completions(&attributes, block, line, attribute_name, arguments);
+    }
+
+    let token = match line.rsplit(char::is_whitespace).next() {
+        Some(token) if token.starts_with('@') => token,
+        _ => return Vec::new(),
+    };
+
+    match (block, token.strip_prefix("@@")) {
+        (Block::Model, Some(_)) => attribute_completions(&attributes.model, "@@", CompletionItemKind::BlockAttribute),
+        (Block::Enum, Some(_)) => attribute_completions(&attributes.enm, "@@", CompletionItemKind::BlockAttribute),
+        (Block::Model, None) => {
+            let datasource = load_datasource(schema);
+            let datasource = datasource.as_ref();
+            let field_type = field_type(line);
+
+            match (datasource, token[1..].split_once('.')) {
+                (Some(datasource), Some((source_name, _))) if source_name == datasource.name => {
+                    native_type_completions(datasource, field_type, false)
+                }
+                (_, Some(_)) => Vec::new(),
+                (datasource, None) => {
+                    let mut items = attribute_completions(&attributes.field, "@", CompletionItemKind::Attribute);
+                    items.extend(
+                        datasource
+                            .map(|ds| native_type_completions(ds, field_type, true))
+                            .unwrap_or_default(),
+                    );
+                    items
+                }
+            }
+        }
+        (Block::Enum, None) => attribute_completions(&attributes.enm_value, "@", CompletionItemKind::Attribute),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Block {
+    Model,
+    Enum,
+}
+
+/// The model or enum block the end of the text is in, if any.
+fn enclosing_block(text: &str) -> Option<Block> {
+    let mut depth = 0usize;
+    let mut block = None;
+
+    for (idx, c) in text.char_indices() {
+        match c {
+            '{' => {
+                if depth == 0 {
+                    block = match line_up_to(text, idx).split_whitespace().next() {
+                        Some("model") | Some("type") => Some(Block::Model),
+                        Some("enum") => Some(Block::Enum),
+                        _ => None,
+                    };
+                }
+
+                depth += 1;
+            }
+            '}' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+
+    if depth == 1 {
+        block
+    } else {
+        None
+    }
+}
+
+/// The text of the line the given offset is in, up to the offset.
+fn line_up_to(text: &str, offset: usize) -> &str {
+    let start = text[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+
+    &text[start..offset]
+}
+
+/// If the line ends inside the argument list of an attribute, the attribute name (without the
+/// leading `@` or `@@`) and the arguments typed so far.
+fn open_attribute_arguments(line: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+
+    for (idx, c) in line.char_indices().rev() {
+        match c {
+            ')' | ']' => depth += 1,
+            '[' if depth > 0 => depth -= 1,
+            '(' if depth > 0 => depth -= 1,
+            '(' => {
+                let before = &line[..idx];
+                let name_start = before
+                    .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                    .map_or(0, |pos| pos + 1);
+
+                // Anything that is not an attribute is a function call, like `autoincrement(`.
+                if before[..name_start].ends_with('@') && name_start < idx {
+                    return Some((&before[name_start..], &line[idx + 1..]));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// The type of the field on the given line, as far as it has been typed.
+fn field_type(line: &str) -> Option<ScalarType> {
+    let type_name = line.split_whitespace().nth(1)?;
+
+    type_name
+        .trim_end_matches(|c: char| c == '?' || c == '[' || c == ']')
+        .parse()
+        .ok()
+}
+
+/// The datasource of the schema, if there is exactly one. Only the datasource blocks are parsed, so
+/// that errors in the rest of the schema do not hide the native types.
+fn load_datasource(schema: &str) -> Option<Datasource> {
+    let sources: Vec<&str> = top_level_blocks(schema)
+        .filter(|block| block.split_whitespace().next() == Some("datasource"))
+        .collect();
+
+    let mut config = crate::parse_configuration_and_ignore_datasource_urls(&sources.join("\n")).ok()?;
+
+    if config.subject.datasources.len() == 1 {
+        config.subject.datasources.pop()
+    } else {
+        None
+    }
+}
+
+/// The complete top level blocks of the schema, starting at their keyword.
+fn top_level_blocks(schema: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    let mut block_start = 0;
+    let mut blocks = Vec::new();
+
+    for (idx, c) in schema.char_indices() {
+        match c {
+            '{' => {
+                if depth == 0 {
+                    block_start = idx - line_up_to(schema, idx).len();
+                }
+
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+
+                if depth == 0 {
+                    blocks.push(&schema[block_start..=idx]);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    blocks.into_iter()
+}
+
+fn attribute_completions<T: 'static>(
+    attributes: &AttributeListValidator<T>,
+    prefix: &str,
+    kind: CompletionItemKind,
+) -> Vec<CompletionItem> {
+    attributes
+        .attributes()
+        .map(|attribute| {
+            let arguments = attribute.arguments();
+
+            CompletionItem {
+                label: attribute.attribute_name().to_owned(),
+                kind,
+                detail: render_signature(&format!("{}{}", prefix, attribute.attribute_name()), &arguments),
+                arguments,
+            }
+        })
+        .collect()
+}
+
+fn native_type_completions(
+    datasource: &Datasource,
+    field_type: Option<ScalarType>,
+    with_source_name: bool,
+) -> Vec<CompletionItem> {
+    datasource
+        .active_connector
+        .available_native_type_constructors()
+        .iter()
+        .filter(|constructor| field_type.map_or(true, |tpe| constructor.prisma_types.contains(&tpe)))
+        .map(|constructor| {
+            let label = if with_source_name {
+                format!("{}.{}", datasource.name, constructor.name)
+            } else {
+                constructor.name.clone()
+            };
+
+            CompletionItem {
+                detail: render_native_type_signature(&datasource.name, constructor),
+                label,
+                kind: CompletionItemKind::NativeType,
+                arguments: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+fn argument_completions(
+    attributes: &AllAttributes,
+    block: Block,
+    line: &str,
+    attribute_name: &str,
+    given_arguments: &str,
+) -> Vec<CompletionItem> {
+    let is_block_attribute = line.trim_start().starts_with("@@");
+
+    let signature = match (block, is_block_attribute) {
+        (Block::Model, true) => find_arguments(&attributes.model, attribute_name),
+        (Block::Model, false) => find_arguments(&attributes.field, attribute_name),
+        (Block::Enum, true) => find_arguments(&attributes.enm, attribute_name),
+        (Block::Enum, false) => find_arguments(&attributes.enm_value, attribute_name),
+    };
+
+    let arguments = match signature {
+        Some(arguments) => arguments,
+        None => return Vec::new(),
+    };
+
+    arguments
+        .into_iter()
+        .filter(|argument| !(argument.is_default && has_unnamed_first_argument(given_arguments)))
+        .filter(|argument| !given_arguments.contains(&format!("{}:", argument.name)))
+        .map(|argument| CompletionItem {
+            label: argument.name.to_owned(),
+            kind: CompletionItemKind::Argument,
+            detail: render_argument(&argument),
+            arguments: Vec::new(),
+        })
+        .collect()
+}
+
+fn has_unnamed_first_argument(given_arguments: &str) -> bool {
+    let first = given_arguments.trim_start();
+    let name_end = first.find(|c: char| !(c.is_alphanumeric() || c == '_'));
+
+    match name_end {
+        Some(end) => end == 0 || !first[end..].trim_start().starts_with(':'),
+        None => false,
+    }
+}
+
+fn find_arguments<T: 'static>(attributes: &AttributeListValidator<T>, name: &str) -> Option<Vec<ArgumentSignature>> {
+    attributes
+        .attributes()
+        .find(|attribute| attribute.attribute_name() == name)
+        .map(|attribute| attribute.arguments())
+}
+
+fn render_signature(name: &str, arguments: &[ArgumentSignature]) -> String {
+    if arguments.is_empty() {
+        return name.to_owned();
+    }
+
+    let arguments: Vec<String> = arguments.iter().map(render_argument).collect();
+
+    format!("{}({})", name, arguments.join(", "))
+}
+
+fn render_argument(argument: &ArgumentSignature) -> String {
+    format!("{}{}", argument.name, if argument.is_optional { "?" } else { "" })
+}
+
+fn render_native_type_signature(source_name: &str, constructor: &NativeTypeConstructor) -> String {
+    let required = std::iter::repeat("_").take(constructor._number_of_args);
+    let optional = std::iter::repeat("_?").take(constructor._number_of_optional_args);
+    let arguments: Vec<&str> = required.chain(optional).collect();
+
+    if arguments.is_empty() {
+        format!("@{}.{}", source_name, constructor.name)
+    } else {
+        format!("@{}.{}({})", source_name, constructor.name, arguments.join(", "))
+    }
+}
+
+fn floor_char_boundary(s: &str, mut offset: usize) -> usize {
+    while !s.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
@@ -1,4 +1,7 @@
-use crate::configuration::preview_features::PreviewFeatures;
+use crate::configuration::{
+    preview_features::PreviewFeatures, DateTimeMode, ForeignKeyIndexes, LintRule, ReferentialIntegrity, RetryPolicy,
+    TlsOptions,
+};
 use datamodel_connector::{Connector, ConnectorCapabilities};
 use serde::Serialize;
 
@@ -10,7 +13,25 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// the url used by the migration and introspection engines, if it differs from `url`
+    pub direct_url: Option<StringFromEnvVar>,
     pub documentation: Option<String>,
+    /// how to retry connecting on transient errors
+    pub retry_policy: RetryPolicy,
+    /// the Postgres LISTEN/NOTIFY channels the query engine subscribes to
+    pub listen_channels: Vec<String>,
+    /// how DateTime values are stored in columns without time zone
+    pub date_time_mode: DateTimeMode,
+    /// whether relations are enforced by foreign keys or emulated by the query engine
+    pub referential_integrity: ReferentialIntegrity,
+    /// whether migrations create the indexes backing foreign keys, or leave it to the database
+    pub foreign_key_indexes: ForeignKeyIndexes,
+    /// whether the writes of the query engine are recorded in the audit log table managed by migrations
+    pub audit_log: bool,
+    /// whether `@updatedAt` timestamps are maintained by the database instead of the query engine
+    pub database_timestamps: bool,
+    /// the lint rules checked when validating the schema
+    pub lint_rules: Vec<LintRule>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
     /// the connector of the active provider
@@ -25,7 +46,16 @@ impl std::fmt::Debug for Datasource {
             .field("provider", &self.provider)
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
+            .field("direct_url", &self.direct_url)
             .field("documentation", &self.documentation)
+            .field("retry_policy", &self.retry_policy)
+            .field("listen_channels", &self.listen_channels)
+            .field("date_time_mode", &self.date_time_mode)
+            .field("referential_integrity", &self.referential_integrity)
+            .field("foreign_key_indexes", &self.foreign_key_indexes)
+            .field("audit_log", &self.audit_log)
+            .field("database_timestamps", &self.database_timestamps)
+            .field("lint_rules", &self.lint_rules)
             .field("active_connector", &&"...")
             .finish()
     }
@@ -36,6 +66,19 @@ impl Datasource {
         &self.url
     }
 
+    /// The URL to use for direct database access (migrations, introspection). Falls back to
+    /// `url` when no `directUrl` is specified.
+    pub fn direct_url_or_url(&self) -> &StringFromEnvVar {
+        self.direct_url.as_ref().unwrap_or(&self.url)
+    }
+
+    /// The TLS options of the URL used for direct database access, see `direct_url_or_url`. The
+    /// URLs were already validated when the datasource was loaded, so this only fails for
+    /// datasources that were constructed by hand.
+    pub fn tls_options(&self) -> Result<TlsOptions, String> {
+        TlsOptions::from_connection_string(&self.direct_url_or_url().value)
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
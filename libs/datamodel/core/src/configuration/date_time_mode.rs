@@ -0,0 +1,46 @@
+use serde::Serialize;
+
+/// How the engines interpret DateTime values stored in columns without time zone information,
+/// e.g. Postgres `timestamp` or MySQL `DATETIME`.
+///
+/// Configured with the `dateTimeMode` datasource argument. Columns that store an instant
+/// (Postgres `timestamptz` and `timetz`) are not affected. The results of `queryRaw` are not
+/// converted either, since their columns are not known to be DateTime fields.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DateTimeMode {
+    /// Values are stored in UTC. The default.
+    Utc,
+    /// Values are stored in the local time zone of the host the engine runs on, as configured
+    /// with the `TZ` environment variable on Unix. Engines running in different time zones read
+    /// different instants from the same stored values, so all the clients of the database must
+    /// share the time zone.
+    Local,
+    /// Values are stored with the wall-clock time they were written with, and read back as UTC.
+    Naive,
+}
+
+impl Default for DateTimeMode {
+    fn default() -> Self {
+        Self::Utc
+    }
+}
+
+impl DateTimeMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "utc" => Some(Self::Utc),
+            "local" => Some(Self::Local),
+            "naive" => Some(Self::Naive),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Utc => "utc",
+            Self::Local => "local",
+            Self::Naive => "naive",
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+/// Who creates the indexes backing the foreign keys of relations.
+///
+/// Configured with the `foreignKeyIndexes` datasource argument. Only MySQL creates indexes for
+/// foreign keys implicitly, so the argument is only valid there.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ForeignKeyIndexes {
+    /// The database creates an index for foreign keys without one. The default.
+    Implicit,
+    /// Migrations create an index for foreign keys that are not covered by an index of the schema.
+    Explicit,
+}
+
+impl Default for ForeignKeyIndexes {
+    fn default() -> Self {
+        Self::Implicit
+    }
+}
+
+impl ForeignKeyIndexes {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "implicit" => Some(Self::Implicit),
+            "explicit" => Some(Self::Explicit),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Implicit => "implicit",
+            Self::Explicit => "explicit",
+        }
+    }
+
+    /// Whether migrations create the indexes backing foreign keys.
+    pub fn are_explicit(&self) -> bool {
+        matches!(self, Self::Explicit)
+    }
+}
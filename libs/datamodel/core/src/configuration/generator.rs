@@ -1,6 +1,6 @@
 use crate::configuration::preview_features::PreviewFeatures;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[serde(rename_all = "camelCase")]
 #[derive(Debug, Serialize)]
@@ -12,7 +12,8 @@ pub struct Generator {
     pub binary_targets: Vec<String>,
     #[serde(default = "Vec::new")]
     pub preview_features: Vec<String>,
-    pub config: HashMap<String, String>,
+    /// The custom properties of the generator, sorted by name.
+    pub config: BTreeMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+/// A lint rule checked when validating a schema. Lint findings are warnings, they never make a
+/// schema invalid.
+///
+/// Configured with the `lint` datasource argument, which lists the enabled rules. Only
+/// `reservedWords` is enabled by default.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LintRule {
+    /// Relation scalar fields that are not the first fields of an index, so following the
+    /// relation backwards scans the table.
+    UnindexedForeignKeys,
+    /// Model names that are not PascalCase.
+    ModelNaming,
+    /// Models without an `@updatedAt` field.
+    UpdatedAt,
+    /// Table and column names that are reserved words of the database and not mapped to a
+    /// different name.
+    ReservedWords,
+}
+
+impl LintRule {
+    pub const ALL: &'static [LintRule] = &[
+        Self::UnindexedForeignKeys,
+        Self::ModelNaming,
+        Self::UpdatedAt,
+        Self::ReservedWords,
+    ];
+
+    /// The rules enabled without a `lint` argument.
+    pub fn defaults() -> Vec<LintRule> {
+        vec![Self::ReservedWords]
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|rule| rule.as_str() == s)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnindexedForeignKeys => "unindexedForeignKeys",
+            Self::ModelNaming => "modelNaming",
+            Self::UpdatedAt => "updatedAt",
+            Self::ReservedWords => "reservedWords",
+        }
+    }
+}
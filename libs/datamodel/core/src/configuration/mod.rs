@@ -1,8 +1,20 @@
 mod configuration;
 mod datasource;
+mod date_time_mode;
+mod foreign_key_indexes;
 mod generator;
+mod lint_rule;
+mod referential_integrity;
+mod retry_policy;
+mod tls;
 
 pub use configuration::*;
 pub use datasource::*;
+pub use date_time_mode::*;
+pub use foreign_key_indexes::*;
 pub use generator::*;
+pub use lint_rule::*;
+pub use referential_integrity::*;
+pub use retry_policy::*;
+pub use tls::*;
 pub mod preview_features;
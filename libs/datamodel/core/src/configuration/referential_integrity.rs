@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+/// Who enforces the referential integrity of relations.
+///
+/// Configured with the `referentialIntegrity` datasource argument. Databases that do not allow
+/// foreign keys, e.g. PlanetScale, need the `prisma` mode.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ReferentialIntegrity {
+    /// The database enforces the relations with foreign keys. The default.
+    ForeignKeys,
+    /// No foreign keys are created. The query engine emulates the referential actions.
+    Prisma,
+}
+
+impl Default for ReferentialIntegrity {
+    fn default() -> Self {
+        Self::ForeignKeys
+    }
+}
+
+impl ReferentialIntegrity {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "foreignKeys" => Some(Self::ForeignKeys),
+            "prisma" => Some(Self::Prisma),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ForeignKeys => "foreignKeys",
+            Self::Prisma => "prisma",
+        }
+    }
+
+    /// Whether foreign keys are rendered in migrations.
+    pub fn uses_foreign_keys(&self) -> bool {
+        matches!(self, Self::ForeignKeys)
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use std::{fmt::Display, future::Future, time::Duration};
+
+/// The upper bound for a single backoff delay, whatever the number of attempts.
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// How the engines retry establishing a connection when they encounter a transient error.
+///
+/// Configured with the `connectRetries` and `connectRetryDelay` (in milliseconds) datasource
+/// arguments. The default policy does not retry.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether the policy allows another attempt after `attempt` failed attempts.
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_retries
+    }
+
+    /// The exponential backoff delay before the retry following `attempt` failed attempts, with
+    /// equal jitter: the delay is picked between half the backoff ceiling and the ceiling itself.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(MAX_DELAY)
+            .min(MAX_DELAY);
+
+        let half = ceiling / 2;
+        half + half.mul_f64(rand::random::<f64>())
+    }
+
+    /// Run `operation` until it succeeds, fails with an error that `classify_transient` does not
+    /// recognize as transient, or the policy runs out of retries. `classify_transient` returns a
+    /// description of the transient error for the logs. The backoff delays are waited for with
+    /// `sleep`, so the policy does not depend on an async runtime.
+    pub async fn retry<T, E, Op, OpFut, Sleep, SleepFut>(
+        &self,
+        mut operation: Op,
+        classify_transient: impl Fn(&E) -> Option<&'static str>,
+        sleep: Sleep,
+    ) -> Result<T, E>
+    where
+        E: Display,
+        Op: FnMut() -> OpFut,
+        OpFut: Future<Output = Result<T, E>>,
+        Sleep: Fn(Duration) -> SleepFut,
+        SleepFut: Future<Output = ()>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            let err = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+
+            match classify_transient(&err) {
+                Some(transient_error) if self.should_retry(attempt) => {
+                    let delay = self.delay_for_attempt(attempt);
+                    attempt += 1;
+
+                    tracing::warn!(
+                        attempt,
+                        max_retries = self.max_retries,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %err,
+                        "Transient error ({}) while connecting to the database. Retrying.",
+                        transient_error,
+                    );
+
+                    sleep(delay).await;
+                }
+                _ => return Err(err),
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use serde::Serialize;
+use std::path::Path;
+
+const SSL_MODE_PARAM: &str = "sslmode";
+const SSL_CERT_PARAM: &str = "sslcert";
+const SSL_IDENTITY_PARAM: &str = "sslidentity";
+const SSL_PASSWORD_PARAM: &str = "sslpassword";
+const SSL_ACCEPT_PARAM: &str = "sslaccept";
+const SSL_SERVER_NAME_PARAM: &str = "sslservername";
+
+/// The `sslmode` connection string parameter (Postgres only).
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum SslMode {
+    Disable,
+    Prefer,
+    Require,
+}
+
+/// The `sslaccept` connection string parameter.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum SslAcceptMode {
+    /// The server certificate must be valid and signed by a trusted root certificate.
+    Strict,
+    /// Any server certificate is accepted. Only recommended for development.
+    AcceptInvalidCerts,
+}
+
+/// The TLS options of a connection string, as understood by the engines.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TlsOptions {
+    pub mode: Option<SslMode>,
+    /// Path to the root certificate (CA) the server certificate is pinned to.
+    pub root_cert_path: Option<String>,
+    /// Path to the PKCS12 bundle containing the client certificate and key.
+    pub client_identity_path: Option<String>,
+    #[serde(skip_serializing)]
+    pub client_identity_password: Option<String>,
+    pub accept: Option<SslAcceptMode>,
+}
+
+impl TlsOptions {
+    /// Parses the TLS related parameters from the query string of a connection string. Other
+    /// parameters are ignored. The returned error is meant to be shown to the user.
+    pub fn from_connection_string(connection_string: &str) -> Result<Self, String> {
+        let query = match connection_string.splitn(2, '?').nth(1) {
+            Some(query) => query,
+            None => return Ok(Self::default()),
+        };
+
+        let mut options = Self::default();
+
+        for (key, value) in query.split('&').filter_map(|pair| {
+            let mut parts = pair.splitn(2, '=');
+            parts.next().map(|key| (key, parts.next().unwrap_or("")))
+        }) {
+            match key {
+                SSL_MODE_PARAM => {
+                    let mode = match value {
+                        "disable" => SslMode::Disable,
+                        "prefer" => SslMode::Prefer,
+                        "require" => SslMode::Require,
+                        _ => {
+                            return Err(invalid_value_error(
+                                SSL_MODE_PARAM,
+                                value,
+                                &["disable", "prefer", "require"],
+                            ))
+                        }
+                    };
+
+                    options.mode = Some(mode);
+                }
+                SSL_ACCEPT_PARAM => {
+                    let accept = match value {
+                        "strict" => SslAcceptMode::Strict,
+                        "accept_invalid_certs" => SslAcceptMode::AcceptInvalidCerts,
+                        _ => {
+                            return Err(invalid_value_error(
+                                SSL_ACCEPT_PARAM,
+                                value,
+                                &["strict", "accept_invalid_certs"],
+                            ))
+                        }
+                    };
+
+                    options.accept = Some(accept);
+                }
+                SSL_CERT_PARAM => options.root_cert_path = Some(non_empty_path(SSL_CERT_PARAM, value)?),
+                SSL_IDENTITY_PARAM => options.client_identity_path = Some(non_empty_path(SSL_IDENTITY_PARAM, value)?),
+                SSL_PASSWORD_PARAM => options.client_identity_password = Some(value.to_owned()),
+                // The database connections of the engines always verify the server certificate
+                // against the host of the connection string.
+                SSL_SERVER_NAME_PARAM => {
+                    return Err(format!(
+                        "The `{}` parameter is not supported, the server certificate is verified against the host of the URL.",
+                        SSL_SERVER_NAME_PARAM
+                    ))
+                }
+                _ => (),
+            }
+        }
+
+        if options.client_identity_password.is_some() && options.client_identity_path.is_none() {
+            return Err(format!(
+                "The `{}` parameter requires a client identity to be provided with the `{}` parameter.",
+                SSL_PASSWORD_PARAM, SSL_IDENTITY_PARAM
+            ));
+        }
+
+        if options.mode == Some(SslMode::Disable) && options.has_certificates() {
+            return Err(format!(
+                "TLS certificates were provided, but TLS is disabled with `{}=disable`.",
+                SSL_MODE_PARAM
+            ));
+        }
+
+        Ok(options)
+    }
+
+    /// Resolves the relative certificate paths from `directory`, the directory of the schema file.
+    pub fn relative_to(mut self, directory: &Path) -> Self {
+        let resolve = |path: &mut Option<String>| {
+            if let Some(path) = path {
+                if Path::new(path.as_str()).is_relative() {
+                    *path = directory.join(path.as_str()).to_string_lossy().into_owned();
+                }
+            }
+        };
+
+        resolve(&mut self.root_cert_path);
+        resolve(&mut self.client_identity_path);
+
+        self
+    }
+
+    /// Whether a root certificate or a client identity is configured.
+    pub fn has_certificates(&self) -> bool {
+        self.root_cert_path.is_some() || self.client_identity_path.is_some()
+    }
+}
+
+fn non_empty_path(param: &str, value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        Err(format!("The `{}` parameter must be a path to a file.", param))
+    } else {
+        Ok(value.to_owned())
+    }
+}
+
+fn invalid_value_error(param: &str, value: &str, expected: &[&str]) -> String {
+    format!(
+        "Invalid value `{}` for the `{}` parameter. Expected one of: {}.",
+        value,
+        param,
+        expected.join(", ")
+    )
+}
@@ -401,6 +401,72 @@ impl DatamodelError {
       DatamodelError::PreviewFeatureNotKnownError {span, ..} => *span,
     }
   }
+  /// A stable code identifying the kind of error, e.g. for editors to link to documentation.
+  /// Codes are never reused or renumbered, new variants get the next free code.
+  pub fn code(&self) -> &'static str {
+    match self {
+      DatamodelError::ArgumentNotFound { .. } => "E001",
+      DatamodelError::ArgumentCountMissmatch { .. } => "E002",
+      DatamodelError::AttributeArgumentNotFound { .. } => "E003",
+      DatamodelError::SourceArgumentNotFound { .. } => "E004",
+      DatamodelError::GeneratorArgumentNotFound { .. } => "E005",
+      DatamodelError::AttributeValidationError { .. } => "E006",
+      DatamodelError::DuplicateAttributeError { .. } => "E007",
+      DatamodelError::ReservedScalarTypeError { .. } => "E008",
+      DatamodelError::DuplicateTopError { .. } => "E009",
+      DatamodelError::DuplicateConfigKeyError { .. } => "E010",
+      DatamodelError::DuplicateDefaultArgumentError { .. } => "E011",
+      DatamodelError::DuplicateArgumentError { .. } => "E012",
+      DatamodelError::UnusedArgumentError { .. } => "E013",
+      DatamodelError::DuplicateFieldError { .. } => "E014",
+      DatamodelError::ScalarListFieldsAreNotSupported { .. } => "E015",
+      DatamodelError::MultipleIndexesWithSameNameAreNotSupported { .. } => "E016",
+      DatamodelError::DuplicateEnumValueError { .. } => "E017",
+      DatamodelError::AttributeNotKnownError { .. } => "E018",
+      DatamodelError::FunctionNotKnownError { .. } => "E019",
+      DatamodelError::DatasourceProviderNotKnownError { .. } => "E020",
+      DatamodelError::PreviewFeatureNotKnownError { .. } => "E021",
+      DatamodelError::LiteralParseError { .. } => "E022",
+      DatamodelError::TypeNotFoundError { .. } => "E023",
+      DatamodelError::ScalarTypeNotFoundError { .. } => "E024",
+      DatamodelError::ParserError { .. } => "E025",
+      DatamodelError::LegacyParserError { .. } => "E026",
+      DatamodelError::ConnectorError { .. } => "E027",
+      DatamodelError::FunctionalEvaluationError { .. } => "E028",
+      DatamodelError::EnvironmentFunctionalEvaluationError { .. } => "E029",
+      DatamodelError::TypeMismatchError { .. } => "E030",
+      DatamodelError::ValueParserError { .. } => "E031",
+      DatamodelError::ModelValidationError { .. } => "E032",
+      DatamodelError::FieldValidationError { .. } => "E033",
+      DatamodelError::SourceValidationError { .. } => "E034",
+      DatamodelError::EnumValidationError { .. } => "E035",
+      DatamodelError::ValidationError { .. } => "E036",
+    }
+  }
+
+  /// How the user could fix the error, if there is an obvious fix.
+  pub fn suggestion(&self) -> Option<String> {
+    match self {
+      DatamodelError::ArgumentNotFound { argument_name, .. } => Some(format!("Add the argument \"{}\".", argument_name)),
+      DatamodelError::AttributeArgumentNotFound { argument_name, attribute_name, .. } => Some(format!("Add the argument \"{}\" to \"@{}\".", argument_name, attribute_name)),
+      DatamodelError::SourceArgumentNotFound { argument_name, source_name, .. } => Some(format!("Add \"{}\" to the data source block \"{}\".", argument_name, source_name)),
+      DatamodelError::GeneratorArgumentNotFound { argument_name, generator_name, .. } => Some(format!("Add \"{}\" to the generator block \"{}\".", argument_name, generator_name)),
+      DatamodelError::DuplicateAttributeError { attribute_name, .. } => Some(format!("Remove one of the \"@{}\" attributes.", attribute_name)),
+      DatamodelError::DuplicateTopError { name, .. } => Some(format!("Rename one of the definitions named \"{}\".", name)),
+      DatamodelError::DuplicateConfigKeyError { key_name, .. } => Some(format!("Remove one of the \"{}\" keys.", key_name)),
+      DatamodelError::DuplicateDefaultArgumentError { arg_name, .. } => Some(format!("Remove the argument name \"{}\" or the unnamed argument.", arg_name)),
+      DatamodelError::DuplicateArgumentError { arg_name, .. } => Some(format!("Remove one of the \"{}\" arguments.", arg_name)),
+      DatamodelError::UnusedArgumentError { arg_name, .. } => Some(format!("Remove the argument \"{}\".", arg_name)),
+      DatamodelError::DuplicateFieldError { field_name, .. } => Some(format!("Rename or remove one of the \"{}\" fields.", field_name)),
+      DatamodelError::DuplicateEnumValueError { value_name, .. } => Some(format!("Remove one of the \"{}\" values.", value_name)),
+      DatamodelError::AttributeNotKnownError { attribute_name, .. } => Some(format!("Remove \"@{}\" or check it for typos.", attribute_name)),
+      DatamodelError::PreviewFeatureNotKnownError { expected_preview_features, .. } => Some(format!("Use one of the known preview features: {}", expected_preview_features)),
+      DatamodelError::TypeNotFoundError { type_name, .. } => Some(format!("Define a model or enum named \"{}\", or check the type for typos.", type_name)),
+      DatamodelError::EnvironmentFunctionalEvaluationError { var_name, .. } => Some(format!("Set the environment variable \"{}\", e.g. in a .env file.", var_name)),
+      _ => None,
+    }
+  }
+
   pub fn description(&self) -> String {
     format!("{}", self)
   }
@@ -1,10 +1,12 @@
 mod collection;
 mod error;
 mod helper;
+mod structured;
 mod validated;
 pub(crate) mod warning;
 
 pub use collection::*;
 pub use error::DatamodelError;
+pub use structured::StructuredDiagnostic;
 pub use validated::*;
 pub use warning::DatamodelWarning;
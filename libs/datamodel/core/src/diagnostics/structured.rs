@@ -0,0 +1,52 @@
+use super::{DatamodelError, DatamodelWarning, Diagnostics};
+use serde::Serialize;
+
+/// A machine-readable error or warning, for clients that render diagnostics themselves, like
+/// editors. `start` and `end` are byte offsets into the datamodel string.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDiagnostic {
+    pub code: &'static str,
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    pub is_warning: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl From<&DatamodelError> for StructuredDiagnostic {
+    fn from(err: &DatamodelError) -> Self {
+        StructuredDiagnostic {
+            code: err.code(),
+            start: err.span().start,
+            end: err.span().end,
+            text: err.description(),
+            is_warning: false,
+            suggestion: err.suggestion(),
+        }
+    }
+}
+
+impl From<&DatamodelWarning> for StructuredDiagnostic {
+    fn from(warn: &DatamodelWarning) -> Self {
+        StructuredDiagnostic {
+            code: warn.code(),
+            start: warn.span().start,
+            end: warn.span().end,
+            text: warn.description(),
+            is_warning: true,
+            suggestion: warn.suggestion(),
+        }
+    }
+}
+
+impl Diagnostics {
+    /// The errors, followed by the warnings, in machine-readable form.
+    pub fn to_structured(&self) -> Vec<StructuredDiagnostic> {
+        self.to_error_iter()
+            .map(StructuredDiagnostic::from)
+            .chain(self.to_warning_iter().map(StructuredDiagnostic::from))
+            .collect()
+    }
+}
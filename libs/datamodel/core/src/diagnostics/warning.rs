@@ -15,6 +15,18 @@ pub enum DatamodelWarning {
 
   #[error("Using multiple providers is now deprecated. You should use a single provider instead. Read more at https://pris.ly/multi-provider-deprecation")]
   DeprecatedProviderArray { span: Span },
+
+  #[error("The {} name `{}` is a reserved word on this database. It only works in queries that quote it.", kind, name)]
+  ReservedWord { kind: &'static str, name: String, map_attribute: &'static str, span: Span },
+
+  #[error("The relation field `{}` on model `{}` is not backed by an index on {}. Following the relation from the related model scans the whole table.", field_name, model_name, fields.join(", "))]
+  UnindexedForeignKey { model_name: String, field_name: String, fields: Vec<String>, span: Span },
+
+  #[error("The model name `{}` is not in PascalCase.", name)]
+  ModelNaming { name: String, suggested_name: String, mapped: bool, span: Span },
+
+  #[error("The model `{}` has no `@updatedAt` field.", model_name)]
+  MissingUpdatedAt { model_name: String, span: Span },
 }
 
 #[rustfmt::skip]
@@ -32,10 +44,73 @@ impl DatamodelWarning {
     }
   }
 
+  pub fn new_reserved_word_warning(kind: &'static str, name: &str, map_attribute: &'static str, span: Span) -> DatamodelWarning {
+    DatamodelWarning::ReservedWord {
+      kind,
+      name: String::from(name),
+      map_attribute,
+      span,
+    }
+  }
+
+  pub fn new_unindexed_foreign_key_warning(model_name: &str, field_name: &str, fields: &[String], span: Span) -> DatamodelWarning {
+    DatamodelWarning::UnindexedForeignKey {
+      model_name: String::from(model_name),
+      field_name: String::from(field_name),
+      fields: fields.to_vec(),
+      span,
+    }
+  }
+
+  pub fn new_model_naming_warning(name: &str, suggested_name: &str, mapped: bool, span: Span) -> DatamodelWarning {
+    DatamodelWarning::ModelNaming {
+      name: String::from(name),
+      suggested_name: String::from(suggested_name),
+      mapped,
+      span,
+    }
+  }
+
+  pub fn new_missing_updated_at_warning(model_name: &str, span: Span) -> DatamodelWarning {
+    DatamodelWarning::MissingUpdatedAt {
+      model_name: String::from(model_name),
+      span,
+    }
+  }
+
   pub fn span(&self) -> Span {
     match self {
      DatamodelWarning::DeprecatedPreviewFeature { span, .. } => *span,
       DatamodelWarning::DeprecatedProviderArray { span, .. } => *span,
+      DatamodelWarning::ReservedWord { span, .. } => *span,
+      DatamodelWarning::UnindexedForeignKey { span, .. } => *span,
+      DatamodelWarning::ModelNaming { span, .. } => *span,
+      DatamodelWarning::MissingUpdatedAt { span, .. } => *span,
+    }
+  }
+
+  /// A stable code identifying the kind of warning, see `DatamodelError::code`.
+  pub fn code(&self) -> &'static str {
+    match self {
+      DatamodelWarning::DeprecatedPreviewFeature { .. } => "W001",
+      DatamodelWarning::DeprecatedProviderArray { .. } => "W002",
+      DatamodelWarning::ReservedWord { .. } => "W003",
+      DatamodelWarning::UnindexedForeignKey { .. } => "W004",
+      DatamodelWarning::ModelNaming { .. } => "W005",
+      DatamodelWarning::MissingUpdatedAt { .. } => "W006",
+    }
+  }
+
+  /// How the user could fix the warning.
+  pub fn suggestion(&self) -> Option<String> {
+    match self {
+      DatamodelWarning::DeprecatedPreviewFeature { preview_feature, .. } => Some(format!("Remove \"{}\" from the preview features of the generator.", preview_feature)),
+      DatamodelWarning::DeprecatedProviderArray { .. } => Some(String::from("Use a single provider instead of an array.")),
+      DatamodelWarning::ReservedWord { kind, map_attribute, .. } => Some(format!("Use `{}` to give the {} a different name in the database.", map_attribute, kind)),
+      DatamodelWarning::UnindexedForeignKey { fields, .. } => Some(format!("Add `@@index([{}])` to the model.", fields.join(", "))),
+      DatamodelWarning::ModelNaming { name, suggested_name, mapped: false, .. } => Some(format!("Rename the model to `{}` and keep the table name with `@@map(\"{}\")`.", suggested_name, name)),
+      DatamodelWarning::ModelNaming { suggested_name, mapped: true, .. } => Some(format!("Rename the model to `{}`.", suggested_name)),
+      DatamodelWarning::MissingUpdatedAt { .. } => Some(String::from("Add a field like `updatedAt DateTime @updatedAt`.")),
     }
   }
 
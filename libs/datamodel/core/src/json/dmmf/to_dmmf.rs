@@ -1,4 +1,4 @@
-use super::{Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
+use super::{CompositeType, Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex, Validation};
 use crate::{dml, IndexType, ScalarType};
 use bigdecimal::ToPrimitive;
 use prisma_value::PrismaValue;
@@ -17,6 +17,7 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +28,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -89,6 +94,38 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type.fields().map(composite_type_field_to_dmmf).collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
+fn composite_type_field_to_dmmf(field: &dml::CompositeTypeField) -> Field {
+    Field {
+        name: field.name.clone(),
+        kind: get_field_kind(&field.field_type),
+        is_required: field.arity == dml::FieldArity::Required,
+        is_list: field.arity == dml::FieldArity::List,
+        is_id: false,
+        is_read_only: false,
+        has_default_value: false,
+        default: None,
+        is_unique: false,
+        relation_name: None,
+        relation_from_fields: None,
+        relation_to_fields: None,
+        relation_on_delete: None,
+        field_type: get_field_type(&field.field_type),
+        is_generated: None,
+        is_updated_at: None,
+        is_engine_generated: None,
+        validations: Vec::new(),
+        documentation: field.documentation.clone(),
+    }
+}
+
 fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
     let a_relation_field_is_based_on_this_field: bool = model
         .relation_fields()
@@ -96,7 +133,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
 
     Field {
         name: field.name().to_string(),
-        kind: get_field_kind(field),
+        kind: get_field_kind(&field.field_type()),
         is_required: *field.arity() == dml::FieldArity::Required,
         is_list: *field.arity() == dml::FieldArity::List,
         is_id: field.is_id(),
@@ -108,16 +145,19 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         relation_from_fields: get_relation_from_fields(field),
         relation_to_fields: get_relation_to_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
-        field_type: get_field_type(field),
+        field_type: get_field_type(&field.field_type()),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
+        is_engine_generated: Some(is_engine_generated(field)),
+        validations: field.validations().iter().map(validation_to_dmmf).collect(),
         documentation: field.documentation().map(|v| v.to_owned()),
     }
 }
 
-fn get_field_kind(field: &dml::Field) -> String {
-    match field.field_type() {
+fn get_field_kind(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         dml::FieldType::NativeType(_, _) => String::from("scalar"),
@@ -132,6 +172,29 @@ fn default_value_to_serde(dv_opt: &Option<dml::DefaultValue>) -> Option<serde_js
     })
 }
 
+/// Whether the query engine generates a value for the field when none is provided.
+fn is_engine_generated(field: &dml::Field) -> bool {
+    matches!(field.default_value(), Some(dml::DefaultValue::Expression(vg)) if vg.is_engine_generated())
+}
+
+fn validation_to_dmmf(validation: &dml::FieldValidation) -> Validation {
+    let (min, max) = match validation {
+        dml::FieldValidation::Length { min, max } => {
+            (min.map(serde_json::Value::from), max.map(serde_json::Value::from))
+        }
+        dml::FieldValidation::Range { min, max } => (
+            min.as_ref().map(prisma_value_to_serde),
+            max.as_ref().map(prisma_value_to_serde),
+        ),
+    };
+
+    Validation {
+        name: validation.name().to_owned(),
+        min,
+        max,
+    }
+}
+
 fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
     match value {
         PrismaValue::Boolean(val) => serde_json::Value::Bool(*val),
@@ -151,6 +214,7 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
             serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
         }
         PrismaValue::Bytes(b) => serde_json::Value::String(prisma_value::encode_bytes(b)),
+        PrismaValue::Interval(val) => serde_json::Value::String(val.to_string()),
     }
 }
 
@@ -163,9 +227,10 @@ fn function_to_serde(name: &str, args: &[PrismaValue]) -> serde_json::Value {
     serde_json::to_value(&func).expect("Failed to render function JSON")
 }
 
-fn get_field_type(field: &dml::Field) -> String {
-    match &field.field_type() {
+fn get_field_type(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Enum(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
@@ -8,6 +8,22 @@ pub struct SourceConfig {
     pub active_provider: String,
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_url: Option<StringFromEnvVar>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub listen_channels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_time_mode: Option<configuration::DateTimeMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referential_integrity: Option<configuration::ReferentialIntegrity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreign_key_indexes: Option<configuration::ForeignKeyIndexes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_log: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_timestamps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lint: Option<Vec<configuration::LintRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
 
@@ -37,6 +53,16 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        direct_url: source.direct_url.clone(),
+        listen_channels: source.listen_channels.clone(),
+        date_time_mode: Some(source.date_time_mode).filter(|mode| *mode != configuration::DateTimeMode::default()),
+        referential_integrity: Some(source.referential_integrity)
+            .filter(|mode| *mode != configuration::ReferentialIntegrity::default()),
+        foreign_key_indexes: Some(source.foreign_key_indexes)
+            .filter(|mode| *mode != configuration::ForeignKeyIndexes::default()),
+        audit_log: Some(source.audit_log).filter(|enabled| *enabled),
+        database_timestamps: Some(source.database_timestamps).filter(|enabled| *enabled),
+        lint: Some(source.lint_rules.clone()).filter(|rules| *rules != configuration::LintRule::defaults()),
         documentation: source.documentation.clone(),
     }
 }
@@ -16,6 +16,7 @@
 //! * `configuration`: contains the models representing the Datasources and Generators of a Prisma schema
 //! * `transform`: contains the logic to turn an AST into models and vice versa
 //! * `json`: contains the logic to turn models into their JSON/DMMF representation
+//! * `completion`: contains the autocompletion metadata for editors
 //!
 //! The flow between the layers is depicted in the following diagram.
 //!<pre>
@@ -78,6 +79,7 @@ extern crate tracing;
 
 pub mod ast;
 pub mod common;
+pub mod completion;
 pub mod configuration;
 pub mod diagnostics;
 pub mod dml;
@@ -1,6 +1,6 @@
 use super::datasource_provider::DatasourceProvider;
 use crate::common::provider_names::*;
-use crate::StringFromEnvVar;
+use crate::{StringFromEnvVar, TlsOptions};
 use datamodel_connector::Connector;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 
@@ -52,7 +52,9 @@ impl DatasourceProvider for PostgresDatasourceProvider {
     fn can_handle_url(&self, name: &str, url: &StringFromEnvVar) -> Result<(), String> {
         let high_prio_validation = validate_url(name, "postgresql://", url);
         let low_prio_validation = validate_url(name, "postgres://", url); // for postgres urls on heroku -> https://devcenter.heroku.com/articles/heroku-postgresql#spring-java
-        low_prio_validation.or(high_prio_validation)
+        low_prio_validation.or(high_prio_validation)?;
+
+        validate_tls_options(name, url).map(drop)
     }
 
     fn connector(&self) -> Box<dyn Connector> {
@@ -78,7 +80,18 @@ impl DatasourceProvider for MySqlDatasourceProvider {
     }
 
     fn can_handle_url(&self, name: &str, url: &StringFromEnvVar) -> Result<(), String> {
-        validate_url(name, "mysql://", url)
+        validate_url(name, "mysql://", url)?;
+
+        let tls_options = validate_tls_options(name, url)?;
+
+        if tls_options.mode.is_some() {
+            return Err(format!(
+                "The URL for datasource `{}` uses the `sslmode` parameter, which is only supported on PostgreSQL. Use `sslaccept` instead.",
+                name
+            ));
+        }
+
+        Ok(())
     }
 
     fn connector(&self) -> Box<dyn Connector> {
@@ -122,3 +135,8 @@ fn validate_url(name: &str, expected_protocol: &str, url: &StringFromEnvVar) ->
         ))
     }
 }
+
+fn validate_tls_options(name: &str, url: &StringFromEnvVar) -> Result<TlsOptions, String> {
+    TlsOptions::from_connection_string(&url.value)
+        .map_err(|err| format!("The URL for datasource `{}` has invalid TLS options: {}", name, err))
+}
@@ -5,12 +5,25 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
-use crate::configuration::StringFromEnvVar;
+use crate::common::provider_names::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME};
+use crate::configuration::{
+    DateTimeMode, ForeignKeyIndexes, LintRule, ReferentialIntegrity, RetryPolicy, StringFromEnvVar,
+};
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const DIRECT_URL_KEY: &str = "directUrl";
+const CONNECT_RETRIES_KEY: &str = "connectRetries";
+const CONNECT_RETRY_DELAY_KEY: &str = "connectRetryDelay";
+const LISTEN_CHANNELS_KEY: &str = "listenChannels";
+const DATE_TIME_MODE_KEY: &str = "dateTimeMode";
+const REFERENTIAL_INTEGRITY_KEY: &str = "referentialIntegrity";
+const FOREIGN_KEY_INDEXES_KEY: &str = "foreignKeyIndexes";
+const AUDIT_LOG_KEY: &str = "auditLog";
+const DATABASE_TIMESTAMPS_KEY: &str = "databaseTimestamps";
+const LINT_KEY: &str = "lint";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -133,7 +146,11 @@ impl DatasourceLoader {
                 (None, format!("{}://", providers.first().unwrap()))
             }
             (_, Some(url)) => {
-                debug!("overwriting datasource `{}` with url '{}'", &source_name, &url);
+                debug!(
+                    "overwriting datasource `{}` with url '{}'",
+                    &source_name,
+                    datasource_url::redact_url(url)
+                );
                 (None, url.to_owned())
             }
             (Ok((env_var, url)), _) => (env_var, url.trim().to_owned()),
@@ -161,6 +178,84 @@ impl DatasourceLoader {
             )));
         }
 
+        let direct_url = match args.optional_arg(DIRECT_URL_KEY) {
+            // An explicit override replaces the URL for all engines, so the direct URL is dropped.
+            Some(_) if ignore_datasource_urls || override_url.is_some() => None,
+            Some(direct_url_arg) => {
+                let (env_var, direct_url) = match direct_url_arg.as_str_from_env() {
+                    Ok(res) => res,
+                    Err(err) => return Err(diagnostics.merge_error(err)),
+                };
+                let direct_url = direct_url.trim().to_owned();
+
+                if direct_url.is_empty() {
+                    let msg = format!(
+                        "You must provide a nonempty direct URL for the datasource `{}`.",
+                        source_name
+                    );
+
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &msg,
+                        source_name,
+                        direct_url_arg.span(),
+                    )));
+                }
+
+                Some((
+                    StringFromEnvVar {
+                        from_env_var: env_var,
+                        value: direct_url,
+                    },
+                    direct_url_arg.span(),
+                ))
+            }
+            None => None,
+        };
+
+        let retry_policy = match Self::lift_retry_policy(&mut args, source_name) {
+            Ok(retry_policy) => retry_policy,
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
+        let (listen_channels, listen_channels_span) = match Self::lift_listen_channels(&mut args, source_name) {
+            Ok(listen_channels) => listen_channels,
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
+        let date_time_mode = match Self::lift_date_time_mode(&mut args, source_name) {
+            Ok(date_time_mode) => date_time_mode,
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
+        let referential_integrity = match Self::lift_referential_integrity(&mut args, source_name) {
+            Ok(referential_integrity) => referential_integrity,
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
+        let (foreign_key_indexes, foreign_key_indexes_span) =
+            match Self::lift_foreign_key_indexes(&mut args, source_name) {
+                Ok(foreign_key_indexes) => foreign_key_indexes,
+                Err(err) => return Err(diagnostics.merge_error(err)),
+            };
+
+        let audit_log = match args.optional_arg(AUDIT_LOG_KEY).map(|arg| arg.as_bool()).transpose() {
+            Ok(audit_log) => audit_log.unwrap_or(false),
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
+        let (database_timestamps, database_timestamps_span) = match args.optional_arg(DATABASE_TIMESTAMPS_KEY) {
+            Some(arg) => match arg.as_bool() {
+                Ok(database_timestamps) => (database_timestamps, arg.span()),
+                Err(err) => return Err(diagnostics.merge_error(err)),
+            },
+            None => (false, Span::empty()),
+        };
+
+        let lint_rules = match Self::lift_lint_rules(&mut args, source_name) {
+            Ok(lint_rules) => lint_rules,
+            Err(err) => return Err(diagnostics.merge_error(err)),
+        };
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let (preview_features, span) = match preview_features_arg.ok() {
             Some(x) => (x.as_array().to_str_vec()?, x.span()),
@@ -197,7 +292,13 @@ impl DatasourceLoader {
                 let url_check_result = provider.can_handle_url(source_name, &url).map_err(|err_msg| {
                     DatamodelError::new_source_validation_error(&err_msg, source_name, url_args.span())
                 });
-                url_check_result.map(|_| provider)
+                let direct_url_check_result = url_check_result.and_then(|_| match &direct_url {
+                    Some((direct_url, span)) => provider
+                        .can_handle_url(source_name, direct_url)
+                        .map_err(|err_msg| DatamodelError::new_source_validation_error(&err_msg, source_name, *span)),
+                    None => Ok(()),
+                });
+                direct_url_check_result.map(|_| provider)
             })
             .collect();
 
@@ -211,13 +312,57 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            if !listen_channels.is_empty() && first_successful_provider.canonical_name() != POSTGRES_SOURCE_NAME {
+                return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                    &format!(
+                        "The `{}` argument is only supported on PostgreSQL.",
+                        LISTEN_CHANNELS_KEY
+                    ),
+                    source_name,
+                    listen_channels_span,
+                )));
+            }
+
+            if foreign_key_indexes != ForeignKeyIndexes::default()
+                && first_successful_provider.canonical_name() != MYSQL_SOURCE_NAME
+            {
+                return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                    &format!("The `{}` argument is only supported on MySQL.", FOREIGN_KEY_INDEXES_KEY),
+                    source_name,
+                    foreign_key_indexes_span,
+                )));
+            }
+
+            if database_timestamps
+                && ![MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME].contains(&first_successful_provider.canonical_name())
+            {
+                return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                    &format!(
+                        "The `{}` argument is only supported on MySQL and PostgreSQL.",
+                        DATABASE_TIMESTAMPS_KEY
+                    ),
+                    source_name,
+                    database_timestamps_span,
+                )));
+            }
+
             Ok(ValidatedDatasource {
                 subject: Datasource {
                     name: source_name.to_string(),
                     provider: providers,
                     active_provider: first_successful_provider.canonical_name().to_string(),
                     url,
+                    direct_url: direct_url.map(|(direct_url, _)| direct_url),
                     documentation,
+                    retry_policy,
+                    listen_channels,
+                    date_time_mode,
+                    referential_integrity,
+                    foreign_key_indexes,
+                    audit_log,
+                    database_timestamps,
+                    lint_rules,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
                     preview_features,
@@ -229,6 +374,158 @@ impl DatasourceLoader {
         }
     }
 
+    fn lift_retry_policy(args: &mut Arguments<'_>, source_name: &str) -> Result<RetryPolicy, DatamodelError> {
+        let mut retry_policy = RetryPolicy::default();
+
+        if let Some(retries_arg) = args.optional_arg(CONNECT_RETRIES_KEY) {
+            retry_policy.max_retries =
+                Self::lift_non_negative_int(&retries_arg, CONNECT_RETRIES_KEY, source_name)? as u32;
+        }
+
+        if let Some(delay_arg) = args.optional_arg(CONNECT_RETRY_DELAY_KEY) {
+            let delay = Self::lift_non_negative_int(&delay_arg, CONNECT_RETRY_DELAY_KEY, source_name)?;
+            retry_policy.base_delay = std::time::Duration::from_millis(delay as u64);
+        }
+
+        Ok(retry_policy)
+    }
+
+    fn lift_listen_channels(
+        args: &mut Arguments<'_>,
+        source_name: &str,
+    ) -> Result<(Vec<String>, Span), DatamodelError> {
+        let listen_channels_arg = match args.optional_arg(LISTEN_CHANNELS_KEY) {
+            Some(arg) => arg,
+            None => return Ok((Vec::new(), Span::empty())),
+        };
+
+        let listen_channels = listen_channels_arg.as_array().to_str_vec()?;
+
+        if listen_channels.iter().any(|channel| channel.trim().is_empty()) {
+            return Err(DatamodelError::new_source_validation_error(
+                &format!(
+                    "The `{}` argument must not contain empty channel names.",
+                    LISTEN_CHANNELS_KEY
+                ),
+                source_name,
+                listen_channels_arg.span(),
+            ));
+        }
+
+        Ok((listen_channels, listen_channels_arg.span()))
+    }
+
+    fn lift_date_time_mode(args: &mut Arguments<'_>, source_name: &str) -> Result<DateTimeMode, DatamodelError> {
+        let date_time_mode_arg = match args.optional_arg(DATE_TIME_MODE_KEY) {
+            Some(arg) => arg,
+            None => return Ok(DateTimeMode::default()),
+        };
+
+        DateTimeMode::parse(&date_time_mode_arg.as_str()?).ok_or_else(|| {
+            DatamodelError::new_source_validation_error(
+                &format!(
+                    "The `{}` argument must be one of \"utc\", \"local\" or \"naive\".",
+                    DATE_TIME_MODE_KEY
+                ),
+                source_name,
+                date_time_mode_arg.span(),
+            )
+        })
+    }
+
+    fn lift_referential_integrity(
+        args: &mut Arguments<'_>,
+        source_name: &str,
+    ) -> Result<ReferentialIntegrity, DatamodelError> {
+        let referential_integrity_arg = match args.optional_arg(REFERENTIAL_INTEGRITY_KEY) {
+            Some(arg) => arg,
+            None => return Ok(ReferentialIntegrity::default()),
+        };
+
+        ReferentialIntegrity::parse(&referential_integrity_arg.as_str()?).ok_or_else(|| {
+            DatamodelError::new_source_validation_error(
+                &format!(
+                    "The `{}` argument must be one of \"foreignKeys\" or \"prisma\".",
+                    REFERENTIAL_INTEGRITY_KEY
+                ),
+                source_name,
+                referential_integrity_arg.span(),
+            )
+        })
+    }
+
+    fn lift_foreign_key_indexes(
+        args: &mut Arguments<'_>,
+        source_name: &str,
+    ) -> Result<(ForeignKeyIndexes, Span), DatamodelError> {
+        let foreign_key_indexes_arg = match args.optional_arg(FOREIGN_KEY_INDEXES_KEY) {
+            Some(arg) => arg,
+            None => return Ok((ForeignKeyIndexes::default(), Span::empty())),
+        };
+
+        let foreign_key_indexes = ForeignKeyIndexes::parse(&foreign_key_indexes_arg.as_str()?).ok_or_else(|| {
+            DatamodelError::new_source_validation_error(
+                &format!(
+                    "The `{}` argument must be one of \"implicit\" or \"explicit\".",
+                    FOREIGN_KEY_INDEXES_KEY
+                ),
+                source_name,
+                foreign_key_indexes_arg.span(),
+            )
+        })?;
+
+        Ok((foreign_key_indexes, foreign_key_indexes_arg.span()))
+    }
+
+    fn lift_lint_rules(args: &mut Arguments<'_>, source_name: &str) -> Result<Vec<LintRule>, DatamodelError> {
+        let lint_arg = match args.optional_arg(LINT_KEY) {
+            Some(arg) => arg,
+            None => return Ok(LintRule::defaults()),
+        };
+
+        let mut lint_rules = Vec::new();
+
+        for rule in lint_arg.as_array().to_str_vec()? {
+            let rule = LintRule::parse(&rule).ok_or_else(|| {
+                let known_rules: Vec<_> = LintRule::ALL
+                    .iter()
+                    .map(|rule| format!("\"{}\"", rule.as_str()))
+                    .collect();
+
+                DatamodelError::new_source_validation_error(
+                    &format!(
+                        "Unknown lint rule \"{}\". The `{}` argument must only contain {}.",
+                        rule,
+                        LINT_KEY,
+                        known_rules.join(", ")
+                    ),
+                    source_name,
+                    lint_arg.span(),
+                )
+            })?;
+
+            if !lint_rules.contains(&rule) {
+                lint_rules.push(rule);
+            }
+        }
+
+        Ok(lint_rules)
+    }
+
+    fn lift_non_negative_int(arg: &ValueValidator, arg_name: &str, source_name: &str) -> Result<i64, DatamodelError> {
+        let value = arg.as_int()?;
+
+        if !(0..=i64::from(u32::MAX)).contains(&value) {
+            return Err(DatamodelError::new_source_validation_error(
+                &format!("The `{}` argument must be a non-negative integer.", arg_name),
+                source_name,
+                arg.span(),
+            ));
+        }
+
+        Ok(value)
+    }
+
     fn get_datasource_provider(&self, provider: &str) -> Option<&Box<dyn DatasourceProvider>> {
         self.source_definitions.iter().find(|sd| sd.is_provider(provider))
     }
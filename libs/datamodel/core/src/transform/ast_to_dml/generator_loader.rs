@@ -3,7 +3,7 @@ use crate::ast::Span;
 use crate::common::preview_features::{DEPRECATED_GENERATOR_PREVIEW_FEATURES, GENERATOR_PREVIEW_FEATURES};
 use crate::transform::ast_to_dml::common::validate_preview_features;
 use crate::{ast, configuration::Generator, diagnostics::*};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 const PROVIDER_KEY: &str = "provider";
 const OUTPUT_KEY: &str = "output";
@@ -74,7 +74,7 @@ impl GeneratorLoader {
             None
         };
 
-        let mut properties: HashMap<String, String> = HashMap::new();
+        let mut properties: BTreeMap<String, String> = BTreeMap::new();
 
         let binary_targets = match args.arg(BINARY_TARGETS_KEY).ok() {
             Some(x) => x.as_array().to_str_vec()?,
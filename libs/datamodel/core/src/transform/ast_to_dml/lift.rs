@@ -50,6 +50,10 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::CompositeType(ct) => match self.lift_composite_type(&ct, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
@@ -57,6 +61,17 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             }
         }
 
+        if !errors.has_errors() {
+            for ast_composite_type in ast_schema.composite_types() {
+                let mut path = vec![ast_composite_type.name.name.clone()];
+                errors.push_opt_error(Self::find_recursive_composite_type(
+                    ast_composite_type,
+                    ast_schema,
+                    &mut path,
+                ));
+            }
+        }
+
         if errors.has_errors() {
             Err(errors)
         } else {
@@ -64,6 +79,122 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
         }
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        // Composite types are stored as JSON on SQL connectors.
+        let supports_composite_types = match self.source {
+            Some(source) => source.combined_connector.supports_json(),
+            None => true,
+        };
+
+        if !supports_composite_types {
+            errors.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "You defined the composite type `{}`. But the current connector does not support composite types.",
+                    &ast_composite_type.name.name
+                ),
+                ast_composite_type.span,
+            ));
+            return Err(errors);
+        }
+
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        for ast_field in &ast_composite_type.fields {
+            if let Some(attribute) = ast_field.attributes.first() {
+                errors.push_error(DatamodelError::new_validation_error(
+                    "Attributes are not supported on fields of composite types.",
+                    attribute.span,
+                ));
+                continue;
+            }
+
+            let field_type = match self.lift_field_type(&ast_field, None, ast_schema, &mut Vec::new()) {
+                Ok((FieldType::Relation(info), _)) => {
+                    errors.push_error(DatamodelError::new_validation_error(
+                        &format!(
+                            "The field `{}` of the composite type `{}` refers to the model `{}`. Composite types can only contain scalar, enum and composite type fields.",
+                            &ast_field.name.name, &ast_composite_type.name.name, &info.to
+                        ),
+                        ast_field.field_type.span,
+                    ));
+                    continue;
+                }
+                Ok((_, extra_attributes)) if !extra_attributes.is_empty() => {
+                    errors.push_error(DatamodelError::new_validation_error(
+                        "Attributes are not supported on fields of composite types.",
+                        ast_field.field_type.span,
+                    ));
+                    continue;
+                }
+                Ok((field_type, _)) => field_type,
+                Err(err) => {
+                    errors.push_error(err);
+                    continue;
+                }
+            };
+
+            let mut field = dml::CompositeTypeField::new(
+                &ast_field.name.name,
+                self.lift_field_arity(&ast_field.arity),
+                field_type,
+            );
+            field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
+            composite_type.add_field(field);
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(composite_type)
+        }
+    }
+
+    /// Internal: Composite types are embedded into their parents, so they can't contain themselves.
+    fn find_recursive_composite_type(
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+        path: &mut Vec<String>,
+    ) -> Option<DatamodelError> {
+        for ast_field in &ast_composite_type.fields {
+            let type_name = &ast_field.field_type.name;
+
+            if path.first() == Some(type_name) {
+                return Some(DatamodelError::new_validation_error(
+                    &format!(
+                        "Recursive composite types are not allowed. Recursive path was: {} -> {}.",
+                        path.join(" -> "),
+                        type_name
+                    ),
+                    ast_field.field_type.span,
+                ));
+            }
+
+            if path.contains(type_name) {
+                continue;
+            }
+
+            if let Some(nested) = ast_schema.find_composite_type(type_name) {
+                path.push(type_name.clone());
+                let error = Self::find_recursive_composite_type(nested, ast_schema, path);
+                path.pop();
+
+                if error.is_some() {
+                    return error;
+                }
+            }
+        }
+
+        None
+    }
+
     /// Internal: Validates a model AST node and lifts it to a DML model.
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, Diagnostics> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
@@ -173,6 +304,20 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
         // We merge attributes so we can fail on duplicates.
         let attributes = [&extra_attributes[..], &ast_field.attributes[..]].concat();
 
+        if let Field::ScalarField(dml::ScalarField {
+            field_type: FieldType::CompositeType(_),
+            ..
+        }) = &field
+        {
+            for attribute in attributes.iter().filter(|attribute| attribute.name.name != "map") {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    "Fields with a composite type only support the `@map` attribute.",
+                    &attribute.name.name,
+                    attribute.span,
+                ));
+            }
+        }
+
         if let Err(mut err) = self.attributes.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
         }
@@ -362,6 +507,8 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
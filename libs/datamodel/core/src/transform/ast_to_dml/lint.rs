@@ -0,0 +1,151 @@
+use super::common::STATE_ERROR;
+use crate::{
+    ast,
+    common::provider_names::MYSQL_SOURCE_NAME,
+    configuration::{Datasource, LintRule},
+    diagnostics::DatamodelWarning,
+    dml,
+};
+
+/// Checks the lint rules enabled on the datasource, or the default rules without one, on a valid
+/// datamodel.
+pub(crate) struct Linter<'a> {
+    source: Option<&'a Datasource>,
+    rules: Vec<LintRule>,
+}
+
+impl<'a> Linter<'a> {
+    pub(crate) fn new(source: Option<&'a Datasource>) -> Self {
+        let rules = source
+            .map(|source| source.lint_rules.clone())
+            .unwrap_or_else(LintRule::defaults);
+
+        Linter { source, rules }
+    }
+
+    pub(crate) fn lint(&self, ast_schema: &ast::SchemaAst, schema: &dml::Datamodel) -> Vec<DatamodelWarning> {
+        let mut warnings = Vec::new();
+
+        for model in schema.models().filter(|model| !model.is_commented_out) {
+            let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+            for rule in &self.rules {
+                match rule {
+                    LintRule::UnindexedForeignKeys => self.unindexed_foreign_keys(model, ast_model, &mut warnings),
+                    LintRule::ModelNaming => model_naming(model, ast_model, &mut warnings),
+                    LintRule::UpdatedAt => updated_at(model, ast_model, &mut warnings),
+                    LintRule::ReservedWords => self.reserved_words(model, ast_model, &mut warnings),
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// MySQL creates an index for each foreign key without one, unless migrations create them
+    /// with `foreignKeyIndexes = "explicit"`.
+    fn unindexed_foreign_keys(&self, model: &dml::Model, ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+        let indexed_implicitly = self
+            .source
+            .map(|source| source.active_provider == MYSQL_SOURCE_NAME && !source.foreign_key_indexes.are_explicit())
+            .unwrap_or(false);
+
+        if indexed_implicitly {
+            return;
+        }
+
+        let id_fields = model.id_field_names();
+        let unique_fields: Vec<Vec<String>> = model
+            .scalar_fields()
+            .filter(|field| field.is_unique)
+            .map(|field| vec![field.name.clone()])
+            .collect();
+
+        let indexed_fields: Vec<&Vec<String>> = std::iter::once(&id_fields)
+            .chain(model.indices.iter().map(|index| &index.fields))
+            .chain(unique_fields.iter())
+            .collect();
+
+        for field in model.relation_fields().filter(|field| !field.is_commented_out) {
+            let fields = &field.relation_info.fields;
+
+            if fields.is_empty() || indexed_fields.any(|indexed| indexed.starts_with(fields)) {
+                continue;
+            }
+
+            warnings.push(DatamodelWarning::new_unindexed_foreign_key_warning(
+                &model.name,
+                &field.name,
+                fields,
+                ast_model.find_field(&field.name).name.span,
+            ));
+        }
+    }
+
+    /// Names that work with Prisma, since it quotes all identifiers, but break raw queries and
+    /// other tools that don't. Names chosen explicitly with `@map` or `@@map` are fine.
+    fn reserved_words(&self, model: &dml::Model, ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+        let connector = match self.source {
+            Some(data_source) => &data_source.combined_connector,
+            None => return,
+        };
+
+        if model.database_name.is_none() && connector.is_reserved_word(&model.name) {
+            warnings.push(DatamodelWarning::new_reserved_word_warning(
+                "table",
+                &model.name,
+                "@@map",
+                ast_model.name.span,
+            ));
+        }
+
+        for field in model.scalar_fields().filter(|field| !field.is_commented_out) {
+            if field.database_name.is_none() && connector.is_reserved_word(&field.name) {
+                warnings.push(DatamodelWarning::new_reserved_word_warning(
+                    "column",
+                    &field.name,
+                    "@map",
+                    ast_model.find_field(&field.name).name.span,
+                ));
+            }
+        }
+    }
+}
+
+fn model_naming(model: &dml::Model, ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+    let suggested_name = pascal_case(&model.name);
+
+    if suggested_name != model.name {
+        warnings.push(DatamodelWarning::new_model_naming_warning(
+            &model.name,
+            &suggested_name,
+            model.database_name.is_some(),
+            ast_model.name.span,
+        ));
+    }
+}
+
+/// Join models of many-to-many relations are not updated, so they don't need the field.
+fn updated_at(model: &dml::Model, ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+    if model.is_join_table || model.scalar_fields().any(|field| field.is_updated_at) {
+        return;
+    }
+
+    warnings.push(DatamodelWarning::new_missing_updated_at_warning(
+        &model.name,
+        ast_model.name.span,
+    ));
+}
+
+/// `user_profile` and `userProfile` as `UserProfile`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().into_iter().flat_map(char::to_uppercase);
+
+            first.chain(chars).collect::<String>()
+        })
+        .collect()
+}
@@ -4,6 +4,7 @@ mod datasource_loader;
 mod datasource_provider;
 mod generator_loader;
 mod lift;
+mod lint;
 mod precheck;
 mod standardise;
 mod validate;
@@ -12,6 +13,7 @@ mod validation_pipeline;
 pub mod reserved_model_names;
 
 use lift::*;
+use lint::*;
 use standardise::*;
 use validate::*;
 
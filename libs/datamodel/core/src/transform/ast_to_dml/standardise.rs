@@ -227,6 +227,8 @@ impl Standardiser {
                         references: vec![],
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        deferrable: false,
+                        map: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -298,6 +300,8 @@ impl Standardiser {
                         references: unique_criteria_field_names,
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        deferrable: false,
+                        map: None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
@@ -2,7 +2,7 @@ use crate::ast::WithAttributes;
 use crate::{
     ast, configuration,
     diagnostics::{DatamodelError, Diagnostics},
-    dml, DefaultValue, FieldType,
+    dml, DefaultValue, FieldType, WithDatabaseName,
 };
 use prisma_value::PrismaValue;
 use std::collections::HashSet;
@@ -96,6 +96,50 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_clustering_setting(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(err) = self.validate_table_options(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) =
+                self.validate_deferrable_constraints(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_index_methods(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) =
+                self.validate_version_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_join_table(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_encrypted_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_identifier_lengths(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -373,6 +417,214 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_clustering_setting(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let clustering_settings = model
+            .indices
+            .iter()
+            .map(|index| index.clustered)
+            .chain(std::iter::once(model.id_clustered));
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_clustering_setting()
+                && clustering_settings.clone().any(|clustered| clustered.is_some())
+            {
+                errors.push_error(DatamodelError::new_model_validation_error(
+                    "The `clustered` argument is only supported on SQL Server.",
+                    &model.name,
+                    ast_model.span,
+                ))
+            }
+        }
+
+        if clustering_settings.filter(|clustered| *clustered == Some(true)).count() > 1 {
+            errors.push_error(DatamodelError::new_model_validation_error(
+                "A model can have at most one clustered index or primary key.",
+                &model.name,
+                ast_model.span,
+            ))
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_table_options(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let has_table_options = model.engine.is_some() || model.row_format.is_some();
+
+        match self.source {
+            Some(data_source) if has_table_options && !data_source.combined_connector.supports_table_options() => {
+                Err(DatamodelError::new_model_validation_error(
+                    "The `@@engine` and `@@rowFormat` attributes are only supported on MySQL.",
+                    &model.name,
+                    ast_model.span,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_deferrable_constraints(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        let has_deferrable_constraints = model.indices.iter().any(|index| index.deferrable)
+            || model.relation_fields().any(|field| field.relation_info.deferrable);
+
+        match self.source {
+            Some(data_source)
+                if has_deferrable_constraints && !data_source.combined_connector.supports_deferrable_constraints() =>
+            {
+                Err(DatamodelError::new_model_validation_error(
+                    "The `deferrable` argument is only supported on PostgreSQL.",
+                    &model.name,
+                    ast_model.span,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_index_methods(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let has_index_methods = model.indices.iter().any(|index| index.method.is_some());
+
+        match self.source {
+            Some(data_source) if has_index_methods && !data_source.combined_connector.supports_index_methods() => {
+                Err(DatamodelError::new_model_validation_error(
+                    "The `type` argument of `@@index` is only supported on PostgreSQL.",
+                    &model.name,
+                    ast_model.span,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_version_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        if model.scalar_fields().filter(|field| field.is_version).count() > 1 {
+            return Err(DatamodelError::new_model_validation_error(
+                "A model can only have one field marked with @version.",
+                &model.name,
+                ast_model.span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Filters traverse a join model from one side of the relation to the other, so it has to
+    /// point to exactly two models.
+    fn validate_join_table(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        if !model.is_join_table {
+            return Ok(());
+        }
+
+        let relation_fields: Vec<_> = model.relation_fields().collect();
+        let is_valid = relation_fields.len() == 2
+            && relation_fields
+                .iter()
+                .all(|field| !field.is_list() && !field.relation_info.fields.is_empty());
+
+        if !is_valid {
+            return Err(DatamodelError::new_model_validation_error(
+                "A model marked with @@joinTable must have exactly two relation fields, both referencing a single record through `fields` and `references`.",
+                &model.name,
+                ast_model.span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Encrypted values can't be compared by the database, so they can't identify or relate records.
+    fn validate_encrypted_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model.scalar_fields().filter(|field| field.is_encrypted) {
+            let is_indexed = field.is_id
+                || field.is_unique
+                || model.id_fields.contains(&field.name)
+                || model.indices.iter().any(|index| index.fields.contains(&field.name))
+                || model
+                    .relation_fields()
+                    .any(|relation_field| relation_field.relation_info.fields.contains(&field.name));
+
+            if is_indexed {
+                let ast_field = ast_model.find_field(&field.name);
+
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    "Fields that are marked with @encrypted can not be part of an id, a unique constraint, an index or a relation.",
+                    &model.name,
+                    &field.name,
+                    ast_field.span,
+                ));
+            }
+        }
+
+        errors.to_result()
+    }
+
+    /// Databases reject, or silently truncate, identifiers above a certain length. The mapped
+    /// names are the ones that end up in the database, so these are checked.
+    fn validate_identifier_lengths(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let max_length = match self.source {
+            Some(data_source) => data_source.combined_connector.max_identifier_length(),
+            None => return Ok(()),
+        };
+
+        let too_long_message = |kind: &str, name: &str| {
+            format!(
+                "The {} name `{}` is {} characters long, the maximum identifier length on this database is {}.",
+                kind,
+                name,
+                name.chars().count(),
+                max_length
+            )
+        };
+
+        if model.final_database_name().chars().count() > max_length {
+            errors.push_error(DatamodelError::new_model_validation_error(
+                &too_long_message("table", model.final_database_name()),
+                &model.name,
+                ast_model.span,
+            ));
+        }
+
+        for field in model.scalar_fields() {
+            if field.final_database_name().chars().count() > max_length {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &too_long_message("column", field.final_database_name()),
+                    &model.name,
+                    &field.name,
+                    ast_model.find_field(&field.name).span,
+                ));
+            }
+        }
+
+        for index_name in model.indices.iter().filter_map(|index| index.name.as_ref()) {
+            if index_name.chars().count() > max_length {
+                errors.push_error(DatamodelError::new_model_validation_error(
+                    &too_long_message("index", index_name),
+                    &model.name,
+                    ast_model.span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -970,6 +1222,39 @@ impl<'a> Validator<'a> {
                             field_span,
                         ));
             }
+
+            if let Some(map) = &rel_info.map {
+                let is_many_to_many = field.is_list() && related_field.is_list();
+                let clashing_model = datamodel.models().find(|other| other.final_database_name() == map);
+
+                match (&related_field_rel_info.map, clashing_model) {
+                    _ if !is_many_to_many => errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` specifies the `map` argument in the {} attribute. Only implicit many to many relations can map the name of their join table.",
+                            &field.name, &model.name, RELATION_ATTRIBUTE_NAME_WITH_AT
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        field_span,
+                    )),
+                    (Some(related_map), _) if related_map != map => errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` maps the join table to `{}`, but the related field `{}` on Model `{}` maps it to `{}`. Both sides of the relation must use the same name.",
+                            &field.name, &model.name, map, &related_field.name, &related_model.name, related_map
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        field_span,
+                    )),
+                    (_, Some(clashing_model)) => errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The join table of the relation field `{}` on Model `{}` is mapped to `{}`, which is already the name of the table of Model `{}`.",
+                            &field.name, &model.name, map, &clashing_model.name
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        field_span,
+                    )),
+                    _ => (),
+                }
+            }
         }
 
         errors
@@ -7,6 +7,7 @@ pub struct ValidationPipeline<'a, 'b> {
     lifter: LiftAstToDml<'a, 'b>,
     validator: Validator<'a>,
     standardiser: Standardiser,
+    linter: Linter<'a>,
 }
 
 impl<'a, 'b> ValidationPipeline<'a, 'b> {
@@ -19,6 +20,7 @@ impl<'a, 'b> ValidationPipeline<'a, 'b> {
             lifter: LiftAstToDml::new(source, generators),
             validator: Validator::new(source),
             standardiser: Standardiser::new(),
+            linter: Linter::new(source),
         }
     }
 
@@ -82,6 +84,9 @@ impl<'a, 'b> ValidationPipeline<'a, 'b> {
             diagnostics.append(&mut err);
         }
 
+        // Phase 7: Lint warnings, which do not make the schema invalid.
+        diagnostics.append_warning_vec(self.linter.lint(ast_schema, &schema));
+
         if diagnostics.has_errors() {
             Err(diagnostics)
         } else {
@@ -31,6 +31,11 @@ impl<T: 'static> AttributeListValidator<T> {
         self.known_attributes.insert(String::from(name), validator);
     }
 
+    /// The known attributes, ordered by name.
+    pub fn attributes(&self) -> impl Iterator<Item = &dyn AttributeValidator<T>> + '_ {
+        self.known_attributes.values().map(|validator| validator.as_ref())
+    }
+
     /// For each attribute in the given object, picks the correct
     /// attribute definition and uses it to validate and apply the attribute.
     pub fn validate_and_apply(&self, ast: &dyn ast::WithAttributes, t: &mut T) -> Result<(), Diagnostics> {
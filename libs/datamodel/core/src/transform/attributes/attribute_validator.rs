@@ -1,5 +1,6 @@
 use super::super::helpers::*;
 use crate::ast;
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
 use crate::dml;
 
@@ -14,6 +15,11 @@ pub trait AttributeValidator<T> {
         false
     }
 
+    /// The arguments the attribute accepts, for autocompletion.
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        Vec::new()
+    }
+
     /// Validates an attribute and applies the attribute
     /// to the given object.
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut T) -> Result<(), DatamodelError>;
@@ -1,4 +1,5 @@
 use super::{super::helpers::*, AttributeValidator};
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
 use crate::{ast, dml, ValueGenerator};
 use prisma_value::PrismaValue;
@@ -11,6 +12,10 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
         &"default"
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("value")]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, field: &mut dml::Field) -> Result<(), DatamodelError> {
         if let dml::Field::RelationField(_) = field {
             return self.new_attribute_validation_error("Cannot set a default value on a relation field.", args.span());
@@ -94,5 +99,6 @@ pub fn lower_prisma_value(pv: &PrismaValue) -> ast::Expression {
         ),
         PrismaValue::Xml(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
         PrismaValue::Bytes(b) => ast::Expression::StringValue(prisma_value::encode_bytes(b), ast::Span::empty()),
+        PrismaValue::Interval(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
     }
 }
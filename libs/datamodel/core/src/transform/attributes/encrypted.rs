@@ -0,0 +1,37 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@encrypted` attribute.
+pub struct EncryptedAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for EncryptedAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"encrypted"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        if let dml::Field::ScalarField(sf) = obj {
+            if matches!(
+                sf.field_type.scalar_type(),
+                Some(dml::ScalarType::String) | Some(dml::ScalarType::Bytes)
+            ) {
+                sf.is_encrypted = true;
+
+                return Ok(());
+            }
+        }
+        self.new_attribute_validation_error(
+            "Fields that are marked with @encrypted must be of type String or Bytes.",
+            args.span(),
+        )
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        if field.is_encrypted() {
+            vec![ast::Attribute::new(self.attribute_name(), Vec::new())]
+        } else {
+            vec![]
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::{super::helpers::*, AttributeValidator};
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
 use crate::{ast, dml};
 
@@ -48,6 +49,13 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
         "id"
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::default_arg("fields"),
+            ArgumentSignature::named("clustered").optional(),
+        ]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
         let fields = args
             .default_arg("fields")?
@@ -57,6 +65,11 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
             .collect();
         obj.id_fields = fields;
 
+        obj.id_clustered = match args.optional_arg("clustered") {
+            Some(clustered_arg) => Some(clustered_arg.as_bool()?),
+            None => None,
+        };
+
         let undefined_fields: Vec<String> = obj
             .id_fields
             .iter()
@@ -136,6 +149,13 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
                     .collect(),
             ));
 
+            if let Some(clustered) = model.id_clustered {
+                args.push(ast::Argument::new(
+                    "clustered",
+                    ast::Expression::BooleanValue(clustered.to_string(), ast::Span::empty()),
+                ));
+            }
+
             return vec![ast::Attribute::new(self.attribute_name(), args)];
         }
 
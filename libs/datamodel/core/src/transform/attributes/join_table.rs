@@ -0,0 +1,26 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@joinTable` attribute.
+pub struct JoinTableAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for JoinTableAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "joinTable"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_join_table = true;
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        if obj.is_join_table {
+            vec![ast::Attribute::new(self.attribute_name(), Vec::new())]
+        } else {
+            vec![]
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::ast::Span;
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
 use crate::{ast, dml, Datamodel, WithDatabaseName};
 
@@ -13,6 +14,10 @@ impl AttributeValidator<dml::Model> for MapAttributeValidator {
         ATTRIBUTE_NAME
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("name")]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
         internal_validate_and_apply(args, obj)
     }
@@ -28,6 +33,10 @@ impl AttributeValidator<dml::Field> for MapAttributeValidatorForField {
         ATTRIBUTE_NAME
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("name")]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
         if obj.is_relation() {
             return self.new_attribute_validation_error(
@@ -51,6 +60,10 @@ impl AttributeValidator<dml::Enum> for MapAttributeValidator {
         ATTRIBUTE_NAME
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("name")]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Enum) -> Result<(), DatamodelError> {
         internal_validate_and_apply(args, obj)
     }
@@ -65,6 +78,10 @@ impl AttributeValidator<dml::EnumValue> for MapAttributeValidator {
         ATTRIBUTE_NAME
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("name")]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::EnumValue) -> Result<(), DatamodelError> {
         internal_validate_and_apply(args, obj)
     }
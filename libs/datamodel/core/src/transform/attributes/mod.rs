@@ -1,14 +1,19 @@
 mod attribute_list_validator;
 mod attribute_validator;
 mod default;
+mod encrypted;
 mod id;
+mod join_table;
 mod map;
 mod relation;
+mod table_options;
 mod unique_and_index;
 mod updated_at;
+mod validations;
+mod version;
 
 use crate::dml;
-use attribute_list_validator::AttributeListValidator;
+pub(crate) use attribute_list_validator::AttributeListValidator;
 use attribute_validator::AttributeValidator;
 
 /// This is the facade for all attribute validations. It is used within the `ValidationPipeline`.
@@ -38,6 +43,10 @@ fn new_builtin_field_attributes() -> AttributeListValidator<dml::Field> {
     validator.add(Box::new(unique_and_index::FieldLevelUniqueAttributeValidator {}));
     validator.add(Box::new(default::DefaultAttributeValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtAttributeValidator {}));
+    validator.add(Box::new(version::VersionAttributeValidator {}));
+    validator.add(Box::new(encrypted::EncryptedAttributeValidator {}));
+    validator.add(Box::new(validations::LengthAttributeValidator {}));
+    validator.add(Box::new(validations::RangeAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidatorForField {}));
     validator.add(Box::new(relation::RelationAttributeValidator {}));
 
@@ -52,6 +61,9 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(table_options::EngineAttributeValidator {}));
+    validator.add(Box::new(table_options::RowFormatAttributeValidator {}));
+    validator.add(Box::new(join_table::JoinTableAttributeValidator {}));
 
     validator
 }
@@ -1,5 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::common::RelationNames;
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
 use crate::{ast, dml, Field};
 
@@ -11,6 +12,16 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
         &"relation"
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::default_arg("name").optional(),
+            ArgumentSignature::named("fields").optional(),
+            ArgumentSignature::named("references").optional(),
+            ArgumentSignature::named("deferrable").optional(),
+            ArgumentSignature::named("map").optional(),
+        ]
+    }
+
     fn validate_and_apply(&self, args: &mut Arguments, field: &mut dml::Field) -> Result<(), DatamodelError> {
         if let dml::Field::RelationField(rf) = field {
             if let Ok(name_arg) = args.default_arg("name") {
@@ -32,6 +43,27 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
+            if let Some(deferrable_arg) = args.optional_arg("deferrable") {
+                rf.relation_info.deferrable = deferrable_arg.as_bool()?;
+
+                if rf.relation_info.fields.is_empty() {
+                    return self.new_attribute_validation_error(
+                        "The `deferrable` argument can only be set on the side of the relation that defines `fields`.",
+                        deferrable_arg.span(),
+                    );
+                }
+            }
+
+            if let Some(map_arg) = args.optional_arg("map") {
+                let map = map_arg.as_str()?;
+
+                if map.is_empty() {
+                    return self.new_attribute_validation_error("The `map` argument cannot be empty.", map_arg.span());
+                }
+
+                rf.relation_info.map = Some(map);
+            }
+
             // TODO: bring `onDelete` back once `prisma migrate` is a thing
             //            if let Ok(on_delete) = args.arg("onDelete") {
             //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
@@ -107,6 +139,17 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 ));
             }
 
+            if relation_info.deferrable {
+                args.push(ast::Argument::new(
+                    "deferrable",
+                    ast::Expression::BooleanValue("true".to_owned(), ast::Span::empty()),
+                ));
+            }
+
+            if let Some(map) = &relation_info.map {
+                args.push(ast::Argument::new_string("map", map));
+            }
+
             if !args.is_empty() {
                 return vec![ast::Attribute::new(self.attribute_name(), args)];
             }
@@ -0,0 +1,109 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::ast::Span;
+use crate::completion::ArgumentSignature;
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// The row formats MySQL accepts in `ROW_FORMAT`.
+const ROW_FORMATS: &[&str] = &["DEFAULT", "DYNAMIC", "FIXED", "COMPRESSED", "REDUNDANT", "COMPACT"];
+
+/// Prismas builtin `@@engine` attribute.
+pub struct EngineAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for EngineAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "engine"
+    }
+
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("engine")]
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let engine = args
+            .default_arg("engine")?
+            .as_str()
+            .map_err(|err| self.wrap_in_attribute_validation_error(&err))?;
+
+        if !is_engine_name(&engine) {
+            return self.new_attribute_validation_error(
+                &format!(
+                    "Invalid storage engine `{}`. Engine names start with a letter and only contain letters, digits and underscores.",
+                    engine
+                ),
+                args.span(),
+            );
+        }
+
+        obj.engine = Some(engine);
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        serialize_string_attribute(self.attribute_name(), obj.engine.as_deref())
+    }
+}
+
+/// Prismas builtin `@@rowFormat` attribute.
+pub struct RowFormatAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for RowFormatAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "rowFormat"
+    }
+
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![ArgumentSignature::default_arg("format")]
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let row_format = args
+            .default_arg("format")?
+            .as_str()
+            .map_err(|err| self.wrap_in_attribute_validation_error(&err))?;
+
+        if !ROW_FORMATS
+            .iter()
+            .any(|format| format.eq_ignore_ascii_case(&row_format))
+        {
+            return self.new_attribute_validation_error(
+                &format!(
+                    "Unknown row format `{}`. The available row formats are: {}.",
+                    row_format,
+                    ROW_FORMATS.join(", ")
+                ),
+                args.span(),
+            );
+        }
+
+        obj.row_format = Some(row_format);
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        serialize_string_attribute(self.attribute_name(), obj.row_format.as_deref())
+    }
+}
+
+/// Whether the name is a plain identifier, as the storage engine is rendered unquoted into `ENGINE = ...`.
+fn is_engine_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(first) if first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn serialize_string_attribute(name: &str, value: Option<&str>) -> Vec<ast::Attribute> {
+    match value {
+        Some(value) => vec![ast::Attribute::new(
+            name,
+            vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                String::from(value),
+                Span::empty(),
+            ))],
+        )],
+        None => vec![],
+    }
+}
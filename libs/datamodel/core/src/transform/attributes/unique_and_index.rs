@@ -1,6 +1,7 @@
 use super::{super::helpers::*, AttributeValidator};
+use crate::completion::ArgumentSignature;
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexDefinition, IndexMethod, IndexType};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -68,6 +69,15 @@ impl AttributeValidator<dml::Model> for ModelLevelUniqueAttributeValidator {
         "unique"
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::default_arg("fields"),
+            ArgumentSignature::named("name").optional(),
+            ArgumentSignature::named("clustered").optional(),
+            ArgumentSignature::named("deferrable").optional(),
+        ]
+    }
+
     fn is_duplicate_definition_allowed(&self) -> bool {
         true
     }
@@ -93,6 +103,15 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
         "index"
     }
 
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::default_arg("fields"),
+            ArgumentSignature::named("name").optional(),
+            ArgumentSignature::named("clustered").optional(),
+            ArgumentSignature::named("type").optional(),
+        ]
+    }
+
     fn is_duplicate_definition_allowed(&self) -> bool {
         true
     }
@@ -121,6 +140,9 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            clustered: None,
+            deferrable: false,
+            method: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -128,6 +150,44 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         };
         index_def.name = name;
 
+        index_def.clustered = match args.optional_arg("clustered") {
+            Some(clustered_arg) => Some(clustered_arg.as_bool()?),
+            None => None,
+        };
+
+        // Only unique constraints can be deferred, the argument is unused on `@@index`.
+        if index_type == IndexType::Unique {
+            if let Some(deferrable_arg) = args.optional_arg("deferrable") {
+                index_def.deferrable = deferrable_arg.as_bool()?;
+            }
+        }
+
+        // Unique indexes are always B-trees, the argument is unused on `@@unique`.
+        if index_type == IndexType::Normal {
+            if let Some(method_arg) = args.optional_arg("type") {
+                let method_name = method_arg.as_constant_literal()?;
+
+                match IndexMethod::ALL.iter().find(|method| method.as_str() == method_name) {
+                    Some(method) => index_def.method = Some(*method),
+                    None => {
+                        return Err(DatamodelError::new_attribute_validation_error(
+                            &format!(
+                                "Unknown index type `{}`. The available index types are: {}.",
+                                method_name,
+                                IndexMethod::ALL
+                                    .iter()
+                                    .map(|method| method.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            self.attribute_name(),
+                            method_arg.span(),
+                        ))
+                    }
+                }
+            }
+        }
+
         let fields = args
             .default_arg("fields")?
             .as_array()
@@ -242,6 +302,24 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
                     args.push(ast::Argument::new_string("name", &name));
                 }
 
+                if let Some(clustered) = index_def.clustered {
+                    args.push(ast::Argument::new(
+                        "clustered",
+                        ast::Expression::BooleanValue(clustered.to_string(), ast::Span::empty()),
+                    ));
+                }
+
+                if let Some(method) = index_def.method {
+                    args.push(ast::Argument::new_constant("type", method.as_str()));
+                }
+
+                if index_def.deferrable {
+                    args.push(ast::Argument::new(
+                        "deferrable",
+                        ast::Expression::BooleanValue("true".to_owned(), ast::Span::empty()),
+                    ));
+                }
+
                 ast::Attribute::new(self.attribute_name(), args)
             })
             .collect();
@@ -0,0 +1,182 @@
+use super::{super::helpers::*, default::lower_prisma_value, AttributeValidator};
+use crate::completion::ArgumentSignature;
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+use prisma_value::PrismaValue;
+use std::convert::TryFrom;
+
+/// Prismas builtin `@length` attribute.
+pub struct LengthAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for LengthAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"length"
+    }
+
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::named("min").optional(),
+            ArgumentSignature::named("max").optional(),
+        ]
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = match obj {
+            dml::Field::ScalarField(sf) if sf.field_type.scalar_type() == Some(dml::ScalarType::String) => sf,
+            _ => {
+                return self.new_attribute_validation_error(
+                    "Fields that are marked with @length must be of type String.",
+                    args.span(),
+                )
+            }
+        };
+
+        let mut bound = |name: &str| -> Result<Option<u32>, DatamodelError> {
+            match args.optional_arg(name) {
+                Some(arg) => {
+                    let value = arg
+                        .as_int()
+                        .map_err(|err| self.wrap_in_attribute_validation_error(&err))?;
+
+                    u32::try_from(value).map(Some).map_err(|_| {
+                        DatamodelError::new_attribute_validation_error(
+                            &format!("The `{}` length must be a positive number.", name),
+                            self.attribute_name(),
+                            arg.span(),
+                        )
+                    })
+                }
+                None => Ok(None),
+            }
+        };
+
+        let min = bound("min")?;
+        let max = bound("max")?;
+
+        match (min, max) {
+            (None, None) => {
+                return self.new_attribute_validation_error("At least one of `min` and `max` must be set.", args.span())
+            }
+            (Some(min), Some(max)) if min > max => {
+                return self.new_attribute_validation_error("`min` must not be larger than `max`.", args.span())
+            }
+            _ => (),
+        }
+
+        sf.validations.push(dml::FieldValidation::Length { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        field
+            .validations()
+            .iter()
+            .filter_map(|validation| match validation {
+                dml::FieldValidation::Length { min, max } => {
+                    let min = min.map(|min| PrismaValue::Int(min.into()));
+                    let max = max.map(|max| PrismaValue::Int(max.into()));
+
+                    Some(ast::Attribute::new(self.attribute_name(), lower_bounds(&min, &max)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Prismas builtin `@range` attribute.
+pub struct RangeAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for RangeAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"range"
+    }
+
+    fn arguments(&self) -> Vec<ArgumentSignature> {
+        vec![
+            ArgumentSignature::named("min").optional(),
+            ArgumentSignature::named("max").optional(),
+        ]
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let (sf, scalar_type) = match obj {
+            dml::Field::ScalarField(sf) => match sf.field_type.scalar_type() {
+                Some(scalar_type @ dml::ScalarType::Int)
+                | Some(scalar_type @ dml::ScalarType::BigInt)
+                | Some(scalar_type @ dml::ScalarType::Float)
+                | Some(scalar_type @ dml::ScalarType::Decimal) => (sf, scalar_type),
+                _ => return self.new_type_error(args.span()),
+            },
+            _ => return self.new_type_error(args.span()),
+        };
+
+        let mut bound = |name: &str| -> Result<Option<PrismaValue>, DatamodelError> {
+            match args.optional_arg(name) {
+                Some(arg) => arg
+                    .as_type(scalar_type)
+                    .map(Some)
+                    .map_err(|err| self.wrap_in_attribute_validation_error(&err)),
+                None => Ok(None),
+            }
+        };
+
+        let min = bound("min")?;
+        let max = bound("max")?;
+
+        let min_is_larger = match (&min, &max) {
+            (None, None) => {
+                return self.new_attribute_validation_error("At least one of `min` and `max` must be set.", args.span())
+            }
+            (Some(PrismaValue::Int(min)), Some(PrismaValue::Int(max)))
+            | (Some(PrismaValue::BigInt(min)), Some(PrismaValue::BigInt(max))) => min > max,
+            (Some(PrismaValue::Float(min)), Some(PrismaValue::Float(max))) => min > max,
+            _ => false,
+        };
+
+        if min_is_larger {
+            return self.new_attribute_validation_error("`min` must not be larger than `max`.", args.span());
+        }
+
+        sf.validations.push(dml::FieldValidation::Range { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        field
+            .validations()
+            .iter()
+            .filter_map(|validation| match validation {
+                dml::FieldValidation::Range { min, max } => {
+                    Some(ast::Attribute::new(self.attribute_name(), lower_bounds(min, max)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl RangeAttributeValidator {
+    fn new_type_error(&self, span: ast::Span) -> Result<(), DatamodelError> {
+        self.new_attribute_validation_error(
+            "Fields that are marked with @range must be of type Int, BigInt, Float or Decimal.",
+            span,
+        )
+    }
+}
+
+fn lower_bounds(min: &Option<PrismaValue>, max: &Option<PrismaValue>) -> Vec<ast::Argument> {
+    let mut arguments = Vec::new();
+
+    if let Some(min) = min {
+        arguments.push(ast::Argument::new("min", lower_prisma_value(min)));
+    }
+
+    if let Some(max) = max {
+        arguments.push(ast::Argument::new("max", lower_prisma_value(max)));
+    }
+
+    arguments
+}
@@ -0,0 +1,38 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@version` attribute.
+pub struct VersionAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for VersionAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"version"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        if let dml::Field::ScalarField(sf) = obj {
+            if sf.field_type.scalar_type() == Some(dml::ScalarType::Int) {
+                if sf.arity != dml::FieldArity::Required {
+                    return self.new_attribute_validation_error(
+                        "Fields that are marked with @version must be required.",
+                        args.span(),
+                    );
+                }
+
+                sf.is_version = true;
+
+                return Ok(());
+            }
+        }
+        self.new_attribute_validation_error("Fields that are marked with @version must be of type Int.", args.span())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        if field.is_version() {
+            vec![ast::Attribute::new(self.attribute_name(), Vec::new())]
+        } else {
+            vec![]
+        }
+    }
+}
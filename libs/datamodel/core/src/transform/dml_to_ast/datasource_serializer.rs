@@ -1,5 +1,7 @@
 use crate::ast;
-use crate::configuration::Datasource;
+use crate::configuration::{
+    Datasource, DateTimeMode, ForeignKeyIndexes, LintRule, ReferentialIntegrity, RetryPolicy, StringFromEnvVar,
+};
 
 pub struct DatasourceSerializer {}
 
@@ -21,14 +23,76 @@ impl DatasourceSerializer {
         let mut arguments: Vec<ast::Argument> = Vec::new();
 
         arguments.push(ast::Argument::new_string("provider", &source.active_provider));
-        match source.url.from_env_var {
-            Some(ref env_var) => {
-                let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
-                arguments.push(ast::Argument::new_function("url", "env", values));
-            }
-            None => {
-                arguments.push(ast::Argument::new_string("url", &source.url.value));
-            }
+        arguments.push(Self::lower_string_from_env_var("url", &source.url));
+
+        if let Some(direct_url) = &source.direct_url {
+            arguments.push(Self::lower_string_from_env_var("directUrl", direct_url));
+        }
+
+        let default_retry_policy = RetryPolicy::default();
+
+        if source.retry_policy.max_retries != default_retry_policy.max_retries {
+            let value = ast::Expression::NumericValue(source.retry_policy.max_retries.to_string(), ast::Span::empty());
+            arguments.push(ast::Argument::new("connectRetries", value));
+        }
+
+        if source.retry_policy.base_delay != default_retry_policy.base_delay {
+            let value = ast::Expression::NumericValue(
+                source.retry_policy.base_delay.as_millis().to_string(),
+                ast::Span::empty(),
+            );
+            arguments.push(ast::Argument::new("connectRetryDelay", value));
+        }
+
+        if !source.listen_channels.is_empty() {
+            let channels: Vec<ast::Expression> = source
+                .listen_channels
+                .iter()
+                .map(|channel| ast::Expression::StringValue(channel.to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("listenChannels", channels));
+        }
+
+        if source.date_time_mode != DateTimeMode::default() {
+            arguments.push(ast::Argument::new_string(
+                "dateTimeMode",
+                source.date_time_mode.as_str(),
+            ));
+        }
+
+        if source.referential_integrity != ReferentialIntegrity::default() {
+            arguments.push(ast::Argument::new_string(
+                "referentialIntegrity",
+                source.referential_integrity.as_str(),
+            ));
+        }
+
+        if source.foreign_key_indexes != ForeignKeyIndexes::default() {
+            arguments.push(ast::Argument::new_string(
+                "foreignKeyIndexes",
+                source.foreign_key_indexes.as_str(),
+            ));
+        }
+
+        if source.audit_log {
+            let value = ast::Expression::BooleanValue(String::from("true"), ast::Span::empty());
+            arguments.push(ast::Argument::new("auditLog", value));
+        }
+
+        if source.database_timestamps {
+            let value = ast::Expression::BooleanValue(String::from("true"), ast::Span::empty());
+            arguments.push(ast::Argument::new("databaseTimestamps", value));
+        }
+
+        if source.lint_rules != LintRule::defaults() {
+            let rules: Vec<ast::Expression> = source
+                .lint_rules
+                .iter()
+                .map(|rule| ast::Expression::StringValue(rule.as_str().to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("lint", rules));
         }
 
         if !&source.preview_features.is_empty() {
@@ -48,4 +112,14 @@ impl DatasourceSerializer {
             span: ast::Span::empty(),
         }
     }
+
+    fn lower_string_from_env_var(arg_name: &str, string_from_env: &StringFromEnvVar) -> ast::Argument {
+        match string_from_env.from_env_var {
+            Some(ref env_var) => {
+                let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
+                ast::Argument::new_function(arg_name, "env", values)
+            }
+            None => ast::Argument::new_string(arg_name, &string_from_env.value),
+        }
+    }
 }
@@ -33,6 +33,10 @@ impl<'a> LowerDmlToAst<'a> {
             tops.push(ast::Top::Enum(self.lower_enum(enm, datamodel)))
         }
 
+        for composite_type in datamodel.composite_types() {
+            tops.push(ast::Top::CompositeType(self.lower_composite_type(composite_type)))
+        }
+
         ast::SchemaAst { tops }
     }
 
@@ -72,6 +76,26 @@ impl<'a> LowerDmlToAst<'a> {
         }
     }
 
+    fn lower_composite_type(&self, composite_type: &dml::CompositeType) -> ast::CompositeType {
+        ast::CompositeType {
+            name: ast::Identifier::new(&composite_type.name),
+            fields: composite_type
+                .fields()
+                .map(|field| ast::Field {
+                    name: ast::Identifier::new(&field.name),
+                    arity: self.lower_field_arity(&field.arity),
+                    attributes: vec![],
+                    field_type: self.lower_type(&field.field_type),
+                    documentation: field.documentation.clone().map(|text| ast::Comment { text }),
+                    span: ast::Span::empty(),
+                    is_commented_out: false,
+                })
+                .collect(),
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+        }
+    }
+
     pub fn lower_field(&self, field: &dml::Field, datamodel: &dml::Datamodel) -> ast::Field {
         let mut attributes = self.attributes.field.serialize(field, datamodel);
         if let (dml::Field::ScalarField(sf), Some(datasource)) = (field, self.datasource) {
@@ -122,6 +146,7 @@ impl<'a> LowerDmlToAst<'a> {
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
+            dml::FieldType::CompositeType(name) => ast::Identifier::new(name),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
         }
     }
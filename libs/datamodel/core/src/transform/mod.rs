@@ -5,7 +5,7 @@
 //! * `dml_to_ast` contains functionality to convert a DML structure back to an AST. This is used for rendering and can not fail.
 //! * `attributes` contains the logic for validating and applying the effects of attributes like e.g. `@default`.
 //! * `helpers` contains helpers to simplify the validation of arguments and values in the AST during validation.
-mod attributes;
+pub(crate) mod attributes;
 mod helpers;
 
 pub mod ast_to_dml;
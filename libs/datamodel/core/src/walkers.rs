@@ -2,7 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, WithDatabaseName,
+        CompositeType, Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField,
+        WithDatabaseName,
     },
     NativeTypeInstance, RelationField,
 };
@@ -108,6 +109,18 @@ impl<'a> ModelWalker<'a> {
         &self.model.name
     }
 
+    pub fn id_clustered(&self) -> Option<bool> {
+        self.model.id_clustered
+    }
+
+    pub fn engine(&self) -> Option<&'a str> {
+        self.model.engine.as_deref()
+    }
+
+    pub fn row_format(&self) -> Option<&'a str> {
+        self.model.row_format.as_deref()
+    }
+
     pub fn id_fields<'b>(&'b self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'b {
         // Single-id models
         self.model
@@ -168,6 +181,9 @@ impl<'a> ScalarFieldWalker<'a> {
             }),
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
+            FieldType::CompositeType(name) => {
+                TypeWalker::CompositeType(self.datamodel.find_composite_type(name).unwrap())
+            }
             _ => TypeWalker::Other,
         }
     }
@@ -184,6 +200,10 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.is_unique
     }
 
+    pub fn is_updated_at(&self) -> bool {
+        self.field.is_updated_at
+    }
+
     pub fn model(&self) -> ModelWalker<'a> {
         ModelWalker {
             model: self.model,
@@ -201,6 +221,7 @@ pub enum TypeWalker<'a> {
     Enum(EnumWalker<'a>),
     Base(ScalarType),
     NativeType(ScalarType, &'a NativeTypeInstance),
+    CompositeType(&'a CompositeType),
     Other,
 }
 
@@ -216,6 +237,10 @@ impl<'a> TypeWalker<'a> {
         matches!(self, TypeWalker::Base(ScalarType::Int))
     }
 
+    pub fn is_bigint(&self) -> bool {
+        matches!(self, TypeWalker::Base(ScalarType::BigInt))
+    }
+
     pub fn is_json(&self) -> bool {
         matches!(self, TypeWalker::Base(ScalarType::Json))
     }
@@ -233,6 +258,10 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.arity
     }
 
+    pub fn is_deferrable(&self) -> bool {
+        self.field.relation_info.deferrable
+    }
+
     pub fn is_one_to_one(&self) -> bool {
         self.field.is_singular() && self.opposite_side().field.is_singular()
     }
@@ -400,7 +429,16 @@ impl<'a> ManyToManyRelationWalker<'a> {
         self.field_a.relation_name()
     }
 
+    /// The name of the join table: the `map` argument of the relation if one side has it,
+    /// `_<relation name>` otherwise.
     pub fn table_name(&self) -> String {
-        format!("_{}", self.relation_name())
+        self.field_a
+            .field
+            .relation_info
+            .map
+            .as_ref()
+            .or_else(|| self.field_b.field.relation_info.map.as_ref())
+            .cloned()
+            .unwrap_or_else(|| format!("_{}", self.relation_name()))
     }
 }
@@ -131,6 +131,42 @@ fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
     ));
 }
 
+#[test]
+fn must_error_if_nanoid_function_has_an_invalid_length() {
+    let dml = r#"
+    model Model {
+        id  Int    @id
+        foo String @default(nanoid(0))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `nanoid()` takes an optional length between 1 and 255.",
+        "default",
+        Span::new(70, 79),
+    ));
+}
+
+#[test]
+fn must_error_if_ulid_function_is_used_for_fields_that_are_not_string() {
+    let dml = r#"
+    model Model {
+        id  Int    @id
+        foo Int    @default(ulid())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `ulid()` can not be used on fields of type `Int`.",
+        "default",
+        Span::new(70, 76),
+    ));
+}
+
 #[test]
 fn must_error_if_default_value_for_enum_is_not_valid() {
     let dml = r#"
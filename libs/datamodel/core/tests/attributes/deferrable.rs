@@ -0,0 +1,92 @@
+use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn deferrable_constraints_must_work_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id   Int    @id
+        name String
+        posts Post[]
+
+        @@unique([name], deferrable: true)
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], deferrable: true)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema.assert_has_model("User").assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["name".to_string()],
+        tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: true,
+        method: None,
+    });
+
+    let author = schema.assert_has_model("Post").assert_has_relation_field("author");
+
+    assert!(author.relation_info.deferrable);
+
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered.contains("@@unique([name], deferrable: true)"));
+    assert!(rendered.contains("@relation(fields: [authorId], references: [id], deferrable: true)"));
+}
+
+#[test]
+fn deferrable_constraints_must_error_on_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url      = "mysql://"
+    }
+
+    model User {
+        id   Int    @id
+        name String
+
+        @@unique([name], deferrable: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The `deferrable` argument is only supported on PostgreSQL.",
+        "User",
+        Span::new(89, 195),
+    ));
+}
+
+#[test]
+fn deferrable_must_error_on_the_side_of_the_relation_without_fields() {
+    let dml = r#"
+    model User {
+        id   Int   @id
+        post Post? @relation(deferrable: true)
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The `deferrable` argument can only be set on the side of the relation that defines `fields`.",
+    );
+}
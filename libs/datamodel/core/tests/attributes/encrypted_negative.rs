@@ -0,0 +1,55 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn should_fail_if_field_type_is_int() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        token Int @encrypted
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @encrypted must be of type String or Bytes.",
+        "encrypted",
+        Span::new(59, 68),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_unique() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String @unique @encrypted
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "Fields that are marked with @encrypted can not be part of an id, a unique constraint, an index or a relation.",
+        "User",
+        "email",
+        Span::new(51, 83),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_part_of_an_index() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String @encrypted
+
+        @@index([email])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error validating field `email` in model `User`: Fields that are marked with @encrypted can not be part of an id, a unique constraint, an index or a relation.");
+}
@@ -0,0 +1,39 @@
+use crate::common::*;
+use datamodel::ScalarType;
+
+#[test]
+fn should_apply_encrypted_attribute() {
+    let dml = r#"
+    model User {
+        id     Int     @id
+        ssn    String  @encrypted
+        secret Bytes?  @encrypted
+        name   String
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    let ssn_field = user_model
+        .assert_has_scalar_field("ssn")
+        .assert_base_type(&ScalarType::String);
+
+    assert!(ssn_field.is_encrypted);
+    assert!(user_model.assert_has_scalar_field("secret").is_encrypted);
+    assert!(!user_model.assert_has_scalar_field("name").is_encrypted);
+}
+
+#[test]
+fn encrypted_attribute_should_be_rendered() {
+    let dml = r#"model User {
+  id  Int    @id
+  ssn String @encrypted
+}
+"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema);
+
+    assert_eq!(rendered, dml);
+}
@@ -1,4 +1,4 @@
-use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexMethod, IndexType};
 
 use crate::common::*;
 
@@ -20,6 +20,9 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -45,6 +48,9 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -91,6 +97,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -126,12 +135,18 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -222,12 +237,18 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -265,3 +286,154 @@ fn index_attributes_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema)).is_ok());
 }
+
+#[test]
+fn the_clustered_argument_must_work_on_sql_server() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url      = "sqlserver://"
+    }
+
+    model User {
+        firstName String
+        lastName  String
+        age       Int
+
+        @@id([firstName, lastName], clustered: false)
+        @@index([age], clustered: true)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["age".to_string()],
+        tpe: IndexType::Normal,
+        clustered: Some(true),
+        deferrable: false,
+        method: None,
+    });
+
+    assert_eq!(user_model.id_clustered, Some(false));
+    assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema)).is_ok());
+}
+
+#[test]
+fn the_clustered_argument_must_error_on_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id  Int @id
+        age Int
+
+        @@index([age], clustered: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The `clustered` argument is only supported on SQL Server.",
+        "User",
+        Span::new(99, 194),
+    ));
+}
+
+#[test]
+fn only_one_clustered_index_must_be_allowed() {
+    let dml = r#"
+    model User {
+        age  Int
+        name String
+
+        @@id([age, name], clustered: true)
+        @@unique([name], clustered: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "A model can have at most one clustered index or primary key.",
+        "User",
+        Span::new(5, 146),
+    ));
+}
+
+#[test]
+fn the_type_argument_must_work_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id   Int  @id
+        data Json
+
+        @@index([data], type: Gin)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema.assert_has_model("User").assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["data".to_string()],
+        tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: Some(IndexMethod::Gin),
+    });
+
+    assert!(render_datamodel_to_string(&schema).contains("@@index([data], type: Gin)"));
+}
+
+#[test]
+fn the_type_argument_must_error_on_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url      = "mysql://"
+    }
+
+    model User {
+        id   Int  @id
+        data Json
+
+        @@index([data], type: Gin)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The `type` argument of `@@index` is only supported on PostgreSQL.",
+        "User",
+        Span::new(89, 183),
+    ));
+}
+
+#[test]
+fn unknown_index_types_must_error() {
+    let dml = r#"
+    model User {
+        id  Int @id
+        age Int
+
+        @@index([age], type: Fancy)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@index\": Unknown index type `Fancy`. The available index types are: BTree, Hash, Gist, SpGist, Gin, Brin.",
+    );
+}
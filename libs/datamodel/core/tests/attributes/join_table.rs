@@ -0,0 +1,60 @@
+use datamodel::render_datamodel_to_string;
+
+use crate::common::*;
+
+#[test]
+fn join_table_attribute_must_work() {
+    let dml = r#"
+    model User {
+        id          Int          @id
+        memberships Membership[]
+    }
+
+    model Team {
+        id          Int          @id
+        memberships Membership[]
+    }
+
+    model Membership {
+        userId Int
+        teamId Int
+        role   String
+        user   User   @relation(fields: [userId], references: [id])
+        team   Team   @relation(fields: [teamId], references: [id])
+
+        @@id([userId, teamId])
+        @@joinTable
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert!(schema.assert_has_model("Membership").is_join_table);
+    assert!(!schema.assert_has_model("User").is_join_table);
+
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered.contains("@@joinTable"));
+}
+
+#[test]
+fn join_table_must_error_without_two_inlined_relations() {
+    let dml = r#"
+    model User {
+        id          Int          @id
+        memberships Membership[]
+    }
+
+    model Membership {
+        id     Int    @id
+        userId Int
+        user   User   @relation(fields: [userId], references: [id])
+
+        @@joinTable
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error validating model \"Membership\": A model marked with @@joinTable must have exactly two relation fields, both referencing a single record through `fields` and `references`.");
+}
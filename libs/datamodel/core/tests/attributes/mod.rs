@@ -1,19 +1,29 @@
 pub mod builtin_attributes;
 pub mod default_negative;
 pub mod default_positive;
+pub mod deferrable;
+pub mod encrypted_negative;
+pub mod encrypted_positive;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
+pub mod join_table;
 pub mod map;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
+pub mod relations_map;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod table_options;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod validations_negative;
+pub mod validations_positive;
+pub mod version_negative;
+pub mod version_positive;
 
 pub mod arg_parsing;
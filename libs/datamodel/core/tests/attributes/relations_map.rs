@@ -0,0 +1,112 @@
+use datamodel::{render_datamodel_to_string, walkers::walk_relations};
+
+use crate::common::*;
+
+#[test]
+fn implicit_many_to_many_relations_can_map_their_join_table() {
+    let dml = r#"
+    model Post {
+        id   Int   @id
+        tags Tag[] @relation("tags", map: "post_tags")
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[] @relation("tags")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let tags = schema.assert_has_model("Post").assert_has_relation_field("tags");
+    assert_eq!(tags.relation_info.map.as_deref(), Some("post_tags"));
+
+    let m2m = walk_relations(&schema)
+        .find_map(|relation| relation.as_m2m())
+        .expect("many to many relation");
+    assert_eq!(m2m.table_name(), "post_tags");
+
+    let rendered = render_datamodel_to_string(&schema);
+    assert!(rendered.contains(r#"@relation("tags", map: "post_tags")"#));
+}
+
+#[test]
+fn unmapped_join_tables_keep_the_default_name() {
+    let dml = r#"
+    model Post {
+        id   Int   @id
+        tags Tag[]
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[]
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let m2m = walk_relations(&schema)
+        .find_map(|relation| relation.as_m2m())
+        .expect("many to many relation");
+    assert_eq!(m2m.table_name(), "_PostToTag");
+}
+
+#[test]
+fn mapping_the_join_table_must_error_on_one_to_many_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(map: "user_posts")
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The relation field `posts` on Model `User` specifies the `map` argument in the @relation attribute. Only implicit many to many relations can map the name of their join table.",
+    );
+}
+
+#[test]
+fn mapping_the_join_table_to_different_names_on_both_sides_must_error() {
+    let dml = r#"
+    model Post {
+        id   Int   @id
+        tags Tag[] @relation("tags", map: "post_tags")
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[] @relation("tags", map: "tags_posts")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message_at(
+        0,
+        "Error parsing attribute \"@relation\": The relation field `tags` on Model `Post` maps the join table to `post_tags`, but the related field `posts` on Model `Tag` maps it to `tags_posts`. Both sides of the relation must use the same name.",
+    );
+}
+
+#[test]
+fn mapping_the_join_table_to_the_name_of_a_model_table_must_error() {
+    let dml = r#"
+    model Post {
+        id   Int   @id
+        tags Tag[] @relation("tags", map: "Tag")
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[] @relation("tags")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The join table of the relation field `tags` on Model `Post` is mapped to `Tag`, which is already the name of the table of Model `Tag`.",
+    );
+}
@@ -748,3 +748,92 @@ fn must_error_when_many_to_many_is_not_possible_due_to_missing_id() {
         ),
     );
 }
+
+#[test]
+fn compound_foreign_keys_can_reference_a_compound_unique_constraint() {
+    let datasources = &[
+        ("postgresql", "postgresql://"),
+        ("mysql", "mysql://"),
+        ("sqlite", "file:dev.db"),
+        ("sqlserver", "sqlserver://"),
+    ];
+
+    for (provider, url) in datasources {
+        let dml = format!(
+            r#"
+            datasource db {{
+                provider = "{}"
+                url = "{}"
+            }}
+
+            model User {{
+                id        Int    @id
+                firstName String
+                lastName  String
+                posts     Post[]
+
+                @@unique([firstName, lastName])
+            }}
+
+            model Post {{
+                id            Int    @id
+                userFirstName String
+                userLastName  String
+                user          User   @relation(fields: [userFirstName, userLastName], references: [firstName, lastName])
+            }}
+            "#,
+            provider, url
+        );
+
+        let schema = parse(&dml);
+        schema
+            .assert_has_model("Post")
+            .assert_has_relation_field("user")
+            .assert_relation_to("User")
+            .assert_relation_base_fields(&["userFirstName", "userLastName"])
+            .assert_relation_referenced_fields(&["firstName", "lastName"]);
+    }
+}
+
+#[test]
+fn compound_foreign_keys_can_reference_a_compound_unique_constraint_in_a_different_order() {
+    let datasources = &[
+        ("postgresql", "postgresql://"),
+        ("mysql", "mysql://"),
+        ("sqlite", "file:dev.db"),
+    ];
+
+    for (provider, url) in datasources {
+        let dml = format!(
+            r#"
+            datasource db {{
+                provider = "{}"
+                url = "{}"
+            }}
+
+            model User {{
+                id        Int    @id
+                firstName String
+                lastName  String
+                posts     Post[]
+
+                @@unique([firstName, lastName])
+            }}
+
+            model Post {{
+                id            Int    @id
+                userFirstName String
+                userLastName  String
+                user          User   @relation(fields: [userLastName, userFirstName], references: [lastName, firstName])
+            }}
+            "#,
+            provider, url
+        );
+
+        let schema = parse(&dml);
+        schema
+            .assert_has_model("Post")
+            .assert_has_relation_field("user")
+            .assert_relation_referenced_fields(&["lastName", "firstName"]);
+    }
+}
@@ -0,0 +1,93 @@
+use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string};
+
+use crate::common::*;
+
+#[test]
+fn table_options_must_work_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url      = "mysql://"
+    }
+
+    model User {
+        id Int @id
+
+        @@engine("MyISAM")
+        @@rowFormat("COMPRESSED")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    assert_eq!(user_model.engine.as_deref(), Some("MyISAM"));
+    assert_eq!(user_model.row_format.as_deref(), Some("COMPRESSED"));
+
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered.contains(r#"@@engine("MyISAM")"#));
+    assert!(rendered.contains(r#"@@rowFormat("COMPRESSED")"#));
+}
+
+#[test]
+fn table_options_must_error_on_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id Int @id
+
+        @@engine("MyISAM")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The `@@engine` and `@@rowFormat` attributes are only supported on MySQL.",
+        "User",
+        Span::new(99, 164),
+    ));
+}
+
+#[test]
+fn unknown_row_formats_must_error() {
+    let dml = r#"
+    model User {
+        id Int @id
+
+        @@rowFormat("SQUASHED")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Unknown row format `SQUASHED`. The available row formats are: DEFAULT, DYNAMIC, FIXED, COMPRESSED, REDUNDANT, COMPACT.",
+        "rowFormat",
+        Span::new(48, 69),
+    ));
+}
+
+#[test]
+fn engines_that_are_not_identifiers_must_error() {
+    let dml = r#"
+    model User {
+        id Int @id
+
+        @@engine("InnoDB; DROP TABLE User")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Invalid storage engine `InnoDB; DROP TABLE User`. Engine names start with a letter and only contain letters, digits and underscores.",
+        "engine",
+        Span::new(48, 81),
+    ));
+}
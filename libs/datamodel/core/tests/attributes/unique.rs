@@ -22,6 +22,9 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -163,6 +166,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -186,12 +192,18 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -217,6 +229,9 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     });
 }
 
@@ -0,0 +1,75 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_if_length_is_used_on_a_non_string_field() {
+    let dml = r#"
+    model User {
+        id  Int @id
+        age Int @length(max: 3)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@length\": Fields that are marked with @length must be of type String.",
+    );
+}
+
+#[test]
+fn should_fail_if_range_is_used_on_a_non_numeric_field() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @range(min: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@range\": Fields that are marked with @range must be of type Int, BigInt, Float or Decimal.",
+    );
+}
+
+#[test]
+fn should_fail_if_no_bound_is_set() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @length
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@length\": At least one of `min` and `max` must be set.");
+}
+
+#[test]
+fn should_fail_if_min_is_larger_than_max() {
+    let dml = r#"
+    model User {
+        id  Int @id
+        age Int @range(min: 10, max: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@range\": `min` must not be larger than `max`.");
+}
+
+#[test]
+fn should_fail_if_a_length_is_negative() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @length(min: -1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@length\": The `min` length must be a positive number.");
+}
@@ -0,0 +1,84 @@
+use crate::common::*;
+use datamodel::{FieldValidation, ScalarType};
+use prisma_value::PrismaValue;
+use serde_json::json;
+
+#[test]
+fn should_apply_validation_attributes() {
+    let dml = r#"
+    model User {
+        id    Int     @id
+        name  String  @length(min: 1, max: 20)
+        bio   String? @length(max: 200)
+        age   Int     @range(min: 0, max: 150)
+        score Float   @range(min: 0.5)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    let name_field = user_model
+        .assert_has_scalar_field("name")
+        .assert_base_type(&ScalarType::String);
+
+    assert_eq!(
+        name_field.validations,
+        vec![FieldValidation::Length {
+            min: Some(1),
+            max: Some(20)
+        }]
+    );
+    assert_eq!(
+        user_model.assert_has_scalar_field("bio").validations,
+        vec![FieldValidation::Length {
+            min: None,
+            max: Some(200)
+        }]
+    );
+    assert_eq!(
+        user_model.assert_has_scalar_field("age").validations,
+        vec![FieldValidation::Range {
+            min: Some(PrismaValue::Int(0)),
+            max: Some(PrismaValue::Int(150))
+        }]
+    );
+    assert!(user_model.assert_has_scalar_field("id").validations.is_empty());
+}
+
+#[test]
+fn validation_attributes_should_be_rendered() {
+    let dml = r#"model User {
+  id    Int    @id @range(min: 1)
+  name  String @length(min: 1, max: 20)
+  score Float  @range(min: 0.5, max: 10)
+}
+"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema);
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn validation_attributes_should_be_in_the_dmmf() {
+    let dml = r#"
+    model User {
+        id   Int    @id @range(max: 1000)
+        name String @length(min: 1, max: 20)
+        bio  String
+    }
+    "#;
+
+    let schema = parse(dml);
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&schema);
+    let fields = &dmmf["models"][0]["fields"];
+
+    assert_eq!(fields[0]["validations"], json!([{ "name": "range", "max": 1000.0 }]));
+    assert_eq!(
+        fields[1]["validations"],
+        json!([{ "name": "length", "min": 1, "max": 20 }])
+    );
+    assert!(fields[2].get("validations").is_none());
+}
@@ -0,0 +1,57 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn should_fail_if_field_type_is_string() {
+    let dml = r#"
+    model User {
+        id      Int    @id
+        version String @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be of type Int.",
+        "version",
+        Span::new(69, 76),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_optional() {
+    let dml = r#"
+    model User {
+        id      Int  @id
+        version Int? @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be required.",
+        "version",
+        Span::new(65, 72),
+    ));
+}
+
+#[test]
+fn should_fail_if_a_model_has_multiple_version_fields() {
+    let dml = r#"
+    model User {
+        id       Int @id
+        version  Int @version
+        revision Int @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "A model can only have one field marked with @version.",
+        "User",
+        Span::new(5, 108),
+    ));
+}
@@ -0,0 +1,35 @@
+use crate::common::*;
+use datamodel::ScalarType;
+
+#[test]
+fn should_apply_version_attribute() {
+    let dml = r#"
+    model User {
+        id      Int @id
+        version Int @version @default(1)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    let version_field = user_model
+        .assert_has_scalar_field("version")
+        .assert_base_type(&ScalarType::Int);
+
+    assert!(version_field.is_version);
+    assert!(!user_model.assert_has_scalar_field("id").is_version);
+}
+
+#[test]
+fn version_attribute_should_be_rendered() {
+    let dml = r#"model User {
+  id      Int @id
+  version Int @version
+}
+"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema);
+
+    assert_eq!(rendered, dml);
+}
@@ -0,0 +1,109 @@
+use datamodel::completion::{completions, ArgumentSignature, CompletionItemKind};
+
+fn labels_at_cursor(schema: &str) -> Vec<String> {
+    let offset = schema.find('|').unwrap();
+    let schema = schema.replacen('|', "", 1);
+
+    completions(&schema, offset)
+        .into_iter()
+        .map(|item| item.label)
+        .collect()
+}
+
+#[test]
+fn field_attributes_are_completed() {
+    let dml = r#"
+    model User {
+        id Int @|
+    }
+    "#;
+
+    assert_eq!(
+        labels_at_cursor(dml),
+        &[
+            "default",
+            "encrypted",
+            "id",
+            "map",
+            "relation",
+            "unique",
+            "updatedAt",
+            "version"
+        ]
+    );
+}
+
+#[test]
+fn block_attributes_are_completed_with_their_signatures() {
+    let dml = r#"
+    model User {
+        id Int @id
+        @@|
+    }
+    "#;
+
+    let offset = dml.find('|').unwrap();
+    let items = completions(&dml.replacen('|', "", 1), offset);
+    let index = items.iter().find(|item| item.label == "index").unwrap();
+
+    assert_eq!(index.kind, CompletionItemKind::BlockAttribute);
+    assert_eq!(index.detail, "@@index(fields, name?, clustered?, type?)");
+    assert_eq!(index.arguments[0], ArgumentSignature::default_arg("fields"));
+}
+
+#[test]
+fn enum_attributes_are_completed() {
+    let dml = r#"
+    enum Role {
+        USER @|
+    }
+    "#;
+
+    assert_eq!(labels_at_cursor(dml), &["map"]);
+}
+
+#[test]
+fn native_types_of_the_active_connector_are_completed() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = env("DATABASE_URL")
+    }
+
+    model User {
+        id   Int @id
+        name String @db.|
+    }
+    "#;
+
+    let labels = labels_at_cursor(dml);
+
+    assert!(labels.contains(&"VarChar".to_owned()));
+    assert!(labels.contains(&"Text".to_owned()));
+    assert!(!labels.contains(&"Integer".to_owned()));
+}
+
+#[test]
+fn missing_named_arguments_are_completed() {
+    let dml = r#"
+    model User {
+        id    Int
+        email String
+        @@index([email], name: "email_idx", |
+    }
+    "#;
+
+    assert_eq!(labels_at_cursor(dml), &["clustered", "type"]);
+}
+
+#[test]
+fn nothing_is_completed_outside_of_models_and_enums() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = env("DATABASE_URL") @|
+    }
+    "#;
+
+    assert!(labels_at_cursor(dml).is_empty());
+}
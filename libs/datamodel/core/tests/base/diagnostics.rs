@@ -0,0 +1,54 @@
+use crate::common::*;
+use datamodel::diagnostics::StructuredDiagnostic;
+use serde_json::json;
+
+#[test]
+fn errors_are_structured_with_code_span_and_suggestion() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let diagnostics = parse_error(dml).to_structured();
+
+    assert_eq!(
+        diagnostics,
+        &[StructuredDiagnostic {
+            code: "E009",
+            start: 53,
+            end: 57,
+            text: "The model \"User\" cannot be defined because a model with that name already exists.".to_owned(),
+            is_warning: false,
+            suggestion: Some("Rename one of the definitions named \"User\".".to_owned()),
+        }]
+    );
+}
+
+#[test]
+fn structured_diagnostics_serialize_to_camel_case() {
+    let dml = r#"
+    model User {
+        id Int @id @unknown
+    }
+    "#;
+
+    let diagnostics = parse_error(dml).to_structured();
+    let json = serde_json::to_value(&diagnostics).unwrap();
+
+    assert_eq!(json[0]["code"], json!("E018"));
+    assert_eq!(json[0]["text"], json!("Attribute not known: \"@unknown\"."));
+    assert_eq!(json[0]["isWarning"], json!(false));
+    assert_eq!(
+        json[0]["suggestion"],
+        json!("Remove \"@unknown\" or check it for typos.")
+    );
+
+    let start = json[0]["start"].as_u64().unwrap() as usize;
+    let end = json[0]["end"].as_u64().unwrap() as usize;
+
+    assert!(dml[start..end].contains("unknown"));
+}
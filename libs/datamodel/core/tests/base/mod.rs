@@ -2,5 +2,7 @@ pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
 pub mod comments;
+pub mod completion;
+pub mod diagnostics;
 pub mod duplicates;
 pub mod preview_features;
@@ -230,6 +230,31 @@ fn test_auto_increment_on_non_primary_columns(providers: &[&str], must_error: bo
     test_capability_support(providers, must_error, dml, error_msg);
 }
 
+#[test]
+fn identifier_lengths_must_be_within_the_limit_of_all_specified_providers() {
+    // Postgres allows 63 characters, MySQL 64 and SQL Server 128. SQLite has no limit.
+    test_identifier_length(&["postgres", "sqlite", "mysql"], true);
+    test_identifier_length(&["postgres", "mysql"], true);
+    test_identifier_length(&["postgres"], true);
+
+    test_identifier_length(&["mysql", "sqlite"], false);
+    test_identifier_length(&["mysql"], false);
+    test_identifier_length(&["sqlserver"], false);
+    test_identifier_length(&["sqlite"], false);
+}
+
+fn test_identifier_length(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id   Int    @id
+      name String @map("a_column_name_that_is_exactly_sixty_four_characters_long_xxxxxxx")
+    }
+    "#;
+
+    let error_msg = "Error validating field `name` in model `Todo`: The column name `a_column_name_that_is_exactly_sixty_four_characters_long_xxxxxxx` is 64 characters long, the maximum identifier length on this database is 63.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
 fn test_capability_support(providers: &[&str], must_error: bool, datamodel: &str, error_msg: &str) {
     let provider_strings: Vec<_> = providers.iter().map(|x| format!("\"{}\"", x)).collect();
     let first_provider = providers.first().unwrap();
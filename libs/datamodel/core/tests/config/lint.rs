@@ -0,0 +1,117 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::*};
+
+#[test]
+fn lint_rules_are_reported_as_warnings() {
+    let schema = r#"datasource db {
+  provider = "postgresql"
+  url = "postgresql://localhost:5432"
+  lint = ["unindexedForeignKeys", "modelNaming", "updatedAt"]
+}
+
+model user_profile {
+  id        Int      @id
+  updatedAt DateTime @updatedAt
+  posts     Post[]
+}
+
+model Post {
+  id        Int          @id
+  authorId  Int
+  author    user_profile @relation(fields: [authorId], references: [id])
+  updatedAt DateTime     @updatedAt
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert_eq!(
+        res.warnings,
+        &[
+            DatamodelWarning::new_model_naming_warning("user_profile", "UserProfile", false, Span::new(151, 163)),
+            DatamodelWarning::new_unindexed_foreign_key_warning(
+                "Post",
+                "author",
+                &["authorId".to_owned()],
+                Span::new(305, 311)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn foreign_keys_are_indexed_implicitly_on_mysql() {
+    let schema = r#"datasource db {
+  provider = "mysql"
+  url = "mysql://localhost:3306"
+  lint = ["unindexedForeignKeys", "updatedAt"]
+}
+
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id        Int      @id
+  authorId  Int
+  author    User     @relation(fields: [authorId], references: [id])
+  updatedAt DateTime @updatedAt
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert_eq!(
+        res.warnings,
+        &[DatamodelWarning::new_missing_updated_at_warning(
+            "User",
+            Span::new(126, 130)
+        )]
+    );
+}
+
+#[test]
+fn foreign_keys_covered_by_the_start_of_an_index_are_indexed() {
+    let schema = r#"datasource db {
+  provider = "postgresql"
+  url = "postgresql://localhost:5432"
+  lint = ["unindexedForeignKeys"]
+}
+
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+
+  @@index([authorId, id])
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+}
+
+#[test]
+fn must_error_for_unknown_lint_rules() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost:5432"
+          lint = ["modelNaming", "camelCase"]
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "Unknown lint rule \"camelCase\". The `lint` argument must only contain \"unindexedForeignKeys\", \"modelNaming\", \"updatedAt\", \"reservedWords\".",
+        "ds",
+        Span::new(122, 150),
+    ));
+}
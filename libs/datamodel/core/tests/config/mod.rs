@@ -1,4 +1,5 @@
 pub mod datasources_to_mcf;
 pub mod generators;
+mod lint;
 mod nice_warnings;
 pub mod sources;
@@ -51,3 +51,64 @@ fn nice_warning_for_provider_array_deprecation_on_single_element_in_array() {
             29, 41,
         )));
 }
+
+#[test]
+fn nice_warning_for_reserved_words_as_table_and_column_names() {
+    let schema = r#"datasource db {
+  provider = "mysql"
+  url = "mysql://localhost:3306"
+}
+
+model Order {
+  id  Int    @id
+  key String
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert_eq!(
+        res.warnings,
+        &[
+            DatamodelWarning::new_reserved_word_warning("table", "Order", "@@map", Span::new(79, 84)),
+            DatamodelWarning::new_reserved_word_warning("column", "key", "@map", Span::new(106, 109)),
+        ]
+    );
+}
+
+#[test]
+fn no_warning_for_reserved_words_that_are_mapped_explicitly() {
+    let schema = r#"datasource db {
+  provider = "postgres"
+  url = "postgres://localhost:5432"
+}
+
+model User {
+  id    Int    @id
+  order String @map("order")
+
+  @@map("user")
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+}
+
+#[test]
+fn no_warning_for_words_that_are_only_reserved_on_other_databases() {
+    let schema = r#"datasource db {
+  provider = "mysql"
+  url = "mysql://localhost:3306"
+}
+
+model User {
+  id Int @id
+}
+"#;
+
+    let res = parse_with_diagnostics(schema);
+
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+}
@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError, StringFromEnvVar};
+use datamodel::{
+    ast::Span, diagnostics::DatamodelError, DateTimeMode, ForeignKeyIndexes, ReferentialIntegrity, SslAcceptMode,
+    SslMode, StringFromEnvVar, TlsOptions,
+};
 use pretty_assertions::assert_eq;
 use serial_test::serial;
 
@@ -298,6 +301,472 @@ fn must_succeed_with_overrides() {
     });
 }
 
+#[test]
+#[serial]
+fn must_load_direct_url_from_env_var() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://pgbouncer"
+          directUrl = env("DIRECT_DATABASE_URL")
+        }
+    "#;
+    std::env::set_var("DIRECT_DATABASE_URL", "postgres://hostfoo");
+
+    let config = parse_configuration(schema);
+    let data_source = config.datasources.first().unwrap();
+
+    data_source.assert_url(StringFromEnvVar {
+        from_env_var: None,
+        value: "postgres://pgbouncer".to_string(),
+    });
+    assert_eq!(
+        data_source.direct_url_or_url(),
+        &StringFromEnvVar {
+            from_env_var: Some("DIRECT_DATABASE_URL".to_string()),
+            value: "postgres://hostfoo".to_string(),
+        }
+    );
+
+    std::env::remove_var("DIRECT_DATABASE_URL");
+}
+
+#[test]
+fn direct_url_falls_back_to_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://hostfoo"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let data_source = config.datasources.first().unwrap();
+
+    assert!(data_source.direct_url.is_none());
+    assert_eq!(data_source.direct_url_or_url(), data_source.url());
+}
+
+#[test]
+fn must_error_if_wrong_protocol_is_used_for_direct_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://hostfoo"
+          directUrl = "mysql://hostbar"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `ds` must start with the protocol `postgresql://`.",
+        "ds",
+        Span::new(118, 135),
+    ));
+}
+
+#[test]
+fn must_ignore_direct_url_when_an_override_was_provided() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://hostfoo"
+          directUrl = "postgres://hostbaz"
+        }
+    "#;
+
+    let url = "postgres://hostbar";
+    let overrides = vec![("ds".to_string(), url.to_string())];
+    let config = parse_configuration_with_url_overrides(schema, overrides);
+    let data_source = config.datasources.first().unwrap();
+
+    assert!(data_source.direct_url.is_none());
+    data_source.assert_url(StringFromEnvVar {
+        from_env_var: None,
+        value: url.to_string(),
+    });
+}
+
+#[test]
+fn must_parse_tls_options_from_the_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db?sslmode=require&sslcert=./ca.pem&sslidentity=./client.p12&sslpassword=pw&sslaccept=strict"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let tls_options = config.datasources.first().unwrap().tls_options().unwrap();
+
+    assert_eq!(
+        tls_options,
+        TlsOptions {
+            mode: Some(SslMode::Require),
+            root_cert_path: Some("./ca.pem".to_string()),
+            client_identity_path: Some("./client.p12".to_string()),
+            client_identity_password: Some("pw".to_string()),
+            accept: Some(SslAcceptMode::Strict),
+        }
+    );
+}
+
+#[test]
+fn relative_tls_certificate_paths_are_resolved_from_the_schema_directory() {
+    let tls_options =
+        TlsOptions::from_connection_string("postgresql://localhost/db?sslcert=./ca.pem&sslidentity=/etc/client.p12")
+            .unwrap()
+            .relative_to(std::path::Path::new("/app/prisma"));
+
+    assert_eq!(
+        tls_options.root_cert_path.map(std::path::PathBuf::from),
+        Some(std::path::Path::new("/app/prisma").join("./ca.pem"))
+    );
+    assert_eq!(tls_options.client_identity_path.as_deref(), Some("/etc/client.p12"));
+}
+
+#[test]
+fn must_parse_tls_options_from_the_direct_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://pgbouncer/db?sslmode=disable"
+          directUrl = "postgresql://10.0.0.1/db?sslmode=require"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let tls_options = config.datasources.first().unwrap().tls_options().unwrap();
+
+    assert_eq!(tls_options.mode, Some(SslMode::Require));
+}
+
+#[test]
+fn must_error_for_sslservername() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://10.0.0.1/db?sslservername=db.example.com"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `ds` has invalid TLS options: The `sslservername` parameter is not supported, the server certificate is verified against the host of the URL.",
+        "ds",
+        Span::new(75, 130),
+    ));
+}
+
+#[test]
+fn must_error_for_invalid_sslmode() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db?sslmode=verify-full"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `ds` has invalid TLS options: Invalid value `verify-full` for the `sslmode` parameter. Expected one of: disable, prefer, require.",
+        "ds",
+        Span::new(75, 122),
+    ));
+}
+
+#[test]
+fn must_error_for_sslpassword_without_sslidentity() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db?sslpassword=pw"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `ds` has invalid TLS options: The `sslpassword` parameter requires a client identity to be provided with the `sslidentity` parameter.",
+        "ds",
+        Span::new(70, 107),
+    ));
+}
+
+#[test]
+fn must_parse_the_connection_retry_policy() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          connectRetries = 5
+          connectRetryDelay = 250
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let retry_policy = config.datasources.first().unwrap().retry_policy;
+
+    assert_eq!(retry_policy.max_retries, 5);
+    assert_eq!(retry_policy.base_delay, std::time::Duration::from_millis(250));
+    assert!(retry_policy.should_retry(4));
+    assert!(!retry_policy.should_retry(5));
+    assert!(retry_policy.delay_for_attempt(2) <= std::time::Duration::from_millis(1000));
+    assert!(retry_policy.delay_for_attempt(2) >= std::time::Duration::from_millis(500));
+}
+
+#[test]
+fn must_not_retry_by_default() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let retry_policy = config.datasources.first().unwrap().retry_policy;
+
+    assert!(!retry_policy.should_retry(0));
+}
+
+#[test]
+fn must_error_for_negative_connect_retries() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          connectRetries = -1
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `connectRetries` argument must be a non-negative integer.",
+        "ds",
+        Span::new(130, 132),
+    ));
+}
+
+#[test]
+fn must_parse_listen_channels() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          listenChannels = ["events", "cache_invalidation"]
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources.first().unwrap().listen_channels,
+        vec!["events".to_owned(), "cache_invalidation".to_owned()]
+    );
+}
+
+#[test]
+fn must_error_for_listen_channels_on_mysql() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+          listenChannels = ["events"]
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `listenChannels` argument is only supported on PostgreSQL.",
+        "ds",
+        Span::new(120, 130),
+    ));
+}
+
+#[test]
+fn must_parse_the_date_time_mode() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+          dateTimeMode = "local"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(config.datasources.first().unwrap().date_time_mode, DateTimeMode::Local);
+}
+
+#[test]
+fn must_default_to_the_utc_date_time_mode() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(config.datasources.first().unwrap().date_time_mode, DateTimeMode::Utc);
+}
+
+#[test]
+fn must_error_for_an_unknown_date_time_mode() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          dateTimeMode = "central"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `dateTimeMode` argument must be one of \"utc\", \"local\" or \"naive\".",
+        "ds",
+        Span::new(128, 137),
+    ));
+}
+
+#[test]
+fn must_parse_the_referential_integrity() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+          referentialIntegrity = "prisma"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources.first().unwrap().referential_integrity,
+        ReferentialIntegrity::Prisma
+    );
+}
+
+#[test]
+fn must_default_to_foreign_keys_for_the_referential_integrity() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources.first().unwrap().referential_integrity,
+        ReferentialIntegrity::ForeignKeys
+    );
+}
+
+#[test]
+fn must_error_for_an_unknown_referential_integrity() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+          referentialIntegrity = "emulated"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `referentialIntegrity` argument must be one of \"foreignKeys\" or \"prisma\".",
+        "ds",
+        Span::new(126, 136),
+    ));
+}
+
+#[test]
+fn must_parse_explicit_foreign_key_indexes() {
+    let schema = r#"
+        datasource ds {
+          provider = "mysql"
+          url = "mysql://localhost/db"
+          foreignKeyIndexes = "explicit"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources.first().unwrap().foreign_key_indexes,
+        ForeignKeyIndexes::Explicit
+    );
+}
+
+#[test]
+fn must_error_for_foreign_key_indexes_on_other_databases_than_mysql() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          foreignKeyIndexes = "explicit"
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `foreignKeyIndexes` argument is only supported on MySQL.",
+        "ds",
+        Span::new(133, 143),
+    ));
+}
+
+#[test]
+fn must_parse_the_audit_log_flag() {
+    let schema = r#"
+        datasource ds {
+          provider = "sqlite"
+          url = "file:dev.db"
+          auditLog = true
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert!(config.datasources.first().unwrap().audit_log);
+}
+
+#[test]
+fn must_parse_the_database_timestamps_flag() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/db"
+          databaseTimestamps = true
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert!(config.datasources.first().unwrap().database_timestamps);
+}
+
+#[test]
+fn must_error_for_database_timestamps_on_sqlite() {
+    let schema = r#"
+        datasource ds {
+          provider = "sqlite"
+          url = "file:dev.db"
+          databaseTimestamps = true
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `databaseTimestamps` argument is only supported on MySQL and PostgreSQL.",
+        "ds",
+        Span::new(116, 120),
+    ));
+}
+
 #[test]
 #[serial]
 fn fail_to_load_sources_for_invalid_source() {
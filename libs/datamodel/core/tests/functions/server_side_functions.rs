@@ -60,3 +60,49 @@ fn correctly_handle_server_side_uuid_function() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid()));
 }
+
+#[test]
+fn correctly_handle_server_side_ulid_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(ulid())
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_scalar_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_ulid()));
+}
+
+#[test]
+fn correctly_handle_server_side_nanoid_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(nanoid())
+        shortId String @default(nanoid(8))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_scalar_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(None)));
+    user_model
+        .assert_has_scalar_field("shortId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(Some(8))));
+
+    let short_id = ValueGenerator::new_nanoid(Some(8))
+        .generate()
+        .unwrap()
+        .into_string()
+        .unwrap();
+    assert_eq!(short_id.len(), 8);
+}
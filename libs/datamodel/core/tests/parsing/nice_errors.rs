@@ -160,7 +160,7 @@ fn nice_error_broken_field_type_legacy_required() {
 }
 
 #[test]
-fn nice_error_legacy_model_decl() {
+fn nice_error_on_attributes_in_composite_types() {
     let dml = r#"
     type User {
         id Int @id
@@ -168,9 +168,9 @@ fn nice_error_legacy_model_decl() {
 
     let error = parse_error(dml);
 
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
+    error.assert_is(DatamodelError::new_validation_error(
+        "Attributes are not supported on fields of composite types.",
+        Span::new(33, 35),
     ));
 }
 
@@ -1,4 +1,5 @@
 extern crate datamodel;
+use datamodel::ast::reformat::{FormatOptions, Reformatter};
 use pretty_assertions::assert_eq;
 use std::str;
 
@@ -163,7 +164,6 @@ model Blog {
 }
 
 #[test]
-#[ignore]
 fn format_should_put_block_attributes_to_end_of_block_with_comments() {
     let input = r#"model Blog {
   @@id([id1, id2]) /// id comment
@@ -176,8 +176,8 @@ fn format_should_put_block_attributes_to_end_of_block_with_comments() {
   id1 Int
   id2 Int
 
-  @@map("blog") /// blog comment
   @@id([id1, id2]) /// id comment
+  @@map("blog") /// blog comment
 }
 "#;
 
@@ -822,9 +822,86 @@ model Bl
     assert_reformat(input, expected);
 }
 
+#[test]
+fn end_of_line_comments_of_block_attributes_in_enums_must_be_kept() {
+    let input = r#"enum Color {
+  RED
+  GREEN
+
+  @@map("colors") // the colors
+}
+"#;
+
+    let expected = r#"enum Color {
+  RED
+  GREEN
+
+  @@map("colors") // the colors
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
+#[test]
+fn format_should_enforce_order_of_attribute_arguments() {
+    let input = r#"model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(references: [id], name: "posts", fields: [authorId])
+
+  @@index(name: "authorIndex", [authorId])
+}
+
+model User {
+  id    Int    @id
+  posts Post[] @relation("posts")
+}
+"#;
+
+    let expected = r#"model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(name: "posts", fields: [authorId], references: [id])
+
+  @@index([authorId], name: "authorIndex")
+}
+
+model User {
+  id    Int    @id
+  posts Post[] @relation("posts")
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
+#[test]
+fn format_with_a_custom_indent_width_and_without_column_alignment() {
+    let input = r#"model User {
+  id Int @id // the id
+  name String @unique
+}
+"#;
+
+    let expected = r#"model User {
+    id Int @id // the id
+    name String @unique
+}
+"#;
+
+    let options = FormatOptions {
+        indent_width: 4,
+        align_columns: false,
+    };
+    let result = Reformatter::new(&input).reformat_to_string_with_options(&options);
+
+    assert_eq!(result, expected);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
-    let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
+    let result = Reformatter::new(&schema).reformat_to_string();
     println!("result: {}", result);
     assert_eq!(result, expected_result);
 }
@@ -0,0 +1,136 @@
+use crate::common::*;
+use datamodel::{dml, render_datamodel_to_string};
+
+#[test]
+fn composite_types_are_lifted() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432"
+    }
+
+    /// A postal address.
+    type Address {
+        street  String
+        zipCode String?
+        tags    String[]
+        geo     Geo
+    }
+
+    type Geo {
+        lat Float
+        lng Float
+    }
+
+    model User {
+        id      Int      @id
+        address Address? @map("user_address")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let address = datamodel.find_composite_type("Address").unwrap();
+    assert_eq!(address.documentation.as_deref(), Some("A postal address."));
+    assert_eq!(address.fields.len(), 4);
+    assert_eq!(address.find_field("zipCode").unwrap().arity, dml::FieldArity::Optional);
+    assert_eq!(address.find_field("tags").unwrap().arity, dml::FieldArity::List);
+    assert_eq!(
+        address.find_field("geo").unwrap().field_type,
+        dml::FieldType::CompositeType("Geo".to_owned())
+    );
+
+    let user = datamodel.assert_has_model("User");
+    let field = user.assert_has_scalar_field("address");
+    assert_eq!(field.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+    field.assert_with_db_name("user_address");
+}
+
+#[test]
+fn composite_types_must_not_refer_to_models() {
+    let dml = r#"
+    type Address {
+        owner User
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("The field `owner` of the composite type `Address` refers to the model `User`. Composite types can only contain scalar, enum and composite type fields.");
+}
+
+#[test]
+fn composite_types_must_not_be_recursive() {
+    let dml = r#"
+    type Tree {
+        value    Int
+        children Tree[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Recursive composite types are not allowed. Recursive path was: Tree -> Tree.");
+}
+
+#[test]
+fn composite_type_fields_only_support_the_map_attribute() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id      Int     @id
+        address Address @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@unique\": Fields with a composite type only support the `@map` attribute.",
+    );
+}
+
+#[test]
+fn composite_types_require_json_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "You defined the composite type `Address`. But the current connector does not support composite types.",
+    );
+}
+
+#[test]
+fn composite_types_render_back_to_the_same_schema() {
+    let input = r#"model User {
+  id      Int      @id
+  address Address?
+}
+
+type Address {
+  street String
+  zip    String?
+}
+"#;
+
+    let rendered = render_datamodel_to_string(&parse(input));
+
+    assert_eq!(rendered, input);
+}
@@ -1,6 +1,6 @@
 use crate::common::*;
 use crate::types::helper::{test_native_types_with_field_attribute_support, test_native_types_without_attributes};
-use datamodel::{ast, diagnostics::DatamodelError};
+use datamodel::{ast, diagnostics::DatamodelError, dml};
 use native_types::PostgresType;
 
 #[test]
@@ -45,7 +45,7 @@ fn should_fail_on_invalid_precision_for_time_types() {
         )
     }
 
-    for tpe in &["Timestamp", "Time"] {
+    for tpe in &["Timestamp", "Timestamptz", "Time", "Timetz"] {
         test_native_types_without_attributes(&format!("{}(7)", tpe), "DateTime", &error_msg(tpe), POSTGRES_SOURCE);
         test_native_types_without_attributes(&format!("{}(-1)", tpe), "DateTime", &error_msg(tpe), POSTGRES_SOURCE);
     }
@@ -55,13 +55,39 @@ fn should_fail_on_invalid_precision_for_time_types() {
 fn should_fail_on_argument_out_of_range_for_bit_data_types() {
     fn error_msg(type_name: &str) -> String {
         format!(
-            "Argument M is out of range for Native type {} of Postgres: M must be a positive integer.",
+            "Argument M is out of range for Native type {} of Postgres: M can range from 1 to 83,886,080.",
             type_name
         )
     }
 
     for tpe in &["Bit", "VarBit"] {
         test_native_types_without_attributes(&format!("{}(0)", tpe), "String", &error_msg(tpe), POSTGRES_SOURCE);
+        test_native_types_without_attributes(
+            &format!("{}(83886081)", tpe),
+            "String",
+            &error_msg(tpe),
+            POSTGRES_SOURCE,
+        );
+    }
+}
+
+#[test]
+fn should_fail_on_argument_out_of_range_for_character_data_types() {
+    fn error_msg(type_name: &str) -> String {
+        format!(
+            "Argument M is out of range for Native type {} of Postgres: M can range from 1 to 10,485,760.",
+            type_name
+        )
+    }
+
+    for tpe in &["VarChar", "Char"] {
+        test_native_types_without_attributes(&format!("{}(0)", tpe), "String", &error_msg(tpe), POSTGRES_SOURCE);
+        test_native_types_without_attributes(
+            &format!("{}(10485761)", tpe),
+            "String",
+            &error_msg(tpe),
+            POSTGRES_SOURCE,
+        );
     }
 }
 
@@ -145,3 +171,179 @@ fn xml_should_work_with_string_scalar_type() {
     let postgres_tpe: PostgresType = sft.deserialize_native_type();
     assert_eq!(postgres_tpe, PostgresType::Xml);
 }
+
+#[test]
+fn should_fail_on_serial_data_types_on_list_fields() {
+    fn error_msg(type_name: &str) -> String {
+        format!("Native type {} of Postgres can not be used on list fields.", type_name)
+    }
+
+    for tpe in &["SmallSerial", "Serial", "BigSerial"] {
+        test_native_types_without_attributes(tpe, "Int[]", &error_msg(tpe), POSTGRES_SOURCE);
+    }
+}
+
+#[test]
+fn native_types_with_arguments_should_work_on_list_fields() {
+    let dml = format!(
+        r#"
+        {datasource}
+
+        generator js {{
+            provider = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }}
+
+        model Blog {{
+            id   Int      @id
+            tags String[] @db.VarChar(64)
+        }}
+    "#,
+        datasource = POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let user_model = datamodel.assert_has_model("Blog");
+    let field = user_model.assert_has_scalar_field("tags");
+    field.assert_arity(&dml::FieldArity::List);
+
+    let postgres_tpe: PostgresType = field.assert_native_type().deserialize_native_type();
+    assert_eq!(postgres_tpe, PostgresType::VarChar(Some(64)));
+}
+
+#[test]
+fn numeric_and_timetz_should_not_be_parsed_as_other_types() {
+    let dml = format!(
+        r#"
+        {datasource}
+
+        generator js {{
+            provider = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }}
+
+        model Blog {{
+            id     Int      @id
+            amount Decimal  @db.Numeric(8, 2)
+            time   DateTime @db.Timetz(3)
+        }}
+    "#,
+        datasource = POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let user_model = datamodel.assert_has_model("Blog");
+
+    let amount: PostgresType = user_model
+        .assert_has_scalar_field("amount")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(amount, PostgresType::Numeric(Some((8, 2))));
+
+    let time: PostgresType = user_model
+        .assert_has_scalar_field("time")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(time, PostgresType::Timetz(Some(3)));
+}
+
+#[test]
+fn should_parse_citext_on_string_fields() {
+    let dml = format!(
+        r#"
+        {datasource}
+
+        generator js {{
+            provider = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }}
+
+        model User {{
+            id    Int    @id
+            email String @db.Citext
+        }}
+    "#,
+        datasource = POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let user_model = datamodel.assert_has_model("User");
+
+    let email: PostgresType = user_model
+        .assert_has_scalar_field("email")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(email, PostgresType::Citext);
+}
+
+#[test]
+fn should_parse_inet_oid_and_money_native_types() {
+    let dml = format!(
+        r#"
+        {datasource}
+
+        generator js {{
+            provider = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }}
+
+        model Host {{
+            id      Int     @id
+            address String  @db.Inet
+            owner   Int     @db.Oid
+            price   Decimal @db.Money
+        }}
+    "#,
+        datasource = POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let host_model = datamodel.assert_has_model("Host");
+
+    let address: PostgresType = host_model
+        .assert_has_scalar_field("address")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(address, PostgresType::Inet);
+
+    let owner: PostgresType = host_model
+        .assert_has_scalar_field("owner")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(owner, PostgresType::Oid);
+
+    let price: PostgresType = host_model
+        .assert_has_scalar_field("price")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(price, PostgresType::Money);
+}
+
+#[test]
+fn should_parse_interval_on_string_fields() {
+    let dml = format!(
+        r#"
+        {datasource}
+
+        generator js {{
+            provider = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }}
+
+        model Subscription {{
+            id     Int    @id
+            period String @db.Interval
+        }}
+    "#,
+        datasource = POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let subscription_model = datamodel.assert_has_model("Subscription");
+
+    let period: PostgresType = subscription_model
+        .assert_has_scalar_field("period")
+        .assert_native_type()
+        .deserialize_native_type();
+    assert_eq!(period, PostgresType::Interval);
+}
@@ -4,9 +4,11 @@ mod mssql;
 mod mysql;
 mod native_type;
 mod postgres;
+mod sqlite;
 
 pub use mssql::MsSqlType;
 pub use mssql::MsSqlTypeParameter;
 pub use mysql::MySqlType;
 pub use native_type::NativeType;
 pub use postgres::PostgresType;
+pub use sqlite::SqliteType;
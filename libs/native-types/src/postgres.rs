@@ -29,6 +29,11 @@ pub enum PostgresType {
     Xml,
     JSON,
     JSONB,
+    Citext,
+    Inet,
+    Oid,
+    Money,
+    Interval,
 }
 
 impl super::NativeType for PostgresType {
@@ -0,0 +1,24 @@
+use serde::*;
+use serde_json::Value;
+
+/// The column types of SQLite that are preserved as native types. SQLite accepts any declared type
+/// and derives the affinity of the column from its name, see https://www.sqlite.org/datatype3.html.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SqliteType {
+    Integer,
+    Real,
+    Text,
+    Blob,
+    Numeric,
+    Decimal(Option<(u32, u32)>),
+    VarChar(Option<u32>),
+    Boolean,
+    DateTime,
+}
+
+impl super::NativeType for SqliteType {
+    fn to_json(&self) -> Value {
+        serde_json::to_value(&self)
+            .unwrap_or_else(|_| panic!("Serializing the native type to json failed: {:?}", &self))
+    }
+}
@@ -30,6 +30,10 @@ impl<'a> DatamodelConverter<'a> {
             relations: self.convert_relations(),
             enums: self.convert_enums(),
             version: Some("v2".to_string()),
+            provider: String::new(),
+            date_time_mode: Default::default(),
+            referential_integrity: Default::default(),
+            database_timestamps: false,
         }
     }
 
@@ -58,6 +62,7 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_join_table: model.is_join_table,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -103,6 +108,8 @@ impl<'a> DatamodelConverter<'a> {
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
+                    native_type: sf.field_type.native_type().cloned(),
+                    is_encrypted: sf.is_encrypted,
                 }),
             })
             .collect()
@@ -277,7 +284,13 @@ impl TempRelationHolder {
     }
 
     pub fn table_name(&self) -> String {
-        format!("_{}", self.name())
+        self.field_a
+            .relation_info
+            .map
+            .as_ref()
+            .or_else(|| self.field_b.relation_info.map.as_ref())
+            .cloned()
+            .unwrap_or_else(|| format!("_{}", self.name()))
     }
 
     pub fn model_a_column(&self) -> String {
@@ -353,7 +366,9 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::Bytes => TypeIdentifier::Bytes,
                 dml::ScalarType::BigInt => TypeIdentifier::BigInt,
             },
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
+            dml::FieldType::NativeType(_, native_type) if native_type.name == "Interval" => TypeIdentifier::Interval,
             dml::FieldType::NativeType(scalar_type, _) => (*scalar_type).into(),
         }
     }
@@ -376,7 +391,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn is_auto_generated_int_id(&self) -> bool {
         let is_autogenerated_id = matches!(self.default_value, Some(DefaultValue::Expression(_)) if self.is_id);
 
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = matches!(self.type_identifier(), TypeIdentifier::Int | TypeIdentifier::BigInt);
 
         is_autogenerated_id && is_an_int
     }
@@ -384,6 +399,8 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn behaviour(&self) -> Option<FieldBehaviour> {
         if self.is_updated_at {
             Some(FieldBehaviour::UpdatedAt)
+        } else if self.is_version {
+            Some(FieldBehaviour::Version)
         } else {
             None
         }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{FieldArity, RelationInfo};
+use datamodel::{DateTimeMode, FieldArity, RelationInfo};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -241,15 +241,30 @@ impl RelationField {
         }
     }
 
+    /// If the related model is a join model marked with `@@joinTable`, the relation field of the
+    /// join model that points to the other side of the many-to-many relation.
+    pub fn join_table_field(&self) -> Option<Arc<RelationField>> {
+        let related_model = self.related_model();
+
+        if !self.is_list || !related_model.is_join_table {
+            return None;
+        }
+
+        let back_field = self.related_field();
+
+        related_model
+            .fields()
+            .relation()
+            .into_iter()
+            .find(|rf| rf.name != back_field.name)
+    }
+
     pub fn is_relation_with_name_and_side(&self, relation_name: &str, side: RelationSide) -> bool {
         self.relation().name == relation_name && self.relation_side == side
     }
 
-    pub fn type_identifiers_with_arities(&self) -> Vec<(TypeIdentifier, FieldArity)> {
-        self.scalar_fields()
-            .iter()
-            .map(|f| f.type_identifier_with_arity())
-            .collect()
+    pub fn column_types(&self) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
+        self.scalar_fields().iter().map(|f| f.column_type()).collect()
     }
 
     pub fn db_names(&self) -> impl Iterator<Item = String> {
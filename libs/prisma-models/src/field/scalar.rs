@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use datamodel::{DefaultValue, FieldArity};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, TimeZone, Utc};
+use datamodel::{DateTimeMode, DefaultValue, FieldArity, NativeTypeInstance};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -30,6 +31,8 @@ pub struct ScalarFieldTemplate {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub native_type: Option<NativeTypeInstance>,
+    pub is_encrypted: bool,
 }
 
 pub struct ScalarField {
@@ -45,6 +48,8 @@ pub struct ScalarField {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub native_type: Option<NativeTypeInstance>,
+    pub is_encrypted: bool,
 
     pub model: ModelWeakRef,
     pub(crate) is_unique: bool,
@@ -66,6 +71,8 @@ impl Debug for ScalarField {
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
+            .field("native_type", &self.native_type)
+            .field("is_encrypted", &self.is_encrypted)
             .field("model", &"#ModelWeakRef#")
             .field("is_unique", &self.is_unique)
             .field("read_only", &self.read_only)
@@ -85,6 +92,7 @@ impl Hash for ScalarField {
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
         self.behaviour.hash(state);
+        self.is_encrypted.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
         self.arity.hash(state);
@@ -102,11 +110,13 @@ impl PartialEq for ScalarField {
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
             && self.behaviour == other.behaviour
+            && self.is_encrypted == other.is_encrypted
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
             && self.model() == other.model()
             && self.arity == other.arity
             && self.db_name == other.db_name
+            && self.native_type == other.native_type
     }
 }
 
@@ -114,6 +124,7 @@ impl PartialEq for ScalarField {
 pub enum FieldBehaviour {
     CreatedAt,
     UpdatedAt,
+    Version,
     ScalarList { strategy: ScalarListStrategy },
 }
 
@@ -140,6 +151,8 @@ impl ScalarFieldTemplate {
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            native_type: self.native_type,
+            is_encrypted: self.is_encrypted,
             model,
         };
 
@@ -184,6 +197,57 @@ impl ScalarField {
         }
     }
 
+    /// Whether the database sets the value of the field instead of the query engine, for
+    /// `@updatedAt` fields and `now()` defaults when the datasource enables `databaseTimestamps`.
+    pub fn is_set_by_database(&self) -> bool {
+        let is_now_default =
+            matches!(&self.default_value, Some(DefaultValue::Expression(generator)) if generator.name == "now");
+
+        self.internal_data_model().database_timestamps && (self.is_updated_at() || is_now_default)
+    }
+
+    pub fn is_version(&self) -> bool {
+        matches!(self.behaviour, Some(FieldBehaviour::Version))
+    }
+
+    /// Whether the column compares strings case-insensitively on its own, like Postgres `citext`.
+    pub fn is_case_insensitive(&self) -> bool {
+        matches!(&self.native_type, Some(native_type) if native_type.name == "Citext")
+    }
+
+    /// Whether the column stores unsigned integers, like MySQL `BIGINT UNSIGNED`.
+    pub fn is_unsigned(&self) -> bool {
+        matches!(&self.native_type, Some(native_type) if native_type.name.starts_with("Unsigned"))
+    }
+
+    /// How DateTime values of this field are stored. Columns with a time zone, like Postgres
+    /// `timestamptz`, always store an instant, so the datasource mode only applies to the others.
+    pub fn date_time_mode(&self) -> DateTimeMode {
+        match &self.native_type {
+            Some(native_type) if native_type.name == "Timestamptz" || native_type.name == "Timetz" => DateTimeMode::Utc,
+            _ => self.internal_data_model().date_time_mode,
+        }
+    }
+
+    /// The instant the given day starts at in the values of this field, so that date-only filters
+    /// compare the days as they are stored: midnight in the local time zone for local date times,
+    /// midnight UTC otherwise.
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<FixedOffset> {
+        let midnight = date.and_hms(0, 0, 0);
+        let utc_midnight = || -> DateTime<FixedOffset> { DateTime::<Utc>::from_utc(midnight, Utc).into() };
+
+        match self.date_time_mode() {
+            DateTimeMode::Local => Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                // Midnight may be skipped by a DST transition, the day then starts an hour later.
+                .or_else(|| Local.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+                .map(Into::into)
+                .unwrap_or_else(utc_midnight),
+            DateTimeMode::Utc | DateTimeMode::Naive => utc_midnight(),
+        }
+    }
+
     pub fn unique(&self) -> bool {
         self.is_unique || self.is_id()
     }
@@ -192,8 +256,9 @@ impl ScalarField {
         &self.db_name.as_ref().unwrap_or(&self.name)
     }
 
-    pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
-        (self.type_identifier.clone(), self.arity)
+    /// The type identifier, arity and DateTime mode the database values of the field are read with.
+    pub fn column_type(&self) -> (TypeIdentifier, FieldArity, DateTimeMode) {
+        (self.type_identifier.clone(), self.arity, self.date_time_mode())
     }
 
     pub fn is_read_only(&self) -> bool {
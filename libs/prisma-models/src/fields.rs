@@ -16,6 +16,7 @@ pub struct Fields {
     model: ModelWeakRef,
     created_at: OnceCell<Option<ScalarFieldRef>>,
     updated_at: OnceCell<Option<ScalarFieldRef>>,
+    version: OnceCell<Option<ScalarFieldRef>>,
 }
 
 impl Fields {
@@ -28,6 +29,7 @@ impl Fields {
             relation: OnceCell::new(),
             created_at: OnceCell::new(),
             updated_at: OnceCell::new(),
+            version: OnceCell::new(),
             model,
         }
     }
@@ -91,6 +93,16 @@ impl Fields {
         })
     }
 
+    /// The field marked with `@version`, used for optimistic concurrency control.
+    pub fn version(&self) -> &Option<ScalarFieldRef> {
+        self.version.get_or_init(|| {
+            self.scalar_weak()
+                .iter()
+                .map(|sf| sf.upgrade().unwrap())
+                .find(|sf| sf.is_version())
+        })
+    }
+
     pub fn scalar(&self) -> Vec<ScalarFieldRef> {
         self.scalar_weak().iter().map(|f| f.upgrade().unwrap()).collect()
     }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use datamodel::{DateTimeMode, ReferentialIntegrity};
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
@@ -12,6 +13,10 @@ pub struct InternalDataModelTemplate {
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
     pub version: Option<String>,
+    pub provider: String,
+    pub date_time_mode: DateTimeMode,
+    pub referential_integrity: ReferentialIntegrity,
+    pub database_timestamps: bool,
 }
 
 #[derive(Debug)]
@@ -26,6 +31,18 @@ pub struct InternalDataModel {
     /// influence the `database` part instead.
     pub db_name: String,
 
+    /// The active provider of the datasource, e.g. `postgresql`.
+    pub provider: String,
+
+    /// How DateTime values are stored in columns without time zone, see `ScalarField::date_time_mode`.
+    pub date_time_mode: DateTimeMode,
+
+    /// Whether the query engine emulates referential actions, see `InternalDataModel::emulates_referential_actions`.
+    pub referential_integrity: ReferentialIntegrity,
+
+    /// Whether the database maintains the timestamps, see `ScalarField::is_set_by_database`.
+    pub database_timestamps: bool,
+
     models: OnceCell<Vec<ModelRef>>,
     relations: OnceCell<Vec<RelationRef>>,
     relation_fields: OnceCell<Vec<RelationFieldRef>>,
@@ -83,6 +100,10 @@ impl InternalDataModelTemplate {
             enums: self.enums.into_iter().map(Arc::new).collect(),
             version: self.version,
             db_name,
+            provider: self.provider,
+            date_time_mode: self.date_time_mode,
+            referential_integrity: self.referential_integrity,
+            database_timestamps: self.database_timestamps,
             relation_fields: OnceCell::new(),
         });
 
@@ -147,6 +168,23 @@ impl InternalDataModel {
         self.version.is_none()
     }
 
+    /// Whether the query engine emulates the referential actions of relations because the database
+    /// has no foreign keys to enforce them.
+    pub fn emulates_referential_actions(&self) -> bool {
+        !self.referential_integrity.uses_foreign_keys()
+    }
+
+    /// All optional, non-list relation fields pointing to the given `model` that hold the foreign key
+    /// on their own model. Their values are set to null when a record of `model` is deleted.
+    pub fn fields_nullified_by_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+        self.relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .filter(|rf| rf.is_optional() && !rf.is_list && rf.is_inlined_on_enclosing_model())
+            .map(|rf| Arc::clone(rf))
+            .collect()
+    }
+
     pub fn fields_requiring_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
         self.relation_fields()
             .iter()
@@ -19,6 +19,7 @@ pub mod sql_ext;
 pub mod prelude;
 
 pub use datamodel::dml;
+pub use datamodel::DateTimeMode;
 pub use datamodel_converter::*;
 pub use error::*;
 pub use field::*;
@@ -13,6 +13,7 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_join_table: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -23,6 +24,7 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub is_join_table: bool,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -38,6 +40,7 @@ impl Debug for Model {
         f.debug_struct("Model")
             .field("name", &self.name)
             .field("is_embedded", &self.is_embedded)
+            .field("is_join_table", &self.is_join_table)
             .field("manifestation", &self.manifestation)
             .field("fields", &self.fields)
             .field("indexes", &self.indexes)
@@ -53,6 +56,7 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_join_table: self.is_join_table,
             manifestation: self.manifestation,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
@@ -5,11 +5,26 @@ use std::string::ToString;
 pub struct OrderBy {
     pub field: ScalarFieldRef,
     pub sort_order: SortOrder,
+    /// If set, the ordering is done on the aggregate of the field instead of the field itself.
+    /// Only valid for grouped queries.
+    pub sort_aggregation: Option<SortAggregation>,
 }
 
 impl OrderBy {
     pub fn new(field: ScalarFieldRef, sort_order: SortOrder) -> Self {
-        Self { field, sort_order }
+        Self {
+            field,
+            sort_order,
+            sort_aggregation: None,
+        }
+    }
+
+    pub fn aggregation(field: ScalarFieldRef, sort_aggregation: SortAggregation, sort_order: SortOrder) -> Self {
+        Self {
+            field,
+            sort_order,
+            sort_aggregation: Some(sort_aggregation),
+        }
     }
 }
 
@@ -23,6 +38,15 @@ pub enum SortOrder {
     Descending,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+
 impl ToString for SortOrder {
     fn to_string(&self) -> String {
         match self {
@@ -37,6 +61,7 @@ impl From<ScalarFieldRef> for OrderBy {
         Self {
             field,
             sort_order: SortOrder::Ascending,
+            sort_aggregation: None,
         }
     }
 }
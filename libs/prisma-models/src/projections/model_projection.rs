@@ -1,6 +1,7 @@
 use super::RecordProjection;
 use crate::{
-    dml::FieldArity, DomainError, Field, ModelRef, PrismaValue, PrismaValueExtensions, ScalarFieldRef, TypeIdentifier,
+    dml::FieldArity, DateTimeMode, DomainError, Field, ModelRef, PrismaValue, PrismaValueExtensions, ScalarFieldRef,
+    TypeIdentifier,
 };
 use itertools::Itertools;
 
@@ -75,8 +76,8 @@ impl ModelProjection {
         })
     }
 
-    pub fn type_identifiers_with_arities(&self) -> Vec<(TypeIdentifier, FieldArity)> {
-        self.scalar_fields().map(|f| f.type_identifier_with_arity()).collect()
+    pub fn column_types(&self) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
+        self.scalar_fields().map(|f| f.column_type()).collect()
     }
 
     /// Checks if a given `RecordProjection` belongs to this `ModelProjection`.
@@ -1,5 +1,5 @@
 use super::ModelProjection;
-use crate::{DomainError, PrismaValue, ScalarFieldRef};
+use crate::{DomainError, PrismaValue, ScalarFieldRef, TypeIdentifier};
 use std::{collections::HashMap, convert::TryFrom};
 
 /// Represents a (sub)set of fields to value pairs from a single record.
@@ -43,7 +43,11 @@ impl RecordProjection {
     {
         for pair in self.pairs.iter_mut() {
             if pair.1.is_null() {
-                pair.1 = value.into();
+                pair.1 = match (value.into(), &pair.0.type_identifier) {
+                    (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+                    (value, _) => value,
+                };
+
                 return true;
             }
         }
@@ -166,6 +166,7 @@ where
             TypeIdentifier::UUID => TypeFamily::Uuid,
             TypeIdentifier::Json => TypeFamily::Text,
             TypeIdentifier::Xml => TypeFamily::Text,
+            TypeIdentifier::Interval => TypeFamily::Text,
             TypeIdentifier::DateTime => TypeFamily::DateTime,
             TypeIdentifier::Bytes => TypeFamily::Bytes,
         };
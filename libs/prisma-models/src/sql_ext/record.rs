@@ -1,4 +1,7 @@
-use crate::{error::DomainError, ModelProjection, RecordProjection, ScalarFieldExt};
+use crate::{
+    date_time_from_db, error::DomainError, ModelProjection, PrismaValue, RecordProjection, ScalarFieldExt,
+    TypeIdentifier,
+};
 use quaint::{connector::ResultSet, Value};
 use std::convert::{TryFrom, TryInto};
 
@@ -15,7 +18,15 @@ impl TryFrom<(&ModelProjection, ResultSet)> for RecordProjection {
             for (i, val) in row.into_iter().enumerate() {
                 match model_projection.map_db_name(columns[i].as_str()) {
                     Some(field) => {
-                        record_projection.add((field, val.try_into()?));
+                        let value = match (val.try_into()?, &field.type_identifier) {
+                            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+                            (PrismaValue::DateTime(dt), TypeIdentifier::DateTime) => {
+                                PrismaValue::DateTime(date_time_from_db(field.date_time_mode(), dt))
+                            }
+                            (value, _) => value,
+                        };
+
+                        record_projection.add((field, value));
                     }
                     None => {
                         return Err(DomainError::ScalarFieldNotFound {
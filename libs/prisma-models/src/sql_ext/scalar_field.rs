@@ -1,4 +1,5 @@
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Local, LocalResult, TimeZone, Utc};
+use datamodel::DateTimeMode;
 use prisma_value::PrismaValue;
 use quaint::ast::Value;
 
@@ -14,15 +15,17 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::String(s), _) => s.into(),
             (PrismaValue::Float(f), _) => f.into(),
             (PrismaValue::Boolean(b), _) => b.into(),
-            (PrismaValue::DateTime(d), _) => d.with_timezone(&Utc).into(),
+            (PrismaValue::DateTime(d), _) => date_time_to_db(self.date_time_mode(), d).into(),
             (PrismaValue::Enum(e), _) => e.into(),
             (PrismaValue::Int(i), _) => (i as i64).into(),
             (PrismaValue::BigInt(i), _) => (i as i64).into(),
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
             (PrismaValue::List(l), _) => Value::Array(Some(l.into_iter().map(|x| self.value(x)).collect())),
-            (PrismaValue::Json(s), _) => Value::Json(serde_json::from_str(&s).unwrap()),
+            // Parsed into a JSON value, so that a JSON `null` is not written as a database NULL.
+            (PrismaValue::Json(s), _) => Value::Json(Some(serde_json::from_str::<serde_json::Value>(&s).unwrap())),
             (PrismaValue::Bytes(b), _) => Value::Bytes(Some(b.into())),
             (PrismaValue::Xml(s), _) => Value::Xml(Some(s.into())),
+            (PrismaValue::Interval(i), _) => i.to_string().into(),
             (PrismaValue::Null, ident) => match ident {
                 TypeIdentifier::String => Value::Text(None),
                 TypeIdentifier::Float => Value::Numeric(None),
@@ -36,11 +39,35 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::BigInt => Value::Integer(None),
                 TypeIdentifier::Bytes => Value::Bytes(None),
                 TypeIdentifier::Xml => Value::Xml(None),
+                TypeIdentifier::Interval => Value::Text(None),
             },
         }
     }
 }
 
+/// Converts a DateTime to the value written to a column with the given `DateTimeMode`.
+pub fn date_time_to_db(mode: DateTimeMode, dt: DateTime<FixedOffset>) -> DateTime<Utc> {
+    // The database only sees the naive part of the value for columns without time zone.
+    match mode {
+        DateTimeMode::Utc => dt.with_timezone(&Utc),
+        DateTimeMode::Local => DateTime::from_utc(dt.with_timezone(&Local).naive_local(), Utc),
+        DateTimeMode::Naive => DateTime::from_utc(dt.naive_local(), Utc),
+    }
+}
+
+/// Reverses `date_time_to_db` for a DateTime read from a column with the given `DateTimeMode`.
+/// The database drivers read the values of columns without time zone as UTC.
+pub fn date_time_from_db(mode: DateTimeMode, dt: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    match mode {
+        // Local times skipped by a DST transition can't exist in the column, keep them as is.
+        DateTimeMode::Local => match Local.from_local_datetime(&dt.naive_utc()) {
+            LocalResult::Single(local) | LocalResult::Ambiguous(local, _) => local.with_timezone(&Utc).into(),
+            LocalResult::None => dt,
+        },
+        DateTimeMode::Utc | DateTimeMode::Naive => dt,
+    }
+}
+
 /// Attempts to convert a PrismaValue to a database value without any additional type information.
 /// Can't reliably map Null values.
 pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
@@ -54,9 +81,49 @@ pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
         PrismaValue::BigInt(i) => (i as i64).into(),
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(convert_lossy).collect())),
-        PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
+        PrismaValue::Json(s) => Value::Json(Some(serde_json::from_str::<serde_json::Value>(&s).unwrap())),
         PrismaValue::Bytes(b) => Value::Bytes(Some(b.into())),
         PrismaValue::Xml(s) => Value::Xml(Some(s.into())),
+        PrismaValue::Interval(i) => i.to_string().into(),
         PrismaValue::Null => Value::Integer(None), // Can't tell which type the null is supposed to be.
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_time(s: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn utc_date_times_are_written_as_the_same_instant() {
+        let dt = date_time("2021-01-15T12:30:00+02:00");
+        let written = date_time_to_db(DateTimeMode::Utc, dt);
+
+        assert_eq!(written.to_rfc3339(), "2021-01-15T10:30:00+00:00");
+        assert_eq!(date_time_from_db(DateTimeMode::Utc, written.into()), dt);
+    }
+
+    #[test]
+    fn naive_date_times_are_written_with_their_wall_clock_time() {
+        let written = date_time_to_db(DateTimeMode::Naive, date_time("2021-01-15T12:30:00+02:00"));
+
+        assert_eq!(written.to_rfc3339(), "2021-01-15T12:30:00+00:00");
+        assert_eq!(
+            date_time_from_db(DateTimeMode::Naive, written.into()),
+            date_time("2021-01-15T12:30:00+00:00")
+        );
+    }
+
+    #[test]
+    fn local_date_times_round_trip() {
+        // The wall-clock time in the local time zone of the host is written, whatever it is.
+        let dt = date_time("2021-01-15T12:30:00+02:00");
+        let written = date_time_to_db(DateTimeMode::Local, dt);
+
+        assert_eq!(written.naive_utc(), dt.with_timezone(&Local).naive_local());
+        assert_eq!(date_time_from_db(DateTimeMode::Local, written.into()), dt);
+    }
+}
@@ -362,6 +362,31 @@ fn many_to_many_relations() {
         }));
 }
 
+#[test]
+fn many_to_many_relations_with_a_mapped_join_table() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id Int @id
+                blogs Blog[] @relation("BlogToPost", map: "blog_posts")
+            }
+
+            model Blog {
+                id Int @id
+                posts Post[] @relation("BlogToPost")
+            }
+        "#,
+    );
+
+    datamodel
+        .assert_relation("BlogToPost")
+        .assert_manifestation(RelationLinkManifestation::RelationTable(RelationTable {
+            table: "blog_posts".to_string(),
+            model_a_column: "A".to_string(),
+            model_b_column: "B".to_string(),
+        }));
+}
+
 #[test]
 fn implicit_relation_fields() {
     let datamodel = convert(
@@ -0,0 +1,292 @@
+use crate::{ConversionFailure, PrismaValueResult};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+
+/// A span of time, stored like the Postgres `interval`: months and days are kept apart from the
+/// time of day, as their length depends on the date they are added to. Written and read as
+/// ISO-8601 durations, e.g. `P1Y2M3DT4H5M6.5S`.
+#[derive(Debug, Default, PartialEq, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl Interval {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Interval {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Parses the default output of Postgres (`IntervalStyle = postgres`), e.g.
+    /// `1 year 2 mons -3 days +04:05:06.5`, as well as ISO-8601 durations.
+    pub fn parse_postgres(s: &str) -> PrismaValueResult<Self> {
+        let s = s.trim();
+
+        if s.starts_with('P') || s.starts_with("-P") {
+            return s.parse();
+        }
+
+        let mut interval = Interval::default();
+        let mut tokens = s.split_whitespace();
+
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                interval.microseconds = interval
+                    .microseconds
+                    .checked_add(parse_postgres_time(token)?)
+                    .ok_or_else(failure)?;
+                continue;
+            }
+
+            let amount: i32 = token.parse().map_err(|_| failure())?;
+            let unit = tokens.next().ok_or_else(failure)?;
+
+            let (field, factor) = match unit.trim_end_matches('s') {
+                "year" => (&mut interval.months, 12),
+                "mon" => (&mut interval.months, 1),
+                "day" => (&mut interval.days, 1),
+                _ => return Err(failure()),
+            };
+
+            *field = amount
+                .checked_mul(factor)
+                .and_then(|amount| field.checked_add(amount))
+                .ok_or_else(failure)?;
+        }
+
+        Ok(interval)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ConversionFailure;
+
+    /// Parses an ISO-8601 duration. The components may carry their own sign, as in the output of
+    /// Postgres, and a leading `-` negates all of them.
+    fn from_str(s: &str) -> PrismaValueResult<Self> {
+        let (negated, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let s = s.strip_prefix('P').ok_or_else(failure)?;
+
+        if s.is_empty() {
+            return Err(failure());
+        }
+
+        let (date, time) = match s.find('T') {
+            Some(idx) if idx + 1 < s.len() => (&s[..idx], Some(&s[idx + 1..])),
+            Some(_) => return Err(failure()),
+            None => (s, None),
+        };
+
+        let mut months: i64 = 0;
+        let mut days: i64 = 0;
+        let mut microseconds: i64 = 0;
+
+        for (amount, designator) in components(date)? {
+            let (total, factor) = match designator {
+                'Y' => (&mut months, 12),
+                'M' => (&mut months, 1),
+                'W' => (&mut days, 7),
+                'D' => (&mut days, 1),
+                _ => return Err(failure()),
+            };
+
+            *total = add_scaled(*total, whole(amount)?, factor)?;
+        }
+
+        for (amount, designator) in components(time.unwrap_or_default())? {
+            microseconds = match designator {
+                'H' => add_scaled(microseconds, whole(amount)?, MICROS_PER_HOUR)?,
+                'M' => add_scaled(microseconds, whole(amount)?, MICROS_PER_MINUTE)?,
+                'S' => add_scaled(microseconds, parse_seconds(amount)?, 1)?,
+                _ => return Err(failure()),
+            };
+        }
+
+        let sign = if negated { -1 } else { 1 };
+
+        Ok(Interval {
+            months: i32::try_from(sign * months).map_err(|_| failure())?,
+            days: i32::try_from(sign * days).map_err(|_| failure())?,
+            microseconds: sign * microseconds,
+        })
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == Interval::default() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+
+        let (years, months) = (self.months / 12, self.months % 12);
+
+        if years != 0 {
+            write!(f, "{}Y", years)?;
+        }
+
+        if months != 0 {
+            write!(f, "{}M", months)?;
+        }
+
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+
+        if self.microseconds == 0 {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+
+        let hours = self.microseconds / MICROS_PER_HOUR;
+        let minutes = self.microseconds % MICROS_PER_HOUR / MICROS_PER_MINUTE;
+        let micros = self.microseconds % MICROS_PER_MINUTE;
+
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+
+        if micros != 0 {
+            let sign = if micros < 0 { "-" } else { "" };
+            let (seconds, fraction) = (micros.abs() / MICROS_PER_SECOND, micros.abs() % MICROS_PER_SECOND);
+
+            if fraction == 0 {
+                write!(f, "{}{}S", sign, seconds)?;
+            } else {
+                let fraction = format!("{:06}", fraction);
+                write!(f, "{}{}.{}S", sign, seconds, fraction.trim_end_matches('0'))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for Interval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+fn failure() -> ConversionFailure {
+    ConversionFailure::new("string", "Interval")
+}
+
+/// Splits `1Y-2M3D` into the amounts and their designators.
+fn components(s: &str) -> PrismaValueResult<Vec<(&str, char)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if idx == start {
+                return Err(failure());
+            }
+
+            components.push((&s[start..idx], c));
+            start = idx + 1;
+        }
+    }
+
+    if start != s.len() {
+        return Err(failure());
+    }
+
+    Ok(components)
+}
+
+fn add_scaled(total: i64, amount: i64, factor: i64) -> PrismaValueResult<i64> {
+    amount
+        .checked_mul(factor)
+        .and_then(|amount| total.checked_add(amount))
+        .ok_or_else(failure)
+}
+
+fn whole(amount: &str) -> PrismaValueResult<i64> {
+    amount.parse().map_err(|_| failure())
+}
+
+/// `-1.5` seconds as `-1_500_000` microseconds.
+fn parse_seconds(amount: &str) -> PrismaValueResult<i64> {
+    let (negative, amount) = match amount.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, amount.strip_prefix('+').unwrap_or(amount)),
+    };
+
+    let (seconds, fraction) = match amount.find(|c| c == '.' || c == ',') {
+        Some(idx) => (&amount[..idx], &amount[idx + 1..]),
+        None => (amount, ""),
+    };
+
+    if seconds.is_empty() || !seconds.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(failure());
+    }
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(failure());
+    }
+
+    let seconds: i64 = seconds.parse().map_err(|_| failure())?;
+    let fraction: i64 = format!("{:0<6}", fraction).parse().map_err(|_| failure())?;
+
+    let micros = seconds
+        .checked_mul(MICROS_PER_SECOND)
+        .and_then(|micros| micros.checked_add(fraction))
+        .ok_or_else(failure)?;
+
+    Ok(if negative { -micros } else { micros })
+}
+
+/// `-04:05:06.5` as microseconds.
+fn parse_postgres_time(s: &str) -> PrismaValueResult<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut parts = s.split(':');
+
+    let hours = parts.next().map(whole).transpose()?.ok_or_else(failure)?;
+    let minutes = parts.next().map(whole).transpose()?.ok_or_else(failure)?;
+    let seconds = parts.next().map(parse_seconds).transpose()?.unwrap_or(0);
+
+    if parts.next().is_some() || hours < 0 || minutes < 0 || seconds < 0 {
+        return Err(failure());
+    }
+
+    let micros = add_scaled(seconds, hours, MICROS_PER_HOUR)?;
+    let micros = add_scaled(micros, minutes, MICROS_PER_MINUTE)?;
+
+    Ok(if negative { -micros } else { micros })
+}
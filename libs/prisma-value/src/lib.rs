@@ -1,5 +1,6 @@
 pub mod arithmetic;
 mod error;
+mod interval;
 #[cfg(feature = "sql-ext")]
 mod sql_ext;
 
@@ -11,6 +12,7 @@ use std::{convert::TryFrom, fmt, str::FromStr};
 use uuid::Uuid;
 
 pub use error::ConversionFailure;
+pub use interval::Interval;
 pub type PrismaValueResult<T> = std::result::Result<T, ConversionFailure>;
 pub type PrismaListValue = Vec<PrismaValue>;
 
@@ -40,6 +42,49 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_bytes")]
     Bytes(Vec<u8>),
+
+    /// Serialized as an ISO-8601 duration.
+    Interval(Interval),
+}
+
+/// Stands in for the nulls of Json fields in filters and writes, as a JSON `null` is a value of
+/// its own that is different from a database NULL.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum JsonNullValue {
+    /// The database NULL.
+    DbNull,
+    /// The JSON `null` value.
+    JsonNull,
+    /// Either of the two, only valid in filters.
+    AnyNull,
+}
+
+impl JsonNullValue {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JsonNullValue::DbNull => "DbNull",
+            JsonNullValue::JsonNull => "JsonNull",
+            JsonNullValue::AnyNull => "AnyNull",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<JsonNullValue> {
+        match name {
+            "DbNull" => Some(JsonNullValue::DbNull),
+            "JsonNull" => Some(JsonNullValue::JsonNull),
+            "AnyNull" => Some(JsonNullValue::AnyNull),
+            _ => None,
+        }
+    }
+
+    /// The placeholder a value of a Json field stands for, if any. Json values are always
+    /// `PrismaValue::Json`, so plain strings can only be placeholders.
+    pub fn from_value(value: &PrismaValue) -> Option<JsonNullValue> {
+        match value {
+            PrismaValue::String(s) | PrismaValue::Enum(s) => JsonNullValue::from_name(s),
+            _ => None,
+        }
+    }
 }
 
 pub fn stringify_date(date: &DateTime<FixedOffset>) -> String {
@@ -204,6 +249,11 @@ impl PrismaValue {
         PrismaValue::Float(BigDecimal::from_f64(float).unwrap())
     }
 
+    /// The JSON `null` value, as opposed to the database NULL `PrismaValue::Null`.
+    pub fn json_null() -> PrismaValue {
+        PrismaValue::Json(String::from("null"))
+    }
+
     pub fn new_datetime(datetime: &str) -> PrismaValue {
         PrismaValue::DateTime(DateTime::parse_from_rfc3339(datetime).unwrap())
     }
@@ -228,6 +278,7 @@ impl fmt::Display for PrismaValue {
                 as_string.fmt(f)
             }
             PrismaValue::Bytes(b) => encode_bytes(b).fmt(f),
+            PrismaValue::Interval(x) => x.fmt(f),
         }
     }
 }
@@ -284,6 +335,12 @@ impl From<Uuid> for PrismaValue {
     }
 }
 
+impl From<Interval> for PrismaValue {
+    fn from(interval: Interval) -> Self {
+        PrismaValue::Interval(interval)
+    }
+}
+
 impl From<PrismaListValue> for PrismaValue {
     fn from(s: PrismaListValue) -> Self {
         PrismaValue::List(s)
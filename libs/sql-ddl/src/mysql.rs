@@ -53,7 +53,7 @@ pub enum AlterTableClause<'a> {
 impl Display for AlterTableClause<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AlterTableClause::RenameTo { next_name } => write!(f, "RENAME TO {}", next_name),
+            AlterTableClause::RenameTo { next_name } => write!(f, "RENAME TO `{}`", next_name),
             AlterTableClause::RenameIndex {
                 previous_name,
                 next_name,
@@ -64,18 +64,48 @@ pub struct CreateIndex<'a> {
     pub is_unique: bool,
     pub table_reference: PostgresIdentifier<'a>,
     pub columns: Vec<Cow<'a, str>>,
+    pub using: Option<IndexMethod>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum IndexMethod {
+    BTree,
+    Hash,
+    Gist,
+    SpGist,
+    Gin,
+    Brin,
+}
+
+impl Display for IndexMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            IndexMethod::BTree => "BTREE",
+            IndexMethod::Hash => "HASH",
+            IndexMethod::Gist => "GIST",
+            IndexMethod::SpGist => "SPGIST",
+            IndexMethod::Gin => "GIN",
+            IndexMethod::Brin => "BRIN",
+        })
+    }
 }
 
 impl<'a> Display for CreateIndex<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {uniqueness}INDEX {index_name} ON {table_reference}(",
+            "CREATE {uniqueness}INDEX {index_name} ON {table_reference}",
             uniqueness = if self.is_unique { "UNIQUE " } else { "" },
             index_name = self.index_name,
             table_reference = self.table_reference,
         )?;
 
+        if let Some(using) = self.using {
+            write!(f, " USING {} (", using)?;
+        } else {
+            f.write_str("(")?;
+        }
+
         self.columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
         f.write_str(")")
@@ -119,6 +149,7 @@ mod tests {
             index_name: "meow_idx".into(),
             table_reference: "Cat".into(),
             columns,
+            using: None,
         };
 
         assert_eq!(
@@ -126,4 +157,20 @@ mod tests {
             "CREATE UNIQUE INDEX \"meow_idx\" ON \"Cat\"(\"name\", \"age\")"
         )
     }
+
+    #[test]
+    fn create_index_using_gin() {
+        let create_index = CreateIndex {
+            is_unique: false,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            columns: vec!["data".into()],
+            using: Some(IndexMethod::Gin),
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX \"meow_idx\" ON \"Cat\" USING GIN (\"data\")"
+        )
+    }
 }
@@ -0,0 +1,33 @@
+use quaint::{prelude::Queryable, single::Quaint};
+use std::{fmt, ops::Deref, sync::Arc};
+
+/// The connection a describer queries the database with. Any `Queryable` can be used, e.g. a
+/// Quaint connection, or the driver of an embedder that cannot open connections on its own.
+#[derive(Clone)]
+pub struct DescriberConnection(Arc<dyn Queryable + Send + Sync>);
+
+impl DescriberConnection {
+    pub fn new(queryable: Arc<dyn Queryable + Send + Sync>) -> Self {
+        DescriberConnection(queryable)
+    }
+}
+
+impl Deref for DescriberConnection {
+    type Target = dyn Queryable + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl From<Quaint> for DescriberConnection {
+    fn from(quaint: Quaint) -> Self {
+        DescriberConnection(Arc::new(quaint))
+    }
+}
+
+impl fmt::Debug for DescriberConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DescriberConnection { .. }")
+    }
+}
@@ -4,9 +4,14 @@
 //! Database description. This crate is used heavily in the introspection and migration engines.
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::fmt::{self, Debug};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug},
+};
 use tracing::debug;
 
+pub use connection::DescriberConnection;
 pub use error::{DescriberError, DescriberErrorKind, DescriberResult};
 use prisma_value::PrismaValue;
 use walkers::{EnumWalker, TableWalker};
@@ -18,6 +23,7 @@ pub mod postgres;
 pub mod sqlite;
 pub mod walkers;
 
+mod connection;
 mod error;
 mod parsers;
 
@@ -35,6 +41,13 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
 
     /// Get the database version.
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>>;
+
+    /// Get approximate statistics about the data in the tables of a schema. These are read from
+    /// the statistics the database keeps for its query planner, so they can be missing or out of
+    /// date.
+    async fn get_statistics(&self, _schema: &str) -> DescriberResult<Vec<TableStatistics>> {
+        Ok(Vec::new())
+    }
 }
 
 pub struct SQLMetadata {
@@ -42,8 +55,25 @@ pub struct SQLMetadata {
     pub size_in_bytes: usize,
 }
 
-/// The result of describing a database schema.
+/// Approximate statistics about the data in a table.
 #[derive(Debug, Clone, PartialEq)]
+pub struct TableStatistics {
+    /// The table's name.
+    pub table: String,
+    /// The approximate number of rows in the table.
+    pub row_count: Option<u64>,
+    /// The space the rows of the table take, in bytes.
+    pub data_size_in_bytes: Option<u64>,
+    /// The space the indexes of the table take, in bytes.
+    pub index_size_in_bytes: Option<u64>,
+    /// The fraction of null values in the sampled rows, by column name. Only available on
+    /// PostgreSQL.
+    pub null_fractions: BTreeMap<String, f64>,
+}
+
+/// The result of describing a database schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SqlSchema {
     /// The schema's tables.
     pub tables: Vec<Table>,
@@ -51,6 +81,10 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views. They are described for external tooling, migrations and
+    /// introspection only work with the tables.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -72,6 +106,7 @@ impl SqlSchema {
                 tables,
                 enums,
                 sequences,
+                ..
             } if tables.is_empty() && enums.is_empty() && sequences.is_empty()
         )
     }
@@ -92,11 +127,17 @@ impl SqlSchema {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 
@@ -113,7 +154,8 @@ impl SqlSchema {
 }
 
 /// A table found in a schema.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Table {
     /// The table's name.
     pub name: String,
@@ -125,6 +167,10 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The storage engine of the table, when it is not the default. Only described on MySQL.
+    pub engine: Option<String>,
+    /// The row format of the table, when it was explicitly set. Only described on MySQL.
+    pub row_format: Option<String>,
 }
 
 impl Table {
@@ -181,7 +227,8 @@ impl Table {
     }
 }
 /// The type of an index.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum IndexType {
     /// Unique type.
     Unique,
@@ -195,8 +242,27 @@ impl IndexType {
     }
 }
 
+/// The method of an index (access method on Postgres).
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexMethod {
+    /// B-tree, the default.
+    BTree,
+    /// Hash.
+    Hash,
+    /// Generalized search tree.
+    Gist,
+    /// Space-partitioned generalized search tree.
+    SpGist,
+    /// Generalized inverted index.
+    Gin,
+    /// Block range index.
+    Brin,
+}
+
 /// An index of a table.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Index {
     /// Index name.
     pub name: String,
@@ -204,6 +270,14 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Whether the index is clustered. Only described on SQL Server.
+    pub clustered: Option<bool>,
+    /// Whether the unique constraint backing the index is `DEFERRABLE INITIALLY DEFERRED`. Only
+    /// described on Postgres.
+    #[serde(default)]
+    pub deferrable: bool,
+    /// The index method, when it is not the default B-tree. Only described on Postgres.
+    pub method: Option<IndexMethod>,
 }
 
 impl Index {
@@ -213,7 +287,8 @@ impl Index {
 }
 
 /// The primary key of a table.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PrimaryKey {
     /// Columns.
     pub columns: Vec<String>,
@@ -221,6 +296,8 @@ pub struct PrimaryKey {
     pub sequence: Option<Sequence>,
     /// The name of the primary key constraint, when available.
     pub constraint_name: Option<String>,
+    /// Whether the primary key is clustered. Only described on SQL Server.
+    pub clustered: Option<bool>,
 }
 
 impl PrimaryKey {
@@ -230,7 +307,8 @@ impl PrimaryKey {
 }
 
 /// A column of a table.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Column {
     /// Column name.
     pub name: String,
@@ -240,6 +318,10 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// Is the column computed by the database? Computed columns are read-only, and only described
+    /// on SQL Server.
+    #[serde(default)]
+    pub computed: bool,
 }
 
 impl Column {
@@ -249,7 +331,8 @@ impl Column {
 }
 
 /// The type of a column.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ColumnType {
     /// The SQL data type.
     pub data_type: String,
@@ -290,7 +373,8 @@ impl ColumnType {
 }
 
 /// Enumeration of column type families.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 // TODO: this name feels weird.
 pub enum ColumnTypeFamily {
     /// Integer types.
@@ -335,6 +419,10 @@ impl ColumnTypeFamily {
         matches!(self, ColumnTypeFamily::Int)
     }
 
+    pub fn is_bigint(&self) -> bool {
+        matches!(self, ColumnTypeFamily::BigInt)
+    }
+
     pub fn is_json(&self) -> bool {
         matches!(self, ColumnTypeFamily::Json)
     }
@@ -361,7 +449,8 @@ impl fmt::Display for ColumnTypeFamily {
 }
 
 /// A column's arity.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ColumnArity {
     /// Required column.
     Required,
@@ -389,7 +478,8 @@ impl ColumnArity {
 }
 
 /// Foreign key action types (for ON DELETE|ON UPDATE).
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ForeignKeyAction {
     /// Produce an error indicating that the deletion or update would create a foreign key
     /// constraint violation. If the constraint is deferred, this error will be produced at
@@ -410,7 +500,8 @@ pub enum ForeignKeyAction {
 }
 
 /// A foreign key.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ForeignKey {
     /// The database name of the foreign key constraint, when available.
     pub constraint_name: Option<String>,
@@ -424,6 +515,9 @@ pub struct ForeignKey {
     pub on_delete_action: ForeignKeyAction,
     /// Action on update.
     pub on_update_action: ForeignKeyAction,
+    /// Whether the constraint is `DEFERRABLE INITIALLY DEFERRED`. Only described on Postgres.
+    #[serde(default)]
+    pub deferrable: bool,
 }
 
 impl PartialEq for ForeignKey {
@@ -435,7 +529,8 @@ impl PartialEq for ForeignKey {
 }
 
 /// A SQL enum.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Enum {
     /// Enum name.
     pub name: String,
@@ -444,20 +539,36 @@ pub struct Enum {
 }
 
 /// A SQL sequence.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Sequence {
     /// Sequence name.
     pub name: String,
 }
 
-#[derive(PartialEq, Debug, Clone)]
+/// A SQL view.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// View name.
+    pub name: String,
+    /// The SQL definition of the view, if the database keeps it.
+    pub definition: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DefaultValue {
     kind: DefaultKind,
     constraint_name: Option<String>,
+    /// Whether the database also sets the value on updates of the row, with `ON UPDATE` on MySQL
+    /// and a trigger on Postgres.
+    #[serde(default)]
+    on_update: bool,
 }
 
 /// A DefaultValue
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum DefaultKind {
     /// A constant value, parsed as String
     VALUE(PrismaValue),
@@ -478,6 +589,13 @@ impl DefaultValue {
         Self::new(DefaultKind::NOW)
     }
 
+    /// The current timestamp, set on inserts and on every update of the row.
+    pub fn now_on_update() -> Self {
+        let mut default = Self::now();
+        default.set_on_update();
+        default
+    }
+
     pub fn value(val: impl Into<PrismaValue>) -> Self {
         Self::new(DefaultKind::VALUE(val.into()))
     }
@@ -490,6 +608,7 @@ impl DefaultValue {
         Self {
             kind,
             constraint_name: None,
+            on_update: false,
         }
     }
 
@@ -505,6 +624,14 @@ impl DefaultValue {
         self.constraint_name.as_deref()
     }
 
+    pub fn set_on_update(&mut self) {
+        self.on_update = true
+    }
+
+    pub fn on_update(&self) -> bool {
+        self.on_update
+    }
+
     pub fn as_value(&self) -> Option<&PrismaValue> {
         match self.kind {
             DefaultKind::VALUE(ref v) => Some(v),
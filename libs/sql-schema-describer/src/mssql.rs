@@ -5,7 +5,6 @@ use indoc::indoc;
 use native_types::NativeType;
 use native_types::{MsSqlType, MsSqlTypeParameter};
 use once_cell::sync::Lazy;
-use quaint::{prelude::Queryable, single::Quaint};
 use regex::Regex;
 use std::borrow::Cow;
 use std::{
@@ -51,7 +50,7 @@ static DEFAULT_DB_GEN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.*)\)").unwrap
 
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
-    conn: Quaint,
+    conn: DescriberConnection,
 }
 
 #[async_trait::async_trait]
@@ -84,13 +83,48 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(table);
         }
 
+        let views = self.get_views(schema).await?;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 
+    #[tracing::instrument]
+    async fn get_statistics(&self, schema: &str) -> DescriberResult<Vec<TableStatistics>> {
+        // The heap or clustered index (index_id 0 or 1) holds the rows, the other indexes are
+        // counted as the index size. Pages are 8 KiB.
+        let sql = indoc! {r#"
+            SELECT
+                t.name AS table_name,
+                SUM(CASE WHEN p.index_id IN (0, 1) THEN p.row_count ELSE 0 END) AS row_count,
+                SUM(CASE WHEN p.index_id IN (0, 1) THEN p.used_page_count ELSE 0 END) * 8192 AS data_size,
+                SUM(CASE WHEN p.index_id > 1 THEN p.used_page_count ELSE 0 END) * 8192 AS index_size
+            FROM sys.tables t
+            INNER JOIN sys.dm_db_partition_stats p ON t.object_id = p.object_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+                AND t.is_ms_shipped = 0
+            GROUP BY t.name
+            ORDER BY t.name
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TableStatistics {
+                table: row.get_expect_string("table_name"),
+                row_count: row.get_i64("row_count").and_then(|count| count.try_into().ok()),
+                data_size_in_bytes: row.get_i64("data_size").and_then(|size| size.try_into().ok()),
+                index_size_in_bytes: row.get_i64("index_size").and_then(|size| size.try_into().ok()),
+                null_fractions: BTreeMap::new(),
+            })
+            .collect())
+    }
+
     #[tracing::instrument]
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>> {
         Ok(self.conn.version().await?)
@@ -100,8 +134,8 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 impl Parser for SqlSchemaDescriber {}
 
 impl SqlSchemaDescriber {
-    pub fn new(conn: Quaint) -> Self {
-        Self { conn }
+    pub fn new(conn: impl Into<DescriberConnection>) -> Self {
+        Self { conn: conn.into() }
     }
 
     #[tracing::instrument]
@@ -116,6 +150,32 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument]
+    async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
+        let select = r#"
+            SELECT v.name AS view_name, OBJECT_DEFINITION(v.object_id) AS view_sql
+            FROM sys.views v
+            WHERE SCHEMA_NAME(v.schema_id) = @P1
+            AND v.is_ms_shipped = 0
+            ORDER BY v.name asc;
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await?;
+
+        let views = rows
+            .into_iter()
+            .map(|row| View {
+                name: row.get_expect_string("view_name"),
+                // The definition is hidden for views created `WITH ENCRYPTION`.
+                definition: row.get_string("view_sql"),
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_table_names(&self, schema: &str) -> DescriberResult<Vec<String>> {
         let select = r#"
@@ -188,6 +248,8 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            engine: None,
+            row_format: None,
         }
     }
 
@@ -199,6 +261,7 @@ impl SqlSchemaDescriber {
                 OBJECT_DEFINITION(c.default_object_id)             AS column_default,
                 c.is_nullable                                      AS is_nullable,
                 COLUMNPROPERTY(c.object_id, c.name, 'IsIdentity')  AS is_identity,
+                c.is_computed                                      AS is_computed,
                 OBJECT_NAME(c.object_id)                           AS table_name,
                 OBJECT_NAME(c.default_object_id)                   AS constraint_name,
                 convert(tinyint, CASE
@@ -246,6 +309,7 @@ impl SqlSchemaDescriber {
             );
 
             let auto_increment = col.get_expect_bool("is_identity");
+            let computed = col.get_expect_bool("is_computed");
             let entry = map.entry(table_name).or_insert_with(Vec::new);
 
             let default = match col.get("column_default") {
@@ -312,6 +376,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                computed,
             });
         }
 
@@ -330,6 +395,7 @@ impl SqlSchemaDescriber {
                 ind.name AS index_name,
                 ind.is_unique AS is_unique,
                 ind.is_primary_key AS is_primary_key,
+                CAST(CASE WHEN ind.type = 1 THEN 1 ELSE 0 END AS BIT) AS is_clustered,
                 col.name AS column_name,
                 ic.key_ordinal AS seq_in_index,
                 t.name AS table_name
@@ -361,6 +427,7 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get_expect_i64("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = row.get_expect_bool("is_unique");
+                    let is_clustered = row.get_expect_bool("is_clustered");
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -393,6 +460,7 @@ impl SqlSchemaDescriber {
                                     columns: vec![column_name],
                                     sequence: None,
                                     constraint_name: Some(index_name),
+                                    clustered: Some(is_clustered),
                                 });
                             }
                         };
@@ -410,6 +478,9 @@ impl SqlSchemaDescriber {
                                     true => IndexType::Unique,
                                     false => IndexType::Normal,
                                 },
+                                clustered: Some(is_clustered),
+                                deferrable: false,
+                                method: None,
                             },
                         );
                     }
@@ -519,6 +590,7 @@ impl SqlSchemaDescriber {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        deferrable: false,
                     };
 
                     intermediate_fks.insert(constraint_name, fk);
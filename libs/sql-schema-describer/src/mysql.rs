@@ -3,7 +3,7 @@ use crate::getters::Getter;
 use crate::parsers::Parser;
 use bigdecimal::ToPrimitive;
 use native_types::{MySqlType, NativeType};
-use quaint::{prelude::Queryable, single::Quaint, Value};
+use quaint::{prelude::Queryable, Value};
 use serde_json::from_str;
 use std::{
     borrow::Cow,
@@ -31,7 +31,7 @@ impl Flavour {
 }
 
 pub struct SqlSchemaDescriber {
-    conn: Quaint,
+    conn: DescriberConnection,
 }
 
 #[async_trait::async_trait]
@@ -63,21 +63,50 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = Self::get_all_columns(&self.conn, schema, &flavour).await?;
         let mut indexes = Self::get_all_indexes(&self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(&self.conn, schema).await?;
+        let mut table_options = Self::get_table_options(&self.conn, schema).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut table_options);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let views = self.get_views(schema).await?;
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_statistics(&self, schema: &str) -> DescriberResult<Vec<TableStatistics>> {
+        let sql = "
+            SELECT table_name as table_name, table_rows as table_rows,
+                data_length as data_length, index_length as index_length
+            FROM information_schema.tables
+            WHERE table_schema = ?
+            AND table_type = 'BASE TABLE'
+            ORDER BY table_name
+        ";
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TableStatistics {
+                table: row.get_expect_string("table_name"),
+                row_count: row.get_i64("table_rows").map(|count| count as u64),
+                data_size_in_bytes: row.get_i64("data_length").map(|size| size as u64),
+                index_size_in_bytes: row.get_i64("index_length").map(|size| size as u64),
+                null_fractions: BTreeMap::new(),
+            })
+            .collect())
+    }
+
     #[tracing::instrument(skip(self))]
     async fn version(&self, schema: &str) -> crate::DescriberResult<Option<String>> {
         Ok(self.conn.version().await?)
@@ -88,8 +117,8 @@ impl Parser for SqlSchemaDescriber {}
 
 impl SqlSchemaDescriber {
     /// Constructor.
-    pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+    pub fn new(conn: impl Into<DescriberConnection>) -> SqlSchemaDescriber {
+        SqlSchemaDescriber { conn: conn.into() }
     }
 
     #[tracing::instrument(skip(self))]
@@ -106,11 +135,30 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
+        let sql = "SELECT table_name as view_name, view_definition as view_sql FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let views = rows
+            .into_iter()
+            .map(|row| View {
+                name: row.get_expect_string("view_name"),
+                definition: row.get_string("view_sql"),
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_table_names(&self, schema: &str) -> DescriberResult<Vec<String>> {
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
+            -- Views are described on their own
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -148,16 +196,18 @@ impl SqlSchemaDescriber {
         Ok(size as usize)
     }
 
-    #[tracing::instrument(skip(self, columns, indexes, foreign_keys))]
+    #[tracing::instrument(skip(self, columns, indexes, foreign_keys, table_options))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        table_options: &mut HashMap<String, (Option<String>, Option<String>)>,
     ) -> (Table, Vec<Enum>) {
         let (columns, enums) = columns.remove(name).unwrap_or((vec![], vec![]));
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
+        let (engine, row_format) = table_options.remove(name).unwrap_or((None, None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
 
@@ -168,6 +218,8 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                engine,
+                row_format,
             },
             enums,
         )
@@ -288,6 +340,7 @@ impl SqlSchemaDescriber {
                             )),
                             //todo check other now() definitions
                             ColumnTypeFamily::DateTime => match Self::default_is_current_timestamp(&default_string) {
+                                true if extra.contains("on update current_timestamp") => DefaultValue::now_on_update(),
                                 true => DefaultValue::now(),
                                 _ => DefaultValue::db_generated(default_string),
                             },
@@ -308,6 +361,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                computed: false,
             };
 
             entry.0.push(col);
@@ -316,6 +370,48 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    /// The storage engine and row format of each table. The engine is only returned when it is not
+    /// the default `InnoDB`, and the row format only when it was explicitly set on the table.
+    async fn get_table_options(
+        conn: &dyn Queryable,
+        schema_name: &str,
+    ) -> DescriberResult<HashMap<String, (Option<String>, Option<String>)>> {
+        let sql = "
+            SELECT table_name as table_name, engine as engine, create_options as create_options
+            FROM information_schema.tables
+            WHERE table_schema = ?
+            AND table_type = 'BASE TABLE'
+        ";
+
+        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+        let mut map = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+
+            let engine = row
+                .get_string("engine")
+                .filter(|engine| !engine.eq_ignore_ascii_case("InnoDB"));
+
+            let row_format = row.get_string("create_options").and_then(|options| {
+                options.split_whitespace().find_map(|option| {
+                    let mut parts = option.splitn(2, '=');
+
+                    match (parts.next(), parts.next()) {
+                        (Some(key), Some(value)) if key.eq_ignore_ascii_case("row_format") => {
+                            Some(value.to_uppercase())
+                        }
+                        _ => None,
+                    }
+                })
+            });
+
+            map.insert(table_name, (engine, row_format));
+        }
+
+        Ok(map)
+    }
+
     async fn get_all_indexes(
         conn: &dyn Queryable,
         schema_name: &str,
@@ -376,6 +472,7 @@ impl SqlSchemaDescriber {
                                     columns: vec![column_name],
                                     sequence: None,
                                     constraint_name: None,
+                                    clustered: None,
                                 });
                             }
                         };
@@ -393,6 +490,9 @@ impl SqlSchemaDescriber {
                                     true => IndexType::Unique,
                                     false => IndexType::Normal,
                                 },
+                                clustered: None,
+                                deferrable: false,
+                                method: None,
                             },
                         );
                     }
@@ -499,6 +599,7 @@ impl SqlSchemaDescriber {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        deferrable: false,
                     };
                     intermediate_fks.insert(constraint_name, fk);
                 }
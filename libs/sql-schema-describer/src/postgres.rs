@@ -3,17 +3,25 @@
 use super::*;
 use crate::getters::Getter;
 use crate::parsers::Parser;
+use bigdecimal::ToPrimitive;
 use native_types::{NativeType, PostgresType};
 use quaint::connector::ResultRow;
-use quaint::{prelude::Queryable, single::Quaint};
 use regex::Regex;
 use serde_json::from_str;
-use std::{borrow::Cow, collections::HashMap, convert::TryInto};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+};
 use tracing::trace;
 
+/// The prefix of the names of the triggers setting the `@updatedAt` columns, followed by the name
+/// of the column.
+pub const UPDATED_AT_TRIGGER_PREFIX: &str = "prisma_updated_at_";
+
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
-    conn: Quaint,
+    conn: DescriberConnection,
 }
 
 #[async_trait::async_trait]
@@ -32,11 +40,64 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         })
     }
 
+    #[tracing::instrument]
+    async fn get_statistics(&self, schema: &str) -> DescriberResult<Vec<TableStatistics>> {
+        let sql = r#"
+            SELECT c.relname AS table_name, c.reltuples::bigint AS row_count,
+                pg_table_size(c.oid) AS data_size, pg_indexes_size(c.oid) AS index_size
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1 AND c.relkind = 'r'
+            ORDER BY c.relname
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        let mut statistics: Vec<TableStatistics> = rows
+            .into_iter()
+            .map(|row| TableStatistics {
+                table: row.get_expect_string("table_name"),
+                // Tables that were never analyzed have a negative estimate.
+                row_count: row.get_i64("row_count").and_then(|count| count.try_into().ok()),
+                data_size_in_bytes: row.get_i64("data_size").and_then(|size| size.try_into().ok()),
+                index_size_in_bytes: row.get_i64("index_size").and_then(|size| size.try_into().ok()),
+                null_fractions: BTreeMap::new(),
+            })
+            .collect();
+
+        let sql = r#"
+            SELECT tablename AS table_name, attname AS column_name, null_frac::numeric AS null_fraction
+            FROM pg_stats
+            WHERE schemaname = $1
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let null_fraction = row
+                .get("null_fraction")
+                .and_then(|value| value.as_numeric())
+                .and_then(|fraction| fraction.to_f64());
+
+            let table_statistics = statistics.iter_mut().find(|stats| stats.table == table_name);
+
+            if let (Some(table_statistics), Some(null_fraction)) = (table_statistics, null_fraction) {
+                table_statistics
+                    .null_fractions
+                    .insert(row.get_expect_string("column_name"), null_fraction);
+            }
+        }
+
+        Ok(statistics)
+    }
+
     #[tracing::instrument]
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
         let sequences = self.get_sequences(schema).await?;
         let enums = self.get_enums(schema).await?;
         let mut columns = self.get_columns(schema, &enums, &sequences).await?;
+        self.mark_updated_at_triggers(schema, &mut columns).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
         let mut indexes = self.get_indices(schema, &sequences).await?;
 
@@ -47,10 +108,13 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
         }
 
+        let views = self.get_views(schema).await?;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 
@@ -75,8 +139,8 @@ impl Parser for SqlSchemaDescriber {
 
 impl SqlSchemaDescriber {
     /// Constructor.
-    pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+    pub fn new(conn: impl Into<DescriberConnection>) -> SqlSchemaDescriber {
+        SqlSchemaDescriber { conn: conn.into() }
     }
 
     #[tracing::instrument]
@@ -98,7 +162,7 @@ impl SqlSchemaDescriber {
         let sql = "
             SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described on their own
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -143,6 +207,8 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices,
             primary_key,
+            engine: None,
+            row_format: None,
         }
     }
 
@@ -209,6 +275,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                computed: false,
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -288,6 +355,7 @@ impl SqlSchemaDescriber {
                 att.attname as "parent_column",
                 con.confdeltype,
                 con.confupdtype,
+                con.condeferrable AND con.condeferred AS is_deferred,
                 conname as constraint_name,
                 child,
                 parent,
@@ -303,7 +371,9 @@ impl SqlSchemaDescriber {
                     con1.conrelid,
                     con1.conname,
                     con1.confdeltype,
-                    con1.confupdtype
+                    con1.confupdtype,
+                    con1.condeferrable,
+                    con1.condeferred
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
@@ -336,6 +406,7 @@ impl SqlSchemaDescriber {
             let confdeltype = row.get_expect_char("confdeltype");
             let confupdtype = row.get_expect_char("confupdtype");
             let constraint_name = row.get_expect_string("constraint_name");
+            let deferrable = row.get_expect_bool("is_deferred");
 
             let on_delete_action = match confdeltype {
                 'a' => ForeignKeyAction::NoAction,
@@ -366,6 +437,7 @@ impl SqlSchemaDescriber {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        deferrable,
                     };
                     intermediate_fks.insert(id, (table_name, fk));
                 }
@@ -410,6 +482,11 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            EXISTS (
+                SELECT 1 FROM pg_constraint
+                WHERE conindid = indexInfos.oid AND contype = 'u' AND condeferrable AND condeferred
+            ) AS is_deferred,
+            (SELECT amname FROM pg_am WHERE pg_am.oid = indexInfos.relam) AS index_method,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -425,8 +502,8 @@ impl SqlSchemaDescriber {
                     pg_index.indkey AS indkey,
                     generate_subscripts(pg_index.indkey, 1) AS indkeyidx
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
+                -- ignores partial indexes and the indexes backing exclusion constraints
+                Where indpred is Null AND NOT indisexclusion
                 GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
@@ -447,7 +524,7 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.oid, indexInfos.relname, indexInfos.relam, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
         ORDER BY rawIndex.indkeyidx
         "#;
 
@@ -461,6 +538,8 @@ impl SqlSchemaDescriber {
             let is_primary_key = row.get_expect_bool("is_primary_key");
             let table_name = row.get_expect_string("table_name");
             let sequence_name = row.get_string("sequence_name");
+            let is_deferred = row.get_expect_bool("is_deferred");
+            let index_method = row.get_string("index_method");
 
             if is_primary_key {
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
@@ -484,6 +563,7 @@ impl SqlSchemaDescriber {
                             columns: vec![column_name],
                             sequence,
                             constraint_name: Some(name.clone()),
+                            clustered: None,
                         });
                     }
                 }
@@ -500,6 +580,9 @@ impl SqlSchemaDescriber {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        clustered: None,
+                        deferrable: is_deferred,
+                        method: index_method.as_deref().and_then(parse_index_method),
                     })
                 }
             }
@@ -508,6 +591,47 @@ impl SqlSchemaDescriber {
         Ok(indexes_map)
     }
 
+    /// Marks the `now()` defaults of the columns set by an updated at trigger, see
+    /// `UPDATED_AT_TRIGGER_PREFIX`.
+    #[tracing::instrument(skip(columns))]
+    async fn mark_updated_at_triggers(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, Vec<Column>>,
+    ) -> DescriberResult<()> {
+        let sql = "
+            SELECT tbl.relname AS table_name, trg.tgname AS trigger_name
+            FROM pg_trigger trg
+            JOIN pg_class tbl ON tbl.oid = trg.tgrelid
+            JOIN pg_namespace ns ON ns.oid = tbl.relnamespace
+            WHERE ns.nspname = $1
+            AND NOT trg.tgisinternal
+            AND left(trg.tgname, length($2::text)) = $2::text";
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), UPDATED_AT_TRIGGER_PREFIX.into()])
+            .await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let trigger_name = row.get_expect_string("trigger_name");
+            let column_name = &trigger_name[UPDATED_AT_TRIGGER_PREFIX.len()..];
+
+            let default = columns
+                .get_mut(&table_name)
+                .and_then(|columns| columns.iter_mut().find(|column| column.name == column_name))
+                .and_then(|column| column.default.as_mut())
+                .filter(|default| default.is_now());
+
+            if let Some(default) = default {
+                default.set_on_update();
+            }
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
         let sql = "SELECT sequence_name
@@ -528,6 +652,27 @@ impl SqlSchemaDescriber {
         Ok(sequences)
     }
 
+    #[tracing::instrument]
+    async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
+        let sql = "
+            SELECT viewname AS view_name, definition AS view_sql
+            FROM pg_catalog.pg_views
+            WHERE schemaname = $1
+            ORDER BY viewname";
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let views = rows
+            .into_iter()
+            .map(|row| View {
+                name: row.get_expect_string("view_name"),
+                definition: row.get_string("view_sql"),
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_enums(&self, schema: &str) -> DescriberResult<Vec<Enum>> {
         let sql = "
@@ -668,12 +813,12 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "int2" | "_int2" => (Int, Some(PostgresType::SmallInt)),
         "int4" | "_int4" => (Int, Some(PostgresType::Integer)),
         "int8" | "_int8" => (BigInt, Some(PostgresType::BigInt)),
-        "oid" | "_oid" => (Int, None),
+        "oid" | "_oid" => (Int, Some(PostgresType::Oid)),
         "float4" | "_float4" => (Float, Some(PostgresType::Real)),
         "float8" | "_float8" => (Float, Some(PostgresType::DoublePrecision)),
         "bool" | "_bool" => (Boolean, Some(PostgresType::Boolean)),
         "text" | "_text" => (String, Some(PostgresType::Text)),
-        "citext" | "_citext" => (String, None),
+        "citext" | "_citext" => (String, Some(PostgresType::Citext)),
         "varchar" | "_varchar" => (String, Some(PostgresType::VarChar(precision.character_maximum_length))),
         "bpchar" | "_bpchar" => (String, Some(PostgresType::Char(precision.character_maximum_length))),
         "date" | "_date" => (DateTime, Some(PostgresType::Date)),
@@ -695,7 +840,7 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
                 },
             )),
         ),
-        "money" | "_money" => (Float, None),
+        "money" | "_money" => (Decimal, Some(PostgresType::Money)),
         "pg_lsn" | "_pg_lsn" => unsupported_type(),
         "time" | "_time" => (DateTime, Some(PostgresType::Time(precision.time_precision))),
         "timetz" | "_timetz" => (DateTime, Some(PostgresType::Timetz(precision.time_precision))),
@@ -704,7 +849,8 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "tsquery" | "_tsquery" => unsupported_type(),
         "tsvector" | "_tsvector" => unsupported_type(),
         "txid_snapshot" | "_txid_snapshot" => unsupported_type(),
-        "inet" | "_inet" => (String, None),
+        "inet" | "_inet" => (String, Some(PostgresType::Inet)),
+        "interval" | "_interval" => (String, Some(PostgresType::Interval)),
         //geometric
         "box" | "_box" => unsupported_type(),
         "circle" | "_circle" => unsupported_type(),
@@ -793,6 +939,18 @@ fn chain_replaces<'a>(s: &'a str, replaces: &[(&Lazy<Regex>, &str)]) -> Cow<'a,
     out
 }
 
+/// The non-default index methods, by their name in `pg_am`.
+fn parse_index_method(amname: &str) -> Option<IndexMethod> {
+    match amname {
+        "hash" => Some(IndexMethod::Hash),
+        "gist" => Some(IndexMethod::Gist),
+        "spgist" => Some(IndexMethod::SpGist),
+        "gin" => Some(IndexMethod::Gin),
+        "brin" => Some(IndexMethod::Brin),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,13 +1,14 @@
 //! SQLite description.
 use super::*;
 use crate::parsers::Parser;
-use quaint::{ast::Value, prelude::Queryable, single::Quaint};
+use native_types::{NativeType, SqliteType};
+use quaint::ast::Value;
 use std::{borrow::Cow, collections::HashMap, convert::TryInto};
 use tracing::trace;
 
 #[derive(Debug)]
 pub struct SqlSchemaDescriber {
-    conn: Quaint,
+    conn: DescriberConnection,
 }
 
 #[async_trait::async_trait]
@@ -52,12 +53,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await?;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables,
+            views,
         })
     }
 
@@ -71,8 +75,8 @@ impl Parser for SqlSchemaDescriber {}
 
 impl SqlSchemaDescriber {
     /// Constructor.
-    pub fn new(conn: Quaint) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+    pub fn new(conn: impl Into<DescriberConnection>) -> SqlSchemaDescriber {
+        SqlSchemaDescriber { conn: conn.into() }
     }
 
     #[tracing::instrument]
@@ -94,6 +98,25 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    async fn get_views(&self, _schema: &str) -> DescriberResult<Vec<View>> {
+        let sql = r#"SELECT name AS view_name, sql AS view_sql FROM sqlite_master WHERE type='view' ORDER BY name ASC"#;
+        trace!("describing views with query: '{}'", sql);
+
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
+
+        let views = result_set
+            .into_iter()
+            .map(|row| View {
+                name: row.get("view_name").and_then(|x| x.to_string()).unwrap(),
+                definition: row.get("view_sql").and_then(|x| x.to_string()),
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     async fn get_table_names(&self, _schema: &str) -> DescriberResult<Vec<String>> {
         let sql = r#"SELECT name FROM sqlite_master WHERE type='table' ORDER BY name ASC"#;
         trace!("describing table names with query: '{}'", sql);
@@ -135,6 +158,8 @@ impl SqlSchemaDescriber {
             indices,
             primary_key,
             foreign_keys,
+            engine: None,
+            row_format: None,
         })
     }
 
@@ -216,6 +241,7 @@ impl SqlSchemaDescriber {
                     tpe,
                     default,
                     auto_increment: false,
+                    computed: false,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -263,6 +289,7 @@ impl SqlSchemaDescriber {
                 columns,
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             })
         };
 
@@ -378,6 +405,7 @@ impl SqlSchemaDescriber {
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
                     constraint_name: None,
+                    deferrable: false,
                 };
 
                 trace!("Detected foreign key {:?}", fk);
@@ -414,6 +442,9 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                clustered: None,
+                deferrable: false,
+                method: None,
             };
 
             let sql = format!(r#"PRAGMA index_info("{}");"#, name);
@@ -474,10 +505,43 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         character_maximum_length: None,
         family,
         arity,
-        native_type: None,
+        native_type: get_native_type(&tpe_lower).map(|x| x.to_json()),
     }
 }
 
+/// The native type of a declared column type. Only the declared types that map to a native type
+/// exactly are preserved, so e.g. `INT` columns, which are not aliases of the rowid like `INTEGER`
+/// columns, are not introspected as `Integer`.
+fn get_native_type(tpe_lower: &str) -> Option<SqliteType> {
+    static PRECISION_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"^(\w+)\s*\(\s*(\d+)\s*(?:,\s*(\d+)\s*)?\)$"#).unwrap());
+
+    let native_type = match tpe_lower.trim() {
+        "integer" => SqliteType::Integer,
+        "real" => SqliteType::Real,
+        "text" => SqliteType::Text,
+        "blob" => SqliteType::Blob,
+        "numeric" => SqliteType::Numeric,
+        "decimal" => SqliteType::Decimal(None),
+        "varchar" => SqliteType::VarChar(None),
+        "boolean" => SqliteType::Boolean,
+        "datetime" => SqliteType::DateTime,
+        tpe => {
+            let captures = PRECISION_RE.captures(tpe)?;
+            let first = captures.get(2)?.as_str().parse().ok()?;
+            let second = captures.get(3).map(|m| m.as_str().parse().ok());
+
+            match (captures.get(1)?.as_str(), second) {
+                ("decimal", Some(scale)) => SqliteType::Decimal(Some((first, scale?))),
+                ("varchar", None) => SqliteType::VarChar(Some(first)),
+                _ => return None,
+            }
+        }
+    };
+
+    Some(native_type)
+}
+
 // "A string constant is formed by enclosing the string in single quotes ('). A single quote within
 // the string can be encoded by putting two single quotes in a row - as in Pascal. C-style escapes
 // using the backslash character are not supported because they are not standard SQL."
@@ -103,6 +103,11 @@ impl<'a> ColumnWalker<'a> {
         self.column().auto_increment
     }
 
+    /// Is this column computed by the database?
+    pub fn is_computed(&self) -> bool {
+        self.column().computed
+    }
+
     /// Is this column a part of the table's primary key?
     pub fn is_part_of_primary_key(&self) -> bool {
         self.table().table().is_part_of_primary_key(self.name())
@@ -262,6 +267,16 @@ impl<'a> TableWalker<'a> {
         self.table().primary_key.as_ref()
     }
 
+    /// The storage engine of the table, when it is not the default.
+    pub fn engine(&self) -> Option<&'a str> {
+        self.table().engine.as_deref()
+    }
+
+    /// The row format of the table, when it was explicitly set.
+    pub fn row_format(&self) -> Option<&'a str> {
+        self.table().row_format.as_deref()
+    }
+
     /// The names of the columns that are part of the primary key. `None` means
     /// there is no primary key on the table.
     pub fn primary_key_column_names(&self) -> Option<&[String]> {
@@ -339,6 +354,11 @@ impl<'schema> ForeignKeyWalker<'schema> {
         &self.foreign_key().on_delete_action
     }
 
+    /// Whether the foreign key is `DEFERRABLE INITIALLY DEFERRED`. Only described on Postgres.
+    pub fn deferrable(&self) -> bool {
+        self.foreign_key().deferrable
+    }
+
     /// The `ON UPDATE` behaviour of the foreign key.
     pub fn on_update_action(&self) -> &ForeignKeyAction {
         &self.foreign_key().on_update_action
@@ -433,6 +453,21 @@ impl<'a> IndexWalker<'a> {
         &self.get().tpe
     }
 
+    /// Whether the index is clustered. Only described on SQL Server.
+    pub fn clustered(&self) -> Option<bool> {
+        self.get().clustered
+    }
+
+    /// Whether the unique constraint is `DEFERRABLE INITIALLY DEFERRED`. Only described on Postgres.
+    pub fn deferrable(&self) -> bool {
+        self.get().deferrable
+    }
+
+    /// The index method, when it is not the default B-tree. Only described on Postgres.
+    pub fn method(&self) -> Option<IndexMethod> {
+        self.get().method
+    }
+
     /// The name of the index.
     pub fn name(&self) -> &str {
         &self.get().name
@@ -1,6 +1,6 @@
 use crate::{common::*, test_api::*};
 use barrel::types;
-use native_types::{MsSqlType, MsSqlTypeParameter, MySqlType, NativeType, PostgresType};
+use native_types::{MsSqlType, MsSqlTypeParameter, MySqlType, NativeType, PostgresType, SqliteType};
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
 use quaint::prelude::{Queryable, SqlFamily};
@@ -28,7 +28,7 @@ fn int_full_data_type(api: &TestApi) -> String {
 fn int_native_type(api: &TestApi) -> Option<Value> {
     match (api.sql_family(), api.connector_name()) {
         (SqlFamily::Postgres, _) => Some(PostgresType::Integer.to_json()),
-        (SqlFamily::Sqlite, _) => None,
+        (SqlFamily::Sqlite, _) => Some(SqliteType::Integer.to_json()),
         (SqlFamily::Mysql, "mysql8") => Some(MySqlType::Int.to_json()),
         (SqlFamily::Mysql, _) => Some(MySqlType::Int.to_json()),
         (SqlFamily::Mssql, _) => Some(MsSqlType::Int.to_json()),
@@ -68,7 +68,7 @@ fn varchar_full_data_type(api: &TestApi, length: u64) -> String {
 fn varchar_native_type(api: &TestApi, length: u32) -> Option<Value> {
     match (api.sql_family(), api.connector_name()) {
         (SqlFamily::Postgres, _) => Some(PostgresType::VarChar(Some(length)).to_json()),
-        (SqlFamily::Sqlite, _) => None,
+        (SqlFamily::Sqlite, _) => Some(SqliteType::VarChar(Some(length)).to_json()),
         (SqlFamily::Mysql, "mysql8") => Some(MySqlType::VarChar(length).to_json()),
         (SqlFamily::Mysql, _) => Some(MySqlType::VarChar(length).to_json()),
         (SqlFamily::Mssql, _) => Some(MsSqlType::VarChar(Some(MsSqlTypeParameter::Number(length as u16))).to_json()),
@@ -101,6 +101,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "column2".to_string(),
@@ -115,6 +116,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
 
@@ -158,6 +160,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        computed: false,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -169,6 +172,9 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }]
     } else {
         vec![]
@@ -193,7 +199,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 referenced_table: "City".to_string(),
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
             }],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -253,6 +262,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "city_name".to_string(),
@@ -270,6 +280,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
 
@@ -278,6 +289,9 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }]
     } else {
         vec![]
@@ -308,7 +322,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 referenced_table: "City".to_string(),
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
             },],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -337,6 +354,7 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        computed: false,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -388,6 +406,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "name".to_string(),
@@ -405,6 +424,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -422,9 +442,16 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                     SqlFamily::Postgres => Some("User_pkey".into()),
                     SqlFamily::Mssql => Some("PK_User".into()),
                     _ => None,
-                }
+                },
+                clustered: if api.sql_family() == SqlFamily::Mssql {
+                    Some(true)
+                } else {
+                    None
+                },
             }),
             foreign_keys: vec![],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -461,6 +488,7 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "count".to_string(),
@@ -475,6 +503,7 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     let pk_sequence = match api.sql_family() {
@@ -492,6 +521,13 @@ async fn indices_must_work(api: &TestApi) {
             name: "count".to_string(),
             columns: vec!["count".to_string()],
             tpe: IndexType::Normal,
+            clustered: if api.sql_family() == SqlFamily::Mssql {
+                Some(false)
+            } else {
+                None
+            },
+            deferrable: false,
+            method: None,
         }],
         user_table.indices
     );
@@ -543,6 +579,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "uniq2".to_string(),
@@ -558,18 +595,29 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     let mut expected_indices = vec![Index {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        clustered: if api.sql_family() == SqlFamily::Mssql {
+            Some(false)
+        } else {
+            None
+        },
+        deferrable: false,
+        method: None,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -577,12 +625,18 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                clustered: None,
+                deferrable: false,
+                method: None,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -590,6 +644,9 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                clustered: Some(false),
+                deferrable: false,
+                method: None,
             },
         ),
     };
@@ -620,6 +677,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    engine: None,
+                    row_format: None,
                 }
             );
         }
@@ -672,7 +731,7 @@ async fn defaults_must_work(api: &TestApi) {
 
     let default = id.default.as_ref().unwrap();
 
-    if api.sql_family().is_mssql() {
+    if api.sql_family() == SqlFamily::Mssql {
         assert!(default.constraint_name().unwrap().starts_with("DF__User__id__"));
     }
 
@@ -64,6 +64,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "bit_col".to_string(),
@@ -78,6 +79,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -92,6 +94,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "int_col".to_string(),
@@ -105,6 +108,7 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "money_col".to_string(),
@@ -119,6 +123,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -133,6 +138,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -147,6 +153,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -161,6 +168,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -175,6 +183,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "float_col".to_string(),
@@ -189,6 +198,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "double_col".to_string(),
@@ -203,6 +213,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "date_col".to_string(),
@@ -217,6 +228,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -230,6 +242,7 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -244,6 +257,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -258,6 +272,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -272,6 +287,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "time_col".to_string(),
@@ -286,6 +302,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +317,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +332,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -328,6 +347,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "text_col".to_string(),
@@ -342,6 +362,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -356,6 +377,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -370,6 +392,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -384,6 +407,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "binary_col".to_string(),
@@ -398,6 +422,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -412,6 +437,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -426,6 +452,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "image_col".to_string(),
@@ -440,6 +467,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "xml_col".to_string(),
@@ -454,6 +482,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -517,6 +546,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                     name: "city".to_string(),
@@ -530,6 +560,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -543,6 +574,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: vec![],
@@ -550,6 +582,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: Some("PK__User".into()),
+                clustered: Some(true),
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -559,6 +592,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("FK__city_cascade".to_owned()),
@@ -567,8 +601,11 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::Cascade,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
                 },
             ],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -595,7 +632,10 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            clustered: Some(false),
+            deferrable: false,
+            method: None,
         }]
     );
 }
@@ -634,6 +674,9 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            clustered: Some(false),
+            deferrable: false,
+            method: None,
         }]
     );
 }
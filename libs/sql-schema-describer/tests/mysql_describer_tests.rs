@@ -77,6 +77,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "int_col".to_string(),
@@ -91,6 +92,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -105,6 +107,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -118,6 +121,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -132,6 +136,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -146,6 +151,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -160,6 +166,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -174,6 +181,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -188,6 +196,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "float_col".to_string(),
@@ -202,6 +211,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "double_col".to_string(),
@@ -216,6 +226,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "date_col".to_string(),
@@ -230,6 +241,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "time_col".to_string(),
@@ -244,6 +256,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -258,6 +271,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -272,6 +286,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "year_col".to_string(),
@@ -286,6 +301,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +316,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +331,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "text_col".to_string(),
@@ -328,6 +346,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -342,6 +361,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -356,6 +376,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -370,6 +391,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "enum_col".to_string(),
@@ -384,6 +406,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "set_col".to_string(),
@@ -398,6 +421,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "binary_col".to_string(),
@@ -412,6 +436,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -426,6 +451,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "blob_col".to_string(),
@@ -440,6 +466,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -455,6 +482,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -469,6 +497,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -483,6 +512,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -497,6 +527,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "point_col".to_string(),
@@ -511,6 +542,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -525,6 +557,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -539,6 +572,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -553,6 +587,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -567,6 +602,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -581,6 +617,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -595,6 +632,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "json_col".to_string(),
@@ -609,6 +647,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -623,8 +662,11 @@ async fn all_mysql_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -670,6 +712,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                     name: "city".to_string(),
@@ -683,6 +726,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -696,6 +740,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -709,6 +754,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -722,6 +768,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: vec![
@@ -729,27 +776,40 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }
             ],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -759,6 +819,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -767,6 +828,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
@@ -775,6 +837,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
@@ -783,8 +846,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
                     on_update_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
             ],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -812,6 +878,9 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }]
     );
 }
@@ -850,6 +919,9 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            clustered: None,
+            deferrable: false,
+            method: None,
         }]
     );
 }
@@ -890,6 +962,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -922,6 +995,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 }
@@ -80,6 +80,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_bool_col".into(),
@@ -93,6 +94,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_date_col".into(),
@@ -106,6 +108,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_double_col".into(),
@@ -119,6 +122,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_float_col".into(),
@@ -132,6 +136,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_int_col".into(),
@@ -145,6 +150,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_text_col".into(),
@@ -158,6 +164,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -171,6 +178,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "binary_col".into(),
@@ -184,6 +192,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "boolean_col".into(),
@@ -197,6 +206,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "date_time_col".into(),
@@ -210,6 +220,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "double_col".into(),
@@ -223,6 +234,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "float_col".into(),
@@ -236,6 +248,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "int_col".into(),
@@ -249,6 +262,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "primary_col".into(),
@@ -262,6 +276,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "string1_col".into(),
@@ -275,6 +290,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "string2_col".into(),
@@ -288,6 +304,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "bigint_col".into(),
@@ -301,6 +318,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "bigserial_col".into(),
@@ -314,6 +332,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "bit_col".into(),
@@ -327,6 +346,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -340,6 +360,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "box_col".into(),
@@ -353,6 +374,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "char_col".into(),
@@ -366,6 +388,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "circle_col".into(),
@@ -379,6 +402,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "line_col".into(),
@@ -392,6 +416,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "time_col".into(),
@@ -405,6 +430,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "timetz_col".into(),
@@ -418,6 +444,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "timestamp_col".into(),
@@ -431,6 +458,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -444,6 +472,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "lseg_col".into(),
@@ -457,6 +486,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "numeric_col".into(),
@@ -470,6 +500,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "path_col".into(),
@@ -483,6 +514,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -496,6 +528,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "polygon_col".into(),
@@ -509,6 +542,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smallint_col".into(),
@@ -522,6 +556,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "smallserial_col".into(),
@@ -535,6 +570,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "serial_col".into(),
@@ -548,6 +584,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "tsquery_col".into(),
@@ -561,6 +598,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "tsvector_col".into(),
@@ -574,6 +612,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "txid_col".into(),
@@ -587,6 +626,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "json_col".into(),
@@ -600,6 +640,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "jsonb_col".into(),
@@ -613,6 +654,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "uuid_col".into(),
@@ -627,6 +669,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -640,6 +683,9 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                clustered: None,
+                deferrable: false,
+                method: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -647,8 +693,11 @@ async fn all_postgres_column_types_must_work() {
                     name: "User_primary_col_seq".into(),
                 },),
                 constraint_name: Some("User_pkey".into()),
+                clustered: None,
             }),
             foreign_keys: vec![],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -693,6 +742,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city".into(),
@@ -707,6 +757,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -721,6 +772,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -735,6 +787,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -749,6 +802,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -763,6 +817,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: vec![],
@@ -770,6 +825,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".into()],
                 sequence: None,
                 constraint_name: Some("User_pkey".into()),
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -779,6 +835,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
@@ -787,6 +844,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
@@ -795,6 +853,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
@@ -803,6 +862,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
@@ -811,8 +871,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".into(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    deferrable: false,
                 },
             ],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -960,3 +1023,89 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_constraints_must_be_described(api: &TestApi) -> TestResult {
+    let create_tables = format!(
+        r#"
+            CREATE TABLE "{0}"."User" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                email TEXT NOT NULL,
+                CONSTRAINT "User_name_key" UNIQUE (name) DEFERRABLE INITIALLY DEFERRED,
+                CONSTRAINT "User_email_key" UNIQUE (email)
+            );
+
+            CREATE TABLE "{0}"."Post" (
+                id INTEGER PRIMARY KEY,
+                author_id INTEGER NOT NULL REFERENCES "{0}"."User" (id) DEFERRABLE INITIALLY DEFERRED,
+                editor_id INTEGER NOT NULL REFERENCES "{0}"."User" (id)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_tables).await?;
+
+    let schema = api.describe().await?;
+
+    let user = schema.table_bang("User");
+
+    assert!(
+        user.indices
+            .iter()
+            .find(|idx| idx.name == "User_name_key")
+            .unwrap()
+            .deferrable
+    );
+    assert!(
+        !user
+            .indices
+            .iter()
+            .find(|idx| idx.name == "User_email_key")
+            .unwrap()
+            .deferrable
+    );
+
+    let post = schema.table_bang("Post");
+    let fk_on = |column: &str| post.foreign_keys.iter().find(|fk| fk.columns == [column]).unwrap();
+
+    assert!(fk_on("author_id").deferrable);
+    assert!(!fk_on("editor_id").deferrable);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_methods_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Booking" (
+                id INTEGER PRIMARY KEY,
+                data JSONB NOT NULL,
+                name TEXT NOT NULL,
+                period TSRANGE NOT NULL,
+                EXCLUDE USING GIST (period WITH &&)
+            );
+
+            CREATE INDEX "Booking_data_idx" ON "{0}"."Booking" USING GIN (data);
+            CREATE INDEX "Booking_name_idx" ON "{0}"."Booking" (name);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Booking");
+
+    // The index backing the exclusion constraint is not described.
+    assert_eq!(table.indices.len(), 2);
+
+    let index_method = |name: &str| table.indices.iter().find(|idx| idx.name == name).unwrap().method;
+
+    assert_eq!(index_method("Booking_data_idx"), Some(IndexMethod::Gin));
+    assert_eq!(index_method("Booking_name_idx"), None);
+
+    Ok(())
+}
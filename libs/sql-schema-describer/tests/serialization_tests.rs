@@ -1,405 +1,202 @@
-// #![allow(non_snake_case)]
-// #![allow(unused)]
-//
-// use barrel::{types, Migration};
-// use native_types::{NativeType, PostgresType};
-// use pretty_assertions::assert_eq;
-// use prisma_value::PrismaValue;
-// use quaint::connector::{Queryable, Sqlite as SqliteDatabaseClient};
-// use sql_schema_describer::*;
-// use std::collections::HashSet;
-// use std::fs::File;
-// use std::path::Path;
-// use std::sync::atomic::{AtomicBool, Ordering};
-// use std::sync::{Arc, Mutex};
-// use std::{thread, time};
-// use tracing::debug;
-//
-// const SCHEMA: &str = "DatabaseInspectorTest";
-//
-// #[test]
-// fn database_schema_is_serializable() {
-//     let mut enum_values = vec!["option1".to_string(), "option2".to_string()];
-//     let schema = SqlSchema {
-//         tables: vec![
-//             Table {
-//                 name: "table1".to_string(),
-//                 columns: vec![
-//                     Column {
-//                         name: "column1".to_string(),
-//                         tpe: ColumnType {
-//                             data_type: "integer".to_string(),
-//                             full_data_type: "int".to_string(),
-//                             character_maximum_length: None,
-//                             native_type: Some(PostgresType::Integer.to_json()),
-//
-//                             family: ColumnTypeFamily::Int,
-//                             arity: ColumnArity::Required,
-//                         },
-//                         default: None,
-//                         auto_increment: true,
-//                     },
-//                     Column {
-//                         name: "column2".to_string(),
-//                         tpe: ColumnType {
-//                             data_type: "varchar(255)".to_string(),
-//                             full_data_type: "varchar(255)".to_string(),
-//                             character_maximum_length: None,
-//
-//                             family: ColumnTypeFamily::String,
-//                             arity: ColumnArity::Nullable,
-//                             native_type: Some(PostgresType::VarChar(Some(255)).to_json()),
-//                         },
-//                         default: Some(DefaultValue::value("default value".to_string())),
-//                         auto_increment: false,
-//                     },
-//                     Column {
-//                         name: "column3".to_string(),
-//                         tpe: ColumnType {
-//                             data_type: "integer".to_string(),
-//                             full_data_type: "integer".to_string(),
-//                             character_maximum_length: None,
-//
-//                             family: ColumnTypeFamily::Int,
-//                             arity: ColumnArity::Required,
-//                             native_type: Some(PostgresType::Integer.to_json()),
-//                         },
-//                         default: None,
-//                         auto_increment: false,
-//                     },
-//                 ],
-//                 indices: vec![Index {
-//                     name: "column2".to_string(),
-//                     columns: vec!["column2".to_string()],
-//                     tpe: IndexType::Normal,
-//                 }],
-//                 primary_key: Some(PrimaryKey {
-//                     columns: vec!["column1".to_string()],
-//                     sequence: None,
-//                     constraint_name: None,
-//                 }),
-//                 foreign_keys: vec![ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column3".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::NoAction,
-//                 }],
-//             },
-//             Table {
-//                 name: "table2".to_string(),
-//                 columns: vec![Column {
-//                     name: "id".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "integer".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Required,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     default: None,
-//                     auto_increment: true,
-//                 }],
-//                 indices: vec![],
-//                 primary_key: Some(PrimaryKey {
-//                     columns: vec!["id".to_string()],
-//                     sequence: None,
-//                     constraint_name: None,
-//                 }),
-//                 foreign_keys: vec![],
-//             },
-//         ],
-//         enums: vec![Enum {
-//             name: "enum1".to_string(),
-//             values: enum_values,
-//         }],
-//         sequences: vec![Sequence {
-//             name: "sequence1".to_string(),
-//         }],
-//     };
-//     let ref_schema_json = include_str!("./resources/schema.json");
-//     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
-//
-//     let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
-//     let schema_deser: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
-//
-//     // Verify that deserialized schema is equivalent
-//     assert_eq!(schema_deser, schema);
-//     // Verify that schema deserialized from reference JSON is equivalent
-//     assert_eq!(ref_schema, schema);
-// }
-//
-// #[test]
-// fn database_schema_without_primary_key_is_serializable() {
-//     let schema = SqlSchema {
-//         tables: vec![Table {
-//             name: "table1".to_string(),
-//             columns: vec![Column {
-//                 name: "column1".to_string(),
-//                 tpe: ColumnType {
-//                     data_type: "integer".to_string(),
-//                     full_data_type: "int".to_string(),
-//                     character_maximum_length: None,
-//
-//                     family: ColumnTypeFamily::Int,
-//                     arity: ColumnArity::Nullable,
-//                     native_type: Some(PostgresType::Integer.to_json()),
-//                 },
-//                 default: None,
-//                 auto_increment: false,
-//             }],
-//             indices: vec![],
-//             primary_key: None,
-//             foreign_keys: vec![],
-//         }],
-//         enums: vec![],
-//         sequences: vec![],
-//     };
-//     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
-//     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
-//
-//     let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
-//     let schema_deser: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
-//
-//     // Verify that deserialized schema is equivalent
-//     assert_eq!(schema_deser, schema);
-//     // Verify that schema deserialized from reference JSON is equivalent
-//     assert_eq!(ref_schema, schema);
-// }
-//
-// #[test]
-// fn database_schema_is_serializable_for_every_column_type_family() {
-//     // Add a column of every column type family
-//     let mut columns: Vec<Column> = vec![
-//         ColumnTypeFamily::Int,
-//         ColumnTypeFamily::Float,
-//         ColumnTypeFamily::Boolean,
-//         ColumnTypeFamily::String,
-//         ColumnTypeFamily::DateTime,
-//         ColumnTypeFamily::Binary,
-//         ColumnTypeFamily::Json,
-//         ColumnTypeFamily::Uuid,
-//     ]
-//     .iter()
-//     .enumerate()
-//     .map(|(i, family)| Column {
-//         name: format!("column{}", i + 1),
-//         tpe: ColumnType {
-//             data_type: "raw type".to_string(),
-//             full_data_type: "full raw type".to_string(),
-//             character_maximum_length: None,
-//             family: family.to_owned(),
-//             arity: ColumnArity::Nullable,
-//             native_type: None,
-//         },
-//         default: None,
-//         auto_increment: false,
-//     })
-//     .collect();
-//     let schema = SqlSchema {
-//         tables: vec![Table {
-//             name: "table1".to_string(),
-//             columns,
-//             indices: vec![],
-//             primary_key: None,
-//             foreign_keys: vec![],
-//         }],
-//         enums: vec![],
-//         sequences: vec![],
-//     };
-//     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
-//     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
-//
-//     let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
-//     let schema_deser: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
-//
-//     // Verify that deserialized schema is equivalent
-//     assert_eq!(schema_deser, schema);
-//     // Verify that schema deserialized from reference JSON is equivalent
-//     assert_eq!(ref_schema, schema);
-// }
-//
-// #[test]
-// fn database_schema_is_serializable_for_every_column_arity() {
-//     // Add a column of every arity
-//     let mut columns: Vec<Column> = vec![ColumnArity::Required, ColumnArity::Nullable, ColumnArity::List]
-//         .iter()
-//         .enumerate()
-//         .map(|(i, arity)| Column {
-//             name: format!("column{}", i + 1),
-//             tpe: ColumnType {
-//                 data_type: "integer".to_string(),
-//                 full_data_type: "int".to_string(),
-//                 character_maximum_length: None,
-//
-//                 family: ColumnTypeFamily::Int,
-//                 arity: arity.to_owned(),
-//                 native_type: Some(PostgresType::Integer.to_json()),
-//             },
-//             default: None,
-//             auto_increment: false,
-//         })
-//         .collect();
-//     let schema = SqlSchema {
-//         tables: vec![Table {
-//             name: "table1".to_string(),
-//             columns,
-//             indices: vec![],
-//             primary_key: None,
-//             foreign_keys: vec![],
-//         }],
-//         enums: vec![],
-//         sequences: vec![],
-//     };
-//     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
-//     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
-//
-//     let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
-//     let schema_deser: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
-//
-//     // Verify that deserialized schema is equivalent
-//     assert_eq!(schema_deser, schema);
-//     // Verify that schema deserialized from reference JSON is equivalent
-//     assert_eq!(ref_schema, schema);
-// }
-//
-// #[test]
-// fn database_schema_is_serializable_for_every_foreign_key_action() {
-//     // Add a foreign key of every possible action
-//     let schema = SqlSchema {
-//         tables: vec![Table {
-//             name: "table1".to_string(),
-//             columns: vec![
-//                 Column {
-//                     name: "column1".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "int".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Nullable,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     auto_increment: false,
-//                     default: None,
-//                 },
-//                 Column {
-//                     name: "column2".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "int".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Nullable,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     auto_increment: false,
-//                     default: None,
-//                 },
-//                 Column {
-//                     name: "column3".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "int".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Nullable,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     auto_increment: false,
-//                     default: None,
-//                 },
-//                 Column {
-//                     name: "column4".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "int".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Nullable,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     auto_increment: false,
-//                     default: None,
-//                 },
-//                 Column {
-//                     name: "column5".to_string(),
-//                     tpe: ColumnType {
-//                         data_type: "integer".to_string(),
-//                         full_data_type: "int".to_string(),
-//                         character_maximum_length: None,
-//
-//                         family: ColumnTypeFamily::Int,
-//                         arity: ColumnArity::Nullable,
-//                         native_type: Some(PostgresType::Integer.to_json()),
-//                     },
-//                     auto_increment: false,
-//                     default: None,
-//                 },
-//             ],
-//             indices: vec![],
-//             primary_key: None,
-//             foreign_keys: vec![
-//                 ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column1".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::NoAction,
-//                 },
-//                 ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column2".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::Restrict,
-//                 },
-//                 ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column3".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::Cascade,
-//                 },
-//                 ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column4".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::SetNull,
-//                 },
-//                 ForeignKey {
-//                     constraint_name: None,
-//                     columns: vec!["column5".to_string()],
-//                     referenced_table: "table2".to_string(),
-//                     referenced_columns: vec!["id".to_string()],
-//                     on_update_action: ForeignKeyAction::NoAction,
-//                     on_delete_action: ForeignKeyAction::SetDefault,
-//                 },
-//             ],
-//         }],
-//         enums: vec![],
-//         sequences: vec![],
-//     };
-//     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
-//     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
-//
-//     let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
-//     let schema_deser: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
-//
-//     // Verify that deserialized schema is equivalent
-//     assert_eq!(schema_deser, schema);
-//     // Verify that schema deserialized from reference JSON is equivalent
-//     assert_eq!(ref_schema, schema);
-// }
+use native_types::{NativeType, PostgresType};
+use pretty_assertions::assert_eq;
+use sql_schema_describer::*;
+
+fn column(name: &str, family: ColumnTypeFamily, arity: ColumnArity) -> Column {
+    Column {
+        name: name.to_owned(),
+        tpe: ColumnType {
+            data_type: "integer".to_owned(),
+            full_data_type: "int".to_owned(),
+            character_maximum_length: None,
+            family,
+            arity,
+            native_type: Some(PostgresType::Integer.to_json()),
+        },
+        default: None,
+        auto_increment: false,
+        computed: false,
+    }
+}
+
+fn table(name: &str, columns: Vec<Column>) -> Table {
+    Table {
+        name: name.to_owned(),
+        columns,
+        indices: vec![],
+        primary_key: None,
+        foreign_keys: vec![],
+        engine: None,
+        row_format: None,
+    }
+}
+
+fn primary_key(column: &str) -> Option<PrimaryKey> {
+    Some(PrimaryKey {
+        columns: vec![column.to_owned()],
+        sequence: None,
+        constraint_name: None,
+        clustered: None,
+    })
+}
+
+fn foreign_key(column: &str, on_delete_action: ForeignKeyAction) -> ForeignKey {
+    ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_owned()],
+        referenced_table: "table2".to_owned(),
+        referenced_columns: vec!["id".to_owned()],
+        on_delete_action,
+        on_update_action: ForeignKeyAction::NoAction,
+        deferrable: false,
+    }
+}
+
+fn schema(tables: Vec<Table>) -> SqlSchema {
+    SqlSchema {
+        tables,
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    }
+}
+
+/// Checks that the schema survives a JSON roundtrip, and that it matches the reference JSON.
+fn assert_serialization(schema: SqlSchema, reference_json: &str) {
+    let reference_schema: SqlSchema = serde_json::from_str(reference_json).expect("deserialize reference schema");
+
+    let schema_json = serde_json::to_string(&schema).expect("serialize schema to JSON");
+    let deserialized_schema: SqlSchema = serde_json::from_str(&schema_json).expect("deserialize schema");
+
+    assert_eq!(deserialized_schema, schema);
+    assert_eq!(reference_schema, schema);
+}
+
+#[test]
+fn database_schema_is_serializable() {
+    let mut column1 = column("column1", ColumnTypeFamily::Int, ColumnArity::Required);
+    column1.auto_increment = true;
+
+    let mut column2 = column("column2", ColumnTypeFamily::String, ColumnArity::Nullable);
+    column2.tpe.data_type = "varchar(255)".to_owned();
+    column2.tpe.full_data_type = "varchar(255)".to_owned();
+    column2.tpe.native_type = Some(PostgresType::VarChar(Some(255)).to_json());
+    column2.default = Some(DefaultValue::value("default value".to_owned()));
+
+    let mut column3 = column("column3", ColumnTypeFamily::Int, ColumnArity::Required);
+    column3.tpe.full_data_type = "integer".to_owned();
+
+    let mut table1 = table("table1", vec![column1, column2, column3]);
+    table1.indices = vec![Index {
+        name: "column2".to_owned(),
+        columns: vec!["column2".to_owned()],
+        tpe: IndexType::Normal,
+        clustered: None,
+        deferrable: false,
+        method: None,
+    }];
+    table1.primary_key = primary_key("column1");
+    table1.foreign_keys = vec![foreign_key("column3", ForeignKeyAction::NoAction)];
+
+    let mut id = column("id", ColumnTypeFamily::Int, ColumnArity::Required);
+    id.tpe.full_data_type = "integer".to_owned();
+    id.auto_increment = true;
+
+    let mut table2 = table("table2", vec![id]);
+    table2.primary_key = primary_key("id");
+
+    let mut schema = schema(vec![table1, table2]);
+    schema.enums = vec![Enum {
+        name: "enum1".to_owned(),
+        values: vec!["option1".to_owned(), "option2".to_owned()],
+    }];
+    schema.sequences = vec![Sequence {
+        name: "sequence1".to_owned(),
+    }];
+    schema.views = vec![View {
+        name: "view1".to_owned(),
+        definition: Some("SELECT column1 FROM table1".to_owned()),
+    }];
+
+    assert_serialization(schema, include_str!("./resources/schema.json"));
+}
+
+#[test]
+fn database_schema_without_primary_key_is_serializable() {
+    let schema = schema(vec![table(
+        "table1",
+        vec![column("column1", ColumnTypeFamily::Int, ColumnArity::Nullable)],
+    )]);
+
+    assert_serialization(schema, include_str!("./resources/schema-without-primary-key.json"));
+}
+
+#[test]
+fn database_schema_is_serializable_for_every_column_type_family() {
+    let columns = vec![
+        ColumnTypeFamily::Int,
+        ColumnTypeFamily::Float,
+        ColumnTypeFamily::Boolean,
+        ColumnTypeFamily::String,
+        ColumnTypeFamily::DateTime,
+        ColumnTypeFamily::Binary,
+        ColumnTypeFamily::Json,
+        ColumnTypeFamily::Uuid,
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, family)| {
+        let mut column = column(&format!("column{}", i + 1), family, ColumnArity::Nullable);
+        column.tpe.data_type = "raw type".to_owned();
+        column.tpe.full_data_type = "full raw type".to_owned();
+        column.tpe.native_type = None;
+        column
+    })
+    .collect();
+
+    assert_serialization(
+        schema(vec![table("table1", columns)]),
+        include_str!("./resources/schema-all-column-type-families.json"),
+    );
+}
+
+#[test]
+fn database_schema_is_serializable_for_every_column_arity() {
+    let columns = vec![ColumnArity::Required, ColumnArity::Nullable, ColumnArity::List]
+        .into_iter()
+        .enumerate()
+        .map(|(i, arity)| column(&format!("column{}", i + 1), ColumnTypeFamily::Int, arity))
+        .collect();
+
+    assert_serialization(
+        schema(vec![table("table1", columns)]),
+        include_str!("./resources/schema-all-column-arities.json"),
+    );
+}
+
+#[test]
+fn database_schema_is_serializable_for_every_foreign_key_action() {
+    let actions = vec![
+        ForeignKeyAction::NoAction,
+        ForeignKeyAction::Restrict,
+        ForeignKeyAction::Cascade,
+        ForeignKeyAction::SetNull,
+        ForeignKeyAction::SetDefault,
+    ];
+
+    let columns = (1..=actions.len())
+        .map(|i| column(&format!("column{}", i), ColumnTypeFamily::Int, ColumnArity::Nullable))
+        .collect();
+
+    let mut table1 = table("table1", columns);
+    table1.foreign_keys = actions
+        .into_iter()
+        .enumerate()
+        .map(|(i, action)| foreign_key(&format!("column{}", i + 1), action))
+        .collect();
+
+    assert_serialization(
+        schema(vec![table1]),
+        include_str!("./resources/schema-all-foreign-key-actions.json"),
+    );
+}
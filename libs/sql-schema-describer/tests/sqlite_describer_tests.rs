@@ -4,6 +4,7 @@ mod test_api;
 
 use barrel::{types, Migration};
 use common::*;
+use native_types::{NativeType, SqliteType};
 use pretty_assertions::assert_eq;
 use quaint::prelude::Queryable;
 use sql_schema_describer::*;
@@ -40,6 +41,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "int4_col".to_string(),
@@ -49,10 +51,11 @@ async fn sqlite_column_types_must_work() {
                 character_maximum_length: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
-                native_type: None,
+                native_type: Some(SqliteType::Integer.to_json()),
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "text_col".to_string(),
@@ -62,10 +65,11 @@ async fn sqlite_column_types_must_work() {
                 character_maximum_length: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
-                native_type: None,
+                native_type: Some(SqliteType::Text.to_json()),
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "real_col".to_string(),
@@ -75,10 +79,11 @@ async fn sqlite_column_types_must_work() {
                 character_maximum_length: None,
                 family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
-                native_type: None,
+                native_type: Some(SqliteType::Real.to_json()),
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
         Column {
             name: "primary_col".to_string(),
@@ -88,10 +93,11 @@ async fn sqlite_column_types_must_work() {
                 character_maximum_length: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
-                native_type: None,
+                native_type: Some(SqliteType::Integer.to_json()),
             },
             default: None,
             auto_increment: true,
+            computed: false,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -101,10 +107,11 @@ async fn sqlite_column_types_must_work() {
                 character_maximum_length: None,
                 family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
-                native_type: None,
+                native_type: Some(SqliteType::Decimal(Some((5, 3))).to_json()),
             },
             default: None,
             auto_increment: false,
+            computed: false,
         },
     ];
 
@@ -118,8 +125,11 @@ async fn sqlite_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -155,10 +165,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         character_maximum_length: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: true,
+                    computed: false,
                 },
                 Column {
                     name: "city".to_string(),
@@ -168,10 +179,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         character_maximum_length: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -181,10 +193,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         character_maximum_length: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -194,10 +207,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         character_maximum_length: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -207,10 +221,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         character_maximum_length: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -221,10 +236,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
-                        native_type: None,
+                        native_type: Some(SqliteType::Integer.to_json()),
                     },
                     default: None,
                     auto_increment: false,
+                    computed: false,
                 },
             ],
             indices: vec![],
@@ -232,6 +248,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -241,6 +258,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -249,6 +267,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -257,6 +276,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -265,6 +285,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    deferrable: false,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -273,8 +294,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_table: "City".to_string(),
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
+                    deferrable: false,
                 },
             ],
+            engine: None,
+            row_format: None,
         }
     );
 }
@@ -310,6 +334,7 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
             columns: vec!["primary_col".to_owned()],
             sequence: None,
             constraint_name: None,
+            clustered: None,
         }
     );
 }
@@ -0,0 +1,563 @@
+//! The forwarding side of the tunnels. Each tunnel has one thread driving its listener, its SSH
+//! session and the connections it forwards, waiting on the readiness of their sockets.
+
+use crate::{invalid, SshTunnel, TunnelError, SSH_HOST, SSH_KEY, SSH_KEY_PASSPHRASE, SSH_KNOWN_HOSTS, SSH_USER};
+use polling::{Event, Poller};
+use ssh2::{BlockDirections, Channel, CheckResult, ErrorCode, KnownHostFileKind, Session};
+use std::{
+    collections::HashMap,
+    io::{self, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+use url::Url;
+
+const DEFAULT_SSH_PORT: u16 = 22;
+
+/// How much is read from one side of a connection before the other side has to catch up.
+const BUFFER_SIZE: usize = 32 * 1024;
+
+/// How long connecting to the bastion host, and opening and closing channels may take.
+const BLOCKING_TIMEOUT_MS: u32 = 10_000;
+
+/// `LIBSSH2_ERROR_CHANNEL_FAILURE`: the bastion host refused to open a channel, but the session
+/// is fine.
+const CHANNEL_FAILURE: ErrorCode = ErrorCode::Session(-21);
+
+const LISTENER: usize = 0;
+const SESSION: usize = 1;
+
+/// Stops the event loop of a tunnel when dropped.
+#[derive(Debug)]
+pub(crate) struct Forwarder {
+    poller: Arc<Poller>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl Drop for Forwarder {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        self.poller.notify().ok();
+    }
+}
+
+/// Connects to the bastion host and starts forwarding the connections to a local port to the
+/// database of the URL.
+pub(crate) fn start(url: &Url, default_port: u16) -> Result<SshTunnel, TunnelError> {
+    let config = SshConfig::from_url(url)?;
+
+    let target = Target {
+        host: url.host_str().unwrap_or("localhost").to_owned(),
+        port: url.port().unwrap_or(default_port),
+    };
+
+    let (session, session_socket) = config.connect()?;
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).map_err(|err| config.error(err))?;
+    let local_addr = listener.local_addr().map_err(|err| config.error(err))?;
+
+    let poller = Arc::new(Poller::new().map_err(|err| config.error(err))?);
+    let shutdown = Arc::new(AtomicBool::new(false));
+
+    // Adding the sockets to the poller makes them non-blocking.
+    poller
+        .add(&listener, Event::readable(LISTENER))
+        .and_then(|_| poller.add(&session_socket, Event::none(SESSION)))
+        .map_err(|err| config.error(err))?;
+
+    let event_loop = EventLoop {
+        poller: poller.clone(),
+        shutdown: shutdown.clone(),
+        listener: Some(listener),
+        config: config.clone(),
+        target,
+        session,
+        session_socket,
+        connections: HashMap::new(),
+        next_key: SESSION + 1,
+    };
+
+    thread::Builder::new()
+        .name(String::from("ssh-tunnel"))
+        .spawn(move || event_loop.run())
+        .map_err(|err| config.error(err))?;
+
+    Ok(SshTunnel {
+        local_addr,
+        _forwarder: Forwarder { poller, shutdown },
+    })
+}
+
+#[derive(Debug, Clone)]
+struct SshConfig {
+    host: String,
+    port: u16,
+    user: String,
+    key: PathBuf,
+    key_passphrase: Option<String>,
+    known_hosts: PathBuf,
+}
+
+/// The database, as seen from the bastion host.
+#[derive(Debug)]
+struct Target {
+    host: String,
+    port: u16,
+}
+
+impl SshConfig {
+    fn from_url(url: &Url) -> Result<Self, TunnelError> {
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(param, _)| param == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        let required = |name: &str| param(name).ok_or_else(|| invalid(format!("The `{}` parameter is missing.", name)));
+
+        let ssh_host = required(SSH_HOST)?;
+        let (host, port) = split_host_port(&ssh_host)?;
+
+        let known_hosts = match param(SSH_KNOWN_HOSTS) {
+            Some(path) => PathBuf::from(path),
+            None => std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(".ssh").join("known_hosts"))
+                .ok_or_else(|| invalid("The `ssh_known_hosts` parameter is missing, and `HOME` is not set."))?,
+        };
+
+        Ok(SshConfig {
+            host,
+            port,
+            user: required(SSH_USER)?,
+            key: PathBuf::from(required(SSH_KEY)?),
+            key_passphrase: param(SSH_KEY_PASSPHRASE),
+            known_hosts,
+        })
+    }
+
+    /// Connects to the bastion host, verifies its key and authenticates. The session is returned
+    /// in non-blocking mode, with a handle on its socket to wait on.
+    fn connect(&self) -> Result<(Session, TcpStream), TunnelError> {
+        let tcp = TcpStream::connect((self.host.as_str(), self.port)).map_err(|err| self.error(err))?;
+        let socket = tcp.try_clone().map_err(|err| self.error(err))?;
+        let mut session = Session::new().map_err(|err| self.error(err))?;
+
+        session.set_timeout(BLOCKING_TIMEOUT_MS);
+        session.set_tcp_stream(tcp);
+        session.handshake().map_err(|err| self.error(err))?;
+
+        self.verify_host_key(&session)?;
+
+        session
+            .userauth_pubkey_file(&self.user, None, &self.key, self.key_passphrase.as_deref())
+            .map_err(|err| self.error(format!("authentication as `{}` failed: {}", self.user, err)))?;
+
+        session.set_blocking(false);
+
+        Ok((session, socket))
+    }
+
+    fn verify_host_key(&self, session: &Session) -> Result<(), TunnelError> {
+        let mut known_hosts = session.known_hosts().map_err(|err| self.error(err))?;
+
+        known_hosts
+            .read_file(&self.known_hosts, KnownHostFileKind::OpenSSH)
+            .map_err(|err| self.error(format!("could not read `{}`: {}", self.known_hosts.display(), err)))?;
+
+        let (key, _) = session
+            .host_key()
+            .ok_or_else(|| self.error("the host did not present a key"))?;
+
+        match known_hosts.check_port(&self.host, self.port, key) {
+            CheckResult::Match => Ok(()),
+            CheckResult::NotFound => Err(self.error(format!(
+                "the host is not in the known hosts file `{}`",
+                self.known_hosts.display()
+            ))),
+            CheckResult::Mismatch => Err(self.error(format!(
+                "the host key does not match the known hosts file `{}`",
+                self.known_hosts.display()
+            ))),
+            CheckResult::Failure => Err(self.error("the host key could not be verified")),
+        }
+    }
+
+    fn error(&self, message: impl ToString) -> TunnelError {
+        TunnelError::Connection {
+            ssh_host: format!("{}:{}", self.host, self.port),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Splits `host:port`. IPv6 addresses are written in brackets, `[::1]:22`.
+fn split_host_port(host: &str) -> Result<(String, u16), TunnelError> {
+    let (host, port) = match host.rfind(':') {
+        Some(idx) if !host[idx..].contains(']') => {
+            let port = host[idx + 1..]
+                .parse()
+                .map_err(|_| invalid(format!("The port of `{}` is invalid.", host)))?;
+
+            (&host[..idx], port)
+        }
+        _ => (host, DEFAULT_SSH_PORT),
+    };
+
+    Ok((host.trim_start_matches('[').trim_end_matches(']').to_owned(), port))
+}
+
+/// The state of a tunnel, owned by its thread. The session is non-blocking and only used from
+/// that thread, every connection reads and writes its channel in turn.
+struct EventLoop {
+    poller: Arc<Poller>,
+    shutdown: Arc<AtomicBool>,
+    /// `None` once the tunnel was dropped.
+    listener: Option<TcpListener>,
+    config: SshConfig,
+    target: Target,
+    session: Session,
+    /// The socket of the session, to wait for the directions libssh2 is blocked on.
+    session_socket: TcpStream,
+    connections: HashMap<usize, Connection>,
+    next_key: usize,
+}
+
+impl EventLoop {
+    fn run(mut self) {
+        let mut events = Vec::new();
+        let mut buf = vec![0u8; BUFFER_SIZE];
+
+        loop {
+            if self.shutdown.load(Ordering::Relaxed) {
+                if let Some(listener) = self.listener.take() {
+                    self.poller.delete(&listener).ok();
+                }
+            }
+
+            self.accept();
+            self.pump(&mut buf);
+
+            if self.listener.is_none() && self.connections.is_empty() {
+                break;
+            }
+
+            if let Err(err) = self.wait(&mut events) {
+                tracing::error!("The SSH tunnel through {} stopped: {}", self.config.host, err);
+                break;
+            }
+        }
+    }
+
+    fn accept(&mut self) {
+        let mut accepted = Vec::new();
+
+        if let Some(listener) = &self.listener {
+            loop {
+                match listener.accept() {
+                    Ok((stream, _)) => accepted.push(stream),
+                    Err(err) if would_block(&err) => break,
+                    Err(err) => {
+                        tracing::error!("The SSH tunnel could not accept a connection: {}", err);
+                        break;
+                    }
+                }
+            }
+        }
+
+        for stream in accepted {
+            let channel = match self.open_channel() {
+                Ok(channel) => channel,
+                Err(err) => {
+                    tracing::warn!("Could not forward a connection through the SSH tunnel: {}", err);
+                    continue;
+                }
+            };
+
+            let key = self.next_key;
+            self.next_key += 1;
+
+            match self.poller.add(&stream, Event::none(key)) {
+                Ok(()) => {
+                    self.connections.insert(key, Connection::new(stream, channel));
+                }
+                Err(err) => tracing::error!("Could not forward a connection through the SSH tunnel: {}", err),
+            }
+        }
+    }
+
+    /// Opens a channel to the database, blocking until the bastion host confirms it. A session
+    /// the bastion host dropped is replaced, closing the connections it carried.
+    fn open_channel(&mut self) -> Result<Channel, TunnelError> {
+        match self.try_open_channel() {
+            Err(err) if err.code() != CHANNEL_FAILURE => {
+                tracing::warn!("Reconnecting the SSH tunnel through {}: {}", self.config.host, err);
+
+                self.reconnect()?;
+                self.try_open_channel().map_err(|err| self.config.error(err))
+            }
+            result => result.map_err(|err| self.config.error(err)),
+        }
+    }
+
+    fn try_open_channel(&self) -> Result<Channel, ssh2::Error> {
+        self.session.set_blocking(true);
+        let channel = self
+            .session
+            .channel_direct_tcpip(&self.target.host, self.target.port, None);
+        self.session.set_blocking(false);
+
+        channel
+    }
+
+    fn reconnect(&mut self) -> Result<(), TunnelError> {
+        for (_, connection) in self.connections.drain() {
+            self.poller.delete(&connection.stream).ok();
+        }
+
+        let (session, session_socket) = self.config.connect()?;
+
+        self.poller.delete(&self.session_socket).ok();
+        self.poller
+            .add(&session_socket, Event::none(SESSION))
+            .map_err(|err| self.config.error(err))?;
+
+        self.session = session;
+        self.session_socket = session_socket;
+
+        Ok(())
+    }
+
+    /// Moves data between the connections and their channels until nothing is ready anymore.
+    fn pump(&mut self, buf: &mut [u8]) {
+        loop {
+            let mut progress = false;
+            let mut finished = Vec::new();
+            let mut failed = Vec::new();
+
+            for (key, connection) in self.connections.iter_mut() {
+                match connection.pump(buf) {
+                    Ok(moved) => {
+                        progress |= moved;
+
+                        if connection.is_finished() {
+                            finished.push(*key);
+                        }
+                    }
+                    Err(err) => {
+                        tracing::warn!("SSH tunnel connection through {} closed: {}", self.config.host, err);
+                        failed.push(*key);
+                    }
+                }
+            }
+
+            for key in finished {
+                if let Some(mut connection) = self.connections.remove(&key) {
+                    self.poller.delete(&connection.stream).ok();
+
+                    // A channel freed before the bastion host confirmed its closing would stay
+                    // open on the bastion host.
+                    self.session.set_blocking(true);
+                    connection.channel.close().ok();
+                    self.session.set_blocking(false);
+                }
+            }
+
+            for key in failed {
+                if let Some(connection) = self.connections.remove(&key) {
+                    self.poller.delete(&connection.stream).ok();
+                }
+            }
+
+            if !progress {
+                break;
+            }
+        }
+    }
+
+    /// Waits until the sockets are ready for what the connections and the session are blocked
+    /// on. The poller delivers an event once, the interests are set again on every wait.
+    fn wait(&self, events: &mut Vec<Event>) -> io::Result<()> {
+        let mut wants_channel_data = false;
+
+        for (key, connection) in &self.connections {
+            let interest = Event {
+                key: *key,
+                readable: connection.wants_to_read(),
+                writable: connection.wants_to_write(),
+            };
+
+            self.poller.modify(&connection.stream, interest)?;
+            wants_channel_data |= connection.wants_channel_data();
+        }
+
+        let blocked = self.session.block_directions();
+
+        let interest = Event {
+            key: SESSION,
+            readable: wants_channel_data || matches!(blocked, BlockDirections::Inbound | BlockDirections::Both),
+            writable: matches!(blocked, BlockDirections::Outbound | BlockDirections::Both),
+        };
+
+        self.poller.modify(&self.session_socket, interest)?;
+
+        if let Some(listener) = &self.listener {
+            self.poller.modify(listener, Event::readable(LISTENER))?;
+        }
+
+        events.clear();
+
+        match self.poller.wait(events, None) {
+            Err(err) if err.kind() != io::ErrorKind::Interrupted => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A local connection, forwarded through its own channel.
+struct Connection {
+    stream: TcpStream,
+    channel: Channel,
+    /// Read from the local connection, not written to the channel yet.
+    upstream: Vec<u8>,
+    /// Read from the channel, not written to the local connection yet.
+    downstream: Vec<u8>,
+    stream_eof: bool,
+    channel_eof: bool,
+}
+
+impl Connection {
+    fn new(stream: TcpStream, channel: Channel) -> Self {
+        Connection {
+            stream,
+            channel,
+            upstream: Vec::new(),
+            downstream: Vec::new(),
+            stream_eof: false,
+            channel_eof: false,
+        }
+    }
+
+    /// Moves what is ready in both directions, and returns whether anything moved.
+    fn pump(&mut self, buf: &mut [u8]) -> io::Result<bool> {
+        let mut progress = false;
+
+        if self.wants_to_read() {
+            match self.stream.read(buf) {
+                Ok(0) => {
+                    self.stream_eof = true;
+                    progress = true;
+                }
+                Ok(n) => {
+                    self.upstream.extend_from_slice(&buf[..n]);
+                    progress = true;
+                }
+                Err(err) if would_block(&err) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        if !self.upstream.is_empty() {
+            match self.channel.write(&self.upstream) {
+                Ok(n) => {
+                    self.upstream.drain(..n);
+                    progress |= n > 0;
+                }
+                Err(err) if would_block(&err) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        if self.wants_channel_data() {
+            match self.channel.read(buf) {
+                Ok(0) if self.channel.eof() => {
+                    self.channel_eof = true;
+                    progress = true;
+                }
+                Ok(0) => (),
+                Ok(n) => {
+                    self.downstream.extend_from_slice(&buf[..n]);
+                    progress = true;
+                }
+                Err(err) if would_block(&err) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        if self.wants_to_write() {
+            match self.stream.write(&self.downstream) {
+                Ok(n) => {
+                    self.downstream.drain(..n);
+                    progress |= n > 0;
+                }
+                Err(err) if would_block(&err) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(progress)
+    }
+
+    /// Either side closed, and what it sent before was passed on.
+    fn is_finished(&self) -> bool {
+        (self.stream_eof && self.upstream.is_empty()) || (self.channel_eof && self.downstream.is_empty())
+    }
+
+    fn wants_to_read(&self) -> bool {
+        !self.stream_eof && self.upstream.len() < BUFFER_SIZE
+    }
+
+    fn wants_to_write(&self) -> bool {
+        !self.downstream.is_empty()
+    }
+
+    fn wants_channel_data(&self) -> bool {
+        !self.channel_eof && self.downstream.len() < BUFFER_SIZE
+    }
+}
+
+fn would_block(err: &io::Error) -> bool {
+    matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::Interrupted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open;
+
+    #[test]
+    fn tunnel_parameters_are_validated() {
+        let error = |url: &str| open(url).unwrap_err().to_string();
+
+        assert_eq!(
+            error("postgresql://postgres@db/app?ssh_host=bastion&ssh_key=/id_rsa&ssh_known_hosts=/kh"),
+            "The `ssh_user` parameter is missing."
+        );
+        assert_eq!(
+            error("mysql://root@db/app?ssh_host=bastion:ssh&ssh_user=a&ssh_key=/id_rsa&ssh_known_hosts=/kh"),
+            "The port of `bastion:ssh` is invalid."
+        );
+    }
+
+    #[test]
+    fn ssh_hosts_may_have_a_port() {
+        let url =
+            Url::parse("mysql://root@db/app?ssh_host=bastion:2222&ssh_user=deploy&ssh_key=/id_rsa&ssh_known_hosts=/kh")
+                .unwrap();
+        let config = SshConfig::from_url(&url).unwrap();
+
+        assert_eq!(config.host, "bastion");
+        assert_eq!(config.port, 2222);
+        assert_eq!(config.user, "deploy");
+
+        let url = Url::parse("mysql://root@db/app?ssh_host=[::1]&ssh_user=deploy&ssh_key=/id_rsa&ssh_known_hosts=/kh")
+            .unwrap();
+        let config = SshConfig::from_url(&url).unwrap();
+
+        assert_eq!(config.host, "::1");
+        assert_eq!(config.port, DEFAULT_SSH_PORT);
+    }
+}
@@ -130,6 +130,10 @@ pub struct TlsConnectionError {
 #[user_facing(code = "P1012", message = "{full_error}")]
 pub struct SchemaParserError {
     pub full_error: String,
+    /// The errors and warnings of the schema as `datamodel::diagnostics::StructuredDiagnostic`s,
+    /// with their codes and spans.
+    #[serde(skip_serializing_if = "serde_json::Value::is_null")]
+    pub diagnostics: serde_json::Value,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -171,6 +175,28 @@ pub struct DatabaseVersionIncompatibility {
     pub errors: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P1016",
+    message = "The TLS certificate of the database server at `{database_host}` could not be verified: {message}\nCheck the `sslcert` (root certificate), `sslidentity` (client identity) and `sslaccept` parameters of your connection string."
+)]
+pub struct TlsCertificateError {
+    /// Database host URI
+    pub database_host: String,
+    pub message: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P1017",
+    message = "Could not open the SSH tunnel through `{ssh_host}`: {message}\nCheck the `ssh_host`, `ssh_user`, `ssh_key` and `ssh_known_hosts` parameters of your connection string."
+)]
+pub struct SshTunnelError {
+    /// Bastion host and port
+    pub ssh_host: String,
+    pub message: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,10 +27,15 @@ pub struct KnownError {
 }
 
 impl KnownError {
+    /// The connection strings in the message and the metadata of the error are redacted, see
+    /// `datasource_url::redact`.
     pub fn new<T: UserFacingError>(inner: T) -> KnownError {
+        let mut meta = serde_json::to_value(&inner).expect("Failed to render user facing error metadata to JSON");
+        redact_json(&mut meta);
+
         KnownError {
-            message: inner.message(),
-            meta: serde_json::to_value(&inner).expect("Failed to render user facing error metadata to JSON"),
+            message: redact(inner.message()),
+            meta,
             error_code: T::ERROR_CODE,
         }
     }
@@ -75,7 +80,7 @@ impl Error {
     pub fn new_non_panic_with_current_backtrace(message: String) -> Self {
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message,
+                message: redact(message),
                 backtrace: Some(format!("{:?}", backtrace::Backtrace::new())),
             }),
             is_panic: false,
@@ -85,7 +90,7 @@ impl Error {
     pub fn from_dyn_error(err: &dyn std::error::Error) -> Self {
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message: err.to_string(),
+                message: redact(err.to_string()),
                 backtrace: None,
             }),
             is_panic: false,
@@ -104,7 +109,7 @@ impl Error {
 
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message: format!("[{}] {}", location, message),
+                message: redact(format!("[{}] {}", location, message)),
                 backtrace,
             }),
             is_panic: true,
@@ -124,7 +129,7 @@ impl Error {
 
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message,
+                message: redact(message),
                 backtrace: None,
             }),
             is_panic: true,
@@ -153,6 +158,11 @@ pub fn new_backtrace() -> backtrace::Backtrace {
 
 impl From<UnknownError> for Error {
     fn from(unknown_error: UnknownError) -> Self {
+        let unknown_error = UnknownError {
+            message: redact(unknown_error.message),
+            ..unknown_error
+        };
+
         Error {
             inner: ErrorType::Unknown(unknown_error),
             is_panic: false,
@@ -168,3 +178,19 @@ impl From<KnownError> for Error {
         }
     }
 }
+
+fn redact(message: String) -> String {
+    match datasource_url::redact(&message) {
+        std::borrow::Cow::Borrowed(_) => message,
+        std::borrow::Cow::Owned(redacted) => redacted,
+    }
+}
+
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(string) => *string = redact(std::mem::take(string)),
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_json),
+        serde_json::Value::Object(map) => map.values_mut().for_each(redact_json),
+        _ => (),
+    }
+}
@@ -174,6 +174,36 @@ impl crate::UserFacingError for ShadowDbCreationError {
     }
 }
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3015",
+    message = "Statement {statement_number} of the seed file `{seed_file_path}` failed to apply.\n\n{statement}\n\nDatabase error:\n{database_error}"
+)]
+pub struct SeedStatementFailed {
+    /// The path to the seed file.
+    pub seed_file_path: String,
+    /// The number of the failed statement, starting at 1.
+    pub statement_number: usize,
+    /// The failed statement, or row for JSON data files.
+    pub statement: String,
+    /// The error returned by the database.
+    pub database_error: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3016",
+    message = "The database user is missing the `{privilege}` privilege, which Prisma Migrate needs to {operation}. Please grant it to the user and try again.\n\n{details}"
+)]
+pub struct MissingDatabasePrivilege {
+    /// The name of the missing privilege, e.g. `CREATE` or `CREATEDB`.
+    pub privilege: String,
+    /// What the privilege is needed for, e.g. `create tables`.
+    pub operation: String,
+    /// The error returned by the database, or how the missing privilege was found.
+    pub details: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
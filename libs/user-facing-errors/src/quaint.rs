@@ -23,17 +23,67 @@ impl From<quaint::error::DatabaseConstraint> for crate::query_engine::DatabaseCo
     }
 }
 
+/// The classes of database errors that are worth retrying, because the same operation may succeed
+/// on a second attempt.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransientError {
+    /// The connection was reset, refused or timed out.
+    ConnectionReset,
+    /// The server is not accepting more connections at the moment.
+    TooManyConnections,
+    /// The transaction could not be serialized or was chosen as a deadlock victim.
+    SerializationFailure,
+}
+
+impl TransientError {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransientError::ConnectionReset => "connection reset",
+            TransientError::TooManyConnections => "too many connections",
+            TransientError::SerializationFailure => "serialization failure",
+        }
+    }
+}
+
+/// Classify a quaint error as transient, if it is one.
+pub fn classify_transient_error(error: &quaint::error::Error) -> Option<TransientError> {
+    match error.kind() {
+        ErrorKind::ConnectionError(_) | ErrorKind::ConnectTimeout(_) => return Some(TransientError::ConnectionReset),
+        _ => (),
+    }
+
+    match error.original_code()? {
+        // Postgres SQLSTATE codes.
+        "53300" => Some(TransientError::TooManyConnections),
+        "40001" | "40P01" => Some(TransientError::SerializationFailure),
+        "57P01" | "08000" | "08003" | "08006" => Some(TransientError::ConnectionReset),
+        // MySQL server error codes.
+        "1040" => Some(TransientError::TooManyConnections),
+        "1213" => Some(TransientError::SerializationFailure),
+        "2006" | "2013" => Some(TransientError::ConnectionReset),
+        _ => None,
+    }
+}
+
 pub fn invalid_url_description(database_str: &str, error_details: &str) -> String {
     let docs = r#"https://www.prisma.io/docs/reference/database-reference/connection-urls"#;
 
     let details = formatdoc! {r#"
             {} in `{}`. Please refer to the documentation in {} for constructing a correct
             connection string. In some cases, certain characters must be escaped. Please
-            check the string for any illegal characters."#, error_details, database_str, docs};
+            check the string for any illegal characters."#, error_details, datasource_url::redact_url(database_str), docs};
 
     details.replace('\n', " ")
 }
 
+/// TLS errors caused by certificates (untrusted root, expired, hostname mismatch, unreadable
+/// client identity) get a dedicated error, since they are almost always a configuration issue.
+fn is_certificate_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+
+    message.contains("certificate") || message.contains("pkcs12") || message.contains("identity")
+}
+
 pub fn render_quaint_error(kind: &ErrorKind, connection_info: &ConnectionInfo) -> Option<KnownError> {
     match (kind, connection_info) {
         (ErrorKind::DatabaseDoesNotExist { .. }, ConnectionInfo::Sqlite { file_path, .. }) => {
@@ -122,8 +172,25 @@ pub fn render_quaint_error(kind: &ErrorKind, connection_info: &ConnectionInfo) -
         }
 
         (ErrorKind::UniqueConstraintViolation { constraint }, _) => {
+            let constraint = query_engine::DatabaseConstraint::from(constraint);
+
             Some(KnownError::new(query_engine::UniqueKeyViolation {
-                constraint: constraint.into(),
+                constraint_details: (&constraint).into(),
+                constraint,
+            }))
+        }
+
+        (ErrorKind::TlsError { message }, ConnectionInfo::Postgres(url)) if is_certificate_error(message) => {
+            Some(KnownError::new(common::TlsCertificateError {
+                database_host: url.host().to_owned(),
+                message: message.into(),
+            }))
+        }
+
+        (ErrorKind::TlsError { message }, ConnectionInfo::Mysql(url)) if is_certificate_error(message) => {
+            Some(KnownError::new(common::TlsCertificateError {
+                database_host: url.host().to_owned(),
+                message: message.into(),
             }))
         }
 
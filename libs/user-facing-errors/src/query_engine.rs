@@ -29,6 +29,38 @@ impl From<Vec<String>> for DatabaseConstraint {
     }
 }
 
+/// The constraint behind a constraint violation, as far as the database reports it. The columns
+/// are mapped back to the fields of the model if the table belongs to one.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Clone)]
+pub struct ConstraintDetails {
+    /// The name of the constraint or index in the database.
+    pub name: Option<String>,
+    /// The table the constraint is defined on.
+    pub table: Option<String>,
+    /// The columns covered by the constraint.
+    pub columns: Vec<String>,
+    /// Model name from Prisma schema
+    pub model_name: Option<String>,
+    /// Field names from the model in Prisma schema, in the order of the columns
+    pub fields: Vec<String>,
+}
+
+impl From<&DatabaseConstraint> for ConstraintDetails {
+    fn from(constraint: &DatabaseConstraint) -> Self {
+        match constraint {
+            DatabaseConstraint::Fields(columns) => Self {
+                columns: columns.clone(),
+                ..Default::default()
+            },
+            DatabaseConstraint::Index(name) => Self {
+                name: Some(name.clone()),
+                ..Default::default()
+            },
+            DatabaseConstraint::ForeignKey => Self::default(),
+        }
+    }
+}
+
 #[derive(Debug, UserFacingError, Serialize)]
 #[user_facing(
     code = "P2000",
@@ -61,6 +93,8 @@ pub struct UniqueKeyViolation {
     /// Field name from one model from Prisma schema
     #[serde(rename = "target")]
     pub constraint: DatabaseConstraint,
+
+    pub constraint_details: ConstraintDetails,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -71,6 +105,8 @@ pub struct UniqueKeyViolation {
 pub struct ForeignKeyViolation {
     /// Field name from one model from Prisma schema
     pub field_name: String,
+
+    pub constraint_details: ConstraintDetails,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -153,6 +189,8 @@ pub struct RawQueryFailed {
 #[user_facing(code = "P2011", message = "Null constraint violation on the {constraint}")]
 pub struct NullConstraintViolation {
     pub constraint: DatabaseConstraint,
+
+    pub constraint_details: ConstraintDetails,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -241,3 +279,38 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "The record for model `{model_name}` was changed by another operation since it was read. Reload it and retry the operation."
+)]
+pub struct RecordChanged {
+    pub model_name: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2024", message = "Check constraint failed on the {constraint}")]
+pub struct CheckConstraintViolation {
+    #[serde(rename = "target")]
+    pub constraint: DatabaseConstraint,
+
+    pub constraint_details: ConstraintDetails,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2025",
+    message = "The role `{role}` is not allowed to run `{operation}` on {target}."
+)]
+pub struct OperationNotAllowed {
+    pub role: String,
+    /// The kind of the operation, e.g. `findMany`.
+    pub operation: String,
+    /// The model the operation runs on, e.g. "the model `User`".
+    pub target: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2026", message = "The request does not carry a valid access token.")]
+pub struct InvalidAccessToken;
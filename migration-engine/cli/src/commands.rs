@@ -87,6 +87,7 @@ fn parse_base64_string(s: &str) -> Result<String, CliError> {
             Err(e) => Err(CliError::Known {
                 error: KnownError::new(SchemaParserError {
                     full_error: format!("{}", e),
+                    diagnostics: serde_json::Value::Null,
                 }),
                 exit_code: 255,
             }),
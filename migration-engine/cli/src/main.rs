@@ -100,7 +100,7 @@ async fn start_engine(datamodel_location: &str, enabled_preview_features: BitFla
         Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
         Err(err) => {
             let (error, exit_code) = match &err {
-                CoreError::ReceivedBadDatamodel(message) => {
+                CoreError::ReceivedBadDatamodel(message, _) => {
                     let error = user_facing_errors::UnknownError {
                         message: message.clone(),
                         backtrace: Some(format!("{:?}", user_facing_errors::new_backtrace())),
@@ -1,4 +1,4 @@
-use crate::{migrations_directory::MigrationDirectory, ConnectorResult, MigrationStep};
+use crate::{migrations_directory::MigrationDirectory, ConnectorResult, MigrationStep, RenameHint};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a
@@ -31,10 +31,13 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
 
     /// Look at the previous migrations and the target schema, and infer a
     /// database migration taking the database to the expected Prisma schema.
+    /// Tables and columns named in the `rename_hints` are renamed instead of
+    /// being dropped and recreated.
     async fn infer_next_migration(
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        rename_hints: &[RenameHint],
     ) -> ConnectorResult<T>;
 
     /// Check that the current local database's schema matches its expected
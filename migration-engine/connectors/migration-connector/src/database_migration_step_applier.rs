@@ -12,12 +12,77 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
 
+    /// Summarize the changes of the migration, in the order of the script, see `MigrationSummary`.
+    fn summarize_steps(&self, database_migration: &T) -> Vec<MigrationStepSummary>;
+
     /// Render the migration to a runnable script.
     fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
 
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+
+    /// Apply the script of a migration to the database. On databases with
+    /// transactional DDL, the script is applied in a transaction, so a failed
+    /// migration leaves the database untouched. Otherwise, or when the script
+    /// contains statements that can't run in a transaction, the statements are
+    /// applied one after the other, and the error tells which one failed.
+    /// Scripts with their own transaction control statements are applied as
+    /// they are.
+    async fn apply_migration_script(&self, script: &str) -> Result<(), MigrationScriptError>;
+
+    /// Insert a single row from a seed data file. The keys of the row are column names.
+    async fn insert_seed_row(
+        &self,
+        table: &str,
+        row: &serde_json::Map<String, serde_json::Value>,
+    ) -> ConnectorResult<()>;
+
+    /// Start a transaction for the statements of a seed file, so a failing
+    /// statement rolls back the statements before it. No transaction is
+    /// started on databases without transactional DDL, or when one of the SQL
+    /// statements manages transactions itself or can't run in a transaction.
+    /// Returns whether a transaction was started.
+    async fn begin_seed_transaction(&self, statements: &[&str]) -> ConnectorResult<bool>;
+
+    /// Commit or roll back the transaction started by `begin_seed_transaction`.
+    async fn end_seed_transaction(&self, commit: bool) -> ConnectorResult<()>;
+}
+
+/// The failure to apply a migration script.
+#[derive(Debug)]
+pub struct MigrationScriptError {
+    /// The zero-based index of the statement that failed, when the statements
+    /// were applied one by one. The statements before it were applied, unless
+    /// the migration was `rolled_back`.
+    pub failed_statement_index: Option<usize>,
+    /// The zero-based index of the batch that failed, for scripts split into
+    /// batches, see `split_sql_batches`.
+    pub failed_batch_index: Option<usize>,
+    /// Whether the script was applied in a transaction, and rolled back.
+    pub rolled_back: bool,
+    /// The error returned by the database.
+    pub error: ConnectorError,
+}
+
+impl MigrationScriptError {
+    /// The logs to record in the migrations table.
+    pub fn logs(&self) -> String {
+        match (self.failed_statement_index, self.failed_batch_index) {
+            (Some(index), _) if self.rolled_back => format!(
+                "Statement {} of the migration script failed, the migration was rolled back.\n{}",
+                index + 1,
+                self.error
+            ),
+            (Some(index), _) => format!(
+                "Statement {} of the migration script failed, the previous statements were applied.\n{}",
+                index + 1,
+                self.error
+            ),
+            (None, Some(index)) => format!("Batch {} of the migration script failed.\n{}", index + 1, self.error),
+            (None, None) => self.error.to_string(),
+        }
+    }
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
@@ -102,7 +102,7 @@ impl ConnectorError {
 
     /// Construct an UrlParseError.
     pub fn url_parse_error(err: impl Display, url: &str) -> Self {
-        Self::generic(anyhow::anyhow!("{} in `{}`", err, url))
+        Self::generic(anyhow::anyhow!("{} in `{}`", err, datasource_url::redact_url(url)))
     }
 }
 
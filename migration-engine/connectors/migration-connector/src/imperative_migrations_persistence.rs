@@ -1,6 +1,10 @@
 use crate::{checksum, ConnectorError, ConnectorResult};
 use serde::Deserialize;
 
+/// The migration name seeds are recorded under in the migrations table. Seeds are not
+/// migrations, so these records are not returned by `list_migrations()`.
+pub const SEED_MIGRATION_NAME: &str = "_prisma_seed";
+
 /// A timestamp.
 pub type Timestamp = chrono::DateTime<chrono::Utc>;
 
@@ -62,6 +66,12 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// migration will not be applied, and the error reported.
     async fn record_failed_step(&self, id: &str, logs: &str) -> ConnectorResult<()>;
 
+    /// Report logs for a migration that failed after some of its statements
+    /// were applied. The applied_steps_count is set to the number of applied
+    /// statements, i.e. the index of the failed statement.
+    async fn record_failed_statement(&self, id: &str, failed_statement_index: usize, logs: &str)
+        -> ConnectorResult<()>;
+
     /// Record that the migration completed *successfully*. This means
     /// populating the `finished_at` field in the migration record.
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
@@ -69,6 +79,11 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// List all applied migrations, ordered by `started_at`. This should fail
     /// hard if the migration persistence is not initialized.
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>>;
+
+    /// List all the seed records (see `SEED_MIGRATION_NAME`), ordered by
+    /// `started_at`. This should fail hard if the migration persistence is not
+    /// initialized.
+    async fn list_seeds(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>>;
 }
 
 /// Error returned when the persistence is not initialized.
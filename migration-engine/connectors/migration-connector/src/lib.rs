@@ -8,11 +8,14 @@ mod destructive_change_checker;
 mod error;
 pub mod features;
 mod imperative_migrations_persistence;
+mod migration_summary;
 
 #[allow(missing_docs)]
 pub mod steps;
 
 mod migrations_directory;
+mod rename_hints;
+mod sql_statements;
 
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
@@ -20,9 +23,14 @@ pub use destructive_change_checker::*;
 pub use error::*;
 pub use features::MigrationFeature;
 pub use imperative_migrations_persistence::{
-    ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp,
+    ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp, SEED_MIGRATION_NAME,
 };
+pub use migration_summary::{MigrationStepSummary, MigrationSummary};
 pub use migrations_directory::{create_migration_directory, list_migrations, ListMigrationsError, MigrationDirectory};
+pub use rename_hints::RenameHint;
+pub use sql_statements::{
+    is_data_manipulation, is_transaction_control, split_sql_batches, split_sql_statements, statement_keywords,
+};
 pub use steps::MigrationStep;
 
 use sha2::{Digest, Sha256};
@@ -48,8 +56,13 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Create the database with the provided URL.
     async fn create_database(database_str: &str) -> ConnectorResult<String>;
 
-    /// Drop all database state.
-    async fn reset(&self) -> ConnectorResult<()>;
+    /// Check that the database user has the privileges the migration engine needs: creating,
+    /// altering and dropping tables, and creating the shadow database if `shadow_database` is set.
+    /// A missing privilege is reported as a `MissingDatabasePrivilege` error naming it.
+    async fn check_permissions(&self, shadow_database: bool) -> ConnectorResult<()>;
+
+    /// Drop all database state, except for the tables named in `keep_tables`.
+    async fn reset(&self, keep_tables: &[String]) -> ConnectorResult<()>;
 
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
@@ -87,7 +100,7 @@ pub trait DatabaseMigrationMarker: Debug + Send + Sync {
 pub type ConnectorResult<T> = Result<T, ConnectorError>;
 
 /// Compute the checksum for a migration script, and return it formatted to be human-readable.
-fn checksum(script: &str) -> String {
+pub fn checksum(script: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(script.as_bytes());
     let checksum: [u8; 32] = hasher.finalize().into();
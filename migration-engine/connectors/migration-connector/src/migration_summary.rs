@@ -0,0 +1,168 @@
+use crate::DestructiveChangeDiagnostics;
+use serde::{Deserialize, Serialize};
+
+/// A structured summary of a database migration, so tools can present the changes of a migration
+/// without parsing its script.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationSummary {
+    /// The changes of the migration, in the order of the script.
+    pub steps: Vec<MigrationStepSummary>,
+    /// The warnings about the data the migration may lose, as in the script.
+    pub warnings: Vec<String>,
+    /// The steps that can not be executed on the current data, as in the script.
+    pub unexecutable_steps: Vec<String>,
+}
+
+impl MigrationSummary {
+    /// The summary of the steps, with the warnings of the diagnostics.
+    pub fn new(steps: Vec<MigrationStepSummary>, diagnostics: &DestructiveChangeDiagnostics) -> Self {
+        MigrationSummary {
+            steps,
+            warnings: diagnostics
+                .warnings
+                .iter()
+                .map(|warning| warning.description.clone())
+                .collect(),
+            unexecutable_steps: diagnostics
+                .unexecutable_migrations
+                .iter()
+                .map(|unexecutable| unexecutable.description.clone())
+                .collect(),
+        }
+    }
+}
+
+/// A single change of a migration, serialized with its kind in the `kind` field, e.g.
+/// `{"kind": "droppedColumn", "table": "User", "column": "name"}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MigrationStepSummary {
+    /// A table was created.
+    CreatedTable {
+        /// The name of the table.
+        table: String,
+    },
+    /// A table was dropped.
+    DroppedTable {
+        /// The name of the table.
+        table: String,
+    },
+    /// A table was renamed.
+    RenamedTable {
+        /// The previous name of the table.
+        from: String,
+        /// The new name of the table.
+        to: String,
+    },
+    /// A table was copied into a new table with the changes, on databases that can't alter them
+    /// in place. The changes to its columns are summarized separately.
+    RedefinedTable {
+        /// The name of the table.
+        table: String,
+    },
+    /// A column was added.
+    AddedColumn {
+        /// The name of the table.
+        table: String,
+        /// The name of the column.
+        column: String,
+    },
+    /// A column was dropped.
+    DroppedColumn {
+        /// The name of the table.
+        table: String,
+        /// The name of the column.
+        column: String,
+    },
+    /// A column was altered, or dropped and added again when it can't be altered.
+    AlteredColumn {
+        /// The name of the table.
+        table: String,
+        /// The name of the column.
+        column: String,
+        /// What changed: `type`, `arity`, `default`, `name` or `sequence`.
+        changes: Vec<String>,
+        /// Whether the column is dropped and added again, losing its data.
+        recreated: bool,
+    },
+    /// The primary key of a table was added or changed.
+    AddedPrimaryKey {
+        /// The name of the table.
+        table: String,
+        /// The columns of the primary key.
+        columns: Vec<String>,
+    },
+    /// The primary key of a table was dropped.
+    DroppedPrimaryKey {
+        /// The name of the table.
+        table: String,
+    },
+    /// The storage options of a table were changed.
+    AlteredTableOptions {
+        /// The name of the table.
+        table: String,
+    },
+    /// An index was created.
+    CreatedIndex {
+        /// The name of the table.
+        table: String,
+        /// The name of the index.
+        index: String,
+    },
+    /// An index was dropped.
+    DroppedIndex {
+        /// The name of the table.
+        table: String,
+        /// The name of the index.
+        index: String,
+    },
+    /// An index was renamed, or dropped and created again.
+    AlteredIndex {
+        /// The name of the table.
+        table: String,
+        /// The new name of the index.
+        index: String,
+    },
+    /// A foreign key was added.
+    AddedForeignKey {
+        /// The name of the table.
+        table: String,
+        /// The table the foreign key references.
+        #[serde(rename = "referencedTable")]
+        referenced_table: String,
+        /// The constrained columns.
+        columns: Vec<String>,
+    },
+    /// A foreign key was dropped.
+    DroppedForeignKey {
+        /// The name of the table.
+        table: String,
+        /// The table the foreign key referenced.
+        #[serde(rename = "referencedTable")]
+        referenced_table: String,
+        /// The constrained columns.
+        columns: Vec<String>,
+    },
+    /// An enum was created.
+    CreatedEnum {
+        /// The name of the enum.
+        name: String,
+    },
+    /// An enum was dropped.
+    DroppedEnum {
+        /// The name of the enum.
+        name: String,
+    },
+    /// Values were added to or removed from an enum.
+    AlteredEnum {
+        /// The name of the enum.
+        name: String,
+        /// The added values.
+        #[serde(rename = "addedValues")]
+        added_values: Vec<String>,
+        /// The removed values.
+        #[serde(rename = "droppedValues")]
+        dropped_values: Vec<String>,
+    },
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// A hint telling the migration inferrer that a table or a column was renamed, so it is migrated
+/// with a renaming instead of being dropped and recreated. Names are database names, not model or
+/// field names.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RenameHint {
+    /// The table named `previous` was renamed to `next`.
+    Table {
+        /// The name of the table before the migration.
+        previous: String,
+        /// The name of the table after the migration.
+        next: String,
+    },
+    /// The column named `previous` was renamed to `next` in `table`.
+    Column {
+        /// The name of the table after the migration.
+        table: String,
+        /// The name of the column before the migration.
+        previous: String,
+        /// The name of the column after the migration.
+        next: String,
+    },
+}
+
+impl RenameHint {
+    /// The name a table had before the migration, if it was renamed to `next_table`.
+    pub fn previous_table_name<'a>(hints: &'a [RenameHint], next_table: &str) -> Option<&'a str> {
+        hints.iter().find_map(|hint| match hint {
+            RenameHint::Table { previous, next } if next == next_table => Some(previous.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The name a column of `table` had before the migration, if it was renamed to `next_column`.
+    pub fn previous_column_name<'a>(hints: &'a [RenameHint], table: &str, next_column: &str) -> Option<&'a str> {
+        hints.iter().find_map(|hint| match hint {
+            RenameHint::Column {
+                table: hint_table,
+                previous,
+                next,
+            } if hint_table == table && next == next_column => Some(previous.as_str()),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,477 @@
+//! Splitting SQL scripts into statements.
+
+/// Split a SQL script into its statements on semicolons, ignoring semicolons
+/// in string literals, quoted identifiers, comments and PostgreSQL
+/// dollar-quoted strings. Quotes are escaped by doubling them, as in standard
+/// SQL. Semicolons in `BEGIN ... END` and `CASE ... END` blocks, like the
+/// bodies of SQLite triggers and SQL Server procedures, do not end the
+/// statement either. Empty statements are skipped.
+pub fn split_sql_statements(script: &str) -> Vec<&str> {
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut block_depth: usize = 0;
+    let mut idx = 0;
+
+    let mut push_statement = |start: usize, end: usize| {
+        let statement = script[start..end].trim();
+
+        if !statement.is_empty() {
+            statements.push(statement);
+        }
+    };
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            quote @ b'\'' | quote @ b'"' | quote @ b'`' => {
+                idx += 1;
+
+                while idx < bytes.len() {
+                    if bytes[idx] == quote {
+                        // Doubled quotes are escaped quotes.
+                        if bytes.get(idx + 1) == Some(&quote) {
+                            idx += 1;
+                        } else {
+                            break;
+                        }
+                    }
+
+                    idx += 1;
+                }
+            }
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                while idx < bytes.len() && bytes[idx] != b'\n' {
+                    idx += 1;
+                }
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = script[idx + 2..]
+                    .find("*/")
+                    .map(|end| idx + 2 + end + 1)
+                    .unwrap_or(bytes.len());
+            }
+            b'$' => {
+                if let Some(tag) = dollar_quote_tag(&script[idx..]) {
+                    idx = script[idx + tag.len()..]
+                        .find(tag)
+                        .map(|end| idx + tag.len() + end + tag.len() - 1)
+                        .unwrap_or(bytes.len());
+                }
+            }
+            b';' if block_depth == 0 => {
+                push_statement(statement_start, idx);
+                statement_start = idx + 1;
+            }
+            byte if byte.is_ascii_alphabetic() && (idx == 0 || !is_word_byte(bytes[idx - 1])) => {
+                let len = bytes[idx..].iter().take_while(|byte| is_word_byte(**byte)).count();
+                let word = &script[idx..idx + len];
+
+                if word.eq_ignore_ascii_case("CASE") {
+                    block_depth += 1;
+                } else if word.eq_ignore_ascii_case("BEGIN") {
+                    // `BEGIN` starting a statement usually opens a transaction.
+                    let starts_statement = statement_keywords(&script[statement_start..idx], 1).is_empty();
+
+                    if !(starts_statement && is_transaction_control(&script[idx..])) {
+                        block_depth += 1;
+                    }
+                } else if word.eq_ignore_ascii_case("END") {
+                    block_depth = block_depth.saturating_sub(1);
+                }
+
+                idx += len - 1;
+            }
+            _ => (),
+        }
+
+        idx += 1;
+    }
+
+    if statement_start < bytes.len() {
+        push_statement(statement_start, bytes.len());
+    }
+
+    statements
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Split a SQL Server script into its batches on the `GO` separator lines, as
+/// `sqlcmd` does. A separator is a line containing only `GO`, in any case,
+/// optionally followed by a comment. Separators in string literals, bracketed
+/// identifiers and block comments are ignored. Empty batches are skipped.
+pub fn split_sql_batches(script: &str) -> Vec<&str> {
+    let bytes = script.as_bytes();
+    let mut batches = Vec::new();
+    let mut batch_start = 0;
+    let mut line_start = 0;
+    let mut idx = 0;
+
+    let mut push_batch = |start: usize, end: usize| {
+        let batch = script[start..end].trim();
+
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+    };
+
+    while idx <= bytes.len() {
+        let byte = bytes.get(idx).copied();
+
+        match byte {
+            None | Some(b'\n') => {
+                // Lines containing the end of a string or comment start before it, so they are
+                // never separators.
+                if is_batch_separator(&script[line_start..idx]) {
+                    push_batch(batch_start, line_start);
+                    batch_start = idx;
+                }
+
+                line_start = idx + 1;
+            }
+            Some(quote @ b'\'') | Some(quote @ b'"') | Some(quote @ b'[') => {
+                let closing = if quote == b'[' { b']' } else { quote };
+                idx += 1;
+
+                while idx < bytes.len() {
+                    if bytes[idx] == closing {
+                        // Doubled quotes are escaped quotes.
+                        if bytes.get(idx + 1) == Some(&closing) {
+                            idx += 1;
+                        } else {
+                            break;
+                        }
+                    }
+
+                    idx += 1;
+                }
+            }
+            Some(b'-') if bytes.get(idx + 1) == Some(&b'-') => {
+                while idx + 1 < bytes.len() && bytes[idx + 1] != b'\n' {
+                    idx += 1;
+                }
+            }
+            Some(b'/') if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = script[idx + 2..]
+                    .find("*/")
+                    .map(|end| idx + 2 + end + 1)
+                    .unwrap_or(bytes.len());
+            }
+            _ => (),
+        }
+
+        idx += 1;
+    }
+
+    if batch_start < bytes.len() {
+        push_batch(batch_start, bytes.len());
+    }
+
+    batches
+}
+
+fn is_batch_separator(line: &str) -> bool {
+    let line = match line.find("--") {
+        Some(comment_start) => &line[..comment_start],
+        None => line,
+    };
+
+    line.trim().eq_ignore_ascii_case("GO")
+}
+
+/// The first `count` words of a statement, upper-cased, after its leading
+/// comments. Quoted identifiers are part of their word, so
+/// `ALTER TYPE "public"."My Enum" ADD VALUE 'A'` starts with `ALTER`, `TYPE`,
+/// `"PUBLIC"."MY ENUM"`, `ADD` and `VALUE`.
+pub fn statement_keywords(statement: &str, count: usize) -> Vec<String> {
+    let mut chars = statement.chars().peekable();
+    let mut words = Vec::with_capacity(count);
+
+    while words.len() < count {
+        match chars.peek() {
+            None => break,
+            Some(c) if c.is_whitespace() => {
+                chars.next();
+            }
+            Some('-') => {
+                chars.next();
+
+                if chars.peek() != Some(&'-') {
+                    words.push("-".to_owned());
+                    continue;
+                }
+
+                while chars.next().map(|c| c != '\n').unwrap_or(false) {}
+            }
+            Some('/') => {
+                chars.next();
+
+                if chars.peek() != Some(&'*') {
+                    words.push("/".to_owned());
+                    continue;
+                }
+
+                chars.next();
+                let mut previous = ' ';
+
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+
+                    previous = c;
+                }
+            }
+            Some(';') => break,
+            Some(_) => {
+                let mut word = String::new();
+
+                while let Some(c) = chars.peek().copied() {
+                    if c.is_whitespace() || c == ';' {
+                        break;
+                    }
+
+                    chars.next();
+                    word.push(c);
+
+                    let closing = match c {
+                        '"' | '`' | '\'' => c,
+                        '[' => ']',
+                        _ => continue,
+                    };
+
+                    for c in chars.by_ref() {
+                        word.push(c);
+
+                        if c == closing {
+                            break;
+                        }
+                    }
+                }
+
+                words.push(word.to_uppercase());
+            }
+        }
+    }
+
+    words
+}
+
+/// Whether the statement starts, ends or otherwise controls a transaction, like
+/// `BEGIN`, `COMMIT` or `SAVEPOINT s1`. Blocks of procedural code that happen to
+/// start with `BEGIN`, like `BEGIN TRY` or `BEGIN ... END` on SQL Server, do not.
+pub fn is_transaction_control(statement: &str) -> bool {
+    let words = statement_keywords(statement, 2);
+    let second = words.get(1).map(String::as_str);
+
+    match words.first().map(String::as_str) {
+        Some("BEGIN") => matches!(
+            second,
+            None | Some("TRANSACTION")
+                | Some("TRAN")
+                | Some("WORK")
+                | Some("DEFERRED")
+                | Some("IMMEDIATE")
+                | Some("EXCLUSIVE")
+                | Some("ISOLATION")
+                | Some("DISTRIBUTED")
+        ),
+        Some("START") => second == Some("TRANSACTION"),
+        Some("END") => matches!(second, Some("TRANSACTION") | Some("WORK")),
+        Some("COMMIT") | Some("ROLLBACK") | Some("SAVEPOINT") | Some("RELEASE") => true,
+        _ => false,
+    }
+}
+
+/// Whether the statement reads or writes data, as opposed to changing the
+/// schema or the session.
+pub fn is_data_manipulation(statement: &str) -> bool {
+    matches!(
+        statement_keywords(statement, 1).first().map(String::as_str),
+        Some("INSERT") | Some("UPDATE") | Some("DELETE") | Some("REPLACE") | Some("SELECT") | Some("WITH")
+    )
+}
+
+/// Returns the opening tag of a dollar-quoted string (`$$` or `$tag$`) at the
+/// start of the input, if any.
+fn dollar_quote_tag(input: &str) -> Option<&str> {
+    let end = input[1..].find(|c: char| !(c.is_alphanumeric() || c == '_'))? + 1;
+
+    if input[end..].starts_with('$') && !input[1..end].starts_with(|c: char| c.is_ascii_digit()) {
+        Some(&input[..=end])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_sql_statements_splits_on_semicolons() {
+        let script = "INSERT INTO \"Cat\" (name) VALUES ('Mittens');\n\nINSERT INTO \"Cat\" (name) VALUES ('Felix')";
+
+        assert_eq!(
+            split_sql_statements(script),
+            &[
+                "INSERT INTO \"Cat\" (name) VALUES ('Mittens')",
+                "INSERT INTO \"Cat\" (name) VALUES ('Felix')"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_sql_statements_ignores_quoted_semicolons_and_comments() {
+        let script = r#"
+            -- Seed the cats; all of them.
+            INSERT INTO "Cat;s" (name) VALUES ('Mit;tens'), ('O''Malley;');
+            /* a block; comment */
+            INSERT INTO `Dog` (name) VALUES ("Rex;");
+        "#;
+
+        let statements = split_sql_statements(script);
+
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].ends_with("('O''Malley;')"));
+        assert_eq!(
+            statements[1],
+            "/* a block; comment */\n            INSERT INTO `Dog` (name) VALUES (\"Rex;\")"
+        );
+    }
+
+    #[test]
+    fn split_sql_batches_splits_on_go_lines() {
+        let script = r#"
+            CREATE TABLE [dbo].[Cat] ([name] NVARCHAR(100));
+            go
+            CREATE VIEW [dbo].[Cats] AS SELECT * FROM [dbo].[Cat];
+            GO -- the view must be created in its own batch
+            INSERT INTO [dbo].[Cat] ([name]) VALUES ('
+            GO
+            ');
+            /*
+            GO
+            */
+            SELECT [
+            GO
+            ] FROM [dbo].[Cat];
+            GO
+        "#;
+
+        let batches = split_sql_batches(script);
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0], "CREATE TABLE [dbo].[Cat] ([name] NVARCHAR(100));");
+        assert_eq!(batches[1], "CREATE VIEW [dbo].[Cats] AS SELECT * FROM [dbo].[Cat];");
+        assert!(batches[2].starts_with("INSERT INTO"));
+        assert!(batches[2].ends_with("] FROM [dbo].[Cat];"));
+    }
+
+    #[test]
+    fn split_sql_batches_keeps_scripts_without_separators_whole() {
+        let script = "CREATE TABLE [Good] ([id] INT);\nINSERT INTO [Good] VALUES (1);";
+
+        assert_eq!(split_sql_batches(script), &[script]);
+        assert!(split_sql_batches("GO\n\ngo\n").is_empty());
+    }
+
+    #[test]
+    fn statement_keywords_skip_comments_and_keep_quoted_identifiers_whole() {
+        let statement = "-- AlterEnum\n/* the mood; of cats */ ALTER TYPE \"public\".\"Cat Mood\" ADD VALUE 'SLEEPY'";
+
+        assert_eq!(
+            statement_keywords(statement, 5),
+            &["ALTER", "TYPE", "\"PUBLIC\".\"CAT MOOD\"", "ADD", "VALUE"]
+        );
+        assert_eq!(statement_keywords("COMMIT;", 3), &["COMMIT"]);
+    }
+
+    #[test]
+    fn is_transaction_control_only_matches_transaction_statements() {
+        for statement in &[
+            "BEGIN",
+            "begin transaction",
+            "BEGIN IMMEDIATE",
+            "START TRANSACTION",
+            "COMMIT",
+            "ROLLBACK TO SAVEPOINT s1",
+            "SAVEPOINT s1",
+            "END TRANSACTION",
+            "-- Done\nCOMMIT",
+        ] {
+            assert!(is_transaction_control(statement), "{}", statement);
+        }
+
+        for statement in &[
+            "BEGIN TRY\n    DROP TABLE [Cat]",
+            "BEGIN\n    INSERT INTO [Cat] VALUES (1)",
+            "END",
+            "DO $$ BEGIN CREATE TYPE mood AS ENUM ('happy'); END $$",
+            "CREATE TABLE \"BEGIN\" (id INT)",
+            "BEGINNING",
+            "PRAGMA foreign_keys=OFF",
+        ] {
+            assert!(!is_transaction_control(statement), "{}", statement);
+        }
+
+        let script = "CREATE FUNCTION f() RETURNS trigger AS $body$ BEGIN RETURN NEW; END; $body$ LANGUAGE plpgsql;";
+
+        assert!(!split_sql_statements(script).into_iter().any(is_transaction_control));
+    }
+
+    #[test]
+    fn is_data_manipulation_works() {
+        assert!(is_data_manipulation("INSERT INTO `Cat` VALUES (1)"));
+        assert!(is_data_manipulation("/* fix names */ update `Cat` SET name = 'a'"));
+        assert!(!is_data_manipulation("ALTER TABLE `Cat` ADD COLUMN `age` INT"));
+        assert!(!is_data_manipulation("CREATE TABLE `Insert` (id INT)"));
+    }
+
+    #[test]
+    fn split_sql_statements_keeps_blocks_whole() {
+        let script = r#"
+            BEGIN;
+            CREATE TRIGGER "touch" AFTER UPDATE ON "Cat" BEGIN
+                UPDATE "Cat" SET "mood" = CASE WHEN "mood" = 'a' THEN 'b' ELSE 'c' END WHERE "id" = NEW."id";
+                DELETE FROM "Dog";
+            END;
+            BEGIN TRY
+                DROP TABLE [Cat];
+            END TRY
+            BEGIN CATCH
+                SELECT 1;
+            END CATCH;
+            SELECT "BeginEnd", 'END' AS end_;
+            END TRANSACTION;
+        "#;
+
+        let statements = split_sql_statements(script);
+
+        assert_eq!(statements.len(), 5, "{:#?}", statements);
+        assert_eq!(statements[0], "BEGIN");
+        assert!(statements[1].starts_with("CREATE TRIGGER"));
+        assert!(statements[1].ends_with("END"));
+        assert!(statements[2].starts_with("BEGIN TRY"));
+        assert!(statements[2].ends_with("END CATCH"));
+        assert_eq!(statements[3], "SELECT \"BeginEnd\", 'END' AS end_");
+        assert_eq!(statements[4], "END TRANSACTION");
+    }
+
+    #[test]
+    fn split_sql_statements_handles_dollar_quoting() {
+        let script =
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql; SELECT $$a;b$$; SELECT $1;";
+
+        assert_eq!(
+            split_sql_statements(script),
+            &[
+                "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql",
+                "SELECT $$a;b$$",
+                "SELECT $1",
+            ]
+        );
+    }
+}
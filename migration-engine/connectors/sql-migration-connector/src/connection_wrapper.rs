@@ -1,16 +1,26 @@
-use crate::error::quaint_error_to_connector_error;
+use crate::{
+    driver_adapter::{AdapterQueryable, DriverAdapter},
+    error::quaint_error_to_connector_error,
+};
 use migration_connector::ConnectorError;
 use quaint::{
     error::{Error as QuaintError, ErrorKind as QuaintKind},
     prelude::{ConnectionInfo, Query, Queryable, ResultSet},
     single::Quaint,
 };
+use sql_schema_describer::DescriberConnection;
+use std::{fmt, sync::Arc};
+use user_facing_errors::migration_engine::MissingDatabasePrivilege;
 
-/// An internal helper for the SQL connector. It wraps a `Quaint` struct and
-/// exposes a similar API, with additional error handling to return
-/// `ConnectorResult`s.
-#[derive(Clone, Debug)]
-pub(crate) struct Connection(Quaint);
+/// An internal helper for the SQL connector. It wraps a `Quaint` struct, or
+/// the driver adapter of an embedder, and exposes a similar API, with
+/// additional error handling to return `ConnectorResult`s.
+#[derive(Clone)]
+pub(crate) struct Connection {
+    queryable: Arc<dyn Queryable + Send + Sync>,
+    connection_info: ConnectionInfo,
+    quaint: Option<Quaint>,
+}
 
 #[derive(Debug)]
 pub(crate) struct ConnectionError<'a> {
@@ -20,10 +30,43 @@ pub(crate) struct ConnectionError<'a> {
 
 type ConnectionResult<'a, T> = Result<T, ConnectionError<'a>>;
 
+/// The error codes of the databases for statements the user lacks a privilege for: insufficient
+/// privilege on Postgres, table and database access denied and missing global privilege on MySQL,
+/// permission denied on SQL Server, and read-only database and access permission denied on SQLite.
+const PERMISSION_DENIED_CODES: &[&str] = &["42501", "1142", "1044", "1227", "229", "262", "8", "3"];
+
 impl ConnectionError<'_> {
     pub(crate) fn kind(&self) -> &QuaintKind {
         self.quaint_error.kind()
     }
+
+    /// Whether the database refused the statement because the user lacks a privilege.
+    pub(crate) fn is_permission_denied(&self) -> bool {
+        self.quaint_error
+            .original_code()
+            .map(|code| PERMISSION_DENIED_CODES.contains(&code))
+            .unwrap_or(false)
+    }
+
+    /// A `MissingDatabasePrivilege` error naming the privilege if the statement was refused for
+    /// lack of privileges, the error as is otherwise.
+    pub(crate) fn into_missing_privilege(self, privilege: &str, operation: &str) -> ConnectorError {
+        if !self.is_permission_denied() {
+            return self.into();
+        }
+
+        let details = self
+            .quaint_error
+            .original_message()
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| self.quaint_error.to_string());
+
+        ConnectorError::user_facing_error(MissingDatabasePrivilege {
+            privilege: privilege.to_owned(),
+            operation: operation.to_owned(),
+            details: format!("Database error:\n{}", details),
+        })
+    }
 }
 
 impl From<ConnectionError<'_>> for ConnectorError {
@@ -32,17 +75,42 @@ impl From<ConnectionError<'_>> for ConnectorError {
     }
 }
 
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("connection_info", &self.connection_info)
+            .finish()
+    }
+}
+
 impl Connection {
     pub(crate) fn new(quaint: Quaint) -> Self {
-        Connection(quaint)
+        Connection {
+            queryable: Arc::new(quaint.clone()),
+            connection_info: quaint.connection_info().clone(),
+            quaint: Some(quaint),
+        }
+    }
+
+    pub(crate) fn from_adapter(adapter: Arc<dyn DriverAdapter>) -> Self {
+        Connection {
+            connection_info: adapter.connection_info().clone(),
+            queryable: Arc::new(AdapterQueryable(adapter)),
+            quaint: None,
+        }
     }
 
     pub(crate) fn connection_info(&self) -> &ConnectionInfo {
-        self.0.connection_info()
+        &self.connection_info
+    }
+
+    /// The connection, for the schema describers.
+    pub(crate) fn describer_connection(&self) -> DescriberConnection {
+        DescriberConnection::new(self.queryable.clone())
     }
 
     pub(crate) async fn execute(&self, query: impl Into<Query<'_>>) -> ConnectionResult<'_, u64> {
-        self.0
+        self.queryable
             .execute(query.into())
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -51,12 +119,13 @@ impl Connection {
             })
     }
 
-    pub(crate) fn quaint(&self) -> &Quaint {
-        &self.0
+    /// The Quaint connection, if the connector was not created with a driver adapter.
+    pub(crate) fn quaint(&self) -> Option<&Quaint> {
+        self.quaint.as_ref()
     }
 
     pub(crate) async fn query(&self, query: impl Into<Query<'_>>) -> ConnectionResult<'_, ResultSet> {
-        self.0
+        self.queryable
             .query(query.into())
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -66,7 +135,7 @@ impl Connection {
     }
 
     pub(crate) async fn query_raw(&self, sql: &str, params: &[quaint::Value<'_>]) -> ConnectionResult<'_, ResultSet> {
-        self.0
+        self.queryable
             .query_raw(sql, params)
             .await
             .map_err(|quaint_error| ConnectionError {
@@ -76,14 +145,17 @@ impl Connection {
     }
 
     pub(crate) async fn raw_cmd(&self, sql: &str) -> ConnectionResult<'_, ()> {
-        self.0.raw_cmd(sql).await.map_err(|quaint_error| ConnectionError {
-            quaint_error,
-            connection_info: self.connection_info(),
-        })
+        self.queryable
+            .raw_cmd(sql)
+            .await
+            .map_err(|quaint_error| ConnectionError {
+                quaint_error,
+                connection_info: self.connection_info(),
+            })
     }
 
     pub(crate) async fn version(&self) -> ConnectionResult<'_, Option<String>> {
-        self.0.version().await.map_err(|quaint_error| ConnectionError {
+        self.queryable.version().await.map_err(|quaint_error| ConnectionError {
             quaint_error,
             connection_info: self.connection_info(),
         })
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use quaint::{
+    ast::{Query, Value},
+    connector::{Queryable, ResultSet},
+    prelude::{ConnectionInfo, SqlFamily},
+    visitor::{self, Visitor},
+};
+use std::sync::Arc;
+
+/// The raw connection layer of the connector, for embedders that cannot let the connector open
+/// its own connections, e.g. in serverless or edge environments without native TCP sockets.
+/// The adapter receives rendered SQL with its parameters, and typically forwards it to a driver
+/// of the host, like a JavaScript driver called through callbacks.
+///
+/// Creating, dropping and resetting databases, as well as shadow databases, still connect with
+/// the connection string.
+#[async_trait]
+pub trait DriverAdapter: Send + Sync {
+    /// The database the adapter is connected to. It determines the SQL dialect of the queries.
+    fn connection_info(&self) -> &ConnectionInfo;
+
+    /// Run a query and return its rows.
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet>;
+
+    /// Run a statement and return the number of affected rows.
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<u64>;
+
+    /// Run one or more statements without parameters, e.g. a migration script.
+    async fn raw_cmd(&self, sql: &str) -> quaint::Result<()>;
+
+    /// The version of the database, if the driver knows it.
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Exposes a driver adapter as a Quaint `Queryable`, rendering the queries built with the Quaint
+/// AST in the dialect of the database.
+pub(crate) struct AdapterQueryable(pub(crate) Arc<dyn DriverAdapter>);
+
+impl AdapterQueryable {
+    fn render<'q>(&self, q: Query<'q>) -> quaint::Result<(String, Vec<Value<'q>>)> {
+        match self.0.connection_info().sql_family() {
+            SqlFamily::Postgres => visitor::Postgres::build(q),
+            SqlFamily::Mysql => visitor::Mysql::build(q),
+            SqlFamily::Sqlite => visitor::Sqlite::build(q),
+            SqlFamily::Mssql => visitor::Mssql::build(q),
+        }
+    }
+}
+
+#[async_trait]
+impl Queryable for AdapterQueryable {
+    async fn query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        let (sql, params) = self.render(q)?;
+        self.0.query_raw(&sql, &params).await
+    }
+
+    async fn execute(&self, q: Query<'_>) -> quaint::Result<u64> {
+        let (sql, params) = self.render(q)?;
+        self.0.execute_raw(&sql, &params).await
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet> {
+        self.0.query_raw(sql, params).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<u64> {
+        self.0.execute_raw(sql, params).await
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> quaint::Result<()> {
+        self.0.raw_cmd(cmd).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        self.0.version().await
+    }
+}
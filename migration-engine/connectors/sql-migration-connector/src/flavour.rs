@@ -19,19 +19,85 @@ use crate::{
     sql_schema_differ::SqlSchemaDifferFlavour,
 };
 use datamodel::Datamodel;
-use migration_connector::{ConnectorResult, MigrationDirectory, MigrationFeature};
+use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::{
     connector::ConnectionInfo,
     prelude::{SqlFamily, Table},
 };
 use sql_schema_describer::SqlSchema;
-use std::fmt::Debug;
+use std::{borrow::Cow, fmt::Debug};
+use user_facing_errors::migration_engine::MissingDatabasePrivilege;
 
 /// The maximum size of identifiers on MySQL, in bytes.
 ///
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
+/// The maximum length of postgres identifiers, in bytes.
+///
+/// Reference: https://www.postgresql.org/docs/12/limits.html
+pub(crate) const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
+
+/// The maximum length of SQL Server identifiers, in characters.
+///
+/// Reference: https://docs.microsoft.com/en-us/sql/relational-databases/databases/database-identifiers
+pub(crate) const MSSQL_IDENTIFIER_SIZE_LIMIT: usize = 128;
+
+/// The table created and dropped by `SqlFlavour::check_table_permissions`.
+const PERMISSIONS_CHECK_TABLE_NAME: &str = "_prisma_migrate_permissions_check";
+
+/// The number of characters of the name hash appended to truncated identifiers.
+const TRUNCATED_IDENTIFIER_HASH_LENGTH: usize = 8;
+
+/// Shortens identifiers above the size limit of the database. The end of the name is replaced with
+/// a hash of the full name, so that long names sharing a prefix do not collide, and the same name
+/// is always truncated the same way.
+pub(crate) fn truncate_identifier(name: &str, size_limit: usize) -> Cow<'_, str> {
+    if name.len() <= size_limit {
+        return Cow::Borrowed(name);
+    }
+
+    let hash = migration_connector::checksum(name);
+    let mut prefix_length = size_limit - TRUNCATED_IDENTIFIER_HASH_LENGTH - 1;
+
+    while !name.is_char_boundary(prefix_length) {
+        prefix_length -= 1;
+    }
+
+    Cow::Owned(format!(
+        "{}_{}",
+        &name[..prefix_length],
+        &hash[..TRUNCATED_IDENTIFIER_HASH_LENGTH]
+    ))
+}
+
+/// Whether an index name in the database matches the (possibly truncated) name it should have.
+/// Indexes created before names were truncated with a hash have the plain prefix of the name.
+pub(crate) fn truncated_identifiers_match(previous_name: &str, next_name: &str, size_limit: usize) -> bool {
+    previous_name == truncate_identifier(next_name, size_limit)
+        || (previous_name.len() == size_limit && next_name.len() > size_limit && next_name.starts_with(previous_name))
+}
+
+/// The first column of the rows of a query, e.g. the names of database objects.
+async fn query_names(connection: &Connection, sql: &str, params: &[quaint::Value<'_>]) -> ConnectorResult<Vec<String>> {
+    Ok(connection
+        .query_raw(sql, params)
+        .await?
+        .into_iter()
+        .filter_map(|row| row.at(0).and_then(|value| value.to_string()))
+        .collect())
+}
+
+/// The error of `SqlFlavour::check_shadow_database_permissions` when the privilege to create
+/// databases is missing.
+fn missing_shadow_database_privilege(privilege: &str, details: &str) -> ConnectorError {
+    ConnectorError::user_facing_error(MissingDatabasePrivilege {
+        privilege: privilege.to_owned(),
+        operation: "create the shadow database".to_owned(),
+        details: details.to_owned(),
+    })
+}
+
 pub(crate) fn from_connection_info(
     connection_info: &ConnectionInfo,
     features: BitFlags<MigrationFeature>,
@@ -62,6 +128,36 @@ pub(crate) trait SqlFlavour:
         None
     }
 
+    /// Check that the database user can create the shadow database, see
+    /// `sql_schema_from_migration_history`.
+    async fn check_shadow_database_permissions(&self, _connection: &Connection) -> ConnectorResult<()> {
+        Ok(())
+    }
+
+    /// Check that the database user can create, alter and drop tables, by doing so with an empty
+    /// table.
+    async fn check_table_permissions(&self, connection: &Connection) -> ConnectorResult<()> {
+        let table = self.permissions_check_table();
+
+        connection
+            .raw_cmd(&format!("CREATE TABLE {} (id INTEGER)", table))
+            .await
+            .map_err(|err| err.into_missing_privilege("CREATE", "create tables"))?;
+
+        // The table is dropped even if it can't be altered.
+        let altered = connection
+            .raw_cmd(&format!("ALTER TABLE {} ADD checked INTEGER", table))
+            .await
+            .map_err(|err| err.into_missing_privilege("ALTER", "alter tables"));
+
+        connection
+            .raw_cmd(&format!("DROP TABLE {}", table))
+            .await
+            .map_err(|err| err.into_missing_privilege("DROP", "drop tables"))?;
+
+        altered
+    }
+
     /// Create a database for the given URL on the server, if applicable.
     async fn create_database(&self, database_url: &str) -> ConnectorResult<String>;
 
@@ -96,6 +192,20 @@ pub(crate) trait SqlFlavour:
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema>;
 
+    /// The sequences of the schema that are not owned by a column, and are not dropped along
+    /// with a table.
+    async fn standalone_sequence_names(&self, _connection: &Connection) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// The views of the schema, which `describe_schema` leaves out.
+    async fn view_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>>;
+
+    /// The table created and dropped by `check_table_permissions`.
+    fn permissions_check_table(&self) -> String {
+        self.quote(PERMISSIONS_CHECK_TABLE_NAME).to_string()
+    }
+
     /// Table to store applied migrations, the name part.
     fn imperative_migrations_table_name(&self) -> &'static str {
         "_prisma_migrations"
@@ -110,4 +220,25 @@ pub(crate) trait SqlFlavour:
     fn features(&self) -> BitFlags<MigrationFeature> {
         BitFlags::empty()
     }
+
+    /// Whether DDL statements can be rolled back as part of a transaction.
+    fn supports_transactional_ddl(&self) -> bool {
+        true
+    }
+
+    /// Whether the statement can't run inside a transaction block. Migration
+    /// scripts containing such statements are applied one statement at a time.
+    fn requires_autocommit(&self, _statement: &str) -> bool {
+        false
+    }
+
+    /// The statement opening a transaction.
+    fn begin_transaction_statement(&self) -> &'static str {
+        "BEGIN"
+    }
+
+    /// The batches of a script, sent to the database one after the other.
+    fn script_batches<'a>(&self, script: &'a str) -> Vec<&'a str> {
+        vec![script]
+    }
 }
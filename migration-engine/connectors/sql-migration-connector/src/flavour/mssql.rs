@@ -1,7 +1,7 @@
 use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
 use connection_string::JdbcString;
 use enumflags2::BitFlags;
-use indoc::formatdoc;
+use indoc::{formatdoc, indoc};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::{
     connector::MssqlUrl,
@@ -34,6 +34,91 @@ impl MssqlFlavour {
         let db_name = params.remove("database").unwrap_or_else(|| String::from("master"));
         Ok((db_name, conn.to_string()))
     }
+
+    /// The URL of the datasource with another database.
+    fn url_for_database(&self, database_name: &str) -> String {
+        let mut jdbc_string: JdbcString = self.url.connection_string().parse().unwrap();
+        jdbc_string
+            .properties_mut()
+            .insert("database".into(), database_name.to_owned());
+
+        jdbc_string.to_string()
+    }
+
+    /// Creates a shadow database. Some servers, e.g. Azure SQL, only allow creating databases
+    /// from `master`: if creating it from the connection fails, it is created from a connection
+    /// to `master`, which is returned to drop it through.
+    async fn create_shadow_database(
+        &self,
+        connection: &Connection,
+        database_name: &str,
+    ) -> ConnectorResult<Option<Connection>> {
+        let create_database = format!("CREATE DATABASE [{}]", database_name);
+
+        let err = match connection.raw_cmd(&create_database).await {
+            Ok(()) => return Ok(None),
+            Err(err) => ConnectorError::from(err).into_shadow_db_creation_error(),
+        };
+
+        tracing::debug!("Could not create the shadow database, retrying from `master`: {}", err);
+
+        let master = match connect(&self.url_for_database("master")).await {
+            Ok(master) => master,
+            Err(_) => return Err(err),
+        };
+
+        master
+            .raw_cmd(&create_database)
+            .await
+            .map_err(ConnectorError::from)
+            .map_err(|err| err.into_shadow_db_creation_error())?;
+
+        Ok(Some(master))
+    }
+
+    /// Applies the migrations to the shadow database and describes the resulting schema.
+    async fn apply_to_shadow_database(
+        &self,
+        migrations: &[MigrationDirectory],
+        shadow_database_url: &str,
+    ) -> ConnectorResult<SqlSchema> {
+        tracing::debug!(
+            "Connecting to temporary database at `{}`",
+            datasource_url::redact_url(shadow_database_url)
+        );
+
+        let temp_database = crate::connect(shadow_database_url).await?;
+
+        if self.schema_name() != "dbo" {
+            let create_schema = format!("CREATE SCHEMA [{schema}]", schema = self.schema_name());
+
+            temp_database.raw_cmd(&create_schema).await?;
+        }
+
+        for migration in migrations {
+            let script = migration.read_migration_script()?;
+
+            tracing::debug!(
+                "Applying migration `{}` to temporary database.",
+                migration.migration_name()
+            );
+
+            for (idx, batch) in self.script_batches(&script).into_iter().enumerate() {
+                if let Err(err) = temp_database.raw_cmd(batch).await {
+                    tracing::debug!(
+                        "Batch {} of migration `{}` failed.",
+                        idx + 1,
+                        migration.migration_name()
+                    );
+
+                    return Err(ConnectorError::from(err)
+                        .into_migration_does_not_apply_cleanly(migration.migration_name().to_owned()));
+                }
+            }
+        }
+
+        self.describe_schema(&temp_database).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -76,7 +161,7 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
+        sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.describer_connection())
             .describe(connection.connection_info().schema_name())
             .await
             .map_err(|err| match err.into_kind() {
@@ -163,79 +248,78 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
+    async fn check_shadow_database_permissions(&self, connection: &Connection) -> ConnectorResult<()> {
+        if can_create_databases(connection).await? {
+            return Ok(());
+        }
+
+        // Like in `create_shadow_database`, for servers only allowing to create databases from
+        // `master`.
+        if let Ok(master) = connect(&self.url_for_database("master")).await {
+            if can_create_databases(&master).await? {
+                return Ok(());
+            }
+        }
+
+        Err(super::missing_shadow_database_privilege(
+            "CREATE ANY DATABASE",
+            "The database user has neither the `CREATE ANY DATABASE` permission on the server nor the `CREATE DATABASE` permission on `master`.",
+        ))
+    }
+
+    fn permissions_check_table(&self) -> String {
+        self.quote_with_schema(super::PERMISSIONS_CHECK_TABLE_NAME).to_string()
+    }
+
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()> {
         connection.raw_cmd("SELECT 1").await?;
 
         Ok(())
     }
 
+    async fn standalone_sequence_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let sql = "SELECT name FROM sys.sequences WHERE SCHEMA_NAME(schema_id) = @P1";
+
+        super::query_names(connection, sql, &[self.schema_name().into()]).await
+    }
+
+    async fn view_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let sql = "SELECT name FROM sys.views WHERE SCHEMA_NAME(schema_id) = @P1";
+
+        super::query_names(connection, sql, &[self.schema_name().into()]).await
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mssql
     }
 
+    fn begin_transaction_statement(&self) -> &'static str {
+        "BEGIN TRANSACTION"
+    }
+
+    fn script_batches<'a>(&self, script: &'a str) -> Vec<&'a str> {
+        migration_connector::split_sql_batches(script)
+    }
+
     async fn sql_schema_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
         let database_name = format!("prisma_shadow_db{}", uuid::Uuid::new_v4());
-        let create_database = format!("CREATE DATABASE [{}]", database_name);
+        let master = self.create_shadow_database(connection, &database_name).await?;
 
-        connection
-            .raw_cmd(&create_database)
-            .await
-            .map_err(ConnectorError::from)
-            .map_err(|err| err.into_shadow_db_creation_error())?;
-
-        let mut jdbc_string: JdbcString = self.url.connection_string().parse().unwrap();
-        jdbc_string
-            .properties_mut()
-            .insert("database".into(), database_name.clone());
-        let temporary_database_url = jdbc_string.to_string();
-
-        tracing::debug!("Connecting to temporary database at `{}`", temporary_database_url);
-
-        // We must create a connection in a block, closing it before dropping
-        // the database.
-        let sql_schema_result = {
-            let temp_database = crate::connect(&temporary_database_url).await?;
-
-            // We go through the whole process without early return, then clean up
-            // the temporary database, and only then return the result. This avoids
-            // leaving shadow databases behind in case of e.g. faulty
-            // migrations.
-
-            if self.schema_name() != "dbo" {
-                let create_schema = format!("CREATE SCHEMA [{schema}]", schema = self.schema_name());
-
-                temp_database.raw_cmd(&create_schema).await?;
-            }
-
-            (|| async {
-                for migration in migrations {
-                    let script = migration.read_migration_script()?;
-
-                    tracing::debug!(
-                        "Applying migration `{}` to temporary database.",
-                        migration.migration_name()
-                    );
-
-                    temp_database
-                        .raw_cmd(&script)
-                        .await
-                        .map_err(ConnectorError::from)
-                        .map_err(|connector_error| {
-                            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                        })?;
-                }
-
-                self.describe_schema(&temp_database).await
-            })()
-            .await
-        };
+        // We go through the whole process without early return, then clean up
+        // the temporary database, and only then return the result. This avoids
+        // leaving shadow databases behind in case of e.g. faulty migrations.
+        // The connection to the shadow database is closed when the migrations
+        // are applied, as a database with open connections can't be dropped.
+        let sql_schema_result = self
+            .apply_to_shadow_database(migrations, &self.url_for_database(&database_name))
+            .await;
 
         let drop_database = format!("DROP DATABASE [{}]", database = database_name);
-        connection.raw_cmd(&drop_database).await?;
+        master.as_ref().unwrap_or(connection).raw_cmd(&drop_database).await?;
 
         sql_schema_result
     }
@@ -244,3 +328,21 @@ impl SqlFlavour for MssqlFlavour {
         self.features
     }
 }
+
+/// Whether the user of the connection can create databases, with the server-level permission or
+/// the database-level one in `master`.
+async fn can_create_databases(connection: &Connection) -> ConnectorResult<bool> {
+    let sql = indoc! {r#"
+        SELECT ISNULL(HAS_PERMS_BY_NAME(NULL, NULL, 'CREATE ANY DATABASE'), 0)
+            | ISNULL(HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'CREATE DATABASE'), 0)
+    "#};
+
+    let permitted = connection
+        .query_raw(sql, &[])
+        .await?
+        .get(0)
+        .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+        .unwrap_or(0);
+
+    Ok(permitted > 0)
+}
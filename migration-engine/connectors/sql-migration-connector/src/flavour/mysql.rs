@@ -6,7 +6,7 @@ use crate::{
 };
 use datamodel::{walkers::walk_scalar_fields, Datamodel};
 use enumflags2::BitFlags;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use once_cell::sync::Lazy;
 use quaint::{connector::MysqlUrl, prelude::SqlFamily};
@@ -23,6 +23,9 @@ pub(crate) struct MysqlFlavour {
 }
 
 impl MysqlFlavour {
+    /// Whether the database is MariaDB. CHECK constraints and `RENAME COLUMN` are not rendered
+    /// differently on MariaDB, because the migration engine produces neither: the datamodel
+    /// has no check constraints, and a column whose name changed is dropped and added again.
     pub(crate) fn is_mariadb(&self) -> bool {
         BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
             .unwrap_or_default()
@@ -35,6 +38,14 @@ impl MysqlFlavour {
             .contains(Circumstances::IsMysql56)
     }
 
+    /// Whether the server can store fractional seconds in DATETIME, TIMESTAMP and TIME columns.
+    /// This is the case from MySQL 5.6.4 on, and on all supported MariaDB versions.
+    pub(crate) fn supports_fractional_seconds(&self) -> bool {
+        !BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
+            .unwrap_or_default()
+            .contains(Circumstances::LacksFractionalSeconds)
+    }
+
     pub(crate) fn lower_cases_table_names(&self) -> bool {
         BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
             .unwrap_or_default()
@@ -74,6 +85,32 @@ impl SqlFlavour for MysqlFlavour {
         }
     }
 
+    async fn check_shadow_database_permissions(&self, connection: &Connection) -> ConnectorResult<()> {
+        // Creating a database with a new name requires the global `CREATE` privilege.
+        let sql = indoc! {r#"
+            SELECT COUNT(*)
+            FROM information_schema.USER_PRIVILEGES
+            WHERE GRANTEE = CONCAT('''', SUBSTRING_INDEX(CURRENT_USER(), '@', 1), '''@''', SUBSTRING_INDEX(CURRENT_USER(), '@', -1), '''')
+                AND PRIVILEGE_TYPE = 'CREATE'
+        "#};
+
+        let global_create_privileges = connection
+            .query_raw(sql, &[])
+            .await?
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+            .unwrap_or(0);
+
+        if global_create_privileges > 0 {
+            return Ok(());
+        }
+
+        Err(super::missing_shadow_database_privilege(
+            "CREATE",
+            "The database user is only granted the `CREATE` privilege on specific databases, not globally (`ON *.*`).",
+        ))
+    }
+
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
         url.set_path("/mysql");
@@ -92,24 +129,13 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
-                id                      VARCHAR(36) PRIMARY KEY NOT NULL,
-                checksum                VARCHAR(64) NOT NULL,
-                finished_at             DATETIME(3),
-                migration_name          VARCHAR(255) NOT NULL,
-                logs                    TEXT,
-                rolled_back_at          DATETIME(3),
-                started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
-                applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
-            ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
-        "#};
+        let sql = imperative_migrations_table(self.supports_fractional_seconds());
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::mysql::SqlSchemaDescriber::new(connection.quaint().clone())
+        sql_schema_describer::mysql::SqlSchemaDescriber::new(connection.describer_connection())
             .describe(connection.connection_info().schema_name())
             .await
             .map_err(|err| match err.into_kind() {
@@ -155,6 +181,8 @@ impl SqlFlavour for MysqlFlavour {
 
             if version.contains("MariaDB") {
                 circumstances |= Circumstances::IsMariadb;
+            } else if version_lacks_fractional_seconds(&version) {
+                circumstances |= Circumstances::LacksFractionalSeconds;
             }
         }
 
@@ -209,10 +237,21 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn view_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let sql = "SELECT table_name FROM information_schema.views WHERE table_schema = DATABASE()";
+
+        super::query_names(connection, sql, &[]).await
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mysql
     }
 
+    // DDL statements cause an implicit commit on MySQL.
+    fn supports_transactional_ddl(&self) -> bool {
+        false
+    }
+
     #[tracing::instrument(skip(self, migrations, connection))]
     async fn sql_schema_from_migration_history(
         &self,
@@ -232,7 +271,10 @@ impl SqlFlavour for MysqlFlavour {
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
-        tracing::debug!("Connecting to temporary database at {:?}", temporary_database_url);
+        tracing::debug!(
+            "Connecting to temporary database at {:?}",
+            datasource_url::redact_url(&temporary_database_url)
+        );
 
         let temp_database = crate::connect(&temporary_database_url).await?;
 
@@ -275,6 +317,41 @@ pub enum Circumstances {
     LowerCasesTableNames = 0b0001,
     IsMysql56 = 0b0010,
     IsMariadb = 0b0100,
+    /// MySQL before 5.6.4 silently drops the fractional part of temporal values, and rejects
+    /// precision arguments on temporal types.
+    LacksFractionalSeconds = 0b1000,
+}
+
+/// The `CREATE TABLE` statement of the migrations table. Without fractional seconds support,
+/// the temporal columns have no precision.
+fn imperative_migrations_table(fractional_seconds: bool) -> String {
+    let precision = if fractional_seconds { "(3)" } else { "" };
+
+    formatdoc! {r#"
+        CREATE TABLE _prisma_migrations (
+            id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+            checksum                VARCHAR(64) NOT NULL,
+            finished_at             DATETIME{precision},
+            migration_name          VARCHAR(255) NOT NULL,
+            logs                    TEXT,
+            rolled_back_at          DATETIME{precision},
+            started_at              DATETIME{precision} NOT NULL DEFAULT CURRENT_TIMESTAMP{precision},
+            applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
+        ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+    "#, precision = precision}
+}
+
+/// Whether a MySQL version string, e.g. `5.6.3-log`, predates fractional seconds support (5.6.4).
+fn version_lacks_fractional_seconds(version: &str) -> bool {
+    let mut components = version
+        .split(|c: char| !c.is_ascii_digit())
+        .take(3)
+        .map(|component| component.parse::<u32>().ok());
+
+    match (components.next(), components.next(), components.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch))) => (major, minor, patch) < (5, 6, 4),
+        _ => false,
+    }
 }
 
 fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>) {
@@ -288,3 +365,35 @@ fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractional_seconds_are_supported_from_mysql_5_6_4() {
+        assert!(version_lacks_fractional_seconds("5.5.62"));
+        assert!(version_lacks_fractional_seconds("5.6.3-log"));
+
+        assert!(!version_lacks_fractional_seconds("5.6.4"));
+        assert!(!version_lacks_fractional_seconds("5.7.32-log"));
+        assert!(!version_lacks_fractional_seconds("8.0.22"));
+        assert!(!version_lacks_fractional_seconds("10.5.8-MariaDB-1:10.5.8+maria~focal"));
+        assert!(!version_lacks_fractional_seconds("unknown"));
+    }
+
+    #[test]
+    fn the_migrations_table_only_has_precision_with_fractional_seconds() {
+        let with_precision = imperative_migrations_table(true);
+
+        assert_eq!(with_precision.matches("DATETIME(3)").count(), 3);
+        assert!(with_precision.contains("DEFAULT CURRENT_TIMESTAMP(3),"));
+
+        let without_precision = imperative_migrations_table(false);
+
+        assert!(!without_precision.contains("(3)"));
+        assert!(without_precision.contains("finished_at             DATETIME,"));
+        assert!(without_precision.contains("DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,"));
+        assert!(without_precision.contains("VARCHAR(36)"));
+    }
+}
@@ -18,6 +18,27 @@ impl PostgresFlavour {
 
 #[async_trait::async_trait]
 impl SqlFlavour for PostgresFlavour {
+    async fn check_shadow_database_permissions(&self, connection: &Connection) -> ConnectorResult<()> {
+        let can_create_databases = connection
+            .query_raw(
+                "SELECT rolcreatedb OR rolsuper FROM pg_roles WHERE rolname = current_user",
+                &[],
+            )
+            .await?
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|value| value.as_bool()))
+            .unwrap_or(false);
+
+        if can_create_databases {
+            return Ok(());
+        }
+
+        Err(super::missing_shadow_database_privilege(
+            "CREATEDB",
+            "The role of the database user has neither the `CREATEDB` nor the `SUPERUSER` attribute.",
+        ))
+    }
+
     #[tracing::instrument(skip(database_str))]
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
@@ -76,7 +97,7 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.describer_connection())
             .describe(connection.connection_info().schema_name())
             .await
             .map_err(|err| match err.into_kind() {
@@ -167,10 +188,49 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn standalone_sequence_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        // Sequences of `serial` and identity columns depend on their column automatically (`a`) or
+        // internally (`i`).
+        let sql = indoc! {r#"
+            SELECT class.relname
+            FROM pg_class class
+            JOIN pg_namespace namespace ON namespace.oid = class.relnamespace
+            WHERE class.relkind = 'S'
+                AND namespace.nspname = $1
+                AND NOT EXISTS (
+                    SELECT 1 FROM pg_depend dependency
+                    WHERE dependency.objid = class.oid AND dependency.deptype IN ('a', 'i')
+                )
+        "#};
+
+        super::query_names(connection, sql, &[self.schema_name().into()]).await
+    }
+
+    async fn view_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let sql = "SELECT table_name FROM information_schema.views WHERE table_schema = $1";
+
+        super::query_names(connection, sql, &[self.schema_name().into()]).await
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Postgres
     }
 
+    // Before PostgreSQL 12, `ALTER TYPE ... ADD VALUE` fails in a transaction
+    // block, and from 12 on, the new value can't be used in the same transaction.
+    fn requires_autocommit(&self, statement: &str) -> bool {
+        let keywords = migration_connector::statement_keywords(statement, 5);
+        let keywords: Vec<&str> = keywords.iter().map(String::as_str).collect();
+
+        match keywords.as_slice() {
+            ["ALTER", "TYPE", _, "ADD", "VALUE"] => true,
+            ["CREATE", "INDEX", "CONCURRENTLY", ..] | ["CREATE", "UNIQUE", "INDEX", "CONCURRENTLY", ..] => true,
+            ["DROP", "INDEX", "CONCURRENTLY", ..] => true,
+            ["CREATE", "DATABASE", ..] | ["DROP", "DATABASE", ..] | ["VACUUM", ..] => true,
+            _ => false,
+        }
+    }
+
     #[tracing::instrument(skip(self, migrations, connection))]
     async fn sql_schema_from_migration_history(
         &self,
@@ -191,7 +251,10 @@ impl SqlFlavour for PostgresFlavour {
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
-        tracing::debug!("Connecting to temporary database at {}", temporary_database_url);
+        tracing::debug!(
+            "Connecting to temporary database at {}",
+            datasource_url::redact_url(&temporary_database_url)
+        );
 
         // We go through the whole process without early return, then clean up
         // the temporary database, and only then return the result. This avoids
@@ -1,21 +1,41 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour,
+    sql_renderer::SqlRenderer,
+};
 use indoc::indoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::prelude::{ConnectionInfo, SqlFamily};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
 use std::path::Path;
 
+/// The file path of in-memory databases. The `file:` and `sqlite:` prefixes and the connection
+/// string parameters are not part of the path, so both `file::memory:?cache=shared` and
+/// `sqlite::memory:` resolve to it.
+const IN_MEMORY_FILE_PATH: &str = ":memory:";
+
 #[derive(Debug)]
 pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
 }
 
+impl SqliteFlavour {
+    /// In-memory databases live as long as the connection, there is no file to create, truncate
+    /// or delete.
+    fn is_in_memory(&self) -> bool {
+        self.file_path == IN_MEMORY_FILE_PATH
+    }
+}
+
 #[async_trait::async_trait]
 impl SqlFlavour for SqliteFlavour {
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         use anyhow::Context;
 
+        if self.is_in_memory() {
+            return Ok(self.file_path.clone());
+        }
+
         let path = Path::new(&self.file_path);
         if path.exists() {
             return Ok(self.file_path.clone());
@@ -52,7 +72,7 @@ impl SqlFlavour for SqliteFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection.quaint().clone())
+        sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection.describer_connection())
             .describe(connection.connection_info().schema_name())
             .await
             .map_err(|err| match err.into_kind() {
@@ -69,6 +89,10 @@ impl SqlFlavour for SqliteFlavour {
             Err(err) => return Err(ConnectorError::url_parse_error(err, database_url)),
         };
 
+        if file_path == IN_MEMORY_FILE_PATH {
+            return Ok(());
+        }
+
         std::fs::remove_file(&file_path).map_err(|err| {
             ConnectorError::generic(
                 anyhow::Error::new(err).context(format!("Failed to delete SQLite database at `{}`", file_path,)),
@@ -84,11 +108,28 @@ impl SqlFlavour for SqliteFlavour {
 
     async fn qe_setup(&self, _database_url: &str) -> ConnectorResult<()> {
         use std::fs::File;
+
+        if self.is_in_memory() {
+            return Ok(());
+        }
+
         File::create(&self.file_path).expect("Failed to truncate SQLite database");
         Ok(())
     }
 
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
+        if self.is_in_memory() {
+            let schema = self.describe_schema(connection).await?;
+
+            for table in &schema.tables {
+                for statement in self.render_drop_table(&table.name) {
+                    connection.raw_cmd(&statement).await?;
+                }
+            }
+
+            return Ok(());
+        }
+
         let file_path = connection.connection_info().file_path().unwrap();
 
         std::fs::File::create(file_path).expect("failed to truncate sqlite file");
@@ -96,10 +137,22 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    async fn view_names(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        super::query_names(connection, "SELECT name FROM sqlite_master WHERE type = 'view'", &[]).await
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Sqlite
     }
 
+    // Changing `foreign_keys` is a no-op inside a transaction.
+    fn requires_autocommit(&self, statement: &str) -> bool {
+        match migration_connector::statement_keywords(statement, 2).as_slice() {
+            [pragma, setting] => pragma == "PRAGMA" && setting.starts_with("FOREIGN_KEYS"),
+            _ => false,
+        }
+    }
+
     #[tracing::instrument(skip(self, migrations, _connection))]
     async fn sql_schema_from_migration_history(
         &self,
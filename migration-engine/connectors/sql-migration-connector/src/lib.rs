@@ -4,6 +4,7 @@
 #![allow(clippy::trivial_regex)] // these will grow
 
 mod connection_wrapper;
+mod driver_adapter;
 mod error;
 mod flavour;
 mod pair;
@@ -12,32 +13,89 @@ mod sql_database_step_applier;
 mod sql_destructive_change_checker;
 mod sql_imperative_migration_persistence;
 mod sql_migration;
+mod sql_migration_summary;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
 
 use connection_wrapper::Connection;
-use datamodel::Datamodel;
+use datamodel::{Datamodel, ForeignKeyIndexes, ReferentialIntegrity, RetryPolicy};
+pub use driver_adapter::DriverAdapter;
 use enumflags2::BitFlags;
 use error::quaint_error_to_connector_error;
 use flavour::SqlFlavour;
 use migration_connector::*;
-use quaint::{prelude::ConnectionInfo, single::Quaint};
-use sql_migration::SqlMigration;
-use sql_schema_describer::SqlSchema;
-use user_facing_errors::{common::InvalidDatabaseString, KnownError};
+use pair::Pair;
+use quaint::{
+    prelude::{ConnectionInfo, SqlFamily},
+    single::Quaint,
+};
+use sql_migration::{RedefineTable, SqlMigration};
+use sql_schema_describer::{
+    walkers::{SqlSchemaExt, TableWalker},
+    DefaultKind, SqlSchema,
+};
+use sql_schema_differ::ColumnChanges;
+use ssh_tunnel::{SshTunnel, TunnelError};
+use std::{collections::HashSet, sync::Arc};
+use user_facing_errors::{
+    common::{InvalidDatabaseString, SshTunnelError},
+    quaint::classify_transient_error,
+    KnownError,
+};
 
 /// The top-level SQL migration connector.
 pub struct SqlMigrationConnector {
     connection: Connection,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
     features: BitFlags<MigrationFeature>,
+    referential_integrity: ReferentialIntegrity,
+    foreign_key_indexes: ForeignKeyIndexes,
+    audit_log: bool,
+    database_timestamps: bool,
+    /// The SSH tunnel the connections of the connector go through, if the connection string asks
+    /// for one.
+    _tunnel: Option<SshTunnel>,
 }
 
 impl SqlMigrationConnector {
     /// Construct and initialize the SQL migration connector.
     pub async fn new(database_str: &str, features: BitFlags<MigrationFeature>) -> ConnectorResult<Self> {
-        let connection = connect(database_str).await?;
+        Self::new_with_retry_policy(database_str, features, RetryPolicy::default()).await
+    }
+
+    /// Construct and initialize the SQL migration connector, retrying to connect on transient
+    /// errors according to the provided policy.
+    pub async fn new_with_retry_policy(
+        database_str: &str,
+        features: BitFlags<MigrationFeature>,
+        retry_policy: RetryPolicy,
+    ) -> ConnectorResult<Self> {
+        let (database_str, tunnel) = connection_url(database_str)?;
+        let connection = connect_with_retries(&database_str, retry_policy).await?;
+        let flavour = flavour::from_connection_info(connection.connection_info(), features);
+
+        flavour.ensure_connection_validity(&connection).await?;
+
+        Ok(Self {
+            flavour,
+            connection,
+            features,
+            referential_integrity: ReferentialIntegrity::default(),
+            foreign_key_indexes: ForeignKeyIndexes::default(),
+            audit_log: false,
+            database_timestamps: false,
+            _tunnel: tunnel,
+        })
+    }
+
+    /// Construct and initialize the SQL migration connector on top of the driver adapter of an
+    /// embedder, instead of connecting to the database with Quaint.
+    pub async fn new_with_adapter(
+        adapter: Arc<dyn DriverAdapter>,
+        features: BitFlags<MigrationFeature>,
+    ) -> ConnectorResult<Self> {
+        let connection = Connection::from_adapter(adapter);
         let flavour = flavour::from_connection_info(connection.connection_info(), features);
 
         flavour.ensure_connection_validity(&connection).await?;
@@ -46,11 +104,45 @@ impl SqlMigrationConnector {
             flavour,
             connection,
             features,
+            referential_integrity: ReferentialIntegrity::default(),
+            foreign_key_indexes: ForeignKeyIndexes::default(),
+            audit_log: false,
+            database_timestamps: false,
+            _tunnel: None,
         })
     }
 
+    /// Set whether the migrations create foreign keys for relations, or leave enforcing them to
+    /// the query engine.
+    pub fn with_referential_integrity(mut self, referential_integrity: ReferentialIntegrity) -> Self {
+        self.referential_integrity = referential_integrity;
+        self
+    }
+
+    /// Set whether the migrations create the indexes backing foreign keys, or leave creating them
+    /// to the database.
+    pub fn with_foreign_key_indexes(mut self, foreign_key_indexes: ForeignKeyIndexes) -> Self {
+        self.foreign_key_indexes = foreign_key_indexes;
+        self
+    }
+
+    /// Set whether the migrations manage the table the query engine records its writes in.
+    pub fn with_audit_log(mut self, audit_log: bool) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
+
+    /// Set whether the database maintains the `@updatedAt` timestamps, with column defaults and
+    /// triggers created by the migrations.
+    pub fn with_database_timestamps(mut self, database_timestamps: bool) -> Self {
+        self.database_timestamps = database_timestamps;
+        self
+    }
+
     /// Create the database corresponding to the connection string, without initializing the connector.
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
+        let (database_str, _tunnel) = connection_url(database_str)?;
+        let database_str = database_str.as_str();
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
         let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty());
@@ -59,6 +151,8 @@ impl SqlMigrationConnector {
 
     /// Drop the database corresponding to the connection string, without initializing the connector.
     pub async fn drop_database(database_str: &str) -> ConnectorResult<()> {
+        let (database_str, _tunnel) = connection_url(database_str)?;
+        let database_str = database_str.as_str();
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
         let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty());
@@ -68,6 +162,8 @@ impl SqlMigrationConnector {
 
     /// Set up the database for connector-test-kit, without initializing the connector.
     pub async fn qe_setup(database_str: &str) -> ConnectorResult<()> {
+        let (database_str, _tunnel) = connection_url(database_str)?;
+        let database_str = database_str.as_str();
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
@@ -84,15 +180,138 @@ impl SqlMigrationConnector {
         self.flavour.as_ref()
     }
 
-    /// For tests.
+    fn referential_integrity(&self) -> ReferentialIntegrity {
+        self.referential_integrity
+    }
+
+    fn foreign_key_indexes(&self) -> ForeignKeyIndexes {
+        self.foreign_key_indexes
+    }
+
+    fn audit_log(&self) -> bool {
+        self.audit_log
+    }
+
+    fn database_timestamps(&self) -> bool {
+        self.database_timestamps
+    }
+
+    /// For tests. Panics if the connector was created with a driver adapter.
     pub fn quaint(&self) -> &Quaint {
-        self.connection.quaint()
+        self.connection
+            .quaint()
+            .expect("The connector was created with a driver adapter")
     }
 
     /// Made public for tests.
     pub async fn describe_schema(&self) -> ConnectorResult<SqlSchema> {
         self.flavour.describe_schema(&self.connection).await
     }
+
+    /// Drop all the tables except `keep_tables`, along with the foreign keys
+    /// from the kept tables to the dropped tables, the views, the sequences
+    /// that the kept tables don't use, and the enums that are not used by
+    /// the kept tables. Names that do not match a table are ignored.
+    async fn reset_keeping_tables(&self, keep_tables: &[String]) -> ConnectorResult<()> {
+        let schema = self.describe_schema().await?;
+        let is_kept = |table_name: &str| keep_tables.iter().any(|kept| kept == table_name);
+        let tables: Vec<TableWalker<'_>> = (0..schema.tables.len())
+            .map(|idx| schema.table_walker_at(idx))
+            .collect();
+
+        // Views may select from the dropped tables, they are dropped first.
+        let mut steps: Vec<String> = self
+            .flavour
+            .view_names(self.conn())
+            .await?
+            .iter()
+            .map(|view_name| self.flavour.render_drop_view(view_name))
+            .collect();
+
+        // Foreign keys are dropped first, so the tables can be dropped in any
+        // order. SQLite can't drop foreign keys, the kept tables referencing
+        // dropped tables are redefined without them instead.
+        if self.flavour.sql_family() == SqlFamily::Sqlite {
+            let mut next_schema = schema.clone();
+            let mut redefine_tables = Vec::new();
+
+            for table in tables.iter().filter(|table| is_kept(table.name())) {
+                if table.foreign_keys().all(|fk| is_kept(fk.referenced_table().name())) {
+                    continue;
+                }
+
+                next_schema.tables[table.table_index()]
+                    .foreign_keys
+                    .retain(|fk| is_kept(&fk.referenced_table));
+
+                redefine_tables.push(RedefineTable {
+                    added_columns: Vec::new(),
+                    dropped_columns: Vec::new(),
+                    dropped_primary_key: false,
+                    column_pairs: (0..table.columns().count())
+                        .map(|idx| (Pair::new(idx, idx), ColumnChanges::default(), None))
+                        .collect(),
+                    table_index: Pair::new(table.table_index(), table.table_index()),
+                });
+            }
+
+            if !redefine_tables.is_empty() {
+                steps.extend(
+                    self.flavour
+                        .render_redefine_tables(&redefine_tables, &Pair::new(&schema, &next_schema)),
+                );
+            }
+        } else {
+            for fk in tables.iter().flat_map(|table| table.foreign_keys()) {
+                if !is_kept(fk.table().name()) || !is_kept(fk.referenced_table().name()) {
+                    steps.push(self.flavour.render_drop_foreign_key(&fk));
+                }
+            }
+        }
+
+        for table in tables.iter().filter(|table| !is_kept(table.name())) {
+            steps.extend(self.flavour.render_drop_table(table.name()));
+        }
+
+        let kept_enums: HashSet<&str> = tables
+            .iter()
+            .filter(|table| is_kept(table.name()))
+            .flat_map(|table| table.columns())
+            .filter_map(|column| column.column_type_family_as_enum())
+            .map(|enm| enm.name.as_str())
+            .collect();
+
+        for enm in (0..schema.enums.len())
+            .map(|idx| schema.enum_walker_at(idx))
+            .filter(|enm| !kept_enums.contains(enm.name()))
+        {
+            steps.extend(self.flavour.render_drop_enum(&enm));
+        }
+
+        // The sequences owned by columns are dropped with their tables, or
+        // kept with them.
+        let kept_sequences: HashSet<&str> = tables
+            .iter()
+            .filter(|table| is_kept(table.name()))
+            .flat_map(|table| table.columns())
+            .filter_map(|column| match column.default().map(|default| default.kind()) {
+                Some(DefaultKind::SEQUENCE(sequence_name)) => Some(sequence_name.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        for sequence_name in self.flavour.standalone_sequence_names(self.conn()).await? {
+            if !kept_sequences.contains(sequence_name.as_str()) {
+                steps.push(self.flavour.render_drop_sequence(&sequence_name));
+            }
+        }
+
+        for step in steps {
+            self.conn().raw_cmd(&step).await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,8 +334,22 @@ impl MigrationConnector for SqlMigrationConnector {
         Self::create_database(database_str).await
     }
 
-    async fn reset(&self) -> ConnectorResult<()> {
-        self.flavour.reset(self.conn()).await
+    async fn check_permissions(&self, shadow_database: bool) -> ConnectorResult<()> {
+        self.flavour.check_table_permissions(self.conn()).await?;
+
+        if shadow_database {
+            self.flavour.check_shadow_database_permissions(self.conn()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn reset(&self, keep_tables: &[String]) -> ConnectorResult<()> {
+        if keep_tables.is_empty() {
+            return self.flavour.reset(self.conn()).await;
+        }
+
+        self.reset_keeping_tables(keep_tables).await
     }
 
     /// Optionally check that the features implied by the provided datamodel are all compatible with
@@ -145,13 +378,43 @@ impl MigrationConnector for SqlMigrationConnector {
     }
 }
 
+/// The connection string to connect with: a Unix domain socket is rewritten to the form Quaint
+/// expects, see `datasource_url::normalize`, and the `ssh_*` parameters open an SSH tunnel the
+/// connection string then points to, see `ssh_tunnel::open`. The tunnel must be kept alive as long
+/// as the connections.
+fn connection_url(database_str: &str) -> ConnectorResult<(String, Option<SshTunnel>)> {
+    let invalid_url = |details: &str| -> ConnectorError {
+        let details = user_facing_errors::quaint::invalid_url_description(database_str, details);
+        KnownError::new(InvalidDatabaseString { details }).into()
+    };
+
+    let normalized = datasource_url::normalize(database_str).map_err(|err| invalid_url(&err.to_string()))?;
+
+    match ssh_tunnel::open(&normalized) {
+        Ok((url, tunnel)) => Ok((url.into_owned(), tunnel)),
+        Err(TunnelError::InvalidParameters(details)) => Err(invalid_url(&details)),
+        Err(TunnelError::Connection { ssh_host, message }) => {
+            Err(KnownError::new(SshTunnelError { ssh_host, message }).into())
+        }
+    }
+}
+
 async fn connect(database_str: &str) -> ConnectorResult<Connection> {
+    connect_with_retries(database_str, RetryPolicy::default()).await
+}
+
+async fn connect_with_retries(database_str: &str, retry_policy: RetryPolicy) -> ConnectorResult<Connection> {
     let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
         let details = user_facing_errors::quaint::invalid_url_description(database_str, &err.to_string());
         KnownError::new(InvalidDatabaseString { details })
     })?;
 
-    let connection = Quaint::new(database_str)
+    let connection = retry_policy
+        .retry(
+            || Quaint::new(database_str),
+            |err| classify_transient_error(err).map(|transient_error| transient_error.as_str()),
+            tokio::time::delay_for,
+        )
         .await
         .map_err(|err| quaint_error_to_connector_error(err, &connection_info))?;
 
@@ -5,6 +5,7 @@ use crate::{
 use datamodel::*;
 use migration_connector::{
     steps::MigrationStep, ConnectorResult, DatabaseMigrationInferrer, MigrationConnector, MigrationDirectory,
+    RenameHint,
 };
 use sql_schema_describer::*;
 
@@ -17,16 +18,40 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe_schema().await?;
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(
+            next,
+            self.flavour(),
+            self.referential_integrity(),
+            self.foreign_key_indexes(),
+            self.audit_log(),
+            self.database_timestamps(),
+        );
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            &[],
+        ))
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<SqlMigration> {
         let current_database_schema = SqlSchema::empty();
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(
+            next,
+            self.flavour(),
+            self.referential_integrity(),
+            self.foreign_key_indexes(),
+            self.audit_log(),
+            self.database_timestamps(),
+        );
+
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            &[],
+        ))
     }
 
     fn infer_from_datamodels(
@@ -35,10 +60,29 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = sql_schema_calculator::calculate_sql_schema(previous, self.flavour());
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        let current_database_schema: SqlSchema = sql_schema_calculator::calculate_sql_schema(
+            previous,
+            self.flavour(),
+            self.referential_integrity(),
+            self.foreign_key_indexes(),
+            self.audit_log(),
+            self.database_timestamps(),
+        );
+        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(
+            next,
+            self.flavour(),
+            self.referential_integrity(),
+            self.foreign_key_indexes(),
+            self.audit_log(),
+            self.database_timestamps(),
+        );
+
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            &[],
+        ))
     }
 
     #[tracing::instrument(skip(self, previous_migrations, target_schema))]
@@ -46,14 +90,27 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        rename_hints: &[RenameHint],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema = self
             .flavour()
             .sql_schema_from_migration_history(previous_migrations, self.conn())
             .await?;
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour());
-
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(
+            target_schema,
+            self.flavour(),
+            self.referential_integrity(),
+            self.foreign_key_indexes(),
+            self.audit_log(),
+            self.database_timestamps(),
+        );
+
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            rename_hints,
+        ))
     }
 
     #[tracing::instrument(skip(self, applied_migrations))]
@@ -65,7 +122,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 
         let actual_schema = self.describe_schema().await?;
 
-        let steps = sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour());
+        let steps =
+            sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour(), &[]);
 
         if steps.is_empty() {
             return Ok(None);
@@ -100,9 +158,13 @@ fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
     flavour: &dyn SqlFlavour,
+    rename_hints: &[RenameHint],
 ) -> SqlMigration {
-    let steps =
-        sql_schema_differ::calculate_steps(Pair::new(&current_database_schema, &expected_database_schema), flavour);
+    let steps = sql_schema_differ::calculate_steps(
+        Pair::new(&current_database_schema, &expected_database_schema),
+        flavour,
+        rename_hints,
+    );
 
     SqlMigration {
         before: current_database_schema,
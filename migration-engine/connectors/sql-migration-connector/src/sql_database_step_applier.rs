@@ -1,12 +1,13 @@
 use crate::{
     pair::Pair,
-    sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration::{CreateTable, DropTable, RenameTable, SqlMigration, SqlMigrationStep},
     SqlFlavour, SqlMigrationConnector,
 };
 use migration_connector::{
-    ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics,
-    PrettyDatabaseMigrationStep,
+    ConnectorError, ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier,
+    DestructiveChangeDiagnostics, MigrationScriptError, MigrationStepSummary, PrettyDatabaseMigrationStep,
 };
+use quaint::ast::{Insert, Table, Value};
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
 #[async_trait::async_trait]
@@ -42,6 +43,10 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         Ok(steps)
     }
 
+    fn summarize_steps(&self, database_migration: &SqlMigration) -> Vec<MigrationStepSummary> {
+        crate::sql_migration_summary::summarize_steps(database_migration)
+    }
+
     fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
         if database_migration.is_empty() {
             return "-- This is an empty migration.".to_string();
@@ -107,11 +112,198 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     }
 
     async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        Ok(self.conn().raw_cmd(script).await?)
+        for batch in self.flavour().script_batches(script) {
+            self.conn().raw_cmd(batch).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn apply_migration_script(&self, script: &str) -> Result<(), MigrationScriptError> {
+        let batches = self.flavour().script_batches(script);
+        let statements: Vec<&str> = batches
+            .iter()
+            .flat_map(|batch| migration_connector::split_sql_statements(batch))
+            .collect();
+
+        // Scripts that manage their own transactions are applied as they are.
+        if statements
+            .iter()
+            .any(|statement| migration_connector::is_transaction_control(statement))
+        {
+            return self.apply_batches(&batches).await;
+        }
+
+        if !self.flavour().supports_transactional_ddl() {
+            return self.apply_statements_with_savepoints(&statements).await;
+        }
+
+        if statements
+            .iter()
+            .any(|statement| self.flavour().requires_autocommit(statement))
+        {
+            return self.apply_statements(&statements).await;
+        }
+
+        self.conn()
+            .raw_cmd(self.flavour().begin_transaction_statement())
+            .await
+            .map_err(|err| script_error(None, err))?;
+
+        if let Err(mut err) = self.apply_statements(&statements).await {
+            self.conn().raw_cmd("ROLLBACK").await.ok();
+            err.rolled_back = true;
+
+            return Err(err);
+        }
+
+        self.conn()
+            .raw_cmd("COMMIT")
+            .await
+            .map_err(|err| script_error(None, err))
+    }
+
+    async fn insert_seed_row(
+        &self,
+        table: &str,
+        row: &serde_json::Map<String, serde_json::Value>,
+    ) -> ConnectorResult<()> {
+        let schema_name = self.conn().connection_info().schema_name().to_owned();
+        let table = Table::from((schema_name, table.to_owned()));
+
+        let insert = row.iter().fold(Insert::single_into(table), |insert, (column, value)| {
+            insert.value(column.clone(), json_to_quaint_value(value))
+        });
+
+        self.conn().execute(insert).await?;
+
+        Ok(())
+    }
+
+    async fn begin_seed_transaction(&self, statements: &[&str]) -> ConnectorResult<bool> {
+        let flavour = self.flavour();
+
+        if !flavour.supports_transactional_ddl()
+            || statements.iter().any(|statement| {
+                migration_connector::is_transaction_control(statement) || flavour.requires_autocommit(statement)
+            })
+        {
+            return Ok(false);
+        }
+
+        self.conn().raw_cmd(flavour.begin_transaction_statement()).await?;
+
+        Ok(true)
+    }
+
+    async fn end_seed_transaction(&self, commit: bool) -> ConnectorResult<()> {
+        self.conn().raw_cmd(if commit { "COMMIT" } else { "ROLLBACK" }).await?;
+
+        Ok(())
+    }
+}
+
+/// Seed values are bound as parameters. Strings are sent as text, nested objects and arrays as JSON.
+fn json_to_quaint_value(value: &serde_json::Value) -> Value<'static> {
+    match value {
+        serde_json::Value::Null => Value::Text(None),
+        serde_json::Value::Bool(b) => Value::Boolean(Some(*b)),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(Some(i)),
+            None => Value::Double(n.as_f64()),
+        },
+        serde_json::Value::String(s) => Value::text(s.clone()),
+        other => Value::Json(Some(other.clone())),
     }
 }
 
 impl SqlMigrationConnector {
+    /// Sends the statements of a migration script one after the other. The
+    /// error tells which statement failed.
+    async fn apply_statements(&self, statements: &[&str]) -> Result<(), MigrationScriptError> {
+        for (idx, statement) in statements.iter().enumerate() {
+            self.conn()
+                .raw_cmd(statement)
+                .await
+                .map_err(|err| script_error(Some(idx), err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the statements of a migration script one after the other, on
+    /// databases where DDL statements commit implicitly. Consecutive data
+    /// manipulation statements share a transaction, with a savepoint before
+    /// each of them, so a failed statement is rolled back on its own and the
+    /// statements before it stay applied, whatever the storage engine.
+    async fn apply_statements_with_savepoints(&self, statements: &[&str]) -> Result<(), MigrationScriptError> {
+        let mut in_transaction = false;
+
+        for (idx, statement) in statements.iter().enumerate() {
+            let is_data_manipulation = migration_connector::is_data_manipulation(statement);
+
+            let control = match (is_data_manipulation, in_transaction) {
+                (true, false) => Some("START TRANSACTION"),
+                (false, true) => Some("COMMIT"),
+                _ => None,
+            };
+
+            if let Some(control) = control {
+                self.conn()
+                    .raw_cmd(control)
+                    .await
+                    .map_err(|err| script_error(Some(idx), err))?;
+
+                in_transaction = is_data_manipulation;
+            }
+
+            if is_data_manipulation {
+                self.conn()
+                    .raw_cmd("SAVEPOINT prisma_migration_statement")
+                    .await
+                    .map_err(|err| script_error(Some(idx), err))?;
+            }
+
+            if let Err(err) = self.conn().raw_cmd(statement).await {
+                if in_transaction {
+                    self.conn()
+                        .raw_cmd("ROLLBACK TO SAVEPOINT prisma_migration_statement")
+                        .await
+                        .ok();
+                    self.conn().raw_cmd("COMMIT").await.ok();
+                }
+
+                return Err(script_error(Some(idx), err));
+            }
+        }
+
+        if in_transaction {
+            self.conn()
+                .raw_cmd("COMMIT")
+                .await
+                .map_err(|err| script_error(None, err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the batches of a migration script one after the other.
+    async fn apply_batches(&self, batches: &[&str]) -> Result<(), MigrationScriptError> {
+        for (idx, batch) in batches.iter().enumerate() {
+            if let Err(err) = self.conn().raw_cmd(batch).await {
+                let mut err = script_error(None, err);
+
+                if batches.len() > 1 {
+                    err.failed_batch_index = Some(idx);
+                }
+
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -154,11 +346,19 @@ fn render_raw_sql(
         SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
             let table = schemas.next().table_walker_at(*table_index);
 
-            vec![renderer.render_create_table(&table)]
+            let mut statements = vec![renderer.render_create_table(&table)];
+            statements.extend(renderer.render_updated_at_triggers(&table));
+
+            statements
         }
         SqlMigrationStep::DropTable(DropTable { table_index }) => {
             renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
         }
+        SqlMigrationStep::RenameTable(RenameTable { table_index }) => {
+            let tables = schemas.tables(table_index);
+
+            vec![renderer.render_rename_table(tables.previous().name(), tables.next().name())]
+        }
         SqlMigrationStep::RedefineIndex { table, index } => {
             renderer.render_drop_and_recreate_index(schemas.tables(table).indexes(index).as_ref())
         }
@@ -196,3 +396,12 @@ fn render_raw_sql(
         }
     }
 }
+
+fn script_error(failed_statement_index: Option<usize>, err: impl Into<ConnectorError>) -> MigrationScriptError {
+    MigrationScriptError {
+        failed_statement_index,
+        failed_batch_index: None,
+        rolled_back: false,
+        error: err.into(),
+    }
+}
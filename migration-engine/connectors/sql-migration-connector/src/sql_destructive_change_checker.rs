@@ -26,7 +26,7 @@ pub(crate) use destructive_change_checker_flavour::DestructiveChangeCheckerFlavo
 use crate::{
     pair::Pair,
     sql_migration::ColumnTypeChange,
-    sql_migration::{AlterEnum, AlterTable, CreateIndex, DropTable, SqlMigrationStep, TableChange},
+    sql_migration::{AlterEnum, AlterTable, CreateIndex, CreateTable, DropTable, SqlMigrationStep, TableChange},
     SqlMigration, SqlMigrationConnector,
 };
 use destructive_check_plan::DestructiveCheckPlan;
@@ -106,7 +106,8 @@ impl SqlMigrationConnector {
                             TableChange::AddColumn(ref add_column) => {
                                 let column = tables.next().column_at(add_column.column_index);
 
-                                self.check_add_column(&column, &mut plan, step_index)
+                                self.check_add_column(&column, &mut plan, step_index);
+                                self.flavour().check_created_column(&column, &mut plan, step_index);
                             }
                             TableChange::DropPrimaryKey { .. } => plan.push_warning(
                                 SqlMigrationWarningCheck::PrimaryKeyChange {
@@ -120,7 +121,7 @@ impl SqlMigrationConnector {
                                 self.flavour
                                     .check_drop_and_recreate_column(&columns, changes, &mut plan, step_index)
                             }
-                            TableChange::AddPrimaryKey { .. } => (),
+                            TableChange::AddPrimaryKey { .. } | TableChange::SetTableOptions { .. } => (),
                         }
                     }
                 }
@@ -220,6 +221,11 @@ impl SqlMigrationConnector {
                         }
                     }
                 }
+                SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
+                    for column in schemas.next().table_walker_at(*table_index).columns() {
+                        self.flavour().check_created_column(&column, &mut plan, step_index);
+                    }
+                }
                 SqlMigrationStep::DropTable(DropTable { table_index }) => {
                     self.check_table_drop(
                         schemas.previous().table_walker_at(*table_index).name(),
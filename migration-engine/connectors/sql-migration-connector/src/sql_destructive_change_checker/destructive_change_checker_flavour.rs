@@ -19,6 +19,9 @@ pub(crate) trait DestructiveChangeCheckerFlavour {
         step_index: usize,
     );
 
+    /// Check a column that is created, either on its own or as part of a new table.
+    fn check_created_column(&self, _column: &ColumnWalker<'_>, _plan: &mut DestructiveCheckPlan, _step_index: usize) {}
+
     /// Check a DropAndRecreateColumn step.
     fn check_drop_and_recreate_column(
         &self,
@@ -9,7 +9,9 @@ use crate::{
     sql_migration::{AlterColumn, ColumnTypeChange},
     sql_schema_differ::ColumnChanges,
 };
-use sql_schema_describer::walkers::ColumnWalker;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily};
 
 impl DestructiveChangeCheckerFlavour for MysqlFlavour {
     fn check_alter_column(
@@ -30,6 +32,10 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
             return;
         }
 
+        if changes.type_changed() {
+            self.check_created_column(columns.next(), plan, step_index);
+        }
+
         // Otherwise, case by case.
         // Column went from optional to required. This is unexecutable unless the table is
         // empty or the column has no existing NULLs.
@@ -76,6 +82,20 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
         };
     }
 
+    fn check_created_column(&self, column: &ColumnWalker<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
+        if self.supports_fractional_seconds() || !requests_fractional_seconds(column) {
+            return;
+        }
+
+        plan.push_warning(
+            SqlMigrationWarningCheck::FractionalSecondsNotSupported {
+                table: column.table().name().to_owned(),
+                column: column.name().to_owned(),
+            },
+            step_index,
+        );
+    }
+
     fn check_drop_and_recreate_column(
         &self,
         _columns: &Pair<ColumnWalker<'_>>,
@@ -87,6 +107,29 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
     }
 }
 
+/// Whether the column is a DATETIME, TIMESTAMP or TIME column with a non-zero precision. Columns
+/// without an explicit native type default to `DATETIME(3)`.
+fn requests_fractional_seconds(column: &ColumnWalker<'_>) -> bool {
+    static TEMPORAL_PRECISION_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)^(?:datetime|timestamp|time)\((\d+)\)").unwrap());
+
+    if !matches!(column.column_type_family(), ColumnTypeFamily::DateTime) {
+        return false;
+    }
+
+    let full_data_type = &column.column_type().full_data_type;
+
+    if full_data_type.is_empty() {
+        return true;
+    }
+
+    TEMPORAL_PRECISION_RE
+        .captures(full_data_type)
+        .and_then(|captures| captures.get(1))
+        .map(|precision| precision.as_str() != "0")
+        .unwrap_or(false)
+}
+
 /// If the type change is an enum change, diagnose it, and return whether it _was_ an enum change.
 fn is_safe_enum_change(columns: &Pair<ColumnWalker<'_>>, plan: &mut DestructiveCheckPlan, step_index: usize) -> bool {
     if let (Some(previous_enum), Some(next_enum)) = (
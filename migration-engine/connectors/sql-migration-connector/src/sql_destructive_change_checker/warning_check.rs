@@ -36,6 +36,10 @@ pub(super) enum SqlMigrationWarningCheck {
         enm: String,
         values: Vec<String>,
     },
+    FractionalSecondsNotSupported {
+        table: String,
+        column: String,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -104,6 +108,7 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::FractionalSecondsNotSupported { table, column } => Some(format!("The `{column}` column on the `{table}` table requests sub-second precision, but the database server does not support fractional seconds (MySQL < 5.6.4). The column will be created with second precision.", column = column, table = table)),
 
         }
     }
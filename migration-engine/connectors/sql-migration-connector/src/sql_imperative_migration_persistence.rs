@@ -1,6 +1,7 @@
 use crate::{error::quaint_error_to_connector_error, SqlMigrationConnector};
 use migration_connector::{
     ConnectorError, ConnectorResult, ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError,
+    SEED_MIGRATION_NAME,
 };
 use quaint::{ast::*, error::ErrorKind as QuaintKind};
 use uuid::Uuid;
@@ -112,6 +113,22 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn record_failed_statement(
+        &self,
+        id: &str,
+        failed_statement_index: usize,
+        logs: &str,
+    ) -> ConnectorResult<()> {
+        let update = Update::table(self.flavour().imperative_migrations_table())
+            .so_that(Column::from("id").equals(id))
+            .set("applied_steps_count", failed_statement_index as i64)
+            .set("logs", logs);
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()> {
         let update = Update::table(self.flavour().imperative_migrations_table())
             .so_that(Column::from("id").equals(id))
@@ -124,6 +141,22 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
 
     #[tracing::instrument(skip(self))]
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
+        self.list_migration_records(Column::from("migration_name").not_equals(SEED_MIGRATION_NAME))
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_seeds(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
+        self.list_migration_records(Column::from("migration_name").equals(SEED_MIGRATION_NAME))
+            .await
+    }
+}
+
+impl SqlMigrationConnector {
+    async fn list_migration_records(
+        &self,
+        condition: impl Into<ConditionTree<'static>>,
+    ) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
         let select = Select::from_table(self.flavour().imperative_migrations_table())
             .column("id")
             .column("checksum")
@@ -133,6 +166,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .column("rolled_back_at")
             .column("started_at")
             .column("applied_steps_count")
+            .so_that(condition)
             .order_by("started_at".ascend());
 
         let result = match self.conn().query(select).await {
@@ -146,7 +180,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         let rows = quaint::serde::from_rows(result)
             .map_err(|err| quaint_error_to_connector_error(err, self.connection.connection_info()))?;
 
-        tracing::debug!("Found {} migrations in the migrations table.", rows.len());
+        tracing::debug!("Found {} records in the migrations table.", rows.len());
 
         Ok(Ok(rows))
     }
@@ -32,6 +32,7 @@ pub(crate) enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
+    RenameTable(RenameTable),
     RedefineIndex { table: Pair<usize>, index: Pair<usize> },
     RedefineTables(Vec<RedefineTable>),
     CreateIndex(CreateIndex),
@@ -65,6 +66,7 @@ impl SqlMigrationStep {
             SqlMigrationStep::RedefineIndex { .. } => "RedefineIndex",
             SqlMigrationStep::DropForeignKey(_) => "DropForeignKey",
             SqlMigrationStep::DropTable(_) => "DropTable",
+            SqlMigrationStep::RenameTable(_) => "RenameTable",
             SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
             SqlMigrationStep::CreateIndex(_) => "CreateIndex",
             SqlMigrationStep::DropIndex(_) => "DropIndex",
@@ -86,6 +88,12 @@ pub(crate) struct DropTable {
     pub table_index: usize,
 }
 
+#[derive(Debug)]
+pub(crate) struct RenameTable {
+    /// Index in (previous_schema, next_schema).
+    pub table_index: Pair<usize>,
+}
+
 #[derive(Debug)]
 pub(crate) struct AlterTable {
     /// Index in (previous_schema, next_schema).
@@ -108,6 +116,11 @@ pub(crate) enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    /// Change the storage engine and/or the row format of the table. Only used on MySQL.
+    SetTableOptions {
+        engine: Option<String>,
+        row_format: Option<String>,
+    },
 }
 
 #[derive(Debug)]
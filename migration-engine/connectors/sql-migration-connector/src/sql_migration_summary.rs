@@ -0,0 +1,238 @@
+use crate::{
+    pair::Pair,
+    sql_migration::{AlterTable, RedefineTable, SqlMigration, SqlMigrationStep, TableChange},
+    sql_schema_differ::{ColumnChange, ColumnChanges},
+};
+use migration_connector::MigrationStepSummary;
+use sql_schema_describer::{
+    walkers::{ForeignKeyWalker, SqlSchemaExt, TableWalker},
+    SqlSchema,
+};
+
+/// Summarize the steps of the migration, see `MigrationSummary`.
+pub(crate) fn summarize_steps(migration: &SqlMigration) -> Vec<MigrationStepSummary> {
+    let schemas = migration.schemas();
+    let mut summary = Vec::with_capacity(migration.steps.len());
+
+    for step in &migration.steps {
+        summarize_step(step, &schemas, &mut summary);
+    }
+
+    summary
+}
+
+fn summarize_step(step: &SqlMigrationStep, schemas: &Pair<&SqlSchema>, summary: &mut Vec<MigrationStepSummary>) {
+    match step {
+        SqlMigrationStep::CreateTable(create_table) => summary.push(MigrationStepSummary::CreatedTable {
+            table: schemas
+                .next()
+                .table_walker_at(create_table.table_index)
+                .name()
+                .to_owned(),
+        }),
+        SqlMigrationStep::DropTable(drop_table) => summary.push(MigrationStepSummary::DroppedTable {
+            table: schemas
+                .previous()
+                .table_walker_at(drop_table.table_index)
+                .name()
+                .to_owned(),
+        }),
+        SqlMigrationStep::RenameTable(rename_table) => {
+            let tables = schemas.tables(&rename_table.table_index);
+
+            summary.push(MigrationStepSummary::RenamedTable {
+                from: tables.previous().name().to_owned(),
+                to: tables.next().name().to_owned(),
+            })
+        }
+        SqlMigrationStep::AlterTable(alter_table) => summarize_alter_table(alter_table, schemas, summary),
+        SqlMigrationStep::RedefineTables(redefine_tables) => {
+            for redefine_table in redefine_tables {
+                summarize_redefine_table(redefine_table, schemas, summary);
+            }
+        }
+        SqlMigrationStep::CreateIndex(create_index) => {
+            let index = schemas
+                .next()
+                .table_walker_at(create_index.table_index)
+                .index_at(create_index.index_index);
+
+            summary.push(MigrationStepSummary::CreatedIndex {
+                table: index.table().name().to_owned(),
+                index: index.name().to_owned(),
+            })
+        }
+        SqlMigrationStep::DropIndex(drop_index) => {
+            let index = schemas
+                .previous()
+                .table_walker_at(drop_index.table_index)
+                .index_at(drop_index.index_index);
+
+            summary.push(MigrationStepSummary::DroppedIndex {
+                table: index.table().name().to_owned(),
+                index: index.name().to_owned(),
+            })
+        }
+        SqlMigrationStep::AlterIndex { table, index } | SqlMigrationStep::RedefineIndex { table, index } => {
+            let tables = schemas.tables(table);
+            let indexes = tables.indexes(index);
+
+            summary.push(MigrationStepSummary::AlteredIndex {
+                table: tables.next().name().to_owned(),
+                index: indexes.next().name().to_owned(),
+            })
+        }
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => {
+            let foreign_key = schemas
+                .next()
+                .table_walker_at(add_foreign_key.table_index)
+                .foreign_key_at(add_foreign_key.foreign_key_index);
+
+            let (table, referenced_table, columns) = foreign_key_summary(&foreign_key);
+
+            summary.push(MigrationStepSummary::AddedForeignKey {
+                table,
+                referenced_table,
+                columns,
+            })
+        }
+        SqlMigrationStep::DropForeignKey(drop_foreign_key) => {
+            let foreign_key = schemas
+                .previous()
+                .table_walker_at(drop_foreign_key.table_index)
+                .foreign_key_at(drop_foreign_key.foreign_key_index);
+
+            let (table, referenced_table, columns) = foreign_key_summary(&foreign_key);
+
+            summary.push(MigrationStepSummary::DroppedForeignKey {
+                table,
+                referenced_table,
+                columns,
+            })
+        }
+        SqlMigrationStep::CreateEnum(create_enum) => summary.push(MigrationStepSummary::CreatedEnum {
+            name: schemas.next().enum_walker_at(create_enum.enum_index).name().to_owned(),
+        }),
+        SqlMigrationStep::DropEnum(drop_enum) => summary.push(MigrationStepSummary::DroppedEnum {
+            name: schemas
+                .previous()
+                .enum_walker_at(drop_enum.enum_index)
+                .name()
+                .to_owned(),
+        }),
+        SqlMigrationStep::AlterEnum(alter_enum) => summary.push(MigrationStepSummary::AlteredEnum {
+            name: schemas.enums(&alter_enum.index).next().name().to_owned(),
+            added_values: alter_enum.created_variants.clone(),
+            dropped_values: alter_enum.dropped_variants.clone(),
+        }),
+    }
+}
+
+fn summarize_alter_table(
+    alter_table: &AlterTable,
+    schemas: &Pair<&SqlSchema>,
+    summary: &mut Vec<MigrationStepSummary>,
+) {
+    let tables = schemas.tables(&alter_table.table_index);
+    let table = tables.next().name();
+
+    for change in &alter_table.changes {
+        let change = match change {
+            TableChange::AddColumn(add_column) => MigrationStepSummary::AddedColumn {
+                table: table.to_owned(),
+                column: tables.next().column_at(add_column.column_index).name().to_owned(),
+            },
+            TableChange::DropColumn(drop_column) => MigrationStepSummary::DroppedColumn {
+                table: table.to_owned(),
+                column: tables.previous().column_at(drop_column.index).name().to_owned(),
+            },
+            TableChange::AlterColumn(alter_column) => {
+                altered_column(&tables, &alter_column.column_index, &alter_column.changes, false)
+            }
+            TableChange::DropAndRecreateColumn { column_index, changes } => {
+                altered_column(&tables, column_index, changes, true)
+            }
+            TableChange::AddPrimaryKey { columns } => MigrationStepSummary::AddedPrimaryKey {
+                table: table.to_owned(),
+                columns: columns.clone(),
+            },
+            TableChange::DropPrimaryKey => MigrationStepSummary::DroppedPrimaryKey {
+                table: tables.previous().name().to_owned(),
+            },
+            TableChange::SetTableOptions { .. } => MigrationStepSummary::AlteredTableOptions {
+                table: table.to_owned(),
+            },
+        };
+
+        summary.push(change);
+    }
+}
+
+fn summarize_redefine_table(
+    redefine_table: &RedefineTable,
+    schemas: &Pair<&SqlSchema>,
+    summary: &mut Vec<MigrationStepSummary>,
+) {
+    let tables = schemas.tables(&redefine_table.table_index);
+    let table = tables.next().name();
+
+    summary.push(MigrationStepSummary::RedefinedTable {
+        table: table.to_owned(),
+    });
+
+    for column_index in &redefine_table.added_columns {
+        summary.push(MigrationStepSummary::AddedColumn {
+            table: table.to_owned(),
+            column: tables.next().column_at(*column_index).name().to_owned(),
+        });
+    }
+
+    for column_index in &redefine_table.dropped_columns {
+        summary.push(MigrationStepSummary::DroppedColumn {
+            table: table.to_owned(),
+            column: tables.previous().column_at(*column_index).name().to_owned(),
+        });
+    }
+
+    for (column_index, changes, _) in &redefine_table.column_pairs {
+        if changes.differs_in_something() {
+            summary.push(altered_column(&tables, column_index, changes, false));
+        }
+    }
+}
+
+fn altered_column(
+    tables: &Pair<TableWalker<'_>>,
+    column_index: &Pair<usize>,
+    changes: &ColumnChanges,
+    recreated: bool,
+) -> MigrationStepSummary {
+    let columns = tables.columns(column_index);
+
+    MigrationStepSummary::AlteredColumn {
+        table: tables.next().name().to_owned(),
+        column: columns.next().name().to_owned(),
+        changes: changes
+            .iter()
+            .map(|change| {
+                match change {
+                    ColumnChange::TypeChanged => "type",
+                    ColumnChange::Arity => "arity",
+                    ColumnChange::Default => "default",
+                    ColumnChange::Renaming => "name",
+                    ColumnChange::Sequence => "sequence",
+                }
+                .to_owned()
+            })
+            .collect(),
+        recreated,
+    }
+}
+
+fn foreign_key_summary(foreign_key: &ForeignKeyWalker<'_>) -> (String, String, Vec<String>) {
+    (
+        foreign_key.table().name().to_owned(),
+        foreign_key.referenced_table().name().to_owned(),
+        foreign_key.constrained_column_names().to_vec(),
+    )
+}
@@ -54,6 +54,12 @@ pub(crate) trait SqlRenderer {
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render the triggers setting the `@updatedAt` columns of a new table, on databases where
+    /// `DefaultValue::on_update` can't be expressed in the column definition.
+    fn render_updated_at_triggers(&self, _table: &TableWalker<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Render a table creation step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -75,11 +81,21 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render the dropping of a sequence, which may have been dropped with its table already.
+    fn render_drop_sequence(&self, sequence_name: &str) -> String {
+        format!("DROP SEQUENCE IF EXISTS {}", self.quote(sequence_name))
+    }
+
     /// Render a `DropTable` step.
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
+    /// Render the dropping of a view.
+    fn render_drop_view(&self, view_name: &str) -> String {
+        format!("DROP VIEW {}", self.quote(view_name))
+    }
+
     /// Render a `RedefineTables` step.
     fn render_redefine_tables(&self, tables: &[RedefineTable], schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
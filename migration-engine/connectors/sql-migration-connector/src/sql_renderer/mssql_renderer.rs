@@ -3,7 +3,7 @@ mod alter_table;
 use super::common::render_on_delete;
 use super::{common, IteratorJoin, Quoted, SqlRenderer};
 use crate::{
-    flavour::MssqlFlavour,
+    flavour::{truncate_identifier, MssqlFlavour, MSSQL_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::{AlterEnum, AlterTable, RedefineTable},
 };
@@ -19,7 +19,7 @@ use std::{
 };
 
 #[derive(Debug)]
-struct QuotedWithSchema<'a> {
+pub(crate) struct QuotedWithSchema<'a> {
     schema_name: &'a str,
     name: &'a str,
 }
@@ -31,7 +31,7 @@ impl<'a> Display for QuotedWithSchema<'a> {
 }
 
 impl MssqlFlavour {
-    fn quote_with_schema<'a>(&'a self, name: &'a str) -> QuotedWithSchema<'a> {
+    pub(crate) fn quote_with_schema<'a>(&'a self, name: &'a str) -> QuotedWithSchema<'a> {
         QuotedWithSchema {
             schema_name: self.schema_name(),
             name,
@@ -123,14 +123,14 @@ impl SqlRenderer for MssqlFlavour {
         let index_with_table = format!(
             "{}.{}.{}",
             self.schema_name(),
-            indexes.previous().table().name(),
+            indexes.next().table().name(),
             indexes.previous().name()
         );
 
         vec![format!(
             "EXEC SP_RENAME N'{index_with_table}', N'{index_new_name}', N'INDEX'",
             index_with_table = index_with_table,
-            index_new_name = indexes.next().name(),
+            index_new_name = truncate_identifier(indexes.next().name(), MSSQL_IDENTIFIER_SIZE_LIMIT),
         )]
     }
 
@@ -144,15 +144,24 @@ impl SqlRenderer for MssqlFlavour {
             IndexType::Normal => "",
         };
 
+        // Indexes are nonclustered by default.
+        let clustering = if index.clustered() == Some(true) {
+            "CLUSTERED "
+        } else {
+            ""
+        };
+
         let index_name = index.name().replace('.', "_");
+        let index_name = truncate_identifier(&index_name, MSSQL_IDENTIFIER_SIZE_LIMIT);
         let index_name = self.quote(&index_name);
         let table_reference = self.quote_with_schema(index.table().name()).to_string();
 
         let columns = index.columns().map(|c| self.quote(c.name()));
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}{clustering}INDEX {index_name} ON {table_reference}({columns})",
             index_type = index_type,
+            clustering = clustering,
             index_name = index_name,
             table_reference = table_reference,
             columns = columns.join(", "),
@@ -172,8 +181,9 @@ impl SqlRenderer for MssqlFlavour {
             let column_names = primary_columns.iter().map(|col| self.quote(&col)).join(",");
 
             format!(
-                ",\n    CONSTRAINT {} PRIMARY KEY ({})",
+                ",\n    CONSTRAINT {} PRIMARY KEY {}({})",
                 self.quote(&index_name),
+                render_primary_key_clustering(table.primary_key().and_then(|pk| pk.clustered)),
                 column_names
             )
         } else {
@@ -190,9 +200,21 @@ impl SqlRenderer for MssqlFlavour {
                 .iter()
                 .map(|index| {
                     let name = index.name().replace('.', "_");
+                    let name = truncate_identifier(&name, MSSQL_IDENTIFIER_SIZE_LIMIT);
                     let columns = index.columns().map(|col| self.quote(col.name()));
 
-                    format!("CONSTRAINT {} UNIQUE ({})", self.quote(&name), columns.join(","))
+                    let clustering = if index.clustered() == Some(true) {
+                        "CLUSTERED "
+                    } else {
+                        ""
+                    };
+
+                    format!(
+                        "CONSTRAINT {} UNIQUE {}({})",
+                        self.quote(&name),
+                        clustering,
+                        columns.join(",")
+                    )
                 })
                 .join(",\n    ");
 
@@ -402,9 +424,26 @@ impl SqlRenderer for MssqlFlavour {
         add_constraint
     }
 
+    fn render_drop_sequence(&self, sequence_name: &str) -> String {
+        format!("DROP SEQUENCE IF EXISTS {}", self.quote_with_schema(sequence_name))
+    }
+
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
     }
+
+    fn render_drop_view(&self, view_name: &str) -> String {
+        format!("DROP VIEW {}", self.quote_with_schema(view_name))
+    }
+}
+
+/// Primary keys are clustered by default, unless another index of the table is clustered.
+fn render_primary_key_clustering(clustered: Option<bool>) -> &'static str {
+    if clustered == Some(false) {
+        "NONCLUSTERED "
+    } else {
+        ""
+    }
 }
 
 fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
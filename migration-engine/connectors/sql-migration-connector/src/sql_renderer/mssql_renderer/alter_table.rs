@@ -26,6 +26,7 @@ pub(crate) fn create_statements(
         add_constraints: BTreeSet::new(),
         add_columns: Vec::new(),
         drop_columns: Vec::new(),
+        rename_columns: Vec::new(),
         column_mods: Vec::new(),
     };
 
@@ -40,6 +41,7 @@ struct AlterTableConstructor<'a> {
     add_constraints: BTreeSet<String>,
     add_columns: Vec<String>,
     drop_columns: Vec<String>,
+    rename_columns: Vec<String>,
     column_mods: Vec<String>,
 }
 
@@ -69,6 +71,7 @@ impl<'a> AlterTableConstructor<'a> {
                 }) => {
                     self.alter_column(*column_index, &changes);
                 }
+                TableChange::SetTableOptions { .. } => unreachable!("SetTableOptions on Microsoft SQL Server"),
             };
         }
 
@@ -83,6 +86,11 @@ impl<'a> AlterTableConstructor<'a> {
             ));
         }
 
+        // Order matters: the column modifications refer to the columns by their next name.
+        if !self.rename_columns.is_empty() {
+            statements.extend(self.rename_columns)
+        }
+
         if !self.column_mods.is_empty() {
             statements.extend(self.column_mods)
         }
@@ -134,10 +142,14 @@ impl<'a> AlterTableConstructor<'a> {
             quoted_columns.push(format!("{}", self.renderer.quote(colname)));
         }
 
+        let clustered = self.tables.next().primary_key().and_then(|pk| pk.clustered);
+
+        let constraint_name = format!("PK__{}__{}", self.tables.next().name(), non_quoted_columns.join("__"));
+
         self.add_constraints.insert(format!(
-            "CONSTRAINT PK__{}__{} PRIMARY KEY ({})",
-            self.tables.next().name(),
-            non_quoted_columns.join("__"),
+            "CONSTRAINT {} PRIMARY KEY {}({})",
+            self.renderer.quote(&constraint_name),
+            super::render_primary_key_clustering(clustered),
             quoted_columns.join(","),
         ));
     }
@@ -164,8 +176,32 @@ impl<'a> AlterTableConstructor<'a> {
         self.add_columns.push(self.renderer.render_column(columns.next()));
     }
 
+    fn rename_column(&mut self, columns: &Pair<ColumnWalker<'_>>) {
+        let column_with_table = format!(
+            "{}.{}.{}",
+            self.renderer.schema_name(),
+            self.tables.previous().name(),
+            columns.previous().name()
+        );
+
+        self.rename_columns.push(format!(
+            "EXEC SP_RENAME N'{column_with_table}', N'{column_new_name}', N'COLUMN'",
+            column_with_table = column_with_table,
+            column_new_name = columns.next().name(),
+        ));
+    }
+
     fn alter_column(&mut self, columns: Pair<usize>, changes: &ColumnChanges) {
         let columns = self.tables.columns(&columns);
+
+        if changes.column_was_renamed() {
+            self.rename_column(&columns);
+
+            if changes.only_renamed() {
+                return;
+            }
+        }
+
         let expanded = expand_alter_column(&columns, changes);
 
         for alter in expanded.into_iter() {
@@ -238,10 +274,12 @@ impl<'a> AlterTableConstructor<'a> {
                                 .map(|c| format!("{}", c))
                                 .collect::<Vec<_>>();
 
+                            let constraint_name =
+                                format!("PK__{}__{}", self.tables.next().name(), non_quoted_columns.join("__"));
+
                             self.add_constraints.insert(format!(
-                                "CONSTRAINT PK__{}__{} UNIQUE ({})",
-                                self.tables.next().name(),
-                                non_quoted_columns.join("__"),
+                                "CONSTRAINT {} UNIQUE ({})",
+                                self.renderer.quote(&constraint_name),
                                 quoted_columns.join(","),
                             ));
                         }
@@ -4,7 +4,7 @@ use super::{
     IteratorJoin, SqlRenderer,
 };
 use crate::{
-    flavour::{MysqlFlavour, SqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
+    flavour::{truncate_identifier, MysqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::{AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, TableChange},
     sql_schema_differ::ColumnChanges,
@@ -68,10 +68,10 @@ impl SqlRenderer for MysqlFlavour {
 
     fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
         vec![ddl::AlterTable {
-            table_name: indexes.previous().table().name().into(),
+            table_name: indexes.next().table().name().into(),
             changes: vec![sql_ddl::mysql::AlterTableClause::RenameIndex {
                 previous_name: indexes.previous().name().into(),
-                next_name: indexes.next().name().into(),
+                next_name: truncate_identifier(indexes.next().name(), MYSQL_IDENTIFIER_SIZE_LIMIT),
             }],
         }
         .to_string()]
@@ -116,15 +116,21 @@ impl SqlRenderer for MysqlFlavour {
                             "ALTER COLUMN {column} DROP DEFAULT",
                             column = Quoted::mysql_ident(columns.previous().name())
                         )),
-                        MysqlAlterColumn::Modify { new_default, changes } => lines.push(render_mysql_modify(
-                            &changes,
-                            new_default.as_ref(),
-                            columns.next(),
-                            self,
-                        )),
+                        MysqlAlterColumn::Modify { new_default, changes } => {
+                            lines.push(render_mysql_modify(&changes, new_default.as_ref(), &columns, self))
+                        }
                     };
                 }
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on MySQL"),
+                TableChange::SetTableOptions { engine, row_format } => {
+                    if let Some(engine) = engine {
+                        lines.push(format!("ENGINE = {}", engine));
+                    }
+
+                    if let Some(row_format) = row_format {
+                        lines.push(format!("ROW_FORMAT = {}", row_format));
+                    }
+                }
             };
         }
 
@@ -141,7 +147,7 @@ impl SqlRenderer for MysqlFlavour {
 
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
-        let tpe_str = render_column_type(&column);
+        let tpe_str = render_column_type(&column, self.supports_fractional_seconds());
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
@@ -154,8 +160,9 @@ impl SqlRenderer for MysqlFlavour {
             })
             .map(|default| {
                 format!(
-                    " DEFAULT {}",
-                    self.render_default(default, &column.column_type_family())
+                    " DEFAULT {}{}",
+                    self.render_default(default, &column.column_type_family()),
+                    render_on_update(default, &column.column_type_family(), self),
                 )
             })
             .unwrap_or_else(String::new);
@@ -200,7 +207,10 @@ impl SqlRenderer for MysqlFlavour {
             | (DefaultKind::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_string_literal(&val)).into()
             }
-            (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP(3)".into(),
+            (DefaultKind::NOW, ColumnTypeFamily::DateTime) if self.supports_fractional_seconds() => {
+                "CURRENT_TIMESTAMP(3)".into()
+            }
+            (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(val), _) => format!("{}", val).into(),
@@ -213,16 +223,9 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
-        let name = index.name();
-        let name = if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-            &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-        } else {
-            &name
-        };
-
         ddl::CreateIndex {
             unique: index.index_type().is_unique(),
-            index_name: name.into(),
+            index_name: truncate_identifier(index.name(), MYSQL_IDENTIFIER_SIZE_LIMIT),
             on: (
                 index.table().name().into(),
                 index.columns().map(|c| c.name().into()).collect(),
@@ -248,11 +251,7 @@ impl SqlRenderer for MysqlFlavour {
                 .indexes()
                 .map(|index| {
                     let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
-                    let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-                        &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-                    } else {
-                        &index.name()
-                    };
+                    let index_name = truncate_identifier(index.name(), MYSQL_IDENTIFIER_SIZE_LIMIT);
 
                     format!(
                         "{}INDEX {}({})",
@@ -268,12 +267,24 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let engine = table
+            .engine()
+            .map(|engine| format!(" ENGINE = {}", engine))
+            .unwrap_or_default();
+
+        let row_format = table
+            .row_format()
+            .map(|row_format| format!(" ROW_FORMAT = {}", row_format))
+            .unwrap_or_default();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci{engine}{row_format}",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            engine = engine,
+            row_format = row_format,
         )
     }
 
@@ -283,7 +294,7 @@ impl SqlRenderer for MysqlFlavour {
             self.render_create_index(indexes.next()),
             sql_ddl::mysql::DropIndex {
                 index_name: indexes.previous().name().into(),
-                table_name: indexes.previous().table().name().into(),
+                table_name: indexes.next().table().name().into(),
             }
             .to_string(),
         ]
@@ -338,9 +349,10 @@ impl SqlRenderer for MysqlFlavour {
 fn render_mysql_modify(
     changes: &ColumnChanges,
     new_default: Option<&sql_schema_describer::DefaultValue>,
-    next_column: &ColumnWalker<'_>,
-    renderer: &dyn SqlFlavour,
+    columns: &Pair<ColumnWalker<'_>>,
+    renderer: &MysqlFlavour,
 ) -> String {
+    let next_column = columns.next();
     let column_type: Option<String> = if changes.type_changed() {
         Some(next_column.column_type().full_data_type.clone()).filter(|r| !r.is_empty() || r.contains("datetime"))
     // @default(now()) does not work with datetimes of certain sizes
@@ -348,18 +360,29 @@ fn render_mysql_modify(
         Some(next_column.column_type().full_data_type.clone()).filter(|r| !r.is_empty())
     };
 
-    let column_type = column_type
-        .map(Cow::Owned)
-        .unwrap_or_else(|| render_column_type(&next_column));
+    let column_type = match column_type {
+        Some(tpe) if renderer.supports_fractional_seconds() => Cow::Owned(tpe),
+        Some(tpe) => Cow::Owned(strip_temporal_precision(&tpe).into_owned()),
+        None => render_column_type(&next_column, renderer.supports_fractional_seconds()),
+    };
 
+    let family = &next_column.column_type().family;
     let default = new_default
-        .map(|default| renderer.render_default(&default, &next_column.column_type().family))
-        .filter(|expr| !expr.is_empty())
-        .map(|expression| format!(" DEFAULT {}", expression))
+        .map(|default| (renderer.render_default(default, family), default))
+        .filter(|(expr, _)| !expr.is_empty())
+        .map(|(expression, default)| format!(" DEFAULT {}{}", expression, render_on_update(default, family, renderer)))
         .unwrap_or_else(String::new);
 
+    // CHANGE is MODIFY with a renaming, and works on MySQL versions without RENAME COLUMN.
+    let modify = if changes.column_was_renamed() {
+        format!("CHANGE {}", Quoted::mysql_ident(columns.previous().name()))
+    } else {
+        "MODIFY".to_owned()
+    };
+
     format!(
-        "MODIFY {column_name} {column_type}{nullability}{default}{sequence}",
+        "{modify} {column_name} {column_type}{nullability}{default}{sequence}",
+        modify = modify,
         column_name = Quoted::mysql_ident(&next_column.name()),
         column_type = column_type,
         nullability = if next_column.arity().is_required() {
@@ -376,14 +399,30 @@ fn render_mysql_modify(
     )
 }
 
-fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
-    if !column.column_type().full_data_type.is_empty() {
-        return column.column_type().full_data_type.clone().into();
+/// The `ON UPDATE` clause of the timestamps maintained by the database with `databaseTimestamps`.
+fn render_on_update(default: &DefaultValue, family: &ColumnTypeFamily, renderer: &MysqlFlavour) -> String {
+    if default.on_update() {
+        format!(" ON UPDATE {}", renderer.render_default(default, family))
+    } else {
+        String::new()
+    }
+}
+
+fn render_column_type(column: &ColumnWalker<'_>, supports_fractional_seconds: bool) -> Cow<'static, str> {
+    let full_data_type = &column.column_type().full_data_type;
+
+    if !full_data_type.is_empty() {
+        return if supports_fractional_seconds {
+            full_data_type.clone().into()
+        } else {
+            strip_temporal_precision(full_data_type).into_owned().into()
+        };
     }
 
     match &column.column_type().family {
         ColumnTypeFamily::Boolean => "BOOLEAN".into(),
-        ColumnTypeFamily::DateTime => "DATETIME(3)".into(),
+        ColumnTypeFamily::DateTime if supports_fractional_seconds => "DATETIME(3)".into(),
+        ColumnTypeFamily::DateTime => "DATETIME".into(),
         ColumnTypeFamily::Float => "DECIMAL(65,30)".into(),
         ColumnTypeFamily::Decimal => "DECIMAL(65,30)".into(),
         ColumnTypeFamily::Int => "INT".into(),
@@ -408,6 +447,15 @@ fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     }
 }
 
+/// Servers without fractional seconds support reject precision arguments on temporal types, so
+/// `DATETIME(3)` is rendered as `DATETIME` there.
+fn strip_temporal_precision(data_type: &str) -> Cow<'_, str> {
+    static TEMPORAL_PRECISION_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)^(datetime|timestamp|time)\(\d+\)").unwrap());
+
+    TEMPORAL_PRECISION_RE.replace(data_type, "$1")
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -429,12 +477,11 @@ enum MysqlAlterColumn {
 
 impl MysqlAlterColumn {
     fn new(columns: &Pair<ColumnWalker<'_>>, changes: &ColumnChanges) -> Self {
-        if changes.only_default_changed() && columns.next().default().is_none() {
-            return MysqlAlterColumn::DropDefault;
-        }
+        // DROP DEFAULT leaves the `ON UPDATE` clause of the column in place.
+        let previous_on_update = columns.previous().default().map(|d| d.on_update()).unwrap_or(false);
 
-        if changes.column_was_renamed() {
-            unreachable!("MySQL column renaming.")
+        if changes.only_default_changed() && columns.next().default().is_none() && !previous_on_update {
+            return MysqlAlterColumn::DropDefault;
         }
 
         let defaults = (
@@ -459,3 +506,20 @@ impl MysqlAlterColumn {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_temporal_precision_only_strips_temporal_types() {
+        assert_eq!(strip_temporal_precision("DATETIME(3)"), "DATETIME");
+        assert_eq!(strip_temporal_precision("timestamp(6)"), "timestamp");
+        assert_eq!(strip_temporal_precision("TIME(2)"), "TIME");
+
+        assert_eq!(strip_temporal_precision("DATETIME"), "DATETIME");
+        assert_eq!(strip_temporal_precision("VARCHAR(3)"), "VARCHAR(3)");
+        assert_eq!(strip_temporal_precision("DECIMAL(65,30)"), "DECIMAL(65,30)");
+        assert_eq!(strip_temporal_precision("TINYINT(1)"), "TINYINT(1)");
+    }
+}
@@ -1,6 +1,6 @@
 use super::{common::*, SqlRenderer};
 use crate::{
-    flavour::PostgresFlavour,
+    flavour::{truncate_identifier, PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::{AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, TableChange},
     sql_schema_differ::{ColumnChange, ColumnChanges},
@@ -8,8 +8,8 @@ use crate::{
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
-use sql_ddl::postgres::{CreateEnum, CreateIndex};
-use sql_schema_describer::{walkers::*, *};
+use sql_ddl::postgres::{self as ddl, CreateEnum, CreateIndex};
+use sql_schema_describer::{postgres::UPDATED_AT_TRIGGER_PREFIX, walkers::*, *};
 use std::borrow::Cow;
 
 impl SqlRenderer for PostgresFlavour {
@@ -133,7 +133,10 @@ impl SqlRenderer for PostgresFlavour {
         vec![format!(
             "ALTER INDEX {} RENAME TO {}",
             self.quote(indexes.previous().name()),
-            self.quote(indexes.next().name())
+            self.quote(&truncate_identifier(
+                indexes.next().name(),
+                POSTGRES_IDENTIFIER_SIZE_LIMIT
+            ))
         )]
     }
 
@@ -167,10 +170,22 @@ impl SqlRenderer for PostgresFlavour {
                     let col_sql = self.render_column(&column);
 
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if has_updated_at_trigger(&column) {
+                        after_statements.extend(render_create_updated_at_trigger(
+                            tables.previous().name(),
+                            column.name(),
+                        ));
+                    }
                 }
                 TableChange::DropColumn(DropColumn { index }) => {
-                    let name = self.quote(tables.previous().column_at(*index).name());
+                    let column = tables.previous().column_at(*index);
+                    let name = self.quote(column.name());
                     lines.push(format!("DROP COLUMN {}", name));
+
+                    if has_updated_at_trigger(&column) {
+                        after_statements.push(render_drop_updated_at_trigger(tables.previous().name(), column.name()));
+                    }
                 }
                 TableChange::AlterColumn(AlterColumn {
                     column_index,
@@ -199,23 +214,37 @@ impl SqlRenderer for PostgresFlavour {
 
                     let col_sql = self.render_column(columns.next());
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if has_updated_at_trigger(columns.previous()) {
+                        after_statements.push(render_drop_updated_at_trigger(
+                            tables.previous().name(),
+                            columns.previous().name(),
+                        ));
+                    }
+
+                    if has_updated_at_trigger(columns.next()) {
+                        after_statements.extend(render_create_updated_at_trigger(
+                            tables.previous().name(),
+                            columns.next().name(),
+                        ));
+                    }
                 }
+                TableChange::SetTableOptions { .. } => unreachable!("SetTableOptions on PostgreSQL"),
             };
         }
 
-        if lines.is_empty() {
-            return Vec::new();
-        }
-
-        let alter_table = format!(
-            "ALTER TABLE {} {}",
-            self.quote(tables.previous().name()),
-            lines.join(",\n")
-        );
+        // Column renamings are rendered as separate statements, so there may be nothing to alter.
+        let alter_table = Some(lines).filter(|lines| !lines.is_empty()).map(|lines| {
+            format!(
+                "ALTER TABLE {} {}",
+                self.quote(tables.previous().name()),
+                lines.join(",\n")
+            )
+        });
 
         before_statements
             .into_iter()
-            .chain(std::iter::once(alter_table))
+            .chain(alter_table)
             .chain(after_statements.into_iter())
             .collect()
     }
@@ -232,7 +261,7 @@ impl SqlRenderer for PostgresFlavour {
         let is_serial = column.is_autoincrement();
 
         if is_serial {
-            format!("{} SERIAL", column_name)
+            format!("{} {}", column_name, render_serial_type(column.column_type()))
         } else {
             format!(
                 "{}{} {}{}{}",
@@ -249,10 +278,11 @@ impl SqlRenderer for PostgresFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
+            "REFERENCES {}({}) {} ON UPDATE CASCADE{}",
             self.quote(&foreign_key.referenced_table().name()),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action())
+            render_on_delete(&foreign_key.on_delete_action()),
+            render_deferrable(foreign_key.deferrable()),
         )
     }
 
@@ -283,12 +313,39 @@ impl SqlRenderer for PostgresFlavour {
         .to_string()]
     }
 
+    fn render_updated_at_triggers(&self, table: &TableWalker<'_>) -> Vec<String> {
+        table
+            .columns()
+            .filter(has_updated_at_trigger)
+            .flat_map(|column| render_create_updated_at_trigger(table.name(), column.name()))
+            .collect()
+    }
+
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
+        // Only constraints can be deferred, unique indexes can't.
+        if index.deferrable() {
+            return format!(
+                "ALTER TABLE {table} ADD CONSTRAINT {name} UNIQUE ({columns}){deferrable}",
+                table = self.quote(index.table().name()),
+                name = self.quote(&truncate_identifier(index.name(), POSTGRES_IDENTIFIER_SIZE_LIMIT)),
+                columns = index.columns().map(|c| self.quote(c.name())).join(", "),
+                deferrable = render_deferrable(true),
+            );
+        }
+
         CreateIndex {
-            index_name: index.name().into(),
+            index_name: truncate_identifier(index.name(), POSTGRES_IDENTIFIER_SIZE_LIMIT),
             is_unique: index.index_type().is_unique(),
             table_reference: index.table().name().into(),
             columns: index.columns().map(|c| c.name().into()).collect(),
+            using: index.method().map(|method| match method {
+                IndexMethod::BTree => ddl::IndexMethod::BTree,
+                IndexMethod::Hash => ddl::IndexMethod::Hash,
+                IndexMethod::Gist => ddl::IndexMethod::Gist,
+                IndexMethod::SpGist => ddl::IndexMethod::SpGist,
+                IndexMethod::Gin => ddl::IndexMethod::Gin,
+                IndexMethod::Brin => ddl::IndexMethod::Brin,
+            }),
         }
         .to_string()
     }
@@ -334,6 +391,14 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
+        if index.deferrable() {
+            return format!(
+                "ALTER TABLE {} DROP CONSTRAINT {}",
+                self.quote(index.table().name()),
+                self.quote(index.name())
+            );
+        }
+
         format!("DROP INDEX {}", self.quote(index.name()))
     }
 
@@ -341,6 +406,11 @@ impl SqlRenderer for PostgresFlavour {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
+    // Views selecting from the view are dropped with it, and skipped afterwards.
+    fn render_drop_view(&self, view_name: &str) -> String {
+        format!("DROP VIEW IF EXISTS {} CASCADE", self.quote(view_name))
+    }
+
     fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
         unreachable!("render_redefine_table on Postgres")
     }
@@ -380,6 +450,57 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
     }
 }
 
+/// The function the triggers of the `@updatedAt` columns call with the name of the column, when
+/// the datasource enables `databaseTimestamps`. The body is a single-quoted string, so scripts
+/// can still be split into statements on semicolons outside of quotes.
+const SET_UPDATED_AT_FUNCTION: &str = "prisma_set_updated_at";
+
+fn has_updated_at_trigger(column: &ColumnWalker<'_>) -> bool {
+    column.default().map(|default| default.on_update()).unwrap_or(false)
+}
+
+fn render_create_updated_at_trigger(table_name: &str, column_name: &str) -> Vec<String> {
+    vec![
+        format!(
+            "CREATE OR REPLACE FUNCTION {function}() RETURNS TRIGGER AS 'BEGIN NEW := jsonb_populate_record(NEW, jsonb_build_object(TG_ARGV[0], CURRENT_TIMESTAMP)); RETURN NEW; END;' LANGUAGE plpgsql",
+            function = Quoted::postgres_ident(SET_UPDATED_AT_FUNCTION),
+        ),
+        format!(
+            "CREATE TRIGGER {trigger} BEFORE UPDATE ON {table} FOR EACH ROW EXECUTE PROCEDURE {function}({column})",
+            trigger = Quoted::postgres_ident(format!("{}{}", UPDATED_AT_TRIGGER_PREFIX, column_name)),
+            table = Quoted::postgres_ident(table_name),
+            function = Quoted::postgres_ident(SET_UPDATED_AT_FUNCTION),
+            column = Quoted::postgres_string(column_name),
+        ),
+    ]
+}
+
+fn render_drop_updated_at_trigger(table_name: &str, column_name: &str) -> String {
+    format!(
+        "DROP TRIGGER IF EXISTS {trigger} ON {table}",
+        trigger = Quoted::postgres_ident(format!("{}{}", UPDATED_AT_TRIGGER_PREFIX, column_name)),
+        table = Quoted::postgres_ident(table_name),
+    )
+}
+
+/// The `SERIAL` pseudo-type matching the width of the column, so that the sequence can't
+/// overflow before the column does.
+fn render_serial_type(t: &ColumnType) -> &'static str {
+    match (&t.family, t.full_data_type.to_uppercase().as_str()) {
+        (_, "BIGINT") | (ColumnTypeFamily::BigInt, "") => "BIGSERIAL",
+        (_, "SMALLINT") => "SMALLSERIAL",
+        _ => "SERIAL",
+    }
+}
+
+fn render_deferrable(deferrable: bool) -> &'static str {
+    if deferrable {
+        " DEFERRABLE INITIALLY DEFERRED"
+    } else {
+        ""
+    }
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
 
@@ -456,6 +577,34 @@ fn render_alter_column(
             }
         }
     }
+
+    // Order matters: the other clauses and statements refer to the column by its previous name.
+    if column_changes.column_was_renamed() {
+        after_statements.push(format!(
+            "ALTER TABLE {table_name} RENAME COLUMN {column_name} TO {next_column_name}",
+            table_name = table_name,
+            column_name = column_name,
+            next_column_name = Quoted::postgres_ident(columns.next().name()),
+        ));
+    }
+
+    // The triggers are named after the column, so they are recreated on renamings.
+    let (previous_on_update, next_on_update) = columns.as_ref().map(has_updated_at_trigger).into_tuple();
+    let renamed = column_changes.column_was_renamed();
+
+    if previous_on_update && (!next_on_update || renamed) {
+        after_statements.push(render_drop_updated_at_trigger(
+            columns.previous().table().name(),
+            columns.previous().name(),
+        ));
+    }
+
+    if next_on_update && (!previous_on_update || renamed) {
+        after_statements.extend(render_create_updated_at_trigger(
+            columns.previous().table().name(),
+            columns.next().name(),
+        ));
+    }
 }
 
 fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &ColumnChanges) -> Vec<PostgresAlterColumn> {
@@ -496,7 +645,8 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
-            ColumnChange::Renaming => unreachable!("column renaming"),
+            // Renamings are rendered separately, after the other changes.
+            ColumnChange::Renaming => (),
         }
     }
 
@@ -106,6 +106,7 @@ impl SqlRenderer for SqliteFlavour {
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
+                TableChange::SetTableOptions { .. } => unreachable!("SetTableOptions on SQLite"),
             };
         }
 
@@ -213,8 +214,13 @@ impl SqlRenderer for SqliteFlavour {
     }
 }
 
-fn render_column_type(t: &ColumnType) -> &'static str {
-    match &t.family {
+fn render_column_type(t: &ColumnType) -> Cow<'_, str> {
+    // Columns with a native type keep their declared type, e.g. for its affinity.
+    if t.native_type.is_some() && !t.full_data_type.is_empty() {
+        return t.full_data_type.as_str().into();
+    }
+
+    let tpe = match &t.family {
         ColumnTypeFamily::Boolean => "BOOLEAN",
         ColumnTypeFamily::DateTime => "DATETIME",
         ColumnTypeFamily::Float => "REAL",
@@ -227,7 +233,9 @@ fn render_column_type(t: &ColumnType) -> &'static str {
         ColumnTypeFamily::Enum(_) => unreachable!("ColumnTypeFamily::Enum on SQLite"),
         ColumnTypeFamily::Uuid => unimplemented!("ColumnTypeFamily::Uuid on SQLite"),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
-    }
+    };
+
+    tpe.into()
 }
 
 fn escape_quotes(s: &str) -> Cow<'_, str> {
@@ -240,7 +248,8 @@ fn escape_quotes(s: &str) -> Cow<'_, str> {
 ///
 /// The process is complicated by the migrations that add make an optional column required with a
 /// default value. In this case, we need to treat them differently and `coalesce`ing them with the
-/// default value, since SQLite does not have the `DEFAULT` keyword.
+/// default value, since SQLite does not have the `DEFAULT` keyword. Columns whose type changed are
+/// `CAST` to their new type, so the copied values get the storage class of the new column.
 fn copy_current_table_into_new_table(
     steps: &mut Vec<String>,
     redefine_table: &RedefineTable,
@@ -257,28 +266,45 @@ fn copy_current_table_into_new_table(
         .iter()
         .map(|(column_indexes, _, _)| tables.next().column_at(*column_indexes.next()).name());
 
-    let source_columns = redefine_table.column_pairs.iter().map(|(column_indexes, changes, _)| {
-        let columns = tables.columns(column_indexes);
-
-        let col_became_required_with_a_default =
-            changes.arity_changed() && columns.next().arity().is_required() && columns.next().default().is_some();
-
-        if col_became_required_with_a_default {
-            format!(
-                "coalesce({column_name}, {default_value}) AS {column_name}",
-                column_name = Quoted::sqlite_ident(columns.previous().name()),
-                default_value = flavour.render_default(
-                    columns
-                        .next()
-                        .default()
-                        .expect("default on required column with default"),
-                    &columns.next().column_type_family()
-                )
-            )
-        } else {
-            Quoted::sqlite_ident(columns.previous().name()).to_string()
-        }
-    });
+    let source_columns = redefine_table
+        .column_pairs
+        .iter()
+        .map(|(column_indexes, changes, type_change)| {
+            let columns = tables.columns(column_indexes);
+            let column_name = Quoted::sqlite_ident(columns.previous().name());
+            let mut value = column_name.to_string();
+
+            let cast_target_type = type_change
+                .as_ref()
+                .and_then(|_| cast_target_type(&columns.next().column_type_family()));
+
+            if let Some(target_type) = cast_target_type {
+                value = format!("CAST({} AS {})", value, target_type);
+            }
+
+            let col_became_required_with_a_default =
+                changes.arity_changed() && columns.next().arity().is_required() && columns.next().default().is_some();
+
+            if col_became_required_with_a_default {
+                value = format!(
+                    "coalesce({value}, {default_value})",
+                    value = value,
+                    default_value = flavour.render_default(
+                        columns
+                            .next()
+                            .default()
+                            .expect("default on required column with default"),
+                        &columns.next().column_type_family()
+                    )
+                );
+            }
+
+            if cast_target_type.is_some() || col_became_required_with_a_default {
+                format!("{} AS {}", value, column_name)
+            } else {
+                value
+            }
+        });
 
     let query = format!(
         r#"INSERT INTO "{temporary_table_name}" ({destination_columns}) SELECT {source_columns} FROM "{previous_table_name}""#,
@@ -291,9 +317,23 @@ fn copy_current_table_into_new_table(
     steps.push(query)
 }
 
+/// The type to `CAST` the values of a column to when its type changes. Booleans and datetimes
+/// are copied as they are: their type has `NUMERIC` affinity, and casting to it would turn
+/// `'2020-01-01T00:00:00Z'` into `2020`.
+fn cast_target_type(family: &ColumnTypeFamily) -> Option<&'static str> {
+    match family {
+        ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => Some("INTEGER"),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => Some("REAL"),
+        ColumnTypeFamily::String => Some("TEXT"),
+        ColumnTypeFamily::Binary => Some("BLOB"),
+        _ => None,
+    }
+}
+
 fn render_column<'a>(column: &ColumnWalker<'a>) -> ddl::Column<'a> {
     sql_ddl::sqlite::Column {
-        autoincrement: column.is_single_primary_key() && column.column_type_family().is_int(),
+        autoincrement: column.is_single_primary_key()
+            && (column.column_type_family().is_int() || column.column_type_family().is_bigint()),
         default: column
             .default()
             .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_) | DefaultKind::SEQUENCE(_)))
@@ -301,7 +341,7 @@ fn render_column<'a>(column: &ColumnWalker<'a>) -> ddl::Column<'a> {
         name: column.name().into(),
         not_null: !column.arity().is_nullable(),
         primary_key: column.is_single_primary_key(),
-        r#type: render_column_type(column.column_type()).into(),
+        r#type: render_column_type(column.column_type()),
     }
 }
 
@@ -5,15 +5,29 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, ForeignKeyIndexes, IndexDefinition, IndexMethod, IndexType,
+    ReferentialIntegrity, ScalarType, ValueGenerator, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use sql_schema_describer::{self as sql, ColumnArity};
 
-pub(crate) fn calculate_sql_schema(datamodel: &Datamodel, flavour: &dyn SqlFlavour) -> sql::SqlSchema {
+pub(crate) const AUDIT_LOG_TABLE_NAME: &str = "_prisma_audit_log";
+
+pub(crate) fn calculate_sql_schema(
+    datamodel: &Datamodel,
+    flavour: &dyn SqlFlavour,
+    referential_integrity: ReferentialIntegrity,
+    foreign_key_indexes: ForeignKeyIndexes,
+    audit_log: bool,
+    database_timestamps: bool,
+) -> sql::SqlSchema {
     let calculator = SqlSchemaCalculator {
         data_model: datamodel,
         flavour,
+        referential_integrity,
+        foreign_key_indexes,
+        audit_log,
+        database_timestamps,
     };
     calculator.calculate_internal()
 }
@@ -21,6 +35,10 @@ pub(crate) fn calculate_sql_schema(datamodel: &Datamodel, flavour: &dyn SqlFlavo
 struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
     flavour: &'a dyn SqlFlavour,
+    referential_integrity: ReferentialIntegrity,
+    foreign_key_indexes: ForeignKeyIndexes,
+    audit_log: bool,
+    database_timestamps: bool,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
@@ -30,11 +48,28 @@ impl<'a> SqlSchemaCalculator<'a> {
 
         for (model, mut table) in model_tables_without_inline_relations {
             self.add_inline_relations_to_model_tables(model, &mut table);
+
+            if self.foreign_key_indexes.are_explicit() {
+                add_foreign_key_indexes(&mut table);
+            }
+
+            if self.database_timestamps {
+                set_updated_at_defaults(model, &mut table);
+            }
+
             tables.push(table);
         }
 
         tables.extend(self.calculate_relation_tables());
 
+        if self.audit_log {
+            tables.push(audit_log_table());
+        }
+
+        for table in tables.iter_mut() {
+            self.flavour.apply_default_clustering(table);
+        }
+
         let enums = self.flavour.calculate_enums(&self.data_model);
         let sequences = Vec::new();
 
@@ -42,6 +77,7 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         }
     }
 
@@ -58,6 +94,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             tpe: column_type(&f),
                             default: migration_value_new(&f),
                             auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            computed: false,
                         })
                     },
                     TypeWalker::Enum(r#enum) => {
@@ -67,8 +104,17 @@ impl<'a> SqlSchemaCalculator<'a> {
                             tpe: self.flavour.enum_column_type(&f,  enum_db_name),
                             default: migration_value_new(&f),
                             auto_increment: false,
+                            computed: false,
                         })
                     }
+                    // Composite types are embedded into the table as JSON.
+                    TypeWalker::CompositeType(_) => Some(sql::Column {
+                        name: f.db_name().to_owned(),
+                        tpe: column_type_for_scalar_type(&ScalarType::Json, column_arity(f.arity())),
+                        default: None,
+                        auto_increment: false,
+                        computed: false,
+                    }),
                     TypeWalker::NativeType(scalar_type, native_type_instance) =>{
                         let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })));
 
@@ -76,7 +122,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                             name: f.db_name().to_owned(),
                             tpe: self.flavour.column_type_for_native_type(&f, scalar_type, native_type_instance),
                             default: migration_value_new(&f),
-                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f)
+                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            computed: false,
                         })
                     } ,
                     _ => None,
@@ -90,6 +137,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .collect(),
                 sequence: None,
                 constraint_name: None,
+                clustered: model.id_clustered(),
             }).filter(|pk| !pk.columns.is_empty());
 
             // TODO: HERE
@@ -98,6 +146,9 @@ impl<'a> SqlSchemaCalculator<'a> {
                     name: self.flavour.single_field_index_name(model.db_name(), f.db_name()),
                     columns: vec![f.db_name().to_owned()],
                     tpe: sql::IndexType::Unique,
+                    clustered: None,
+                    deferrable: false,
+                    method: None,
                 }
             });
 
@@ -131,6 +182,17 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field| field.db_name().to_owned())
                         .collect(),
                     tpe: index_type,
+                    clustered: index_definition.clustered,
+                    deferrable: index_definition.deferrable,
+                    // B-trees are the default, the describer doesn't report them.
+                    method: index_definition.method.and_then(|method| match method {
+                        IndexMethod::BTree => None,
+                        IndexMethod::Hash => Some(sql::IndexMethod::Hash),
+                        IndexMethod::Gist => Some(sql::IndexMethod::Gist),
+                        IndexMethod::SpGist => Some(sql::IndexMethod::SpGist),
+                        IndexMethod::Gin => Some(sql::IndexMethod::Gin),
+                        IndexMethod::Brin => Some(sql::IndexMethod::Brin),
+                    }),
                 }
             });
 
@@ -140,6 +202,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                engine: model.engine().map(String::from),
+                row_format: model.row_format().map(String::from),
             };
 
             (model, table)
@@ -159,18 +223,30 @@ impl<'a> SqlSchemaCalculator<'a> {
                 add_one_to_one_relation_unique_index(table, &fk_columns);
             }
 
-            // Foreign key
-            {
+            // Foreign key, unless the query engine emulates the relations.
+            if self.referential_integrity.uses_foreign_keys() {
+                let mut column_pairs: Vec<(String, String)> = fk_columns
+                    .into_iter()
+                    .zip(relation_field.referenced_columns().map(String::from))
+                    .collect();
+
+                if self.flavour.foreign_keys_follow_referenced_index_order() {
+                    order_by_referenced_index(&relation_field.referenced_model(), &mut column_pairs);
+                }
+
+                let (columns, referenced_columns) = column_pairs.into_iter().unzip();
+
                 let fk = sql::ForeignKey {
                     constraint_name: None,
-                    columns: fk_columns,
+                    columns,
                     referenced_table: relation_field.referenced_model().database_name().to_owned(),
-                    referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
+                    referenced_columns,
                     on_update_action: sql::ForeignKeyAction::Cascade,
                     on_delete_action: match column_arity(relation_field.arity()) {
                         ColumnArity::Required => sql::ForeignKeyAction::Cascade,
                         _ => sql::ForeignKeyAction::SetNull,
                     },
+                    deferrable: relation_field.is_deferrable(),
                 };
 
                 table.foreign_keys.push(fk);
@@ -189,35 +265,47 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let model_a = model_a_id.model();
                 let model_b = model_b_id.model();
 
-                let foreign_keys = vec![
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_a_column().into()],
-                        referenced_table: model_a.db_name().into(),
-                        referenced_columns: vec![model_a_id.db_name().into()],
-                        on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                        on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    },
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_b_column().into()],
-                        referenced_table: model_b.db_name().into(),
-                        referenced_columns: vec![model_b_id.db_name().into()],
-                        on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                        on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    },
-                ];
+                let foreign_keys = if self.referential_integrity.uses_foreign_keys() {
+                    vec![
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_a_column().into()],
+                            referenced_table: model_a.db_name().into(),
+                            referenced_columns: vec![model_a_id.db_name().into()],
+                            on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            deferrable: false,
+                        },
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_b_column().into()],
+                            referenced_table: model_b.db_name().into(),
+                            referenced_columns: vec![model_b_id.db_name().into()],
+                            on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            deferrable: false,
+                        },
+                    ]
+                } else {
+                    Vec::new()
+                };
 
                 let indexes = vec![
                     sql::Index {
                         name: format!("{}_AB_unique", &table_name),
                         columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                         tpe: sql::IndexType::Unique,
+                        clustered: None,
+                        deferrable: false,
+                        method: None,
                     },
                     sql::Index {
                         name: format!("{}_B_index", &table_name),
                         columns: vec![m2m.model_b_column().into()],
                         tpe: sql::IndexType::Normal,
+                        clustered: None,
+                        deferrable: false,
+                        method: None,
                     },
                 ];
 
@@ -227,12 +315,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                         tpe: column_type(&model_a_id),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                     sql::Column {
                         name: m2m.model_b_column().into(),
                         tpe: column_type(&model_b_id),
                         default: None,
                         auto_increment: false,
+                        computed: false,
                     },
                 ];
 
@@ -242,6 +332,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    engine: None,
+                    row_format: None,
                 }
             })
     }
@@ -319,6 +411,80 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
     }
 }
 
+/// Adds an index for the foreign keys that no index or primary key starting with their columns
+/// covers, so that the database does not create one implicitly.
+fn add_foreign_key_indexes(table: &mut sql::Table) {
+    let is_covered = |table: &sql::Table, columns: &[String]| {
+        table
+            .primary_key
+            .as_ref()
+            .map(|pk| pk.columns.starts_with(columns))
+            .unwrap_or(false)
+            || table.indices.iter().any(|index| index.columns.starts_with(columns))
+    };
+
+    let fk_columns: Vec<Vec<String>> = table.foreign_keys.iter().map(|fk| fk.columns.clone()).collect();
+
+    for columns in fk_columns {
+        if columns.is_empty() || is_covered(table, &columns) {
+            continue;
+        }
+
+        table.indices.push(sql::Index {
+            name: format!("{}.{}_index", table.name, columns.join("_")),
+            columns,
+            tpe: sql::IndexType::Normal,
+            clustered: None,
+            deferrable: false,
+            method: None,
+        });
+    }
+}
+
+/// The table the query engine records its writes in, if the datasource enables `auditLog`. The
+/// primary keys and changed fields are stored as JSON text, so the table works on all databases.
+fn audit_log_table() -> sql::Table {
+    let column = |name: &str, scalar_type: ScalarType, arity: ColumnArity| sql::Column {
+        name: name.to_owned(),
+        tpe: column_type_for_scalar_type(&scalar_type, arity),
+        default: None,
+        auto_increment: name == "id",
+        computed: false,
+    };
+
+    sql::Table {
+        name: AUDIT_LOG_TABLE_NAME.to_owned(),
+        columns: vec![
+            column("id", ScalarType::Int, ColumnArity::Required),
+            column("model", ScalarType::String, ColumnArity::Required),
+            column("operation", ScalarType::String, ColumnArity::Required),
+            column("primary_key", ScalarType::String, ColumnArity::Nullable),
+            column("changed_fields", ScalarType::String, ColumnArity::Required),
+            column("request_id", ScalarType::String, ColumnArity::Nullable),
+            column("timestamp", ScalarType::DateTime, ColumnArity::Required),
+        ],
+        indices: Vec::new(),
+        primary_key: Some(sql::PrimaryKey {
+            columns: vec!["id".to_owned()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys: Vec::new(),
+        engine: None,
+        row_format: None,
+    }
+}
+
+/// With `databaseTimestamps`, the database sets the `@updatedAt` columns on inserts and updates.
+fn set_updated_at_defaults(model: ModelWalker<'_>, table: &mut sql::Table) {
+    for field in model.scalar_fields().filter(|field| field.is_updated_at()) {
+        if let Some(column) = table.columns.iter_mut().find(|column| column.name == field.db_name()) {
+            column.default = Some(sql::DefaultValue::now_on_update());
+        }
+    }
+}
+
 fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[String]) {
     // Don't add a duplicate index.
     if table
@@ -334,7 +500,34 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     };
 
     table.indices.push(index);
 }
+
+/// Reorders the (referencing, referenced) column pairs of a foreign key so the referenced columns
+/// follow the order of the primary key or unique index they form, if any.
+fn order_by_referenced_index(referenced_model: &ModelWalker<'_>, column_pairs: &mut [(String, String)]) {
+    let id_columns: Vec<&str> = referenced_model.id_fields().map(|field| field.db_name()).collect();
+    let unique_index_columns = referenced_model
+        .unique_indexes()
+        .map(|index| index.fields().map(|field| field.db_name()).collect::<Vec<_>>());
+
+    let index_columns = std::iter::once(id_columns).chain(unique_index_columns).find(|columns| {
+        columns.len() == column_pairs.len()
+            && column_pairs
+                .iter()
+                .all(|(_, referenced)| columns.contains(&referenced.as_str()))
+    });
+
+    if let Some(index_columns) = index_columns {
+        column_pairs.sort_by_key(|(_, referenced)| {
+            index_columns
+                .iter()
+                .position(|column| *column == referenced.as_str())
+        });
+    }
+}
@@ -30,6 +30,16 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         sql::ForeignKeyAction::Cascade
     }
 
+    /// Whether the columns of a foreign key must be in the order of the primary key or unique index
+    /// they reference, as opposed to any order.
+    fn foreign_keys_follow_referenced_index_order(&self) -> bool {
+        false
+    }
+
+    /// Fill in the clustering the database picks by default for the indexes and the primary key
+    /// of the table, on databases where clustering is described.
+    fn apply_default_clustering(&self, _table: &mut sql::Table) {}
+
     // TODO: Maybe we should rethink this a bit?
     fn single_field_index_name(&self, model_name: &str, field_name: &str) -> String {
         format!("{}.{}_unique", model_name, field_name)
@@ -5,7 +5,7 @@ use datamodel::{
     FieldArity, NativeTypeInstance, ScalarType,
 };
 use native_types::{MsSqlType, MsSqlTypeParameter, NativeType};
-use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction};
+use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction, Table};
 
 impl SqlSchemaCalculatorFlavour for MssqlFlavour {
     fn column_type_for_native_type(
@@ -90,4 +90,17 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
     fn single_field_index_name(&self, model_name: &str, field_name: &str) -> String {
         format!("{}_{}_unique", model_name, field_name)
     }
+
+    fn apply_default_clustering(&self, table: &mut Table) {
+        // SQL Server clusters the primary key, unless another index of the table is clustered.
+        let has_clustered_index = table.indices.iter().any(|index| index.clustered == Some(true));
+
+        for index in table.indices.iter_mut() {
+            index.clustered.get_or_insert(false);
+        }
+
+        if let Some(pk) = table.primary_key.as_mut() {
+            pk.clustered.get_or_insert(!has_clustered_index);
+        }
+    }
 }
@@ -8,6 +8,12 @@ use native_types::MySqlType;
 use sql_schema_describer::{self as sql};
 
 impl SqlSchemaCalculatorFlavour for MysqlFlavour {
+    // MySQL only accepts foreign keys whose referenced columns are the leading columns of an index,
+    // in the same order.
+    fn foreign_keys_follow_referenced_index_order(&self) -> bool {
+        true
+    }
+
     fn calculate_enums(&self, datamodel: &Datamodel) -> Vec<sql::Enum> {
         // This is a lower bound for the size of the generated enums (we assume
         // each enum is used at least once).
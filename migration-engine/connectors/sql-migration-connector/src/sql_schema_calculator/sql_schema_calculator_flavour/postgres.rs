@@ -63,6 +63,11 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             PostgresType::Xml => "XML".to_owned(),
             PostgresType::JSON => "JSON".to_owned(),
             PostgresType::JSONB => "JSONB".to_owned(),
+            PostgresType::Citext => "CITEXT".to_owned(),
+            PostgresType::Inet => "INET".to_owned(),
+            PostgresType::Oid => "OID".to_owned(),
+            PostgresType::Money => "MONEY".to_owned(),
+            PostgresType::Interval => "INTERVAL".to_owned(),
         };
 
         sql::ColumnType {
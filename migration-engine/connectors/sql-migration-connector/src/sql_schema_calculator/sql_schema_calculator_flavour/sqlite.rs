@@ -1,4 +1,6 @@
-use datamodel::walkers::ScalarFieldWalker;
+use datamodel::{walkers::ScalarFieldWalker, NativeTypeInstance, ScalarType};
+use native_types::SqliteType;
+use sql_schema_describer::{self as sql};
 
 use super::SqlSchemaCalculatorFlavour;
 use crate::flavour::SqliteFlavour;
@@ -6,15 +8,43 @@ use crate::flavour::SqliteFlavour;
 impl SqlSchemaCalculatorFlavour for SqliteFlavour {
     fn column_type_for_native_type(
         &self,
-        _field: &datamodel::walkers::ScalarFieldWalker<'_>,
-        _scalar_type: datamodel::ScalarType,
-        _native_type_instance: &datamodel::NativeTypeInstance,
-    ) -> sql_schema_describer::ColumnType {
-        unreachable!("column_type_for_native_type on SQLite")
+        field: &ScalarFieldWalker<'_>,
+        scalar_type: ScalarType,
+        native_type_instance: &NativeTypeInstance,
+    ) -> sql::ColumnType {
+        let sqlite_type: SqliteType = native_type_instance.deserialize_native_type();
+
+        let data_type = match sqlite_type {
+            SqliteType::Integer => "INTEGER".to_owned(),
+            SqliteType::Real => "REAL".to_owned(),
+            SqliteType::Text => "TEXT".to_owned(),
+            SqliteType::Blob => "BLOB".to_owned(),
+            SqliteType::Numeric => "NUMERIC".to_owned(),
+            SqliteType::Decimal(None) => "DECIMAL".to_owned(),
+            SqliteType::Decimal(Some((precision, scale))) => format!("DECIMAL({}, {})", precision, scale),
+            SqliteType::VarChar(None) => "VARCHAR".to_owned(),
+            SqliteType::VarChar(Some(length)) => format!("VARCHAR({})", length),
+            SqliteType::Boolean => "BOOLEAN".to_owned(),
+            SqliteType::DateTime => "DATETIME".to_owned(),
+        };
+
+        // The family still decides how the values are read and written, the declared type only
+        // determines the affinity of the column.
+        let arity = super::super::column_arity(field.arity());
+        let family = super::super::column_type_for_scalar_type(&scalar_type, arity).family;
+
+        sql::ColumnType {
+            data_type: data_type.clone(),
+            full_data_type: data_type,
+            character_maximum_length: None,
+            family,
+            arity,
+            native_type: Some(native_type_instance.serialized_native_type.clone()),
+        }
     }
 
     // Integer primary keys on SQLite are automatically assigned the rowid, which means they are automatically autoincrementing.
     fn field_is_implicit_autoincrement_primary_key(&self, field: &ScalarFieldWalker<'_>) -> bool {
-        field.is_id() && field.field_type().is_int()
+        field.is_id() && (field.field_type().is_int() || field.field_type().is_bigint())
     }
 }
@@ -11,18 +11,28 @@ use crate::{
     pair::Pair,
     sql_migration::{
         self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable,
-        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep, TableChange,
+        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, RenameTable, SqlMigrationStep,
+        TableChange,
     },
     SqlFlavour, SqlSchema,
 };
 use column::ColumnTypeChange;
 use enums::EnumDiffer;
-use sql_schema_describer::walkers::{EnumWalker, ForeignKeyWalker, TableWalker};
+use migration_connector::RenameHint;
+use sql_schema_describer::walkers::{EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker};
 use std::collections::HashSet;
 use table::TableDiffer;
 
-pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavour) -> Vec<SqlMigrationStep> {
-    let differ = SqlSchemaDiffer { schemas, flavour };
+pub(crate) fn calculate_steps(
+    schemas: Pair<&SqlSchema>,
+    flavour: &dyn SqlFlavour,
+    rename_hints: &[RenameHint],
+) -> Vec<SqlMigrationStep> {
+    let differ = SqlSchemaDiffer {
+        schemas,
+        flavour,
+        rename_hints,
+    };
 
     let tables_to_redefine = differ.flavour.tables_to_redefine(&differ);
     let mut alter_indexes = differ.alter_indexes(&tables_to_redefine);
@@ -36,6 +46,10 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
     let (drop_tables, mut drop_foreign_keys) = differ.drop_tables();
     differ.drop_foreign_keys(&mut drop_foreign_keys, &tables_to_redefine);
 
+    let (drop_dependent_foreign_keys, recreate_dependent_foreign_keys) =
+        differ.foreign_keys_depending_on_dropped_indexes(&tables_to_redefine);
+    drop_foreign_keys.extend(drop_dependent_foreign_keys);
+
     let drop_indexes = differ.drop_indexes(&tables_to_redefine);
     let create_indexes = differ.create_indexes(&tables_to_redefine);
 
@@ -51,13 +65,22 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         .into_iter()
         .map(SqlMigrationStep::CreateEnum)
         .chain(differ.alter_enums().into_iter().map(SqlMigrationStep::AlterEnum))
-        .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
+        // Order matters: foreign keys must be dropped before the unique indexes they reference.
         .chain(drop_foreign_keys.into_iter().map(SqlMigrationStep::DropForeignKey))
+        .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
         .chain(
             differ
                 .alter_tables(&tables_to_redefine)
                 .map(SqlMigrationStep::AlterTable),
         )
+        // Order matters: the tables are renamed after they are altered, because the ALTER TABLE
+        // statements use the previous table names, and before the indexes and foreign keys
+        // of the next schema are created.
+        .chain(
+            differ
+                .rename_tables(&tables_to_redefine)
+                .map(SqlMigrationStep::RenameTable),
+        )
         // Order matters: we must drop enums before we create tables,
         // because the new tables might be named the same as the dropped
         // enum, and that conflicts on postgres.
@@ -86,12 +109,20 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
                     index: idxs.as_ref().map(|(_, idx)| *idx),
                 }),
         )
+        // Order matters: the foreign keys we dropped because they depend on a unique index that
+        // is dropped or redefined can only be created again once that index exists again.
+        .chain(
+            recreate_dependent_foreign_keys
+                .into_iter()
+                .map(SqlMigrationStep::AddForeignKey),
+        )
         .collect()
 }
 
 pub(crate) struct SqlSchemaDiffer<'a> {
     schemas: Pair<&'a SqlSchema>,
     flavour: &'a dyn SqlFlavour,
+    rename_hints: &'a [RenameHint],
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
@@ -164,6 +195,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     .chain(SqlSchemaDiffer::add_columns(&differ))
                     .chain(SqlSchemaDiffer::alter_columns(&differ))
                     .chain(SqlSchemaDiffer::add_primary_key(&differ))
+                    .chain(SqlSchemaDiffer::set_table_options(&differ))
                     .collect();
 
                 Some(changes)
@@ -175,6 +207,23 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             })
     }
 
+    /// Renamed tables that are not redefined. Redefined tables are recreated under their next name.
+    fn rename_tables<'a, 'b: 'a>(
+        &'a self,
+        tables_to_redefine: &'b HashSet<String>,
+    ) -> impl Iterator<Item = RenameTable> + 'a {
+        self.table_pairs()
+            .filter(move |tables| !tables_to_redefine.contains(tables.next().name()))
+            .filter(move |tables| {
+                !self
+                    .flavour
+                    .table_names_match(Pair::new(tables.previous().name(), tables.next().name()))
+            })
+            .map(|differ| RenameTable {
+                table_index: differ.tables.map(|t| t.table_index()),
+            })
+    }
+
     fn drop_columns<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         differ.dropped_columns().map(|column| {
             let change = DropColumn {
@@ -228,6 +277,20 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
+    fn set_table_options(differ: &TableDiffer<'_>) -> Option<TableChange> {
+        let engine = differ.engine_change();
+        let row_format = differ.row_format_change();
+
+        if engine.is_none() && row_format.is_none() {
+            return None;
+        }
+
+        Some(TableChange::SetTableOptions {
+            engine: engine.map(String::from),
+            row_format: row_format.map(String::from),
+        })
+    }
+
     fn drop_foreign_keys<'a>(
         &'a self,
         drop_foreign_keys: &mut Vec<DropForeignKey>,
@@ -251,6 +314,78 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         }
     }
 
+    /// Foreign keys that are unchanged, but reference a unique index that is dropped or
+    /// redefined, for example because a field was added to the compound `@@unique` a relation
+    /// references. The database does not let us drop the index while a foreign key depends on it,
+    /// so these foreign keys are dropped before it and created again afterwards. The same goes
+    /// for the last index backing the columns of a foreign key, on databases requiring one.
+    fn foreign_keys_depending_on_dropped_indexes(
+        &self,
+        tables_to_redefine: &HashSet<String>,
+    ) -> (Vec<DropForeignKey>, Vec<AddForeignKey>) {
+        let mut dropped_unique_indexes: Vec<IndexWalker<'schema>> = Vec::new();
+
+        for tables in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+        {
+            dropped_unique_indexes.extend(tables.dropped_indexes().filter(|index| index.index_type().is_unique()));
+
+            if !self.flavour.can_alter_index() {
+                dropped_unique_indexes.extend(
+                    tables
+                        .index_pairs()
+                        .filter(|pair| self.flavour.index_should_be_renamed(pair))
+                        .map(|pair| *pair.previous())
+                        .filter(|index| index.index_type().is_unique()),
+                );
+            }
+        }
+
+        let mut drop_foreign_keys = Vec::new();
+        let mut add_foreign_keys = Vec::new();
+
+        for tables in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+        {
+            for fks in tables.foreign_key_pairs() {
+                let previous_fk = fks.previous();
+
+                let depends_on_dropped_index = dropped_unique_indexes.iter().any(|index| {
+                    index.table().name() == previous_fk.referenced_table().name()
+                        && index.column_names().len() == previous_fk.referenced_columns_count()
+                        && previous_fk
+                            .referenced_column_names()
+                            .iter()
+                            .all(|column| index.contains_column(column))
+                });
+
+                let loses_its_index =
+                    self.flavour.foreign_keys_need_an_index() && index::fk_loses_its_index(&tables, previous_fk);
+
+                let constraint_name = match previous_fk.constraint_name() {
+                    Some(name) if depends_on_dropped_index || loses_its_index => name,
+                    _ => continue,
+                };
+
+                drop_foreign_keys.push(DropForeignKey {
+                    table_index: tables.previous().table_index(),
+                    table: tables.previous().name().to_owned(),
+                    foreign_key_index: previous_fk.foreign_key_index(),
+                    constraint_name: constraint_name.to_owned(),
+                });
+
+                add_foreign_keys.push(AddForeignKey {
+                    table_index: tables.next().table_index(),
+                    foreign_key_index: fks.next().foreign_key_index(),
+                });
+            }
+        }
+
+        (drop_foreign_keys, add_foreign_keys)
+    }
+
     fn add_primary_key(differ: &TableDiffer<'_>) -> Option<TableChange> {
         let from_psl_change = differ
             .created_primary_key()
@@ -345,8 +480,8 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         for tables in self.table_pairs() {
             for index in tables.dropped_indexes() {
                 // On MySQL, foreign keys automatically create indexes. These foreign-key-created
-                // indexes should only be dropped as part of the foreign key.
-                if self.flavour.should_skip_fk_indexes() && index::index_covers_fk(&tables.previous(), &index) {
+                // indexes should only be dropped along with the foreign key.
+                if self.flavour.should_skip_fk_indexes() && index::index_covers_fk(&tables, &index) {
                     continue;
                 }
 
@@ -434,12 +569,10 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 self.schemas
                     .next()
                     .table_walkers()
-                    .find(move |next_table| {
-                        self.flavour
-                            .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-                    })
+                    .find(move |next_table| self.tables_match(Pair::new(previous_table.name(), next_table.name())))
                     .map(move |next_table| TableDiffer {
                         flavour: self.flavour,
+                        rename_hints: self.rename_hints,
                         tables: Pair::new(previous_table, next_table),
                     })
             })
@@ -465,19 +598,17 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
     fn created_tables(&self) -> impl Iterator<Item = TableWalker<'_>> {
         self.next_tables().filter(move |next_table| {
-            !self.previous_tables().any(|previous_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .previous_tables()
+                .any(|previous_table| self.tables_match(Pair::new(previous_table.name(), next_table.name())))
         })
     }
 
     fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.previous_tables().filter(move |previous_table| {
-            !self.next_tables().any(|next_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .next_tables()
+                .any(|next_table| self.tables_match(Pair::new(previous_table.name(), next_table.name())))
         })
     }
 
@@ -495,6 +626,25 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |table| !self.table_is_ignored(&table.name()))
     }
 
+    /// Whether the tables with the given names are the same table. Tables are matched by name,
+    /// unless the next table was renamed according to the rename hints.
+    fn tables_match(&self, names: Pair<&str>) -> bool {
+        match RenameHint::previous_table_name(self.rename_hints, names.next()) {
+            Some(previous_name) => self
+                .flavour
+                .table_names_match(Pair::new(*names.previous(), previous_name)),
+            None => {
+                self.flavour.table_names_match(names)
+                    && !self.rename_hints.iter().any(|hint| match hint {
+                        RenameHint::Table { previous, .. } => self
+                            .flavour
+                            .table_names_match(Pair::new(*names.previous(), previous.as_str())),
+                        RenameHint::Column { .. } => false,
+                    })
+            }
+        }
+    }
+
     fn table_is_ignored(&self, table_name: &str) -> bool {
         table_name == "_prisma_migrations" || self.flavour.table_should_be_ignored(&table_name)
     }
@@ -574,6 +724,7 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         .all(|(previous, next)| previous == next);
 
     references_same_table
+        && previous.deferrable() == next.deferrable()
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
@@ -75,6 +75,13 @@ impl<'a> ColumnDiffer<'a> {
             return true;
         }
 
+        // Whether the database sets the value on updates, with `databaseTimestamps`.
+        let on_update = |column: &ColumnWalker<'_>| column.default().map(|d| d.on_update()).unwrap_or(false);
+
+        if on_update(&self.previous) != on_update(&self.next) {
+            return false;
+        }
+
         let defaults = (
             &self.previous.default().as_ref().map(|d| d.kind()),
             &self.next.default().as_ref().map(|d| d.kind()),
@@ -177,6 +184,10 @@ impl ColumnChanges {
         self.changes == BitFlags::from(ColumnChange::TypeChanged)
     }
 
+    pub(crate) fn only_renamed(&self) -> bool {
+        self.changes == BitFlags::from(ColumnChange::Renaming)
+    }
+
     pub(crate) fn column_was_renamed(&self) -> bool {
         self.changes.contains(ColumnChange::Renaming)
     }
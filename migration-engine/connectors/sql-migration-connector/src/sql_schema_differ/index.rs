@@ -1,7 +1,40 @@
-use sql_schema_describer::walkers::{IndexWalker, TableWalker};
+use super::table::TableDiffer;
+use sql_schema_describer::walkers::{ForeignKeyWalker, IndexWalker};
 
-pub(super) fn index_covers_fk(table: &TableWalker<'_>, index: &IndexWalker<'_>) -> bool {
+/// Whether the index has exactly the columns of a foreign key that is kept by the migration, i.e.
+/// it is the index the database created implicitly for the foreign key.
+pub(super) fn index_covers_fk(table: &TableDiffer<'_>, index: &IndexWalker<'_>) -> bool {
     table
-        .foreign_keys()
-        .any(|fk| fk.constrained_column_names() == index.column_names())
+        .foreign_key_pairs()
+        .any(|fks| fks.previous().constrained_column_names() == index.column_names())
+}
+
+/// Whether the migration drops all the indexes a kept foreign key can use, i.e. the indexes and
+/// the primary key starting with its columns. The database refuses to drop the last of them while
+/// the foreign key exists.
+pub(super) fn fk_loses_its_index(table: &TableDiffer<'_>, fk: &ForeignKeyWalker<'_>) -> bool {
+    let fk_columns = fk.constrained_column_names();
+
+    if table
+        .previous()
+        .primary_key_column_names()
+        .map(|columns| columns.starts_with(fk_columns))
+        .unwrap_or(false)
+    {
+        return false;
+    }
+
+    let dropped_indexes: Vec<usize> = table
+        .dropped_indexes()
+        .filter(|index| !index_covers_fk(table, index))
+        .map(|index| index.index())
+        .collect();
+
+    let mut backing_indexes = table
+        .previous()
+        .indexes()
+        .filter(|index| index.column_names().starts_with(fk_columns))
+        .peekable();
+
+    backing_indexes.peek().is_some() && backing_indexes.all(|index| dropped_indexes.contains(&index.index()))
 }
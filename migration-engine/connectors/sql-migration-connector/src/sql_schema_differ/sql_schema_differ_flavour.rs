@@ -58,6 +58,12 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether the database requires an index starting with the columns of a foreign key, and
+    /// creates one implicitly when there is none.
+    fn foreign_keys_need_an_index(&self) -> bool {
+        false
+    }
+
     /// Whether indexes matching a foreign key should be skipped.
     fn should_skip_fk_indexes(&self) -> bool {
         false
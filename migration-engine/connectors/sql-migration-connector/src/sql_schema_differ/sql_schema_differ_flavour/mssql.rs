@@ -1,6 +1,7 @@
 use super::SqlSchemaDifferFlavour;
 use crate::{
-    flavour::{MssqlFlavour, SqlFlavour},
+    flavour::{truncate_identifier, MssqlFlavour, SqlFlavour, MSSQL_IDENTIFIER_SIZE_LIMIT},
+    pair::Pair,
     sql_schema_differ::{
         column::{ColumnDiffer, ColumnTypeChange},
         SqlSchemaDiffer,
@@ -12,6 +13,13 @@ use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 use std::collections::HashSet;
 
 impl SqlSchemaDifferFlavour for MssqlFlavour {
+    fn index_should_be_renamed(&self, indexes: &Pair<IndexWalker<'_>>) -> bool {
+        // Implements correct comparison for truncated index names.
+        let (previous_name, next_name) = indexes.as_ref().map(|idx| idx.name()).into_tuple();
+
+        previous_name != truncate_identifier(next_name, MSSQL_IDENTIFIER_SIZE_LIMIT)
+    }
+
     fn should_skip_index_for_new_table(&self, index: &IndexWalker<'_>) -> bool {
         index.index_type().is_unique()
     }
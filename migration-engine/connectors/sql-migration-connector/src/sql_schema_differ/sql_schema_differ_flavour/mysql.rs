@@ -1,6 +1,8 @@
 use super::SqlSchemaDifferFlavour;
 use crate::{
-    flavour::MysqlFlavour, flavour::MYSQL_IDENTIFIER_SIZE_LIMIT, pair::Pair, sql_schema_differ::column::ColumnDiffer,
+    flavour::{truncated_identifiers_match, MysqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
+    pair::Pair,
+    sql_schema_differ::column::ColumnDiffer,
     sql_schema_differ::ColumnTypeChange,
 };
 use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
@@ -51,15 +53,15 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         None
     }
 
+    fn foreign_keys_need_an_index(&self) -> bool {
+        true
+    }
+
     fn index_should_be_renamed(&self, indexes: &Pair<IndexWalker<'_>>) -> bool {
         // Implements correct comparison for truncated index names.
         let (previous_name, next_name) = indexes.as_ref().map(|idx| idx.name()).into_tuple();
 
-        if previous_name.len() == MYSQL_IDENTIFIER_SIZE_LIMIT && next_name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-            previous_name[0..MYSQL_IDENTIFIER_SIZE_LIMIT] != next_name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-        } else {
-            previous_name != next_name
-        }
+        !truncated_identifiers_match(previous_name, next_name, MYSQL_IDENTIFIER_SIZE_LIMIT)
     }
 
     fn should_create_indexes_from_created_tables(&self) -> bool {
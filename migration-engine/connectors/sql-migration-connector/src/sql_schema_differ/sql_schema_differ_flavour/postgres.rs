@@ -1,6 +1,6 @@
 use super::SqlSchemaDifferFlavour;
 use crate::{
-    flavour::PostgresFlavour,
+    flavour::{truncated_identifiers_match, PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::AlterEnum,
     sql_schema_differ::column::{ColumnDiffer, ColumnTypeChange},
@@ -10,11 +10,6 @@ use once_cell::sync::Lazy;
 use regex::RegexSet;
 use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 
-/// The maximum length of postgres identifiers, in bytes.
-///
-/// Reference: https://www.postgresql.org/docs/12/limits.html
-const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
-
 impl SqlSchemaDifferFlavour for PostgresFlavour {
     fn alter_enums(&self, differ: &SqlSchemaDiffer<'_>) -> Vec<AlterEnum> {
         differ
@@ -73,11 +68,7 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         // Implements correct comparison for truncated index names.
         let (previous_name, next_name) = pair.as_ref().map(|idx| idx.name()).into_tuple();
 
-        if previous_name.len() == POSTGRES_IDENTIFIER_SIZE_LIMIT && next_name.len() > POSTGRES_IDENTIFIER_SIZE_LIMIT {
-            previous_name[0..POSTGRES_IDENTIFIER_SIZE_LIMIT] != next_name[0..POSTGRES_IDENTIFIER_SIZE_LIMIT]
-        } else {
-            previous_name != next_name
-        }
+        !truncated_identifiers_match(previous_name, next_name, POSTGRES_IDENTIFIER_SIZE_LIMIT)
     }
 
     fn table_should_be_ignored(&self, table_name: &str) -> bool {
@@ -1,3 +1,4 @@
+use native_types::SqliteType;
 use sql_schema_describer::ColumnTypeFamily;
 
 use super::SqlSchemaDifferFlavour;
@@ -10,8 +11,15 @@ use std::collections::HashSet;
 
 impl SqlSchemaDifferFlavour for SqliteFlavour {
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
+        let previous_type: Option<SqliteType> = differ.previous.column_native_type();
+        let next_type: Option<SqliteType> = differ.next.column_native_type();
+
         match (differ.previous.column_type_family(), differ.next.column_type_family()) {
-            (a, b) if a == b => None,
+            // Changing the declared type changes the affinity of the column.
+            (a, b) if a == b => match (previous_type, next_type) {
+                (Some(previous), Some(next)) if previous != next => Some(ColumnTypeChange::SafeCast),
+                _ => None,
+            },
             (_, ColumnTypeFamily::String) => Some(ColumnTypeChange::SafeCast),
             (_, _) => Some(ColumnTypeChange::RiskyCast),
         }
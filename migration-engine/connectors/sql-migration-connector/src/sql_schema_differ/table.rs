@@ -1,21 +1,29 @@
 use super::column::ColumnDiffer;
 use crate::{flavour::SqlFlavour, pair::Pair};
+use migration_connector::RenameHint;
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
     PrimaryKey,
 };
 
+/// The storage engine the describer reports as no engine.
+const DEFAULT_ENGINE: &str = "InnoDB";
+/// The row format the describer reports as no row format.
+const DEFAULT_ROW_FORMAT: &str = "DEFAULT";
+
 pub(crate) struct TableDiffer<'a> {
     pub(crate) flavour: &'a dyn SqlFlavour,
+    pub(crate) rename_hints: &'a [RenameHint],
     pub(crate) tables: Pair<TableWalker<'a>>,
 }
 
 impl<'schema> TableDiffer<'schema> {
     pub(crate) fn column_pairs<'a>(&'a self) -> impl Iterator<Item = ColumnDiffer<'schema>> + 'a {
         self.previous_columns()
+            .filter(|previous_column| !previous_column.is_computed())
             .filter_map(move |previous_column| {
                 self.next_columns()
-                    .find(|next_column| columns_match(&previous_column, next_column))
+                    .find(|next_column| self.columns_match(&previous_column, next_column))
                     .map(|next_column| (previous_column, next_column))
             })
             .map(move |(previous, next)| ColumnDiffer {
@@ -25,18 +33,21 @@ impl<'schema> TableDiffer<'schema> {
             })
     }
 
+    /// Computed columns are managed by the database, so they are neither altered nor dropped.
     pub(crate) fn dropped_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.previous_columns().filter(move |previous_column| {
-            self.next_columns()
-                .find(|next_column| columns_match(previous_column, next_column))
-                .is_none()
+            !previous_column.is_computed()
+                && self
+                    .next_columns()
+                    .find(|next_column| self.columns_match(previous_column, next_column))
+                    .is_none()
         })
     }
 
     pub(crate) fn added_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.next_columns().filter(move |next_column| {
             self.previous_columns()
-                .find(|previous_column| columns_match(previous_column, next_column))
+                .find(|previous_column| self.columns_match(previous_column, next_column))
                 .is_none()
         })
     }
@@ -57,6 +68,14 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    pub(crate) fn foreign_key_pairs<'a>(&'a self) -> impl Iterator<Item = Pair<ForeignKeyWalker<'schema>>> + 'a {
+        self.previous_foreign_keys().filter_map(move |previous_fk| {
+            self.next_foreign_keys()
+                .find(|next_fk| super::foreign_keys_match(&previous_fk, next_fk))
+                .map(|next_fk| Pair::new(previous_fk, next_fk))
+        })
+    }
+
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = IndexWalker<'schema>> + 'a {
         self.next_indexes().filter(move |next_index| {
             !self
@@ -85,7 +104,12 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (None, Some(pk)) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(next_pk),
+            (Some(previous_pk), Some(next_pk))
+                if !self.primary_key_columns_match(previous_pk, next_pk)
+                    || previous_pk.clustered != next_pk.clustered =>
+            {
+                Some(next_pk)
+            }
             (Some(previous_pk), Some(next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(next_pk)
@@ -101,7 +125,12 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn dropped_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (Some(pk), None) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(previous_pk),
+            (Some(previous_pk), Some(next_pk))
+                if !self.primary_key_columns_match(previous_pk, next_pk)
+                    || previous_pk.clustered != next_pk.clustered =>
+            {
+                Some(previous_pk)
+            }
             (Some(previous_pk), Some(_next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(previous_pk)
@@ -113,6 +142,23 @@ impl<'schema> TableDiffer<'schema> {
         }
     }
 
+    /// Whether the primary keys have the same columns, taking renamed columns into account.
+    fn primary_key_columns_match(&self, previous_pk: &PrimaryKey, next_pk: &PrimaryKey) -> bool {
+        let table = self.next().name();
+
+        previous_pk.columns.len() == next_pk.columns.len()
+            && previous_pk
+                .columns
+                .iter()
+                .zip(next_pk.columns.iter())
+                .all(|(previous, next)| {
+                    let next =
+                        RenameHint::previous_column_name(self.rename_hints, table, next).unwrap_or(next.as_str());
+
+                    previous.as_str() == next
+                })
+    }
+
     /// Returns true if any of the columns of the primary key changed type.
     fn primary_key_column_changed(&self, previous_pk: &PrimaryKey) -> bool {
         self.column_pairs()
@@ -125,6 +171,45 @@ impl<'schema> TableDiffer<'schema> {
             .any(|columns| columns.all_changes().0.type_changed())
     }
 
+    /// The storage engine to switch the table to, if it changed. Tables without an
+    /// explicit `@@engine` keep the engine they have.
+    pub(crate) fn engine_change(&self) -> Option<&'schema str> {
+        let previous = self.previous().engine().unwrap_or(DEFAULT_ENGINE);
+        let next = self.next().engine()?;
+
+        Some(next).filter(|next| !next.eq_ignore_ascii_case(previous))
+    }
+
+    /// The row format to switch the table to, if it changed. Tables without an
+    /// explicit `@@rowFormat` keep the row format they have.
+    pub(crate) fn row_format_change(&self) -> Option<&'schema str> {
+        let previous = self.previous().row_format().unwrap_or(DEFAULT_ROW_FORMAT);
+        let next = self.next().row_format()?;
+
+        Some(next).filter(|next| !next.eq_ignore_ascii_case(previous))
+    }
+
+    /// Whether the columns are the same column. Columns are matched by name, unless the next
+    /// column was renamed according to the rename hints.
+    fn columns_match(&self, previous: &ColumnWalker<'_>, next: &ColumnWalker<'_>) -> bool {
+        let table = self.next().name();
+
+        match RenameHint::previous_column_name(self.rename_hints, table, next.name()) {
+            Some(previous_name) => previous.name() == previous_name,
+            None => {
+                previous.name() == next.name()
+                    && !self.rename_hints.iter().any(|hint| match hint {
+                        RenameHint::Column {
+                            table: hint_table,
+                            previous: renamed,
+                            ..
+                        } => hint_table == table && renamed == previous.name(),
+                        RenameHint::Table { .. } => false,
+                    })
+            }
+        }
+    }
+
     fn previous_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.previous().columns()
     }
@@ -158,11 +243,11 @@ impl<'schema> TableDiffer<'schema> {
     }
 }
 
-pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool {
-    a.name() == b.name()
-}
-
 /// Compare two SQL indexes and return whether they only differ by name.
 fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+    first.column_names() == second.column_names()
+        && first.index_type() == second.index_type()
+        && first.clustered() == second.clustered()
+        && first.deferrable() == second.deferrable()
+        && first.method() == second.method()
 }
@@ -36,6 +36,7 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn version(&self, input: &serde_json::Value) -> CoreResult<String>;
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
     async fn apply_script(&self, input: &ApplyScriptInput) -> CoreResult<ApplyScriptOutput>;
+    async fn check_permissions(&self, input: &CheckPermissionsInput) -> CoreResult<()>;
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
     async fn debug_panic(&self, input: &()) -> CoreResult<()>;
     async fn dev_diagnostic(&self, input: &DevDiagnosticInput) -> CoreResult<DevDiagnosticOutput>;
@@ -44,6 +45,10 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &DiagnoseMigrationHistoryInput,
     ) -> CoreResult<DiagnoseMigrationHistoryOutput>;
     async fn evaluate_data_loss(&self, input: &EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput>;
+    async fn get_migrations_metadata(
+        &self,
+        input: &GetMigrationsMetadataInput,
+    ) -> CoreResult<GetMigrationsMetadataOutput>;
     async fn list_migration_directories(
         &self,
         input: &ListMigrationDirectoriesInput,
@@ -55,8 +60,9 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &MarkMigrationRolledBackInput,
     ) -> CoreResult<MarkMigrationRolledBackOutput>;
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
-    async fn reset(&self, input: &()) -> CoreResult<()>;
+    async fn reset(&self, input: &ResetInput) -> CoreResult<()>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn seed(&self, input: &SeedInput) -> CoreResult<SeedOutput>;
 }
 
 #[async_trait::async_trait]
@@ -79,6 +85,15 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .await
     }
 
+    async fn check_permissions(&self, input: &CheckPermissionsInput) -> CoreResult<()> {
+        self.handle_command::<CheckPermissionsCommand>(input)
+            .instrument(tracing::info_span!(
+                "CheckPermissions",
+                shadow_database = input.shadow_database
+            ))
+            .await
+    }
+
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
         self.handle_command::<CreateMigrationCommand>(input)
             .instrument(tracing::info_span!(
@@ -116,6 +131,15 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .await
     }
 
+    async fn get_migrations_metadata(
+        &self,
+        input: &GetMigrationsMetadataInput,
+    ) -> CoreResult<GetMigrationsMetadataOutput> {
+        self.handle_command::<GetMigrationsMetadataCommand>(input)
+            .instrument(tracing::info_span!("GetMigrationsMetadata"))
+            .await
+    }
+
     async fn list_migration_directories(
         &self,
         input: &ListMigrationDirectoriesInput,
@@ -155,7 +179,7 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .await
     }
 
-    async fn reset(&self, input: &()) -> CoreResult<()> {
+    async fn reset(&self, input: &ResetInput) -> CoreResult<()> {
         self.handle_command::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
             .await
@@ -166,4 +190,13 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
+
+    async fn seed(&self, input: &SeedInput) -> CoreResult<SeedOutput> {
+        self.handle_command::<SeedCommand>(input)
+            .instrument(tracing::info_span!(
+                "Seed",
+                seed_file_path = input.seed_file_path.as_str()
+            ))
+            .await
+    }
 }
@@ -1,5 +1,8 @@
 use super::error_rendering::render_jsonrpc_error;
-use crate::{CoreError, CoreResult, GenericApi};
+use crate::{
+    commands::{CheckPermissionsInput, ResetInput},
+    CoreError, CoreResult, GenericApi,
+};
 use enumflags2::BitFlags;
 use futures::{FutureExt, TryFutureExt};
 use jsonrpc_core::{types::error::Error as JsonRpcError, IoHandler, Params};
@@ -15,18 +18,21 @@ pub struct RpcApi {
 enum RpcCommand {
     ApplyMigrations,
     ApplyScript,
+    CheckPermissions,
     CreateMigration,
     DebugPanic,
     DevDiagnostic,
     DiagnoseMigrationHistory,
     EvaluateDataLoss,
     GetDatabaseVersion,
+    GetMigrationsMetadata,
     ListMigrationDirectories,
     MarkMigrationApplied,
     MarkMigrationRolledBack,
     PlanMigration,
     Reset,
     SchemaPush,
+    Seed,
 }
 
 impl RpcCommand {
@@ -34,18 +40,21 @@ impl RpcCommand {
         match self {
             RpcCommand::ApplyMigrations => "applyMigrations",
             RpcCommand::ApplyScript => "applyScript",
+            RpcCommand::CheckPermissions => "checkPermissions",
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
             RpcCommand::DevDiagnostic => "devDiagnostic",
             RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
             RpcCommand::EvaluateDataLoss => "evaluateDataLoss",
             RpcCommand::GetDatabaseVersion => "getDatabaseVersion",
+            RpcCommand::GetMigrationsMetadata => "getMigrationsMetadata",
             RpcCommand::ListMigrationDirectories => "listMigrationDirectories",
             RpcCommand::MarkMigrationApplied => "markMigrationApplied",
             RpcCommand::MarkMigrationRolledBack => "markMigrationRolledBack",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::Seed => "seed",
         }
     }
 }
@@ -53,18 +62,21 @@ impl RpcCommand {
 const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::ApplyMigrations,
     RpcCommand::ApplyScript,
+    RpcCommand::CheckPermissions,
     RpcCommand::CreateMigration,
     RpcCommand::DebugPanic,
     RpcCommand::DevDiagnostic,
     RpcCommand::DiagnoseMigrationHistory,
     RpcCommand::EvaluateDataLoss,
     RpcCommand::GetDatabaseVersion,
+    RpcCommand::GetMigrationsMetadata,
     RpcCommand::ListMigrationDirectories,
     RpcCommand::MarkMigrationApplied,
     RpcCommand::MarkMigrationRolledBack,
     RpcCommand::PlanMigration,
     RpcCommand::Reset,
     RpcCommand::SchemaPush,
+    RpcCommand::Seed,
 ];
 
 impl RpcApi {
@@ -117,6 +129,15 @@ impl RpcApi {
         Ok(match cmd {
             RpcCommand::ApplyScript => render(executor.apply_script(&params.parse()?).await?),
             RpcCommand::ApplyMigrations => render(executor.apply_migrations(&params.parse()?).await?),
+            RpcCommand::CheckPermissions => {
+                // The input is optional, the shadow database is not checked by default.
+                let input = match params {
+                    Params::None => CheckPermissionsInput::default(),
+                    params => params.parse()?,
+                };
+
+                render(executor.check_permissions(&input).await?)
+            }
             RpcCommand::CreateMigration => render(executor.create_migration(&params.parse()?).await?),
             RpcCommand::DevDiagnostic => render(executor.dev_diagnostic(&params.parse()?).await?),
             RpcCommand::DebugPanic => render(executor.debug_panic(&()).await?),
@@ -125,14 +146,24 @@ impl RpcApi {
             }
             RpcCommand::EvaluateDataLoss => render(executor.evaluate_data_loss(&params.parse()?).await?),
             RpcCommand::GetDatabaseVersion => render(executor.version(&serde_json::Value::Null).await?),
+            RpcCommand::GetMigrationsMetadata => render(executor.get_migrations_metadata(&params.parse()?).await?),
             RpcCommand::ListMigrationDirectories => {
                 render(executor.list_migration_directories(&params.parse()?).await?)
             }
             RpcCommand::MarkMigrationApplied => render(executor.mark_migration_applied(&params.parse()?).await?),
             RpcCommand::MarkMigrationRolledBack => render(executor.mark_migration_rolled_back(&params.parse()?).await?),
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
-            RpcCommand::Reset => render(executor.reset(&()).await?),
+            RpcCommand::Reset => {
+                // The input is optional, for backwards compatibility.
+                let input = match params {
+                    Params::None => ResetInput::default(),
+                    params => params.parse()?,
+                };
+
+                render(executor.reset(&input).await?)
+            }
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
+            RpcCommand::Seed => render(executor.seed(&params.parse()?).await?),
         })
     }
 }
@@ -5,6 +5,7 @@
 
 mod apply_migrations;
 mod apply_script;
+mod check_permissions;
 mod command;
 mod create_migration;
 mod debug_panic;
@@ -12,15 +13,18 @@ mod dev_diagnostic;
 mod diagnose_migration_history;
 mod evaluate_data_loss;
 mod get_database_version;
+mod get_migrations_metadata;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod plan_migration;
 mod reset;
 mod schema_push;
+mod seed;
 
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
 pub use apply_script::{ApplyScriptCommand, ApplyScriptInput, ApplyScriptOutput};
+pub use check_permissions::{CheckPermissionsCommand, CheckPermissionsInput};
 pub use command::MigrationCommand;
 pub use create_migration::{CreateMigrationCommand, CreateMigrationInput, CreateMigrationOutput};
 pub use debug_panic::DebugPanicCommand;
@@ -31,11 +35,15 @@ pub use diagnose_migration_history::{
 };
 pub use evaluate_data_loss::*;
 pub use get_database_version::*;
+pub use get_migrations_metadata::{
+    GetMigrationsMetadataCommand, GetMigrationsMetadataInput, GetMigrationsMetadataOutput, MigrationMetadata,
+};
 pub use list_migration_directories::*;
 pub use mark_migration_applied::{MarkMigrationAppliedCommand, MarkMigrationAppliedInput, MarkMigrationAppliedOutput};
 pub use mark_migration_rolled_back::{
     MarkMigrationRolledBackCommand, MarkMigrationRolledBackInput, MarkMigrationRolledBackOutput,
 };
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
-pub use reset::ResetCommand;
+pub use reset::{ResetCommand, ResetInput};
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use seed::{SeedCommand, SeedInput, SeedOutput};
@@ -86,7 +86,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 .record_migration_started(unapplied_migration.migration_name(), &script)
                 .await?;
 
-            match applier.apply_script(&script).await {
+            match applier.apply_migration_script(&script).await {
                 Ok(()) => {
                     tracing::debug!("Successfully applied the script.");
                     migration_persistence.record_successful_step(&migration_id).await?;
@@ -96,11 +96,18 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 Err(err) => {
                     tracing::debug!("Failed to apply the script.");
 
-                    let logs = err.to_string();
+                    let logs = err.logs();
 
-                    migration_persistence.record_failed_step(&migration_id, &logs).await?;
+                    match err.failed_statement_index {
+                        Some(index) if !err.rolled_back => {
+                            migration_persistence
+                                .record_failed_statement(&migration_id, index, &logs)
+                                .await?
+                        }
+                        _ => migration_persistence.record_failed_step(&migration_id, &logs).await?,
+                    }
 
-                    return Err(err.into());
+                    return Err(err.error.into());
                 }
             }
         }
@@ -0,0 +1,29 @@
+use crate::{api::MigrationApi, commands::command::MigrationCommand, CoreResult};
+use migration_connector::MigrationConnector;
+use serde::Deserialize;
+
+/// The input to the `checkPermissions` command.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckPermissionsInput {
+    /// Whether to check that the database user can create the shadow database, as needed to
+    /// create migrations.
+    #[serde(default)]
+    pub shadow_database: bool,
+}
+
+/// Check that the database user has the privileges to migrate the database, before running other
+/// commands. A missing privilege is returned as a `MissingDatabasePrivilege` error naming it.
+pub struct CheckPermissionsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for CheckPermissionsCommand {
+    type Input = CheckPermissionsInput;
+    type Output = ();
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        engine.connector().check_permissions(input.shadow_database).await?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreError, CoreResult};
-use migration_connector::{DatabaseMigrationMarker, MigrationConnector};
+use migration_connector::{DatabaseMigrationMarker, MigrationConnector, MigrationSummary, RenameHint};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use user_facing_errors::migration_engine::MigrationNameTooLong;
@@ -20,6 +20,9 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// The tables and columns that were renamed since the last migration.
+    #[serde(default)]
+    pub rename_hints: Vec<RenameHint>,
 }
 
 /// The output of the `createMigration` command.
@@ -28,6 +31,8 @@ pub struct CreateMigrationInput {
 pub struct CreateMigrationOutput {
     /// The name of the newly generated migration directory, if any.
     pub generated_migration_name: Option<String>,
+    /// The steps and warnings of the generated migration, if any.
+    pub summary: Option<MigrationSummary>,
 }
 
 #[async_trait::async_trait]
@@ -50,12 +55,13 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = database_migration_inferrer
-            .infer_next_migration(&previous_migrations, &target_schema)
+            .infer_next_migration(&previous_migrations, &target_schema, &input.rename_hints)
             .await?;
 
         if migration.is_empty() && !input.draft {
             return Ok(CreateMigrationOutput {
                 generated_migration_name: None,
+                summary: None,
             });
         }
 
@@ -81,6 +87,10 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
+            summary: Some(MigrationSummary::new(
+                applier.summarize_steps(&migration),
+                &destructive_change_diagnostics,
+            )),
         })
     }
 }
@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreResult};
-use migration_connector::{list_migrations, MigrationConnector};
+use migration_connector::{list_migrations, MigrationConnector, RenameHint};
 use serde::{Deserialize, Serialize};
 
 /// Development command for migrations. Evaluate the data loss induced by the
@@ -18,6 +18,9 @@ pub struct EvaluateDataLossInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// The tables and columns that were renamed since the last migration.
+    #[serde(default)]
+    pub rename_hints: Vec<RenameHint>,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -60,7 +63,7 @@ impl MigrationCommand for EvaluateDataLoss {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = inferrer
-            .infer_next_migration(&migrations_from_directory, &target_schema)
+            .infer_next_migration(&migrations_from_directory, &target_schema, &input.rename_hints)
             .await?;
 
         let rendered_migration_steps = applier
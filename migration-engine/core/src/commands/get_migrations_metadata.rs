@@ -0,0 +1,136 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreResult};
+use migration_connector::{
+    split_sql_statements, ConnectorError, MigrationConnector, MigrationRecord, PersistenceNotInitializedError,
+    Timestamp,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `GetMigrationsMetadata` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMigrationsMetadataInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `GetMigrationsMetadata` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMigrationsMetadataOutput {
+    /// The migrations of the migrations directory, in order, followed by the
+    /// migrations that are only found in the database.
+    pub migrations: Vec<MigrationMetadata>,
+    /// Is the migrations table initialized in the database.
+    pub has_migrations_table: bool,
+}
+
+/// What is known about a migration, from the migrations directory and from the
+/// migrations table.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationMetadata {
+    /// The name of the migration, i.e. the name of its directory.
+    pub migration_name: String,
+    /// The SHA-256 checksum of the migration script in the migrations
+    /// directory. `None` if the migration is not in the migrations directory.
+    pub checksum: Option<String>,
+    /// The number of statements in the migration script. `None` if the
+    /// migration is not in the migrations directory.
+    pub statements_count: Option<usize>,
+    /// The checksum of the script that was applied, as recorded in the
+    /// database. `None` if the migration was never applied.
+    pub applied_checksum: Option<String>,
+    /// When the migration started being applied.
+    pub started_at: Option<Timestamp>,
+    /// When the migration completed successfully.
+    pub finished_at: Option<Timestamp>,
+    /// When the migration was rolled back.
+    pub rolled_back_at: Option<Timestamp>,
+    /// The number of steps that were successfully applied.
+    pub applied_steps_count: Option<u32>,
+}
+
+impl MigrationMetadata {
+    fn new(migration_name: String) -> Self {
+        MigrationMetadata {
+            migration_name,
+            checksum: None,
+            statements_count: None,
+            applied_checksum: None,
+            started_at: None,
+            finished_at: None,
+            rolled_back_at: None,
+            applied_steps_count: None,
+        }
+    }
+
+    fn record_application(&mut self, record: &MigrationRecord) {
+        self.applied_checksum = Some(record.checksum.clone());
+        self.started_at = Some(record.started_at);
+        self.finished_at = record.finished_at;
+        self.rolled_back_at = record.rolled_back_at;
+        self.applied_steps_count = Some(record.applied_steps_count);
+    }
+}
+
+/// Returns the metadata of every migration in the migrations directory and in
+/// the migrations table, so status UIs do not have to read the migrations
+/// directory and table themselves. A migration that was applied several times
+/// is described by its latest application. This command does not write
+/// anything.
+pub struct GetMigrationsMetadataCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for GetMigrationsMetadataCommand {
+    type Input = GetMigrationsMetadataInput;
+    type Output = GetMigrationsMetadataOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let migration_persistence = engine.connector().new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        let (migrations_from_database, has_migrations_table) = match migration_persistence.list_migrations().await? {
+            Ok(migrations) => (migrations, true),
+            Err(PersistenceNotInitializedError {}) => (vec![], false),
+        };
+
+        let mut migrations = Vec::with_capacity(migrations_from_filesystem.len());
+
+        for fs_migration in &migrations_from_filesystem {
+            let script = fs_migration.read_migration_script().map_err(ConnectorError::from)?;
+
+            let mut metadata = MigrationMetadata::new(fs_migration.migration_name().to_owned());
+            metadata.checksum = Some(migration_connector::checksum(&script));
+            metadata.statements_count = Some(split_sql_statements(&script).len());
+
+            migrations.push(metadata);
+        }
+
+        // The migrations are listed in the order they were started, so later
+        // records override earlier ones.
+        for db_migration in &migrations_from_database {
+            let existing = migrations
+                .iter_mut()
+                .find(|metadata| metadata.migration_name == db_migration.migration_name);
+
+            match existing {
+                Some(metadata) => metadata.record_application(db_migration),
+                None => {
+                    let mut metadata = MigrationMetadata::new(db_migration.migration_name.clone());
+                    metadata.record_application(db_migration);
+
+                    migrations.push(metadata);
+                }
+            }
+        }
+
+        Ok(GetMigrationsMetadataOutput {
+            migrations,
+            has_migrations_table,
+        })
+    }
+}
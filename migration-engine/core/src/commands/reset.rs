@@ -1,21 +1,30 @@
 use crate::{api::MigrationApi, commands::command::MigrationCommand, CoreResult};
 use migration_connector::MigrationConnector;
+use serde::Deserialize;
+
+/// The input to the `reset` command.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetInput {
+    /// The names of tables that should survive the reset, for example tables
+    /// managed by another tool. Foreign keys from these tables to dropped
+    /// tables are dropped.
+    #[serde(default)]
+    pub keep_tables: Vec<String>,
+}
 
 /// The `reset` command.
 pub struct ResetCommand;
 
 #[async_trait::async_trait]
 impl<'a> MigrationCommand for ResetCommand {
-    type Input = ();
+    type Input = ResetInput;
     type Output = ();
 
-    async fn execute<C: MigrationConnector>(
-        _input: &Self::Input,
-        engine: &MigrationApi<C>,
-    ) -> CoreResult<Self::Output> {
-        tracing::debug!("Resetting the database.");
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        tracing::debug!(keep_tables = ?input.keep_tables, "Resetting the database.");
 
-        engine.connector().reset().await?;
+        engine.connector().reset(&input.keep_tables).await?;
 
         Ok(())
     }
@@ -0,0 +1,293 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreError, CoreResult};
+use migration_connector::{ImperativeMigrationsPersistence, MigrationRecord, SEED_MIGRATION_NAME};
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use std::{fmt, path::Path};
+use user_facing_errors::migration_engine::SeedStatementFailed;
+
+/// The input to the `seed` command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedInput {
+    /// The path to the seed file. Files with a `.json` extension are data
+    /// files: an object mapping table names to arrays of rows. Any other file
+    /// is treated as a SQL script.
+    pub seed_file_path: String,
+    /// Apply the seed file even if the same file was already applied to the
+    /// database.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// The output of the `seed` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedOutput {
+    /// Whether the seed file was applied. This is false when the same file
+    /// was already applied, and `force` was not set.
+    pub applied: bool,
+    /// The number of statements (or rows, for data files) that were applied.
+    pub statements_count: usize,
+}
+
+/// Apply a seed file to the database, typically after `reset` or `dev`.
+///
+/// Every application is recorded in the migrations table under
+/// `SEED_MIGRATION_NAME`, with the checksum of the seed file. A seed file that
+/// already completed successfully is not applied again unless `force` is set.
+/// Statements are applied one after the other, in a transaction where the
+/// database supports it, and the first failing statement is reported in a
+/// `SeedStatementFailed` error.
+pub struct SeedCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for SeedCommand {
+    type Input = SeedInput;
+    type Output = SeedOutput;
+
+    async fn execute<C>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector,
+    {
+        let connector = engine.connector();
+        let applier = connector.database_migration_step_applier();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let content = std::fs::read_to_string(&input.seed_file_path).map_err(|err| {
+            CoreError::Generic(
+                anyhow::Error::new(err).context(format!("Could not read the seed file at `{}`", input.seed_file_path)),
+            )
+        })?;
+
+        let statements = parse_seed_file(&input.seed_file_path, &content)?;
+        let previous_seeds = list_seeds(migration_persistence).await?;
+        let checksum = migration_connector::checksum(&content);
+
+        if !input.force && previous_seeds.iter().any(|seed| seed_was_applied(seed, &checksum)) {
+            tracing::info!(
+                "The seed file `{}` was already applied, skipping.",
+                input.seed_file_path
+            );
+
+            return Ok(SeedOutput {
+                applied: false,
+                statements_count: 0,
+            });
+        }
+
+        let seed_id = migration_persistence
+            .record_migration_started(SEED_MIGRATION_NAME, &content)
+            .await?;
+
+        let sql_statements: Vec<&str> = statements
+            .iter()
+            .filter_map(|statement| match statement {
+                SeedStatement::Sql(sql) => Some(sql.as_str()),
+                SeedStatement::Row { .. } => None,
+            })
+            .collect();
+
+        // The steps are recorded in the transaction too, so a rolled back seed
+        // is recorded without applied steps.
+        let in_transaction = applier.begin_seed_transaction(&sql_statements).await?;
+
+        for (idx, statement) in statements.iter().enumerate() {
+            let result = match statement {
+                SeedStatement::Sql(sql) => applier.apply_script(sql).await,
+                SeedStatement::Row { table, row } => applier.insert_seed_row(table, row).await,
+            };
+
+            match result {
+                Ok(()) => migration_persistence.record_successful_step(&seed_id).await?,
+                Err(err) => {
+                    let database_error = err.to_string();
+
+                    if in_transaction {
+                        applier.end_seed_transaction(false).await?;
+                    }
+
+                    migration_persistence
+                        .record_failed_step(&seed_id, &database_error)
+                        .await?;
+
+                    return Err(CoreError::user_facing(SeedStatementFailed {
+                        seed_file_path: input.seed_file_path.clone(),
+                        statement_number: idx + 1,
+                        statement: statement.to_string(),
+                        database_error,
+                    }));
+                }
+            }
+        }
+
+        if in_transaction {
+            applier.end_seed_transaction(true).await?;
+        }
+
+        migration_persistence.record_migration_finished(&seed_id).await?;
+
+        Ok(SeedOutput {
+            applied: true,
+            statements_count: statements.len(),
+        })
+    }
+}
+
+async fn list_seeds(migration_persistence: &dyn ImperativeMigrationsPersistence) -> CoreResult<Vec<MigrationRecord>> {
+    match migration_persistence.list_seeds().await? {
+        Ok(seeds) => Ok(seeds),
+        Err(_) => {
+            // The seed can run before any migration was applied (e.g. after a
+            // reset), so the migrations table may not exist yet.
+            migration_persistence.baseline_initialize().await?;
+
+            Ok(Vec::new())
+        }
+    }
+}
+
+fn seed_was_applied(seed: &MigrationRecord, checksum: &str) -> bool {
+    seed.checksum == checksum && seed.finished_at.is_some() && seed.rolled_back_at.is_none()
+}
+
+#[derive(Debug, PartialEq)]
+enum SeedStatement {
+    Sql(String),
+    Row {
+        table: String,
+        row: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+impl std::fmt::Display for SeedStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedStatement::Sql(sql) => f.write_str(sql),
+            SeedStatement::Row { table, row } => {
+                write!(f, "Insert into `{}`: {}", table, serde_json::Value::Object(row.clone()))
+            }
+        }
+    }
+}
+
+fn parse_seed_file(path: &str, content: &str) -> CoreResult<Vec<SeedStatement>> {
+    let is_data_file = Path::new(path)
+        .extension()
+        .map(|ext| ext.eq_ignore_ascii_case("json"))
+        .unwrap_or(false);
+
+    if is_data_file {
+        parse_data_file(content)
+            .map_err(|err| CoreError::Generic(anyhow::anyhow!("The seed file at `{}` is not valid: {}", path, err)))
+    } else {
+        Ok(migration_connector::split_sql_statements(content)
+            .into_iter()
+            .map(|statement| SeedStatement::Sql(statement.to_owned()))
+            .collect())
+    }
+}
+
+/// Data files map table names to arrays of rows. Tables and rows are inserted
+/// in the order they appear in the file, so parent tables must come before the
+/// tables referencing them.
+fn parse_data_file(content: &str) -> Result<Vec<SeedStatement>, String> {
+    let DataFileTables(tables) = serde_json::from_str(content).map_err(|err| err.to_string())?;
+
+    let mut statements = Vec::new();
+
+    for (table, rows) in tables {
+        let rows = match rows {
+            serde_json::Value::Array(rows) => rows,
+            _ => return Err(format!("The rows of `{}` must be an array.", table)),
+        };
+
+        for row in rows {
+            match row {
+                serde_json::Value::Object(row) => statements.push(SeedStatement::Row {
+                    table: table.clone(),
+                    row,
+                }),
+                _ => return Err(format!("The rows of `{}` must be objects.", table)),
+            }
+        }
+    }
+
+    Ok(statements)
+}
+
+/// The tables of a data file with their rows, in the order of the file. The
+/// maps of serde_json are sorted by key.
+struct DataFileTables(Vec<(String, serde_json::Value)>);
+
+impl<'de> Deserialize<'de> for DataFileTables {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TablesVisitor;
+
+        impl<'de> Visitor<'de> for TablesVisitor {
+            type Value = DataFileTables;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an object mapping table names to arrays of rows")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut tables = Vec::new();
+
+                while let Some(table) = map.next_entry()? {
+                    tables.push(table);
+                }
+
+                Ok(DataFileTables(tables))
+            }
+        }
+
+        deserializer.deserialize_map(TablesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_data_file_works() {
+        let content = r#"{ "Cat": [{ "name": "Mittens" }, { "name": "Felix", "age": 3 }], "Dog": [] }"#;
+        let statements = parse_data_file(content).unwrap();
+
+        assert_eq!(statements.len(), 2);
+
+        match &statements[1] {
+            SeedStatement::Row { table, row } => {
+                assert_eq!(table, "Cat");
+                assert_eq!(row["name"], "Felix");
+                assert_eq!(row["age"], 3);
+            }
+            other => panic!("Expected a row, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_data_file_keeps_the_order_of_the_tables() {
+        let content = r#"{ "User": [{ "id": 1 }], "Post": [{ "id": 1, "authorId": 1 }], "Comment": [] }"#;
+        let tables: Vec<String> = parse_data_file(content)
+            .unwrap()
+            .into_iter()
+            .map(|statement| match statement {
+                SeedStatement::Row { table, .. } => table,
+                other => panic!("Expected a row, got {:?}", other),
+            })
+            .collect();
+
+        assert_eq!(tables, &["User", "Post"]);
+    }
+
+    #[test]
+    fn parse_data_file_rejects_non_array_rows() {
+        let err = parse_data_file(r#"{ "Cat": { "name": "Mittens" } }"#).unwrap_err();
+
+        assert_eq!(err, "The rows of `Cat` must be an array.");
+    }
+}
@@ -1,3 +1,4 @@
+use datamodel::diagnostics::Diagnostics;
 use migration_connector::{ConnectorError, ListMigrationsError};
 use std::{error::Error as StdError, fmt::Display};
 use user_facing_errors::{KnownError, UserFacingError};
@@ -8,8 +9,9 @@ pub type CoreResult<T> = Result<T, CoreError>;
 /// The top-level error type for migration engine commands.
 #[derive(Debug)]
 pub enum CoreError {
-    /// When there was a bad datamodel as part of the input.
-    ReceivedBadDatamodel(String),
+    /// When there was a bad datamodel as part of the input. Holds the rendered errors and the
+    /// diagnostics they were rendered from.
+    ReceivedBadDatamodel(String, Diagnostics),
 
     /// Errors from the connector.
     ConnectorError(ConnectorError),
@@ -27,7 +29,7 @@ pub enum CoreError {
 impl Display for CoreError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CoreError::ReceivedBadDatamodel(err) => write!(f, "{}", err),
+            CoreError::ReceivedBadDatamodel(err, _) => write!(f, "{}", err),
             CoreError::ConnectorError(err) => write!(f, "Connector error: {:#}", err),
             CoreError::GatedPreviewFeatures(features) => {
                 let feats: Vec<_> = features.iter().map(|f| format!("`{}`", f)).collect();
@@ -43,7 +45,7 @@ impl Display for CoreError {
 impl StdError for CoreError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
-            CoreError::ReceivedBadDatamodel(_) => None,
+            CoreError::ReceivedBadDatamodel(_, _) => None,
             CoreError::GatedPreviewFeatures(_) => None,
             CoreError::UserFacing(_) => None,
             CoreError::ConnectorError(err) => Some(err),
@@ -58,8 +60,12 @@ impl CoreError {
         match self {
             CoreError::ConnectorError(err) => err.to_user_facing(),
             CoreError::UserFacing(err) => err.into(),
-            CoreError::ReceivedBadDatamodel(full_error) => {
-                KnownError::new(user_facing_errors::common::SchemaParserError { full_error }).into()
+            CoreError::ReceivedBadDatamodel(full_error, diagnostics) => {
+                KnownError::new(user_facing_errors::common::SchemaParserError {
+                    full_error,
+                    diagnostics: serde_json::to_value(diagnostics.to_structured()).unwrap(),
+                })
+                .into()
             }
             CoreError::GatedPreviewFeatures(features) => {
                 KnownError::new(user_facing_errors::migration_engine::PreviewFeaturesBlocked { features }).into()
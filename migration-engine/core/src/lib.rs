@@ -51,16 +51,18 @@ pub async fn migration_api(
     let connector = match &source.active_provider {
         #[cfg(feature = "sql")]
         provider if POSTGRES_SOURCE_NAME == provider => {
-            let database_str = &source.url().value;
-
-            let mut u = url::Url::parse(database_str).map_err(|err| {
+            let database_str = &source.direct_url_or_url().value;
+            let invalid_url = |err: &dyn std::fmt::Display| {
                 let details = user_facing_errors::quaint::invalid_url_description(
                     database_str,
                     &format!("Error parsing connection string: {}", err),
                 );
 
                 ConnectorError::from(KnownError::new(InvalidDatabaseString { details }))
-            })?;
+            };
+
+            let database_str = datasource_url::normalize(database_str).map_err(|err| invalid_url(&err))?;
+            let mut u = url::Url::parse(&database_str).map_err(|err| invalid_url(&err))?;
 
             let params: Vec<(String, String)> = u.query_pairs().map(|(k, v)| (k.to_string(), v.to_string())).collect();
 
@@ -78,11 +80,25 @@ pub async fn migration_api(
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str(), features).await?
+            SqlMigrationConnector::new_with_retry_policy(u.as_str(), features, source.retry_policy)
+                .await?
+                .with_referential_integrity(source.referential_integrity)
+                .with_foreign_key_indexes(source.foreign_key_indexes)
+                .with_audit_log(source.audit_log)
+                .with_database_timestamps(source.database_timestamps)
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value, features).await?
+            SqlMigrationConnector::new_with_retry_policy(
+                &source.direct_url_or_url().value,
+                features,
+                source.retry_policy,
+            )
+            .await?
+            .with_referential_integrity(source.referential_integrity)
+            .with_foreign_key_indexes(source.foreign_key_indexes)
+            .with_audit_log(source.audit_log)
+            .with_database_timestamps(source.database_timestamps)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -111,7 +127,7 @@ pub async fn create_database(schema: &str) -> CoreResult<String> {
             ]
             .contains(&provider.as_str()) =>
         {
-            Ok(SqlMigrationConnector::create_database(&source.url().value).await?)
+            Ok(SqlMigrationConnector::create_database(&source.direct_url_or_url().value).await?)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -136,7 +152,7 @@ pub async fn drop_database(schema: &str) -> CoreResult<()> {
             ]
             .contains(&provider.as_str()) =>
         {
-            Ok(SqlMigrationConnector::drop_database(&source.url().value).await?)
+            Ok(SqlMigrationConnector::drop_database(&source.direct_url_or_url().value).await?)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -163,8 +179,13 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
             .contains(&provider.as_str()) =>
         {
             // 1. creates schema & database
-            SqlMigrationConnector::qe_setup(&source.url().value).await?;
-            SqlMigrationConnector::new(&source.url().value, features).await?
+            SqlMigrationConnector::qe_setup(&source.direct_url_or_url().value).await?;
+            SqlMigrationConnector::new(&source.direct_url_or_url().value, features)
+                .await?
+                .with_referential_integrity(source.referential_integrity)
+                .with_foreign_key_indexes(source.foreign_key_indexes)
+                .with_audit_log(source.audit_log)
+                .with_database_timestamps(source.database_timestamps)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -186,13 +207,13 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
 fn parse_configuration(datamodel: &str) -> CoreResult<Configuration> {
     datamodel::parse_configuration(&datamodel)
         .map(|validated_config| validated_config.subject)
-        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
+        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel), err))
 }
 
 fn parse_datamodel(datamodel: &str) -> CoreResult<Datamodel> {
     datamodel::parse_datamodel(&datamodel)
         .map(|d| d.subject)
-        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
+        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel), err))
 }
 
 #[cfg(test)]
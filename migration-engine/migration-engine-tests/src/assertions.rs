@@ -1,7 +1,7 @@
 use pretty_assertions::assert_eq;
 use sql_schema_describer::{
-    Column, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexType,
-    PrimaryKey, SqlSchema, Table,
+    Column, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexMethod,
+    IndexType, PrimaryKey, SqlSchema, Table,
 };
 
 pub(crate) type AssertionResult<T> = Result<T, anyhow::Error>;
@@ -256,6 +256,18 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_engine(self, engine: Option<&str>) -> AssertionResult<Self> {
+        assert_eq!(self.0.engine.as_deref(), engine);
+
+        Ok(self)
+    }
+
+    pub fn assert_row_format(self, row_format: Option<&str>) -> AssertionResult<Self> {
+        assert_eq!(self.0.row_format.as_deref(), row_format);
+
+        Ok(self)
+    }
+
     pub fn assert_index_on_columns<F>(self, columns: &[&str], index_assertions: F) -> AssertionResult<Self>
     where
         F: FnOnce(IndexAssertion<'a>) -> AssertionResult<IndexAssertion<'a>>,
@@ -486,6 +498,12 @@ impl<'a> PrimaryKeyAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_is_clustered(self, clustered: bool) -> AssertionResult<Self> {
+        assert_eq!(self.pk.clustered, Some(clustered));
+
+        Ok(self)
+    }
+
     pub fn assert_has_autoincrement(self) -> AssertionResult<Self> {
         anyhow::ensure!(
             self.table
@@ -541,6 +559,12 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_deferrable(self, deferrable: bool) -> AssertionResult<Self> {
+        assert_eq!(self.0.deferrable, deferrable);
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -563,4 +587,22 @@ impl<'a> IndexAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_clustered(self, clustered: bool) -> AssertionResult<Self> {
+        assert_eq!(self.0.clustered, Some(clustered));
+
+        Ok(self)
+    }
+
+    pub fn assert_is_deferrable(self, deferrable: bool) -> AssertionResult<Self> {
+        assert_eq!(self.0.deferrable, deferrable);
+
+        Ok(self)
+    }
+
+    pub fn assert_method(self, method: Option<IndexMethod>) -> AssertionResult<Self> {
+        assert_eq!(self.0.method, method);
+
+        Ok(self)
+    }
 }
@@ -9,7 +9,7 @@ mod mark_migration_rolled_back;
 mod reset;
 mod schema_push;
 
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 pub use apply_migrations::ApplyMigrations;
 pub use create_migration::CreateMigration;
@@ -31,13 +31,17 @@ use indoc::formatdoc;
 use migration_connector::{ImperativeMigrationsPersistence, MigrationFeature, MigrationRecord};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyScriptInput,
+    commands::{
+        ApplyScriptInput, CheckPermissionsInput, GetMigrationsMetadataInput, GetMigrationsMetadataOutput, SeedInput,
+        SeedOutput,
+    },
+    CoreResult,
 };
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{DriverAdapter, SqlMigrationConnector};
 use sql_schema_describer::*;
 use tempfile::TempDir;
 use test_setup::*;
@@ -118,6 +122,17 @@ impl TestApi {
         ListMigrationDirectories::new(&self.api, migrations_directory)
     }
 
+    pub async fn get_migrations_metadata(
+        &self,
+        migrations_directory: &TempDir,
+    ) -> CoreResult<GetMigrationsMetadataOutput> {
+        self.api
+            .get_migrations_metadata(&GetMigrationsMetadataInput {
+                migrations_directory_path: migrations_directory.path().to_str().unwrap().to_owned(),
+            })
+            .await
+    }
+
     pub async fn apply_script(&self, script: impl Into<String>) -> anyhow::Result<()> {
         self.api
             .apply_script(&ApplyScriptInput { script: script.into() })
@@ -126,6 +141,21 @@ impl TestApi {
         Ok(())
     }
 
+    pub async fn seed(&self, seed_file_path: &std::path::Path) -> CoreResult<SeedOutput> {
+        self.api
+            .seed(&SeedInput {
+                seed_file_path: seed_file_path.to_str().unwrap().to_owned(),
+                force: false,
+            })
+            .await
+    }
+
+    pub async fn check_permissions(&self, shadow_database: bool) -> CoreResult<()> {
+        self.api
+            .check_permissions(&CheckPermissionsInput { shadow_database })
+            .await
+    }
+
     /// Convenient builder and assertions for the CreateMigration command.
     pub fn create_migration<'a>(
         &'a self,
@@ -415,6 +445,57 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
     }
 }
 
+/// A test API on an in-memory SQLite database, which lives as long as the test API.
+pub async fn sqlite_in_memory_test_api() -> TestApi {
+    let connector = SqlMigrationConnector::new("file::memory:?cache=shared", BitFlags::empty())
+        .await
+        .unwrap();
+
+    TestApi {
+        database: connector.quaint().clone(),
+        api: MigrationApi::new(connector),
+        tags: Tags::Sqlite.into(),
+    }
+}
+
+/// A test API on a SQLite database the connector queries through a driver adapter, instead of
+/// its own connection.
+pub async fn sqlite_driver_adapter_test_api(db_name: &str) -> TestApi {
+    let quaint = Quaint::new(&sqlite_test_url(db_name)).await.unwrap();
+    let adapter = QuaintDriverAdapter(quaint.clone());
+    let connector = SqlMigrationConnector::new_with_adapter(Arc::new(adapter), BitFlags::empty())
+        .await
+        .unwrap();
+
+    TestApi {
+        database: quaint,
+        api: MigrationApi::new(connector),
+        tags: Tags::Sqlite.into(),
+    }
+}
+
+/// Forwards the rendered queries to Quaint, the way an embedder forwards them to its driver.
+struct QuaintDriverAdapter(Quaint);
+
+#[async_trait::async_trait]
+impl DriverAdapter for QuaintDriverAdapter {
+    fn connection_info(&self) -> &ConnectionInfo {
+        self.0.connection_info()
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[quaint::Value<'_>]) -> quaint::Result<quaint::prelude::ResultSet> {
+        self.0.query_raw(sql, params).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[quaint::Value<'_>]) -> quaint::Result<u64> {
+        self.0.execute_raw(sql, params).await
+    }
+
+    async fn raw_cmd(&self, sql: &str) -> quaint::Result<()> {
+        self.0.raw_cmd(sql).await
+    }
+}
+
 pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
     mssql_test_api(mssql_2017_url("master"), args).await
 }
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use migration_connector::RenameHint;
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
 use std::path::Path;
@@ -12,6 +13,7 @@ pub struct CreateMigration<'a> {
     migrations_directory: &'a TempDir,
     draft: bool,
     name: &'a str,
+    rename_hints: Vec<RenameHint>,
 }
 
 impl<'a> CreateMigration<'a> {
@@ -22,6 +24,7 @@ impl<'a> CreateMigration<'a> {
             migrations_directory,
             draft: false,
             name,
+            rename_hints: Vec::new(),
         }
     }
 
@@ -31,6 +34,12 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn rename_hint(mut self, rename_hint: RenameHint) -> Self {
+        self.rename_hints.push(rename_hint);
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
@@ -39,6 +48,7 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                rename_hints: self.rename_hints,
             })
             .await?;
 
@@ -167,4 +177,20 @@ impl MigrationAssertion<'_> {
 
         Ok(self)
     }
+
+    /// Assert that the migration script contains `expected_statement`.
+    pub fn assert_contents_contain(self, expected_statement: &str) -> AssertionResult<Self> {
+        let migration_file_path = self.path.join("migration.sql");
+        let contents: String = std::fs::read_to_string(&migration_file_path)
+            .with_context(|| format!("Trying to read migration file at {:?}", migration_file_path))?;
+
+        anyhow::ensure!(
+            contents.contains(expected_statement),
+            "Assertion error. Expected the migration script to contain `{}`, got:\n{}",
+            expected_statement,
+            contents
+        );
+
+        Ok(self)
+    }
 }
@@ -28,6 +28,7 @@ impl<'a> EvaluateDataLoss<'a> {
             .evaluate_data_loss(&EvaluateDataLossInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
                 prisma_schema: self.prisma_schema,
+                rename_hints: Vec::new(),
             })
             .await?;
 
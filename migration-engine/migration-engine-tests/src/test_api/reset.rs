@@ -1,17 +1,30 @@
-use migration_core::GenericApi;
+use migration_core::{commands::ResetInput, GenericApi};
 
 #[must_use = "This struct does nothing on its own. See Reset::send()"]
 pub struct Reset<'a> {
     api: &'a dyn GenericApi,
+    keep_tables: Vec<String>,
 }
 
 impl<'a> Reset<'a> {
     pub fn new(api: &'a dyn GenericApi) -> Self {
-        Reset { api }
+        Reset {
+            api,
+            keep_tables: Vec::new(),
+        }
+    }
+
+    pub fn keep_table(mut self, table_name: impl Into<String>) -> Self {
+        self.keep_tables.push(table_name.into());
+        self
     }
 
     pub async fn send(self) -> anyhow::Result<ResetAssertion<'a>> {
-        self.api.reset(&()).await?;
+        self.api
+            .reset(&ResetInput {
+                keep_tables: self.keep_tables,
+            })
+            .await?;
 
         Ok(ResetAssertion { _api: self.api })
     }
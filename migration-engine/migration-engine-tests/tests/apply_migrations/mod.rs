@@ -124,11 +124,171 @@ async fn migrations_should_fail_when_the_script_is_invalid(api: &TestApi) -> Tes
         .assert_applied_steps_count(1)?
         .assert_success()?;
 
+    // MySQL commits DDL statements implicitly, so the statement adding the
+    // column stays applied. Elsewhere, the whole migration is rolled back.
+    let applied_steps_count = if api.is_mysql() { 1 } else { 0 };
+
     second
         .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(applied_steps_count)?
+        .assert_failed()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        if api.is_mysql() {
+            table.assert_has_column("fluffiness")
+        } else {
+            table.assert_does_not_have_column("fluffiness")
+        }
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn rolled_back_migrations_record_the_failed_statement(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+    let mut statements_count = 0;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| {
+            statements_count = migration_connector::split_sql_statements(script).len();
+            script.push_str("\nSELECT (^.^)_n;\n");
+        })?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    let migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let expected_logs = format!(
+        "Statement {} of the migration script failed, the migration was rolled back.",
+        statements_count + 1
+    );
+
+    assert!(
+        migration
+            .logs
+            .as_deref()
+            .unwrap_or_default()
+            .starts_with(&expected_logs),
+        "{:?}",
+        migration.logs
+    );
+
+    migration
+        .assert_migration_name("initial")?
         .assert_applied_steps_count(0)?
         .assert_failed()?;
 
+    let schema = api.assert_schema().await?.into_schema();
+
+    assert!(schema.tables.iter().all(|table| table.name != "Cat"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_adding_enum_values_are_applied_outside_of_a_transaction(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int  @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int  @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    // The new value can't be used in the transaction that added it.
+    api.create_migration("add-sleepy", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| script.push_str("\nINSERT INTO \"Cat\" (id, mood) VALUES (1, 'SLEEPY');\n"))?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-sleepy"])?;
+
+    api.dump_table("Cat").await?.assert_row_count(1)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn failed_data_statements_are_rolled_back_to_their_savepoint_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| {
+            script.push_str("\nINSERT INTO `Cat` (id, name) VALUES (1, 'Mittens');");
+            script.push_str("\nINSERT INTO `Cat` (id, name) VALUES (2, 'Felix'), (1, 'Mittens again');\n");
+        })?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    let migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    // The table and the first row were applied, the second insert was rolled back.
+    migration
+        .assert_migration_name("initial")?
+        .assert_applied_steps_count(2)?
+        .assert_failed()?;
+
+    api.dump_table("Cat").await?.assert_row_count(1)?;
+
     Ok(())
 }
 
@@ -239,3 +399,36 @@ async fn migrations_should_succeed_on_an_uninitialized_nonempty_database_with_po
 
     Ok(())
 }
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn migration_scripts_are_split_into_batches_on_go_separators(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+    let schema_name = api.schema_name().to_owned();
+
+    // `CREATE VIEW` must be the first statement of its batch.
+    api.create_migration("init", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| {
+            script.push_str(&format!(
+                "\nGO\nCREATE VIEW [{0}].[CatNames] AS SELECT name FROM [{0}].[Cat];\ngo -- end of the view\n",
+                schema_name
+            ))
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["init"])?;
+
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    Ok(())
+}
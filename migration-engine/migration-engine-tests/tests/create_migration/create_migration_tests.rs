@@ -1,5 +1,6 @@
 use crate::*;
 use indoc::indoc;
+use migration_connector::{MigrationStepSummary, RenameHint};
 use migration_engine_tests::{test_each_connector, TestResult};
 
 #[test_each_connector]
@@ -403,3 +404,202 @@ async fn create_enum_renders_correctly(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn create_migration_renames_tables_with_rename_hints(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+    api.apply_migrations(&dir).send().await?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Kitten {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    api.create_migration("rename-cats", dm2, &dir)
+        .rename_hint(RenameHint::Table {
+            previous: "Cat".into(),
+            next: "Kitten".into(),
+        })
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .assert_migration("rename-cats", |migration| match api.sql_family() {
+            SqlFamily::Postgres | SqlFamily::Sqlite => migration.assert_contents(indoc! {
+                r#"
+                -- RenameTable
+                ALTER TABLE "Cat" RENAME TO "Kitten";
+                "#
+            }),
+            SqlFamily::Mysql => migration.assert_contents(indoc! {
+                r#"
+                -- RenameTable
+                ALTER TABLE `Cat` RENAME TO `Kitten`;
+                "#
+            }),
+            SqlFamily::Mssql => {
+                migration.assert_contents_contain(&format!("EXEC SP_RENAME N'{}.Cat', N'Kitten'", api.schema_name()))
+            }
+        })?;
+
+    api.apply_migrations(&dir).send().await?;
+    api.dump_table("Kitten")
+        .await?
+        .assert_single_row(|row| row.assert_int_value("id", 1)?.assert_text_value("name", "Garfield"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_renames_columns_with_rename_hints(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+    api.apply_migrations(&dir).send().await?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            fullName    String
+        }
+    "#;
+
+    api.create_migration("rename-name", dm2, &dir)
+        .rename_hint(RenameHint::Column {
+            table: "Cat".into(),
+            previous: "name".into(),
+            next: "fullName".into(),
+        })
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .assert_migration("rename-name", |migration| match api.sql_family() {
+            SqlFamily::Postgres => migration.assert_contents(indoc! {
+                r#"
+                -- AlterTable
+                ALTER TABLE "Cat" RENAME COLUMN "name" TO "fullName";
+                "#
+            }),
+            SqlFamily::Mysql => migration.assert_contents_contain("CHANGE `name` `fullName`"),
+            SqlFamily::Mssql => migration.assert_contents_contain(&format!(
+                "EXEC SP_RENAME N'{}.Cat.name', N'fullName', N'COLUMN'",
+                api.schema_name()
+            )),
+            // SQLite redefines the table, copying the data of `name` into `fullName`.
+            SqlFamily::Sqlite => migration
+                .assert_contents_contain("-- RedefineTables")?
+                .assert_contents_contain(r#"CREATE TABLE "new_Cat""#),
+        })?;
+
+    api.apply_migrations(&dir).send().await?;
+    api.dump_table("Cat")
+        .await?
+        .assert_single_row(|row| row.assert_int_value("id", 1)?.assert_text_value("fullName", "Garfield"))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn create_migration_summarizes_the_steps(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    let summary = api
+        .create_migration("create-cats", dm1, &dir)
+        .send()
+        .await?
+        .into_output()
+        .summary
+        .unwrap();
+
+    assert_eq!(
+        summary.steps,
+        &[MigrationStepSummary::CreatedTable { table: "Cat".into() }]
+    );
+    assert!(summary.warnings.is_empty());
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            age         Int?
+        }
+    "#;
+
+    let summary = api
+        .create_migration("replace-name", dm2, &dir)
+        .send()
+        .await?
+        .into_output()
+        .summary
+        .unwrap();
+
+    assert_eq!(
+        summary.steps,
+        &[
+            MigrationStepSummary::DroppedColumn {
+                table: "Cat".into(),
+                column: "name".into(),
+            },
+            MigrationStepSummary::AddedColumn {
+                table: "Cat".into(),
+                column: "age".into(),
+            },
+        ]
+    );
+    assert_eq!(summary.warnings.len(), 1);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn empty_migrations_have_no_summary(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm, &dir).send().await?;
+
+    let output = api.create_migration("empty", dm, &dir).send().await?.into_output();
+
+    assert!(output.generated_migration_name.is_none());
+    assert!(output.summary.is_none());
+
+    Ok(())
+}
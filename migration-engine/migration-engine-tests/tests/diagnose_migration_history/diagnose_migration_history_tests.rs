@@ -620,6 +620,7 @@ async fn with_a_failed_migration(api: &TestApi) -> TestResult {
 
     let CreateMigrationOutput {
         generated_migration_name,
+        ..
     } = api
         .create_migration("01-init", dm, &migrations_directory)
         .send()
@@ -708,6 +709,7 @@ async fn with_an_invalid_unapplied_migration_should_report_it(api: &TestApi) ->
 
     let CreateMigrationOutput {
         generated_migration_name,
+        ..
     } = api
         .create_migration("second-migration", dm2, &directory)
         .send()
@@ -257,11 +257,17 @@ async fn bad_datasource_url_and_provider_combinations_must_return_a_proper_error
         err_message
     );
 
+    let diagnostics = json_error["meta"]["diagnostics"].as_array().unwrap();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(err_message.contains(diagnostics[0]["text"].as_str().unwrap()));
+
     let expected = json!({
         "is_panic": false,
         "message": err_message.clone(),
         "meta": {
             "full_error": err_message,
+            "diagnostics": diagnostics,
         },
         "error_code": "P1012",
     });
@@ -314,13 +320,24 @@ async fn datamodel_parser_errors_must_return_a_known_error(api: &TestApi) {
     "#;
 
     let error = api.schema_push(bad_dm).send().await.unwrap_err().render_user_facing();
+    let type_start = bad_dm.find("Post[]").unwrap();
 
     let expected_msg = "\u{1b}[1;91merror\u{1b}[0m: \u{1b}[1mType \"Post\" is neither a built-in type, nor refers to another model, custom type, or enum.\u{1b}[0m\n  \u{1b}[1;94m-->\u{1b}[0m  \u{1b}[4mschema.prisma:4\u{1b}[0m\n\u{1b}[1;94m   | \u{1b}[0m\n\u{1b}[1;94m 3 | \u{1b}[0m            id Float @id\n\u{1b}[1;94m 4 | \u{1b}[0m            post \u{1b}[1;91mPost[]\u{1b}[0m\n\u{1b}[1;94m   | \u{1b}[0m\n";
 
     let expected_error = user_facing_errors::Error::from(user_facing_errors::KnownError {
         error_code: "P1012".into(),
         message: expected_msg.into(),
-        meta: serde_json::json!({ "full_error": expected_msg }),
+        meta: serde_json::json!({
+            "full_error": expected_msg,
+            "diagnostics": [{
+                "code": "E023",
+                "start": type_start,
+                "end": type_start + "Post[]".len(),
+                "text": "Type \"Post\" is neither a built-in type, nor refers to another model, custom type, or enum.",
+                "isWarning": false,
+                "suggestion": "Define a model or enum named \"Post\", or check the type for typos.",
+            }],
+        }),
     });
 
     assert_eq!(error, expected_error);
@@ -493,7 +510,7 @@ async fn connection_string_problems_give_a_nice_error() {
                     Please refer to the documentation in https://www.prisma.io/docs/reference/database-reference/connection-urls
                     for constructing a correct connection string. In some cases, certain characters must be escaped.
                     Please check the string for any illegal characters.",
-                    connection_string = provider.1
+                    connection_string = provider.1.replace("password-with-#", "***")
                 ).replace('\n', " ")
             },
             _ => {
@@ -502,7 +519,7 @@ async fn connection_string_problems_give_a_nice_error() {
                     Please refer to the documentation in https://www.prisma.io/docs/reference/database-reference/connection-urls
                     for constructing a correct connection string. In some cases, certain characters must be escaped.
                     Please check the string for any illegal characters.",
-                    connection_string = provider.1
+                    connection_string = provider.1.replace("password-with-#", "***")
                 ).replace('\n', " ")
             }
         };
@@ -1 +1,2 @@
 mod error_tests;
+mod permissions_tests;
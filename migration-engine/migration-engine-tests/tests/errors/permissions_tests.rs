@@ -0,0 +1,106 @@
+use crate::*;
+use enumflags2::BitFlags;
+use migration_core::{commands::CheckPermissionsInput, migration_api};
+use pretty_assertions::assert_eq;
+use user_facing_errors::{migration_engine::MissingDatabasePrivilege, UserFacingError};
+
+#[test_each_connector]
+async fn check_permissions_succeeds_with_all_privileges(api: &TestApi) -> TestResult {
+    api.check_permissions(true).await?;
+
+    // The table the privileges are checked with is dropped.
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn check_permissions_names_the_missing_create_privilege_on_mysql(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            "
+            DROP USER IF EXISTS 'prismapermissionstestuser';
+            CREATE USER 'prismapermissionstestuser' IDENTIFIED by '1234batman';
+            GRANT SELECT, INSERT, UPDATE, DELETE ON {}.* TO 'prismapermissionstestuser';
+            ",
+            api.connection_info().dbname().unwrap(),
+        ))
+        .await?;
+
+    let (host, port) = db_host_and_port_mysql_8_0();
+
+    let datamodel = format!(
+        r#"
+        datasource db {{
+            provider = "mysql"
+            url = "mysql://prismapermissionstestuser:1234batman@{dbhost}:{dbport}/{dbname}"
+        }}
+        "#,
+        dbhost = host,
+        dbname = api.connection_info().dbname().unwrap(),
+        dbport = port,
+    );
+
+    let migration_api = migration_api(&datamodel, BitFlags::empty()).await?;
+
+    let error = migration_api
+        .check_permissions(&CheckPermissionsInput { shadow_database: false })
+        .await
+        .unwrap_err()
+        .render_user_facing();
+
+    let known_error = error.as_known().unwrap();
+
+    assert_eq!(known_error.error_code, MissingDatabasePrivilege::ERROR_CODE);
+    assert_eq!(known_error.meta["privilege"], "CREATE");
+    assert_eq!(known_error.meta["operation"], "create tables");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres_12"))]
+async fn check_permissions_names_the_missing_createdb_attribute_on_postgres(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(
+            "
+            DROP USER IF EXISTS prismapermissionstestuser;
+            CREATE USER prismapermissionstestuser PASSWORD '1234batman' LOGIN;
+            ",
+        )
+        .await?;
+
+    let (host, port) = db_host_and_port_postgres_12();
+
+    let datamodel = format!(
+        r#"
+        datasource db {{
+            provider = "postgresql"
+            url = "postgresql://prismapermissionstestuser:1234batman@{dbhost}:{dbport}/{dbname}"
+        }}
+        "#,
+        dbhost = host,
+        dbname = api.connection_info().dbname().unwrap(),
+        dbport = port,
+    );
+
+    let migration_api = migration_api(&datamodel, BitFlags::empty()).await?;
+
+    // The user can create tables in the public schema, but not the shadow database.
+    migration_api
+        .check_permissions(&CheckPermissionsInput { shadow_database: false })
+        .await?;
+
+    let error = migration_api
+        .check_permissions(&CheckPermissionsInput { shadow_database: true })
+        .await
+        .unwrap_err()
+        .render_user_facing();
+
+    let known_error = error.as_known().unwrap();
+
+    assert_eq!(known_error.error_code, MissingDatabasePrivilege::ERROR_CODE);
+    assert_eq!(known_error.meta["privilege"], "CREATEDB");
+    assert_eq!(known_error.meta["operation"], "create the shadow database");
+
+    Ok(())
+}
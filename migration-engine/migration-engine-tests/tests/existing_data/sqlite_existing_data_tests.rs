@@ -50,3 +50,47 @@ async fn changing_a_column_from_optional_to_required_with_a_default_is_safe(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn changing_the_type_of_a_column_casts_the_existing_values(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id     String @id
+            weight Float?
+            label  Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let insert = Insert::multi_into(api.render_table_name("Test"), &["id", "weight", "label"])
+        .values(("a", 12.7, 1))
+        .values(("b", Value::Integer(None), 2));
+
+    api.database().query(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id     String @id
+            weight Int    @default(0)
+            label  String
+        }
+    "#;
+
+    api.schema_push(dm2).force(true).send().await?.assert_executable()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table
+            .assert_column("weight", |column| column.assert_type_is_int()?.assert_is_required())?
+            .assert_column("label", |column| column.assert_type_is_string())
+    })?;
+
+    let data = api.dump_table("Test").await?;
+    let weights: Vec<Option<i64>> = data.iter().map(|row| row.get("weight").unwrap().as_i64()).collect();
+    let labels: Vec<Option<&str>> = data.iter().map(|row| row.get("label").unwrap().as_str()).collect();
+
+    assert_eq!(weights, &[Some(12), Some(0)]);
+    assert_eq!(labels, &[Some("1"), Some("2")]);
+
+    Ok(())
+}
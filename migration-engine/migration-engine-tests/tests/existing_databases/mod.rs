@@ -430,3 +430,29 @@ async fn existing_enums_are_picked_up(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn existing_computed_columns_are_preserved(api: &TestApi) -> TestResult {
+    let sql = format!(
+        "CREATE TABLE [{}].[Order] (id INT PRIMARY KEY, price INT NOT NULL, quantity INT NOT NULL, total AS price * quantity)",
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let dm = r#"
+        model Order {
+            id Int @id
+            price Int
+            quantity Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Order", |table| table.assert_has_column("total"))?;
+
+    Ok(())
+}
@@ -37,3 +37,67 @@ async fn listing_a_single_migration_name_should_work(api: &TestApi) -> TestResul
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn migrations_metadata_describes_applied_and_unapplied_migrations(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+            name String @unique
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let output = api.get_migrations_metadata(&migrations_directory).await?;
+
+    assert!(output.migrations.is_empty());
+    assert!(!output.has_migrations_table);
+
+    api.create_migration("init", dm1, &migrations_directory).send().await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["init"])?;
+
+    api.create_migration("second", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    let output = api.get_migrations_metadata(&migrations_directory).await?;
+
+    assert!(output.has_migrations_table);
+    assert_eq!(output.migrations.len(), 2);
+
+    let (init, second) = (&output.migrations[0], &output.migrations[1]);
+
+    assert!(init.migration_name.ends_with("_init"));
+    assert!(init.checksum.is_some());
+    assert_eq!(init.applied_checksum, init.checksum);
+    assert!(init.statements_count.unwrap() >= 1);
+    assert!(init.started_at.is_some());
+    assert!(init.finished_at.is_some());
+    assert!(init.rolled_back_at.is_none());
+
+    assert!(second.migration_name.ends_with("_second"));
+    assert!(second.checksum.is_some());
+    assert!(second.statements_count.unwrap() >= 1);
+    assert!(second.applied_checksum.is_none());
+    assert!(second.started_at.is_none());
+    assert!(second.finished_at.is_none());
+
+    Ok(())
+}
@@ -11,6 +11,7 @@ mod migrations;
 mod native_types;
 mod reset;
 mod schema_push;
+mod seed;
 
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
@@ -222,6 +223,43 @@ async fn adding_an_id_field_of_type_int_with_autoincrement_works(api: &TestApi)
     Ok(())
 }
 
+#[test_each_connector]
+async fn adding_an_id_field_of_type_bigint_with_autoincrement_works(api: &TestApi) -> TestResult {
+    let dm = api.native_types_datamodel(
+        r#"
+        model Test {
+            myId BigInt @id @default(autoincrement())
+            text String
+        }
+    "#,
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    let result = api.describe_database().await?;
+
+    let column = result.table_bang("Test").column_bang("myId");
+
+    match api.sql_family() {
+        SqlFamily::Postgres => {
+            let sequence = result.get_sequence("Test_myId_seq").expect("sequence must exist");
+            let default = column.default.as_ref().expect("Must have nextval default");
+            assert_eq!(DefaultValue::sequence(sequence.name.clone()), *default);
+            assert_eq!(column.tpe.family, ColumnTypeFamily::BigInt);
+        }
+        // SQLite integer primary keys are always 64 bit.
+        SqlFamily::Sqlite => assert_eq!(column.auto_increment, true),
+        _ => {
+            assert_eq!(column.auto_increment, true);
+            assert_eq!(column.tpe.family, ColumnTypeFamily::BigInt);
+        }
+    }
+
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 // Ignoring sqlite is OK, because sqlite integer primary keys are always auto-incrementing.
 #[test_each_connector(ignore("sqlite"))]
 async fn making_an_existing_id_field_autoincrement_works(api: &TestApi) -> TestResult {
@@ -915,6 +953,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
             })
     })?;
 
@@ -998,6 +1037,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -1064,6 +1104,34 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
     Ok(())
 }
 
+#[test_each_connector]
+async fn adding_a_many_to_many_relation_with_a_mapped_join_table_must_work(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model A {
+            id Int @id
+            bs B[] @relation(name: "my_relation", map: "a_b")
+        }
+        model B {
+            id Int @id
+            as A[] @relation(name: "my_relation")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("a_b", |table| {
+        table
+            .assert_columns_count(2)?
+            .assert_fk_on_columns(&["A"], |fk| fk.assert_references("A", &["id"]))?
+            .assert_fk_on_columns(&["B"], |fk| fk.assert_references("B", &["id"]))?
+            .assert_index_on_columns(&["A", "B"], |idx| idx.assert_is_unique()?.assert_name("a_b_AB_unique"))
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_table(api: &TestApi) -> TestResult {
     let dm1 = r#"
@@ -1113,6 +1181,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -1126,6 +1195,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
                 on_update_action: ForeignKeyAction::NoAction,
+                deferrable: false,
             }
         ]
     );
@@ -1168,6 +1238,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -1209,6 +1280,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -1290,6 +1362,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -1324,6 +1397,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
+            deferrable: false,
         }]
     );
 
@@ -1717,6 +1791,9 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        clustered: None,
+        deferrable: false,
+        method: None,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -469,10 +469,11 @@ async fn indexes_with_an_automatically_truncated_name_are_idempotent(api: &TestA
                 |idx| {
                     idx.assert_name(if api.is_mysql() {
                         // The size limit of identifiers is 64 bytes on MySQL
-                        // and 63 on Postgres.
-                        "TestModelWithALongName.looooooooooooongfield_evenLongerFieldName"
+                        // and 63 on Postgres. The end of the name is replaced
+                        // with a hash of the full name.
+                        "TestModelWithALongName.looooooooooooongfield_evenLonger_d5d6a421"
                     } else {
-                        "TestModelWithALongName.looooooooooooongfield_evenLongerFieldNam"
+                        "TestModelWithALongName.looooooooooooongfield_evenLonge_d5d6a421"
                     })
                 },
             )
@@ -534,3 +535,46 @@ async fn new_index_with_same_name_as_index_from_dropped_table_works(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn clustered_indexes_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int
+
+            @@index([name, age])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_pk(|pk| pk.assert_is_clustered(true))?
+            .assert_index_on_columns(&["name", "age"], |idx| idx.assert_is_clustered(false))
+    })?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int
+
+            @@index([name, age], clustered: true)
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_pk(|pk| pk.assert_is_clustered(false))?
+            .assert_index_on_columns(&["name", "age"], |idx| idx.assert_is_clustered(true))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
+use enumflags2::BitFlags;
+use migration_core::{commands::SchemaPushInput, migration_api};
 use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
 use std::fmt::Write as _;
 
 /// We need to test this specifically for mysql, because foreign keys are indexes, and they are
@@ -47,6 +50,64 @@ async fn indexes_on_foreign_key_fields_are_not_created_twice(api: &TestApi) -> T
     Ok(())
 }
 
+/// MySQL refuses to drop the last index starting with the columns of a foreign key, so the
+/// foreign key is dropped and created again around it, letting MySQL create its own index.
+#[test_each_connector(tags("mysql"))]
+async fn the_last_index_backing_a_foreign_key_can_be_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Human {
+            id String @id
+            catname String
+            nickname String
+            cat_rel Cat @relation(fields: [catname], references: [name])
+
+            @@index([catname, nickname])
+        }
+
+        model Cat {
+            id String @id
+            name String @unique
+            humans Human[]
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Human", |table| {
+        table
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["catname", "nickname"], |idx| idx.assert_is_not_unique())
+    })?;
+
+    let dm2 = r#"
+        model Human {
+            id String @id
+            catname String
+            nickname String
+            cat_rel Cat @relation(fields: [catname], references: [name])
+        }
+
+        model Cat {
+            id String @id
+            name String @unique
+            humans Human[]
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Human", |table| {
+        table
+            .assert_foreign_keys_count(1)?
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["catname"], |idx| idx.assert_is_not_unique())
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 // We have to test this because one enum on MySQL can map to multiple enums in the database.
 #[test_each_connector(tags("mysql"))]
 async fn enum_creation_is_idempotent(api: &TestApi) -> TestResult {
@@ -286,3 +347,121 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn table_options_can_be_changed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+
+            @@engine("MyISAM")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_engine(Some("MyISAM"))?.assert_row_format(None)
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+
+            @@engine("InnoDB")
+            @@rowFormat("COMPACT")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_engine(None)?.assert_row_format(Some("COMPACT"))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn tables_without_table_options_keep_their_engine_and_row_format(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("CREATE TABLE `Cat` (`id` INTEGER NOT NULL, PRIMARY KEY (`id`)) ENGINE = MyISAM ROW_FORMAT = FIXED")
+        .await?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_engine(Some("MyISAM"))?.assert_row_format(Some("FIXED"))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn database_timestamps_are_set_on_update(api: &TestApi) -> TestResult {
+    let schema = format!(
+        r#"
+        datasource db {{
+            provider = "mysql"
+            url = "{url}"
+            databaseTimestamps = true
+        }}
+
+        model Cat {{
+            id Int @id
+            name String
+            updatedAt DateTime @updatedAt
+        }}
+        "#,
+        url = mysql_8_url(api.connection_info().dbname().unwrap()),
+    );
+
+    let migration_api = migration_api(&schema, BitFlags::empty()).await?;
+    let input = SchemaPushInput {
+        schema,
+        force: false,
+        assume_empty: false,
+    };
+
+    migration_api.schema_push(&input).await?;
+
+    let sql_schema = api.assert_schema().await?.into_schema();
+    let default = sql_schema.table_bang("Cat").column_bang("updatedAt").default.as_ref();
+
+    assert!(default
+        .map(|default| default.is_now() && default.on_update())
+        .unwrap_or(false));
+
+    api.database()
+        .raw_cmd("INSERT INTO `Cat` (`id`, `name`, `updatedAt`) VALUES (1, 'Garfield', '2000-01-01')")
+        .await?;
+    api.database().raw_cmd("UPDATE `Cat` SET `name` = 'Nyan'").await?;
+
+    let updated_count = api
+        .database()
+        .query_raw(
+            "SELECT COUNT(*) AS `count` FROM `Cat` WHERE `updatedAt` > '2000-01-01'",
+            &[],
+        )
+        .await?
+        .into_single()?
+        .get("count")
+        .and_then(|count| count.as_i64());
+
+    assert_eq!(updated_count, Some(1));
+
+    // The `ON UPDATE` clause is described, so pushing again is a no-op.
+    assert!(migration_api.schema_push(&input).await?.had_no_changes_to_push());
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
+use enumflags2::BitFlags;
+use migration_core::{commands::SchemaPushInput, migration_api};
 use migration_engine_tests::*;
 use quaint::prelude::Queryable;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, IndexMethod};
 use std::fmt::Write;
 
 #[test_each_connector(tags("postgres"))]
@@ -124,6 +126,7 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
         ("ts", "DateTime", "Timestamp(0)", "timestamp"),
         ("date", "DateTime", "Date", "date"),
         ("time", "DateTime", "Time(2)", "time"),
+        ("timetz", "DateTime", "Timetz(2)", "timetz"),
         ("bool", "Boolean", "Boolean", "bool"),
         ("bit", "String", "Bit(1)", "bit"),
         ("varbit", "String", "VarBit(1)", "varbit"),
@@ -168,3 +171,193 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn native_type_list_columns_can_be_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgresql://localhost/test"
+        }
+
+        generator client {
+          provider = "prisma-client-js"
+          previewFeatures = ["nativeTypes"]
+        }
+
+        model A {
+            id     Int        @id
+            tags   String[]   @pg.VarChar(64)
+            prices Decimal[]  @pg.Numeric(8, 2)
+            times  DateTime[] @pg.Timestamp(0)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table
+            .assert_column("tags", |col| col.assert_is_list()?.assert_full_data_type("_varchar"))?
+            .assert_column("prices", |col| col.assert_is_list()?.assert_full_data_type("_numeric"))?
+            .assert_column("times", |col| col.assert_is_list()?.assert_full_data_type("_timestamp"))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_constraints_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            email String
+            posts Post[]
+
+            @@unique([email])
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            email String
+            posts Post[]
+
+            @@unique([email], deferrable: true)
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], deferrable: true)
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("User", |table| {
+            table.assert_index_on_columns(&["email"], |idx| idx.assert_is_unique()?.assert_is_deferrable(true))
+        })?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_is_deferrable(true))
+        })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("User", |table| {
+            table.assert_index_on_columns(&["email"], |idx| idx.assert_is_deferrable(false))
+        })?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_is_deferrable(false))
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_methods_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int  @id
+            data Json
+
+            @@index([data])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int  @id
+            data Json
+
+            @@index([data], type: Gin)
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["data"], |idx| idx.assert_method(Some(IndexMethod::Gin)))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres_12"))]
+async fn database_timestamps_are_set_by_triggers(api: &TestApi) -> TestResult {
+    let schema = format!(
+        r#"
+        datasource db {{
+            provider = "postgresql"
+            url = "{url}"
+            databaseTimestamps = true
+        }}
+
+        model Cat {{
+            id Int @id
+            name String
+            updatedAt DateTime @updatedAt
+        }}
+        "#,
+        url = postgres_12_url(api.connection_info().dbname().unwrap()),
+    );
+
+    let migration_api = migration_api(&schema, BitFlags::empty()).await?;
+    let input = SchemaPushInput {
+        schema,
+        force: false,
+        assume_empty: false,
+    };
+
+    migration_api.schema_push(&input).await?;
+
+    let sql_schema = api.assert_schema().await?.into_schema();
+    let default = sql_schema.table_bang("Cat").column_bang("updatedAt").default.as_ref();
+
+    assert!(default
+        .map(|default| default.is_now() && default.on_update())
+        .unwrap_or(false));
+
+    api.database()
+        .raw_cmd(r#"INSERT INTO "Cat" ("id", "name", "updatedAt") VALUES (1, 'Garfield', '2000-01-01')"#)
+        .await?;
+    api.database().raw_cmd(r#"UPDATE "Cat" SET "name" = 'Nyan'"#).await?;
+
+    let updated_count = api
+        .database()
+        .query_raw(
+            r#"SELECT COUNT(*) AS "count" FROM "Cat" WHERE "updatedAt" > '2000-01-01'"#,
+            &[],
+        )
+        .await?
+        .into_single()?
+        .get("count")
+        .and_then(|count| count.as_i64());
+
+    assert_eq!(updated_count, Some(1));
+
+    // The triggers are described, so pushing again is a no-op.
+    assert!(migration_api.schema_push(&input).await?.had_no_changes_to_push());
+
+    Ok(())
+}
@@ -88,3 +88,62 @@ async fn creating_a_model_with_a_non_autoincrement_id_column_is_idempotent(api:
 
     Ok(())
 }
+
+#[tokio::test]
+async fn in_memory_databases_can_be_migrated_and_reset() -> TestResult {
+    let api = sqlite_in_memory_test_api().await;
+
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    let persistence = api.imperative_migration_persistence();
+    persistence.initialize().await?;
+    persistence
+        .record_migration_started("01init", "CREATE TABLE cats;")
+        .await?;
+
+    assert_eq!(persistence.list_migrations().await?.unwrap().len(), 1);
+
+    api.reset().send().await?;
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn schema_push_works_through_a_driver_adapter() -> TestResult {
+    let api = sqlite_driver_adapter_test_api("schema_push_works_through_a_driver_adapter").await;
+
+    let dm = r#"
+        model Cat {
+            id      Int    @id
+            name    String @unique
+            ownerId Int?
+            owner   Human? @relation(fields: [ownerId], references: [id])
+        }
+
+        model Human {
+            id   Int   @id
+            cats Cat[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Human", &["id"]))?
+            .assert_index_on_columns(&["name"], |idx| idx.assert_is_unique())
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
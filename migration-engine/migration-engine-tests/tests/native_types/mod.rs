@@ -1 +1,2 @@
 mod mssql;
+mod sqlite;
@@ -0,0 +1,55 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("sqlite"), features("native_types"))]
+async fn native_types_keep_their_declared_type(api: &TestApi) -> TestResult {
+    let dm1 = api.native_types_datamodel(
+        r#"
+        model A {
+            id       Int      @id @default(autoincrement()) @test_db.Integer
+            numeric  Decimal  @test_db.Numeric
+            decimal  Decimal  @test_db.Decimal(8, 2)
+            text     String   @test_db.Text
+            bool     Boolean  @test_db.Boolean
+            dateTime DateTime @test_db.DateTime
+            plain    String
+        }
+        "#,
+    );
+
+    api.schema_push(&dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table
+            .assert_column("id", |c| c.assert_full_data_type("INTEGER"))?
+            .assert_column("numeric", |c| c.assert_full_data_type("NUMERIC"))?
+            .assert_column("decimal", |c| c.assert_full_data_type("DECIMAL(8, 2)"))?
+            .assert_column("text", |c| c.assert_full_data_type("TEXT"))?
+            .assert_column("bool", |c| c.assert_full_data_type("BOOLEAN"))?
+            .assert_column("dateTime", |c| c.assert_full_data_type("DATETIME"))?
+            .assert_column("plain", |c| c.assert_full_data_type("TEXT"))
+    })?;
+
+    api.schema_push(&dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = api.native_types_datamodel(
+        r#"
+        model A {
+            id       Int      @id @default(autoincrement()) @test_db.Integer
+            numeric  Decimal  @test_db.Numeric
+            decimal  Decimal  @test_db.Decimal(8, 2)
+            text     String   @test_db.VarChar(20)
+            bool     Boolean  @test_db.Boolean
+            dateTime DateTime @test_db.DateTime
+            plain    String
+        }
+        "#,
+    );
+
+    api.schema_push(&dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table.assert_column("text", |c| c.assert_full_data_type("VARCHAR(20)"))
+    })?;
+
+    Ok(())
+}
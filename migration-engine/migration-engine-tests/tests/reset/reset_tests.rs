@@ -111,3 +111,75 @@ async fn reset_then_diagnostics_with_migrations_directory_works(api: &TestApi) -
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn reset_can_keep_tables(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            ownerId Int
+            owner   Human @relation(fields: [ownerId], references: [id])
+        }
+
+        model Human {
+            id   Int @id
+            cats Cat[]
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm).send().await?;
+
+    api.assert_schema().await?.assert_tables_count(3)?;
+
+    api.insert("Human").value("id", 1).result_raw().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .value("ownerId", 1)
+        .result_raw()
+        .await?;
+
+    let (cat_names, cat) = match api.sql_family() {
+        SqlFamily::Mysql => ("`CatNames`".to_owned(), "`Cat`".to_owned()),
+        SqlFamily::Mssql => (
+            format!("[{}].[CatNames]", api.schema_name()),
+            format!("[{}].[Cat]", api.schema_name()),
+        ),
+        _ => (r#""CatNames""#.to_owned(), r#""Cat""#.to_owned()),
+    };
+
+    api.database()
+        .raw_cmd(&format!("CREATE VIEW {} AS SELECT name FROM {}", cat_names, cat))
+        .await?;
+
+    if api.sql_family() == SqlFamily::Postgres {
+        api.database().raw_cmd(r#"CREATE SEQUENCE "CatNumbers""#).await?;
+    }
+
+    api.reset().keep_table("Cat").send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Cat", |table| table.assert_foreign_keys_count(0))?;
+
+    let rows = api.select("Cat").column("name").send().await?;
+
+    rows.assert_single_row(|row| row.assert_text_value("name", "Garfield"))?;
+
+    let view = api
+        .database()
+        .query_raw(&format!("SELECT * FROM {}", cat_names), &[])
+        .await;
+
+    assert!(view.is_err(), "The view was not dropped: {:?}", view);
+    assert!(api.describe_database().await?.sequences.is_empty());
+
+    Ok(())
+}
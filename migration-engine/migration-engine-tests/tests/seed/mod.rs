@@ -0,0 +1 @@
+mod seed_tests;
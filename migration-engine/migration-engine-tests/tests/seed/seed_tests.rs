@@ -0,0 +1,74 @@
+use crate::*;
+
+#[test_each_connector]
+async fn seed_data_files_insert_parent_tables_first(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Owner {
+            id   Int    @id
+            name String
+            cats Cat[]
+        }
+
+        model Cat {
+            id      Int   @id
+            ownerId Int
+            owner   Owner @relation(fields: [ownerId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    // `Cat` sorts before `Owner`, the rows must be inserted in file order for
+    // the foreign key to be satisfied.
+    let seed_directory = tempfile::tempdir()?;
+    let seed_file_path = seed_directory.path().join("seed.json");
+
+    std::fs::write(
+        &seed_file_path,
+        r#"{
+            "Owner": [{ "id": 1, "name": "Jon" }],
+            "Cat": [{ "id": 1, "ownerId": 1 }, { "id": 2, "ownerId": 1 }]
+        }"#,
+    )?;
+
+    let output = api.seed(&seed_file_path).await?;
+
+    assert!(output.applied);
+    assert_eq!(output.statements_count, 3);
+
+    let cats = api.select("Cat").column("id").column("ownerId").send_debug().await?;
+
+    assert_eq!(cats.len(), 2);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn failed_seeds_are_rolled_back(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Owner {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let seed_directory = tempfile::tempdir()?;
+    let seed_file_path = seed_directory.path().join("seed.json");
+
+    std::fs::write(
+        &seed_file_path,
+        r#"{ "Owner": [{ "id": 1, "name": "Jon" }, { "id": 2, "name": "Arya" }, { "id": 1, "name": "Sansa" }] }"#,
+    )?;
+
+    let err = api.seed(&seed_file_path).await.unwrap_err().to_string();
+
+    assert!(err.contains("Statement 3 of the seed file"), "{}", err);
+
+    let owners = api.select("Owner").column("id").send_debug().await?;
+
+    assert!(owners.is_empty(), "{:?}", owners);
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use crate::CompletionOpts;
+use std::io::{self, Read};
+
+pub fn run(opts: CompletionOpts) {
+    let mut datamodel_string = String::new();
+
+    io::stdin()
+        .read_to_string(&mut datamodel_string)
+        .expect("Unable to read from stdin.");
+
+    let items = datamodel::completion::completions(&datamodel_string, opts.offset);
+    let json = serde_json::to_string(&items).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
@@ -1,4 +1,4 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::ast::reformat::{FormatOptions, Reformatter};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
@@ -22,15 +22,20 @@ pub fn run(opts: FormatOpts) {
         }
     };
 
+    let options = FormatOptions {
+        indent_width: opts.tabwidth,
+        align_columns: !opts.no_align_columns,
+    };
+
     match opts.output {
         Some(file_name) => {
             let file = File::open(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()));
             let mut stream = BufWriter::new(file);
 
-            Reformatter::new(&datamodel_string).reformat_to(&mut stream, opts.tabwidth);
+            Reformatter::new(&datamodel_string).reformat_to_with_options(&mut stream, &options);
         }
         None => {
-            Reformatter::new(&datamodel_string).reformat_to(&mut io::stdout().lock(), opts.tabwidth);
+            Reformatter::new(&datamodel_string).reformat_to_with_options(&mut io::stdout().lock(), &options);
         }
     }
 }
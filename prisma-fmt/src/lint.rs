@@ -25,6 +25,8 @@ pub fn run(opts: LintOpts) {
                     end: err.span().end,
                     text: format!("{}", err),
                     is_warning: false,
+                    code: err.code(),
+                    suggestion: err.suggestion(),
                 })
                 .collect();
 
@@ -35,6 +37,8 @@ pub fn run(opts: LintOpts) {
                     end: warn.span().end,
                     text: format!("{}", warn),
                     is_warning: true,
+                    code: warn.code(),
+                    suggestion: warn.suggestion(),
                 })
                 .collect();
 
@@ -51,6 +55,8 @@ pub fn run(opts: LintOpts) {
                     end: warn.span().end,
                     text: format!("{}", warn),
                     is_warning: true,
+                    code: warn.code(),
+                    suggestion: warn.suggestion(),
                 })
                 .collect();
 
@@ -1,3 +1,4 @@
+mod completion;
 mod format;
 mod lint;
 mod native;
@@ -27,6 +28,17 @@ pub struct FormatOpts {
     /// Specifies which tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// Separates the names, types and attributes of fields with a single
+    /// space instead of aligning them in columns
+    #[structopt(long)]
+    no_align_columns: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct CompletionOpts {
+    /// The byte offset of the cursor in the schema read from STDIN
+    #[structopt(long)]
+    offset: usize,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -48,6 +60,8 @@ pub enum FmtOpts {
     NativeTypes,
     /// Specifies preview features mode
     PreviewFeatures(PreviewFeaturesOpts),
+    /// Specifies autocompletion mode
+    Completion(CompletionOpts),
 }
 
 #[derive(serde::Serialize)]
@@ -56,6 +70,9 @@ pub struct MiniError {
     pub end: usize,
     pub text: String,
     pub is_warning: bool,
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
 fn main() {
@@ -64,5 +81,6 @@ fn main() {
         FmtOpts::Format(opts) => format::run(opts),
         FmtOpts::NativeTypes => native::run(),
         FmtOpts::PreviewFeatures(opts) => preview::run(opts),
+        FmtOpts::Completion(opts) => completion::run(opts),
     }
 }
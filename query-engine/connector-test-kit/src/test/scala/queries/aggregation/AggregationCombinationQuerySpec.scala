@@ -67,7 +67,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":0},"sum":{"float":0,"int":0,"dec":"0"},"avg":{"float":0,"int":0,"dec":"0"},"min":{"float":0,"int":0,"dec":"0"},"max":{"float":0,"int":0,"dec":"0"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":0},"sum":{"float":0,"int":"0","dec":"0"},"avg":{"float":0,"int":0,"dec":"0"},"min":{"float":0,"int":0,"dec":"0"},"max":{"float":0,"int":0,"dec":"0"}}}}""")
   }
 
   "Using a combination of aggregations with some records in the database" should "return the correct results for each aggregation" in {
@@ -106,7 +106,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":10,"int":15,"dec":"10"},"avg":{"float":5,"int":7.5,"dec":"5"},"min":{"float":4.5,"int":5,"dec":"4.5"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":10,"int":"15","dec":"10"},"avg":{"float":5,"int":7.5,"dec":"5"},"min":{"float":4.5,"int":5,"dec":"4.5"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
   }
 
   "Using a combination of aggregations with all sorts of query arguments" should "work" in {
@@ -147,7 +147,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":10,"int":15,"dec":"10"},"avg":{"float":5,"int":7.5,"dec":"5"},"min":{"float":4.5,"int":5,"dec":"4.5"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":10,"int":"15","dec":"10"},"avg":{"float":5,"int":7.5,"dec":"5"},"min":{"float":4.5,"int":5,"dec":"4.5"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
 
     result = server.query(
       """{
@@ -180,7 +180,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":4},"sum":{"float":11.5,"int":18,"dec":"11.5"},"avg":{"float":2.875,"int":4.5,"dec":"2.875"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":4},"sum":{"float":11.5,"int":"18","dec":"11.5"},"avg":{"float":2.875,"int":4.5,"dec":"2.875"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
 
     result = server.query(
       """{
@@ -213,7 +213,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":4},"sum":{"float":11.5,"int":18,"dec":"11.5"},"avg":{"float":2.875,"int":4.5,"dec":"2.875"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":4},"sum":{"float":11.5,"int":"18","dec":"11.5"},"avg":{"float":2.875,"int":4.5,"dec":"2.875"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":5.5,"int":10,"dec":"5.5"}}}}""")
 
     result = server.query(
       """{
@@ -246,7 +246,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":3,"dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":"3","dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
 
     result = server.query(
       """{
@@ -279,7 +279,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":3,"dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":"3","dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
 
     result = server.query(
       s"""{
@@ -312,7 +312,7 @@ class AggregationCombinationQuerySpec extends FlatSpec with Matchers with ApiSpe
     )
 
     result.toString should be(
-      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":3,"dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
+      """{"data":{"aggregateItem":{"count":{"_all":2},"sum":{"float":1.5,"int":"3","dec":"1.5"},"avg":{"float":0.75,"int":1.5,"dec":"0.75"},"min":{"float":0,"int":1,"dec":"0"},"max":{"float":1.5,"int":2,"dec":"1.5"}}}}""")
   }
 
   "Using any aggregation with an unstable cursor" should "fail" in {
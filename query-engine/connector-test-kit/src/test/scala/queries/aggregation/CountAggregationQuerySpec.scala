@@ -176,4 +176,40 @@ class CountAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase
 
     result7 should equal("""{"data":{"aggregateItem":{"count":{"_all":3}}}}""".parseJson)
   }
+
+  "Counting distinct values" should "count each non-null value and combination once" in {
+    createItem(Some("1"), Some("a"))
+    createItem(Some("1"), Some("a"))
+    createItem(Some("1"), Some("b"))
+    createItem(Some("2"), None)
+    createItem(None, None)
+
+    val result = server.query(
+      s"""{
+         |  aggregateItem {
+         |    count(distinct: true) {
+         |      _all
+         |      s1
+         |      s2
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.aggregateItem.count._all") should be(2)
+    result.pathAsLong("data.aggregateItem.count.s1") should be(2)
+    result.pathAsLong("data.aggregateItem.count.s2") should be(2)
+
+    val result2 = server.query(
+      s"""{
+         |  aggregateItem(where: { s2: { equals: "a" }}) {
+         |    count(distinct: true) { s1 }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result2.pathAsLong("data.aggregateItem.count.s1") should be(1)
+  }
 }
@@ -81,7 +81,7 @@ class GroupByHavingQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
 
     // group3 is filtered completely, group1 (int 0) is filtered as well.
     result.toString should be(
-      """{"data":{"groupByModel":[{"s":"group1","int":5,"count":{"_all":1},"sum":{"int":5}},{"s":"group2","int":5,"count":{"_all":1},"sum":{"int":5}}]}}""")
+      """{"data":{"groupByModel":[{"s":"group1","int":5,"count":{"_all":1},"sum":{"int":"5"}},{"s":"group2","int":5,"count":{"_all":1},"sum":{"int":"5"}}]}}""")
   }
 
   "Using a groupBy with a `having` scalar filters on list fields" should "work" taggedAs (IgnoreMySql, IgnoreSQLite, IgnoreMsSql) in {
@@ -300,7 +300,7 @@ class GroupByHavingQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
          |}""".stripMargin,
       project
     )
-    result.toString should be("""{"data":{"groupByModel":[{"s":"group1","sum":{"float":16,"int":16,"dec":"16"}}]}}""")
+    result.toString should be("""{"data":{"groupByModel":[{"s":"group1","sum":{"float":16,"int":"16","dec":"16"}}]}}""")
 
     // Group 2 (3 is null)
     result = server.query(
@@ -320,7 +320,7 @@ class GroupByHavingQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
          |}""".stripMargin,
       project
     )
-    result.toString should be("""{"data":{"groupByModel":[{"s":"group2","sum":{"float":5,"int":5,"dec":"5"}}]}}""")
+    result.toString should be("""{"data":{"groupByModel":[{"s":"group2","sum":{"float":5,"int":"5","dec":"5"}}]}}""")
 
     // Group 1 and 2 returned
     result = server.query(
@@ -341,7 +341,7 @@ class GroupByHavingQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
       project
     )
     result.toString should be(
-      """{"data":{"groupByModel":[{"s":"group1","sum":{"float":16,"int":16,"dec":"16"}},{"s":"group2","sum":{"float":5,"int":5,"dec":"5"}}]}}""")
+      """{"data":{"groupByModel":[{"s":"group1","sum":{"float":16,"int":"16","dec":"16"}},{"s":"group2","sum":{"float":5,"int":"5","dec":"5"}}]}}""")
   }
 
   // ***********
@@ -187,6 +187,57 @@ class GroupByQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
       """{"data":{"groupByModel":[{"s":"group2","count":{"s":1},"sum":{"float":10},"min":{"int":5}},{"s":"group1","count":{"s":1},"sum":{"float":10.1},"min":{"int":5}}]}}""")
   }
 
+  "Using a groupBy with a cursor" should "return the groups starting at the cursor" in {
+    // Float, int, dec, s, id
+    create(10.1, 5, "1.1", "group1", Some("1"))
+    create(10, 5, "11", "group2", Some("3"))
+    create(10, 5, "11", "group3", Some("4"))
+    create(15, 5, "11", "group3", Some("5"))
+
+    var result = server.query(
+      s"""{
+         |  groupByModel(by: [s, int], orderBy: { s: desc }, cursor: { s: "group2", int: 5 }, take: 2) {
+         |    s
+         |    count { s }
+         |    sum { float }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    // The groups are ordered group3, group2, group1. The cursor group is included.
+    result.toString should be(
+      """{"data":{"groupByModel":[{"s":"group2","count":{"s":1},"sum":{"float":10}},{"s":"group1","count":{"s":1},"sum":{"float":10.1}}]}}""")
+
+    result = server.query(
+      s"""{
+         |  groupByModel(by: [s, int], orderBy: { s: desc }, cursor: { s: "group2", int: 5 }, take: -2) {
+         |    s
+         |    count { s }
+         |    sum { float }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    // Negative takes return the groups before the cursor, in the requested order.
+    result.toString should be(
+      """{"data":{"groupByModel":[{"s":"group3","count":{"s":2},"sum":{"float":25}},{"s":"group2","count":{"s":1},"sum":{"float":10}}]}}""")
+  }
+
+  "Using a groupBy with a cursor that doesn't match the by-arguments" should "return an error" in {
+    server.queryThatMustFail(
+      s"""{
+         |  groupByModel(by: [s, int], cursor: { s: "group2" }) {
+         |    s
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019,
+      errorContains = "The cursor of a groupBy query must contain exactly the fields of the by-arguments of the query: s, int"
+    )
+  }
+
   "Using a groupBy with scalar filters" should "return the correct groups" in {
     // What this test checks: Scalar filters apply before the grouping is done,
     // changing the aggregations of the groups, not the groups directly.
@@ -0,0 +1,78 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupByRollupQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Model {
+      |  id  String @id @default(cuid())
+      |  int Int    @map("db_int")
+      |  s   String @map("db_s")
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def create(int: Int, s: String) = {
+    server.query(
+      s"""mutation {
+         |  createModel(data: { int: $int, s: "$s" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Using a groupBy with rollup" should "return a subtotal row for each prefix of the groups" taggedAs (IgnoreSQLite, IgnoreMySql, IgnoreMySql56) in {
+    create(5, "group1")
+    create(5, "group1")
+    create(0, "group1")
+    create(5, "group2")
+
+    val result = server.query(
+      s"""{
+         |  groupByModel(by: [s, int], rollup: true) {
+         |    s
+         |    int
+         |    count { _all }
+         |    _subtotal
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    // The ordering of nulls differs between databases.
+    result.pathAsSeq("data.groupByModel").map(_.toString).toSet should be(
+      Set(
+        """{"s":"group1","int":0,"count":{"_all":1},"_subtotal":false}""",
+        """{"s":"group1","int":5,"count":{"_all":2},"_subtotal":false}""",
+        """{"s":"group2","int":5,"count":{"_all":1},"_subtotal":false}""",
+        """{"s":"group1","int":null,"count":{"_all":3},"_subtotal":true}""",
+        """{"s":"group2","int":null,"count":{"_all":1},"_subtotal":true}""",
+        """{"s":null,"int":null,"count":{"_all":4},"_subtotal":true}"""
+      ))
+  }
+
+  "Using a groupBy without rollup" should "return no subtotal rows" taggedAs (IgnoreSQLite, IgnoreMySql, IgnoreMySql56) in {
+    create(5, "group1")
+    create(0, "group2")
+
+    val result = server.query(
+      s"""{
+         |  groupByModel(by: [s], orderBy: { s: asc }) {
+         |    s
+         |    _subtotal
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByModel":[{"s":"group1","_subtotal":false},{"s":"group2","_subtotal":false}]}}""")
+  }
+}
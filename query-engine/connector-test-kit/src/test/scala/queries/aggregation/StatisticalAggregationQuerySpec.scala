@@ -0,0 +1,105 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class StatisticalAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  float Float
+      |  int   Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def createItem(float: Double, int: Int) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { float: $float, int: $int }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Standard deviations and variances" should "be computed over the sample" taggedAs (IgnoreSQLite) in {
+    createItem(1.5, 2)
+    createItem(2.5, 4)
+    createItem(3.5, 6)
+
+    val result = server.query(
+      s"""{
+         |  aggregateItem {
+         |    stdDev {
+         |      float
+         |      int
+         |    }
+         |    variance {
+         |      float
+         |      int
+         |    }
+         |    count { _all }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsDouble("data.aggregateItem.stdDev.float") should be(1.0)
+    result.pathAsDouble("data.aggregateItem.stdDev.int") should be(2.0)
+    result.pathAsDouble("data.aggregateItem.variance.float") should be(1.0)
+    result.pathAsDouble("data.aggregateItem.variance.int") should be(4.0)
+    result.pathAsLong("data.aggregateItem.count._all") should be(3)
+  }
+
+  "Percentiles" should "interpolate between the values" taggedAs (IgnoreSQLite, IgnoreMySql, IgnoreMySql56, IgnoreMsSql) in {
+    createItem(1.5, 2)
+    createItem(2.5, 4)
+    createItem(3.5, 6)
+
+    val result = server.query(
+      s"""{
+         |  aggregateItem {
+         |    percentile(p: 0.5) { int }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsDouble("data.aggregateItem.percentile.int") should be(4.0)
+
+    val result2 = server.query(
+      s"""{
+         |  aggregateItem(where: { int: { gt: 0 } }) {
+         |    percentile(p: 0.25) {
+         |      float
+         |      int
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result2.pathAsDouble("data.aggregateItem.percentile.float") should be(2.0)
+    result2.pathAsDouble("data.aggregateItem.percentile.int") should be(3.0)
+  }
+
+  "A percentile outside of 0 and 1" should "fail" taggedAs (IgnoreSQLite, IgnoreMySql, IgnoreMySql56, IgnoreMsSql) in {
+    server.queryThatMustFail(
+      s"""{
+         |  aggregateItem {
+         |    percentile(p: 2) { int }
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019,
+      errorContains = "The percentile `p` must be between 0 and 1."
+    )
+  }
+}
@@ -55,7 +55,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsFloat("data.aggregateItem.sum.float") should be(0.0)
     result.pathAsString("data.aggregateItem.sum.dec") should be("0")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(0)
+    result.pathAsString("data.aggregateItem.sum.int") should be("0")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("0")
   }
 
@@ -80,7 +80,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(10.0)
     result.pathAsString("data.aggregateItem.sum.dec") should be("10")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(15)
+    result.pathAsString("data.aggregateItem.sum.int") should be("15")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("15")
   }
 
@@ -108,7 +108,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(10.0)
     result.pathAsString("data.aggregateItem.sum.dec") should be("10")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(15)
+    result.pathAsString("data.aggregateItem.sum.int") should be("15")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("15")
 
     result = server.query(
@@ -129,7 +129,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(11.5)
     result.pathAsString("data.aggregateItem.sum.dec") should be("11.5")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(18)
+    result.pathAsString("data.aggregateItem.sum.int") should be("18")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("18")
 
     result = server.query(
@@ -150,7 +150,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(11.5)
     result.pathAsString("data.aggregateItem.sum.dec") should be("11.5")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(18)
+    result.pathAsString("data.aggregateItem.sum.int") should be("18")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("18")
 
     result = server.query(
@@ -171,7 +171,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(1.5)
     result.pathAsString("data.aggregateItem.sum.dec") should be("1.5")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(3)
+    result.pathAsString("data.aggregateItem.sum.int") should be("3")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("3")
 
     result = server.query(
@@ -192,7 +192,7 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(1.5)
     result.pathAsString("data.aggregateItem.sum.dec") should be("1.5")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(3)
+    result.pathAsString("data.aggregateItem.sum.int") should be("3")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("3")
 
     result = server.query(
@@ -213,7 +213,25 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(1.5)
     result.pathAsString("data.aggregateItem.sum.dec") should be("1.5")
 
-    result.pathAsInt("data.aggregateItem.sum.int") should be(3)
+    result.pathAsString("data.aggregateItem.sum.int") should be("3")
     result.pathAsString("data.aggregateItem.sum.bInt") should be("3")
   }
+
+  "Summing Int values exceeding 32 bits" should "return the sum as a BigInt" taggedAs(IgnoreMsSql) in {
+    createItem(1.0, Int.MaxValue, "1", "1")
+    createItem(1.0, Int.MaxValue, "1", "1")
+
+    val result = server.query(
+      s"""{
+         |  aggregateItem {
+         |    sum {
+         |      int
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsString("data.aggregateItem.sum.int") should be("4294967294")
+  }
 }
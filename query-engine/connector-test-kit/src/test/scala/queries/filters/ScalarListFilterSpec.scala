@@ -0,0 +1,88 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Model {
+       |   id      Int      @id
+       |   strings String[]
+       |   ints    Int[]
+       |}"""
+  }
+
+  override def beforeEach(): Unit = {
+    database.setup(project)
+    super.beforeEach()
+  }
+
+  "The `has` filter" should "work" in {
+    seed()
+
+    server
+      .query("""query { findManyModel(where: { strings: { has: "a" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { ints: { has: 3 }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { NOT: { strings: { has: "a" }}}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":3}]}}""")
+  }
+
+  "The `hasEvery` filter" should "work" in {
+    seed()
+
+    server
+      .query("""query { findManyModel(where: { strings: { hasEvery: ["a", "b"] }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { strings: { hasEvery: [] }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2},{"id":3}]}}""")
+  }
+
+  "The `hasSome` filter" should "work" in {
+    seed()
+
+    server
+      .query("""query { findManyModel(where: { strings: { hasSome: ["b", "c"] }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { strings: { hasSome: [] }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[]}}""")
+  }
+
+  "The `isEmpty` filter" should "work" in {
+    seed()
+
+    server
+      .query("""query { findManyModel(where: { strings: { isEmpty: true }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":3}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { strings: { isEmpty: false }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+  }
+
+  def seed(): Unit = {
+    create(1, """["a", "b"]""", "[1, 2]")
+    create(2, """["a", "c"]""", "[3]")
+    create(3, "[]", "[]")
+  }
+
+  def create(id: Int, strings: String, ints: String): Unit = {
+    server.query(
+      s"""mutation { createOneModel(data: { id: $id, strings: { set: $strings }, ints: { set: $ints }}) { id }}""",
+      project,
+      legacy = false
+    )
+  }
+}
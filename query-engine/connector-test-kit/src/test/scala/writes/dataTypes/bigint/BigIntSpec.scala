@@ -67,4 +67,39 @@ class BigIntSpec extends FlatSpec with Matchers with ApiSpecBase {
 
     res.toString should be("""{"data":{"updateOneModel":{"field":null}}}""")
   }
+
+  "A BigInt id with autoincrement" should "be generated and work as a cursor" in {
+    val project = ProjectDsl.fromString {
+      """|model Model {
+         | id    BigInt @id @default(autoincrement())
+         | field String
+         |}"""
+    }
+
+    database.setup(project)
+
+    val first  = server.query("""mutation { createOneModel(data: { field: "A" }) { id } }""", project, legacy = false)
+    val second = server.query("""mutation { createOneModel(data: { field: "B" }) { id } }""", project, legacy = false)
+    server.query("""mutation { createOneModel(data: { field: "C" }) { id } }""", project, legacy = false)
+
+    val firstId  = first.pathAsString("data.createOneModel.id")
+    val secondId = second.pathAsString("data.createOneModel.id")
+
+    firstId should be("1")
+    secondId should be("2")
+
+    val res = server.query(
+      s"""
+         |{
+         |  findManyModel(cursor: { id: "$secondId" }, take: 2) {
+         |    id
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"findManyModel":[{"id":"2","field":"B"},{"id":"3","field":"C"}]}}""")
+  }
 }
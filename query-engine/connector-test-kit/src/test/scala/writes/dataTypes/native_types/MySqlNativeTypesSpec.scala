@@ -58,6 +58,66 @@ class MySqlNativeTypesSpec extends FlatSpec with Matchers with ApiSpecBase with
     }
   }
 
+  "MySQL native unsigned int types" should "round-trip values beyond the signed range" in {
+    val project = ProjectDsl.fromString {
+      """
+        |model Model {
+        |  id   String @id @default(cuid())
+        |  uInt Int    @test.UnsignedInt
+        |  uBig BigInt @test.UnsignedBigInt
+        |}"""
+    }
+
+    database.setup(project)
+
+    val res = server.query(
+      s"""
+         |mutation {
+         |  createOneModel(
+         |    data: {
+         |      uInt: 4294967295
+         |      uBig: "18446744073709551615"
+         |    }
+         |  ) {
+         |    uInt
+         |    uBig
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"createOneModel":{"uInt":4294967295,"uBig":"18446744073709551615"}}}""")
+
+    server
+      .query(
+        """{ findManyModel(where: { uBig: { gt: "9223372036854775807" } }) { uBig } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyModel":[{"uBig":"18446744073709551615"}]}}""")
+  }
+
+  "MySQL signed BigInt fields" should "reject values beyond the signed range" in {
+    val project = ProjectDsl.fromString {
+      """
+        |model Model {
+        |  id  String @id @default(cuid())
+        |  big BigInt @test.BigInt
+        |}"""
+    }
+
+    database.setup(project)
+
+    server.queryThatMustFail(
+      """mutation { createOneModel(data: { big: "9223372036854775808" }) { big } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "'9223372036854775808' is not a valid big integer string",
+      legacy = false
+    )
+  }
+
   "MySQL native decimal types" should "work" in {
     val project = ProjectDsl.fromString {
       """
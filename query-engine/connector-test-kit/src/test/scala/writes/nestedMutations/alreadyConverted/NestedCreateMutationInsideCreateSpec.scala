@@ -906,4 +906,50 @@ class NestedCreateMutationInsideCreateSpec extends WordSpecLike with Matchers wi
 
     result.toString should be("""{"data":{"users":[{"nick":"marcus","memberships":[{"list":{"name":"Personal Inbox"}}]}]}}""")
   }
+
+  "creating many nested children at once should connect all of them to the parent" in {
+    val project = SchemaDsl.fromStringV11() {
+      s"""
+         |model List {
+         |  id     String @id @default(cuid())
+         |  name   String
+         |  todos  Todo[]
+         |}
+         |
+         |model Todo {
+         |  id     String  @id @default(cuid())
+         |  title  String
+         |  note   String?
+         |  listId String?
+         |  list   List?   @relation(fields: [listId], references: [id])
+         |}
+       """
+    }
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  createList(data: {
+        |    name: "list"
+        |    todos: {
+        |      create: [
+        |        { title: "a" }
+        |        { title: "b", note: "with note" }
+        |        { title: "c" }
+        |      ]
+        |    }
+        |  }){
+        |    name
+        |  }
+        |}
+      """,
+      project
+    )
+
+    server
+      .query("""{ lists { name todos(orderBy: { title: asc }) { title note } } }""", project)
+      .toString should be(
+      """{"data":{"lists":[{"name":"list","todos":[{"title":"a","note":null},{"title":"b","note":"with note"},{"title":"c","note":null}]}]}}""")
+  }
 }
@@ -0,0 +1,67 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class VersionFieldSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = ProjectDsl.fromString {
+    """model TestModel {
+      |  id      Int    @id
+      |  name    String
+      |  version Int    @version
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "A version field" should "start at 1 on create" in {
+    server
+      .query("""mutation { createOneTestModel(data: { id: 1, name: "a" }) { version } }""", project, legacy = false)
+      .toString should be("""{"data":{"createOneTestModel":{"version":1}}}""")
+  }
+
+  "A version field" should "be incremented by updates" in {
+    server.query("""mutation { createOneTestModel(data: { id: 1, name: "a" }) { id } }""", project, legacy = false)
+    server.query("""mutation { createOneTestModel(data: { id: 2, name: "b" }) { id } }""", project, legacy = false)
+
+    server
+      .query("""mutation { updateOneTestModel(where: { id: 1 }, data: { name: { set: "c" } }) { name version } }""",
+             project,
+             legacy = false)
+      .toString should be("""{"data":{"updateOneTestModel":{"name":"c","version":2}}}""")
+
+    server.query("""mutation { updateManyTestModel(data: { name: { set: "d" } }) { count } }""", project, legacy = false)
+
+    server
+      .query("""{ findManyTestModel(orderBy: { id: asc }) { id version } }""", project, legacy = false)
+      .toString should be("""{"data":{"findManyTestModel":[{"id":1,"version":3},{"id":2,"version":2}]}}""")
+  }
+
+  "A version field" should "reject updates with a stale version" in {
+    server.query("""mutation { createOneTestModel(data: { id: 1, name: "a" }) { id } }""", project, legacy = false)
+    server.query("""mutation { updateOneTestModel(where: { id: 1, version: 1 }, data: { name: { set: "b" } }) { id } }""",
+                 project,
+                 legacy = false)
+
+    server.queryThatMustFail(
+      """mutation { updateOneTestModel(where: { id: 1, version: 1 }, data: { name: { set: "c" } }) { id } }""",
+      project,
+      errorCode = 2023,
+      legacy = false
+    )
+  }
+
+  "A version field" should "not prevent deletes of unchanged records" in {
+    server.query("""mutation { createOneTestModel(data: { id: 1, name: "a" }) { id } }""", project, legacy = false)
+
+    server
+      .query("""mutation { deleteOneTestModel(where: { id: 1 }) { id } }""", project, legacy = false)
+      .toString should be("""{"data":{"deleteOneTestModel":{"id":1}}}""")
+  }
+}
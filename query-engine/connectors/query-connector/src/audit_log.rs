@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// The table audit entries are recorded in. It is created by the migration engine if the
+/// datasource enables `auditLog`.
+pub const AUDIT_LOG_TABLE: &str = "_prisma_audit_log";
+
+/// A record of a create, update or delete executed by the engine.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// The name of the model written to.
+    pub model: String,
+    /// `create`, `update`, `upsert` or `delete`.
+    pub operation: String,
+    /// The primary key of the written record as an object of the field names and values, `None`
+    /// if it is not known, e.g. for a `createMany` of records with generated primary keys.
+    pub primary_key: Option<serde_json::Value>,
+    /// The names of the fields the write sets. Empty for deletes.
+    pub changed_fields: Vec<String>,
+    /// The id of the request, from its `x-request-id` header.
+    pub request_id: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
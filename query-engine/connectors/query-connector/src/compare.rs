@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::filter::{Filter, RelativeTime};
 use prisma_models::PrismaValue;
 
 /// Comparing methods for scalar fields.
@@ -58,6 +58,20 @@ pub trait ScalarCompare {
     fn greater_than_or_equals<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn in_the_last(&self, window: RelativeTime) -> Filter;
+}
+
+/// Bitwise comparison methods for single integer fields. A mask has no meaning for the
+/// concatenated fields of a compound projection.
+pub trait BitwiseCompare {
+    fn has_bits<T>(&self, mask: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn has_any_bits<T>(&self, mask: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
 
 /// Comparison methods for relational fields.
@@ -87,13 +101,13 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_none(&self) -> Filter;
+    fn is_empty_list(&self, empty: bool) -> Filter;
 }
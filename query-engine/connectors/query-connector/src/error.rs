@@ -1,7 +1,10 @@
 use crate::filter::Filter;
 use prisma_models::prelude::DomainError;
 use thiserror::Error;
-use user_facing_errors::{query_engine::DatabaseConstraint, KnownError};
+use user_facing_errors::{
+    query_engine::{ConstraintDetails, DatabaseConstraint},
+    KnownError,
+};
 
 #[derive(Debug, Error)]
 #[error("{}", kind)]
@@ -15,9 +18,34 @@ pub struct ConnectorError {
 impl ConnectorError {
     pub fn from_kind(kind: ErrorKind) -> Self {
         let user_facing_error = match &kind {
-            ErrorKind::NullConstraintViolation { constraint } => Some(KnownError::new(
+            ErrorKind::UniqueConstraintViolation { constraint, details } => {
+                Some(KnownError::new(user_facing_errors::query_engine::UniqueKeyViolation {
+                    constraint: constraint.to_owned(),
+                    constraint_details: details.to_owned(),
+                }))
+            }
+            ErrorKind::NullConstraintViolation { constraint, details } => Some(KnownError::new(
                 user_facing_errors::query_engine::NullConstraintViolation {
                     constraint: constraint.to_owned(),
+                    constraint_details: details.to_owned(),
+                },
+            )),
+            ErrorKind::ForeignKeyConstraintViolation { constraint, details } => {
+                let field_name = if !details.fields.is_empty() {
+                    details.fields.join(", ")
+                } else {
+                    details.name.clone().unwrap_or_else(|| constraint.to_string())
+                };
+
+                Some(KnownError::new(user_facing_errors::query_engine::ForeignKeyViolation {
+                    field_name,
+                    constraint_details: details.to_owned(),
+                }))
+            }
+            ErrorKind::CheckConstraintViolation { constraint, details } => Some(KnownError::new(
+                user_facing_errors::query_engine::CheckConstraintViolation {
+                    constraint: constraint.to_owned(),
+                    constraint_details: details.to_owned(),
                 },
             )),
             ErrorKind::TableDoesNotExist { table } => {
@@ -30,6 +58,11 @@ impl ConnectorError {
                     column: column.clone(),
                 }))
             }
+            ErrorKind::RecordChanged { model } => {
+                Some(KnownError::new(user_facing_errors::query_engine::RecordChanged {
+                    model_name: model.clone(),
+                }))
+            }
             ErrorKind::InvalidDatabaseUrl { details, url } => {
                 let details = user_facing_errors::quaint::invalid_url_description(url, details);
 
@@ -37,6 +70,12 @@ impl ConnectorError {
                     details,
                 }))
             }
+            ErrorKind::SshTunnelError { ssh_host, message } => {
+                Some(KnownError::new(user_facing_errors::common::SshTunnelError {
+                    ssh_host: ssh_host.clone(),
+                    message: message.clone(),
+                }))
+            }
 
             _ => None,
         };
@@ -51,17 +90,35 @@ impl ConnectorError {
 #[derive(Debug, Error)]
 pub enum ErrorKind {
     #[error("Unique constraint failed: {}", constraint)]
-    UniqueConstraintViolation { constraint: DatabaseConstraint },
+    UniqueConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Null constraint failed: {}", constraint)]
-    NullConstraintViolation { constraint: DatabaseConstraint },
+    NullConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Foreign key constraint failed")]
-    ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
+    ForeignKeyConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
+
+    #[error("Check constraint failed: {}", constraint)]
+    CheckConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
+    #[error("The record for model {} was changed by another operation.", model)]
+    RecordChanged { model: String },
+
     #[error("Column '{}' does not exist.", column)]
     ColumnDoesNotExist { column: String },
 
@@ -136,6 +193,12 @@ pub enum ErrorKind {
 
     #[error("{}", details)]
     InvalidDatabaseUrl { details: String, url: String },
+
+    #[error("Could not open the SSH tunnel through {}: {}", ssh_host, message)]
+    SshTunnelError { ssh_host: String, message: String },
+
+    #[error("Unsupported feature: {}", _0)]
+    UnsupportedFeature(String),
 }
 
 impl From<DomainError> for ConnectorError {
@@ -66,6 +66,18 @@ impl Filter {
         }
     }
 
+    /// Whether the filter or one of its nested filters filters by related records. Such filters
+    /// select from other tables, or the table of the filtered model itself.
+    pub fn has_relation_filters(&self) -> bool {
+        match self {
+            Self::And(filters) | Self::Or(filters) | Self::Not(filters) => {
+                filters.iter().any(|filter| filter.has_relation_filters())
+            }
+            Self::Relation(_) | Self::OneRelationIsNull(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn can_batch(&self) -> bool {
         match self {
             Self::Scalar(sf) => sf.can_batch(),
@@ -1,5 +1,5 @@
 use super::Filter;
-use crate::compare::ScalarCompare;
+use crate::compare::{BitwiseCompare, ScalarCompare};
 use once_cell::sync::Lazy;
 use prisma_models::{ModelProjection, PrismaListValue, PrismaValue, ScalarFieldRef};
 use std::{collections::BTreeSet, env, sync::Arc};
@@ -140,6 +140,31 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    /// All bits of the mask are set: `column & mask = mask`.
+    HasBits(PrismaValue),
+    /// At least one bit of the mask is set: `column & mask <> 0`.
+    HasAnyBits(PrismaValue),
+    /// The value lies between the given amount of time ago and now, both taken from the clock of
+    /// the database when the query runs.
+    InTheLast(RelativeTime),
+}
+
+/// A rolling time window ending now, e.g. the last 7 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeTime {
+    pub amount: i64,
+    pub unit: TimeUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
 }
 
 impl ScalarCompare for ScalarFieldRef {
@@ -310,6 +335,41 @@ impl ScalarCompare for ScalarFieldRef {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field is within the given time window ending now.
+    fn in_the_last(&self, window: RelativeTime) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::InTheLast(window),
+            mode: QueryMode::Default,
+        })
+    }
+}
+
+impl BitwiseCompare for ScalarFieldRef {
+    /// All bits of the given mask are set on the field.
+    fn has_bits<T>(&self, mask: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::HasBits(mask.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// At least one bit of the given mask is set on the field.
+    fn has_any_bits<T>(&self, mask: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::HasAnyBits(mask.into()),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ModelProjection {
@@ -480,4 +540,13 @@ impl ScalarCompare for ModelProjection {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field is within the given time window ending now.
+    fn in_the_last(&self, window: RelativeTime) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::InTheLast(window),
+            mode: QueryMode::Default,
+        })
+    }
 }
@@ -0,0 +1,60 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+/// Fields of a model a query filters or orders by without an index that could serve it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingIndex {
+    pub model: String,
+    pub fields: Vec<String>,
+    pub usage: IndexUsage,
+    /// The attribute to add to the model, e.g. `@@index([authorId, published])`.
+    pub suggestion: String,
+}
+
+impl MissingIndex {
+    pub fn new(model: impl Into<String>, fields: Vec<String>, usage: IndexUsage) -> Self {
+        let suggestion = format!("@@index([{}])", fields.join(", "));
+
+        Self {
+            model: model.into(),
+            fields,
+            usage,
+            suggestion,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexUsage {
+    Filter,
+    OrderBy,
+}
+
+/// Collects the missing indexes of the queries of a request. Clones share the same advice, so
+/// the request handler can read what the query core collected.
+#[derive(Debug, Clone, Default)]
+pub struct IndexAdvice {
+    missing: Arc<Mutex<Vec<MissingIndex>>>,
+}
+
+impl IndexAdvice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a missing index, unless the same index was already recorded for the request.
+    pub fn push(&self, missing_index: MissingIndex) {
+        let mut missing = self.missing.lock().unwrap();
+
+        if !missing.contains(&missing_index) {
+            missing.push(missing_index);
+        }
+    }
+
+    /// Takes the missing indexes collected so far.
+    pub fn take(&self) -> Vec<MissingIndex> {
+        std::mem::take(&mut *self.missing.lock().unwrap())
+    }
+}
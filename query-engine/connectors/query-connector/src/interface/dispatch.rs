@@ -9,10 +9,18 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> crate::Result<Option<SingleRecord>> {
         match self {
-            Self::Connection(c) => c.get_single_record(model, filter, selected_fields).await,
-            Self::Transaction(tx) => tx.get_single_record(model, filter, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_single_record(model, filter, selected_fields, relation_counts, relation_joins)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_single_record(model, filter, selected_fields, relation_counts, relation_joins)
+                    .await
+            }
         }
     }
 
@@ -21,10 +29,30 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> crate::Result<ManyRecords> {
         match self {
-            Self::Connection(c) => c.get_many_records(model, query_arguments, selected_fields).await,
-            Self::Transaction(tx) => tx.get_many_records(model, query_arguments, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_many_records(model, query_arguments, selected_fields, relation_counts, relation_joins)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records(model, query_arguments, selected_fields, relation_counts, relation_joins)
+                    .await
+            }
+        }
+    }
+
+    async fn get_raw_records(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords> {
+        match self {
+            Self::Connection(c) => c.get_raw_records(query, parameters, selected_fields).await,
+            Self::Transaction(tx) => tx.get_raw_records(query, parameters, selected_fields).await,
         }
     }
 
@@ -44,7 +72,7 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: GroupBy,
         having: Option<Filter>,
     ) -> crate::Result<Vec<AggregationRow>> {
         match self {
@@ -69,6 +97,20 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn try_create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+        match self {
+            Self::Connection(c) => c.try_create_record(model, args).await,
+            Self::Transaction(tx) => tx.try_create_record(model, args).await,
+        }
+    }
+
+    async fn create_records(&self, model: &ModelRef, args: Vec<WriteArgs>) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args).await,
+            Self::Transaction(tx) => tx.create_records(model, args).await,
+        }
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
@@ -81,6 +123,31 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn update_many_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.update_many_records(model, record_filter, args).await,
+            Self::Transaction(tx) => tx.update_many_records(model, record_filter, args).await,
+        }
+    }
+
+    async fn upsert_record(
+        &self,
+        model: &ModelRef,
+        selector: RecordProjection,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Connection(c) => c.upsert_record(model, selector, create, update).await,
+            Self::Transaction(tx) => tx.upsert_record(model, selector, create, update).await,
+        }
+    }
+
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize> {
         match self {
             Self::Connection(c) => c.delete_records(model, record_filter).await,
@@ -125,4 +192,11 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.execute_raw(query, parameters).await,
         }
     }
+
+    async fn record_audit_entries(&self, entries: Vec<AuditEntry>) -> crate::Result<()> {
+        match self {
+            Self::Connection(c) => c.record_audit_entries(entries).await,
+            Self::Transaction(tx) => tx.record_audit_entries(entries).await,
+        }
+    }
 }
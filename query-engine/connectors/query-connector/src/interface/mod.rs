@@ -1,7 +1,7 @@
 mod dispatch;
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{AuditEntry, Filter, QueryArguments, QueryHints, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
@@ -14,6 +14,17 @@ pub trait Connector {
 
     /// Returns name of the connector.
     fn name(&self) -> String;
+
+    /// Checks that the data source is reachable, with a round-trip to the database.
+    async fn ping(&self) -> crate::Result<()> {
+        self.get_connection().await.map(|_| ())
+    }
+
+    /// Returns whether the database enforces the foreign keys of relations. If it doesn't, the
+    /// query engine has to emulate the referential actions.
+    async fn enforces_foreign_keys(&self) -> crate::Result<bool> {
+        Ok(true)
+    }
 }
 
 #[async_trait]
@@ -25,6 +36,9 @@ pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
 pub trait Transaction: ReadOperations + WriteOperations + Send + Sync {
     async fn commit(&self) -> crate::Result<()>;
     async fn rollback(&self) -> crate::Result<()>;
+
+    /// Applies the query hints of a request for the remainder of the transaction.
+    async fn apply_query_hints(&self, hints: &QueryHints) -> crate::Result<()>;
 }
 
 pub enum ConnectionLike<'conn, 'tx>
@@ -45,6 +59,10 @@ where
 pub struct RecordFilter {
     pub filter: Filter,
     pub selectors: Option<Vec<RecordProjection>>,
+
+    /// The `@version` value the client expects the records to still have. If set,
+    /// connectors fail with a conflict if any of the records has a different version.
+    pub expected_version: Option<PrismaValue>,
 }
 
 impl RecordFilter {
@@ -52,8 +70,14 @@ impl RecordFilter {
         Self {
             filter: Filter::empty(),
             selectors: None,
+            expected_version: None,
         }
     }
+
+    pub fn with_expected_version(mut self, expected_version: Option<PrismaValue>) -> Self {
+        self.expected_version = expected_version;
+        self
+    }
 }
 
 impl From<Filter> for RecordFilter {
@@ -61,6 +85,7 @@ impl From<Filter> for RecordFilter {
         Self {
             filter,
             selectors: None,
+            expected_version: None,
         }
     }
 }
@@ -70,6 +95,7 @@ impl From<Vec<RecordProjection>> for RecordFilter {
         Self {
             filter: Filter::empty(),
             selectors: Some(selectors),
+            expected_version: None,
         }
     }
 }
@@ -79,6 +105,7 @@ impl From<RecordProjection> for RecordFilter {
         Self {
             filter: Filter::empty(),
             selectors: Some(vec![selector]),
+            expected_version: None,
         }
     }
 }
@@ -93,12 +120,19 @@ pub enum AggregationSelection {
     /// `all` indicates that an all-records selection has been made (e.g. SQL *).
     /// `fields` are specific fields to count on. By convention, if `all` is true,
     /// it will always be the last of the count results.
-    Count { all: bool, fields: Vec<ScalarFieldRef> },
+    /// If `distinct` is set, only the distinct non-null values of each field are counted, and `all`
+    /// counts the distinct combinations of the values of `fields` that contain no null.
+    Count {
+        all: bool,
+        fields: Vec<ScalarFieldRef>,
+        distinct: bool,
+    },
 
     /// Compute average for each field contained.
     Average(Vec<ScalarFieldRef>),
 
-    /// Compute sum for each field contained.
+    /// Compute sum for each field contained. Sums of `Int` fields are read as `BigInt`, sums of
+    /// `BigInt` fields as `Decimal`, so that they can't overflow the type of the field.
     Sum(Vec<ScalarFieldRef>),
 
     /// Compute mininum for each field contained.
@@ -106,44 +140,154 @@ pub enum AggregationSelection {
 
     /// Compute maximum for each field contained.
     Max(Vec<ScalarFieldRef>),
+
+    /// Compute the sample standard deviation for each field contained.
+    StdDev(Vec<ScalarFieldRef>),
+
+    /// Compute the sample variance for each field contained.
+    Variance(Vec<ScalarFieldRef>),
+
+    /// Compute the continuous percentile for each field contained, interpolating between values.
+    /// `percentile` is between 0 and 1, the median is the percentile 0.5.
+    Percentile {
+        fields: Vec<ScalarFieldRef>,
+        percentile: f64,
+    },
 }
 
 impl AggregationSelection {
-    pub fn identifiers(&self) -> Vec<(TypeIdentifier, FieldArity)> {
+    /// The types the results of the aggregation are read with. The minimum and maximum of DateTime
+    /// fields are read with the `DateTimeMode` of the fields.
+    pub fn identifiers(&self) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
         match self {
-            AggregationSelection::Field(field) => vec![(field.type_identifier.clone(), FieldArity::Required)],
-            AggregationSelection::Count { all, fields } => {
+            AggregationSelection::Field(field) => vec![(
+                field.type_identifier.clone(),
+                FieldArity::Required,
+                field.date_time_mode(),
+            )],
+            AggregationSelection::Count { all, fields, .. } => {
                 let mut mapped = Self::map_field_types(&fields, Some(TypeIdentifier::Int));
 
                 if *all {
-                    mapped.push((TypeIdentifier::Int, FieldArity::Required));
+                    mapped.push((TypeIdentifier::Int, FieldArity::Required, DateTimeMode::Utc));
                 }
 
                 mapped
             }
             AggregationSelection::Average(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::Float)),
-            AggregationSelection::Sum(fields) => Self::map_field_types(&fields, None),
+            AggregationSelection::Sum(fields) => fields
+                .iter()
+                .map(|f| {
+                    let type_identifier = match f.type_identifier {
+                        TypeIdentifier::Int => TypeIdentifier::BigInt,
+                        TypeIdentifier::BigInt => TypeIdentifier::Decimal,
+                        ref other => other.clone(),
+                    };
+
+                    (type_identifier, FieldArity::Required, DateTimeMode::Utc)
+                })
+                .collect(),
             AggregationSelection::Min(fields) => Self::map_field_types(&fields, None),
             AggregationSelection::Max(fields) => Self::map_field_types(&fields, None),
+            AggregationSelection::StdDev(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::Float)),
+            AggregationSelection::Variance(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::Float)),
+            AggregationSelection::Percentile { fields, .. } => {
+                Self::map_field_types(&fields, Some(TypeIdentifier::Float))
+            }
         }
     }
 
+    /// Statistical aggregations can't be expressed with the common aggregate functions and are
+    /// only supported by some connectors.
+    pub fn is_statistical(&self) -> bool {
+        matches!(
+            self,
+            AggregationSelection::StdDev(_)
+                | AggregationSelection::Variance(_)
+                | AggregationSelection::Percentile { .. }
+        )
+    }
+
     fn map_field_types(
         fields: &[ScalarFieldRef],
         fixed_type: Option<TypeIdentifier>,
-    ) -> Vec<(TypeIdentifier, FieldArity)> {
+    ) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
         fields
             .iter()
-            .map(|f| {
-                (
-                    fixed_type.clone().unwrap_or_else(|| f.type_identifier.clone()),
-                    FieldArity::Required,
-                )
+            .map(|f| match &fixed_type {
+                Some(type_identifier) => (type_identifier.clone(), FieldArity::Required, DateTimeMode::Utc),
+                None => (f.type_identifier.clone(), FieldArity::Required, f.date_time_mode()),
             })
             .collect()
     }
 }
 
+/// Counts the records related through a list relation field, selected alongside the records of
+/// the model, e.g. `_count { posts }`.
+#[derive(Debug, Clone)]
+pub struct RelationCountSelection {
+    pub field: RelationFieldRef,
+    /// Only related records matching the filter are counted.
+    pub filter: Option<Filter>,
+}
+
+impl RelationCountSelection {
+    /// The name of the column holding the count in the records returned by the connector.
+    pub fn db_alias(&self) -> String {
+        format!("_count_{}", self.field.name)
+    }
+}
+
+/// Loads the record related through a to-one relation field with a join, in the same query as the
+/// records of the model.
+#[derive(Debug, Clone)]
+pub struct RelationJoin {
+    pub field: RelationFieldRef,
+    /// The fields of the related model to select.
+    pub selected_fields: ModelProjection,
+}
+
+impl RelationJoin {
+    /// The alias of the joined table.
+    pub fn table_alias(&self) -> String {
+        format!("_join_{}", self.field.name)
+    }
+
+    /// The name of the column holding the value of a selected field of the related record in the
+    /// records returned by the connector.
+    pub fn db_alias(&self, db_name: &str) -> String {
+        format!("{}.{}", self.table_alias(), db_name)
+    }
+
+    /// The names of the columns of all selected fields of the related record.
+    pub fn db_aliases(&self) -> Vec<String> {
+        self.selected_fields
+            .db_names()
+            .map(|db_name| self.db_alias(db_name))
+            .collect()
+    }
+}
+
+/// Groups the records of an aggregation query by the values of `fields`. Without fields, all
+/// records are aggregated into a single row.
+#[derive(Debug, Clone, Default)]
+pub struct GroupBy {
+    pub fields: Vec<ScalarFieldRef>,
+    /// Adds subtotal rows that aggregate the groups of each prefix of `fields`, down to a grand
+    /// total, as with `GROUP BY ROLLUP`. The grouped values a subtotal rolls up are null.
+    pub rollup: bool,
+}
+
+impl GroupBy {
+    pub fn new(fields: Vec<ScalarFieldRef>, rollup: bool) -> Self {
+        Self { fields, rollup }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
 pub type AggregationRow = Vec<AggregationResult>;
 
 /// Result of an aggregation operation on a model or field.
@@ -157,6 +301,11 @@ pub enum AggregationResult {
     Sum(ScalarFieldRef, PrismaValue),
     Min(ScalarFieldRef, PrismaValue),
     Max(ScalarFieldRef, PrismaValue),
+    StdDev(ScalarFieldRef, PrismaValue),
+    Variance(ScalarFieldRef, PrismaValue),
+    Percentile(ScalarFieldRef, PrismaValue),
+    /// Whether a row of a rollup is a subtotal rather than a group.
+    Subtotal(bool),
 }
 
 #[async_trait]
@@ -167,11 +316,15 @@ pub trait ReadOperations {
     /// - The `Filter` defines what item we want back and is guaranteed to be
     ///   defined to filter at most one item by the core.
     /// - The `SelectedFields` defines the values to be returned.
+    /// - The `RelationCountSelection`s and `RelationJoin`s are returned after the
+    ///   selected fields, see `get_many_records`.
     async fn get_single_record(
         &self,
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> crate::Result<Option<SingleRecord>>;
 
     /// Gets multiple records from the database.
@@ -180,11 +333,32 @@ pub trait ReadOperations {
     /// - The `QueryArguments` defines various constraints (see docs for detailed explanation).
     /// - The `SelectedFields` defines the fields (e.g. columns or document fields)
     ///   to be returned as a projection of fields of the model it queries.
+    /// - The `RelationCountSelection`s define the relations to count the related
+    ///   records of. The counts are returned as additional `Int` values after the
+    ///   selected fields, named by `RelationCountSelection::db_alias`.
+    /// - The `RelationJoin`s define the to-one relations to load the related record
+    ///   of in the same query. The selected fields of the related records are
+    ///   returned after the counts, named by `RelationJoin::db_alias`, and are null
+    ///   if there is no related record.
     async fn get_many_records(
         &self,
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
+    ) -> crate::Result<ManyRecords>;
+
+    /// Executes a raw query and maps the result onto records of a model.
+    ///
+    /// - The `query` and `parameters` are passed to the database as they are.
+    /// - The `SelectedFields` defines the fields to be returned. Result columns are matched to
+    ///   the fields by their database names and coerced to the type of the field.
+    async fn get_raw_records(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+        selected_fields: &ModelProjection,
     ) -> crate::Result<ManyRecords>;
 
     /// Retrieves pairs of IDs that belong together from a intermediate join
@@ -209,7 +383,7 @@ pub trait ReadOperations {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: GroupBy,
         having: Option<Filter>,
     ) -> crate::Result<Vec<AggregationRow>>;
 }
@@ -219,6 +393,14 @@ pub trait WriteOperations {
     /// Insert a single record to the database.
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection>;
 
+    /// Insert a single record like `create_record`. If the insert fails, e.g. on a unique
+    /// violation, the surrounding transaction stays usable, so the caller can recover.
+    async fn try_create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection>;
+
+    /// Insert multiple records to the database in as few statements as possible,
+    /// resulting in the number of records created.
+    async fn create_records(&self, model: &ModelRef, args: Vec<WriteArgs>) -> crate::Result<usize>;
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
     /// `Filter`.
     async fn update_records(
@@ -228,6 +410,28 @@ pub trait WriteOperations {
         args: WriteArgs,
     ) -> crate::Result<Vec<RecordProjection>>;
 
+    /// Update records like `update_records`, resulting in the number of records
+    /// updated. As the identifiers are not needed, connectors may update the
+    /// records in a single statement without reading them first.
+    async fn update_many_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> crate::Result<usize>;
+
+    /// Insert a record with the `create` arguments, or update the record identified by
+    /// `selector` with the `update` arguments if it already exists, without a window for a
+    /// concurrent write in between. Only used for connectors with the `NativeUpsert`
+    /// capability. The `create` arguments must contain the values of the `selector`.
+    async fn upsert_record(
+        &self,
+        model: &ModelRef,
+        selector: RecordProjection,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> crate::Result<()>;
+
     /// Delete records in the `Model` with the given `Filter`.
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize>;
 
@@ -260,4 +464,7 @@ pub trait WriteOperations {
     ///
     /// Returns resulting rows as JSON.
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value>;
+
+    /// Insert the entries into the audit log table, see `AUDIT_LOG_TABLE`.
+    async fn record_audit_entries(&self, entries: Vec<AuditEntry>) -> crate::Result<()>;
 }
@@ -3,15 +3,27 @@
 pub mod error;
 pub mod filter;
 
+mod audit_log;
 mod compare;
+mod index_advice;
 mod interface;
 mod query_arguments;
+mod query_graph_traces;
+mod query_hints;
+mod query_plans;
+mod slow_queries;
 mod write_args;
 
+pub use audit_log::*;
 pub use compare::*;
+pub use index_advice::*;
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
+pub use query_graph_traces::*;
+pub use query_hints::*;
+pub use query_plans::*;
+pub use slow_queries::*;
 pub use write_args::*;
 
 pub type Result<T> = std::result::Result<T, error::ConnectorError>;
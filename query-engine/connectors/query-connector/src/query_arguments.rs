@@ -12,6 +12,7 @@ pub struct SkipAndLimit {
 /// - `filter` scopes the data by defining conditions (akin to `WHERE` in SQL).
 /// - `order_by` defines the ordering of records, from most high to low precedence.
 /// - `distinct` designates the fields on which the records should be distinct.
+/// - `window` numbers the records within partitions, before they are ordered and paged.
 /// - The `ignore_*` flags are a temporary bandaid to tell the connector to do not
 ///   include certain constraints when building queries, because the core is already
 ///   performing these action in a different manner (e.g. in-memory on all records).
@@ -28,10 +29,29 @@ pub struct QueryArguments {
     pub filter: Option<Filter>,
     pub order_by: Vec<OrderBy>,
     pub distinct: Option<ModelProjection>,
+    pub window: Option<Window>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
 }
 
+/// Numbers the records within the partitions of records with equal values of `partition_by`, in
+/// the order of `order_by`, with the `ROW_NUMBER` and `RANK` window functions. The numbers are
+/// selected as the `_rowNumber` and `_rank` of the records. Ties are broken by the primary
+/// identifier for row numbers, and share the same rank.
+#[derive(Debug, Clone)]
+pub struct Window {
+    pub partition_by: Vec<ScalarFieldRef>,
+    pub order_by: Vec<OrderBy>,
+    /// Only the records numbered up to `take` within their partition are returned, e.g. the
+    /// first three posts of each author.
+    pub take: Option<i64>,
+}
+
+impl Window {
+    pub const ROW_NUMBER: &'static str = "_rowNumber";
+    pub const RANK: &'static str = "_rank";
+}
+
 impl QueryArguments {
     pub fn new(model: ModelRef) -> Self {
         Self {
@@ -42,6 +62,7 @@ impl QueryArguments {
             filter: None,
             order_by: vec![],
             distinct: None,
+            window: None,
             ignore_take: false,
             ignore_skip: false,
         }
@@ -54,6 +75,7 @@ impl QueryArguments {
             && self.filter.is_none()
             && self.order_by.is_empty()
             && self.distinct.is_none()
+            && self.window.is_none()
     }
 
     /// We can't execute all operations on the DB level reliably.
@@ -76,13 +98,24 @@ impl QueryArguments {
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
     /// is the same as the model used
-    /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
+    /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique
+    /// (including the primary identifier). Orderings on aggregates are not considered.
     /// `false` otherwise.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        let order_fields: Vec<_> = self
+            .order_by
+            .iter()
+            .filter(|o| o.sort_aggregation.is_none())
+            .map(|o| &o.field)
+            .collect();
 
-        !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+        !order_fields.is_empty()
+            && (order_fields.iter().any(|f| f.unique())
+                || self
+                    .model
+                    .primary_identifier()
+                    .scalar_fields()
+                    .all(|f| order_fields.contains(&&f))
                 || self
                     .model
                     .unique_indexes()
@@ -95,7 +128,9 @@ impl QueryArguments {
     }
 
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.window.is_none()
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -107,6 +142,7 @@ impl QueryArguments {
                 let skip = self.skip;
                 let order_by = self.order_by;
                 let distinct = self.distinct;
+                let window = self.window;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
 
@@ -121,6 +157,7 @@ impl QueryArguments {
                         filter: Some(filter),
                         order_by: order_by.clone(),
                         distinct: distinct.clone(),
+                        window: window.clone(),
                         ignore_skip,
                         ignore_take,
                     })
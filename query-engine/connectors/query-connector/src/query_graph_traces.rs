@@ -0,0 +1,90 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+/// How the query graph of an operation was built and transformed, and in which order its nodes
+/// are executed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphTrace {
+    /// The name of the top-level field of the operation, e.g. `createOneUser`.
+    pub operation: String,
+    pub events: Vec<QueryGraphEvent>,
+    /// The nodes in the order the interpreter runs them. Nodes in the branches of `If` flow nodes
+    /// are listed even if the branch is not taken at runtime.
+    pub execution_order: Vec<QueryGraphStep>,
+}
+
+impl QueryGraphTrace {
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            events: Vec::new(),
+            execution_order: Vec::new(),
+        }
+    }
+}
+
+/// A change to the query graph. Nodes are referred to by their id, which is stable for the
+/// lifetime of the graph.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryGraphEvent {
+    /// The step that made the change: `build` for the graph as the query builders return it,
+    /// then the finalization steps `swapMarked`, `insertReloads` and `normalizeIfNodes`.
+    pub stage: &'static str,
+    #[serde(flatten)]
+    pub action: QueryGraphAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum QueryGraphAction {
+    CreateNode {
+        node: String,
+        content: String,
+    },
+    CreateEdge {
+        from: String,
+        to: String,
+        dependency: String,
+    },
+    /// The dependency is empty if the edge was consumed before it was removed.
+    RemoveEdge {
+        from: String,
+        to: String,
+        dependency: Option<String>,
+    },
+    /// The child is executed before the parent after the `swapMarked` stage.
+    MarkNodes {
+        parent: String,
+        child: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryGraphStep {
+    pub node: String,
+    pub content: String,
+}
+
+/// Collects the query graph traces of the operations of a request. Clones share the same
+/// traces, so the request handler can read what the query core collected.
+#[derive(Debug, Clone, Default)]
+pub struct QueryGraphTraces {
+    traces: Arc<Mutex<Vec<QueryGraphTrace>>>,
+}
+
+impl QueryGraphTraces {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, trace: QueryGraphTrace) {
+        self.traces.lock().unwrap().push(trace);
+    }
+
+    /// Takes the traces collected so far.
+    pub fn take(&self) -> Vec<QueryGraphTrace> {
+        std::mem::take(&mut *self.traces.lock().unwrap())
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{IndexAdvice, QueryGraphTraces, QueryPlans, SlowQueryLog};
+
+/// Connector-specific settings a request asks to apply to all the queries it executes, for
+/// example `statement_timeout` on Postgres or `max_execution_time` on MySQL.
+///
+/// Settings are applied at the beginning of the transaction the request runs in, and only for
+/// the duration of that transaction. They override the `statement_timeout` of the datasource URL.
+/// Postgres applies them with `SET LOCAL`, MySQL and MariaDB set session variables that are reset
+/// when the transaction ends. SQLite and SQL Server have no such settings, requests with settings
+/// fail there instead of silently running without them.
+///
+/// On MySQL, requests can also attach optimizer hints, e.g. `MAX_EXECUTION_TIME(1000)`, which are
+/// added to each generated statement as a `/*+ ... */` comment, see `add_optimizer_hint`.
+///
+/// Hints can also ask the connector to explain the statements of the request, see `explain`, and
+/// to record its slow queries, see `log_slow_queries`. The query core traces the query graphs of
+/// the request if asked to, see `trace_query_graphs`, and advises on the indexes its queries are
+/// missing, see `advise_indexes`.
+#[derive(Debug, Clone, Default)]
+pub struct QueryHints {
+    settings: Vec<(String, String)>,
+    optimizer_hints: Vec<String>,
+    explain: Option<QueryPlans>,
+    slow_query_log: Option<SlowQueryLog>,
+    query_graph_traces: Option<QueryGraphTraces>,
+    index_advice: Option<IndexAdvice>,
+    request_id: Option<String>,
+}
+
+impl QueryHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a setting. The name must be a plain (optionally dot-qualified) identifier, so it can be
+    /// rendered without quoting. The value is always rendered as an escaped string literal.
+    pub fn add_setting(&mut self, name: impl Into<String>, value: impl Into<String>) -> Result<(), String> {
+        let name = name.into();
+        let value = value.into();
+
+        if !is_valid_setting_name(&name) {
+            return Err(format!("`{}` is not a valid query hint setting name.", name));
+        }
+
+        if value.contains('\0') {
+            return Err(format!("The value of the query hint setting `{}` is invalid.", name));
+        }
+
+        self.settings.push((name, value));
+
+        Ok(())
+    }
+
+    pub fn settings(&self) -> &[(String, String)] {
+        &self.settings
+    }
+
+    /// Add an optimizer hint, e.g. `MAX_EXECUTION_TIME(1000)` or `NO_INDEX_MERGE(t1)`. The hint
+    /// must be an identifier followed by its arguments in parentheses, made of identifiers,
+    /// numbers, commas and spaces, so it can't close the comment it is rendered in.
+    pub fn add_optimizer_hint(&mut self, hint: impl Into<String>) -> Result<(), String> {
+        let hint = hint.into();
+
+        if optimizer_hint_name(&hint).is_none() {
+            return Err(format!("`{}` is not a valid optimizer hint.", hint));
+        }
+
+        self.optimizer_hints.push(hint);
+
+        Ok(())
+    }
+
+    pub fn optimizer_hints(&self) -> &[String] {
+        &self.optimizer_hints
+    }
+
+    /// Collect the execution plans of the generated statements of the request into `plans`. Raw
+    /// queries are not explained.
+    pub fn explain(&mut self, plans: QueryPlans) {
+        self.explain = Some(plans);
+    }
+
+    pub fn query_plans(&self) -> Option<&QueryPlans> {
+        self.explain.as_ref()
+    }
+
+    /// Record the queries of the request that exceed the threshold of `log`.
+    pub fn log_slow_queries(&mut self, log: SlowQueryLog) {
+        self.slow_query_log = Some(log);
+    }
+
+    pub fn slow_query_log(&self) -> Option<&SlowQueryLog> {
+        self.slow_query_log.as_ref()
+    }
+
+    /// Record how the query graphs of the request are built and transformed into `traces`.
+    pub fn trace_query_graphs(&mut self, traces: QueryGraphTraces) {
+        self.query_graph_traces = Some(traces);
+    }
+
+    pub fn query_graph_traces(&self) -> Option<&QueryGraphTraces> {
+        self.query_graph_traces.as_ref()
+    }
+
+    /// Record the fields the queries of the request filter or order by without a matching index
+    /// into `advice`.
+    pub fn advise_indexes(&mut self, advice: IndexAdvice) {
+        self.index_advice = Some(advice);
+    }
+
+    pub fn index_advice(&self) -> Option<&IndexAdvice> {
+        self.index_advice.as_ref()
+    }
+
+    /// Set the id of the request, recorded with its audit entries.
+    pub fn set_request_id(&mut self, request_id: impl Into<String>) {
+        self.request_id = Some(request_id.into());
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Whether the hints require a transaction to be applied. Logging slow queries, tracing query
+    /// graphs and advising on indexes do not.
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty() && self.optimizer_hints.is_empty() && self.explain.is_none()
+    }
+}
+
+/// The name of an optimizer hint of the form `NAME(arguments)`, if it is valid.
+pub fn optimizer_hint_name(hint: &str) -> Option<&str> {
+    let open = hint.find('(')?;
+    let (name, arguments) = hint.split_at(open);
+    let arguments = arguments.strip_prefix('(')?.strip_suffix(')')?;
+
+    let is_valid_argument = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | ',' | ' ' | '.' | '@');
+
+    if is_valid_setting_name(name) && !name.contains('.') && arguments.chars().all(is_valid_argument) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+fn is_valid_setting_name(name: &str) -> bool {
+    let is_valid_identifier = |ident: &str| {
+        let mut chars = ident.chars();
+
+        match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            _ => false,
+        }
+    };
+
+    let parts: Vec<&str> = name.split('.').collect();
+
+    parts.len() <= 2 && parts.into_iter().all(is_valid_identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setting_names_must_be_identifiers() {
+        let mut hints = QueryHints::new();
+
+        assert!(hints.add_setting("statement_timeout", "1000").is_ok());
+        assert!(hints.add_setting("app.current_user", "42").is_ok());
+        assert!(hints.add_setting("statement_timeout = 0; DROP TABLE a", "1").is_err());
+        assert!(hints.add_setting("1abc", "1").is_err());
+        assert!(hints.add_setting("a.b.c", "1").is_err());
+        assert!(hints.add_setting("", "1").is_err());
+
+        assert_eq!(hints.settings().len(), 2);
+    }
+
+    #[test]
+    fn optimizer_hints_can_not_close_their_comment() {
+        let mut hints = QueryHints::new();
+
+        assert!(hints.add_optimizer_hint("MAX_EXECUTION_TIME(1000)").is_ok());
+        assert!(hints.add_optimizer_hint("NO_INDEX_MERGE(t1 idx_a, idx_b)").is_ok());
+        assert!(hints
+            .add_optimizer_hint("MAX_EXECUTION_TIME(1) */ DROP TABLE a; /*")
+            .is_err());
+        assert!(hints.add_optimizer_hint("SET_VAR(sql_mode = 'a')").is_err());
+        assert!(hints.add_optimizer_hint("MAX_EXECUTION_TIME").is_err());
+
+        assert_eq!(
+            optimizer_hint_name(&hints.optimizer_hints()[0]),
+            Some("MAX_EXECUTION_TIME")
+        );
+        assert_eq!(hints.optimizer_hints().len(), 2);
+    }
+}
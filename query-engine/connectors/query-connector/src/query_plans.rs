@@ -0,0 +1,33 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+/// The execution plan of a statement, as reported by the database.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryPlan {
+    /// The SQL of the statement, with parameter placeholders.
+    pub query: String,
+    /// The plan, e.g. the output of `EXPLAIN (FORMAT JSON)` on Postgres.
+    pub plan: serde_json::Value,
+}
+
+/// Collects the execution plans of the statements a request runs. Clones share the same plans,
+/// so the request handler can read what the connector collected.
+#[derive(Debug, Clone, Default)]
+pub struct QueryPlans {
+    plans: Arc<Mutex<Vec<QueryPlan>>>,
+}
+
+impl QueryPlans {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, plan: QueryPlan) {
+        self.plans.lock().unwrap().push(plan);
+    }
+
+    /// Takes the plans collected so far.
+    pub fn take(&self) -> Vec<QueryPlan> {
+        std::mem::take(&mut *self.plans.lock().unwrap())
+    }
+}
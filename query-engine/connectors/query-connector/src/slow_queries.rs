@@ -0,0 +1,137 @@
+use serde::Serialize;
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+tokio::task_local! {
+    static SLOW_QUERY_SCOPE: SlowQueryScope;
+}
+
+/// A query that took longer than the slow query threshold of the engine.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowQuery {
+    /// The SQL of the statement, with parameter placeholders.
+    pub query: String,
+    pub parameter_count: usize,
+    pub duration_ms: u64,
+    /// The path of the GraphQL field the query was executed for, e.g. `findManyUser.posts`.
+    pub field_path: String,
+}
+
+/// Collects the queries of a request that exceed the threshold. Clones share the same queries,
+/// so the request handler can read what the connector collected.
+#[derive(Debug, Clone)]
+pub struct SlowQueryLog {
+    threshold: Duration,
+    queries: Arc<Mutex<Vec<SlowQuery>>>,
+}
+
+impl SlowQueryLog {
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            queries: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Takes the slow queries collected so far.
+    pub fn take(&self) -> Vec<SlowQuery> {
+        std::mem::take(&mut *self.queries.lock().unwrap())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SlowQueryScope {
+    log: SlowQueryLog,
+    field_path: String,
+}
+
+/// Runs `fut` for the top-level `field` of a request, recording the queries it runs that exceed
+/// the threshold of `log`. Queries run outside of such a scope are never recorded.
+pub async fn log_slow_queries<F: Future>(log: Option<&SlowQueryLog>, field: &str, fut: F) -> F::Output {
+    match log {
+        Some(log) => {
+            let scope = SlowQueryScope {
+                log: log.clone(),
+                field_path: field.to_owned(),
+            };
+
+            SLOW_QUERY_SCOPE.scope(scope, fut).await
+        }
+        None => fut.await,
+    }
+}
+
+/// Runs `fut` for the nested `field`, which is appended to the field path of the queries it runs.
+pub async fn with_field_path<F: Future>(field: &str, fut: F) -> F::Output {
+    let scope = SLOW_QUERY_SCOPE.try_with(|scope| SlowQueryScope {
+        log: scope.log.clone(),
+        field_path: format!("{}.{}", scope.field_path, field),
+    });
+
+    match scope {
+        Ok(scope) => SLOW_QUERY_SCOPE.scope(scope, fut).await,
+        Err(_) => fut.await,
+    }
+}
+
+/// Records the query if it took longer than the threshold of the current scope. Returns the
+/// recorded query, so the connector can report it.
+pub fn record_slow_query(query: &str, parameter_count: usize, duration: Duration) -> Option<SlowQuery> {
+    SLOW_QUERY_SCOPE
+        .try_with(|scope| {
+            if duration < scope.log.threshold {
+                return None;
+            }
+
+            let slow_query = SlowQuery {
+                query: query.to_owned(),
+                parameter_count,
+                duration_ms: duration.as_millis() as u64,
+                field_path: scope.field_path.clone(),
+            };
+
+            scope.log.queries.lock().unwrap().push(slow_query.clone());
+
+            Some(slow_query)
+        })
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn queries_are_recorded_with_their_field_path() {
+        let log = SlowQueryLog::new(Duration::from_millis(100));
+
+        log_slow_queries(Some(&log), "findManyUser", async {
+            assert!(record_slow_query("SELECT 1", 0, Duration::from_millis(10)).is_none());
+
+            with_field_path("posts", async {
+                record_slow_query("SELECT 2", 1, Duration::from_millis(150)).unwrap();
+            })
+            .await;
+        })
+        .await;
+
+        assert!(record_slow_query("SELECT 3", 0, Duration::from_secs(1)).is_none());
+
+        let queries = log.take();
+
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].query, "SELECT 2");
+        assert_eq!(queries[0].parameter_count, 1);
+        assert_eq!(queries[0].duration_ms, 150);
+        assert_eq!(queries[0].field_path, "findManyUser.posts");
+    }
+}
@@ -161,7 +161,7 @@ impl WriteArgs {
             }
         }
 
-        if let Some(f) = updated_at_field {
+        if let Some(f) = updated_at_field.as_ref().filter(|f| !f.is_set_by_database()) {
             if self.args.get(f.db_name()).is_none() {
                 self.args.insert(f.into(), now.into());
             }
@@ -170,7 +170,7 @@ impl WriteArgs {
 
     pub fn update_datetimes(&mut self, model: ModelRef) {
         if !self.args.is_empty() {
-            if let Some(field) = model.fields().updated_at() {
+            if let Some(field) = model.fields().updated_at().as_ref().filter(|f| !f.is_set_by_database()) {
                 if self.args.get(field.db_name()).is_none() {
                     self.args
                         .insert(field.into(), PrismaValue::DateTime(Utc::now().into()).into());
@@ -179,6 +179,29 @@ impl WriteArgs {
         }
     }
 
+    /// Starts the version of new records at 1, if the model has a `@version` field
+    /// and no version is given.
+    pub fn add_version(&mut self, model: ModelRef) {
+        if let Some(field) = model.fields().version() {
+            if self.args.get(field.db_name()).is_none() {
+                self.args.insert(field.into(), PrismaValue::Int(1).into());
+            }
+        }
+    }
+
+    /// Increments the version of updated records, if the model has a `@version` field
+    /// and no version is given.
+    pub fn increment_version(&mut self, model: ModelRef) {
+        if !self.args.is_empty() {
+            if let Some(field) = model.fields().version() {
+                if self.args.get(field.db_name()).is_none() {
+                    self.args
+                        .insert(field.into(), WriteExpression::Add(PrismaValue::Int(1)));
+                }
+            }
+        }
+    }
+
     pub fn as_record_projection(&self, model_projection: ModelProjection) -> Option<RecordProjection> {
         let pairs: Vec<_> = model_projection
             .scalar_fields()
@@ -0,0 +1,282 @@
+//! Extracts the name, table and columns of a violated constraint from the errors of the
+//! databases, and maps them back to the models and fields of the datamodel.
+//!
+//! Quaint reports either the name or the columns of the constraint, depending on the database.
+//! The rest is read from the original error message, which has a stable format per error code.
+
+use prisma_models::ModelRef;
+use quaint::error::Error;
+use user_facing_errors::query_engine::{ConstraintDetails, DatabaseConstraint};
+
+/// Whether the error is a violation of a check constraint. Quaint does not classify those.
+pub(crate) fn is_check_violation(error: &Error) -> bool {
+    match error.original_code() {
+        // Postgres, MySQL, MariaDB and SQLite (extended result code).
+        Some("23514") | Some("3819") | Some("4025") | Some("275") => true,
+        // SQL Server uses the same code for foreign key violations.
+        Some("547") => error
+            .original_message()
+            .map(|message| message.contains("CHECK constraint"))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// The details of the violated constraint, starting from what quaint already extracted.
+pub(crate) fn from_error(error: &Error, constraint: &DatabaseConstraint) -> ConstraintDetails {
+    let mut details = ConstraintDetails::from(constraint);
+
+    let (code, message) = match (error.original_code(), error.original_message()) {
+        (Some(code), Some(message)) => (code, message),
+        _ => return details,
+    };
+
+    let parsed = parse(code, message);
+
+    if parsed.name.is_some() {
+        details.name = parsed.name;
+    }
+
+    if parsed.table.is_some() {
+        details.table = parsed.table;
+    }
+
+    if !parsed.columns.is_empty() {
+        details.columns = parsed.columns;
+    }
+
+    details
+}
+
+/// Fills in the model and fields of the table of the constraint. If the database does not report
+/// the table, it is assumed to be the table of `model` if `defaults_to_model` is set. Constraints
+/// reported by name only get their columns from the unique indexes and primary key of the model.
+pub(crate) fn resolve(details: &mut ConstraintDetails, model: &ModelRef, defaults_to_model: bool) {
+    let internal_data_model = model.internal_data_model();
+
+    let target = match &details.table {
+        Some(table) => internal_data_model
+            .models()
+            .iter()
+            .find(|candidate| candidate.db_name() == table.as_str())
+            .cloned(),
+        None if defaults_to_model => Some(model.clone()),
+        None => None,
+    };
+
+    let target = match target {
+        Some(target) => target,
+        None => return,
+    };
+
+    if details.columns.is_empty() {
+        if let Some(name) = &details.name {
+            details.columns = index_columns(&target, name);
+        }
+    }
+
+    details.table = Some(target.db_name().to_owned());
+    details.model_name = Some(target.name.clone());
+    details.fields = details
+        .columns
+        .iter()
+        .filter_map(|column| target.map_scalar_db_field_name(column))
+        .map(|field| field.name.clone())
+        .collect();
+}
+
+fn parse(code: &str, message: &str) -> ConstraintDetails {
+    let mut details = ConstraintDetails::default();
+
+    match code {
+        // Postgres
+        "23505" => {
+            details.name = quoted_after(message, "unique constraint");
+        }
+        "23503" => {
+            details.name = quoted_after(message, "foreign key constraint");
+
+            // Deleting a referenced row reports the referencing table after the constraint.
+            let constraint_end = message.find("foreign key constraint").unwrap_or(0);
+            details.table =
+                quoted_after(&message[constraint_end..], "on table").or_else(|| quoted_after(message, "on table"));
+        }
+        "23502" => {
+            details.table = quoted_after(message, "of relation");
+            details.columns = quoted_after(message, "column").into_iter().collect();
+        }
+        "23514" => {
+            details.name = quoted_after(message, "check constraint");
+            details.table = quoted_after(message, "for relation");
+        }
+
+        // MySQL and MariaDB
+        "1062" => {
+            details.name = quoted_after(message, "for key");
+        }
+        "1451" | "1452" => {
+            details.name = quoted_after(message, "CONSTRAINT");
+            details.table = between(message, "fails (", ", CONSTRAINT").map(unqualified);
+            details.columns = between(message, "FOREIGN KEY (", ")")
+                .map(|columns| columns.split(',').map(unquoted).collect())
+                .unwrap_or_default();
+        }
+        "1048" => {
+            details.columns = quoted_after(message, "Column").into_iter().collect();
+        }
+        "3819" => {
+            details.name = quoted_after(message, "Check constraint");
+        }
+        "4025" => {
+            details.name = quoted_after(message, "CONSTRAINT");
+        }
+
+        // SQLite, e.g. `UNIQUE constraint failed: User.email, User.name`
+        "2067" | "1555" | "1299" => {
+            if let Some(columns) = message.split("constraint failed: ").nth(1) {
+                for column in columns.split(',') {
+                    let mut parts = column.trim().splitn(2, '.');
+
+                    if let (Some(table), Some(column)) = (parts.next(), parts.next()) {
+                        details.table = Some(table.to_owned());
+                        details.columns.push(column.to_owned());
+                    }
+                }
+            }
+        }
+        "275" => {
+            details.name = message.split("constraint failed: ").nth(1).map(ToOwned::to_owned);
+        }
+
+        // SQL Server
+        "2627" => {
+            details.name = quoted_after(message, "constraint");
+            details.table = quoted_after(message, "in object").map(|table| unqualified(&table));
+        }
+        "2601" => {
+            details.name = quoted_after(message, "unique index");
+            details.table = quoted_after(message, "in object").map(|table| unqualified(&table));
+        }
+        "547" => {
+            details.name = quoted_after(message, "constraint");
+
+            // Inserts and updates report the referenced column of a foreign key, not the
+            // constrained one.
+            if !message.contains("FOREIGN KEY constraint") {
+                details.table = quoted_after(message, ", table").map(|table| unqualified(&table));
+                details.columns = quoted_after(message, ", column").into_iter().collect();
+            }
+        }
+        "515" => {
+            details.table = quoted_after(message, ", table").map(|table| unqualified(&table));
+            details.columns = quoted_after(message, "into column").into_iter().collect();
+        }
+
+        _ => (),
+    }
+
+    details
+}
+
+/// The columns of the unique index or primary key of `model` with the given name. Indexes without
+/// an explicit name are matched against the names the migration engine gives them.
+fn index_columns(model: &ModelRef, name: &str) -> Vec<String> {
+    let table = model.db_name();
+    let mut candidates: Vec<(Vec<String>, Vec<String>)> = Vec::new();
+
+    for index in model.unique_indexes() {
+        let columns: Vec<String> = index.fields().iter().map(|field| field.db_name().to_owned()).collect();
+
+        let names = match &index.name {
+            Some(name) => vec![name.clone()],
+            None => vec![format!("{}.{}_unique", table, columns.join("_"))],
+        };
+
+        candidates.push((names, columns));
+    }
+
+    for field in model.fields().scalar().into_iter().filter(|field| field.is_unique) {
+        let names = vec![
+            format!("{}.{}_unique", table, field.db_name()),
+            format!("{}_{}_unique", table, field.db_name()),
+        ];
+
+        candidates.push((names, vec![field.db_name().to_owned()]));
+    }
+
+    if let Some(id_fields) = model.fields().id() {
+        let names = vec![format!("{}_pkey", table), String::from("PRIMARY")];
+        let columns = id_fields.iter().map(|field| field.db_name().to_owned()).collect();
+
+        candidates.push((names, columns));
+    }
+
+    // MySQL 8 prefixes the names of keys with the name of their table.
+    candidates
+        .into_iter()
+        .find(|(names, _)| {
+            names
+                .iter()
+                .any(|candidate| candidate == name || format!("{}.{}", table, candidate) == name)
+        })
+        .map(|(_, columns)| columns)
+        .unwrap_or_default()
+}
+
+/// The identifier quoted with `"`, `'` or `` ` `` right after the first occurrence of `marker`.
+fn quoted_after(message: &str, marker: &str) -> Option<String> {
+    let rest = message[message.find(marker)? + marker.len()..].trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let rest = &rest[1..];
+
+    rest.find(quote).map(|end| rest[..end].to_owned())
+}
+
+fn between<'a>(message: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let rest = &message[message.find(start)? + start.len()..];
+
+    rest.find(end).map(|end| &rest[..end])
+}
+
+/// The table name without the database and schema, e.g. `User` for `db.dbo.User`.
+fn unqualified(table: &str) -> String {
+    table.rsplit('.').next().map(unquoted).unwrap_or_default()
+}
+
+fn unquoted(identifier: &str) -> String {
+    identifier.trim().trim_matches(|c| c == '`' || c == '"').to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_errors_of_all_databases() {
+        let postgres = parse(
+            "23503",
+            r#"update or delete on table "User" violates foreign key constraint "Post_authorId_fkey" on table "Post""#,
+        );
+        assert_eq!(postgres.name.as_deref(), Some("Post_authorId_fkey"));
+        assert_eq!(postgres.table.as_deref(), Some("Post"));
+
+        let mysql = parse(
+            "1452",
+            "Cannot add or update a child row: a foreign key constraint fails (`db`.`Post`, CONSTRAINT `Post_ibfk_1` FOREIGN KEY (`authorId`) REFERENCES `User` (`id`))",
+        );
+        assert_eq!(mysql.name.as_deref(), Some("Post_ibfk_1"));
+        assert_eq!(mysql.table.as_deref(), Some("Post"));
+        assert_eq!(mysql.columns, vec!["authorId"]);
+
+        let sqlite = parse("2067", "UNIQUE constraint failed: User.firstName, User.lastName");
+        assert_eq!(sqlite.table.as_deref(), Some("User"));
+        assert_eq!(sqlite.columns, vec!["firstName", "lastName"]);
+
+        let mssql = parse(
+            "515",
+            "Cannot insert the value NULL into column 'name', table 'master.dbo.User'; column does not allow nulls. INSERT fails.",
+        );
+        assert_eq!(mssql.table.as_deref(), Some("User"));
+        assert_eq!(mssql.columns, vec!["name"]);
+    }
+}
@@ -75,6 +75,8 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // that contains all the values required for the odering row comparison (order_subquery).
             // That does _not_ mean that this retrieved row has an ordering unique across all records, because
             // that can only be true if the orderBy contains a combination of fields that are unique, or a single unique field.
+            // Group by cursors are the exception: they match all rows of a group, which share the values of the ordered
+            // fields. The subquery is limited to one row to not multiply the rows of the outer query.
             let cursor_condition = cursor_row.clone().equals(cursor_values.clone());
 
             // Orderings for this query. Influences which fields we need to fetch for comparing order fields.
@@ -86,7 +88,8 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                 .fold(Select::from_table(model.as_table()), |select, (field, _)| {
                     select.column(field.as_column())
                 })
-                .so_that(cursor_condition);
+                .so_that(cursor_condition)
+                .limit(1);
 
             let subquery_table = Table::from(order_subquery).alias(ORDER_TABLE_ALIAS);
             let len = order_definitions.len();
@@ -1,9 +1,12 @@
-use super::transaction::SqlConnectorTransaction;
+use super::{
+    instrumented::InstrumentedQueryable, transaction::SqlConnectorTransaction, ReturningSupport, StatementTimeout,
+};
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, AuditEntry, Connection, GroupBy,
+    QueryArguments, ReadOperations, RecordFilter, RelationCountSelection, RelationJoin, Transaction, WriteArgs,
+    WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,6 +16,8 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    returning: ReturningSupport,
+    statement_timeout: Option<StatementTimeout>,
 }
 
 impl<C> SqlConnection<C>
@@ -21,7 +26,32 @@ where
 {
     pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+
+        Self {
+            inner,
+            connection_info,
+            returning: ReturningSupport::default(),
+            statement_timeout: None,
+        }
+    }
+
+    /// Complete the writes supported by the database with a `RETURNING` clause, instead of
+    /// relying on the last inserted id or selecting the affected records beforehand.
+    pub fn with_returning(mut self, returning: ReturningSupport) -> Self {
+        self.returning = returning;
+        self
+    }
+
+    /// Limit the execution time of the generated statements, see `StatementTimeout`.
+    pub(crate) fn with_statement_timeout(mut self, statement_timeout: Option<StatementTimeout>) -> Self {
+        self.statement_timeout = statement_timeout;
+        self
+    }
+
+    /// The connection, timing its queries.
+    fn queryable(&self) -> InstrumentedQueryable<'_, C> {
+        InstrumentedQueryable::new(&self.inner, self.connection_info.sql_family(), None)
+            .with_statement_timeout(self.statement_timeout)
     }
 
     async fn catch<O>(
@@ -33,6 +63,16 @@ where
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
     }
+
+    /// Like `catch`, with constraint violations mapped to the datamodel, see `SqlError::with_model`.
+    async fn catch_write<O>(
+        &self,
+        model: &ModelRef,
+        fut: impl Future<Output = Result<O, SqlError>>,
+    ) -> Result<O, connector_interface::error::ConnectorError> {
+        self.catch(async move { fut.await.map_err(|err| err.with_model(model)) })
+            .await
+    }
 }
 
 #[async_trait]
@@ -43,9 +83,16 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let returning = self.returning;
+        let statement_timeout = self.statement_timeout;
+
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            let tx = SqlConnectorTransaction::new(tx, &connection_info)
+                .with_returning(returning)
+                .with_statement_timeout(statement_timeout);
+
+            Ok(Box::new(tx) as Box<dyn Transaction>)
         })
         .await
     }
@@ -61,9 +108,21 @@ where
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move {
+            read::get_single_record(
+                &self.queryable(),
+                model,
+                filter,
+                selected_fields,
+                relation_counts,
+                relation_joins,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -71,8 +130,31 @@ where
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            read::get_many_records(
+                &self.queryable(),
+                model,
+                query_arguments,
+                selected_fields,
+                relation_counts,
+                relation_joins,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn get_raw_records(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+        selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move { read::get_raw_records(&self.queryable(), query, parameters, selected_fields).await })
             .await
     }
 
@@ -81,8 +163,10 @@ where
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        self.catch(
+            async move { read::get_related_m2m_record_ids(&self.queryable(), from_field, from_record_ids).await },
+        )
+        .await
     }
 
     async fn aggregate_records(
@@ -90,12 +174,21 @@ where
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: GroupBy,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        self.catch(async move {
+            read::aggregate(
+                &self.queryable(),
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
         .await
     }
 }
@@ -106,8 +199,29 @@ where
     C: QueryExt + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch_write(model, async move {
+            write::create_record(
+                &self.queryable(),
+                model,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
+    }
+
+    async fn try_create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
+        // Outside of a transaction, a failed statement affects nothing else.
+        self.create_record(model, args).await
+    }
+
+    async fn create_records(&self, model: &ModelRef, args: Vec<WriteArgs>) -> connector::Result<usize> {
+        self.catch_write(model, async move {
+            write::create_records(&self.queryable(), model, args).await
+        })
+        .await
     }
 
     async fn update_records(
@@ -116,13 +230,73 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch_write(model, async move {
+            write::update_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn update_many_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> connector::Result<usize> {
+        self.catch_write(model, async move {
+            write::update_many_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
+    }
+
+    async fn upsert_record(
+        &self,
+        model: &ModelRef,
+        selector: RecordProjection,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> connector::Result<()> {
+        self.catch_write(model, async move {
+            write::upsert_record(
+                &self.queryable(),
+                model,
+                selector,
+                create,
+                update,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch_write(model, async move {
+            write::delete_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
     }
 
     async fn connect(
@@ -131,8 +305,10 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
-            .await
+        self.catch_write(&field.model(), async move {
+            write::connect(&self.queryable(), field, parent_id, child_ids).await
+        })
+        .await
     }
 
     async fn disconnect(
@@ -141,17 +317,26 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
-            .await
+        self.catch_write(&field.model(), async move {
+            write::disconnect(&self.queryable(), field, parent_id, child_ids).await
+        })
+        .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::execute_raw(&self.queryable(), query, parameters).await })
             .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.queryable(), query, parameters).await })
             .await
     }
+
+    async fn record_audit_entries(&self, entries: Vec<AuditEntry>) -> connector::Result<()> {
+        self.catch(async move {
+            write::record_audit_entries(&self.queryable(), self.connection_info.schema_name(), entries).await
+        })
+        .await
+    }
 }
@@ -0,0 +1,198 @@
+use crate::QueryExt;
+use async_trait::async_trait;
+use connector_interface::{self as connector, QueryPlan, QueryPlans};
+use quaint::{
+    ast::{Query, Value},
+    connector::{Queryable, ResultSet},
+    prelude::SqlFamily,
+    visitor::{self, Visitor},
+};
+use std::{borrow::Cow, future::Future, time::Instant};
+
+/// A `Queryable` that times the queries it runs, and reports the ones exceeding the slow query
+/// threshold of the request. If there are plans to collect, the statements generated by the
+/// connector are also explained before executing them. Raw queries are never explained.
+pub(crate) struct InstrumentedQueryable<'a, Q> {
+    inner: &'a Q,
+    sql_family: SqlFamily,
+    plans: Option<QueryPlans>,
+    /// The hints of the `/*+ ... */` comment added to the generated statements on MySQL.
+    optimizer_hints: Vec<String>,
+    statement_timeout: Option<StatementTimeout>,
+}
+
+/// The `statement_timeout` of the datasource URL on MySQL and MariaDB, in milliseconds. Neither has
+/// a startup parameter for it, so it is added to the generated statements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum StatementTimeout {
+    /// A `MAX_EXECUTION_TIME` optimizer hint. MySQL limits the execution time of `SELECT`
+    /// statements only.
+    MaxExecutionTime(u64),
+    /// A `SET STATEMENT max_statement_time = ... FOR` prefix, in seconds. MariaDB limits all
+    /// statements.
+    MaxStatementTime(u64),
+}
+
+/// The statements MySQL reads optimizer hints in, right after their first keyword.
+const OPTIMIZER_HINT_KEYWORDS: &[&str] = &["SELECT", "INSERT", "UPDATE", "DELETE", "REPLACE"];
+
+impl<'a, Q: Queryable> InstrumentedQueryable<'a, Q> {
+    pub(crate) fn new(inner: &'a Q, sql_family: SqlFamily, plans: Option<QueryPlans>) -> Self {
+        Self {
+            inner,
+            sql_family,
+            plans,
+            optimizer_hints: Vec::new(),
+            statement_timeout: None,
+        }
+    }
+
+    /// Add `hints` to the generated statements, see `QueryHints::add_optimizer_hint`. The hints
+    /// were validated, and are only supported on MySQL.
+    pub(crate) fn with_optimizer_hints(mut self, hints: &[String]) -> Self {
+        self.optimizer_hints = hints.to_vec();
+        self
+    }
+
+    /// Limit the execution time of the generated statements, see `StatementTimeout`.
+    pub(crate) fn with_statement_timeout(mut self, statement_timeout: Option<StatementTimeout>) -> Self {
+        self.statement_timeout = statement_timeout;
+        self
+    }
+
+    fn render<'q>(&self, q: Query<'q>) -> quaint::Result<(String, Vec<Value<'q>>)> {
+        let (sql, params) = match self.sql_family {
+            SqlFamily::Postgres => visitor::Postgres::build(q),
+            SqlFamily::Mysql => visitor::Mysql::build(q),
+            SqlFamily::Sqlite => visitor::Sqlite::build(q),
+            SqlFamily::Mssql => visitor::Mssql::build(q),
+        }?;
+
+        Ok((self.add_optimizer_hints(sql), params))
+    }
+
+    fn add_optimizer_hints(&self, sql: String) -> String {
+        let keyword_end = sql.find(' ').unwrap_or_else(|| sql.len());
+        let keyword = &sql[..keyword_end];
+
+        if !OPTIMIZER_HINT_KEYWORDS.iter().any(|k| keyword.eq_ignore_ascii_case(k)) {
+            return sql;
+        }
+
+        // MySQL uses the first of conflicting hints, the ones of the request come first.
+        let mut hints = self.optimizer_hints.clone();
+
+        if let Some(StatementTimeout::MaxExecutionTime(timeout)) = self.statement_timeout {
+            if keyword.eq_ignore_ascii_case("SELECT") {
+                hints.push(format!("MAX_EXECUTION_TIME({})", timeout));
+            }
+        }
+
+        if hints.is_empty() {
+            return sql;
+        }
+
+        format!("{} /*+ {} */{}", keyword, hints.join(" "), &sql[keyword_end..])
+    }
+
+    /// The statement to run for `sql`. The MariaDB timeout is not part of the explained statement.
+    fn executed_sql<'s>(&self, sql: &'s str) -> Cow<'s, str> {
+        match self.statement_timeout {
+            Some(StatementTimeout::MaxStatementTime(timeout)) => Cow::Owned(format!(
+                "SET STATEMENT max_statement_time = {} FOR {}",
+                timeout as f64 / 1000.0,
+                sql
+            )),
+            _ => Cow::Borrowed(sql),
+        }
+    }
+
+    async fn timed<T>(&self, sql: &str, params: &[Value<'_>], fut: impl Future<Output = T>) -> T {
+        let start = Instant::now();
+        let result = fut.await;
+
+        if let Some(slow_query) = connector::record_slow_query(sql, params.len(), start.elapsed()) {
+            tracing::warn!(
+                query = %slow_query.query,
+                parameter_count = slow_query.parameter_count,
+                duration_ms = slow_query.duration_ms,
+                field_path = %slow_query.field_path,
+                "Slow query"
+            );
+        }
+
+        result
+    }
+
+    async fn explain(&self, plans: &QueryPlans, sql: &str, params: &[Value<'_>]) -> quaint::Result<()> {
+        let explain = match self.sql_family {
+            SqlFamily::Postgres => format!("EXPLAIN (FORMAT JSON) {}", sql),
+            SqlFamily::Mysql => format!("EXPLAIN FORMAT=JSON {}", sql),
+            SqlFamily::Sqlite | SqlFamily::Mssql => return Ok(()),
+        };
+
+        let row = self.inner.query_raw(&explain, params).await?.into_single()?;
+
+        let plan = match row.at(0) {
+            Some(Value::Json(Some(plan))) => plan.clone(),
+            Some(Value::Text(Some(plan))) => {
+                serde_json::from_str(plan).unwrap_or_else(|_| serde_json::Value::String(plan.to_string()))
+            }
+            Some(Value::Bytes(Some(plan))) => serde_json::from_slice(plan)
+                .unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(plan).into_owned())),
+            _ => serde_json::Value::Null,
+        };
+
+        plans.push(QueryPlan {
+            query: sql.to_owned(),
+            plan,
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'a, Q: Queryable> Queryable for InstrumentedQueryable<'a, Q> {
+    async fn query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        let (sql, params) = self.render(q)?;
+
+        if let Some(plans) = &self.plans {
+            self.explain(plans, &sql, &params).await?;
+        }
+
+        let executed = self.executed_sql(&sql);
+        self.timed(&sql, &params, self.inner.query_raw(&executed, &params))
+            .await
+    }
+
+    async fn execute(&self, q: Query<'_>) -> quaint::Result<u64> {
+        let (sql, params) = self.render(q)?;
+
+        if let Some(plans) = &self.plans {
+            self.explain(plans, &sql, &params).await?;
+        }
+
+        let executed = self.executed_sql(&sql);
+        self.timed(&sql, &params, self.inner.execute_raw(&executed, &params))
+            .await
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet> {
+        self.timed(sql, params, self.inner.query_raw(sql, params)).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<u64> {
+        self.timed(sql, params, self.inner.execute_raw(sql, params)).await
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> quaint::Result<()> {
+        self.inner.raw_cmd(cmd).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        self.inner.version().await
+    }
+}
+
+impl<'a, Q: Queryable> QueryExt for InstrumentedQueryable<'a, Q> {}
@@ -1,4 +1,5 @@
 mod connection;
+mod instrumented;
 mod mssql;
 mod mysql;
 mod postgresql;
@@ -8,14 +9,39 @@ mod transaction;
 pub(crate) mod operations;
 
 use async_trait::async_trait;
-use connector_interface::{error::ConnectorError, Connector};
-use datamodel::Datasource;
+use connector_interface::{
+    error::{ConnectorError, ErrorKind},
+    Connector,
+};
+use datamodel::{Datasource, RetryPolicy};
+use quaint::{
+    pooled::{PooledConnection, Quaint},
+    prelude::Queryable,
+};
+use ssh_tunnel::{SshTunnel, TunnelError};
+use url::Url;
+use user_facing_errors::quaint::classify_transient_error;
 
 pub use mssql::*;
 pub use mysql::*;
 pub use postgresql::*;
 pub use sqlite::*;
 
+pub(crate) use instrumented::StatementTimeout;
+
+/// The write statements the connector completes with a `RETURNING` clause, instead of reading
+/// the affected identifiers in a separate round-trip. PostgreSQL inserts are not covered, Quaint
+/// renders their `RETURNING` clause already.
+///
+/// Only the identifiers are returned. The selection of the mutation is still read afterwards by
+/// the follow-up read of the query graph, which also resolves relations, relation counts and the
+/// read hooks; returning the selected columns to skip that read is not supported.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReturningSupport {
+    pub insert: bool,
+    pub update: bool,
+}
+
 #[async_trait]
 pub trait FromSource {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self>
@@ -32,3 +58,185 @@ async fn catch<O>(
         Err(err) => Err(err.into_connector_error(connection_info)),
     }
 }
+
+/// The URL to connect to the datasource with: a Unix domain socket given in the `socket` parameter
+/// is rewritten to the form Quaint expects, and the `ssh_*` parameters open an SSH tunnel the URL
+/// then points to. The tunnel must live as long as the pool.
+fn connection_url(source: &Datasource) -> connector_interface::Result<(String, Option<SshTunnel>)> {
+    let url = &source.url().value;
+    let invalid_url = |details: String| {
+        ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
+            details,
+            url: url.to_owned(),
+        })
+    };
+
+    let normalized = datasource_url::normalize(url).map_err(|err| invalid_url(err.to_string()))?;
+
+    match ssh_tunnel::open(&normalized) {
+        Ok((tunneled_url, tunnel)) => Ok((tunneled_url.into_owned(), tunnel)),
+        Err(TunnelError::InvalidParameters(details)) => Err(invalid_url(details)),
+        Err(TunnelError::Connection { ssh_host, message }) => {
+            Err(ConnectorError::from_kind(ErrorKind::SshTunnelError {
+                ssh_host,
+                message,
+            }))
+        }
+    }
+}
+
+/// The `statement_timeout` parameter of the datasource URL: the milliseconds a statement may run
+/// before the database cancels it. Quaint ignores the parameter. It is passed to PostgreSQL when a
+/// connection is opened, see `postgres_url`. MySQL and MariaDB have no such startup parameter, the
+/// timeout is added to the generated statements instead, see `StatementTimeout`; raw queries are
+/// not limited there. SQL Server and SQLite reject it, see `reject_statement_timeout`.
+fn statement_timeout(url: &str) -> connector_interface::Result<Option<u64>> {
+    let query = match url.splitn(2, '?').nth(1) {
+        Some(query) => query,
+        None => return Ok(None),
+    };
+
+    let value = query
+        .split('&')
+        .filter_map(|pair| {
+            let mut pair = pair.splitn(2, '=');
+            pair.next().filter(|name| *name == "statement_timeout").and(pair.next())
+        })
+        .last();
+
+    match value {
+        Some(value) => value.parse().map(Some).map_err(|_| {
+            ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
+                details: format!("`statement_timeout` must be a number of milliseconds, got `{}`.", value),
+                url: url.to_owned(),
+            })
+        }),
+        None => Ok(None),
+    }
+}
+
+/// The URL to build the PostgreSQL pool with. Quaint sends the `options` parameter to the server
+/// when it opens a connection: the `statement_timeout` is added there as a `-c` option, so that it
+/// applies to every connection of the pool from the start, without a `SET` on every checkout.
+fn postgres_url(url: &str, statement_timeout: Option<u64>) -> connector_interface::Result<String> {
+    let timeout = match statement_timeout {
+        Some(timeout) => timeout,
+        None => return Ok(url.to_owned()),
+    };
+
+    let mut parsed = Url::parse(url).map_err(|err| {
+        ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
+            details: err.to_string(),
+            url: url.to_owned(),
+        })
+    })?;
+
+    let mut options = format!("-c statement_timeout={}", timeout);
+    let mut params = Vec::new();
+
+    for (name, value) in parsed.query_pairs() {
+        match name.as_ref() {
+            "statement_timeout" => (),
+            "options" => options = format!("{} {}", value, options),
+            _ => params.push((name.into_owned(), value.into_owned())),
+        }
+    }
+
+    params.push((String::from("options"), options));
+    parsed.query_pairs_mut().clear().extend_pairs(params);
+
+    Ok(parsed.into())
+}
+
+/// SQL Server and SQLite have no statement timeout to apply the `statement_timeout` parameter to.
+/// SQL Server URLs separate their parameters with `;`.
+fn reject_statement_timeout(url: &str, database: &str) -> connector_interface::Result<()> {
+    let has_timeout = url.split(|c| c == '?' || c == '&' || c == ';').skip(1).any(|pair| {
+        pair.splitn(2, '=')
+            .next()
+            .map_or(false, |name| name.trim().eq_ignore_ascii_case("statement_timeout"))
+    });
+
+    if has_timeout {
+        return Err(ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
+            details: format!("The `statement_timeout` parameter is not supported on {}.", database),
+            url: url.to_owned(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Check out a connection from the pool, retrying with backoff on transient errors as configured
+/// by the datasource retry policy.
+async fn check_out(pool: &Quaint, retry_policy: RetryPolicy) -> crate::Result<PooledConnection> {
+    let conn = retry_policy
+        .retry(
+            || pool.check_out(),
+            |err| classify_transient_error(err).map(|transient_error| transient_error.as_str()),
+            tokio::time::delay_for,
+        )
+        .await?;
+
+    Ok(conn)
+}
+
+/// Run a trivial statement on a fresh check out from the pool. Transient errors are not retried,
+/// the result must reflect whether the database is reachable right now.
+async fn ping(pool: &Quaint) -> crate::Result<()> {
+    let conn = pool.check_out().await?;
+    conn.raw_cmd("SELECT 1").await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_timeout_is_read_from_the_url() {
+        assert_eq!(
+            statement_timeout("postgresql://localhost/db?schema=a&statement_timeout=1500").unwrap(),
+            Some(1500)
+        );
+        assert_eq!(
+            statement_timeout("mysql://localhost/db?connect_timeout=5").unwrap(),
+            None
+        );
+        assert_eq!(statement_timeout("mysql://localhost/db").unwrap(), None);
+        assert!(statement_timeout("postgresql://localhost/db?statement_timeout=1s").is_err());
+    }
+
+    #[test]
+    fn the_statement_timeout_is_a_startup_option_on_postgres() {
+        assert_eq!(
+            postgres_url("postgresql://localhost/db?schema=a&statement_timeout=1500", Some(1500)).unwrap(),
+            "postgresql://localhost/db?schema=a&options=-c+statement_timeout%3D1500"
+        );
+        assert_eq!(
+            postgres_url(
+                "postgresql://localhost/db?options=-c%20search_path%3Da&statement_timeout=1500",
+                Some(1500)
+            )
+            .unwrap(),
+            "postgresql://localhost/db?options=-c+search_path%3Da+-c+statement_timeout%3D1500"
+        );
+        assert_eq!(
+            postgres_url("postgresql://localhost/db?schema=a", None).unwrap(),
+            "postgresql://localhost/db?schema=a"
+        );
+    }
+
+    #[test]
+    fn the_statement_timeout_is_rejected_on_sql_server_and_sqlite() {
+        assert!(reject_statement_timeout("file:dev.db?statement_timeout=1500", "SQLite").is_err());
+        assert!(reject_statement_timeout("file:dev.db?connection_limit=1", "SQLite").is_ok());
+        assert!(reject_statement_timeout(
+            "sqlserver://localhost:1433;database=master;statement_timeout=1500",
+            "SQL Server"
+        )
+        .is_err());
+        assert!(reject_statement_timeout("sqlserver://localhost:1433;database=master", "SQL Server").is_ok());
+    }
+}
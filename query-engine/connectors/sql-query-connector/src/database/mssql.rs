@@ -6,19 +6,23 @@ use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector,
 };
-use datamodel::Datasource;
+use datamodel::{Datasource, RetryPolicy};
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
 use std::time::Duration;
 
 pub struct Mssql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    retry_policy: RetryPolicy,
 }
 
 #[async_trait]
 impl FromSource for Mssql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        // SSH tunnels are not supported on SQL Server.
+        let (database_str, _) = super::connection_url(source)?;
+        let database_str = database_str.as_str();
+        super::reject_statement_timeout(database_str, "SQL Server")?;
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -38,7 +42,11 @@ impl FromSource for Mssql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Self { pool, connection_info })
+        Ok(Self {
+            pool,
+            connection_info,
+            retry_policy: source.retry_policy,
+        })
     }
 }
 
@@ -46,7 +54,7 @@ impl FromSource for Mssql {
 impl Connector for Mssql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            let conn = super::check_out(&self.pool, self.retry_policy).await?;
             let conn = SqlConnection::new(conn, &self.connection_info);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
@@ -57,4 +65,8 @@ impl Connector for Mssql {
     fn name(&self) -> String {
         "mssql".to_owned()
     }
+
+    async fn ping(&self) -> connector::Result<()> {
+        super::catch(&self.connection_info, super::ping(&self.pool)).await
+    }
 }
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, ReturningSupport, StatementTimeout};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -6,19 +6,40 @@ use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector,
 };
-use datamodel::Datasource;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
-use std::time::Duration;
+use datamodel::{Datasource, RetryPolicy};
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
+use ssh_tunnel::SshTunnel;
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    time::Duration,
+};
+
+const DIALECT_UNKNOWN: u8 = 0;
+const DIALECT_MYSQL: u8 = 1;
+const DIALECT_MARIADB_WITH_RETURNING: u8 = 2;
+const DIALECT_MARIADB: u8 = 3;
 
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    retry_policy: RetryPolicy,
+    /// Milliseconds, see `super::statement_timeout`.
+    statement_timeout: Option<u64>,
+    /// Detected from the server version on the first connection.
+    dialect: AtomicU8,
+    /// Forwards the connections of the pool through a bastion host, see `ssh_tunnel`. Declared after
+    /// the pool, so that it is dropped after it.
+    _tunnel: Option<SshTunnel>,
 }
 
 #[async_trait]
 impl FromSource for Mysql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        let (database_str, tunnel) = super::connection_url(source)?;
+        let database_str = database_str.as_str();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -27,7 +48,9 @@ impl FromSource for Mysql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let statement_timeout = super::statement_timeout(database_str)?;
+
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -38,7 +61,14 @@ impl FromSource for Mysql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            connection_info,
+            retry_policy: source.retry_policy,
+            statement_timeout,
+            dialect: AtomicU8::new(DIALECT_UNKNOWN),
+            _tunnel: tunnel,
+        })
     }
 }
 
@@ -46,8 +76,35 @@ impl FromSource for Mysql {
 impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = super::check_out(&self.pool, self.retry_policy).await?;
+
+            if self.dialect.load(Ordering::Relaxed) == DIALECT_UNKNOWN {
+                let version = conn.version().await.map_err(SqlError::from)?;
+                let dialect = match version.as_deref() {
+                    Some(version) if supports_insert_returning(version) => DIALECT_MARIADB_WITH_RETURNING,
+                    Some(version) if version.contains("MariaDB") => DIALECT_MARIADB,
+                    _ => DIALECT_MYSQL,
+                };
+
+                self.dialect.store(dialect, Ordering::Relaxed);
+            }
+
+            let statement_timeout = self
+                .statement_timeout
+                .map(|timeout| match self.dialect.load(Ordering::Relaxed) {
+                    DIALECT_MYSQL => StatementTimeout::MaxExecutionTime(timeout),
+                    _ => StatementTimeout::MaxStatementTime(timeout),
+                });
+
+            // MariaDB has no `UPDATE ... RETURNING`.
+            let returning = ReturningSupport {
+                insert: self.dialect.load(Ordering::Relaxed) == DIALECT_MARIADB_WITH_RETURNING,
+                update: false,
+            };
+
+            let conn = SqlConnection::new(conn, &self.connection_info)
+                .with_returning(returning)
+                .with_statement_timeout(statement_timeout);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -57,4 +114,44 @@ impl Connector for Mysql {
     fn name(&self) -> String {
         "mysql".to_owned()
     }
+
+    async fn ping(&self) -> connector::Result<()> {
+        super::catch(&self.connection_info, super::ping(&self.pool)).await
+    }
+}
+
+/// MariaDB supports `INSERT ... RETURNING` from 10.5 on. The version string looks like
+/// `10.5.8-MariaDB-1:10.5.8+maria~focal`, prefixed with `5.5.5-` in the protocol handshake.
+fn supports_insert_returning(version: &str) -> bool {
+    if !version.contains("MariaDB") {
+        return false;
+    }
+
+    let version = version.trim_start_matches("5.5.5-");
+    let mut components = version
+        .split(|c: char| !c.is_ascii_digit())
+        .take(2)
+        .map(|component| component.parse::<u32>().ok());
+
+    match (components.next(), components.next()) {
+        (Some(Some(major)), Some(Some(minor))) => (major, minor) >= (10, 5),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_returning_is_supported_from_mariadb_10_5() {
+        assert!(supports_insert_returning("10.5.8-MariaDB-1:10.5.8+maria~focal"));
+        assert!(supports_insert_returning("11.0.2-MariaDB"));
+        assert!(supports_insert_returning("5.5.5-10.6.4-MariaDB"));
+
+        assert!(!supports_insert_returning("10.4.17-MariaDB-1:10.4.17+maria~focal"));
+        assert!(!supports_insert_returning("10.0.38-MariaDB"));
+        assert!(!supports_insert_returning("8.0.22"));
+        assert!(!supports_insert_returning("MariaDB"));
+    }
 }
@@ -4,19 +4,25 @@ use crate::{
     QueryExt, SqlError,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
+use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
+    relation_counts: &[RelationCountSelection],
+    relation_joins: &[RelationJoin],
 ) -> crate::Result<Option<SingleRecord>> {
     let query = read::get_records(&model, selected_fields.as_columns(), filter);
-    let field_names = selected_fields.db_names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+    let query = read::with_relation_counts(query, relation_counts);
+    let query = read::with_relation_joins(query, relation_joins);
+    let field_names = field_names(selected_fields, relation_counts, relation_joins);
+    let idents = idents(selected_fields, relation_counts, relation_joins);
 
     let record = (match conn.find(query, idents.as_slice()).await {
         Ok(result) => Ok(Some(result)),
@@ -35,11 +41,21 @@ pub async fn get_many_records(
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
+    relation_counts: &[RelationCountSelection],
+    relation_joins: &[RelationJoin],
+    sql_family: SqlFamily,
 ) -> crate::Result<ManyRecords> {
     let reversed = query_arguments.needs_reversed_order();
-    let field_names = selected_fields.db_names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
-    let mut records = ManyRecords::new(field_names);
+    let mut field_names = field_names(selected_fields, relation_counts, relation_joins);
+    let mut idents = idents(selected_fields, relation_counts, relation_joins);
+
+    // The numbers of the window are selected last.
+    if query_arguments.window.is_some() {
+        field_names.extend(vec![Window::ROW_NUMBER.to_owned(), Window::RANK.to_owned()]);
+        idents.extend(vec![(TypeIdentifier::Int, FieldArity::Required, DateTimeMode::Utc); 2]);
+    }
+
+    let mut records = ManyRecords::new(field_names.clone());
 
     if let Some(0) = query_arguments.take {
         return Ok(records);
@@ -57,6 +73,8 @@ pub async fn get_many_records(
 
         for args in batches.into_iter() {
             let query = read::get_records(model, selected_fields.as_columns(), args);
+            let query = read::with_relation_counts(query, relation_counts);
+            let query = read::with_relation_joins(query, relation_joins);
             futures.push(conn.filter(query.into(), idents.as_slice()));
         }
 
@@ -69,8 +87,38 @@ pub async fn get_many_records(
         if !order.is_empty() {
             records.order_by(&order)
         }
+    } else if let Some(window) = &query_arguments.window {
+        // All records matching the filter are numbered, the ordering and paging apply to the
+        // numbered records, which therefore also select the ordered fields.
+        let selected: Vec<&str> = selected_fields.db_names().collect();
+        let ordered_columns = query_arguments
+            .order_by
+            .iter()
+            .filter(|order_by| !selected.contains(&order_by.field.db_name()))
+            .unique_by(|order_by| order_by.field.db_name())
+            .map(|order_by| order_by.field.as_column());
+
+        let records_args = QueryArguments {
+            filter: query_arguments.filter.clone(),
+            ..QueryArguments::new(model.clone())
+        };
+
+        let query = read::get_records(model, selected_fields.as_columns().chain(ordered_columns), records_args);
+        let query = read::with_relation_counts(query, relation_counts);
+        let query = read::with_relation_joins(query, relation_joins);
+        let query = read::with_window(query, &field_names, window, &query_arguments);
+
+        for item in conn
+            .filter_windowed(query, window, model, idents.as_slice(), sql_family)
+            .await?
+            .into_iter()
+        {
+            records.push(Record::from(item))
+        }
     } else {
         let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::with_relation_counts(query, relation_counts);
+        let query = read::with_relation_joins(query, relation_joins);
 
         for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
@@ -84,19 +132,74 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// The names of the selected fields, followed by the aliases of the relation counts and of the
+/// fields of the joined relations.
+fn field_names(
+    selected_fields: &ModelProjection,
+    relation_counts: &[RelationCountSelection],
+    relation_joins: &[RelationJoin],
+) -> Vec<String> {
+    selected_fields
+        .db_names()
+        .map(String::from)
+        .chain(relation_counts.iter().map(|selection| selection.db_alias()))
+        .chain(relation_joins.iter().flat_map(|join| join.db_aliases()))
+        .collect()
+}
+
+/// The types of the selected fields, followed by the types of the relation counts and of the
+/// fields of the joined relations. Joined fields are optional, as there might be no related record.
+fn idents(
+    selected_fields: &ModelProjection,
+    relation_counts: &[RelationCountSelection],
+    relation_joins: &[RelationJoin],
+) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
+    let mut idents = selected_fields.column_types();
+    idents.extend(
+        relation_counts
+            .iter()
+            .map(|_| (TypeIdentifier::Int, FieldArity::Required, DateTimeMode::Utc)),
+    );
+
+    for join in relation_joins {
+        idents.extend(
+            join.selected_fields
+                .column_types()
+                .into_iter()
+                .map(|(ident, _, date_time_mode)| (ident, FieldArity::Optional, date_time_mode)),
+        );
+    }
+
+    idents
+}
+
+/// Executes a plain SQL query and maps the result columns onto the selected fields of the model by
+/// their database names, coercing the values with the type identifiers of the fields.
+pub async fn get_raw_records(
+    conn: &dyn QueryExt,
+    query: String,
+    parameters: Vec<PrismaValue>,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    let field_names: Vec<String> = selected_fields.db_names().map(String::from).collect();
+    let idents: Vec<_> = selected_fields.column_types();
+    let mut records = ManyRecords::new(field_names.clone());
+
+    for item in conn.raw_rows(query, parameters, &field_names, &idents).await? {
+        records.push(Record::from(item))
+    }
+
+    Ok(records)
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
     from_record_ids: &[RecordProjection],
 ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>> {
     let mut idents = vec![];
-    idents.extend(from_field.model().primary_identifier().type_identifiers_with_arities());
-    idents.extend(
-        from_field
-            .related_model()
-            .primary_identifier()
-            .type_identifiers_with_arities(),
-    );
+    idents.extend(from_field.model().primary_identifier().column_types());
+    idents.extend(from_field.related_model().primary_identifier().column_types());
 
     let relation = from_field.relation();
     let table = relation.as_table();
@@ -150,13 +253,14 @@ pub async fn aggregate(
     model: &ModelRef,
     query_arguments: QueryArguments,
     selections: Vec<AggregationSelection>,
-    group_by: Vec<ScalarFieldRef>,
+    group_by: GroupBy,
     having: Option<Filter>,
+    sql_family: SqlFamily,
 ) -> crate::Result<Vec<AggregationRow>> {
-    if group_by.len() > 0 {
-        group_by_aggregate(conn, model, query_arguments, selections, group_by, having).await
+    if !group_by.is_empty() {
+        group_by_aggregate(conn, model, query_arguments, selections, group_by, having, sql_family).await
     } else {
-        plain_aggregate(conn, model, query_arguments, selections)
+        plain_aggregate(conn, model, query_arguments, selections, sql_family)
             .await
             .map(|v| vec![v])
     }
@@ -167,20 +271,38 @@ async fn plain_aggregate(
     model: &ModelRef,
     query_arguments: QueryArguments,
     selections: Vec<AggregationSelection>,
+    sql_family: SqlFamily,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &selections, query_arguments);
+    let (statistical, selections): (Vec<_>, Vec<_>) =
+        selections.into_iter().partition(AggregationSelection::is_statistical);
 
-    let idents: Vec<_> = selections
-        .iter()
-        .flat_map(|aggregator| aggregator.identifiers())
-        .collect();
+    let mut results = Vec::new();
 
-    let mut rows = conn.filter(query.into(), idents.as_slice()).await?;
-    let row = rows
-        .pop()
-        .expect("Expected exactly one return row for aggregation query.");
+    if !selections.is_empty() {
+        let query = read::aggregate(model, &selections, query_arguments.clone());
 
-    Ok(row.into_aggregation_results(&selections))
+        let idents: Vec<_> = selections
+            .iter()
+            .flat_map(|aggregator| aggregator.identifiers())
+            .collect();
+
+        let mut rows = conn.filter(query.into(), idents.as_slice()).await?;
+        let row = rows
+            .pop()
+            .expect("Expected exactly one return row for aggregation query.");
+
+        results.extend(row.into_aggregation_results(&selections));
+    }
+
+    // Statistical aggregations are executed in a separate query over the same records.
+    if !statistical.is_empty() {
+        let records = read::aggregation_records(model, &statistical, query_arguments);
+        let row = conn.statistical_aggregate(records, &statistical, sql_family).await?;
+
+        results.extend(row.into_aggregation_results(&statistical));
+    }
+
+    Ok(results)
 }
 
 async fn group_by_aggregate(
@@ -188,20 +310,43 @@ async fn group_by_aggregate(
     model: &ModelRef,
     query_arguments: QueryArguments,
     selections: Vec<AggregationSelection>,
-    group_by: Vec<ScalarFieldRef>,
+    group_by: GroupBy,
     having: Option<Filter>,
+    sql_family: SqlFamily,
 ) -> crate::Result<Vec<AggregationRow>> {
-    let query = read::group_by_aggregate(model, query_arguments, &selections, group_by, having);
+    let reversed = query_arguments.needs_reversed_order();
+    let query = read::group_by_aggregate(model, query_arguments, &selections, &group_by, having);
 
-    let idents: Vec<_> = selections
+    let mut idents: Vec<_> = selections
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
         .collect();
 
-    let rows = conn.filter(query.into(), idents.as_slice()).await?;
+    let mut rows = if group_by.rollup {
+        // The grouping of the last grouped field is selected last, it is 1 for subtotal rows.
+        idents.push((TypeIdentifier::Int, FieldArity::Required, DateTimeMode::Utc));
+        conn.filter_rollup(query, &group_by.fields, idents.as_slice(), sql_family)
+            .await?
+    } else {
+        conn.filter(query.into(), idents.as_slice()).await?
+    };
+
+    // A negative take reverses the ordering in SQL, the groups are returned in the requested order.
+    if reversed {
+        rows.reverse();
+    }
 
     Ok(rows
         .into_iter()
-        .map(|row| row.into_aggregation_results(&selections))
+        .map(|mut row| {
+            let grouping = if group_by.rollup { row.values.pop() } else { None };
+            let mut results = row.into_aggregation_results(&selections);
+
+            if let Some(grouping) = grouping {
+                results.push(AggregationResult::Subtotal(grouping != PrismaValue::Int(0)));
+            }
+
+            results
+        })
         .collect())
 }
@@ -1,56 +1,42 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{error::SqlError, query_builder::write, QueryExt, ReturningSupport};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::prelude::SqlFamily;
 use std::{collections::HashMap, convert::TryFrom};
-use user_facing_errors::query_engine::DatabaseConstraint;
 
 /// Create a single record to the database defined in `conn`, resulting into a
 /// `RecordProjection` as an identifier pointing to the just-created record.
-pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+///
+/// With `returning.insert`, the `RETURNING` clause selects the identifier columns
+/// only, see `ReturningSupport`.
+pub async fn create_record(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: WriteArgs,
+    returning: ReturningSupport,
+    sql_family: SqlFamily,
+) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
 
-    let result_set = match conn.insert(insert).await {
-        Ok(id) => id,
-        Err(e) => match e.kind() {
-            ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
-                quaint::error::DatabaseConstraint::Index(name) => {
-                    let constraint = DatabaseConstraint::Index(name.clone());
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::Fields(fields) => {
-                    let constraint = DatabaseConstraint::Fields(fields.clone());
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::ForeignKey => {
-                    let constraint = DatabaseConstraint::ForeignKey;
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-            },
-            ErrorKind::NullConstraintViolation { constraint } => match constraint {
-                quaint::error::DatabaseConstraint::Index(name) => {
-                    let constraint = DatabaseConstraint::Index(name.clone());
-                    return Err(SqlError::NullConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::Fields(fields) => {
-                    let constraint = DatabaseConstraint::Fields(fields.clone());
-                    return Err(SqlError::NullConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::ForeignKey => {
-                    let constraint = DatabaseConstraint::ForeignKey;
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-            },
-            _ => return Err(SqlError::from(e)),
-        },
+    let inserted = if returning.insert {
+        conn.query_returning(
+            insert.into(),
+            model.primary_identifier().db_names().collect(),
+            sql_family,
+        )
+        .await
+    } else {
+        conn.insert(insert).await.map_err(SqlError::from)
     };
 
+    let result_set = inserted?;
+
     match (returned_id, result_set.len(), result_set.last_insert_id()) {
         // All values provided in the write arrghs
         (Some(identifier), _, _) if !identifier.misses_autogen_value() => Ok(identifier),
 
-        // PostgreSQL with a working RETURNING statement
+        // A database with a working RETURNING statement
         (_, n, _) if n > 0 => Ok(RecordProjection::try_from((&model.primary_identifier(), result_set))?),
 
         // We have an auto-incremented id that we got from MySQL or SQLite
@@ -63,15 +49,86 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
     }
 }
 
+/// Create a record like `create_record` in a savepoint, which is rolled back if
+/// the insert fails. Otherwise, Postgres rejects all further statements of the
+/// transaction after a failed one.
+pub async fn create_record_in_savepoint(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: WriteArgs,
+    returning: ReturningSupport,
+    sql_family: SqlFamily,
+) -> crate::Result<RecordProjection> {
+    let (savepoint, rollback, release) = match sql_family {
+        SqlFamily::Mssql => (
+            "SAVE TRANSACTION prisma_create",
+            "ROLLBACK TRANSACTION prisma_create",
+            None,
+        ),
+        _ => (
+            "SAVEPOINT prisma_create",
+            "ROLLBACK TO SAVEPOINT prisma_create",
+            Some("RELEASE SAVEPOINT prisma_create"),
+        ),
+    };
+
+    conn.raw_cmd(savepoint).await?;
+
+    match create_record(conn, model, args, returning, sql_family).await {
+        Ok(id) => {
+            if let Some(release) = release {
+                conn.raw_cmd(release).await?;
+            }
+
+            Ok(id)
+        }
+        Err(err) => {
+            conn.raw_cmd(rollback).await?;
+            Err(err)
+        }
+    }
+}
+
+/// Create multiple records in the database defined in `conn`, batching them
+/// into multi-row inserts. Results in the number of records created.
+pub async fn create_records(conn: &dyn QueryExt, model: &ModelRef, args: Vec<WriteArgs>) -> crate::Result<usize> {
+    let count = args.len();
+
+    for insert in write::create_records(model, args) {
+        conn.query(insert).await?;
+    }
+
+    Ok(count)
+}
+
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
+///
+/// If the database supports `UPDATE ... RETURNING` and the records are not
+/// already selected, the update happens in one round-trip without reading the
+/// identifiers first.
 pub async fn update_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
+    returning: ReturningSupport,
+    sql_family: SqlFamily,
 ) -> crate::Result<Vec<RecordProjection>> {
+    if let (Some(version), Some(expected)) = (model.fields().version(), record_filter.expected_version.clone()) {
+        if !args.is_empty() {
+            return update_versioned_records(conn, model, record_filter, args, version, expected).await;
+        }
+    }
+
+    if returning.update && record_filter.selectors.is_none() && !args.is_empty() {
+        let update = write::update_by_filter(model, record_filter.filter, args)?;
+        return conn
+            .update_returning_ids(update, model.primary_identifier(), sql_family)
+            .await;
+    }
+
     let ids = conn.filter_selectors(model, record_filter).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
@@ -91,12 +148,184 @@ pub async fn update_records(
     Ok(merge_write_args(ids, id_args))
 }
 
+/// Update the records defined in `record_filter` like `update_records`,
+/// resulting in the number of records updated.
+///
+/// Records selected by a filter are updated in a single `UPDATE ... WHERE`
+/// statement, including filters on related records, instead of reading their
+/// identifiers first and updating them in chunks of identifiers. Not on MySQL,
+/// which by default counts only the rows whose values actually changed.
+pub async fn update_many_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+    returning: ReturningSupport,
+    sql_family: SqlFamily,
+) -> crate::Result<usize> {
+    let update_by_filter = record_filter.expected_version.is_none()
+        && record_filter.selectors.is_none()
+        && !args.is_empty()
+        && !matches!(sql_family, SqlFamily::Mysql);
+
+    if !update_by_filter {
+        let ids = update_records(conn, model, record_filter, args, returning, sql_family).await?;
+        return Ok(ids.len());
+    }
+
+    let update = write::update_by_filter(model, record_filter.filter, args)?;
+    let count = conn.execute(update.into()).await?;
+
+    Ok(count as usize)
+}
+
+/// Create the record defined by `create`, or update the record identified by
+/// `selector` with `update` if it exists, in a single statement.
+///
+/// MySQL updates the existing record on a conflict with any unique key, not
+/// only the one of the selector. If the `INSERT` could conflict with another
+/// unique key, the record is read first and then created or updated instead.
+pub async fn upsert_record(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    selector: RecordProjection,
+    create: WriteArgs,
+    update: WriteArgs,
+    returning: ReturningSupport,
+    sql_family: SqlFamily,
+) -> crate::Result<()> {
+    if matches!(sql_family, SqlFamily::Mysql) && may_conflict_elsewhere(model, &selector, &create) {
+        if conn.filter_ids(model, selector.clone().filter()).await?.is_empty() {
+            create_record(conn, model, create, returning, sql_family).await?;
+        } else {
+            update_records(conn, model, selector.filter().into(), update, returning, sql_family).await?;
+        }
+
+        return Ok(());
+    }
+
+    let conflict_columns = selector.fields().map(|field| field.db_name().to_owned()).collect();
+    let (insert, assignments) = write::upsert_record(model, create, update)?;
+
+    conn.upsert(insert, model.db_name(), conflict_columns, assignments, sql_family)
+        .await?;
+
+    Ok(())
+}
+
+/// Whether inserting `create` could violate a unique key of the model other
+/// than the one of the `selector`, which requires values for all its fields.
+fn may_conflict_elsewhere(model: &ModelRef, selector: &RecordProjection, create: &WriteArgs) -> bool {
+    let selector_fields: Vec<ScalarFieldRef> = selector.fields().collect();
+
+    let mut unique_keys: Vec<Vec<ScalarFieldRef>> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| field.unique())
+        .map(|field| vec![field])
+        .collect();
+
+    unique_keys.extend(model.fields().id());
+    unique_keys.extend(model.unique_indexes().into_iter().map(|index| index.fields()));
+
+    unique_keys
+        .into_iter()
+        .filter(|fields| fields != &selector_fields)
+        .any(|fields| {
+            fields.iter().all(|field| {
+                !matches!(
+                    create.get_field_value(field.db_name()),
+                    None | Some(WriteExpression::Value(PrismaValue::Null))
+                )
+            })
+        })
+}
+
+/// Updates the records of a model with a `@version` field, if they still have the
+/// `expected` version. Otherwise another operation changed a record since the client
+/// read it and the update fails.
+async fn update_versioned_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+    version: &ScalarFieldRef,
+    expected: PrismaValue,
+) -> crate::Result<Vec<RecordProjection>> {
+    let ids = conn.filter_selectors(model, record_filter).await?;
+    let id_args = pick_args(&model.primary_identifier(), &args);
+
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let updates = {
+        let ids: Vec<&RecordProjection> = ids.iter().collect();
+        write::update_many_versioned(model, ids.as_slice(), version, expected, args)?
+    };
+
+    let mut count = 0;
+
+    for update in updates {
+        count += conn.execute(update).await?;
+    }
+
+    if (count as usize) < ids.len() {
+        return Err(SqlError::RecordChanged {
+            model: model.name.clone(),
+        });
+    }
+
+    Ok(merge_write_args(ids, id_args))
+}
+
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
+///
+/// Records selected by a filter are deleted in a single `DELETE ... WHERE`
+/// statement if possible. Their identifiers are still read first if relation
+/// table rows need to be deleted along with them.
 pub async fn delete_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
+    sql_family: SqlFamily,
 ) -> crate::Result<usize> {
+    if let (Some(version), Some(expected)) = (model.fields().version(), record_filter.expected_version.clone()) {
+        let ids = conn.filter_selectors(model, record_filter).await?;
+        let ids: Vec<&RecordProjection> = ids.iter().collect();
+        let mut count = 0;
+
+        for delete in write::delete_many_versioned(model, ids.as_slice(), version, expected) {
+            count += conn.execute(delete).await?;
+        }
+
+        if (count as usize) < ids.len() {
+            return Err(SqlError::RecordChanged {
+                model: model.name.clone(),
+            });
+        }
+
+        if model.internal_data_model().emulates_referential_actions() {
+            for delete in write::delete_relation_table_links(model, ids.as_slice()) {
+                conn.query(delete).await?;
+            }
+        }
+
+        return Ok(ids.len());
+    }
+
+    let delete_by_filter = record_filter.selectors.is_none()
+        && !model.internal_data_model().emulates_referential_actions()
+        && can_delete_by_filter(&record_filter.filter, sql_family);
+
+    if delete_by_filter {
+        let delete = write::delete_by_filter(model, record_filter.filter);
+        let count = conn.execute(delete.into()).await?;
+
+        return Ok(count as usize);
+    }
+
     let ids = conn.filter_selectors(model, record_filter).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
@@ -109,9 +338,22 @@ pub async fn delete_records(
         conn.query(delete).await?;
     }
 
+    if model.internal_data_model().emulates_referential_actions() {
+        for delete in write::delete_relation_table_links(model, ids.as_slice()) {
+            conn.query(delete).await?;
+        }
+    }
+
     Ok(count)
 }
 
+/// Whether the records matching `filter` can be deleted by a statement
+/// filtering them directly. MySQL rejects `DELETE` statements with subqueries
+/// selecting from the table deleted from, which relation filters may do.
+fn can_delete_by_filter(filter: &Filter, sql_family: SqlFamily) -> bool {
+    !(matches!(sql_family, SqlFamily::Mysql) && filter.has_relation_filters())
+}
+
 /// Connect relations defined in `child_ids` to a parent defined in `parent_id`.
 /// The relation information is in the `RelationFieldRef`.
 pub async fn connect(
@@ -158,6 +400,19 @@ pub async fn query_raw(
     Ok(value)
 }
 
+/// Insert the entries into the audit log table of the schema named `schema_name`.
+pub async fn record_audit_entries(
+    conn: &dyn QueryExt,
+    schema_name: &str,
+    entries: Vec<AuditEntry>,
+) -> crate::Result<()> {
+    for insert in write::create_audit_entries(schema_name, entries) {
+        conn.query(insert).await?;
+    }
+
+    Ok(())
+}
+
 /// Picks all arguments out of `args` that are updating a value for a field
 /// contained in `projection`, as those need to be merged into the records later on.
 fn pick_args(projection: &ModelProjection, args: &WriteArgs) -> WriteArgs {
@@ -204,7 +459,7 @@ fn merge_write_args(loaded_ids: Vec<RecordProjection>, incoming_args: WriteArgs)
 
 fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
     match expr {
-        WriteExpression::Field(_) => unimplemented!(),
+        WriteExpression::Field(_) => unreachable!("Field references are rejected when building the update."),
         WriteExpression::Value(pv) => pv,
         WriteExpression::Add(rhs) => val + rhs,
         WriteExpression::Substract(rhs) => val - rhs,
@@ -1,23 +1,29 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, ReturningSupport};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector,
 };
-use datamodel::Datasource;
+use datamodel::{Datasource, RetryPolicy};
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use ssh_tunnel::SshTunnel;
 use std::time::Duration;
 
 pub struct PostgreSql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    retry_policy: RetryPolicy,
+    /// Forwards the connections of the pool through a bastion host, see `ssh_tunnel`. Declared after
+    /// the pool, so that it is dropped after it.
+    _tunnel: Option<SshTunnel>,
 }
 
 #[async_trait]
 impl FromSource for PostgreSql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        let (database_str, tunnel) = super::connection_url(source)?;
+        let database_str = database_str.as_str();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -26,7 +32,10 @@ impl FromSource for PostgreSql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let statement_timeout = super::statement_timeout(database_str)?;
+        let pool_url = super::postgres_url(database_str, statement_timeout)?;
+
+        let mut builder = Quaint::builder(&pool_url)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -36,7 +45,12 @@ impl FromSource for PostgreSql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
-        Ok(PostgreSql { pool, connection_info })
+        Ok(PostgreSql {
+            pool,
+            connection_info,
+            retry_policy: source.retry_policy,
+            _tunnel: tunnel,
+        })
     }
 }
 
@@ -44,8 +58,14 @@ impl FromSource for PostgreSql {
 impl Connector for PostgreSql {
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = super::check_out(&self.pool, self.retry_policy).await?;
+
+            let returning = ReturningSupport {
+                insert: false,
+                update: true,
+            };
+
+            let conn = SqlConnection::new(conn, &self.connection_info).with_returning(returning);
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
@@ -54,4 +74,8 @@ impl Connector for PostgreSql {
     fn name(&self) -> String {
         "postgres".to_owned()
     }
+
+    async fn ping(&self) -> connector_interface::Result<()> {
+        super::catch(&self.connection_info, super::ping(&self.pool)).await
+    }
 }
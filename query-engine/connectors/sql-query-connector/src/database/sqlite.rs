@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, ReturningSupport};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -7,12 +7,30 @@ use connector_interface::{
     Connection, Connector,
 };
 use datamodel::Datasource;
-use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
-use std::{convert::TryFrom, time::Duration};
+use quaint::{
+    connector::SqliteParams,
+    error::ErrorKind as QuaintKind,
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
+use std::{
+    convert::TryFrom,
+    sync::atomic::{AtomicU8, Ordering},
+    time::Duration,
+};
+
+/// The file path of in-memory databases, e.g. `file::memory:?cache=shared` or `sqlite::memory:`.
+const IN_MEMORY_FILE_PATH: &str = ":memory:";
+
+const RETURNING_UNKNOWN: u8 = 0;
+const RETURNING_UNSUPPORTED: u8 = 1;
+const RETURNING_SUPPORTED: u8 = 2;
 
 pub struct Sqlite {
     pool: Quaint,
     file_path: String,
+    /// Detected from the SQLite library version on the first connection.
+    returning: AtomicU8,
 }
 
 impl Sqlite {
@@ -28,23 +46,31 @@ impl Sqlite {
 #[async_trait]
 impl FromSource for Sqlite {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Sqlite> {
-        let database_str = &source.url().value;
+        // SQLite databases are files, there is no tunnel to keep.
+        let (database_str, _) = super::connection_url(source)?;
+        let database_str = database_str.as_str();
+        super::reject_statement_timeout(database_str, "SQLite")?;
 
         let connection_info = ConnectionInfo::from_url(database_str)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let params = SqliteParams::try_from(database_str.as_str())
+        let params = SqliteParams::try_from(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
         let file_path = params.file_path;
+        let in_memory = file_path == IN_MEMORY_FILE_PATH;
 
         let url_with_db = {
-            let db_name = std::path::Path::new(&file_path)
-                .file_stem()
-                .and_then(|stem| stem.to_str())
-                .ok_or_else(|| invalid_file_path_error(&file_path, &connection_info))?
-                .to_owned();
+            let db_name = if in_memory {
+                "memory".to_owned()
+            } else {
+                std::path::Path::new(&file_path)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .ok_or_else(|| invalid_file_path_error(&file_path, &connection_info))?
+                    .to_owned()
+            };
 
             let mut splitted = database_str.split('?');
             let url = splitted.next().unwrap();
@@ -65,13 +91,24 @@ impl FromSource for Sqlite {
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
-        builder.max_idle_lifetime(Duration::from_secs(300));
+        // An in-memory database lives as long as its connection, so all the queries must share a
+        // single connection that is never closed for being idle.
+        if in_memory {
+            builder.connection_limit(1);
+        } else {
+            builder.max_idle_lifetime(Duration::from_secs(300));
+        }
+
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
         let pool = builder.build();
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool,
+            file_path,
+            returning: AtomicU8::new(RETURNING_UNKNOWN),
+        })
     }
 }
 
@@ -88,7 +125,24 @@ impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+
+            if self.returning.load(Ordering::Relaxed) == RETURNING_UNKNOWN {
+                let version = conn.version().await.map_err(SqlError::from)?;
+                let returning = match version.as_deref().map(supports_returning) {
+                    Some(true) => RETURNING_SUPPORTED,
+                    _ => RETURNING_UNSUPPORTED,
+                };
+
+                self.returning.store(returning, Ordering::Relaxed);
+            }
+
+            let supported = self.returning.load(Ordering::Relaxed) == RETURNING_SUPPORTED;
+            let returning = ReturningSupport {
+                insert: supported,
+                update: supported,
+            };
+
+            let conn = SqlConnection::new(conn, self.connection_info()).with_returning(returning);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -98,4 +152,64 @@ impl Connector for Sqlite {
     fn name(&self) -> String {
         "sqlite".to_owned()
     }
+
+    async fn ping(&self) -> connector::Result<()> {
+        super::catch(&self.connection_info(), super::ping(&self.pool)).await
+    }
+
+    /// Decided from the schema of the database, not from `PRAGMA foreign_keys`, which is a
+    /// setting of each pooled connection and on by default in the bundled library. Foreign keys
+    /// are not enforced if the library was built without foreign key support, or if the database
+    /// has tables but none of them declares a foreign key, like files created before the
+    /// relations were constrained.
+    async fn enforces_foreign_keys(&self) -> connector::Result<bool> {
+        super::catch(&self.connection_info(), async move {
+            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+
+            // Libraries built without foreign key support return no row for the pragma.
+            let supported = !conn
+                .query_raw("PRAGMA foreign_keys", &[])
+                .await
+                .map_err(SqlError::from)?
+                .is_empty();
+
+            // The database file is attached under its schema name, see `from_source`.
+            let sql = format!(
+                r#"SELECT
+                    (SELECT COUNT(*) FROM "{schema}".sqlite_master
+                     WHERE type = 'table' AND name NOT LIKE 'sqlite%' AND substr(name, 1, 7) <> '_prisma') AS tables,
+                    (SELECT COUNT(*) FROM "{schema}".sqlite_master AS m, pragma_foreign_key_list(m.name, '{schema}')
+                     WHERE m.type = 'table') AS foreign_keys"#,
+                schema = self.connection_info().schema_name()
+            );
+
+            let row = conn.query_raw(&sql, &[]).await.map_err(SqlError::from)?.into_single()?;
+            let count = |column: &str| row.get(column).and_then(|value| value.as_i64()).unwrap_or(0);
+
+            let enforced = supported && (count("tables") == 0 || count("foreign_keys") > 0);
+
+            if !enforced {
+                tracing::warn!(
+                    file_path = self.file_path.as_str(),
+                    "SQLite does not enforce foreign keys for this database. Referential actions are emulated by the query engine."
+                );
+            }
+
+            Ok(enforced)
+        })
+        .await
+    }
+}
+
+/// SQLite supports `RETURNING` on `INSERT`, `UPDATE` and `DELETE` from 3.35.0 on.
+fn supports_returning(version: &str) -> bool {
+    let mut components = version
+        .split('.')
+        .take(2)
+        .map(|component| component.trim().parse::<u32>().ok());
+
+    match (components.next(), components.next()) {
+        (Some(Some(major)), Some(Some(minor))) => (major, minor) >= (3, 35),
+        _ => false,
+    }
 }
@@ -1,17 +1,31 @@
+use super::{instrumented::InstrumentedQueryable, ReturningSupport, StatementTimeout};
 use crate::database::operations::*;
 use crate::SqlError;
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector,
+    error::{ConnectorError, ErrorKind},
+    filter::Filter,
+    AggregationRow, AggregationSelection, AuditEntry, GroupBy, QueryArguments, QueryHints, QueryPlans, ReadOperations,
+    RecordFilter, RelationCountSelection, RelationJoin, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::prelude::ConnectionInfo;
+use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
+use std::sync::Mutex;
 
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    returning: ReturningSupport,
+    query_plans: Mutex<Option<QueryPlans>>,
+    /// The optimizer hints of the request, added to the generated statements on MySQL.
+    optimizer_hints: Mutex<Vec<String>>,
+    /// The statement timeout of the datasource URL on MySQL, unless a query hint overrides it.
+    statement_timeout: Mutex<Option<StatementTimeout>>,
+    /// MySQL session variables set by query hints, reset to their defaults before the transaction
+    /// ends.
+    session_settings: Mutex<Vec<String>>,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
@@ -20,9 +34,36 @@ impl<'tx> SqlConnectorTransaction<'tx> {
         Self {
             inner: tx,
             connection_info,
+            returning: ReturningSupport::default(),
+            query_plans: Mutex::new(None),
+            optimizer_hints: Mutex::new(Vec::new()),
+            statement_timeout: Mutex::new(None),
+            session_settings: Mutex::new(Vec::new()),
         }
     }
 
+    /// Complete the writes in this transaction with a `RETURNING` clause where supported.
+    pub fn with_returning(mut self, returning: ReturningSupport) -> Self {
+        self.returning = returning;
+        self
+    }
+
+    /// Limit the execution time of the generated statements, see `StatementTimeout`.
+    pub(crate) fn with_statement_timeout(self, statement_timeout: Option<StatementTimeout>) -> Self {
+        *self.statement_timeout.lock().unwrap() = statement_timeout;
+        self
+    }
+
+    /// The transaction, timing its queries and explaining the generated statements if the request
+    /// asked for it. The generated statements carry the optimizer hints of the request.
+    fn queryable(&self) -> InstrumentedQueryable<'_, quaint::connector::Transaction<'tx>> {
+        let plans = self.query_plans.lock().unwrap().clone();
+
+        InstrumentedQueryable::new(&self.inner, self.connection_info.sql_family(), plans)
+            .with_optimizer_hints(&self.optimizer_hints.lock().unwrap())
+            .with_statement_timeout(*self.statement_timeout.lock().unwrap())
+    }
+
     async fn catch<O>(
         &self,
         fut: impl std::future::Future<Output = Result<O, SqlError>>,
@@ -32,18 +73,132 @@ impl<'tx> SqlConnectorTransaction<'tx> {
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
     }
+
+    /// MySQL has no transaction-scoped settings, the session variables the query hints set would
+    /// outlive the transaction on the pooled connection otherwise.
+    async fn reset_session_settings(&self) -> connector::Result<()> {
+        let names = std::mem::take(&mut *self.session_settings.lock().unwrap());
+
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        let assignments: Vec<String> = names.iter().map(|name| format!("{} = DEFAULT", name)).collect();
+        let statement = format!("SET SESSION {}", assignments.join(", "));
+
+        self.catch(async move { Ok(self.inner.raw_cmd(&statement).await.map_err(SqlError::from)?) })
+            .await
+    }
+
+    /// Like `catch`, with constraint violations mapped to the datamodel, see `SqlError::with_model`.
+    async fn catch_write<O>(
+        &self,
+        model: &ModelRef,
+        fut: impl std::future::Future<Output = Result<O, SqlError>>,
+    ) -> Result<O, connector_interface::error::ConnectorError> {
+        self.catch(async move { fut.await.map_err(|err| err.with_model(model)) })
+            .await
+    }
 }
 
 #[async_trait]
 impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
+    // The transaction is rolled back instead when the session settings can't be reset.
     async fn commit(&self) -> connector::Result<()> {
+        if let Err(err) = self.reset_session_settings().await {
+            self.inner.rollback().await.ok();
+            return Err(err);
+        }
+
         self.catch(async move { Ok(self.inner.commit().await.map_err(SqlError::from)?) })
             .await
     }
 
     async fn rollback(&self) -> connector::Result<()> {
+        let reset = self.reset_session_settings().await;
+
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
-            .await
+            .await?;
+
+        reset
+    }
+
+    async fn apply_query_hints(&self, hints: &QueryHints) -> connector::Result<()> {
+        if let Some(plans) = hints.query_plans() {
+            match self.connection_info.sql_family() {
+                SqlFamily::Postgres | SqlFamily::Mysql => *self.query_plans.lock().unwrap() = Some(plans.clone()),
+                family => {
+                    return Err(ConnectorError::from_kind(ErrorKind::UnsupportedFeature(format!(
+                        "Explaining queries is not supported on {}.",
+                        family.as_str()
+                    ))))
+                }
+            }
+        }
+
+        if !hints.optimizer_hints().is_empty() {
+            match self.connection_info.sql_family() {
+                SqlFamily::Mysql => *self.optimizer_hints.lock().unwrap() = hints.optimizer_hints().to_vec(),
+                family => {
+                    return Err(ConnectorError::from_kind(ErrorKind::UnsupportedFeature(format!(
+                        "Optimizer hints are not supported on {}.",
+                        family.as_str()
+                    ))))
+                }
+            }
+        }
+
+        if hints.settings().is_empty() {
+            return Ok(());
+        }
+
+        match self.connection_info.sql_family() {
+            SqlFamily::Postgres => {
+                self.catch(async move {
+                    for (name, value) in hints.settings() {
+                        // The name is validated to be a plain identifier, the value is escaped.
+                        let statement = format!("SET LOCAL {} = '{}'", name, value.replace('\'', "''"));
+                        self.inner.raw_cmd(&statement).await.map_err(SqlError::from)?;
+                    }
+
+                    Ok(())
+                })
+                .await
+            }
+            // The settings are session variables, e.g. `max_execution_time` on MySQL or
+            // `max_statement_time` on MariaDB. Numbers are rendered as such, the variables do not
+            // accept string literals. Setting one of the timeouts overrides the statement timeout
+            // of the datasource URL.
+            SqlFamily::Mysql => {
+                self.catch(async move {
+                    for (name, value) in hints.settings() {
+                        if name.eq_ignore_ascii_case("max_execution_time")
+                            || name.eq_ignore_ascii_case("max_statement_time")
+                        {
+                            *self.statement_timeout.lock().unwrap() = None;
+                        }
+
+                        let value = if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                            value.to_owned()
+                        } else {
+                            format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+                        };
+
+                        self.session_settings.lock().unwrap().push(name.to_owned());
+
+                        let statement = format!("SET SESSION {} = {}", name, value);
+                        self.inner.raw_cmd(&statement).await.map_err(SqlError::from)?;
+                    }
+
+                    Ok(())
+                })
+                .await
+            }
+            family => Err(ConnectorError::from_kind(ErrorKind::UnsupportedFeature(format!(
+                "Query hints are not supported on {}.",
+                family.as_str()
+            )))),
+        }
     }
 }
 
@@ -54,9 +209,21 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move {
+            read::get_single_record(
+                &self.queryable(),
+                model,
+                filter,
+                selected_fields,
+                relation_counts,
+                relation_joins,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -64,8 +231,31 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationCountSelection],
+        relation_joins: &[RelationJoin],
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.queryable(),
+                model,
+                query_arguments,
+                selected_fields,
+                relation_counts,
+                relation_joins,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn get_raw_records(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move { read::get_raw_records(&self.queryable(), query, parameters, selected_fields).await })
             .await
     }
 
@@ -74,8 +264,10 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        self.catch(
+            async move { read::get_related_m2m_record_ids(&self.queryable(), from_field, from_record_ids).await },
+        )
+        .await
     }
 
     async fn aggregate_records(
@@ -83,12 +275,21 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: GroupBy,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        self.catch(async move {
+            read::aggregate(
+                &self.queryable(),
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
         .await
     }
 }
@@ -96,8 +297,38 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
 #[async_trait]
 impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch_write(model, async move {
+            write::create_record(
+                &self.queryable(),
+                model,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
+    }
+
+    async fn try_create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
+        self.catch_write(model, async move {
+            write::create_record_in_savepoint(
+                &self.queryable(),
+                model,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn create_records(&self, model: &ModelRef, args: Vec<WriteArgs>) -> connector::Result<usize> {
+        self.catch_write(model, async move {
+            write::create_records(&self.queryable(), model, args).await
+        })
+        .await
     }
 
     async fn update_records(
@@ -106,13 +337,73 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch_write(model, async move {
+            write::update_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
+    }
+
+    async fn update_many_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> connector::Result<usize> {
+        self.catch_write(model, async move {
+            write::update_many_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                args,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn upsert_record(
+        &self,
+        model: &ModelRef,
+        selector: RecordProjection,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> connector::Result<()> {
+        self.catch_write(model, async move {
+            write::upsert_record(
+                &self.queryable(),
+                model,
+                selector,
+                create,
+                update,
+                self.returning,
+                self.connection_info.sql_family(),
+            )
+            .await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch_write(model, async move {
+            write::delete_records(
+                &self.queryable(),
+                model,
+                record_filter,
+                self.connection_info.sql_family(),
+            )
             .await
+        })
+        .await
     }
 
     async fn connect(
@@ -121,8 +412,10 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
-            .await
+        self.catch_write(&field.model(), async move {
+            write::connect(&self.queryable(), field, parent_id, child_ids).await
+        })
+        .await
     }
 
     async fn disconnect(
@@ -131,17 +424,26 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
-            .await
+        self.catch_write(&field.model(), async move {
+            write::disconnect(&self.queryable(), field, parent_id, child_ids).await
+        })
+        .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::execute_raw(&self.queryable(), query, parameters).await })
             .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.queryable(), query, parameters).await })
             .await
     }
+
+    async fn record_audit_entries(&self, entries: Vec<AuditEntry>) -> connector::Result<()> {
+        self.catch(async move {
+            write::record_audit_entries(&self.queryable(), self.connection_info.schema_name(), entries).await
+        })
+        .await
+    }
 }
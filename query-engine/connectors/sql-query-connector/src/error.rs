@@ -1,9 +1,10 @@
+use crate::constraint_details;
 use connector_interface::{error::*, Filter};
-use prisma_models::prelude::DomainError;
+use prisma_models::{prelude::DomainError, ModelRef};
 use quaint::error::ErrorKind as QuaintKind;
 use std::{any::Any, string::FromUtf8Error};
 use thiserror::Error;
-use user_facing_errors::query_engine::DatabaseConstraint;
+use user_facing_errors::query_engine::{ConstraintDetails, DatabaseConstraint};
 
 pub struct RawError {
     code: Option<String>,
@@ -41,17 +42,35 @@ impl From<Box<dyn Any + Send>> for RawError {
 #[derive(Debug, Error)]
 pub enum SqlError {
     #[error("Unique constraint failed: {:?}", constraint)]
-    UniqueConstraintViolation { constraint: DatabaseConstraint },
+    UniqueConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Null constraint failed: {:?}", constraint)]
-    NullConstraintViolation { constraint: DatabaseConstraint },
+    NullConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Foreign key constraint failed")]
-    ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
+    ForeignKeyConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
+
+    #[error("Check constraint failed: {:?}", constraint)]
+    CheckConstraintViolation {
+        constraint: DatabaseConstraint,
+        details: ConstraintDetails,
+    },
 
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
+    #[error("The record for model {} was changed by another operation.", model)]
+    RecordChanged { model: String },
+
     #[error("Table {} does not exist", _0)]
     TableDoesNotExist(String),
 
@@ -110,23 +129,40 @@ pub enum SqlError {
 }
 
 impl SqlError {
+    /// Maps the table and columns of a constraint violation to the model and fields of the
+    /// datamodel. `model` is the model of the failing write, which is assumed to hold the
+    /// constraint if the database does not report its table.
+    pub(crate) fn with_model(mut self, model: &ModelRef) -> Self {
+        match &mut self {
+            SqlError::UniqueConstraintViolation { details, .. }
+            | SqlError::NullConstraintViolation { details, .. }
+            | SqlError::CheckConstraintViolation { details, .. } => constraint_details::resolve(details, model, true),
+            // Deleting a referenced record violates a foreign key of another table.
+            SqlError::ForeignKeyConstraintViolation { details, .. } => {
+                constraint_details::resolve(details, model, false)
+            }
+            _ => (),
+        }
+
+        self
+    }
+
     pub(crate) fn into_connector_error(self, connection_info: &quaint::prelude::ConnectionInfo) -> ConnectorError {
         match self {
-            SqlError::UniqueConstraintViolation { constraint } => ConnectorError {
-                user_facing_error: Some(user_facing_errors::KnownError::new(
-                    user_facing_errors::query_engine::UniqueKeyViolation {
-                        constraint: constraint.clone(),
-                    },
-                )),
-                kind: ErrorKind::UniqueConstraintViolation { constraint },
-            },
-            SqlError::NullConstraintViolation { constraint } => {
-                ConnectorError::from_kind(ErrorKind::NullConstraintViolation { constraint })
+            SqlError::UniqueConstraintViolation { constraint, details } => {
+                ConnectorError::from_kind(ErrorKind::UniqueConstraintViolation { constraint, details })
+            }
+            SqlError::NullConstraintViolation { constraint, details } => {
+                ConnectorError::from_kind(ErrorKind::NullConstraintViolation { constraint, details })
+            }
+            SqlError::ForeignKeyConstraintViolation { constraint, details } => {
+                ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint, details })
             }
-            SqlError::ForeignKeyConstraintViolation { constraint } => {
-                ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint })
+            SqlError::CheckConstraintViolation { constraint, details } => {
+                ConnectorError::from_kind(ErrorKind::CheckConstraintViolation { constraint, details })
             }
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
+            SqlError::RecordChanged { model } => ConnectorError::from_kind(ErrorKind::RecordChanged { model }),
             SqlError::TableDoesNotExist(table) => ConnectorError::from_kind(ErrorKind::TableDoesNotExist { table }),
             SqlError::ColumnDoesNotExist(column) => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist { column }),
             SqlError::ConnectionError(e) => ConnectorError {
@@ -192,6 +228,25 @@ impl From<prisma_models::ConversionFailure> for SqlError {
 
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
+        if constraint_details::is_check_violation(&e) {
+            let details = constraint_details::from_error(&e, &DatabaseConstraint::Fields(Vec::new()));
+            let constraint = match &details.name {
+                Some(name) => DatabaseConstraint::Index(name.clone()),
+                None => DatabaseConstraint::Fields(details.columns.clone()),
+            };
+
+            return Self::CheckConstraintViolation { constraint, details };
+        }
+
+        let details = match e.kind() {
+            QuaintKind::UniqueConstraintViolation { constraint }
+            | QuaintKind::NullConstraintViolation { constraint }
+            | QuaintKind::ForeignKeyConstraintViolation { constraint } => {
+                constraint_details::from_error(&e, &constraint.into())
+            }
+            _ => ConstraintDetails::default(),
+        };
+
         match QuaintKind::from(e) {
             QuaintKind::FromRowError(_) => todo!("QuaintKind::FromRowError"),
             QuaintKind::QueryError(qe) => Self::QueryError(qe),
@@ -199,14 +254,17 @@ impl From<quaint::error::Error> for SqlError {
             QuaintKind::NotFound => Self::RecordDoesNotExist,
             QuaintKind::UniqueConstraintViolation { constraint } => Self::UniqueConstraintViolation {
                 constraint: constraint.into(),
+                details,
             },
 
             QuaintKind::NullConstraintViolation { constraint } => Self::NullConstraintViolation {
                 constraint: constraint.into(),
+                details,
             },
 
             QuaintKind::ForeignKeyConstraintViolation { constraint } => Self::ForeignKeyConstraintViolation {
                 constraint: constraint.into(),
+                details,
             },
 
             e @ QuaintKind::ConnectionError(_) => Self::ConnectionError(e),
@@ -1,4 +1,5 @@
 use connector_interface::filter::*;
+use datamodel::common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME};
 use prisma_models::prelude::*;
 use quaint::ast::*;
 
@@ -116,6 +117,7 @@ impl AliasedCondition for Filter {
                 }
             },
             Filter::Scalar(filter) => filter.aliased_cond(alias),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
@@ -134,7 +136,13 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(mut self, alias: Option<Alias>) -> ConditionTree<'static> {
+        // Case-insensitive columns (e.g. `citext`) already compare insensitively, and wrapping them
+        // in `LOWER()` would prevent the database from using their indexes.
+        if matches!(&self.projection, ScalarProjection::Single(field) if field.is_case_insensitive()) {
+            self.mode = QueryMode::Default;
+        }
+
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -170,6 +178,19 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree using the array operators of
+    /// the database. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let comparable: Expression = match alias {
+            Some(alias) => self.field.as_column().table(alias.to_string(None)).into(),
+            None => self.field.as_column().into(),
+        };
+
+        convert_scalar_list_filter(comparable, self.condition, &self.field)
+    }
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -311,10 +332,35 @@ impl AliasedCondition for AggregationFilter {
 
 fn aggregate_conditions<T>(filter: Filter, alias: Option<Alias>, field_transformer: T) -> ConditionTree<'static>
 where
-    T: Fn(Column) -> Expression,
+    T: Fn(Column) -> Expression + Copy,
 {
+    // Negated and date filters combine several conditions on the aggregate.
     let sf = match filter {
         Filter::Scalar(sf) => sf,
+        Filter::And(filters) => {
+            return ConditionTree::And(
+                filters
+                    .into_iter()
+                    .map(|f| aggregate_conditions(f, alias, field_transformer).into())
+                    .collect(),
+            )
+        }
+        Filter::Or(filters) => {
+            return ConditionTree::Or(
+                filters
+                    .into_iter()
+                    .map(|f| aggregate_conditions(f, alias, field_transformer).into())
+                    .collect(),
+            )
+        }
+        Filter::Not(filters) => {
+            return ConditionTree::And(
+                filters
+                    .into_iter()
+                    .map(|f| aggregate_conditions(f, alias, field_transformer).not().into())
+                    .collect(),
+            )
+        }
         _ => unimplemented!(),
     };
 
@@ -334,7 +380,7 @@ where
 }
 
 fn convert_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>> + Clone,
     cond: ScalarCondition,
     mode: QueryMode,
     fields: &[ScalarFieldRef],
@@ -346,7 +392,7 @@ fn convert_scalar_filter(
 }
 
 fn default_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>> + Clone,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
 ) -> ConditionTree<'static> {
@@ -391,13 +437,16 @@ fn default_scalar_filter(
             }
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
+        ScalarCondition::HasBits(mask) => bitwise_filter(comparable, convert_value(fields, mask), false),
+        ScalarCondition::HasAnyBits(mask) => bitwise_filter(comparable, convert_value(fields, mask), true),
+        ScalarCondition::InTheLast(window) => relative_time_filter(comparable, window, fields),
     };
 
     ConditionTree::single(condition)
 }
 
 fn insensitive_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>> + Clone,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
 ) -> ConditionTree<'static> {
@@ -462,6 +511,132 @@ fn insensitive_scalar_filter(
                     .collect::<Vec<_>>(),
             ),
         },
+        ScalarCondition::HasBits(mask) => bitwise_filter(comparable, convert_value(fields, mask), false),
+        ScalarCondition::HasAnyBits(mask) => bitwise_filter(comparable, convert_value(fields, mask), true),
+        ScalarCondition::InTheLast(window) => relative_time_filter(comparable, window, fields),
+    };
+
+    ConditionTree::single(condition)
+}
+
+/// `column & mask = mask`, or `column & mask <> 0` if any of the bits suffices. The bitwise AND
+/// binds tighter than the comparison on all supported databases.
+fn bitwise_filter(comparable: impl Comparable<'static>, mask: Value<'static>, any: bool) -> Compare<'static> {
+    let masked = Expression::from(comparable.compare_raw("&", mask.clone()));
+
+    if any {
+        masked.not_equals(Value::integer(0))
+    } else {
+        masked.equals(mask)
+    }
+}
+
+/// `column >= start AND now >= column`, with both ends of the window taken from the clock of the
+/// database. The values are assumed to be stored in UTC, as with the default `dateTimeMode`. quaint
+/// only renders raw SQL as the operator of a comparison, so the column is repeated as its right
+/// operand.
+fn relative_time_filter<C>(comparable: C, window: RelativeTime, fields: &[ScalarFieldRef]) -> Compare<'static>
+where
+    C: Comparable<'static> + Into<Expression<'static>> + Clone,
+{
+    let (start, now) = relative_time_bounds(fields.first().unwrap(), window);
+
+    comparable
+        .clone()
+        .compare_raw(format!(">= {} AND {} >=", start, now), comparable)
+}
+
+/// The SQL of the start of the window and of the current time. The amount is an integer and the
+/// unit a keyword, so both are rendered inline.
+fn relative_time_bounds(field: &ScalarFieldRef, window: RelativeTime) -> (String, String) {
+    let RelativeTime { amount, unit } = window;
+
+    match field.internal_data_model().provider.as_str() {
+        MYSQL_SOURCE_NAME => {
+            let unit = match unit {
+                TimeUnit::Seconds => "SECOND",
+                TimeUnit::Minutes => "MINUTE",
+                TimeUnit::Hours => "HOUR",
+                TimeUnit::Days => "DAY",
+                TimeUnit::Weeks => "WEEK",
+                TimeUnit::Months => "MONTH",
+                TimeUnit::Years => "YEAR",
+            };
+
+            let now = "UTC_TIMESTAMP(3)";
+            (format!("{} - INTERVAL {} {}", now, amount, unit), now.to_owned())
+        }
+        SQLITE_SOURCE_NAME => {
+            // SQLite stores DateTime values as milliseconds since the epoch, and its date modifiers
+            // have no weeks.
+            let (amount, unit) = match unit {
+                TimeUnit::Seconds => (amount, "seconds"),
+                TimeUnit::Minutes => (amount, "minutes"),
+                TimeUnit::Hours => (amount, "hours"),
+                TimeUnit::Days => (amount, "days"),
+                TimeUnit::Weeks => (amount.saturating_mul(7), "days"),
+                TimeUnit::Months => (amount, "months"),
+                TimeUnit::Years => (amount, "years"),
+            };
+
+            let millis =
+                |modifier: String| format!("CAST((julianday('now'{}) - 2440587.5) * 86400000 AS INTEGER)", modifier);
+
+            (millis(format!(", '-{} {}'", amount, unit)), millis(String::new()))
+        }
+        MSSQL_SOURCE_NAME => {
+            let unit = match unit {
+                TimeUnit::Seconds => "second",
+                TimeUnit::Minutes => "minute",
+                TimeUnit::Hours => "hour",
+                TimeUnit::Days => "day",
+                TimeUnit::Weeks => "week",
+                TimeUnit::Months => "month",
+                TimeUnit::Years => "year",
+            };
+
+            let now = "SYSUTCDATETIME()";
+            (format!("DATEADD({}, -{}, {})", unit, amount, now), now.to_owned())
+        }
+        _ => {
+            let unit = match unit {
+                TimeUnit::Seconds => "seconds",
+                TimeUnit::Minutes => "minutes",
+                TimeUnit::Hours => "hours",
+                TimeUnit::Days => "days",
+                TimeUnit::Weeks => "weeks",
+                TimeUnit::Months => "months",
+                TimeUnit::Years => "years",
+            };
+
+            // `timestamp` columns hold the UTC wall clock time, `timestamptz` columns an instant.
+            let now = match &field.native_type {
+                Some(native_type) if native_type.name == "Timestamptz" => "CURRENT_TIMESTAMP",
+                _ => "(CURRENT_TIMESTAMP AT TIME ZONE 'UTC')",
+            };
+
+            (format!("{} - INTERVAL '{} {}'", now, amount, unit), now.to_owned())
+        }
+    }
+}
+
+fn convert_scalar_list_filter(
+    comparable: Expression<'static>,
+    cond: ScalarListCondition,
+    field: &ScalarFieldRef,
+) -> ConditionTree<'static> {
+    let condition = match cond {
+        ScalarListCondition::Contains(value) => {
+            comparable.compare_raw("@>", field.value(PrismaValue::List(vec![value])))
+        }
+        ScalarListCondition::ContainsEvery(values) => {
+            comparable.compare_raw("@>", field.value(PrismaValue::List(values)))
+        }
+        ScalarListCondition::ContainsSome(values) => {
+            comparable.compare_raw("&&", field.value(PrismaValue::List(values)))
+        }
+        ScalarListCondition::IsEmpty(true) => comparable.compare_raw("=", field.value(PrismaValue::List(Vec::new()))),
+        ScalarListCondition::IsEmpty(false) => comparable.compare_raw("<>", field.value(PrismaValue::List(Vec::new()))),
     };
 
     ConditionTree::single(condition)
@@ -483,3 +658,90 @@ fn convert_values<'a>(fields: &[ScalarFieldRef], values: Vec<PrismaValue>) -> Ve
         values.into_iter().map(|value| field.value(value)).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector_interface::ScalarCompare;
+    use datamodel::common::provider_names::POSTGRES_SOURCE_NAME;
+    use quaint::visitor::{self, Visitor};
+
+    fn created_at(provider: &str) -> (InternalDataModelRef, ScalarFieldRef) {
+        let datamodel = r#"
+            model Event {
+                id        Int      @id
+                createdAt DateTime
+            }
+        "#;
+
+        let mut template = DatamodelConverter::convert_string(datamodel.to_owned());
+        template.provider = provider.to_owned();
+
+        let internal_data_model = template.build("db".to_owned());
+        let model = internal_data_model.find_model("Event").unwrap();
+        let field = model.fields().find_from_scalar("createdAt").unwrap();
+
+        (internal_data_model, field)
+    }
+
+    fn bounds(provider: &str, amount: i64, unit: TimeUnit) -> (String, String) {
+        let (_internal_data_model, field) = created_at(provider);
+
+        relative_time_bounds(&field, RelativeTime { amount, unit })
+    }
+
+    #[test]
+    fn relative_time_bounds_use_the_clock_of_the_database() {
+        let pg_now = "(CURRENT_TIMESTAMP AT TIME ZONE 'UTC')";
+        assert_eq!(
+            bounds(POSTGRES_SOURCE_NAME, 3, TimeUnit::Months),
+            (format!("{} - INTERVAL '3 months'", pg_now), pg_now.to_owned())
+        );
+
+        assert_eq!(
+            bounds(MYSQL_SOURCE_NAME, 2, TimeUnit::Weeks),
+            (
+                "UTC_TIMESTAMP(3) - INTERVAL 2 WEEK".to_owned(),
+                "UTC_TIMESTAMP(3)".to_owned()
+            )
+        );
+
+        assert_eq!(
+            bounds(SQLITE_SOURCE_NAME, 2, TimeUnit::Weeks),
+            (
+                "CAST((julianday('now', '-14 days') - 2440587.5) * 86400000 AS INTEGER)".to_owned(),
+                "CAST((julianday('now') - 2440587.5) * 86400000 AS INTEGER)".to_owned()
+            )
+        );
+
+        assert_eq!(
+            bounds(MSSQL_SOURCE_NAME, 30, TimeUnit::Seconds),
+            (
+                "DATEADD(second, -30, SYSUTCDATETIME())".to_owned(),
+                "SYSUTCDATETIME()".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn in_the_last_binds_no_instants_of_the_engine() {
+        let (_internal_data_model, field) = created_at(SQLITE_SOURCE_NAME);
+        let filter = field.in_the_last(RelativeTime {
+            amount: 7,
+            unit: TimeUnit::Days,
+        });
+
+        let query = Select::from_table("Event").so_that(filter.aliased_cond(None));
+        let (sql, params) = visitor::Sqlite::build(query).unwrap();
+
+        assert!(params.is_empty(), "{:?}", params);
+        assert!(
+            sql.contains(
+                "`createdAt` >= CAST((julianday('now', '-7 days') - 2440587.5) * 86400000 AS INTEGER) \
+                 AND CAST((julianday('now') - 2440587.5) * 86400000 AS INTEGER) >= "
+            ),
+            "{}",
+            sql
+        );
+    }
+}
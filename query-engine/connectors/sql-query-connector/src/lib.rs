@@ -1,3 +1,4 @@
+mod constraint_details;
 mod cursor_condition;
 mod database;
 mod error;
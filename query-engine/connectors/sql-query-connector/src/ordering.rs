@@ -8,13 +8,30 @@ pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>>
     let needs_reversed_order = query_arguments.needs_reversed_order();
 
     query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
+        let expr = order_expression(next_order_by);
+
         match (next_order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
-            (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Ascending, false) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Descending, false) => acc.push(next_order_by.field.as_column().descend()),
+            (SortOrder::Ascending, true) => acc.push((expr, Some(Order::Desc))),
+            (SortOrder::Descending, true) => acc.push((expr, Some(Order::Asc))),
+            (SortOrder::Ascending, false) => acc.push((expr, Some(Order::Asc))),
+            (SortOrder::Descending, false) => acc.push((expr, Some(Order::Desc))),
         }
 
         acc
     })
 }
+
+/// The expression to order by: either the column itself, or the same aggregate expression over
+/// the column the group by query selects, which keeps it valid next to `GROUP BY` and `HAVING`.
+fn order_expression(order_by: &OrderBy) -> Expression<'static> {
+    let column = order_by.field.as_column();
+
+    match order_by.sort_aggregation {
+        None => column.into(),
+        Some(SortAggregation::Count) => count(column).into(),
+        Some(SortAggregation::Avg) => avg(column).into(),
+        Some(SortAggregation::Sum) => sum(column).into(),
+        Some(SortAggregation::Min) => min(column).into(),
+        Some(SortAggregation::Max) => max(column).into(),
+    }
+}
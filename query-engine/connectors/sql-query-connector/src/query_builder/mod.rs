@@ -9,6 +9,9 @@ use quaint::ast::{Column, Comparable, ConditionTree, Query, Row, Values};
 
 const PARAMETER_LIMIT: usize = 2000;
 
+/// The maximum number of rows in a multi-row `INSERT`, SQL Server rejects more.
+const INSERT_ROW_LIMIT: usize = 1000;
+
 pub(super) fn chunked_conditions<F, Q>(
     columns: &[Column<'static>],
     records: &[&RecordProjection],
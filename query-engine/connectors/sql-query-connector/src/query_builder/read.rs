@@ -1,5 +1,12 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
-use connector_interface::{filter::Filter, AggregationSelection, QueryArguments};
+use crate::{
+    cursor_condition,
+    filter_conversion::{Alias, AliasMode, AliasedCondition},
+    ordering,
+    query_arguments_ext::QueryArgumentsExt,
+};
+use connector_interface::{
+    filter::Filter, AggregationSelection, GroupBy, QueryArguments, RelationCountSelection, RelationJoin, Window,
+};
 use itertools::Itertools;
 use prisma_models::*;
 use quaint::ast::*;
@@ -72,6 +79,175 @@ where
     columns.fold(query.into_select(model), |acc, col| acc.column(col))
 }
 
+/// Adds a correlated subquery for each relation count to the select, counting the related records
+/// of every record, e.g. for the posts of a user:
+/// ```sql
+/// SELECT
+///     `User`.`id`,
+///     (
+///         SELECT COUNT(*) FROM `Post` AS `j0`
+///         WHERE `j0`.`authorId` = `User`.`id` AND `j0`.`published` = true
+///     ) AS `_count_posts`
+/// FROM
+///     `User`
+/// ```
+/// Many-to-many relations count the rows of the join table, joined with the related table for the
+/// filter.
+pub fn with_relation_counts(select: Select<'static>, relation_counts: &[RelationCountSelection]) -> Select<'static> {
+    relation_counts.iter().fold(select, |select, selection| {
+        let count_query = Expression::from(relation_count(selection)).alias(selection.db_alias());
+        select.value(count_query)
+    })
+}
+
+/// Left joins the related table of each to-one relation and selects the fields of the related
+/// record, e.g. for the author of a post:
+/// ```sql
+/// SELECT
+///     `Post`.`id`,
+///     `_join_author`.`id` AS `_join_author.id`
+/// FROM
+///     `Post`
+///     LEFT JOIN `User` AS `_join_author` ON `_join_author`.`id` = `Post`.`authorId`
+/// ```
+pub fn with_relation_joins(select: Select<'static>, relation_joins: &[RelationJoin]) -> Select<'static> {
+    relation_joins.iter().fold(select, |select, join| {
+        let field = &join.field;
+        let table_alias = join.table_alias();
+
+        let related_columns: Vec<Column<'static>> = field
+            .related_field()
+            .linking_fields()
+            .as_columns()
+            .map(|column| column.table(table_alias.clone()))
+            .collect();
+
+        let parent_columns: Vec<Column<'static>> = field.linking_fields().as_columns().collect();
+
+        let related_table = field
+            .related_model()
+            .as_table()
+            .alias(table_alias.clone())
+            .on(Row::from(related_columns).equals(Row::from(parent_columns)));
+
+        join.selected_fields
+            .as_columns()
+            .zip(join.db_aliases())
+            .fold(select.left_join(related_table), |select, (column, alias)| {
+                select.column(column.table(table_alias.clone()).alias(alias))
+            })
+    })
+}
+
+/// Markers of the window functions of `with_window`, which quaint can't render. They are replaced
+/// in the rendered query, see `QueryExt::filter_windowed`. Like `ROLLUP_MARKER`, they start with
+/// a NUL character, which no table or column name can contain.
+pub const ROW_NUMBER_MARKER: &str = "\u{0}row_number";
+pub const RANK_MARKER: &str = "\u{0}rank";
+
+/// Numbers the `records` within the partitions of the `window` and keeps the records numbered up
+/// to its `take`, before ordering and paging them with `args`, e.g. for the top posts per author:
+/// ```sql
+/// SELECT "id", "title", "_rowNumber", "_rank" FROM (
+///     SELECT
+///         "Post"."id",
+///         "Post"."title",
+///         ROW_NUMBER() OVER (PARTITION BY "Post"."authorId" ORDER BY "Post"."likes" DESC, "Post"."id" ASC) AS "_rowNumber",
+///         RANK() OVER (PARTITION BY "Post"."authorId" ORDER BY "Post"."likes" DESC) AS "_rank"
+///     FROM "Post"
+///     WHERE ...
+/// ) AS "windowed"
+/// WHERE "_rowNumber" <= 3
+/// ORDER BY "title" ASC
+/// ```
+/// The `records` must select the fields `args` orders by, `columns` are the returned columns.
+pub fn with_window(
+    records: Select<'static>,
+    columns: &[String],
+    window: &Window,
+    args: &QueryArguments,
+) -> Select<'static> {
+    let records = records
+        .column(Column::from(ROW_NUMBER_MARKER).alias(Window::ROW_NUMBER))
+        .column(Column::from(RANK_MARKER).alias(Window::RANK));
+
+    let select = columns.iter().fold(
+        Select::from_table(Table::from(records).alias("windowed")),
+        |select, column| select.column(Column::from(column.clone())),
+    );
+
+    let select = match window.take {
+        Some(take) => select.so_that(Column::from(Window::ROW_NUMBER).less_than_or_equals(take)),
+        None => select,
+    };
+
+    let reversed = args.needs_reversed_order();
+
+    let select = args.order_by.iter().fold(select, |select, order_by| {
+        let order = match (order_by.sort_order, reversed) {
+            (SortOrder::Ascending, false) | (SortOrder::Descending, true) => Order::Asc,
+            (SortOrder::Ascending, true) | (SortOrder::Descending, false) => Order::Desc,
+        };
+
+        select.order_by((Column::from(order_by.field.db_name().to_owned()).into(), Some(order)))
+    });
+
+    let select = select.offset(args.skip.unwrap_or(0) as usize);
+
+    match args.take_abs() {
+        Some(take) => select.limit(take as usize),
+        None => select,
+    }
+}
+
+fn relation_count(selection: &RelationCountSelection) -> Select<'static> {
+    let field = &selection.field;
+    let alias = Alias::default();
+    let join_alias = alias.to_string(Some(AliasMode::Join));
+
+    let related_table = field.related_model().as_table().alias(join_alias.clone());
+    let related_columns: Vec<Column<'static>> = field
+        .related_field()
+        .linking_fields()
+        .as_columns()
+        .map(|column| column.table(join_alias.clone()))
+        .collect();
+
+    let filter = selection
+        .filter
+        .clone()
+        .map(|filter| filter.aliased_cond(Some(alias.flip(AliasMode::Join))))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    if field.relation().is_many_to_many() {
+        let table_alias = alias.to_string(Some(AliasMode::Table));
+        let parent_columns: Vec<Column<'static>> = field.model().primary_identifier().as_columns().collect();
+
+        let identifier_columns: Vec<Column<'static>> = field
+            .identifier_columns()
+            .map(|column| column.table(table_alias.clone()))
+            .collect();
+
+        let join_columns: Vec<Column<'static>> = field
+            .join_columns()
+            .map(|column| column.table(table_alias.clone()))
+            .collect();
+
+        let join = related_table.on(Row::from(related_columns).equals(Row::from(join_columns)));
+
+        Select::from_table(field.as_table().alias(table_alias))
+            .value(count(asterisk()))
+            .inner_join(join)
+            .so_that(filter.and(Row::from(identifier_columns).equals(Row::from(parent_columns))))
+    } else {
+        let parent_columns: Vec<Column<'static>> = field.linking_fields().as_columns().collect();
+
+        Select::from_table(related_table)
+            .value(count(asterisk()))
+            .so_that(filter.and(Row::from(related_columns).equals(Row::from(parent_columns))))
+    }
+}
+
 /// Generates a query of the form:
 /// ```sql
 /// SELECT
@@ -96,19 +272,24 @@ where
 ///             1 = 1
 ///     ) AS `sub`;
 /// ```
+/// Distinct counts are selected with subqueries on the same records, see `count_distinct`.
+///
 /// Important note: Do not use the AsColumn trait here as we need to construct column references that are relative,
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
 pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
-    let columns = extract_columns(model, &selections);
-    let sub_query = get_records(model, columns.into_iter(), args);
-    let sub_table = Table::from(sub_query).alias("sub");
+    let sub_query = aggregation_records(model, selections, args);
+    let sub_table = Table::from(sub_query.clone()).alias("sub");
 
     selections
         .iter()
         .fold(Select::from_table(sub_table), |select, next_op| match next_op {
             AggregationSelection::Field(field) => select.column(Column::from(field.db_name().to_owned())),
 
-            AggregationSelection::Count { all, fields } => {
+            AggregationSelection::Count {
+                all,
+                fields,
+                distinct: false,
+            } => {
                 let select = fields.iter().fold(select, |select, next_field| {
                     select.value(count(Column::from(next_field.db_name().to_owned())))
                 });
@@ -120,6 +301,23 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
                 }
             }
 
+            AggregationSelection::Count {
+                all,
+                fields,
+                distinct: true,
+            } => {
+                let select = fields.iter().fold(select, |select, next_field| {
+                    select.value(count_distinct(&sub_query, std::slice::from_ref(next_field)))
+                });
+
+                match (*all, fields.is_empty()) {
+                    (false, _) => select,
+                    // The records themselves are distinct by their primary identifier.
+                    (true, true) => select.value(count(asterisk())),
+                    (true, false) => select.value(count_distinct(&sub_query, fields)),
+                }
+            }
+
             AggregationSelection::Average(fields) => fields.iter().fold(select, |select, next_field| {
                 select.value(avg(Column::from(next_field.db_name().to_owned())))
             }),
@@ -135,14 +333,66 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
             AggregationSelection::Max(fields) => fields.iter().fold(select, |select, next_field| {
                 select.value(max(Column::from(next_field.db_name().to_owned())))
             }),
+
+            AggregationSelection::StdDev(_)
+            | AggregationSelection::Variance(_)
+            | AggregationSelection::Percentile { .. } => {
+                unreachable!("Statistical aggregations are executed with `QueryExt::statistical_aggregate`.")
+            }
         })
 }
 
+/// Selects the columns of the records of `model` the `selections` aggregate over.
+pub fn aggregation_records(
+    model: &ModelRef,
+    selections: &[AggregationSelection],
+    args: QueryArguments,
+) -> Select<'static> {
+    let columns = extract_columns(model, selections);
+    get_records(model, columns.into_iter(), args)
+}
+
+/// Counts the distinct combinations of the values of `fields` in the records of `sub_query`,
+/// leaving out combinations that contain a null:
+/// ```sql
+/// (
+///     SELECT COUNT(*) FROM (
+///         SELECT `a`, `b` FROM (...) AS `sub`
+///         WHERE `a` IS NOT NULL AND `b` IS NOT NULL
+///         GROUP BY `a`, `b`
+///     ) AS `distinct_sub`
+/// )
+/// ```
+/// This is equivalent to `COUNT(DISTINCT a, b)`, which only some databases support for more than
+/// one column.
+fn count_distinct(sub_query: &Select<'static>, fields: &[ScalarFieldRef]) -> Expression<'static> {
+    let sub_table = Table::from(sub_query.clone()).alias("sub");
+
+    let distinct_values = fields.iter().fold(Select::from_table(sub_table), |select, field| {
+        let column = Column::from(field.db_name().to_owned());
+
+        select
+            .column(column.clone())
+            .and_where(column.clone().is_not_null())
+            .group_by(column)
+    });
+
+    let count_query = Select::from_table(Table::from(distinct_values).alias("distinct_sub")).value(count(asterisk()));
+
+    Expression::from(count_query)
+}
+
+/// Markers of the `ROLLUP (...)` grouping and of the `GROUPING(...)` of its last column, which
+/// quaint can't render. They are replaced in the rendered query, see `QueryExt::filter_rollup`.
+/// Identifiers can't contain NUL characters, so the markers never collide with a table or column.
+pub const ROLLUP_MARKER: &str = "\u{0}rollup";
+pub const GROUPING_MARKER: &str = "\u{0}grouping";
+
 pub fn group_by_aggregate(
     model: &ModelRef,
     args: QueryArguments,
     selections: &[AggregationSelection],
-    group_by: Vec<ScalarFieldRef>,
+    group_by: &GroupBy,
     having: Option<Filter>,
 ) -> Select<'static> {
     let base_query: Select = args.into_select(model);
@@ -150,7 +400,7 @@ pub fn group_by_aggregate(
     let select_query = selections.iter().fold(base_query, |select, next_op| match next_op {
         AggregationSelection::Field(field) => select.column(field.as_column()),
 
-        AggregationSelection::Count { all, fields } => {
+        AggregationSelection::Count { all, fields, .. } => {
             let select = fields
                 .iter()
                 .fold(select, |select, next_field| select.value(count(next_field.as_column())));
@@ -177,11 +427,25 @@ pub fn group_by_aggregate(
         AggregationSelection::Max(fields) => fields
             .iter()
             .fold(select, |select, next_field| select.value(max(next_field.as_column()))),
+
+        AggregationSelection::StdDev(_)
+        | AggregationSelection::Variance(_)
+        | AggregationSelection::Percentile { .. } => {
+            unreachable!("Statistical aggregations are not available for grouped queries.")
+        }
     });
 
-    let grouped = group_by
-        .into_iter()
-        .fold(select_query, |query, field| query.group_by(field.as_column()));
+    // The grouping of the last grouped column is selected last, it is 1 for subtotal rows.
+    let grouped = if group_by.rollup {
+        select_query
+            .column(Column::from(GROUPING_MARKER))
+            .group_by(Column::from(ROLLUP_MARKER))
+    } else {
+        group_by
+            .fields
+            .iter()
+            .fold(select_query, |query, field| query.group_by(field.as_column()))
+    };
 
     match having {
         Some(filter) => grouped.having(filter.aliased_cond(None)),
@@ -194,7 +458,7 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
         .iter()
         .flat_map(|selection| match selection {
             AggregationSelection::Field(field) => vec![field.clone()],
-            AggregationSelection::Count { all: _, fields } => {
+            AggregationSelection::Count { fields, .. } => {
                 if fields.is_empty() {
                     model.primary_identifier().scalar_fields().collect()
                 } else {
@@ -205,6 +469,9 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
             AggregationSelection::Sum(fields) => fields.clone(),
             AggregationSelection::Min(fields) => fields.clone(),
             AggregationSelection::Max(fields) => fields.clone(),
+            AggregationSelection::StdDev(fields) => fields.clone(),
+            AggregationSelection::Variance(fields) => fields.clone(),
+            AggregationSelection::Percentile { fields, .. } => fields.clone(),
         })
         .unique_by(|field| field.db_name().to_owned())
         .collect();
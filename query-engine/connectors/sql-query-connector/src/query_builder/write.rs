@@ -1,13 +1,110 @@
-use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
+use crate::{AliasedCondition, SqlError};
+use connector_interface::{
+    filter::Filter, AuditEntry, DatasourceFieldName, WriteArgs, WriteExpression, AUDIT_LOG_TABLE,
+};
 use prisma_models::*;
 use quaint::ast::*;
 use std::convert::TryInto;
 
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
 /// optional `RecordProjection` if available from the arguments or model.
-pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>, Option<RecordProjection>) {
+pub fn create_record(model: &ModelRef, args: WriteArgs) -> (Insert<'static>, Option<RecordProjection>) {
     let return_id = args.as_record_projection(model.primary_identifier());
+    let insert = insert_record(model, args);
 
+    (
+        Insert::from(insert).returning(model.primary_identifier().as_columns()),
+        return_id,
+    )
+}
+
+/// `INSERT` the audit entries into the audit log table of the schema. The primary keys and the
+/// changed fields are stored as JSON text.
+pub fn create_audit_entries(schema_name: &str, entries: Vec<AuditEntry>) -> Vec<Query<'static>> {
+    let table: Table<'static> = (schema_name.to_owned(), AUDIT_LOG_TABLE.to_owned()).into();
+    let columns = vec![
+        "model",
+        "operation",
+        "primary_key",
+        "changed_fields",
+        "request_id",
+        "timestamp",
+    ];
+
+    entries
+        .chunks(super::PARAMETER_LIMIT / columns.len())
+        .map(|chunk| {
+            let insert = chunk
+                .iter()
+                .fold(Insert::multi_into(table.clone(), columns.clone()), |insert, entry| {
+                    let changed_fields = serde_json::to_string(&entry.changed_fields).unwrap();
+
+                    insert.values(vec![
+                        Value::Text(Some(entry.model.clone().into())),
+                        Value::Text(Some(entry.operation.clone().into())),
+                        Value::Text(entry.primary_key.as_ref().map(|key| key.to_string().into())),
+                        Value::Text(Some(changed_fields.into())),
+                        Value::Text(entry.request_id.clone().map(Into::into)),
+                        Value::DateTime(Some(entry.timestamp)),
+                    ])
+                });
+
+            insert.build().into()
+        })
+        .collect()
+}
+
+/// An assignment of the update part of an upsert: the column, the operator
+/// applied to its current value and the given value (if any), and the value.
+pub type UpsertAssignment = (String, Option<&'static str>, Value<'static>);
+
+/// The `INSERT` of an upsert and the assignments applied instead if the record
+/// already exists. Quaint has no conflict clause updating the existing record,
+/// so the statement is completed by `QueryExt::upsert`.
+pub fn upsert_record(
+    model: &ModelRef,
+    create: WriteArgs,
+    update: WriteArgs,
+) -> crate::Result<(Insert<'static>, Vec<UpsertAssignment>)> {
+    let scalar_fields = model.fields().scalar();
+
+    let assignments = update
+        .args
+        .into_iter()
+        .map(|(DatasourceFieldName(name), val)| {
+            let field = scalar_fields
+                .iter()
+                .find(|f| f.db_name() == &name)
+                .expect("Expected field to be valid");
+
+            let (operator, value) = match val {
+                WriteExpression::Field(DatasourceFieldName(other)) => return Err(field_reference_error(&name, &other)),
+                WriteExpression::Value(rhs) => (None, rhs),
+                WriteExpression::Add(rhs) => (Some("+"), rhs),
+                WriteExpression::Substract(rhs) => (Some("-"), rhs),
+                WriteExpression::Multiply(rhs) => (Some("*"), rhs),
+                WriteExpression::Divide(rhs) => (Some("/"), rhs),
+            };
+
+            Ok((name, operator, field.value(value)))
+        })
+        .collect::<crate::Result<_>>()?;
+
+    Ok((insert_record(model, create).into(), assignments))
+}
+
+/// Writes can't set a field to the value of another field of the record yet.
+fn field_reference_error(field: &str, other: &str) -> SqlError {
+    SqlError::QueryError(
+        format!(
+            "Setting the field `{}` to the value of the field `{}` is not supported.",
+            field, other
+        )
+        .into(),
+    )
+}
+
+fn insert_record(model: &ModelRef, mut args: WriteArgs) -> SingleRowInsert<'static> {
     let fields: Vec<_> = model
         .fields()
         .scalar()
@@ -15,7 +112,7 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
         .filter(|field| args.has_arg_for(&field.db_name()))
         .collect();
 
-    let insert = fields
+    fields
         .into_iter()
         .fold(Insert::single_into(model.as_table()), |insert, field| {
             let db_name = field.db_name();
@@ -25,12 +122,75 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
                 .expect("Create calls can only use PrismaValue write expressions (right now).");
 
             insert.value(db_name.to_owned(), field.value(value))
+        })
+}
+
+/// `INSERT` many records to the database, in the given order. Consecutive records
+/// providing values for the same fields share multi-row `INSERT` statements,
+/// chunked to stay below the parameter and row limits. Records without any
+/// values are inserted one by one.
+pub fn create_records(model: &ModelRef, args: Vec<WriteArgs>) -> Vec<Query<'static>> {
+    let fields = model.fields().scalar();
+    let mut batches: Vec<(Vec<ScalarFieldRef>, Vec<Vec<Value<'static>>>)> = Vec::new();
+
+    for mut args in args {
+        let provided: Vec<ScalarFieldRef> = fields
+            .iter()
+            .filter(|field| args.has_arg_for(field.db_name()))
+            .cloned()
+            .collect();
+
+        let row: Vec<Value<'static>> = provided
+            .iter()
+            .map(|field| {
+                let value: PrismaValue = args
+                    .take_field_value(field.db_name())
+                    .unwrap()
+                    .try_into()
+                    .expect("Create calls can only use PrismaValue write expressions (right now).");
+
+                field.value(value)
+            })
+            .collect();
+
+        let batch = batches.last_mut().filter(|(batch_fields, _)| {
+            batch_fields.len() == provided.len()
+                && batch_fields
+                    .iter()
+                    .zip(provided.iter())
+                    .all(|(a, b)| a.db_name() == b.db_name())
         });
 
-    (
-        Insert::from(insert).returning(model.primary_identifier().as_columns()),
-        return_id,
-    )
+        match batch {
+            Some((_, rows)) => rows.push(row),
+            None => batches.push((provided, vec![row])),
+        }
+    }
+
+    let mut queries = Vec::new();
+
+    for (fields, rows) in batches {
+        if fields.is_empty() {
+            queries.extend(rows.iter().map(|_| Insert::single_into(model.as_table()).into()));
+            continue;
+        }
+
+        let columns: Vec<_> = fields.iter().map(|field| field.db_name().to_owned()).collect();
+
+        let chunk_size = std::cmp::min(super::PARAMETER_LIMIT / columns.len(), super::INSERT_ROW_LIMIT);
+
+        for chunk in rows.chunks(chunk_size) {
+            let insert = chunk
+                .iter()
+                .fold(Insert::multi_into(model.as_table(), columns.clone()), |insert, row| {
+                    insert.values(row.clone())
+                });
+
+            queries.push(insert.build().into());
+        }
+    }
+
+    queries
 }
 
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
@@ -38,12 +198,49 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
         return Ok(Vec::new());
     }
 
+    let query = update_columns(model, args)?;
+    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
+    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+
+    Ok(result)
+}
+
+/// Like `update_many`, but each record only matches while its `@version` field
+/// still has the `expected` value.
+pub fn update_many_versioned(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    version: &ScalarFieldRef,
+    expected: PrismaValue,
+    args: WriteArgs,
+) -> crate::Result<Vec<Query<'static>>> {
+    if args.args.is_empty() || ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = update_columns(model, args)?;
+    let columns: Vec<_> = ids[0].fields().map(|field| field.as_column()).collect();
+    let version_condition = version.as_column().equals(version.value(expected));
+    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| {
+        query.clone().so_that(conditions.and(version_condition.clone()))
+    });
+
+    Ok(result)
+}
+
+/// `UPDATE` all records matching the filter in one statement, instead of
+/// chunking over identifiers read beforehand. The caller makes sure `args` is
+/// not empty.
+pub fn update_by_filter(model: &ModelRef, filter: Filter, args: WriteArgs) -> crate::Result<Update<'static>> {
+    Ok(update_columns(model, args)?.so_that(filter.aliased_cond(None)))
+}
+
+fn update_columns(model: &ModelRef, args: WriteArgs) -> crate::Result<Update<'static>> {
     let scalar_fields = model.fields().scalar();
 
-    let query = args
-        .args
+    args.args
         .into_iter()
-        .fold(Update::table(model.as_table()), |acc, (field_name, val)| {
+        .try_fold(Update::table(model.as_table()), |acc, (field_name, val)| {
             let DatasourceFieldName(name) = field_name;
             let field = scalar_fields
                 .iter()
@@ -51,7 +248,7 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                 .expect("Expected field to be valid");
 
             let value: Expression = match val {
-                WriteExpression::Field(_) => unimplemented!(),
+                WriteExpression::Field(DatasourceFieldName(other)) => return Err(field_reference_error(&name, &other)),
                 WriteExpression::Value(rhs) => field.value(rhs).into(),
                 WriteExpression::Add(rhs) => {
                     let e: Expression<'_> = Column::from(name.clone()).into();
@@ -74,13 +271,14 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                 }
             };
 
-            acc.set(name, value)
-        });
-
-    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
-    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+            Ok(acc.set(name, value))
+        })
+}
 
-    Ok(result)
+/// `DELETE` all records matching the filter in one statement, instead of
+/// chunking over identifiers read beforehand.
+pub fn delete_by_filter(model: &ModelRef, filter: Filter) -> Delete<'static> {
+    Delete::from_table(model.as_table()).so_that(filter.aliased_cond(None))
 }
 
 pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
@@ -91,6 +289,46 @@ pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'st
     })
 }
 
+/// Like `delete_many`, but each record only matches while its `@version` field
+/// still has the `expected` value.
+pub fn delete_many_versioned(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    version: &ScalarFieldRef,
+    expected: PrismaValue,
+) -> Vec<Query<'static>> {
+    if ids.is_empty() {
+        return Vec::new();
+    }
+
+    let columns: Vec<_> = ids[0].fields().map(|field| field.as_column()).collect();
+    let version_condition = version.as_column().equals(version.value(expected));
+
+    super::chunked_conditions(&columns, ids, |conditions| {
+        Delete::from_table(model.as_table()).so_that(conditions.and(version_condition.clone()))
+    })
+}
+
+/// Deletes the rows linking the given records to other records in all many-to-many relation
+/// tables of the model. Only needed if the relation tables have no foreign keys cascading the
+/// delete, see `InternalDataModel::emulates_referential_actions`.
+pub fn delete_relation_table_links(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
+    model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.relation().is_many_to_many())
+        .flat_map(|rf| {
+            let relation = rf.relation();
+            let columns: Vec<_> = rf.related_field().m2m_columns();
+
+            super::chunked_conditions(&columns, ids, |conditions| {
+                Delete::from_table(relation.as_table()).so_that(conditions)
+            })
+        })
+        .collect()
+}
+
 pub fn create_relation_table_records(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
@@ -134,3 +372,94 @@ pub fn delete_relation_table_records(
 
     Delete::from_table(relation.as_table()).so_that(parent_id_criteria.and(child_id_criteria))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::visitor::{self, Visitor};
+
+    fn cat_model() -> (InternalDataModelRef, ModelRef) {
+        let datamodel = r#"
+            model Cat {
+                id   Int     @id
+                name String?
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_owned()).build("db".to_owned());
+        let model = internal_data_model.find_model("Cat").unwrap();
+
+        (internal_data_model, model)
+    }
+
+    fn cat_args(model: &ModelRef, id: i64, name: Option<&str>) -> WriteArgs {
+        let mut args = WriteArgs::new();
+        args.insert(&model.fields().find_from_scalar("id").unwrap(), PrismaValue::Int(id));
+
+        if let Some(name) = name {
+            args.insert(
+                &model.fields().find_from_scalar("name").unwrap(),
+                PrismaValue::String(name.to_owned()),
+            );
+        }
+
+        args
+    }
+
+    fn params(query: Query<'static>) -> Vec<Value<'static>> {
+        visitor::Sqlite::build(query).unwrap().1
+    }
+
+    #[test]
+    fn create_records_keeps_the_order_of_the_records() {
+        let (_internal_data_model, model) = cat_model();
+        let args = vec![
+            cat_args(&model, 1, Some("a")),
+            cat_args(&model, 2, None),
+            cat_args(&model, 3, Some("c")),
+            cat_args(&model, 4, Some("d")),
+        ];
+
+        let inserts: Vec<Vec<Value>> = create_records(&model, args).into_iter().map(params).collect();
+
+        assert_eq!(
+            inserts,
+            vec![
+                vec![Value::integer(1), Value::text("a")],
+                vec![Value::integer(2)],
+                vec![Value::integer(3), Value::text("c"), Value::integer(4), Value::text("d")],
+            ]
+        );
+    }
+
+    #[test]
+    fn create_records_stays_below_the_row_limit() {
+        let (_internal_data_model, model) = cat_model();
+        let args = (0..2500).map(|id| cat_args(&model, id, None)).collect();
+
+        let rows: Vec<usize> = create_records(&model, args)
+            .into_iter()
+            .map(|query| params(query).len())
+            .collect();
+
+        assert_eq!(rows, vec![1000, 1000, 500]);
+    }
+
+    #[test]
+    fn updates_referencing_another_field_are_rejected() {
+        let (_internal_data_model, model) = cat_model();
+        let id = model.fields().find_from_scalar("id").unwrap();
+        let name = model.fields().find_from_scalar("name").unwrap();
+
+        let mut args = WriteArgs::new();
+        args.insert(&name, WriteExpression::Field((&id).into()));
+
+        let err = update_by_filter(&model, Filter::empty(), args.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error querying the database: Setting the field `name` to the value of the field `id` is not supported."
+        );
+
+        assert!(upsert_record(&model, cat_args(&model, 1, None), args).is_err());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{
+    error::*,
+    query_builder::{read, write::UpsertAssignment},
+    row_value_to_prisma_value_with_arity, AliasedCondition, SqlRow, ToSqlRow,
+};
 use async_trait::async_trait;
-use connector_interface::{filter::Filter, RecordFilter};
+use connector_interface::{filter::Filter, AggregationSelection, RecordFilter, Window};
 use datamodel::FieldArity;
 use futures::future::FutureExt;
 use prisma_models::*;
@@ -8,6 +12,8 @@ use quaint::{
     ast::*,
     connector::{self, Queryable},
     pooled::PooledConnection,
+    prelude::SqlFamily,
+    visitor::{self, Visitor},
 };
 
 use serde_json::{Map, Value};
@@ -20,8 +26,313 @@ impl QueryExt for PooledConnection {}
 /// database operations on top of `Queryable`.
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
+    /// Execute an `INSERT` or `UPDATE` statement with a `RETURNING` clause for the given columns.
+    /// Quaint only renders `RETURNING` for PostgreSQL inserts, so the clause is appended here.
+    /// Fails on SQL Server, which has no `RETURNING` clause.
+    async fn query_returning(
+        &self,
+        query: Query<'_>,
+        returning: Vec<String>,
+        sql_family: SqlFamily,
+    ) -> crate::Result<connector::ResultSet> {
+        // The columns are rendered as a select list, quoted like the identifiers of the query.
+        let columns = Select::default().columns(returning);
+        let ((sql, params), (columns, _)) = match sql_family {
+            SqlFamily::Postgres => (visitor::Postgres::build(query)?, visitor::Postgres::build(columns)?),
+            SqlFamily::Mysql => (visitor::Mysql::build(query)?, visitor::Mysql::build(columns)?),
+            SqlFamily::Sqlite => (visitor::Sqlite::build(query)?, visitor::Sqlite::build(columns)?),
+            SqlFamily::Mssql => return Err(SqlError::QueryError("SQL Server has no RETURNING clause.".into())),
+        };
+
+        let sql = format!("{} RETURNING {}", sql, columns.trim_start_matches("SELECT "));
+
+        Ok(self.query_raw(&sql, &params).await?)
+    }
+
+    /// Execute the `INSERT` of an upsert, applying the `assignments` to the existing record
+    /// instead if it conflicts with the unique `conflict_columns`: `ON CONFLICT ... DO UPDATE`
+    /// on PostgreSQL and SQLite, `ON DUPLICATE KEY UPDATE` on MySQL, which applies to conflicts
+    /// with any unique key. Without assignments, the existing record is left as it is.
+    async fn upsert(
+        &self,
+        insert: Insert<'_>,
+        table: &str,
+        conflict_columns: Vec<String>,
+        assignments: Vec<UpsertAssignment>,
+        sql_family: SqlFamily,
+    ) -> quaint::Result<u64> {
+        let ((sql, mut params), quote) = match sql_family {
+            SqlFamily::Postgres => (visitor::Postgres::build(insert)?, '"'),
+            SqlFamily::Mysql => (visitor::Mysql::build(insert)?, '`'),
+            SqlFamily::Sqlite => (visitor::Sqlite::build(insert)?, '"'),
+            SqlFamily::Mssql => unreachable!("SQL Server has no native upsert."),
+        };
+
+        let quoted = |name: &str| format!("{}{}{}", quote, name, quote);
+        let mut set = Vec::with_capacity(assignments.len());
+
+        for (column, operator, value) in assignments {
+            params.push(value);
+
+            let placeholder = match sql_family {
+                SqlFamily::Postgres => format!("${}", params.len()),
+                _ => "?".to_owned(),
+            };
+
+            // PostgreSQL rejects unqualified columns on the right-hand side as ambiguous.
+            let value = match operator {
+                Some(operator) => format!("{}.{} {} {}", quoted(table), quoted(&column), operator, placeholder),
+                None => placeholder,
+            };
+
+            set.push(format!("{} = {}", quoted(&column), value));
+        }
+
+        let conflict_columns: Vec<String> = conflict_columns.iter().map(|column| quoted(column)).collect();
+
+        let sql = match (sql_family, set.is_empty()) {
+            (SqlFamily::Mysql, true) => format!(
+                "{} ON DUPLICATE KEY UPDATE {} = {}",
+                sql, conflict_columns[0], conflict_columns[0]
+            ),
+            (SqlFamily::Mysql, false) => format!("{} ON DUPLICATE KEY UPDATE {}", sql, set.join(", ")),
+            (_, true) => format!("{} ON CONFLICT ({}) DO NOTHING", sql, conflict_columns.join(", ")),
+            (_, false) => format!(
+                "{} ON CONFLICT ({}) DO UPDATE SET {}",
+                sql,
+                conflict_columns.join(", "),
+                set.join(", ")
+            ),
+        };
+
+        self.execute_raw(&sql, &params).await
+    }
+
+    /// Execute the statistical aggregations of `selections` over the `records`, which quaint has
+    /// no functions for: `STDDEV_SAMP` and `VAR_SAMP`, `STDEV` and `VAR` on SQL Server, and
+    /// `PERCENTILE_CONT` on PostgreSQL. The records query is rendered and wrapped here:
+    /// ```sql
+    /// SELECT STDDEV_SAMP("float"), PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY "float")
+    /// FROM (SELECT ... FROM "Table" WHERE ...) AS "sub"
+    /// ```
+    async fn statistical_aggregate(
+        &self,
+        records: Select<'_>,
+        selections: &[AggregationSelection],
+        sql_family: SqlFamily,
+    ) -> crate::Result<SqlRow> {
+        let ((sql, params), quote) = match sql_family {
+            SqlFamily::Postgres => (visitor::Postgres::build(records)?, ('"', '"')),
+            SqlFamily::Mysql => (visitor::Mysql::build(records)?, ('`', '`')),
+            SqlFamily::Mssql => (visitor::Mssql::build(records)?, ('[', ']')),
+            SqlFamily::Sqlite => unreachable!("SQLite has no statistical aggregate functions."),
+        };
+
+        let quoted = |name: &str| format!("{}{}{}", quote.0, name, quote.1);
+
+        let (stddev, variance) = match sql_family {
+            SqlFamily::Mssql => ("STDEV", "VAR"),
+            _ => ("STDDEV_SAMP", "VAR_SAMP"),
+        };
+
+        let aggregate = |function: &str, fields: &[ScalarFieldRef]| -> Vec<String> {
+            fields
+                .iter()
+                .map(|field| format!("{}({})", function, quoted(field.db_name())))
+                .collect()
+        };
+
+        let values: Vec<String> = selections
+            .iter()
+            .flat_map(|selection| match selection {
+                AggregationSelection::StdDev(fields) => aggregate(stddev, fields),
+                AggregationSelection::Variance(fields) => aggregate(variance, fields),
+                AggregationSelection::Percentile { fields, percentile } => fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "PERCENTILE_CONT({}) WITHIN GROUP (ORDER BY {})",
+                            percentile,
+                            quoted(field.db_name())
+                        )
+                    })
+                    .collect(),
+                _ => unreachable!("Only statistical aggregations are executed here."),
+            })
+            .collect();
+
+        let sql = format!("SELECT {} FROM ({}) AS {}", values.join(", "), sql, quoted("sub"));
+
+        let idents: Vec<_> = selections
+            .iter()
+            .flat_map(|selection| selection.identifiers())
+            .collect();
+
+        let row = self
+            .query_raw(&sql, &params)
+            .await?
+            .into_iter()
+            .next()
+            .expect("Expected exactly one return row for aggregation query.");
+
+        row.to_sql_row(&idents)
+    }
+
+    /// Execute a grouped aggregation query of `read::group_by_aggregate` with `GROUP BY ROLLUP`,
+    /// and map the resulting types with the given identifiers. Quaint can't render `ROLLUP` or
+    /// `GROUPING`, so the markers of the query are replaced with the rendered grouped columns:
+    /// ```sql
+    /// SELECT "Table"."a", "Table"."b", COUNT(*), GROUPING("Table"."b") FROM "Table"
+    /// GROUP BY ROLLUP ("Table"."a", "Table"."b")
+    /// ```
+    async fn filter_rollup(
+        &self,
+        query: Select<'_>,
+        group_by: &[ScalarFieldRef],
+        idents: &[(TypeIdentifier, FieldArity, DateTimeMode)],
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<SqlRow>> {
+        // The columns are rendered as a select list, quoted like the identifiers of the query.
+        let columns = Select::default().columns(group_by.iter().map(|field| field.as_column()).collect::<Vec<_>>());
+        let last_column = Select::default().column(group_by.last().expect("A grouped query").as_column());
+
+        let ((sql, params), (columns, _), (last_column, _), quote) = match sql_family {
+            SqlFamily::Postgres => (
+                visitor::Postgres::build(query)?,
+                visitor::Postgres::build(columns)?,
+                visitor::Postgres::build(last_column)?,
+                ('"', '"'),
+            ),
+            SqlFamily::Mssql => (
+                visitor::Mssql::build(query)?,
+                visitor::Mssql::build(columns)?,
+                visitor::Mssql::build(last_column)?,
+                ('[', ']'),
+            ),
+            _ => unreachable!("Only PostgreSQL and SQL Server support GROUP BY ROLLUP."),
+        };
+
+        let quoted = |name: &str| format!("{}{}{}", quote.0, name, quote.1);
+
+        let sql = sql
+            .replace(
+                &quoted(read::ROLLUP_MARKER),
+                &format!("ROLLUP ({})", columns.trim_start_matches("SELECT ")),
+            )
+            .replace(
+                &quoted(read::GROUPING_MARKER),
+                &format!("GROUPING({})", last_column.trim_start_matches("SELECT ")),
+            );
+
+        let mut sql_rows = Vec::new();
+
+        for row in self.query_raw(&sql, &params).await? {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
+    /// Execute a query of `read::with_window` and map the resulting types with the given
+    /// identifiers. Quaint has no `RANK` window function, so the markers of the numbers are
+    /// replaced in the rendered query with `ROW_NUMBER() OVER (...)` and `RANK() OVER (...)`.
+    async fn filter_windowed(
+        &self,
+        query: Select<'_>,
+        window: &Window,
+        model: &ModelRef,
+        idents: &[(TypeIdentifier, FieldArity, DateTimeMode)],
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<SqlRow>> {
+        let ((sql, params), quote) = match sql_family {
+            SqlFamily::Postgres => (visitor::Postgres::build(query)?, ('"', '"')),
+            SqlFamily::Sqlite => (visitor::Sqlite::build(query)?, ('"', '"')),
+            SqlFamily::Mssql => (visitor::Mssql::build(query)?, ('[', ']')),
+            SqlFamily::Mysql => unreachable!("MySQL 5 has no window functions."),
+        };
+
+        let quoted = |name: &str| format!("{}{}{}", quote.0, name, quote.1);
+        let column = |field: &ScalarFieldRef| format!("{}.{}", quoted(model.db_name()), quoted(field.db_name()));
+
+        let partition = if window.partition_by.is_empty() {
+            String::new()
+        } else {
+            let columns: Vec<String> = window.partition_by.iter().map(&column).collect();
+            format!("PARTITION BY {} ", columns.join(", "))
+        };
+
+        let rank_order: Vec<String> = window
+            .order_by
+            .iter()
+            .map(|order_by| match order_by.sort_order {
+                SortOrder::Ascending => format!("{} ASC", column(&order_by.field)),
+                SortOrder::Descending => format!("{} DESC", column(&order_by.field)),
+            })
+            .collect();
+
+        // Row numbers are unique within a partition, ties are broken by the primary identifier.
+        let tie_breakers = model
+            .primary_identifier()
+            .scalar_fields()
+            .filter(|field| !window.order_by.iter().any(|order_by| &order_by.field == field))
+            .map(|field| format!("{} ASC", column(&field)));
+
+        let row_number_order: Vec<String> = rank_order.iter().cloned().chain(tie_breakers).collect();
+
+        let sql = sql
+            .replace(
+                &quoted(read::ROW_NUMBER_MARKER),
+                &format!(
+                    "ROW_NUMBER() OVER ({}ORDER BY {})",
+                    partition,
+                    row_number_order.join(", ")
+                ),
+            )
+            .replace(
+                &quoted(read::RANK_MARKER),
+                &format!("RANK() OVER ({}ORDER BY {})", partition, rank_order.join(", ")),
+            );
+
+        let mut sql_rows = Vec::new();
+
+        for row in self.query_raw(&sql, &params).await? {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
+    /// Execute an `UPDATE` statement returning the primary identifiers of the updated records,
+    /// after the update has been applied.
+    async fn update_returning_ids(
+        &self,
+        update: Update<'_>,
+        model_id: ModelProjection,
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<RecordProjection>> {
+        let idents = identifier_types(&model_id);
+        let result_set = self
+            .query_returning(update.into(), model_id.db_names().collect(), sql_family)
+            .await?;
+
+        let mut result = Vec::new();
+
+        for row in result_set {
+            let row = row.to_sql_row(&idents)?;
+            let tuples: Vec<_> = model_id.scalar_fields().zip(row.values.into_iter()).collect();
+
+            result.push(RecordProjection::new(tuples));
+        }
+
+        Ok(result)
+    }
+
     /// Filter and map the resulting types with the given identifiers.
-    async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
+    async fn filter(
+        &self,
+        q: Query<'_>,
+        idents: &[(TypeIdentifier, FieldArity, DateTimeMode)],
+    ) -> crate::Result<Vec<SqlRow>> {
         let result_set = self.query(q).await?;
         let mut sql_rows = Vec::new();
 
@@ -72,8 +383,60 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(changes as usize)
     }
 
+    /// Execute a plain SQL query in the database, mapping the result columns with the given names
+    /// to `SqlRow`s, coerced with the given identifiers. Columns are matched by name, so the order
+    /// of the columns in the query doesn't matter and additional columns are ignored.
+    async fn raw_rows<'a>(
+        &'a self,
+        q: String,
+        params: Vec<PrismaValue>,
+        columns: &[String],
+        idents: &[(TypeIdentifier, FieldArity, DateTimeMode)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
+        let result_set = AssertUnwindSafe(self.query_raw(&q, &params))
+            .catch_unwind()
+            .await
+            .map_err(RawError::from)?
+            .map_err(RawError::from)?;
+
+        let result_columns: Vec<String> = result_set.columns().iter().map(ToString::to_string).collect();
+        let positions = columns
+            .iter()
+            .map(|column| {
+                result_columns
+                    .iter()
+                    .position(|result_column| result_column == column)
+                    .ok_or_else(|| SqlError::RawError {
+                        code: String::from("N/A"),
+                        message: format!("The query result is missing the column `{}`.", column),
+                    })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            let values: Vec<quaint::ast::Value> = row.into_iter().collect();
+            let mut sql_row = SqlRow::default();
+
+            for (position, ident) in positions.iter().zip(idents) {
+                let value = row_value_to_prisma_value_with_arity(values[*position].clone(), ident)?;
+                sql_row.values.push(value);
+            }
+
+            sql_rows.push(sql_row);
+        }
+
+        Ok(sql_rows)
+    }
+
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
+    async fn find(
+        &self,
+        q: Select<'_>,
+        idents: &[(TypeIdentifier, FieldArity, DateTimeMode)],
+    ) -> crate::Result<SqlRow> {
         self.filter(q.limit(1).into(), idents)
             .await?
             .into_iter()
@@ -85,7 +448,7 @@ pub trait QueryExt: Queryable + Send + Sync {
     async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)])
+            .find(q, &[(TypeIdentifier::Int, FieldArity::Required, DateTimeMode::Utc)])
             .await?
             .values
             .into_iter()
@@ -125,15 +488,7 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
-        let idents: Vec<_> = model_id
-            .fields()
-            .into_iter()
-            .flat_map(|f| match f {
-                Field::Scalar(sf) => vec![sf.type_identifier_with_arity()],
-                Field::Relation(rf) => rf.type_identifiers_with_arities(),
-            })
-            .collect();
-
+        let idents = identifier_types(&model_id);
         let mut rows = self.filter(select.into(), &idents).await?;
         let mut result = Vec::new();
 
@@ -147,3 +502,15 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(result)
     }
 }
+
+/// The types to read the columns of the given (primary) identifier as.
+fn identifier_types(model_id: &ModelProjection) -> Vec<(TypeIdentifier, FieldArity, DateTimeMode)> {
+    model_id
+        .fields()
+        .into_iter()
+        .flat_map(|f| match f {
+            Field::Scalar(sf) => vec![sf.column_type()],
+            Field::Relation(rf) => rf.column_types(),
+        })
+        .collect()
+}
@@ -1,9 +1,9 @@
 use crate::error::SqlError;
-use bigdecimal::{BigDecimal, FromPrimitive};
+use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
 use chrono::{DateTime, NaiveDate, Utc};
 use connector_interface::{AggregationResult, AggregationSelection};
-use datamodel::FieldArity;
-use prisma_models::{PrismaValue, Record, TypeIdentifier};
+use datamodel::{DateTimeMode, FieldArity};
+use prisma_models::{date_time_from_db, Interval, PrismaValue, Record, TypeIdentifier};
 use quaint::{
     ast::{Expression, Value},
     connector::ResultRow,
@@ -29,7 +29,7 @@ impl SqlRow {
                     vec![AggregationResult::Field(field.clone(), values.pop().unwrap())]
                 }
 
-                AggregationSelection::Count { all, fields } => {
+                AggregationSelection::Count { all, fields, .. } => {
                     let mut results: Vec<_> = fields
                         .iter()
                         .map(|field| {
@@ -77,6 +77,27 @@ impl SqlRow {
                         AggregationResult::Max(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
                     })
                     .collect(),
+
+                AggregationSelection::StdDev(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::StdDev(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
+
+                AggregationSelection::Variance(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::Variance(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
+
+                AggregationSelection::Percentile { fields, .. } => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::Percentile(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
             })
             .collect()
     }
@@ -100,44 +121,74 @@ pub trait ToSqlRow {
     /// Conversion from a database specific row to an allocated `SqlRow`. To
     /// help deciding the right types, the provided `TypeIdentifier`s should map
     /// to the returned columns in the right order.
-    fn to_sql_row<'b>(self, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow>;
+    fn to_sql_row<'b>(self, idents: &[(TypeIdentifier, FieldArity, DateTimeMode)]) -> crate::Result<SqlRow>;
 }
 
 impl ToSqlRow for ResultRow {
-    fn to_sql_row<'b>(self, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
+    fn to_sql_row<'b>(self, idents: &[(TypeIdentifier, FieldArity, DateTimeMode)]) -> crate::Result<SqlRow> {
         let mut row = SqlRow::default();
         let row_width = idents.len();
 
         row.values.reserve(row_width);
 
         for (i, p_value) in self.into_iter().enumerate().take(row_width) {
-            let pv = match &idents[i] {
-                (type_identifier, FieldArity::List) => match p_value {
-                    value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
-                    Value::Array(None) => Ok(PrismaValue::List(Vec::new())),
-                    Value::Array(Some(l)) => l
-                        .into_iter()
-                        .map(|p_value| row_value_to_prisma_value(p_value, &type_identifier))
-                        .collect::<crate::Result<Vec<_>>>()
-                        .map(PrismaValue::List),
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            format!("List field did not return an Array from database. Type identifier was {:?}. Value was {:?}.", &type_identifier, &p_value),
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                (type_identifier, _) => row_value_to_prisma_value(p_value, &type_identifier),
-            }?;
-
-            row.values.push(pv);
+            row.values
+                .push(row_value_to_prisma_value_with_arity(p_value, &idents[i])?);
         }
 
         Ok(row)
     }
 }
 
+/// Converts a database value into a `PrismaValue` for a field with the given type and arity.
+/// DateTime values are read back with the given `DateTimeMode`.
+pub fn row_value_to_prisma_value_with_arity(
+    p_value: Value,
+    ident: &(TypeIdentifier, FieldArity, DateTimeMode),
+) -> Result<PrismaValue, SqlError> {
+    let (type_identifier, arity, date_time_mode) = ident;
+
+    let value = match arity {
+        FieldArity::List => {
+            match p_value {
+                value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
+                Value::Array(None) => Ok(PrismaValue::List(Vec::new())),
+                Value::Array(Some(l)) => l
+                    .into_iter()
+                    .map(|p_value| row_value_to_prisma_value(p_value, type_identifier))
+                    .collect::<crate::Result<Vec<_>>>()
+                    .map(PrismaValue::List),
+                _ => {
+                    let error = io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("List field did not return an Array from database. Type identifier was {:?}. Value was {:?}.", type_identifier, &p_value),
+                    );
+                    Err(SqlError::ConversionError(error.into()))
+                }
+            }
+        }
+        _ => row_value_to_prisma_value(p_value, type_identifier),
+    }?;
+
+    Ok(match date_time_mode {
+        DateTimeMode::Utc => value,
+        _ => date_times_from_db(value, *date_time_mode),
+    })
+}
+
+fn date_times_from_db(value: PrismaValue, date_time_mode: DateTimeMode) -> PrismaValue {
+    match value {
+        PrismaValue::DateTime(dt) => PrismaValue::DateTime(date_time_from_db(date_time_mode, dt)),
+        PrismaValue::List(values) => PrismaValue::List(
+            values
+                .into_iter()
+                .map(|value| date_times_from_db(value, date_time_mode))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
 pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifier) -> Result<PrismaValue, SqlError> {
     Ok(match type_identifier {
         TypeIdentifier::Boolean => match p_value {
@@ -247,9 +298,8 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 }
                 _ => PrismaValue::Float(BigDecimal::from_f32(f).unwrap().normalized()),
             },
-            Value::Integer(Some(i)) => {
-                PrismaValue::Float(BigDecimal::from_f64(i as f64).expect("f64 was not a BigDecimal."))
-            }
+            // Converted exactly, going through `f64` would round large sums.
+            Value::Integer(Some(i)) => PrismaValue::Float(BigDecimal::from(i)),
             Value::Text(_) | Value::Bytes(_) => {
                 let dec: BigDecimal = p_value
                     .as_str()
@@ -267,14 +317,36 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
-        TypeIdentifier::Int | TypeIdentifier::BigInt => match p_value {
+        TypeIdentifier::Int => match p_value {
             Value::Integer(Some(i)) => PrismaValue::Int(i),
+            Value::Numeric(Some(dec)) => PrismaValue::Int(numeric_to_i64(&dec)?),
             Value::Bytes(Some(bytes)) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
             Value::Text(Some(txt)) => PrismaValue::Int(
                 i64::from_str(txt.trim_start_matches('\0')).map_err(|err| SqlError::ConversionError(err.into()))?,
             ),
             other => PrismaValue::try_from(other)?,
         },
+        // Read as `BigInt` so that the values compare equal to the ones parsed from the query, e.g. cursors.
+        // Values of unsigned columns beyond the signed range, e.g. MySQL `BIGINT UNSIGNED`, are read
+        // as exact decimals.
+        TypeIdentifier::BigInt => match p_value {
+            Value::Integer(Some(i)) => PrismaValue::BigInt(i),
+            Value::Numeric(Some(dec)) => numeric_to_bigint(&dec)?,
+            Value::Bytes(Some(bytes)) => PrismaValue::BigInt(interpret_bytes_as_i64(&bytes)),
+            Value::Text(Some(txt)) => {
+                let txt = txt.trim_start_matches('\0');
+
+                match (i64::from_str(txt), u64::from_str(txt)) {
+                    (Ok(i), _) => PrismaValue::BigInt(i),
+                    (Err(_), Ok(u)) => PrismaValue::Float(BigDecimal::from(u)),
+                    (Err(err), Err(_)) => return Err(SqlError::ConversionError(err.into())),
+                }
+            }
+            other => match PrismaValue::try_from(other)? {
+                PrismaValue::Int(i) => PrismaValue::BigInt(i),
+                value => value,
+            },
+        },
         TypeIdentifier::String => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Uuid(Some(uuid)) => PrismaValue::String(uuid.to_string()),
@@ -303,6 +375,18 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        // Postgres returns intervals in its own output format unless `IntervalStyle` is set to ISO-8601.
+        TypeIdentifier::Interval => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Text(Some(s)) => PrismaValue::Interval(Interval::parse_postgres(&s)?),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Interval-type value '{:?}' not stored as text.", p_value),
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
     })
 }
 
@@ -331,6 +415,30 @@ impl From<&SqlId> for Expression<'static> {
 
 // We assume the bytes are stored as a big endian signed integer, because that is what
 // mysql does if you enter a numeric value for a bits column.
+/// Numeric values are returned for integer sums by some databases, e.g. MySQL. Values that don't
+/// fit into an `i64` are rejected instead of being wrapped around.
+fn numeric_to_i64(dec: &BigDecimal) -> Result<i64, SqlError> {
+    match dec.to_i64() {
+        Some(i) if BigDecimal::from(i) == *dec => Ok(i),
+        _ => {
+            let error = io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Numeric value `{}` does not fit into a 64-bit integer.", dec),
+            );
+
+            Err(SqlError::ConversionError(error.into()))
+        }
+    }
+}
+
+/// Like `numeric_to_i64`, but values up to the unsigned 64-bit maximum are kept as exact decimals.
+fn numeric_to_bigint(dec: &BigDecimal) -> Result<PrismaValue, SqlError> {
+    match dec.to_u64() {
+        Some(u) if u > i64::MAX as u64 && BigDecimal::from(u) == *dec => Ok(PrismaValue::Float(BigDecimal::from(u))),
+        _ => numeric_to_i64(dec).map(PrismaValue::BigInt),
+    }
+}
+
 fn interpret_bytes_as_i64(bytes: &[u8]) -> i64 {
     match bytes.len() {
         8 => i64::from_be_bytes([
@@ -408,4 +516,35 @@ mod test {
             assert_eq!(roundtripped, i as i64);
         }
     }
+
+    #[test]
+    fn unsigned_bigints_beyond_the_signed_range_are_read_exactly() {
+        let max = PrismaValue::Float(BigDecimal::from(u64::MAX));
+
+        assert_eq!(
+            row_value_to_prisma_value(
+                Value::Text(Some("18446744073709551615".into())),
+                &TypeIdentifier::BigInt
+            )
+            .unwrap(),
+            max
+        );
+        assert_eq!(
+            row_value_to_prisma_value(
+                Value::Numeric(Some(BigDecimal::from(u64::MAX))),
+                &TypeIdentifier::BigInt
+            )
+            .unwrap(),
+            max
+        );
+        assert_eq!(
+            row_value_to_prisma_value(Value::Numeric(Some(BigDecimal::from(42))), &TypeIdentifier::BigInt).unwrap(),
+            PrismaValue::BigInt(42)
+        );
+        assert!(row_value_to_prisma_value(
+            Value::Text(Some("18446744073709551616".into())),
+            &TypeIdentifier::BigInt
+        )
+        .is_err());
+    }
 }
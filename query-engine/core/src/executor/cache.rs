@@ -0,0 +1,427 @@
+use crate::{
+    query_document::{Operation, QueryValue, Selection},
+    response_ir::ResponseData,
+    schema::{QueryInfo, QuerySchemaRef, QueryTag},
+};
+use connector::QueryHints;
+use indexmap::IndexMap;
+use prisma_models::ModelRef;
+use serde::Serialize;
+use std::{
+    collections::HashSet,
+    fmt::Write as _,
+    io,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// The memory budget of the query cache if the engine is not configured otherwise.
+pub const DEFAULT_QUERY_CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+pub type QueryCacheRef = Arc<QueryCache>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct QueryCacheConfig {
+    /// How long a cached response is served.
+    pub ttl: Duration,
+    /// The approximate size of the cached responses, in bytes, above which the oldest responses
+    /// are evicted.
+    pub max_bytes: usize,
+}
+
+/// Counters of the query cache.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    /// Responses removed because a write changed one of the models they were read from.
+    pub invalidations: u64,
+    /// Responses removed because they expired or to stay within the memory budget.
+    pub evictions: u64,
+    pub entries: usize,
+    pub bytes: usize,
+}
+
+/// Caches the responses of read operations.
+///
+/// Responses are keyed by the operation: its name, alias, arguments and selections, independent
+/// of the order of the arguments. Every response is tagged with the models it was read from,
+/// including the models of the relations it selects or filters on. Write operations executed by
+/// the same engine drop the responses tagged with the models they write to. Writes that can change
+/// related records through referential actions, i.e. updates and deletes, also drop the responses
+/// of all models related to them. Raw writes drop all responses.
+///
+/// Writes from anywhere else, e.g. other engines using the same database, are not observed: the
+/// TTL bounds how long such responses are stale.
+///
+/// Operations with settings or explain query hints are neither served from nor stored in the
+/// cache, as settings can change what the database returns, and explain needs the statements to
+/// be executed.
+pub struct QueryCache {
+    config: QueryCacheConfig,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// The entries, oldest first. All entries have the same TTL, so this is also the order in
+    /// which they expire.
+    entries: IndexMap<String, CacheEntry>,
+    /// Incremented on every invalidation, so responses read before the invalidation are not stored.
+    generation: u64,
+    metrics: QueryCacheMetrics,
+}
+
+struct CacheEntry {
+    response: ResponseData,
+    models: HashSet<String>,
+    size: usize,
+    expires_at: Instant,
+}
+
+/// What the executor does with the cache for an operation, see `QueryCache::prepare`.
+pub enum CacheAction {
+    /// The operation can't be cached.
+    None,
+    /// The cached response of the operation.
+    Hit(ResponseData),
+    /// The response of the operation is not cached yet and is stored once it is executed.
+    Store(CacheTicket),
+    /// The operation writes: the responses of the models it changes are dropped once it is executed.
+    Invalidate(Invalidation),
+}
+
+/// Stores the response of a read operation, unless the cache was invalidated since its lookup.
+pub struct CacheTicket {
+    key: String,
+    models: HashSet<String>,
+    generation: u64,
+}
+
+/// The responses a write operation invalidates.
+#[derive(Debug, Clone)]
+pub enum Invalidation {
+    Models(HashSet<String>),
+    All,
+}
+
+impl QueryCache {
+    pub fn new(config: QueryCacheConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Looks up the response of a read operation, or determines the responses a write operation
+    /// invalidates.
+    pub fn prepare(&self, operation: &Operation, query_schema: &QuerySchemaRef, hints: &QueryHints) -> CacheAction {
+        let selection = match operation {
+            Operation::Write(_) => {
+                return match self.invalidation(operation, query_schema) {
+                    Some(invalidation) => CacheAction::Invalidate(invalidation),
+                    None => CacheAction::None,
+                }
+            }
+            Operation::Read(_)
+                if !hints.is_empty() || hints.query_graph_traces().is_some() || hints.index_advice().is_some() =>
+            {
+                return CacheAction::None
+            }
+            Operation::Read(selection) => selection,
+        };
+
+        let field = query_schema.find_query_field(selection.name());
+
+        let model = match field.as_ref().and_then(|field| field.query_info.as_ref()) {
+            Some(info) if info.tag != QueryTag::FindRaw => match &info.model {
+                Some(model) => model.clone(),
+                None => return CacheAction::None,
+            },
+            _ => return CacheAction::None,
+        };
+
+        let mut key = String::new();
+        write_selection(&mut key, selection);
+
+        let mut state = self.lock();
+        let now = Instant::now();
+
+        let expired = match state.entries.get(&key) {
+            Some(entry) if entry.expires_at > now => {
+                let response = entry.response.clone();
+                state.metrics.hits += 1;
+
+                return CacheAction::Hit(response);
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        if expired {
+            state.remove(&key);
+            state.metrics.evictions += 1;
+        }
+
+        state.metrics.misses += 1;
+
+        CacheAction::Store(CacheTicket {
+            key,
+            models: selection_models(selection, &model),
+            generation: state.generation,
+        })
+    }
+
+    /// Stores the response of a read, or applies the invalidation of a write, once the operation
+    /// of the action is executed.
+    pub fn complete(&self, action: CacheAction, result: &crate::Result<ResponseData>) {
+        match (action, result) {
+            (CacheAction::Store(ticket), Ok(response)) => self.store(ticket, response),
+            // Failed writes that did not run in a transaction can have written some records.
+            (CacheAction::Invalidate(invalidation), _) => self.invalidate(&invalidation),
+            _ => (),
+        }
+    }
+
+    /// The responses a write operation invalidates. `None` for read operations.
+    pub fn invalidation(&self, operation: &Operation, query_schema: &QuerySchemaRef) -> Option<Invalidation> {
+        let selection = match operation {
+            Operation::Write(selection) => selection,
+            Operation::Read(_) => return None,
+        };
+
+        let field = query_schema.find_mutation_field(selection.name());
+
+        let (model, tag) = match field.as_ref().and_then(|field| field.query_info.as_ref()) {
+            Some(QueryInfo {
+                model: Some(model),
+                tag,
+            }) => (model.clone(), tag.clone()),
+            // Raw queries.
+            _ => return Some(Invalidation::All),
+        };
+
+        let mut models = selection_models(selection, &model);
+
+        if tag != QueryTag::CreateOne {
+            models = with_related_models(&model, models);
+        }
+
+        Some(Invalidation::Models(models))
+    }
+
+    /// Drops the responses of an invalidation.
+    pub fn invalidate(&self, invalidation: &Invalidation) {
+        let mut state = self.lock();
+        state.generation += 1;
+
+        let keys: Vec<String> = state
+            .entries
+            .iter()
+            .filter(|(_, entry)| match invalidation {
+                Invalidation::All => true,
+                Invalidation::Models(models) => !entry.models.is_disjoint(models),
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in keys {
+            state.remove(&key);
+            state.metrics.invalidations += 1;
+        }
+    }
+
+    /// Drops all responses, e.g. when the datamodel changes.
+    pub fn clear(&self) {
+        self.invalidate(&Invalidation::All);
+    }
+
+    pub fn metrics(&self) -> QueryCacheMetrics {
+        let state = self.lock();
+
+        QueryCacheMetrics {
+            entries: state.entries.len(),
+            ..state.metrics
+        }
+    }
+
+    fn store(&self, ticket: CacheTicket, response: &ResponseData) {
+        let size = ticket.key.len() + response.key.len() + serialized_size(response);
+
+        if size > self.config.max_bytes {
+            return;
+        }
+
+        let mut state = self.lock();
+
+        if state.generation != ticket.generation {
+            return;
+        }
+
+        let now = Instant::now();
+        state.remove(&ticket.key);
+
+        loop {
+            let evict = match state.entries.get_index(0) {
+                Some((_, oldest)) => oldest.expires_at <= now || state.metrics.bytes + size > self.config.max_bytes,
+                None => false,
+            };
+
+            if !evict {
+                break;
+            }
+
+            if let Some((_, entry)) = state.entries.shift_remove_index(0) {
+                state.metrics.bytes -= entry.size;
+                state.metrics.evictions += 1;
+            }
+        }
+
+        state.metrics.bytes += size;
+        state.entries.insert(
+            ticket.key,
+            CacheEntry {
+                response: response.clone(),
+                models: ticket.models,
+                size,
+                expires_at: now + self.config.ttl,
+            },
+        );
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().expect("query cache lock poisoned")
+    }
+}
+
+impl CacheState {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.shift_remove(key) {
+            self.metrics.bytes -= entry.size;
+        }
+    }
+}
+
+/// Renders the selection into `key`. The arguments are sorted by name, while the order of the
+/// keys of object arguments is kept, as it is significant, e.g. for `orderBy`.
+fn write_selection(key: &mut String, selection: &Selection) {
+    let mut arguments: Vec<&(String, QueryValue)> = selection.arguments().iter().collect();
+    arguments.sort_by(|a, b| a.0.cmp(&b.0));
+
+    write!(key, "{}{:?}{:?}{{", selection.name(), selection.alias(), arguments).unwrap();
+
+    for nested in selection.nested_selections() {
+        write_selection(key, nested);
+        key.push(',');
+    }
+
+    key.push('}');
+}
+
+/// The names of the models a selection on `model` reads or writes: `model` itself and the models
+/// of the relations its arguments and nested selections name.
+pub fn selection_models(selection: &Selection, model: &ModelRef) -> HashSet<String> {
+    let mut models = HashSet::new();
+    collect_selection_models(selection, model, &mut models);
+
+    models
+}
+
+fn collect_selection_models(selection: &Selection, model: &ModelRef, models: &mut HashSet<String>) {
+    models.insert(model.name.clone());
+
+    for (_, value) in selection.arguments() {
+        collect_value_models(value, model, models);
+    }
+
+    for nested in selection.nested_selections() {
+        match related_models(model, nested.name()) {
+            Some(related) => {
+                models.extend(related.iter().map(|m| m.name.clone()));
+                collect_selection_models(nested, &related[0], models);
+            }
+            // Not a relation, e.g. `_count`, which selects relations itself.
+            None => collect_selection_models(nested, model, models),
+        }
+    }
+}
+
+fn collect_value_models(value: &QueryValue, model: &ModelRef, models: &mut HashSet<String>) {
+    match value {
+        QueryValue::Object(map) => {
+            for (key, value) in map {
+                match related_models(model, key) {
+                    Some(related) => {
+                        models.extend(related.iter().map(|m| m.name.clone()));
+                        collect_value_models(value, &related[0], models);
+                    }
+                    None => collect_value_models(value, model, models),
+                }
+            }
+        }
+        QueryValue::List(values) => {
+            for value in values {
+                collect_value_models(value, model, models);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// The related model of the relation field `name` of `model`, followed by the model on the other
+/// side of the join table if the related model is a `@@joinTable`.
+pub fn related_models(model: &ModelRef, name: &str) -> Option<Vec<ModelRef>> {
+    let field = model.fields().find_from_relation_fields(name).ok()?;
+    let mut related = vec![field.related_model()];
+
+    if let Some(join_table_field) = field.join_table_field() {
+        related.push(join_table_field.related_model());
+    }
+
+    Some(related)
+}
+
+/// `models` and all models transitively related to them.
+fn with_related_models(model: &ModelRef, mut models: HashSet<String>) -> HashSet<String> {
+    let internal_data_model = model.internal_data_model();
+    let mut pending: Vec<String> = models.iter().cloned().collect();
+
+    while let Some(name) = pending.pop() {
+        let model = match internal_data_model.find_model(&name) {
+            Ok(model) => model,
+            Err(_) => continue,
+        };
+
+        for field in model.fields().relation() {
+            let related = field.related_model();
+
+            if models.insert(related.name.clone()) {
+                pending.push(related.name.clone());
+            }
+        }
+    }
+
+    models
+}
+
+/// The length of the JSON serialization of a response, as an estimate of its size in memory.
+fn serialized_size(response: &ResponseData) -> usize {
+    struct Counter(usize);
+
+    impl io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = serde_json::to_writer(&mut counter, &response.data);
+
+    counter.0
+}
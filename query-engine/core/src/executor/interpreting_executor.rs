@@ -1,7 +1,10 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{pipeline::QueryPipeline, CacheAction, QueryCacheRef, QueryExecutor};
+use crate::{
+    interpreter::ValueTransformers, AuditLog, FieldEncryptionRef, Operation, OperationMetadata, QueryGraphBuilder,
+    QueryInterpreter, QuerySchemaRef, ResponseData, ValueTransformerRef,
+};
 use async_trait::async_trait;
-use connector::{Connection, ConnectionLike, Connector};
+use connector::{Connection, ConnectionLike, Connector, QueryHints};
 use futures::future;
 
 /// Central query executor and main entry point into the query core.
@@ -12,6 +15,13 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Value transformers, encryption hooks for fields marked with `@encrypted` and the audit log
+    /// of the writes, registered by the embedder.
+    transformers: ValueTransformers,
+
+    /// Cache for the responses of read operations, see `QueryCache`.
+    cache: Option<QueryCacheRef>,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,36 +32,124 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            transformers: ValueTransformers::default(),
+            cache: None,
         }
     }
 
+    /// Registers the hooks that encrypt and decrypt the values of fields marked with `@encrypted`.
+    pub fn with_field_encryption(mut self, field_encryption: FieldEncryptionRef) -> Self {
+        self.transformers.set_field_encryption(field_encryption);
+        self
+    }
+
+    /// Registers a transformer for the queries and results of all operations, see `ValueTransformer`.
+    /// Transformers are applied in the order they are registered.
+    pub fn with_value_transformer(mut self, transformer: ValueTransformerRef) -> Self {
+        self.transformers.push(transformer);
+        self
+    }
+
+    /// Records the creates, updates and deletes of all operations in `audit_log`.
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.transformers.set_audit_log(audit_log);
+        self
+    }
+
+    /// Serves read operations from `cache`, and invalidates it with the write operations.
+    pub fn with_cache(mut self, cache: QueryCacheRef) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
         operation: Operation,
         conn: Box<dyn Connection>,
         force_transactions: bool,
+        transformers: ValueTransformers,
+        cache: Option<QueryCacheRef>,
         query_schema: QuerySchemaRef,
+        hints: QueryHints,
     ) -> crate::Result<ResponseData> {
-        // Parse, validate, and extract query graph from query document.
-        let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
-        let is_transactional = force_transactions || query_graph.needs_transaction();
+        let cache_action = match &cache {
+            Some(cache) => cache.prepare(&operation, &query_schema, &hints),
+            None => CacheAction::None,
+        };
 
-        if is_transactional {
-            let tx = conn.start_transaction().await?;
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+        if let CacheAction::Hit(response) = cache_action {
+            return Ok(response);
+        }
 
-            if result.is_ok() {
-                tx.commit().await?;
+        let operation_name = operation.name().to_owned();
+        let is_write = matches!(operation, Operation::Write(_));
+        let transformers = transformers
+            .for_operation(OperationMetadata::new(&operation, &query_schema).with_request_id(hints.request_id()));
+        let audit = transformers.clone();
+        let slow_query_log = hints.slow_query_log().cloned();
+        let connect_or_create_retries = query_schema.connect_or_create_retries;
+
+        let fut = async move {
+            // Parse, validate, and extract query graph from query document.
+            let (query_graph, serializer) = QueryGraphBuilder::new(query_schema)
+                .with_trace(hints.query_graph_traces().cloned())
+                .with_index_advice(hints.index_advice().cloned())
+                .build(operation)?;
+            let is_transactional = force_transactions
+                || !hints.is_empty()
+                || query_graph.needs_transaction()
+                || (is_write && audit.audit_needs_transaction());
+
+            if is_transactional {
+                let tx = conn.start_transaction().await?;
+
+                // Every failure after the start of the transaction rolls it back, which also resets
+                // the session settings of the query hints.
+                let result: crate::Result<ResponseData> = async {
+                    tx.apply_query_hints(&hints).await?;
+
+                    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()))
+                        .with_transformers(transformers)
+                        .with_connect_or_create_retries(connect_or_create_retries);
+                    let response = QueryPipeline::new(query_graph, interpreter, serializer)
+                        .execute()
+                        .await?;
+
+                    tx.commit().await?;
+
+                    Ok(response)
+                }
+                .await;
+
+                match result {
+                    Ok(_) => audit.flush_audit_log(),
+                    Err(_) => {
+                        tx.rollback().await.ok();
+                    }
+                }
+
+                result
             } else {
-                tx.rollback().await?;
+                let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()))
+                    .with_transformers(transformers)
+                    .with_connect_or_create_retries(connect_or_create_retries);
+                let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+
+                if result.is_ok() {
+                    audit.flush_audit_log();
+                }
+
+                result
             }
+        };
 
-            result
-        } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query_graph, interpreter, serializer).execute().await
+        let result = connector::log_slow_queries(slow_query_log.as_ref(), &operation_name, fut).await;
+
+        if let Some(cache) = cache {
+            cache.complete(cache_action, &result);
         }
+
+        result
     }
 }
 
@@ -76,29 +174,80 @@ where
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        hints: QueryHints,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
         if transactional {
+            let operation_names: Vec<String> = operations.iter().map(|op| op.name().to_owned()).collect();
+            let operation_transformers: Vec<_> = operations
+                .iter()
+                .map(|op| {
+                    self.transformers
+                        .for_operation(OperationMetadata::new(op, &query_schema).with_request_id(hints.request_id()))
+                })
+                .collect();
+            // Reads of a transactional batch bypass the cache, so they see the writes of the batch.
+            let invalidations: Vec<_> = match &self.cache {
+                Some(cache) => operations
+                    .iter()
+                    .filter_map(|op| cache.invalidation(op, &query_schema))
+                    .collect(),
+                None => Vec::new(),
+            };
             let queries = operations
                 .into_iter()
-                .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
+                .map(|op| {
+                    QueryGraphBuilder::new(query_schema.clone())
+                        .with_trace(hints.query_graph_traces().cloned())
+                        .with_index_advice(hints.index_advice().cloned())
+                        .build(op)
+                })
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
             let conn = self.connector.get_connection().await?;
             let tx = conn.start_transaction().await?;
-            let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
-                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            // See `execute_single_operation`.
+            let results: crate::Result<Vec<crate::Result<ResponseData>>> = async {
+                tx.apply_query_hints(&hints).await?;
+
+                let mut results = Vec::with_capacity(queries.len());
+
+                for (((query, info), operation_name), transformers) in queries
+                    .into_iter()
+                    .zip(operation_names)
+                    .zip(operation_transformers.iter().cloned())
+                {
+                    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()))
+                        .with_transformers(transformers)
+                        .with_connect_or_create_retries(query_schema.connect_or_create_retries);
+                    let pipeline = QueryPipeline::new(query, interpreter, info).execute();
+                    let result = connector::log_slow_queries(hints.slow_query_log(), &operation_name, pipeline).await;
 
-                if result.is_err() {
-                    tx.rollback().await?;
+                    results.push(Ok(result?));
                 }
 
-                results.push(Ok(result?));
+                tx.commit().await?;
+
+                Ok(results)
+            }
+            .await;
+
+            if results.is_err() {
+                tx.rollback().await.ok();
+            }
+
+            let results = results?;
+
+            for transformers in operation_transformers.iter() {
+                transformers.flush_audit_log();
+            }
+
+            if let Some(cache) = &self.cache {
+                for invalidation in invalidations.iter() {
+                    cache.invalidate(invalidation);
+                }
             }
 
-            tx.commit().await?;
             Ok(results)
         } else {
             let mut futures = Vec::with_capacity(operations.len());
@@ -109,7 +258,10 @@ where
                     operation,
                     conn,
                     self.force_transactions,
+                    self.transformers.clone(),
+                    self.cache.clone(),
                     query_schema.clone(),
+                    hints.clone(),
                 )));
             }
 
@@ -124,9 +276,23 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        hints: QueryHints,
+    ) -> crate::Result<ResponseData> {
         let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+        Self::execute_single_operation(
+            operation,
+            conn,
+            self.force_transactions,
+            self.transformers.clone(),
+            self.cache.clone(),
+            query_schema.clone(),
+            hints,
+        )
+        .await
     }
 
     fn primary_connector(&self) -> &dyn Connector {
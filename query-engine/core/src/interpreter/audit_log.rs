@@ -0,0 +1,229 @@
+use super::{InterpretationResult, OperationMetadata};
+use crate::{query_ast::*, result_ast::*};
+use chrono::Utc;
+use connector::{AuditEntry, ConnectionLike, QueryArguments, ReadOperations, RecordFilter, WriteArgs, WriteOperations};
+use prisma_models::{ModelRef, RecordProjection};
+use std::sync::{Arc, Mutex};
+
+pub type AuditSinkRef = Arc<dyn AuditSink>;
+
+/// An external log stream receiving the audit entries, implemented by the embedder of the query
+/// engine.
+pub trait AuditSink: Send + Sync {
+    /// Called with the entries of an operation once its writes are committed.
+    fn record(&self, entries: Vec<AuditEntry>);
+}
+
+/// Where the creates, updates and deletes of the operations are recorded. Connects and disconnects
+/// of relations and raw queries are not recorded.
+#[derive(Clone)]
+pub enum AuditLog {
+    /// The `_prisma_audit_log` table the migration engine creates if the datasource enables
+    /// `auditLog`. The entries are inserted in the transaction of the writes, so they are rolled
+    /// back with them.
+    Table,
+
+    /// An external sink, which receives the entries of an operation after it succeeded.
+    Stream(AuditSinkRef),
+}
+
+/// The audit entries of a single operation.
+#[derive(Clone)]
+pub(crate) struct AuditTrail {
+    log: AuditLog,
+    pending: Arc<Mutex<Vec<AuditEntry>>>,
+}
+
+/// The entries of a write query, before it is executed. Creates and updates of a single record
+/// only know the primary key of the record from the result.
+pub(crate) struct AuditedWrite {
+    model: ModelRef,
+    operation: &'static str,
+    primary_keys: Vec<Option<RecordProjection>>,
+    changed_fields: Vec<String>,
+    key_from_result: bool,
+}
+
+impl AuditTrail {
+    pub(crate) fn new(log: AuditLog) -> Self {
+        Self {
+            log,
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Whether the writes of the operation must run in a transaction with their entries.
+    pub(crate) fn is_transactional(&self) -> bool {
+        matches!(self.log, AuditLog::Table)
+    }
+
+    pub(crate) async fn record<'conn, 'tx>(
+        &self,
+        conn: &ConnectionLike<'conn, 'tx>,
+        operation: &OperationMetadata,
+        write: AuditedWrite,
+        result: &QueryResult,
+    ) -> InterpretationResult<()> {
+        let primary_keys = match result {
+            QueryResult::Id(id) if write.key_from_result => vec![id.clone()],
+            _ => write.primary_keys,
+        };
+
+        let timestamp = Utc::now();
+        let entries: Vec<AuditEntry> = primary_keys
+            .into_iter()
+            .map(|primary_key| AuditEntry {
+                model: write.model.name.clone(),
+                operation: write.operation.to_owned(),
+                primary_key: primary_key.map(|key| primary_key_json(&key)),
+                changed_fields: write.changed_fields.clone(),
+                request_id: operation.request_id.clone(),
+                timestamp,
+            })
+            .collect();
+
+        match &self.log {
+            AuditLog::Table => Ok(conn.record_audit_entries(entries).await?),
+            AuditLog::Stream(_) => {
+                self.pending.lock().unwrap().extend(entries);
+                Ok(())
+            }
+        }
+    }
+
+    /// Hands the entries recorded so far to the sink. Called once the writes are committed.
+    pub(crate) fn flush(&self) {
+        if let AuditLog::Stream(sink) = &self.log {
+            let entries = std::mem::take(&mut *self.pending.lock().unwrap());
+
+            if !entries.is_empty() {
+                sink.record(entries);
+            }
+        }
+    }
+}
+
+impl AuditedWrite {
+    /// `None` for the writes that are not recorded.
+    pub(crate) fn new(query: &WriteQuery) -> Option<Self> {
+        let audited = match query {
+            WriteQuery::CreateRecord(q) => Self {
+                model: q.model.clone(),
+                operation: "create",
+                primary_keys: vec![None],
+                changed_fields: changed_fields(&q.model, &[&q.args]),
+                key_from_result: true,
+            },
+            WriteQuery::CreateManyRecords(q) => Self {
+                model: q.model.clone(),
+                operation: "create",
+                primary_keys: q
+                    .args
+                    .iter()
+                    .map(|args| args.as_record_projection(q.model.primary_identifier()))
+                    .collect(),
+                changed_fields: changed_fields(&q.model, &q.args.iter().collect::<Vec<_>>()),
+                key_from_result: false,
+            },
+            WriteQuery::UpdateRecord(q) => Self {
+                model: q.model.clone(),
+                operation: "update",
+                primary_keys: vec![None],
+                changed_fields: changed_fields(&q.model, &[&q.args]),
+                key_from_result: true,
+            },
+            WriteQuery::UpsertRecord(q) => Self {
+                model: q.model.clone(),
+                operation: "upsert",
+                primary_keys: vec![Some(q.selector.clone())],
+                changed_fields: changed_fields(&q.model, &[&q.create, &q.update]),
+                key_from_result: false,
+            },
+            WriteQuery::UpdateManyRecords(q) => Self {
+                model: q.model.clone(),
+                operation: "update",
+                primary_keys: selected_keys(&q.record_filter),
+                changed_fields: changed_fields(&q.model, &[&q.args]),
+                key_from_result: false,
+            },
+            WriteQuery::DeleteRecord(q) => Self {
+                model: q.model.clone(),
+                operation: "delete",
+                primary_keys: q.record_filter.as_ref().map(selected_keys).unwrap_or_default(),
+                changed_fields: Vec::new(),
+                key_from_result: false,
+            },
+            WriteQuery::DeleteManyRecords(q) => Self {
+                model: q.model.clone(),
+                operation: "delete",
+                primary_keys: selected_keys(&q.record_filter),
+                changed_fields: Vec::new(),
+                key_from_result: false,
+            },
+            WriteQuery::ConnectRecords(_)
+            | WriteQuery::DisconnectRecords(_)
+            | WriteQuery::ExecuteRaw(_)
+            | WriteQuery::QueryRaw(_) => return None,
+        };
+
+        Some(audited)
+    }
+}
+
+/// Limits the updates and deletes by filter to the records the filter matches when the write is
+/// executed, so that an entry is recorded for each record the write affects.
+pub(crate) async fn select_audited_records<'conn, 'tx>(
+    conn: &ConnectionLike<'conn, 'tx>,
+    query: &mut WriteQuery,
+) -> InterpretationResult<()> {
+    let (model, record_filter) = match query {
+        WriteQuery::UpdateManyRecords(q) => (&q.model, &mut q.record_filter),
+        WriteQuery::DeleteManyRecords(q) => (&q.model, &mut q.record_filter),
+        WriteQuery::DeleteRecord(DeleteRecord {
+            model,
+            record_filter: Some(record_filter),
+        }) => (&*model, record_filter),
+        _ => return Ok(()),
+    };
+
+    if record_filter.selectors.is_some() {
+        return Ok(());
+    }
+
+    let model_id = model.primary_identifier();
+    let query_args = QueryArguments::from((model.clone(), record_filter.filter.clone()));
+    let records = conn.get_many_records(model, query_args, &model_id, &[], &[]).await?;
+
+    record_filter.selectors = Some(records.projections(&model_id)?);
+
+    Ok(())
+}
+
+/// The records the filter is limited to, see `select_audited_records`.
+fn selected_keys(record_filter: &RecordFilter) -> Vec<Option<RecordProjection>> {
+    record_filter.selectors.iter().flatten().cloned().map(Some).collect()
+}
+
+/// The names of the fields set by any of the arguments, in the order of the model.
+fn changed_fields(model: &ModelRef, args: &[&WriteArgs]) -> Vec<String> {
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| args.iter().any(|args| args.has_arg_for(field.db_name())))
+        .map(|field| field.name.clone())
+        .collect()
+}
+
+fn primary_key_json(key: &RecordProjection) -> serde_json::Value {
+    let fields = key
+        .pairs
+        .iter()
+        .map(|(field, value)| {
+            let value = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+            (field.name.clone(), value)
+        })
+        .collect();
+
+    serde_json::Value::Object(fields)
+}
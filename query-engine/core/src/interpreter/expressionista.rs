@@ -17,12 +17,15 @@ struct IfNodeAcc {
 
 impl Expressionista {
     pub fn translate(mut graph: QueryGraph) -> InterpretationResult<Expression> {
-        graph
+        let expression = graph
             .root_nodes()
             .into_iter()
             .map(|root_node| Self::build_expression(&mut graph, &root_node, vec![]))
             .collect::<InterpretationResult<Vec<Expression>>>()
-            .map(|res| Expression::Sequence { seq: res })
+            .map(|res| Expression::Sequence { seq: res });
+
+        graph.finish_trace();
+        expression
     }
 
     fn build_expression(
@@ -0,0 +1,122 @@
+use super::{InterpretationResult, InterpreterError};
+use crate::query_ast::*;
+use connector::{WriteArgs, WriteExpression};
+use prisma_models::{ManyRecords, ModelRef, PrismaValue, ScalarFieldRef};
+use std::{error::Error, sync::Arc};
+
+pub type FieldEncryptionRef = Arc<dyn FieldEncryption>;
+
+pub type FieldEncryptionResult = std::result::Result<PrismaValue, Box<dyn Error + Send + Sync>>;
+
+/// Encrypts and decrypts the values of fields marked with `@encrypted`. Implemented by the
+/// embedder of the query engine, which is also responsible for managing the keys.
+///
+/// Values are encrypted right before write queries are handed to the connector, and decrypted
+/// right after read queries return, before the results are serialized. Null values are never
+/// passed to the hooks, and scalar lists are transformed element by element.
+///
+/// The database only sees the ciphertext, so encrypted fields are left out of the filter, order
+/// by, distinct and group by inputs and of the minimum and maximum aggregates.
+pub trait FieldEncryption: Send + Sync {
+    fn encrypt(&self, field: &ScalarFieldRef, value: PrismaValue) -> FieldEncryptionResult;
+
+    fn decrypt(&self, field: &ScalarFieldRef, value: PrismaValue) -> FieldEncryptionResult;
+}
+
+/// Encrypts all values written to encrypted fields by the given write query.
+pub(crate) fn encrypt_write(encryption: &dyn FieldEncryption, query: &mut WriteQuery) -> InterpretationResult<()> {
+    match query {
+        WriteQuery::CreateRecord(q) => encrypt_args(encryption, &q.model, &mut q.args),
+
+        WriteQuery::CreateManyRecords(q) => {
+            for args in q.args.iter_mut() {
+                encrypt_args(encryption, &q.model, args)?;
+            }
+
+            Ok(())
+        }
+
+        WriteQuery::UpdateRecord(q) => encrypt_args(encryption, &q.model, &mut q.args),
+
+        WriteQuery::UpsertRecord(q) => {
+            encrypt_args(encryption, &q.model, &mut q.create)?;
+            encrypt_args(encryption, &q.model, &mut q.update)
+        }
+
+        WriteQuery::UpdateManyRecords(q) => encrypt_args(encryption, &q.model, &mut q.args),
+
+        _ => Ok(()),
+    }
+}
+
+fn encrypt_args(encryption: &dyn FieldEncryption, model: &ModelRef, args: &mut WriteArgs) -> InterpretationResult<()> {
+    for field in encrypted_fields(model) {
+        if let Some(WriteExpression::Value(value)) = args.args.get_mut(field.db_name()) {
+            let plain = std::mem::replace(value, PrismaValue::Null);
+
+            *value = transform(plain, &|value| encryption.encrypt(&field, value)).map_err(|err| {
+                InterpreterError::InterpretationError(
+                    format!("Failed to encrypt the value of the field `{}`: {}", field.name, err),
+                    None,
+                )
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Decrypts the values of encrypted fields in the given records of the model.
+pub(crate) fn decrypt_records(
+    encryption: &dyn FieldEncryption,
+    model: &ModelRef,
+    records: &mut ManyRecords,
+) -> InterpretationResult<()> {
+    let fields: Vec<(usize, ScalarFieldRef)> = encrypted_fields(model)
+        .into_iter()
+        .filter_map(|field| {
+            records
+                .field_names
+                .iter()
+                .position(|name| name == field.db_name())
+                .map(|index| (index, field))
+        })
+        .collect();
+
+    for record in records.records.iter_mut() {
+        for (index, field) in fields.iter() {
+            let cipher = std::mem::replace(&mut record.values[*index], PrismaValue::Null);
+
+            record.values[*index] = transform(cipher, &|value| encryption.decrypt(field, value)).map_err(|err| {
+                InterpreterError::InterpretationError(
+                    format!("Failed to decrypt the value of the field `{}`: {}", field.name, err),
+                    None,
+                )
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn encrypted_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| field.is_encrypted)
+        .collect()
+}
+
+fn transform(value: PrismaValue, f: &dyn Fn(PrismaValue) -> FieldEncryptionResult) -> FieldEncryptionResult {
+    match value {
+        PrismaValue::Null => Ok(PrismaValue::Null),
+        PrismaValue::List(values) => Ok(PrismaValue::List(
+            values
+                .into_iter()
+                .map(|value| transform(value, f))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        value => f(value),
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     expression::*,
     query_interpreters::{read, write},
+    value_transformer::OperationTransformers,
     InterpretationResult, InterpreterError,
 };
 use crate::{Query, QueryResult};
@@ -122,6 +123,8 @@ impl Env {
 }
 pub struct QueryInterpreter<'conn, 'tx> {
     pub(crate) conn: ConnectionLike<'conn, 'tx>,
+    transformers: OperationTransformers,
+    connect_or_create_retries: u32,
     log: SegQueue<String>,
 }
 
@@ -140,7 +143,25 @@ where
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            transformers: OperationTransformers::default(),
+            connect_or_create_retries: 0,
+            log,
+        }
+    }
+
+    /// Applies the value transformers of the operation to the queries and results, see
+    /// `ValueTransformer`.
+    pub(crate) fn with_transformers(mut self, transformers: OperationTransformers) -> Self {
+        self.transformers = transformers;
+        self
+    }
+
+    /// See `QuerySchema::connect_or_create_retries`.
+    pub fn with_connect_or_create_retries(mut self, retries: u32) -> Self {
+        self.connect_or_create_retries = retries;
+        self
     }
 
     pub fn interpret(
@@ -206,16 +227,28 @@ where
             Expression::Query { query } => {
                 let fut = async move {
                     match query {
-                        Query::Read(read) => {
+                        Query::Read(mut read) => {
+                            self.transformers.before_read(&mut read)?;
                             self.log_line(level, || format!("READ {}", read));
-                            Ok(read::execute(&self.conn, read, None)
-                                .await
-                                .map(ExpressionResult::Query)?)
+
+                            let mut result = read::execute(&self.conn, read, None).await?;
+                            self.transformers.after_read(&mut result)?;
+
+                            Ok(ExpressionResult::Query(result))
                         }
 
-                        Query::Write(write) => {
+                        Query::Write(mut write) => {
+                            self.transformers.before_write(&mut write)?;
+                            let audited = self.transformers.audited_write(&self.conn, &mut write).await?;
+
                             self.log_line(level, || format!("WRITE {}", write));
-                            Ok(write::execute(&self.conn, write).await.map(ExpressionResult::Query)?)
+                            let result = write::execute(&self.conn, write, self.connect_or_create_retries).await?;
+
+                            if let Some(audited) = audited {
+                                self.transformers.record_write(&self.conn, audited, &result).await?;
+                            }
+
+                            Ok(ExpressionResult::Query(result))
                         }
                     }
                 };
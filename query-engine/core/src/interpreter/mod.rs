@@ -1,15 +1,23 @@
+mod audit_log;
 mod error;
 mod expression;
 mod expressionista;
+mod field_encryption;
 mod formatters;
 mod interpreter;
+mod value_transformer;
 
 pub(self) mod query_interpreters;
 
+pub use audit_log::{AuditLog, AuditSink, AuditSinkRef};
 pub use error::*;
 pub use expression::*;
 pub use expressionista::*;
+pub use field_encryption::{FieldEncryption, FieldEncryptionRef, FieldEncryptionResult};
 pub use formatters::*;
 pub use interpreter::*;
+pub use value_transformer::{OperationMetadata, ValueTransformer, ValueTransformerRef, ValueTransformerResult};
+
+pub(crate) use value_transformer::ValueTransformers;
 
 type InterpretationResult<T> = std::result::Result<T, InterpreterError>;
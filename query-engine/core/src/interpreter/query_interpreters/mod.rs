@@ -1,4 +1,5 @@
 mod inmemory_record_processor;
 mod nested_read;
 pub mod read;
+mod referential_actions;
 pub mod write;
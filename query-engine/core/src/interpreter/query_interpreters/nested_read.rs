@@ -1,7 +1,7 @@
 use super::inmemory_record_processor::InMemoryRecordProcessor;
 use crate::{interpreter::InterpretationResult, query_ast::*};
-use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
-use prisma_models::{ManyRecords, ModelProjection, Record, RecordProjection, RelationFieldRef};
+use connector::{self, filter::Filter, ConnectionLike, ReadOperations, RelationJoin, ScalarCompare};
+use prisma_models::{ManyRecords, Record, RecordProjection};
 use prisma_value::PrismaValue;
 use std::collections::HashMap;
 
@@ -9,6 +9,7 @@ pub async fn m2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: &RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
+    relation_joins: &[RelationJoin],
     processor: InMemoryRecordProcessor,
 ) -> InterpretationResult<ManyRecords> {
     let parent_field = &query.parent_field;
@@ -44,7 +45,11 @@ pub async fn m2m<'a, 'b>(
         .collect::<std::result::Result<Vec<_>, _>>()?;
 
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query.args.do_nothing() && child_link_id == query.selected_fields {
+    let mut scalars = if query.args.do_nothing()
+        && child_link_id == query.selected_fields
+        && query.relation_counts.is_empty()
+        && relation_joins.is_empty()
+    {
         ManyRecords::from_projection(child_ids, &query.selected_fields)
     } else {
         let mut args = query.args.clone();
@@ -54,8 +59,14 @@ pub async fn m2m<'a, 'b>(
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&query.parent_field.related_model(), args, &query.selected_fields)
-            .await?
+        tx.get_many_records(
+            &query.parent_field.related_model(),
+            args,
+            &query.selected_fields,
+            &query.relation_counts.selections,
+            relation_joins,
+        )
+        .await?
     };
 
     // Child id to parent ids
@@ -109,20 +120,21 @@ pub async fn m2m<'a, 'b>(
 // [DTODO] This is implemented in an inefficient fashion, e.g. too much Arc cloning going on.
 pub async fn one2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
-    parent_field: &RelationFieldRef,
-    parent_projections: Option<Vec<RecordProjection>>,
+    query: &RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
-    query_args: QueryArguments,
-    selected_fields: &ModelProjection,
+    relation_joins: &[RelationJoin],
     processor: InMemoryRecordProcessor,
 ) -> InterpretationResult<ManyRecords> {
+    let parent_field = &query.parent_field;
+    let selected_fields = &query.selected_fields;
+    let relation_counts = &query.relation_counts.selections;
     let parent_model_id = parent_field.model().primary_identifier();
     let parent_link_id = parent_field.linking_fields();
     let child_link_id = parent_field.related_field().linking_fields();
 
     // Primary ID to link ID
-    let joined_projections = match parent_projections {
-        Some(projections) => projections,
+    let joined_projections = match query.parent_projections {
+        Some(ref projections) => projections.clone(),
         None => {
             let extractor = parent_model_id.clone().merge(parent_link_id.clone());
             parent_result
@@ -165,18 +177,28 @@ pub async fn one2m<'a, 'b>(
     }
 
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query_args.do_nothing() && &child_link_id == selected_fields {
+    let mut scalars = if query.args.do_nothing()
+        && &child_link_id == selected_fields
+        && relation_counts.is_empty()
+        && relation_joins.is_empty()
+    {
         ManyRecords::from_projection(uniq_projections, selected_fields)
     } else {
         let filter = child_link_id.is_in(uniq_projections);
-        let mut args = query_args;
+        let mut args = query.args.clone();
 
         args.filter = match args.filter {
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&parent_field.related_model(), args, selected_fields)
-            .await?
+        tx.get_many_records(
+            &parent_field.related_model(),
+            args,
+            selected_fields,
+            relation_counts,
+            relation_joins,
+        )
+        .await?
     };
 
     // Inlining is done on the parent, this means that we need to write the primary parent ID
@@ -1,9 +1,9 @@
 use super::*;
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
+use connector::{self, ConnectionLike, QueryArguments, ReadOperations, RelationJoin};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
-use prisma_models::ManyRecords;
+use prisma_models::{ManyRecords, ModelProjection, Record};
 
 pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -14,8 +14,12 @@ pub fn execute<'a, 'b>(
         match query {
             ReadQuery::RecordQuery(q) => read_one(tx, q).await,
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
-            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
+            ReadQuery::RelatedRecordsQuery(q) => {
+                let field = q.name.clone();
+                connector::with_field_path(&field, read_related(tx, q, parent_result)).await
+            }
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::RawRecordsQuery(q) => read_raw(tx, q).await,
         }
     };
 
@@ -31,18 +35,30 @@ fn read_one<'conn, 'tx>(
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let (joined, nested) = split_joined_reads(query.relation_load_strategy, query.nested);
+        let joins = relation_joins(&joined);
+        let scalars = tx
+            .get_single_record(
+                &model,
+                &filter,
+                &query.selected_fields,
+                &query.relation_counts.selections,
+                &joins,
+            )
+            .await?;
 
         match scalars {
             Some(record) => {
-                let records: ManyRecords = record.into();
-                let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records)).await?;
+                let mut records: ManyRecords = record.into();
+                let mut nested_results = process_joined(tx, joined, &joins, &mut records, &model_id).await?;
+                nested_results.extend(process_nested(tx, nested, Some(&records)).await?);
 
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
                     fields: query.selection_order,
                     scalars: records,
-                    nested,
+                    relation_counts: query.relation_counts,
+                    nested: nested_results,
                     model_id,
                     query_arguments: QueryArguments::new(model),
                 }))
@@ -53,6 +69,7 @@ fn read_one<'conn, 'tx>(
                 fields: query.selection_order,
                 model_id,
                 scalars: ManyRecords::default(),
+                relation_counts: query.relation_counts,
                 nested: vec![],
                 query_arguments: QueryArguments::new(model),
             })),
@@ -73,20 +90,36 @@ fn read_many<'a, 'b>(
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = if query.args.requires_inmemory_processing() {
+        let (joined, nested) = split_joined_reads(query.relation_load_strategy, std::mem::take(&mut query.nested));
+        let joins = relation_joins(&joined);
+
+        let mut scalars = if query.args.requires_inmemory_processing() {
             let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
             let scalars = tx
-                .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
+                .get_many_records(
+                    &query.model,
+                    query.args.clone(),
+                    &query.selected_fields,
+                    &query.relation_counts.selections,
+                    &joins,
+                )
                 .await?;
 
             processor.apply(scalars)
         } else {
-            tx.get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-                .await?
+            tx.get_many_records(
+                &query.model,
+                query.args.clone(),
+                &query.selected_fields,
+                &query.relation_counts.selections,
+                &joins,
+            )
+            .await?
         };
 
         let model_id = query.model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let mut nested_results = process_joined(tx, joined, &joins, &mut scalars, &model_id).await?;
+        nested_results.extend(process_nested(tx, nested, Some(&scalars)).await?);
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -94,7 +127,8 @@ fn read_many<'a, 'b>(
             query_arguments: query.args,
             model_id,
             scalars,
-            nested,
+            relation_counts: query.relation_counts,
+            nested: nested_results,
         }))
     };
 
@@ -111,25 +145,19 @@ fn read_related<'a, 'b>(
         let relation = query.parent_field.relation();
         let is_m2m = relation.is_many_to_many();
         let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
+        let (joined, nested) = split_joined_reads(query.relation_load_strategy, std::mem::take(&mut query.nested));
+        let joins = relation_joins(&joined);
 
-        let scalars = if is_m2m {
-            nested_read::m2m(tx, &query, parent_result, processor).await?
+        let mut scalars = if is_m2m {
+            nested_read::m2m(tx, &query, parent_result, &joins, processor).await?
         } else {
-            nested_read::one2m(
-                tx,
-                &query.parent_field,
-                query.parent_projections,
-                parent_result,
-                query.args.clone(),
-                &query.selected_fields,
-                processor,
-            )
-            .await?
+            nested_read::one2m(tx, &query, parent_result, &joins, processor).await?
         };
 
         let model = query.parent_field.related_model();
         let model_id = model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let mut nested_results = process_joined(tx, joined, &joins, &mut scalars, &model_id).await?;
+        nested_results.extend(process_nested(tx, nested, Some(&scalars)).await?);
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -137,6 +165,34 @@ fn read_related<'a, 'b>(
             query_arguments: query.args,
             model_id,
             scalars,
+            relation_counts: query.relation_counts,
+            nested: nested_results,
+        }))
+    };
+
+    fut.boxed()
+}
+
+/// Queries records with a raw query, mapping the result onto the selected fields of the model.
+fn read_raw<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: RawRecordsQuery,
+) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
+    let fut = async move {
+        let scalars = tx
+            .get_raw_records(query.raw.query, query.raw.parameters, &query.selected_fields)
+            .await?;
+
+        let model_id = query.model.primary_identifier();
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+
+        Ok(QueryResult::RecordSelection(RecordSelection {
+            name: query.name,
+            fields: query.selection_order,
+            query_arguments: QueryArguments::new(query.model),
+            model_id,
+            scalars,
+            relation_counts: RelationCounts::default(),
             nested,
         }))
     };
@@ -160,6 +216,128 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
+/// Splits off the nested reads that are loaded with joins in the query of their parent records:
+/// With the join strategy, these are the reads of to-one relations without relation counts.
+fn split_joined_reads(
+    strategy: RelationLoadStrategy,
+    nested: Vec<ReadQuery>,
+) -> (Vec<RelatedRecordsQuery>, Vec<ReadQuery>) {
+    if strategy != RelationLoadStrategy::Join {
+        return (vec![], nested);
+    }
+
+    let mut joined = vec![];
+    let mut rest = vec![];
+
+    for query in nested {
+        match query {
+            ReadQuery::RelatedRecordsQuery(q) if !q.parent_field.is_list && q.relation_counts.is_empty() => {
+                joined.push(q)
+            }
+            query => rest.push(query),
+        }
+    }
+
+    (joined, rest)
+}
+
+fn relation_joins(joined: &[RelatedRecordsQuery]) -> Vec<RelationJoin> {
+    joined
+        .iter()
+        .map(|query| RelationJoin {
+            field: query.parent_field.clone(),
+            selected_fields: query.selected_fields.clone(),
+        })
+        .collect()
+}
+
+/// Moves the related records loaded with joins out of the parent records and processes the
+/// nested reads of the joined reads.
+async fn process_joined<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    joined: Vec<RelatedRecordsQuery>,
+    joins: &[RelationJoin],
+    parent_records: &mut ManyRecords,
+    parent_model_id: &ModelProjection,
+) -> InterpretationResult<Vec<QueryResult>> {
+    let joined_records = take_joined_records(parent_records, joins, parent_model_id)?;
+    let mut results = Vec::with_capacity(joined.len());
+
+    for (query, scalars) in joined.into_iter().zip(joined_records) {
+        let model_id = query.parent_field.related_model().primary_identifier();
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+
+        results.push(QueryResult::RecordSelection(RecordSelection {
+            name: query.name,
+            fields: query.selection_order,
+            query_arguments: query.args,
+            model_id,
+            scalars,
+            relation_counts: query.relation_counts,
+            nested,
+        }));
+    }
+
+    Ok(results)
+}
+
+/// Splits the joined values off the parent records into records of the related models, with the
+/// parent record as their parent. Parent records without a related record have only null values.
+fn take_joined_records(
+    parent_records: &mut ManyRecords,
+    joins: &[RelationJoin],
+    parent_model_id: &ModelProjection,
+) -> InterpretationResult<Vec<ManyRecords>> {
+    let mut results = Vec::with_capacity(joins.len());
+
+    for join in joins {
+        let mut records = ManyRecords::empty(&join.selected_fields);
+        let related_model_id = join.field.related_model().primary_identifier();
+
+        let positions: Vec<usize> = join
+            .db_aliases()
+            .iter()
+            .filter_map(|alias| parent_records.field_names.iter().position(|name| name == alias))
+            .collect();
+
+        if !positions.is_empty() {
+            for parent in parent_records.records.iter() {
+                let values = positions
+                    .iter()
+                    .map(|position| parent.values[*position].clone())
+                    .collect();
+                let mut record = Record::new(values);
+
+                let related_id = record.projection(&records.field_names, &related_model_id)?;
+
+                if related_id.values().all(|value| value.is_null()) {
+                    continue;
+                }
+
+                record.parent_id = Some(parent.projection(&parent_records.field_names, parent_model_id)?);
+                records.push(record);
+            }
+        }
+
+        results.push(records);
+    }
+
+    let first_joined = joins
+        .iter()
+        .flat_map(|join| join.db_aliases())
+        .find_map(|alias| parent_records.field_names.iter().position(|name| name == &alias));
+
+    if let Some(first_joined) = first_joined {
+        parent_records.field_names.truncate(first_joined);
+
+        for record in parent_records.records.iter_mut() {
+            record.values.truncate(first_joined);
+        }
+    }
+
+    Ok(results)
+}
+
 fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
@@ -0,0 +1,174 @@
+use crate::interpreter::{InterpretationResult, InterpreterError};
+use connector::{
+    ConnectionLike, IdFilter, QueryArguments, ReadOperations, RecordFilter, WriteArgs, WriteExpression, WriteOperations,
+};
+use futures::future::{BoxFuture, FutureExt};
+use prisma_models::{ModelRef, RecordProjection, RelationFieldRef};
+
+/// Updates records like `WriteOperations::update_records`.
+///
+/// If the database doesn't enforce the foreign keys of relations (see `InternalDataModel::emulates_referential_actions`),
+/// changes to fields referenced by relations are cascaded to the related records, like `ON UPDATE CASCADE`
+/// foreign keys would do:
+/// - Foreign keys of inline relations pointing to the updated records are set to the new values.
+/// - Rows of many-to-many relation tables are moved to the new identifiers.
+///
+/// Cascading updates can cascade further, if the updated foreign keys are referenced themselves.
+pub fn update_records<'conn, 'tx>(
+    tx: &'conn ConnectionLike<'conn, 'tx>,
+    model: ModelRef,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+) -> BoxFuture<'conn, InterpretationResult<Vec<RecordProjection>>> {
+    let fut = async move {
+        let cascades = if model.internal_data_model().emulates_referential_actions() {
+            cascaded_relation_fields(&model, &args)
+        } else {
+            vec![]
+        };
+
+        if cascades.is_empty() {
+            return Ok(tx.update_records(&model, record_filter, args).await?);
+        }
+
+        // The referenced values need to be read before the update changes them.
+        let projection = cascades.iter().fold(model.primary_identifier(), |projection, rf| {
+            projection.merge(rf.linking_fields())
+        });
+
+        let filter = match record_filter.selectors {
+            Some(ref selectors) => selectors.clone().filter(),
+            None => record_filter.filter.clone(),
+        };
+
+        let previous = tx
+            .get_many_records(
+                &model,
+                QueryArguments::from((model.clone(), filter)),
+                &projection,
+                &[],
+                &[],
+            )
+            .await?;
+
+        let ids = tx.update_records(&model, record_filter, args.clone()).await?;
+
+        for record in previous.records.iter() {
+            for rf in cascades.iter() {
+                let old_values = record.projection(&previous.field_names, &rf.linking_fields())?;
+                let new_values = updated_values(&old_values, &args)?;
+
+                if old_values == new_values {
+                    continue;
+                }
+
+                if rf.relation().is_many_to_many() {
+                    cascade_to_relation_table(tx, rf, &old_values, &new_values).await?;
+                } else {
+                    cascade_to_foreign_keys(tx, rf, old_values, new_values).await?;
+                }
+            }
+        }
+
+        Ok(ids)
+    };
+
+    fut.boxed()
+}
+
+/// Updates records like `WriteOperations::update_many_records`, resulting in the number of
+/// updated records. Updates that need to be cascaded are done like in `update_records`.
+pub async fn update_many_records<'conn, 'tx>(
+    tx: &'conn ConnectionLike<'conn, 'tx>,
+    model: ModelRef,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+) -> InterpretationResult<usize> {
+    if model.internal_data_model().emulates_referential_actions() && !cascaded_relation_fields(&model, &args).is_empty()
+    {
+        let ids = update_records(tx, model, record_filter, args).await?;
+        return Ok(ids.len());
+    }
+
+    Ok(tx.update_many_records(&model, record_filter, args).await?)
+}
+
+/// The relation fields on `model` whose referenced fields are written by `args`, and which have
+/// the foreign key on the related side (or in a relation table).
+fn cascaded_relation_fields(model: &ModelRef, args: &WriteArgs) -> Vec<RelationFieldRef> {
+    model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.relation().is_many_to_many() || rf.relation_is_inlined_in_child())
+        .filter(|rf| {
+            rf.linking_fields()
+                .scalar_fields()
+                .any(|field| args.has_arg_for(field.db_name()))
+        })
+        .collect()
+}
+
+/// Applies the values written by `args` to the referenced values of a record.
+fn updated_values(old_values: &RecordProjection, args: &WriteArgs) -> InterpretationResult<RecordProjection> {
+    old_values
+        .pairs
+        .iter()
+        .map(|(field, value)| match args.get_field_value(field.db_name()) {
+            None => Ok((field.clone(), value.clone())),
+            Some(WriteExpression::Value(new_value)) => Ok((field.clone(), new_value.clone())),
+            Some(_) => Err(InterpreterError::InterpretationError(
+                format!(
+                    "The field `{}` is referenced by a relation and can only be set to a new value, as the database does not enforce foreign keys.",
+                    field.name
+                ),
+                None,
+            )),
+        })
+        .collect::<InterpretationResult<Vec<_>>>()
+        .map(RecordProjection::new)
+}
+
+/// Sets the foreign keys pointing to `old_values` over the relation field `rf` to `new_values`.
+async fn cascade_to_foreign_keys<'conn, 'tx>(
+    tx: &'conn ConnectionLike<'conn, 'tx>,
+    rf: &RelationFieldRef,
+    old_values: RecordProjection,
+    new_values: RecordProjection,
+) -> InterpretationResult<()> {
+    let foreign_key = rf.related_field();
+    let foreign_key_fields = foreign_key.scalar_fields();
+
+    let filter = RecordProjection::new(foreign_key_fields.iter().cloned().zip(old_values.values()).collect()).filter();
+    let mut args = WriteArgs::new();
+
+    for (field, value) in foreign_key_fields.iter().zip(new_values.values()) {
+        args.insert(field, value);
+    }
+
+    update_records(tx, foreign_key.model(), filter.into(), args).await?;
+
+    Ok(())
+}
+
+/// Moves the relation table rows of the relation field `rf` from `old_id` to `new_id`.
+async fn cascade_to_relation_table<'conn, 'tx>(
+    tx: &'conn ConnectionLike<'conn, 'tx>,
+    rf: &RelationFieldRef,
+    old_id: &RecordProjection,
+    new_id: &RecordProjection,
+) -> InterpretationResult<()> {
+    let linked_ids: Vec<RecordProjection> = tx
+        .get_related_m2m_record_ids(rf, &[old_id.clone()])
+        .await?
+        .into_iter()
+        .map(|(_, linked_id)| linked_id)
+        .collect();
+
+    if !linked_ids.is_empty() {
+        tx.disconnect(rf, old_id, &linked_ids).await?;
+        tx.connect(rf, new_id, &linked_ids).await?;
+    }
+
+    Ok(())
+}
@@ -1,18 +1,25 @@
+use super::referential_actions;
 use crate::{
     interpreter::{InterpretationResult, InterpreterError},
     query_ast::*,
     QueryResult,
 };
-use connector::{ConnectionLike, WriteOperations};
+use connector::{
+    error::ErrorKind, ConnectionLike, IdFilter, QueryArguments, ReadOperations, WriteArgs, WriteOperations,
+};
+use prisma_models::{ModelRef, RecordProjection};
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     write_query: WriteQuery,
+    connect_or_create_retries: u32,
 ) -> InterpretationResult<QueryResult> {
     match write_query {
-        WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateRecord(q) => create_one(tx, q, connect_or_create_retries).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
+        WriteQuery::UpsertRecord(q) => upsert_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
         WriteQuery::DeleteManyRecords(q) => delete_many(tx, q).await,
@@ -43,18 +50,98 @@ async fn execute_raw<'a, 'b>(
     Ok(QueryResult::Json(num))
 }
 
-async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) -> InterpretationResult<QueryResult> {
-    let res = tx.create_record(&q.model, q.args).await?;
+async fn create_one<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateRecord,
+    connect_or_create_retries: u32,
+) -> InterpretationResult<QueryResult> {
+    let res = match q.connect_or_create {
+        Some(retry) if connect_or_create_retries > 0 => {
+            create_or_connect_existing(tx, &q.model, q.args, retry, connect_or_create_retries).await?
+        }
+        _ => tx.create_record(&q.model, q.args).await?,
+    };
 
     Ok(QueryResult::Id(Some(res)))
 }
 
+/// Creates the record of a `connectOrCreate`, or returns the existing record instead if a concurrent
+/// request created it after the record was looked up. Whether the unique violation of the create was
+/// on the selector of the `connectOrCreate` is decided by reading the record by the selector. If it
+/// doesn't exist (anymore), the create is attempted again, at most `retries` times. The concurrently
+/// created record is only visible under read committed isolation, not e.g. under the repeatable read
+/// default of MySQL, where the unique violation is returned after the retries.
+async fn create_or_connect_existing<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    model: &ModelRef,
+    args: WriteArgs,
+    retry: ConnectOrCreateRetry,
+    retries: u32,
+) -> InterpretationResult<RecordProjection> {
+    let model_id = model.primary_identifier();
+    let mut attempt = 0;
+
+    loop {
+        let err = match tx.try_create_record(model, args.clone()).await {
+            Ok(id) => return Ok(id),
+            Err(err) => err,
+        };
+
+        if attempt == retries || !matches!(err.kind, ErrorKind::UniqueConstraintViolation { .. }) {
+            return Err(err.into());
+        }
+
+        attempt += 1;
+
+        let query_args = QueryArguments::from((model.clone(), retry.selector.clone().filter()));
+        let existing = tx.get_many_records(model, query_args, &model_id, &[], &[]).await?;
+
+        if let Some(id) = existing.projections(&model_id)?.pop() {
+            debug!(
+                "connectOrCreate on {} connects the record created by a concurrent request.",
+                model.name
+            );
+
+            if let Some(linking_fields) = &retry.linking_fields {
+                let mut link_args = WriteArgs::new();
+
+                for field in linking_fields.scalar_fields() {
+                    if let Some(value) = args.get_field_value(field.db_name()) {
+                        link_args.insert(&field, value.clone());
+                    }
+                }
+
+                if !link_args.is_empty() {
+                    tx.update_records(model, id.clone().filter().into(), link_args).await?;
+                }
+            }
+
+            return Ok(id);
+        }
+    }
+}
+
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.create_records(&q.model, q.args).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
-    let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
+    let mut res = referential_actions::update_records(tx, q.model, q.record_filter, q.args).await?;
 
     Ok(QueryResult::Id(res.pop()))
 }
 
+async fn upsert_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpsertRecord) -> InterpretationResult<QueryResult> {
+    tx.upsert_record(&q.model, q.selector, q.create, q.update).await?;
+
+    Ok(QueryResult::Unit)
+}
+
 async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) -> InterpretationResult<QueryResult> {
     // We need to ensure that we have a record finder, else we delete everything (conversion to empty filter).
     let filter = match q.record_filter {
@@ -74,9 +161,9 @@ async fn update_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: UpdateManyRecords,
 ) -> InterpretationResult<QueryResult> {
-    let res = tx.update_records(&q.model, q.record_filter, q.args).await?;
+    let res = referential_actions::update_many_records(tx, q.model, q.record_filter, q.args).await?;
 
-    Ok(QueryResult::Count(res.len()))
+    Ok(QueryResult::Count(res))
 }
 
 async fn delete_many<'a, 'b>(
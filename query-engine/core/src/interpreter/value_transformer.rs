@@ -0,0 +1,226 @@
+use super::{
+    audit_log::{self, AuditLog, AuditTrail, AuditedWrite},
+    field_encryption::{self, FieldEncryptionRef},
+    InterpretationResult, InterpreterError,
+};
+use crate::{
+    query_ast::*,
+    query_document::Operation,
+    result_ast::*,
+    schema::{QuerySchemaRef, QueryTag},
+};
+use connector::ConnectionLike;
+use prisma_models::{ManyRecords, ModelRef};
+use std::{error::Error, sync::Arc};
+
+pub type ValueTransformerRef = Arc<dyn ValueTransformer>;
+
+pub type ValueTransformerResult = std::result::Result<(), Box<dyn Error + Send + Sync>>;
+
+/// The operation of the query document the transformed queries belong to.
+#[derive(Debug, Clone, Default)]
+pub struct OperationMetadata {
+    /// The name of the operation, e.g. `findManyUser`.
+    pub name: String,
+
+    /// `None` for operations that are not in the query schema.
+    pub tag: Option<QueryTag>,
+
+    /// The model of the operation, `None` for raw queries.
+    pub model: Option<ModelRef>,
+
+    /// The id of the request the operation belongs to, see `QueryHints::request_id`.
+    pub request_id: Option<String>,
+}
+
+impl OperationMetadata {
+    pub(crate) fn new(operation: &Operation, query_schema: &QuerySchemaRef) -> Self {
+        let field = match operation {
+            Operation::Read(selection) => query_schema.find_query_field(selection.name()),
+            Operation::Write(selection) => query_schema.find_mutation_field(selection.name()),
+        };
+
+        let info = field.as_ref().and_then(|field| field.query_info.as_ref());
+
+        Self {
+            name: operation.name().to_owned(),
+            tag: info.map(|info| info.tag.clone()),
+            model: info.and_then(|info| info.model.clone()),
+            request_id: None,
+        }
+    }
+
+    pub(crate) fn with_request_id(mut self, request_id: Option<&str>) -> Self {
+        self.request_id = request_id.map(ToOwned::to_owned);
+        self
+    }
+}
+
+/// Transforms the queries of an operation before they are handed to the connector, and their
+/// results right after they are read, e.g. to stamp audit fields, scope reads to a tenant or mask
+/// fields. Implemented and registered by the embedder of the query engine.
+///
+/// Every query node of the operation's query graph goes through the hooks, including the reads
+/// the engine issues internally, e.g. to find the records of a nested write. The `before_*` hooks
+/// run in the order the transformers were registered, `after_read` runs in the reverse order, so
+/// the first transformer sees the queries first and the results last. Fields marked with
+/// `@encrypted` are encrypted after all `before_write` hooks ran and decrypted before the first
+/// `after_read` hook, so transformers only see plain values. A failing hook fails the operation.
+pub trait ValueTransformer: Send + Sync {
+    /// Called with each read query, its nested reads are part of the query.
+    fn before_read(&self, _operation: &OperationMetadata, _query: &mut ReadQuery) -> ValueTransformerResult {
+        Ok(())
+    }
+
+    fn before_write(&self, _operation: &OperationMetadata, _query: &mut WriteQuery) -> ValueTransformerResult {
+        Ok(())
+    }
+
+    /// Called with the records of each model read by a query, including the ones of nested reads.
+    fn after_read(
+        &self,
+        _operation: &OperationMetadata,
+        _model: &ModelRef,
+        _records: &mut ManyRecords,
+    ) -> ValueTransformerResult {
+        Ok(())
+    }
+}
+
+/// The transformers registered with the executor, in registration order, the hooks of the
+/// `@encrypted` fields and the audit log of the writes.
+#[derive(Clone, Default)]
+pub(crate) struct ValueTransformers {
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+    audit_log: Option<AuditLog>,
+}
+
+impl ValueTransformers {
+    pub(crate) fn push(&mut self, transformer: ValueTransformerRef) {
+        self.transformers.push(transformer);
+    }
+
+    pub(crate) fn set_field_encryption(&mut self, field_encryption: FieldEncryptionRef) {
+        self.field_encryption = Some(field_encryption);
+    }
+
+    pub(crate) fn set_audit_log(&mut self, audit_log: AuditLog) {
+        self.audit_log = Some(audit_log);
+    }
+
+    pub(crate) fn for_operation(&self, operation: OperationMetadata) -> OperationTransformers {
+        OperationTransformers {
+            operation,
+            transformers: self.clone(),
+            audit: self.audit_log.clone().map(AuditTrail::new),
+        }
+    }
+}
+
+/// The transformers applied to the queries of a single operation. Clones share the audit entries
+/// recorded for the operation.
+#[derive(Clone, Default)]
+pub(crate) struct OperationTransformers {
+    operation: OperationMetadata,
+    transformers: ValueTransformers,
+    audit: Option<AuditTrail>,
+}
+
+impl OperationTransformers {
+    pub(crate) fn before_read(&self, query: &mut ReadQuery) -> InterpretationResult<()> {
+        for transformer in self.transformers.transformers.iter() {
+            transformer
+                .before_read(&self.operation, query)
+                .map_err(|err| self.error(err))?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn before_write(&self, query: &mut WriteQuery) -> InterpretationResult<()> {
+        for transformer in self.transformers.transformers.iter() {
+            transformer
+                .before_write(&self.operation, query)
+                .map_err(|err| self.error(err))?;
+        }
+
+        match &self.transformers.field_encryption {
+            Some(encryption) => field_encryption::encrypt_write(encryption.as_ref(), query),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn after_read(&self, result: &mut QueryResult) -> InterpretationResult<()> {
+        if let QueryResult::RecordSelection(selection) = result {
+            let model = &selection.query_arguments.model;
+
+            if let Some(encryption) = &self.transformers.field_encryption {
+                field_encryption::decrypt_records(encryption.as_ref(), model, &mut selection.scalars)?;
+            }
+
+            for transformer in self.transformers.transformers.iter().rev() {
+                transformer
+                    .after_read(&self.operation, model, &mut selection.scalars)
+                    .map_err(|err| self.error(err))?;
+            }
+
+            for nested in selection.nested.iter_mut() {
+                self.after_read(nested)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The audit entries of the write, to be recorded with its result, see `record_write`. Updates
+    /// and deletes by filter are limited to the records the filter currently matches.
+    pub(crate) async fn audited_write<'conn, 'tx>(
+        &self,
+        conn: &ConnectionLike<'conn, 'tx>,
+        query: &mut WriteQuery,
+    ) -> InterpretationResult<Option<AuditedWrite>> {
+        if self.audit.is_none() {
+            return Ok(None);
+        }
+
+        audit_log::select_audited_records(conn, query).await?;
+
+        Ok(AuditedWrite::new(query))
+    }
+
+    pub(crate) async fn record_write<'conn, 'tx>(
+        &self,
+        conn: &ConnectionLike<'conn, 'tx>,
+        write: AuditedWrite,
+        result: &QueryResult,
+    ) -> InterpretationResult<()> {
+        match &self.audit {
+            Some(audit) => audit.record(conn, &self.operation, write, result).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the writes of the operation must run in a transaction, so their audit entries are
+    /// inserted atomically with them.
+    pub(crate) fn audit_needs_transaction(&self) -> bool {
+        self.audit.as_ref().map(AuditTrail::is_transactional).unwrap_or(false)
+    }
+
+    /// Hands the audit entries of the operation to the audit sink, once its writes are committed.
+    pub(crate) fn flush_audit_log(&self) {
+        if let Some(audit) = &self.audit {
+            audit.flush();
+        }
+    }
+
+    fn error(&self, err: Box<dyn Error + Send + Sync>) -> InterpreterError {
+        InterpreterError::InterpretationError(
+            format!(
+                "A value transformer failed in the operation `{}`: {}",
+                self.operation.name, err
+            ),
+            None,
+        )
+    }
+}
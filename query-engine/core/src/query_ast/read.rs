@@ -1,6 +1,6 @@
 //! Prisma read query AST
-use super::FilteredQuery;
-use connector::{filter::Filter, AggregationSelection, QueryArguments};
+use super::{FilteredQuery, RawQuery};
+use connector::{filter::Filter, AggregationSelection, GroupBy, QueryArguments, RelationCountSelection};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -10,6 +10,7 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    RawRecordsQuery(RawRecordsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +20,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::RawRecordsQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +32,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::RawRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
         }
     }
 
@@ -39,6 +42,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::RawRecordsQuery(x) => x.model.clone(),
         }
     }
 }
@@ -88,6 +92,15 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::RawRecordsQuery(q) => write!(
+                f,
+                "RawRecordsQuery(name: '{}', model: {}, query: {} ({:?}), selection: {:?})",
+                q.name,
+                q.model.name,
+                q.raw.query,
+                q.raw.parameters,
+                q.selected_fields.names().collect::<Vec<_>>()
+            ),
         }
     }
 }
@@ -101,6 +114,8 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub relation_counts: RelationCounts,
+    pub relation_load_strategy: RelationLoadStrategy,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +127,8 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub relation_counts: RelationCounts,
+    pub relation_load_strategy: RelationLoadStrategy,
 }
 
 #[derive(Debug, Clone)]
@@ -123,12 +140,57 @@ pub struct RelatedRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub relation_counts: RelationCounts,
+    pub relation_load_strategy: RelationLoadStrategy,
 
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_projections: Option<Vec<RecordProjection>>,
 }
 
+/// The `_count` selection of a read query. The counts are selected by the connector alongside
+/// the scalar fields of the records.
+#[derive(Debug, Clone)]
+pub struct RelationCounts {
+    /// The name the counts are returned under, `_count` unless aliased.
+    pub name: String,
+    /// The names the counts are returned under, one per selection.
+    pub selection_order: Vec<String>,
+    pub selections: Vec<RelationCountSelection>,
+}
+
+impl RelationCounts {
+    pub fn is_empty(&self) -> bool {
+        self.selections.is_empty()
+    }
+}
+
+impl Default for RelationCounts {
+    fn default() -> Self {
+        Self {
+            name: String::from("_count"),
+            selection_order: vec![],
+            selections: vec![],
+        }
+    }
+}
+
+/// How the relations selected by a read query are loaded, set with the `relationLoadStrategy`
+/// argument and applied to all nested reads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationLoadStrategy {
+    /// To-one relations are loaded with joins in the query of their parent records.
+    Join,
+    /// Every relation is loaded with a separate query per nesting level.
+    Query,
+}
+
+impl Default for RelationLoadStrategy {
+    fn default() -> Self {
+        Self::Query
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
@@ -137,10 +199,22 @@ pub struct AggregateRecordsQuery {
     pub selection_order: Vec<(String, Option<Vec<String>>)>,
     pub args: QueryArguments,
     pub selectors: Vec<AggregationSelection>,
-    pub group_by: Vec<ScalarFieldRef>,
+    pub group_by: GroupBy,
     pub having: Option<Filter>,
 }
 
+/// A raw query whose result columns are mapped onto the selected fields of a model.
+#[derive(Debug, Clone)]
+pub struct RawRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub raw: RawQuery,
+    pub selected_fields: ModelProjection,
+    pub nested: Vec<ReadQuery>,
+    pub selection_order: Vec<String>,
+}
+
 impl FilteredQuery for RecordQuery {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         self.filter.as_mut()
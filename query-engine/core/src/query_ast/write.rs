@@ -7,7 +7,9 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
+    UpsertRecord(UpsertRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
     DeleteManyRecords(DeleteManyRecords),
@@ -37,6 +39,13 @@ impl WriteQuery {
             Self::CreateRecord(ref mut x) => &mut x.args,
             Self::UpdateRecord(x) => &mut x.args,
             Self::UpdateManyRecords(x) => &mut x.args,
+            Self::CreateManyRecords(x) => {
+                for args in x.args.iter_mut() {
+                    args.insert(DatasourceFieldName(key.clone()), value.clone());
+                }
+
+                return;
+            }
 
             _ => return,
         };
@@ -49,10 +58,12 @@ impl WriteQuery {
 
         // Write operations only return IDs at the moment, so anything different
         // from the primary ID is automatically not returned.
-        // DeleteMany, Connect and Disconnect do not return anything.
+        // CreateMany, Upsert, DeleteMany, Connect and Disconnect do not return anything.
         match self {
             Self::CreateRecord(_) => returns_id,
+            Self::CreateManyRecords(_) => false,
             Self::UpdateRecord(_) => returns_id,
+            Self::UpsertRecord(_) => false,
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
             Self::DeleteManyRecords(_) => false,
@@ -66,7 +77,9 @@ impl WriteQuery {
     pub fn model(&self) -> ModelRef {
         match self {
             Self::CreateRecord(q) => Arc::clone(&q.model),
+            Self::CreateManyRecords(q) => Arc::clone(&q.model),
             Self::UpdateRecord(q) => Arc::clone(&q.model),
+            Self::UpsertRecord(q) => Arc::clone(&q.model),
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
             Self::DeleteManyRecords(q) => Arc::clone(&q.model),
@@ -104,11 +117,17 @@ impl std::fmt::Display for WriteQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::CreateRecord(q) => write!(f, "CreateRecord(model: {}, args: {:?})", q.model.name, q.args,),
+            Self::CreateManyRecords(q) => write!(f, "CreateManyRecords(model: {}, args: {:?})", q.model.name, q.args),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, filter: {:?}, args: {:?})",
                 q.model.name, q.record_filter, q.args,
             ),
+            Self::UpsertRecord(q) => write!(
+                f,
+                "UpsertRecord(model: {}, selector: {:?}, create: {:?}, update: {:?})",
+                q.model.name, q.selector, q.create, q.update,
+            ),
             Self::DeleteRecord(q) => write!(f, "DeleteRecord: {}, {:?}", q.model.name, q.record_filter),
             Self::UpdateManyRecords(q) => write!(f, "UpdateManyRecords(model: {}, args: {:?})", q.model.name, q.args),
             Self::DeleteManyRecords(q) => write!(f, "DeleteManyRecords: {}", q.model.name),
@@ -124,6 +143,28 @@ impl std::fmt::Display for WriteQuery {
 pub struct CreateRecord {
     pub model: ModelRef,
     pub args: WriteArgs,
+    /// Set for the create of a `connectOrCreate`.
+    pub connect_or_create: Option<ConnectOrCreateRetry>,
+}
+
+/// How the create of a `connectOrCreate` recovers if another request created the record after it
+/// was looked up: the create fails with a unique violation, and the existing record is connected
+/// instead. The interpreter retries the create if the record is gone again by the time it is read.
+#[derive(Debug, Clone)]
+pub struct ConnectOrCreateRetry {
+    /// The unique selector of the `connectOrCreate`.
+    pub selector: RecordProjection,
+    /// The fields of the record linking it to the parent, if the create sets them. They are
+    /// updated on the existing record instead.
+    pub linking_fields: Option<ModelProjection>,
+}
+
+/// Creates all records in as few statements as possible. Only used for
+/// records without nested writes of their own, as no IDs are returned.
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<WriteArgs>,
 }
 
 #[derive(Debug, Clone)]
@@ -133,6 +174,17 @@ pub struct UpdateRecord {
     pub args: WriteArgs,
 }
 
+/// Creates the record, or updates the record identified by `selector` if it
+/// already exists, in one operation of a connector with the `NativeUpsert`
+/// capability. The `create` arguments contain the values of the `selector`.
+#[derive(Debug, Clone)]
+pub struct UpsertRecord {
+    pub model: ModelRef,
+    pub selector: RecordProjection,
+    pub create: WriteArgs,
+    pub update: WriteArgs,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateManyRecords {
     pub model: ModelRef,
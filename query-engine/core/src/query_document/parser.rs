@@ -3,7 +3,7 @@ use crate::schema::*;
 use bigdecimal::{BigDecimal, ToPrimitive};
 use chrono::prelude::*;
 use indexmap::IndexMap;
-use prisma_value::PrismaValue;
+use prisma_value::{Interval, PrismaValue};
 use std::{borrow::Borrow, collections::HashSet, convert::TryFrom, str::FromStr, sync::Arc};
 use uuid::Uuid;
 
@@ -227,6 +227,8 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::Bytes) => Self::parse_bytes(parent_path, s),
             (QueryValue::String(s), ScalarType::Decimal) => Self::parse_decimal(parent_path, s),
             (QueryValue::String(s), ScalarType::BigInt) => Self::parse_bigint(parent_path, s),
+            (QueryValue::String(s), ScalarType::UnsignedBigInt) => Self::parse_unsigned_bigint(parent_path, s),
+            (QueryValue::String(s), ScalarType::Interval) => Self::parse_interval(parent_path, s),
             (QueryValue::String(s), ScalarType::UUID) => {
                 Self::parse_uuid(parent_path, s.as_str()).map(PrismaValue::Uuid)
             }
@@ -241,6 +243,7 @@ impl QueryDocumentParser {
             (QueryValue::Int(i), ScalarType::Float) => Ok(PrismaValue::Float(BigDecimal::from(i))),
             (QueryValue::Int(i), ScalarType::Decimal) => Ok(PrismaValue::Float(BigDecimal::from(i))),
             (QueryValue::Int(i), ScalarType::BigInt) => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::Int(i), ScalarType::UnsignedBigInt) => Ok(PrismaValue::BigInt(i)),
 
             (QueryValue::Float(f), ScalarType::Float) => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int) => Ok(PrismaValue::Int(f.to_i64().unwrap())),
@@ -281,6 +284,18 @@ impl QueryDocumentParser {
             })
     }
 
+    pub fn parse_interval(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
+        s.parse::<Interval>()
+            .map(PrismaValue::Interval)
+            .map_err(|_| QueryParserError {
+                path: path.clone(),
+                error_kind: QueryParserErrorKind::ValueParseError(format!(
+                    "'{}' is not a valid ISO 8601 duration, e.g. 'P1DT2H30M'.",
+                    s
+                )),
+            })
+    }
+
     pub fn parse_decimal(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
         BigDecimal::from_str(&s)
             .map(PrismaValue::Float)
@@ -297,6 +312,22 @@ impl QueryDocumentParser {
         })
     }
 
+    /// Values of unsigned columns, e.g. MySQL `BIGINT UNSIGNED`, beyond the signed 64-bit range
+    /// are kept as exact decimals.
+    pub fn parse_unsigned_bigint(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
+        match (s.parse::<i64>(), s.parse::<u64>()) {
+            (Ok(i), _) => Ok(PrismaValue::BigInt(i)),
+            (Err(_), Ok(u)) => Ok(PrismaValue::Float(BigDecimal::from(u))),
+            (Err(_), Err(_)) => Err(QueryParserError {
+                path: path.clone(),
+                error_kind: QueryParserErrorKind::ValueParseError(format!(
+                    "'{}' is not a valid unsigned big integer string",
+                    s
+                )),
+            }),
+        }
+    }
+
     // [DTODO] This is likely incorrect or at least using the wrong abstractions.
     pub fn parse_json_list(path: &QueryPath, s: &str) -> QueryParserResult<PrismaValue> {
         let json = Self::parse_json(path, s)?;
@@ -455,7 +486,10 @@ impl QueryDocumentParser {
         map.extend(defaults.into_iter());
 
         // Ensure the constraints are upheld.
-        let num_fields = map.len();
+        let num_fields = match schema_object.constraints.fields {
+            Some(ref fields) => map.keys().filter(|key| fields.contains(key)).count(),
+            None => map.len(),
+        };
         let too_many = schema_object
             .constraints
             .max_num_fields
@@ -472,7 +506,7 @@ impl QueryDocumentParser {
             let error_kind = QueryParserErrorKind::FieldCountError(FieldCountError::new(
                 schema_object.constraints.min_num_fields,
                 schema_object.constraints.max_num_fields,
-                map.len(),
+                num_fields,
             ));
             return Err(QueryParserError::new(path, error_kind));
         }
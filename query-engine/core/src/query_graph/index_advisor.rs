@@ -0,0 +1,139 @@
+use super::{Node, NodeRef, QueryGraph};
+use crate::{Query, ReadQuery, WriteQuery};
+use connector::{
+    filter::{Filter, ScalarProjection},
+    IndexAdvice, IndexUsage, MissingIndex, QueryArguments,
+};
+use prisma_models::{ModelRef, ScalarFieldRef};
+
+impl QueryGraph {
+    /// Records the fields the queries of the graph filter or order by into `advice` if they are
+    /// not the leading field of an index of their model. The primary key and unique fields count
+    /// as indexes. Filters on compound ids and uniques, scalar lists and aggregates are ignored.
+    pub fn advise_indexes(&self, advice: &IndexAdvice) {
+        for node_ix in self.graph.node_indices() {
+            if let Some(Node::Query(query)) = self.node_content(&NodeRef { node_ix }) {
+                advise_query(query, advice);
+            }
+        }
+    }
+}
+
+fn advise_query(query: &Query, advice: &IndexAdvice) {
+    match query {
+        Query::Read(rq) => advise_read(rq, advice),
+        Query::Write(WriteQuery::UpdateRecord(q)) => advise_filter(&q.model, &q.record_filter.filter, advice),
+        Query::Write(WriteQuery::UpdateManyRecords(q)) => advise_filter(&q.model, &q.record_filter.filter, advice),
+        Query::Write(WriteQuery::DeleteRecord(q)) => {
+            if let Some(record_filter) = &q.record_filter {
+                advise_filter(&q.model, &record_filter.filter, advice);
+            }
+        }
+        Query::Write(WriteQuery::DeleteManyRecords(q)) => advise_filter(&q.model, &q.record_filter.filter, advice),
+        Query::Write(_) => (),
+    }
+}
+
+fn advise_read(query: &ReadQuery, advice: &IndexAdvice) {
+    let nested = match query {
+        ReadQuery::RecordQuery(q) => {
+            if let Some(filter) = &q.filter {
+                advise_filter(&q.model, filter, advice);
+            }
+
+            &q.nested
+        }
+        ReadQuery::ManyRecordsQuery(q) => {
+            advise_args(&q.args, advice);
+            &q.nested
+        }
+        ReadQuery::RelatedRecordsQuery(q) => {
+            advise_args(&q.args, advice);
+            &q.nested
+        }
+        ReadQuery::AggregateRecordsQuery(q) => {
+            advise_args(&q.args, advice);
+            return;
+        }
+        ReadQuery::RawRecordsQuery(_) => return,
+    };
+
+    for nested_query in nested {
+        advise_read(nested_query, advice);
+    }
+}
+
+fn advise_args(args: &QueryArguments, advice: &IndexAdvice) {
+    if let Some(filter) = &args.filter {
+        advise_filter(&args.model, filter, advice);
+    }
+
+    // Orderings of grouped queries by aggregates can't use an index.
+    if args.order_by.iter().any(|order_by| order_by.sort_aggregation.is_some()) {
+        return;
+    }
+
+    let ordered_fields: Vec<ScalarFieldRef> = args.order_by.iter().map(|order_by| order_by.field.clone()).collect();
+
+    // An index can serve the ordering if it starts with the first ordered field.
+    if let Some(first) = ordered_fields.first() {
+        if !is_indexed(&args.model, first) {
+            advice.push(MissingIndex::new(
+                args.model.name.clone(),
+                field_names(&ordered_fields),
+                IndexUsage::OrderBy,
+            ));
+        }
+    }
+}
+
+fn advise_filter(model: &ModelRef, filter: &Filter, advice: &IndexAdvice) {
+    let mut filtered_fields = Vec::new();
+    collect_filtered_fields(filter, &mut filtered_fields, advice);
+
+    let unindexed: Vec<ScalarFieldRef> = filtered_fields
+        .into_iter()
+        .filter(|field| !is_indexed(model, field))
+        .collect();
+
+    if !unindexed.is_empty() {
+        advice.push(MissingIndex::new(
+            model.name.clone(),
+            field_names(&unindexed),
+            IndexUsage::Filter,
+        ));
+    }
+}
+
+/// Collects the fields of the scalar conditions of `filter`. Relation filters are advised on
+/// separately, as their conditions apply to the related model.
+fn collect_filtered_fields(filter: &Filter, fields: &mut Vec<ScalarFieldRef>, advice: &IndexAdvice) {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => {
+            for filter in filters {
+                collect_filtered_fields(filter, fields, advice);
+            }
+        }
+        Filter::Scalar(sf) => {
+            if let ScalarProjection::Single(field) = &sf.projection {
+                if !fields.iter().any(|f| f.name == field.name) {
+                    fields.push(field.clone());
+                }
+            }
+        }
+        Filter::Relation(rf) => advise_filter(&rf.field.related_model(), &rf.nested_filter, advice),
+        _ => (),
+    }
+}
+
+fn is_indexed(model: &ModelRef, field: &ScalarFieldRef) -> bool {
+    let is_leading = |fields: Vec<ScalarFieldRef>| fields.first().map_or(false, |first| first.name == field.name);
+
+    field.unique()
+        || is_leading(model.primary_identifier().scalar_fields().collect())
+        || model.indexes().iter().any(|index| is_leading(index.fields()))
+}
+
+fn field_names(fields: &[ScalarFieldRef]) -> Vec<String> {
+    fields.iter().map(|field| field.name.clone()).collect()
+}
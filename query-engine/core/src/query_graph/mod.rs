@@ -1,6 +1,8 @@
 mod error;
 mod formatters;
 mod guard;
+mod index_advisor;
+mod tracer;
 mod transformers;
 
 pub use error::*;
@@ -9,12 +11,14 @@ pub use transformers::*;
 
 use crate::{
     interpreter::ExpressionResult, FilteredQuery, ManyRecordsQuery, Query, QueryGraphBuilderResult, ReadQuery,
+    RelationCounts, RelationLoadStrategy,
 };
-use connector::{IdFilter, QueryArguments};
+use connector::{IdFilter, QueryArguments, QueryGraphAction, QueryGraphTraces};
 use guard::*;
 use petgraph::{graph::*, visit::EdgeRef as PEdgeRef, *};
 use prisma_models::{ModelProjection, ModelRef, RecordProjection};
 use std::{borrow::Borrow, collections::HashSet};
+use tracer::GraphTracer;
 
 pub type QueryGraphResult<T> = std::result::Result<T, QueryGraphError>;
 
@@ -180,6 +184,9 @@ pub struct QueryGraph {
     /// Nodes are visited during query graph processing.
     /// Influences traversal rules and how child nodes are treated.
     visited: Vec<NodeIndex>,
+
+    /// Records the changes to the graph and the order of translation if tracing is enabled.
+    tracer: Option<GraphTracer>,
 }
 
 /// Implementation detail of the QueryGraph.
@@ -204,8 +211,11 @@ impl QueryGraph {
 
     pub fn finalize(&mut self) -> QueryGraphResult<()> {
         if !self.finalized {
+            self.set_trace_stage("swapMarked");
             self.swap_marked()?;
+            self.set_trace_stage("insertReloads");
             self.insert_reloads()?;
+            self.set_trace_stage("normalizeIfNodes");
             self.normalize_if_nodes()?;
             self.finalized = true;
         }
@@ -213,6 +223,65 @@ impl QueryGraph {
         Ok(())
     }
 
+    /// Starts tracing the graph of `operation` into `traces`. The graph as built so far is recorded
+    /// in the `build` stage, all following changes in the stage that made them. Visited nodes are
+    /// recorded as the execution order, see `finish_trace`.
+    pub fn enable_trace(&mut self, traces: QueryGraphTraces, operation: &str) {
+        self.tracer = Some(GraphTracer::new(traces, operation));
+
+        for node_ix in self.graph.node_indices() {
+            let node = NodeRef { node_ix };
+            self.trace(|graph| QueryGraphAction::CreateNode {
+                node: node.id(),
+                content: graph.node_content(&node).map(ToString::to_string).unwrap_or_default(),
+            });
+        }
+
+        for edge_ix in self.graph.edge_indices() {
+            let edge = EdgeRef { edge_ix };
+            self.trace(|graph| graph.create_edge_action(&edge));
+        }
+
+        for (parent, child) in self.marked_node_pairs.clone() {
+            self.trace(|_| QueryGraphAction::MarkNodes {
+                parent: parent.id(),
+                child: child.id(),
+            });
+        }
+    }
+
+    /// Hands the trace of the graph over to the collector passed to `enable_trace`. Called once
+    /// the graph is translated, when all nodes have been visited.
+    pub fn finish_trace(&mut self) {
+        if let Some(tracer) = self.tracer.take() {
+            tracer.finish();
+        }
+    }
+
+    fn set_trace_stage(&mut self, stage: &'static str) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.set_stage(stage);
+        }
+    }
+
+    fn trace<F>(&mut self, action: F)
+    where
+        F: FnOnce(&Self) -> QueryGraphAction,
+    {
+        if self.tracer.is_some() {
+            let action = action(self);
+            self.tracer.as_mut().unwrap().record(action);
+        }
+    }
+
+    fn create_edge_action(&self, edge: &EdgeRef) -> QueryGraphAction {
+        QueryGraphAction::CreateEdge {
+            from: self.edge_source(edge).id(),
+            to: self.edge_target(edge).id(),
+            dependency: self.edge_content(edge).map(ToString::to_string).unwrap_or_default(),
+        }
+    }
+
     /// Returns a NodeRef to the result node that occurs in the subtree, if it exists.
     /// Returns None if no such node is found.
     pub fn find_result_node(&self, starting_node: &NodeRef) -> Option<NodeRef> {
@@ -239,6 +308,11 @@ impl QueryGraph {
         if !self.visited.contains(&node.node_ix) {
             trace!("Visited: {}", node.id());
             self.visited.push(node.node_ix);
+
+            if self.tracer.is_some() {
+                let content = self.node_content(node).map(ToString::to_string).unwrap_or_default();
+                self.tracer.as_mut().unwrap().record_step(node.id(), content);
+            }
         }
     }
 
@@ -285,8 +359,14 @@ impl QueryGraph {
         T: Into<Node>,
     {
         let node_ix = self.graph.add_node(Guard::new(t.into()));
+        let node = NodeRef { node_ix };
 
-        NodeRef { node_ix }
+        self.trace(|graph| QueryGraphAction::CreateNode {
+            node: node.id(),
+            content: graph.node_content(&node).map(ToString::to_string).unwrap_or_default(),
+        });
+
+        node
     }
 
     /// Creates an edge with given `content`, originating from node `from` and pointing to node `to`.
@@ -302,6 +382,8 @@ impl QueryGraph {
         let edge_ix = self.graph.add_edge(from.node_ix, to.node_ix, Guard::new(content));
         let edge = EdgeRef { edge_ix };
 
+        self.trace(|graph| graph.create_edge_action(&edge));
+
         Ok(edge)
     }
 
@@ -362,6 +444,12 @@ impl QueryGraph {
     /// Completely removes the edge from the graph, returning it's content.
     /// This operation is destructive on the underlying graph and invalidates references.
     pub fn remove_edge(&mut self, edge: EdgeRef) -> Option<QueryGraphDependency> {
+        self.trace(|graph| QueryGraphAction::RemoveEdge {
+            from: graph.edge_source(&edge).id(),
+            to: graph.edge_target(&edge).id(),
+            dependency: graph.edge_content(&edge).map(ToString::to_string),
+        });
+
         self.graph.remove_edge(edge.edge_ix).unwrap().into_inner()
     }
 
@@ -437,6 +525,11 @@ impl QueryGraph {
     /// Marks a node pair for swapping.
     pub fn mark_nodes(&mut self, parent_node: &NodeRef, child_node: &NodeRef) {
         self.marked_node_pairs.push((*parent_node, *child_node));
+
+        self.trace(|_| QueryGraphAction::MarkNodes {
+            parent: parent_node.id(),
+            child: child_node.id(),
+        });
     }
 
     /// Swaps all marked parent-child pairs.
@@ -746,6 +839,8 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                relation_counts: RelationCounts::default(),
+                relation_load_strategy: RelationLoadStrategy::default(),
             });
 
             let query = Query::Read(read_query);
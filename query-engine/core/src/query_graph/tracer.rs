@@ -0,0 +1,38 @@
+use connector::{QueryGraphAction, QueryGraphEvent, QueryGraphStep, QueryGraphTrace, QueryGraphTraces};
+
+/// Records the changes to a query graph while it is traced, see `QueryGraph::enable_trace`.
+pub(super) struct GraphTracer {
+    traces: QueryGraphTraces,
+    trace: QueryGraphTrace,
+    stage: &'static str,
+}
+
+impl GraphTracer {
+    pub(super) fn new(traces: QueryGraphTraces, operation: &str) -> Self {
+        Self {
+            traces,
+            trace: QueryGraphTrace::new(operation),
+            stage: "build",
+        }
+    }
+
+    pub(super) fn set_stage(&mut self, stage: &'static str) {
+        self.stage = stage;
+    }
+
+    pub(super) fn record(&mut self, action: QueryGraphAction) {
+        self.trace.events.push(QueryGraphEvent {
+            stage: self.stage,
+            action,
+        });
+    }
+
+    pub(super) fn record_step(&mut self, node: String, content: String) {
+        self.trace.execution_order.push(QueryGraphStep { node, content });
+    }
+
+    /// Hands the trace over to the collector of the request.
+    pub(super) fn finish(self) {
+        self.traces.push(self.trace);
+    }
+}
@@ -1,11 +1,19 @@
 use super::*;
 use crate::{query_document::*, query_graph::*, schema::*, IrSerializer};
+use connector::{IndexAdvice, QueryGraphTraces};
+use datamodel_connector::ConnectorCapability;
 use prisma_value::PrismaValue;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
 // the query_document module, possibly already as part of the parser.
 pub struct QueryGraphBuilder {
     pub query_schema: QuerySchemaRef,
+
+    /// Collects the traces of the built graphs, see `QueryGraph::enable_trace`.
+    trace: Option<QueryGraphTraces>,
+
+    /// Collects the missing indexes of the built graphs, see `QueryGraph::advise_indexes`.
+    index_advice: Option<IndexAdvice>,
 }
 
 #[derive(Default)]
@@ -39,7 +47,23 @@ impl From<Vec<ParsedArgument>> for RawArgs {
 
 impl QueryGraphBuilder {
     pub fn new(query_schema: QuerySchemaRef) -> Self {
-        Self { query_schema }
+        Self {
+            query_schema,
+            trace: None,
+            index_advice: None,
+        }
+    }
+
+    /// Traces the finalization and translation of the built graphs into `traces`.
+    pub fn with_trace(mut self, traces: Option<QueryGraphTraces>) -> Self {
+        self.trace = traces;
+        self
+    }
+
+    /// Advises on the indexes the queries of the built graphs are missing into `advice`.
+    pub fn with_index_advice(mut self, advice: Option<IndexAdvice>) -> Self {
+        self.index_advice = advice;
+        self
     }
 
     /// Maps an operation to a query.
@@ -77,28 +101,49 @@ impl QueryGraphBuilder {
     fn dispatch_build(&self, field_pair: FieldPair) -> QueryGraphBuilderResult<QueryGraph> {
         let query_info = field_pair.schema_field.query_info.as_ref().unwrap();
         let parsed_field = field_pair.parsed_field;
+        let operation = parsed_field.name.clone();
 
         let mut graph = match (&query_info.tag, query_info.model.clone()) {
             (QueryTag::FindOne, Some(m)) => read::find_one(parsed_field, m).map(Into::into),
             (QueryTag::FindFirst, Some(m)) => read::find_first(parsed_field, m).map(Into::into),
             (QueryTag::FindMany, Some(m)) => read::find_many(parsed_field, m).map(Into::into),
+            (QueryTag::FindRaw, Some(m)) => {
+                read::find_raw(parsed_field, m, self.query_schema.read_only).map(Into::into)
+            }
             (QueryTag::Aggregate, Some(m)) => read::aggregate(parsed_field, m).map(Into::into),
-            (QueryTag::GroupBy, Some(m)) => read::group_by(parsed_field, m).map(Into::into),
+            (QueryTag::GroupBy, Some(m)) => {
+                read::group_by(parsed_field, m, self.query_schema.group_by_take_limit).map(Into::into)
+            }
             (QueryTag::CreateOne, Some(m)) => QueryGraph::root(|g| write::create_record(g, m, parsed_field)),
             (QueryTag::UpdateOne, Some(m)) => QueryGraph::root(|g| write::update_record(g, m, parsed_field)),
             (QueryTag::UpdateMany, Some(m)) => QueryGraph::root(|g| write::update_many_records(g, m, parsed_field)),
-            (QueryTag::UpsertOne, Some(m)) => QueryGraph::root(|g| write::upsert_record(g, m, parsed_field)),
+            (QueryTag::UpsertOne, Some(m)) => {
+                let native_upsert = self.query_schema.has_capability(ConnectorCapability::NativeUpsert);
+                QueryGraph::root(|g| write::upsert_record(g, m, parsed_field, native_upsert))
+            }
             (QueryTag::DeleteOne, Some(m)) => QueryGraph::root(|g| write::delete_record(g, m, parsed_field)),
             (QueryTag::DeleteMany, Some(m)) => QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field)),
-            (QueryTag::ExecuteRaw, _) => QueryGraph::root(|g| write::execute_raw(g, parsed_field)),
-            (QueryTag::QueryRaw, _) => QueryGraph::root(|g| write::query_raw(g, parsed_field)),
+            (QueryTag::ExecuteRaw, _) => {
+                QueryGraph::root(|g| write::execute_raw(g, parsed_field, self.query_schema.read_only))
+            }
+            (QueryTag::QueryRaw, _) => {
+                QueryGraph::root(|g| write::query_raw(g, parsed_field, self.query_schema.read_only))
+            }
             _ => unreachable!("Query builder dispatching failed."),
         }?;
 
+        if let Some(traces) = &self.trace {
+            graph.enable_trace(traces.clone(), &operation);
+        }
+
         // Run final transformations.
         graph.finalize()?;
         trace!("{}", graph);
 
+        if let Some(advice) = &self.index_advice {
+            graph.advise_indexes(advice);
+        }
+
         Ok(graph)
     }
 
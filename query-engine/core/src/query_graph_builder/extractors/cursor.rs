@@ -0,0 +1,75 @@
+use crate::{QueryGraphBuilderError, QueryGraphBuilderResult};
+use bigdecimal::BigDecimal;
+use chrono::DateTime;
+use prisma_models::{ModelRef, PrismaValue, RecordProjection, ScalarFieldRef, TypeIdentifier};
+use serde_json::Value;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Encodes the primary identifier of a record into an opaque cursor: the URL safe base64 of a
+/// JSON array of the identifier values, in the order of the identifier fields.
+pub fn encode_cursor(record_id: &RecordProjection) -> String {
+    let values: Vec<Value> = record_id.values().map(encode_value).collect();
+    let json = serde_json::to_string(&values).expect("cursor values to serialize");
+
+    base64::encode_config(json, base64::URL_SAFE_NO_PAD)
+}
+
+/// Decodes an opaque cursor of `encode_cursor` into the primary identifier of a record of `model`.
+pub fn decode_cursor(cursor: &str, model: &ModelRef) -> QueryGraphBuilderResult<RecordProjection> {
+    let invalid =
+        || QueryGraphBuilderError::InputError(format!("Invalid cursor '{}' for model {}.", cursor, model.name));
+
+    let json = base64::decode_config(cursor, base64::URL_SAFE_NO_PAD).map_err(|_| invalid())?;
+    let values: Vec<Value> = serde_json::from_slice(&json).map_err(|_| invalid())?;
+    let fields: Vec<ScalarFieldRef> = model.primary_identifier().scalar_fields().collect();
+
+    if values.len() != fields.len() {
+        return Err(invalid());
+    }
+
+    let pairs = fields
+        .into_iter()
+        .zip(values)
+        .map(|(field, value)| match decode_value(&field.type_identifier, value) {
+            Some(value) => Ok((field, value)),
+            None => Err(invalid()),
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(RecordProjection::new(pairs))
+}
+
+/// Values are encoded losslessly, as opposed to the serialization of `PrismaValue`, which renders
+/// decimals as floats.
+fn encode_value(value: PrismaValue) -> Value {
+    match value {
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => Value::from(i),
+        PrismaValue::Boolean(b) => Value::from(b),
+        PrismaValue::String(s) | PrismaValue::Enum(s) => Value::from(s),
+        PrismaValue::Uuid(uuid) => Value::from(uuid.to_hyphenated().to_string()),
+        PrismaValue::DateTime(date) => Value::from(prisma_value::stringify_date(&date)),
+        PrismaValue::Float(decimal) => Value::from(decimal.to_string()),
+        PrismaValue::Bytes(bytes) => Value::from(prisma_value::encode_bytes(&bytes)),
+        _ => Value::Null,
+    }
+}
+
+fn decode_value(typ: &TypeIdentifier, value: Value) -> Option<PrismaValue> {
+    let value = match (typ, value) {
+        (TypeIdentifier::Int, Value::Number(n)) => PrismaValue::Int(n.as_i64()?),
+        (TypeIdentifier::BigInt, Value::Number(n)) => PrismaValue::BigInt(n.as_i64()?),
+        (TypeIdentifier::Boolean, Value::Bool(b)) => PrismaValue::Boolean(b),
+        (TypeIdentifier::String, Value::String(s)) => PrismaValue::String(s),
+        (TypeIdentifier::Enum(_), Value::String(s)) => PrismaValue::Enum(s),
+        (TypeIdentifier::UUID, Value::String(s)) => PrismaValue::Uuid(Uuid::parse_str(&s).ok()?),
+        (TypeIdentifier::DateTime, Value::String(s)) => PrismaValue::DateTime(DateTime::parse_from_rfc3339(&s).ok()?),
+        (TypeIdentifier::Float, Value::String(s)) | (TypeIdentifier::Decimal, Value::String(s)) => {
+            PrismaValue::Float(BigDecimal::from_str(&s).ok()?)
+        }
+        (TypeIdentifier::Bytes, Value::String(s)) => PrismaValue::Bytes(prisma_value::decode_bytes(&s).ok()?),
+        _ => return None,
+    };
+
+    Some(value)
+}
@@ -5,11 +5,11 @@ mod scalar;
 use super::utils;
 use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
-    QueryGraphBuilderError, QueryGraphBuilderResult,
+    schema_builder, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
 use connector::{filter::Filter, QueryMode, RelationCompare, ScalarCompare};
 use filter_grouping::*;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef};
 use std::{convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
@@ -36,6 +36,23 @@ pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> Que
     Ok(Filter::and(filters))
 }
 
+/// Removes the `@version` field of the model from a unique selector. The client gives it in
+/// addition to the unique criteria to only write the record if it still has that version.
+pub fn extract_expected_version(
+    value_map: &mut ParsedInputMap,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Option<PrismaValue>> {
+    let version = match model.fields().version() {
+        Some(version) if !version.unique() => version,
+        _ => return Ok(None),
+    };
+
+    match value_map.remove(&version.name) {
+        Some(value) => Ok(Some(value.try_into()?)),
+        None => Ok(None),
+    }
+}
+
 fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
     let mut input_map: ParsedInputMap = value.try_into()?;
 
@@ -50,6 +67,44 @@ fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -
     Ok(Filter::And(filters))
 }
 
+/// Extracts the fields and values of a unique selector that consists of exactly one
+/// unique criterion without null values, like `{ email: "..." }` or a compound id.
+pub fn extract_unique_selector(
+    value_map: &ParsedInputMap,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Option<RecordProjection>> {
+    let (field_name, value) = match value_map.iter().next() {
+        Some(entry) if value_map.len() == 1 => entry,
+        _ => return Ok(None),
+    };
+
+    let pairs = match model.fields().find_from_scalar(field_name) {
+        Ok(field) => vec![(field, value.clone().try_into()?)],
+        Err(_) => {
+            let fields = match utils::resolve_compound_field(field_name, model) {
+                Some(fields) => fields,
+                None => return Ok(None),
+            };
+
+            let mut input_map: ParsedInputMap = value.clone().try_into()?;
+
+            fields
+                .into_iter()
+                .map(|sf| {
+                    let pv: PrismaValue = input_map.remove(&sf.name).unwrap().try_into()?;
+                    Ok((sf, pv))
+                })
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?
+        }
+    };
+
+    if pairs.iter().any(|(_, value)| value.is_null()) {
+        return Ok(None);
+    }
+
+    Ok(Some(RecordProjection::new(pairs)))
+}
+
 /// Extracts a regular filter potentially matching many records.
 ///
 /// # Filter rules
@@ -111,9 +166,12 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
                         }
                     }
                     Err(_) => {
-                        let filters = match model.fields().find_from_all(&key)? {
-                            Field::Relation(rf) => extract_relation_filters(rf, value),
-                            Field::Scalar(sf) => extract_scalar_filters(sf, value),
+                        let filters = match find_join_table_filter(model, &key) {
+                            Some((rf, join_field)) => extract_join_table_filters(&rf, &join_field, value),
+                            None => match model.fields().find_from_all(&key)? {
+                                Field::Relation(rf) => extract_relation_filters(rf, value),
+                                Field::Scalar(sf) => extract_scalar_filters(sf, value),
+                            },
                         }?;
 
                         // strip empty filters
@@ -200,6 +258,31 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
     }
 }
 
+/// Resolves a `<field>_<join field>` filter key to the list relation field pointing to a join
+/// model and the relation field of the join model pointing to the other side of the relation.
+fn find_join_table_filter(model: &ModelRef, key: &str) -> Option<(RelationFieldRef, RelationFieldRef)> {
+    model.fields().relation().into_iter().find_map(|rf| {
+        rf.join_table_field()
+            .filter(|join_field| schema_builder::join_table_filter_name(&rf, join_field) == key)
+            .map(|join_field| (rf, join_field))
+    })
+}
+
+/// Filters over a join model, e.g. `where: { <field>_<join field>: { some: <value> } }`, are
+/// the relation filters `where: { <field>: { some: { <join field>: { is: <value> } } } }`.
+fn extract_join_table_filters(
+    field: &RelationFieldRef,
+    join_field: &RelationFieldRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
+    let filter_map: ParsedInputMap = value.try_into()?;
+
+    filter_map
+        .into_iter()
+        .map(|(k, v)| relation::parse_join_table(&k, field, join_field, v))
+        .collect()
+}
+
 fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
     let value: PrismaValue = input.try_into()?;
     let s = match value {
@@ -25,3 +25,26 @@ pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue
         ))),
     }
 }
+
+/// Parses a list filter on the far side of a many-to-many relation backed by a join model, see
+/// `extract_join_table_filters`.
+pub fn parse_join_table(
+    filter_key: &str,
+    field: &RelationFieldRef,
+    join_field: &RelationFieldRef,
+    input: ParsedInputValue,
+) -> QueryGraphBuilderResult<Filter> {
+    let value: ParsedInputMap = input.try_into()?;
+    let related_filter = join_field.to_one_related(extract_filter(value, &join_field.related_model())?);
+
+    match filter_key {
+        "some" => Ok(field.at_least_one_related(related_filter)),
+        "none" => Ok(field.no_related(related_filter)),
+        "every" => Ok(field.every_related(related_filter)),
+
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid filter key `{}` input combination for join table filter",
+            filter_key
+        ))),
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, ScalarCompare};
-use prisma_models::{PrismaValue, ScalarFieldRef};
+use chrono::NaiveDate;
+use connector::{BitwiseCompare, Filter, RelativeTime, ScalarCompare, ScalarListCompare, TimeUnit};
+use prisma_models::{JsonNullValue, PrismaValue, ScalarFieldRef, TypeIdentifier};
 use std::convert::TryInto;
 
 pub fn parse(
@@ -9,6 +10,14 @@ pub fn parse(
     input: ParsedInputValue,
     reverse: bool,
 ) -> QueryGraphBuilderResult<Vec<Filter>> {
+    if let Some(json_null) = json_null_value(field, &input) {
+        match filter_key {
+            "equals" => return Ok(vec![json_null_filter(field, json_null, reverse)]),
+            "not" => return Ok(vec![json_null_filter(field, json_null, !reverse)]),
+            _ => (),
+        }
+    }
+
     let filter = match filter_key {
         "not" => {
             match input {
@@ -81,6 +90,25 @@ pub fn parse(
         "lte" => vec![field.less_than_or_equals(as_prisma_value(input)?)],
         "gte" => vec![field.greater_than_or_equals(as_prisma_value(input)?)],
 
+        // Bitwise filters
+        "hasBit" | "hasAnyBit" => bitwise_filter(filter_key, field, input, reverse)?,
+
+        // Date filters
+        "dateEquals" | "dateLt" | "dateLte" | "dateGt" | "dateGte" => date_filter(filter_key, field, input, reverse)?,
+
+        "inTheLast" => relative_time_filter(field, input, reverse)?,
+
+        // List filters
+        "has" if reverse => vec![Filter::not(vec![field.contains_element(as_prisma_value(input)?)])],
+        "hasEvery" if reverse => vec![Filter::not(vec![field.contains_every_element(as_prisma_list(input)?)])],
+        "hasSome" if reverse => vec![Filter::not(vec![field.contains_some_element(as_prisma_list(input)?)])],
+        "isEmpty" if reverse => vec![field.is_empty_list(!as_bool(input)?)],
+
+        "has" => vec![field.contains_element(as_prisma_value(input)?)],
+        "hasEvery" => vec![field.contains_every_element(as_prisma_list(input)?)],
+        "hasSome" => vec![field.contains_some_element(as_prisma_list(input)?)],
+        "isEmpty" => vec![field.is_empty_list(as_bool(input)?)],
+
         // Aggregation filters
         "count" => aggregation_filter(field, input, reverse, Filter::count)?,
         "avg" => aggregation_filter(field, input, reverse, Filter::average)?,
@@ -99,10 +127,173 @@ pub fn parse(
     Ok(filter)
 }
 
+/// The `DbNull`, `JsonNull` or `AnyNull` placeholder passed to a filter on a Json field.
+fn json_null_value(field: &ScalarFieldRef, input: &ParsedInputValue) -> Option<JsonNullValue> {
+    match input {
+        ParsedInputValue::Single(value) if field.type_identifier == TypeIdentifier::Json => {
+            JsonNullValue::from_value(value)
+        }
+        _ => None,
+    }
+}
+
+/// Filters Json fields on the database NULL, the JSON `null`, or either of them.
+fn json_null_filter(field: &ScalarFieldRef, json_null: JsonNullValue, reverse: bool) -> Filter {
+    let values = match json_null {
+        JsonNullValue::DbNull => vec![PrismaValue::Null],
+        JsonNullValue::JsonNull => vec![PrismaValue::json_null()],
+        JsonNullValue::AnyNull => vec![PrismaValue::Null, PrismaValue::json_null()],
+    };
+
+    if reverse {
+        Filter::and(values.into_iter().map(|value| field.not_equals(value)).collect())
+    } else {
+        Filter::or(values.into_iter().map(|value| field.equals(value)).collect())
+    }
+}
+
+/// Date filters compare with the instants the days start at, which works on all connectors and
+/// can use the indexes of the column, unlike truncating its values to dates. `dateEquals` is the
+/// range from the start of the day to the start of the next one.
+fn date_filter(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    reverse: bool,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
+    let date = match as_prisma_value(input)? {
+        PrismaValue::DateTime(dt) => dt.naive_local().date(),
+        PrismaValue::String(s) => NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(|_| {
+            QueryGraphBuilderError::InputError(format!("'{}' is not a valid date, expected YYYY-MM-DD.", s))
+        })?,
+        _ => unreachable!(), // Validation guarantees this.
+    };
+
+    let next_date = date
+        .succ_opt()
+        .ok_or_else(|| QueryGraphBuilderError::InputError(format!("'{}' is out of the supported date range.", date)))?;
+
+    let start = PrismaValue::DateTime(field.start_of_day(date));
+    let end = PrismaValue::DateTime(field.start_of_day(next_date));
+
+    let filters = match (filter_key, reverse) {
+        ("dateEquals", false) => vec![field.greater_than_or_equals(start), field.less_than(end)],
+        ("dateEquals", true) => vec![Filter::or(vec![
+            field.less_than(start),
+            field.greater_than_or_equals(end),
+        ])],
+        ("dateLt", false) | ("dateGte", true) => vec![field.less_than(start)],
+        ("dateGte", false) | ("dateLt", true) => vec![field.greater_than_or_equals(start)],
+        ("dateLte", false) | ("dateGt", true) => vec![field.less_than(end)],
+        ("dateGt", false) | ("dateLte", true) => vec![field.greater_than_or_equals(end)],
+        _ => unreachable!(),
+    };
+
+    Ok(filters)
+}
+
+/// Bitwise filters mask integer columns. `BIT` columns hold bit strings rather than integers on
+/// most databases, and the masks must not be negative, as MySQL evaluates `&` on unsigned 64-bit
+/// integers and would match different rows than the other connectors.
+fn bitwise_filter(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    reverse: bool,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
+    if matches!(&field.native_type, Some(native_type) if native_type.name == "Bit") {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "`{}` is not supported on the `Bit` column `{}`.",
+            filter_key, field.name
+        )));
+    }
+
+    let mask = match as_prisma_value(input)? {
+        PrismaValue::Int(mask) | PrismaValue::BigInt(mask) if mask < 0 => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "The mask of `{}` must not be negative, got {}.",
+                filter_key, mask
+            )))
+        }
+        mask => mask,
+    };
+
+    let filter = match filter_key {
+        "hasBit" => field.has_bits(mask),
+        "hasAnyBit" => field.has_any_bits(mask),
+        _ => unreachable!(),
+    };
+
+    if reverse {
+        Ok(vec![Filter::not(vec![filter])])
+    } else {
+        Ok(vec![filter])
+    }
+}
+
+/// `inTheLast` matches the values from the given amount of time ago up to now. The database
+/// evaluates the window with its own clock when the query runs, see `ScalarCondition::InTheLast`.
+fn relative_time_filter(
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    reverse: bool,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
+    let mut window: ParsedInputMap = input.try_into()?;
+
+    // The upper bound is the largest amount all connectors accept, e.g. `DATEADD` on SQL Server.
+    let amount = match as_prisma_value(window.remove("amount").unwrap())? {
+        PrismaValue::Int(amount) if (0..=i64::from(i32::MAX)).contains(&amount) => amount,
+        PrismaValue::Int(amount) => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "The amount of `inTheLast` must be between 0 and {}, got {}.",
+                i32::MAX,
+                amount
+            )))
+        }
+        _ => unreachable!(), // Validation guarantees this.
+    };
+
+    let unit = match as_prisma_value(window.remove("unit").unwrap())? {
+        PrismaValue::Enum(unit) | PrismaValue::String(unit) => match unit.as_str() {
+            "seconds" => TimeUnit::Seconds,
+            "minutes" => TimeUnit::Minutes,
+            "hours" => TimeUnit::Hours,
+            "days" => TimeUnit::Days,
+            "weeks" => TimeUnit::Weeks,
+            "months" => TimeUnit::Months,
+            "years" => TimeUnit::Years,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    let filter = field.in_the_last(RelativeTime { amount, unit });
+
+    if reverse {
+        Ok(vec![Filter::not(vec![filter])])
+    } else {
+        Ok(vec![filter])
+    }
+}
+
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
 
+fn as_prisma_list(input: ParsedInputValue) -> QueryGraphBuilderResult<Vec<PrismaValue>> {
+    match as_prisma_value(input)? {
+        PrismaValue::List(values) => Ok(values),
+        value => Ok(vec![value]), // Single value to list coercion.
+    }
+}
+
+fn as_bool(input: ParsedInputValue) -> QueryGraphBuilderResult<bool> {
+    match as_prisma_value(input)? {
+        PrismaValue::Boolean(b) => Ok(b),
+        _ => unreachable!(), // Validation guarantees this.
+    }
+}
+
 fn aggregation_filter<F>(
     field: &ScalarFieldRef,
     input: ParsedInputValue,
@@ -1,7 +1,9 @@
+mod cursor;
 mod filters;
 mod query_arguments;
 mod utils;
 
+pub use cursor::*;
 pub use filters::*;
 pub use query_arguments::*;
 
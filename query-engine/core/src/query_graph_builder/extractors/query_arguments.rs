@@ -3,9 +3,10 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
+use connector::{QueryArguments, Window};
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortAggregation,
+    SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -13,6 +14,33 @@ use std::convert::{identity, TryInto};
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail.
 pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
+    let query_args = extract_arguments(arguments, model)?;
+    let tie_breakers = model.primary_identifier().scalar_fields().collect();
+
+    Ok(finalize_arguments(query_args, tie_breakers))
+}
+
+/// Same as `extract_query_args`, but for group by queries: Groups are unique by the grouped fields,
+/// which are therefore used instead of the primary identifier to break ties in the ordering.
+pub fn extract_grouped_query_args(
+    arguments: Vec<ParsedArgument>,
+    model: &ModelRef,
+    group_by: &[ScalarFieldRef],
+    default_take: Option<i64>,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    let mut query_args = extract_arguments(arguments, model)?;
+
+    if query_args.take.is_none() {
+        query_args.take = default_take;
+    }
+
+    Ok(finalize_arguments(query_args, group_by.to_vec()))
+}
+
+fn extract_arguments(mut arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
+    let after = arguments.lookup("after");
+    let before = arguments.lookup("before");
+
     let query_args = arguments.into_iter().fold(
         Ok(QueryArguments::new(model.clone())),
         |result: QueryGraphBuilderResult<QueryArguments>, arg| {
@@ -43,6 +71,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
+                    "window" => Ok(QueryArguments {
+                        window: extract_window(model, arg.value)?,
+                        ..res
+                    }),
+
                     "where" => {
                         let val: Option<ParsedInputMap> = arg.value.try_into()?;
                         match val {
@@ -62,7 +95,66 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
-    Ok(finalize_arguments(query_args, model))
+    let query_args = apply_keyset_cursor(query_args, after, before, model)?;
+
+    if query_args.window.is_some() && (query_args.cursor.is_some() || query_args.distinct.is_some()) {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `window` argument can't be used together with cursors or `distinct`.".to_owned(),
+        ));
+    }
+
+    Ok(query_args)
+}
+
+/// Pages from the record of an opaque `after` or `before` cursor, see `decode_cursor`. As opposed
+/// to `cursor`, the record of the cursor itself is not returned. `before` pages backwards, so it
+/// requires `take`, which counts the records from the cursor towards the start of the ordering.
+fn apply_keyset_cursor(
+    mut args: QueryArguments,
+    after: Option<ParsedArgument>,
+    before: Option<ParsedArgument>,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    let (cursor_arg, backwards) = match (after, before) {
+        (None, None) => return Ok(args),
+        (Some(after), None) => (after, false),
+        (None, Some(before)) => (before, true),
+        (Some(_), Some(_)) => {
+            return Err(QueryGraphBuilderError::InputError(
+                "The `after` and `before` arguments can't be used together.".to_owned(),
+            ))
+        }
+    };
+
+    if args.cursor.is_some() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` argument can't be used together with `cursor`.",
+            cursor_arg.name
+        )));
+    }
+
+    match (backwards, args.take) {
+        (true, Some(take)) if take > 0 => args.take = Some(-take),
+        (true, _) => {
+            return Err(QueryGraphBuilderError::InputError(
+                "The `before` argument requires a positive `take`.".to_owned(),
+            ))
+        }
+        (false, Some(take)) if take < 0 => {
+            return Err(QueryGraphBuilderError::InputError(
+                "The `after` argument can't be used with a negative `take`, use `before` instead.".to_owned(),
+            ))
+        }
+        (false, _) => (),
+    }
+
+    let cursor: PrismaValue = cursor_arg.value.try_into()?;
+    let cursor = decode_cursor(&cursor.into_string().unwrap(), model)?;
+
+    args.cursor = Some(cursor);
+    args.skip = Some(args.skip.unwrap_or(0) + 1);
+
+    Ok(args)
 }
 
 /// Extracts order by conditions in order of appearance, as defined in
@@ -72,21 +164,7 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
+                process_order_object(model, object)
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().filter_map(identity).collect()),
@@ -101,24 +179,50 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
 }
 
 fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
-
     match object.into_iter().next() {
         None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
+
+        // Ordering by an aggregate, e.g. `{ _count: { id: desc } }`. Only available on group by.
+        Some((aggregation_name, ParsedInputValue::Map(inner))) => {
+            let sort_aggregation = match aggregation_name.as_str() {
+                "_count" => SortAggregation::Count,
+                "_avg" => SortAggregation::Avg,
+                "_sum" => SortAggregation::Sum,
+                "_min" => SortAggregation::Min,
+                "_max" => SortAggregation::Max,
                 _ => unreachable!(),
             };
 
+            match inner.into_iter().next() {
+                None => Ok(None),
+                Some((field_name, sort_order)) => {
+                    let field = model.fields().find_from_scalar(&field_name)?;
+                    let sort_order = extract_sort_order(sort_order)?;
+
+                    Ok(Some(OrderBy::aggregation(field, sort_aggregation, sort_order)))
+                }
+            }
+        }
+
+        Some((field_name, sort_order)) => {
+            let field = model.fields().find_from_scalar(&field_name)?;
+            let sort_order = extract_sort_order(sort_order)?;
+
             Ok(Some(OrderBy::new(field, sort_order)))
         }
     }
 }
 
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => SortOrder::Ascending,
+        "desc" => SortOrder::Descending,
+        _ => unreachable!(),
+    })
+}
+
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
     let fields: Vec<Field> = match value {
         ParsedInputValue::List(list) => list
@@ -134,6 +238,55 @@ fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelPro
     Ok(ModelProjection::new(fields))
 }
 
+fn extract_window(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Option<Window>> {
+    let map: Option<ParsedInputMap> = value.try_into()?;
+    let mut map = match map {
+        Some(map) => map,
+        None => return Ok(None),
+    };
+
+    let partition_by: Vec<ScalarFieldRef> = match map.remove("partitionBy") {
+        Some(ParsedInputValue::List(list)) => list
+            .into_iter()
+            .map(|element| {
+                let field: ScalarFieldRef = element.try_into()?;
+                Ok(field)
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        Some(value) => vec![value.try_into()?],
+        None => vec![],
+    };
+
+    let order_by = match map.remove("orderBy") {
+        Some(value) => extract_order_by(model, value)?,
+        None => vec![],
+    };
+
+    if order_by.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `window` argument requires a non-empty `orderBy`.".to_owned(),
+        ));
+    }
+
+    let take: Option<i64> = match map.remove("take") {
+        Some(value) => value.try_into()?,
+        None => None,
+    };
+
+    if let Some(take) = take.filter(|take| *take < 1) {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The `take` of a `window` must be positive, found: {}",
+            take
+        )));
+    }
+
+    Ok(Some(Window {
+        partition_by,
+        order_by,
+        take,
+    }))
+}
+
 fn extract_skip(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<i64>> {
     let val: Option<i64> = value.try_into()?;
 
@@ -195,20 +348,27 @@ fn extract_compound_cursor_field(
 }
 
 /// Runs final transformations on the QueryArguments.
-fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
+fn finalize_arguments(mut args: QueryArguments, tie_breakers: Vec<ScalarFieldRef>) -> QueryArguments {
     // Check if the query requires an implicit ordering added to the arguments.
     // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
-    // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
+    // as a cursor needs a direction to page. If no ordering is given, or the given ordering is not unique, we append
+    // the tie breakers (usually the primary identifier) that are not already ordered by, in ascending order.
+    // This makes the ordering total, which also allows the connectors to page cursors on the database level.
     let add_implicit_ordering =
         (args.skip.as_ref().map(|skip| *skip > 0).unwrap_or(false) || args.cursor.is_some() || args.take.is_some())
-            && args.order_by.is_empty();
+            && !args.is_stable_ordering();
 
     if add_implicit_ordering {
-        let primary_identifier = model.primary_identifier();
-        let order_bys = primary_identifier.into_iter().map(|f| match f {
-            Field::Scalar(f) => f.into(),
-            _ => unreachable!(),
-        });
+        let order_bys: Vec<OrderBy> = tie_breakers
+            .into_iter()
+            .filter(|field| {
+                !args
+                    .order_by
+                    .iter()
+                    .any(|o| o.sort_aggregation.is_none() && &o.field == field)
+            })
+            .map(Into::into)
+            .collect();
 
         args.order_by.extend(order_bys);
     }
@@ -1,5 +1,6 @@
 use super::*;
 use crate::{query_document::ParsedField, AggregateRecordsQuery, ReadQuery};
+use connector::GroupBy;
 use prisma_models::ModelRef;
 
 pub fn aggregate(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
@@ -32,7 +33,7 @@ pub fn aggregate(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
         selection_order,
         args,
         selectors,
-        group_by: vec![],
+        group_by: GroupBy::default(),
         having: None,
     }))
 }
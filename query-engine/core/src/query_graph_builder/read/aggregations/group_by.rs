@@ -2,10 +2,15 @@ use std::convert::TryInto;
 
 use super::*;
 use crate::{query_document::ParsedField, AggregateRecordsQuery, ArgumentListLookup, ParsedInputValue, ReadQuery};
-use connector::Filter;
-use prisma_models::{ModelRef, OrderBy, ScalarFieldRef};
-
-pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+use connector::{Filter, GroupBy, QueryArguments};
+use prisma_models::{ModelRef, OrderBy, PrismaValue, ScalarFieldRef};
+
+/// Builds a group by query. If the query has no `take` argument, at most `take_limit` groups are returned.
+pub fn group_by(
+    mut field: ParsedField,
+    model: ModelRef,
+    take_limit: Option<i64>,
+) -> QueryGraphBuilderResult<ReadQuery> {
     let name = field.name;
     let alias = field.alias;
     let model = model;
@@ -17,17 +22,28 @@ pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         None => None,
     };
 
-    let args = extractors::extract_query_args(field.arguments, &model)?;
+    let rollup = match field.arguments.lookup("rollup") {
+        Some(rollup_arg) => {
+            let value: PrismaValue = rollup_arg.value.try_into()?;
+            value == PrismaValue::Boolean(true)
+        }
+        None => false,
+    };
+
+    let args = extractors::extract_grouped_query_args(field.arguments, &model, &group_by, take_limit)?;
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order = collect_selection_tree(&nested_fields);
 
+    // Whether a row is a subtotal is not an aggregation, it is set on the rows of a rollup.
     let selectors: Vec<_> = nested_fields
         .into_iter()
+        .filter(|field| field.parsed_field.name != "_subtotal")
         .map(|field| resolve_query(field, &model))
         .collect::<QueryGraphBuilderResult<_>>()?;
 
     verify_selections(&selectors, &group_by)
         .and_then(|_| verify_orderings(&args.order_by, &group_by))
+        .and_then(|_| verify_cursor(&args, &group_by))
         .and_then(|_| verify_having(having.as_ref(), &selectors))?;
 
     Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
@@ -37,7 +53,7 @@ pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         selection_order,
         args,
         selectors,
-        group_by,
+        group_by: GroupBy::new(group_by, rollup),
         having,
     }))
 }
@@ -67,12 +83,12 @@ fn verify_selections(selectors: &[AggregationSelection], group_by: &[ScalarField
 }
 
 /// Cross checks that the requested order-bys of the request are valid with regard to the requested group bys.
-/// Every ordered field must be present in the group by as well. (Note: We do not yet allow order by aggregate)
+/// Every field ordered by value must be present in the group by as well. Orderings on aggregates are always valid.
 fn verify_orderings(orderings: &[OrderBy], group_by: &[ScalarFieldRef]) -> QueryGraphBuilderResult<()> {
     let mut missing_fields = vec![];
 
     for ordering in orderings {
-        if !group_by.contains(&ordering.field) {
+        if ordering.sort_aggregation.is_none() && !group_by.contains(&ordering.field) {
             missing_fields.push(ordering.field.name.clone());
         }
     }
@@ -87,6 +103,38 @@ fn verify_orderings(orderings: &[OrderBy], group_by: &[ScalarFieldRef]) -> Query
     }
 }
 
+/// Cross checks that the cursor identifies a group: it has to contain exactly the fields of the group by.
+/// Groups are paged by comparing their grouped values, so ordering by aggregates is not possible with a cursor.
+fn verify_cursor(args: &QueryArguments, group_by: &[ScalarFieldRef]) -> QueryGraphBuilderResult<()> {
+    let cursor = match args.cursor {
+        Some(ref cursor) => cursor,
+        None => return Ok(()),
+    };
+
+    let cursor_fields: Vec<ScalarFieldRef> = cursor.fields().collect();
+    let is_complete =
+        cursor_fields.len() == group_by.len() && group_by.iter().all(|field| cursor_fields.contains(field));
+
+    if !is_complete {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The cursor of a groupBy query must contain exactly the fields of the by-arguments of the query: {}",
+            group_by
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    if args.order_by.iter().any(|ordering| ordering.sort_aggregation.is_some()) {
+        return Err(QueryGraphBuilderError::InputError(
+            "Ordering by aggregates is not supported together with a cursor in groupBy queries.".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
 /// Cross checks that every scalar field used in `having` is either an aggregate or contained in the selectors.
 fn verify_having(having: Option<&Filter>, selectors: &[AggregationSelection]) -> QueryGraphBuilderResult<()> {
     if let Some(filter) = having {
@@ -130,6 +178,7 @@ fn collect_scalar_fields(filter: &Filter) -> Vec<&ScalarFieldRef> {
         Filter::Or(inner) => inner.into_iter().flat_map(|f| collect_scalar_fields(f)).collect(),
         Filter::Not(inner) => inner.into_iter().flat_map(|f| collect_scalar_fields(f)).collect(),
         Filter::Scalar(sf) => sf.projection.scalar_fields(),
+        Filter::ScalarList(lf) => vec![&lf.field],
         Filter::Aggregation(_) => vec![], // Aggregations have no effect here.
         _ => unreachable!(),
     }
@@ -6,15 +6,24 @@ mod group_by;
 pub use aggregate::*;
 pub use group_by::*;
 
-use crate::FieldPair;
+use crate::{ArgumentListLookup, FieldPair};
 use connector::AggregationSelection;
 use itertools::Itertools;
-use prisma_models::{ModelRef, ScalarFieldRef};
+use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
 
 /// Resolves the given field as a aggregation query.
 fn resolve_query(mut field: FieldPair, model: &ModelRef) -> QueryGraphBuilderResult<AggregationSelection> {
     let query = match field.parsed_field.name.as_str() {
         "count" => {
+            let distinct = match field.parsed_field.arguments.lookup("distinct") {
+                Some(arg) => {
+                    let value: PrismaValue = arg.value.try_into()?;
+                    value == PrismaValue::Boolean(true)
+                }
+                None => false,
+            };
+
             let nested_fields = field
                 .parsed_field
                 .nested_fields
@@ -33,11 +42,13 @@ fn resolve_query(mut field: FieldPair, model: &ModelRef) -> QueryGraphBuilderRes
                     AggregationSelection::Count {
                         all: true,
                         fields: resolve_fields(model, field),
+                        distinct,
                     }
                 }
                 None => AggregationSelection::Count {
                     all: false,
                     fields: resolve_fields(model, field),
+                    distinct,
                 },
             }
         }
@@ -45,6 +56,26 @@ fn resolve_query(mut field: FieldPair, model: &ModelRef) -> QueryGraphBuilderRes
         "sum" => AggregationSelection::Sum(resolve_fields(model, field)),
         "min" => AggregationSelection::Min(resolve_fields(model, field)),
         "max" => AggregationSelection::Max(resolve_fields(model, field)),
+        "stdDev" => AggregationSelection::StdDev(resolve_fields(model, field)),
+        "variance" => AggregationSelection::Variance(resolve_fields(model, field)),
+        "percentile" => {
+            let percentile: Option<f64> = match field.parsed_field.arguments.lookup("p") {
+                Some(arg) => arg.value.try_into()?,
+                None => None,
+            };
+
+            match percentile {
+                Some(percentile) if (0.0..=1.0).contains(&percentile) => AggregationSelection::Percentile {
+                    fields: resolve_fields(model, field),
+                    percentile,
+                },
+                _ => {
+                    return Err(QueryGraphBuilderError::InputError(
+                        "The percentile `p` must be between 0 and 1.".to_owned(),
+                    ))
+                }
+            }
+        }
         name => AggregationSelection::Field(model.fields().find_from_scalar(name).unwrap()),
     };
 
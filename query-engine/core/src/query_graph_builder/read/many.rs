@@ -2,14 +2,16 @@ use super::*;
 use crate::{query_document::ParsedField, ManyRecordsQuery, ReadQuery};
 use prisma_models::ModelRef;
 
-pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+pub fn find_many(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let relation_load_strategy = utils::extract_relation_load_strategy(&mut field.arguments)?;
     let args = extractors::extract_query_args(field.arguments, &model)?;
     let name = field.name;
     let alias = field.alias;
-    let nested_fields = field.nested_fields.unwrap().fields;
+    let mut nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
-    let nested = utils::collect_nested_queries(nested_fields, &model)?;
+    let relation_counts = utils::collect_relation_counts(&mut nested_fields, &model)?;
+    let nested = utils::collect_nested_queries(nested_fields, &model, relation_load_strategy)?;
     let model = model;
 
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
@@ -23,5 +25,7 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
         selected_fields,
         nested,
         selection_order,
+        relation_counts,
+        relation_load_strategy,
     }))
 }
@@ -4,6 +4,7 @@ mod aggregations;
 mod first;
 mod many;
 mod one;
+mod raw;
 mod related;
 mod utils;
 
@@ -11,6 +12,7 @@ pub use aggregations::*;
 pub use first::*;
 pub use many::*;
 pub use one::*;
+pub use raw::*;
 pub use related::*;
 
 use super::*;
@@ -13,13 +13,15 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         None => None,
     };
 
+    let relation_load_strategy = utils::extract_relation_load_strategy(&mut field.arguments)?;
     let name = field.name;
     let alias = field.alias;
     let model = model;
-    let nested_fields = field.nested_fields.unwrap().fields;
+    let mut nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
-    let nested = utils::collect_nested_queries(nested_fields, &model)?;
+    let relation_counts = utils::collect_relation_counts(&mut nested_fields, &model)?;
+    let nested = utils::collect_nested_queries(nested_fields, &model, relation_load_strategy)?;
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
 
     Ok(ReadQuery::RecordQuery(RecordQuery {
@@ -30,5 +32,7 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         selected_fields,
         nested,
         selection_order,
+        relation_counts,
+        relation_load_strategy,
     }))
 }
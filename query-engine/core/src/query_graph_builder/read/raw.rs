@@ -0,0 +1,26 @@
+use super::*;
+use crate::{query_document::ParsedField, RawRecordsQuery, ReadQuery, RelationLoadStrategy};
+use prisma_models::ModelRef;
+
+/// Builds a read query that maps the result of a raw query onto records of the model.
+/// Result columns are matched to the selected fields by their database names.
+pub fn find_raw(mut field: ParsedField, model: ModelRef, read_only: bool) -> QueryGraphBuilderResult<ReadQuery> {
+    let raw = write::raw_query(&mut field, read_only)?;
+    let name = field.name;
+    let alias = field.alias;
+    let nested_fields = field.nested_fields.unwrap().fields;
+    let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
+    let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
+    let nested = utils::collect_nested_queries(nested_fields, &model, RelationLoadStrategy::default())?;
+    let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
+
+    Ok(ReadQuery::RawRecordsQuery(RawRecordsQuery {
+        name,
+        alias,
+        model,
+        raw,
+        selected_fields,
+        nested,
+        selection_order,
+    }))
+}
@@ -1,19 +1,21 @@
 use super::*;
-use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsQuery};
+use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsQuery, RelationLoadStrategy};
 use prisma_models::{ModelRef, RelationFieldRef};
 
 pub fn find_related(
     field: ParsedField,
     parent: RelationFieldRef,
     model: ModelRef,
+    relation_load_strategy: RelationLoadStrategy,
 ) -> QueryGraphBuilderResult<ReadQuery> {
     let args = extractors::extract_query_args(field.arguments, &model)?;
     let name = field.name;
     let alias = field.alias;
-    let sub_selections = field.nested_fields.unwrap().fields;
+    let mut sub_selections = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&sub_selections);
     let selected_fields = utils::collect_selected_fields(&sub_selections, &model);
-    let nested = utils::collect_nested_queries(sub_selections, &model)?;
+    let relation_counts = utils::collect_relation_counts(&mut sub_selections, &model)?;
+    let nested = utils::collect_nested_queries(sub_selections, &model, relation_load_strategy)?;
     let parent_field = parent;
 
     let selected_fields = utils::merge_relation_selections(selected_fields, Some(parent_field.clone()), &nested);
@@ -27,6 +29,8 @@ pub fn find_related(
         selected_fields,
         nested,
         selection_order,
+        relation_counts,
+        relation_load_strategy,
         parent_projections: None,
     }))
 }
@@ -1,7 +1,10 @@
 use super::*;
-use crate::{FieldPair, ReadQuery};
-use prisma_models::{Field, ModelProjection, ModelRef, RecordProjection, RelationFieldRef};
-use std::sync::Arc;
+use crate::{
+    ArgumentListLookup, FieldPair, ParsedArgument, ParsedInputMap, ReadQuery, RelationCounts, RelationLoadStrategy,
+};
+use connector::RelationCountSelection;
+use prisma_models::{Field, ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef};
+use std::{convert::TryInto, sync::Arc};
 
 pub fn collect_selection_order(from: &[FieldPair]) -> Vec<String> {
     from.iter()
@@ -35,8 +38,21 @@ pub fn collect_selected_fields(from: &[FieldPair], model: &ModelRef) -> ModelPro
     model_id.merge(selected_projection)
 }
 
-pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
+/// Builds the nested read queries of the selected relations. Relation counts are not read with
+/// nested queries, see `collect_relation_counts`, cursors are derived from the primary
+/// identifier of the records and the numbers of a window are selected with the records.
+pub fn collect_nested_queries(
+    from: Vec<FieldPair>,
+    model: &ModelRef,
+    relation_load_strategy: RelationLoadStrategy,
+) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
+        .filter(|pair| {
+            !matches!(
+                pair.parsed_field.name.as_str(),
+                "_count" | "_cursor" | "_rowNumber" | "_rank"
+            )
+        })
         .filter_map(|pair| {
             let model_field = model.fields().find_from_all(&pair.parsed_field.name).unwrap();
             match model_field {
@@ -45,13 +61,70 @@ pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGr
                     let model = rf.related_model();
                     let parent = Arc::clone(&rf);
 
-                    Some(related::find_related(pair.parsed_field, parent, model))
+                    Some(related::find_related(
+                        pair.parsed_field,
+                        parent,
+                        model,
+                        relation_load_strategy,
+                    ))
                 }
             }
         })
         .collect::<QueryGraphBuilderResult<Vec<ReadQuery>>>()
 }
 
+/// Takes the `_count` selection, if any, out of the selected fields and collects its relation counts.
+pub fn collect_relation_counts(from: &mut Vec<FieldPair>, model: &ModelRef) -> QueryGraphBuilderResult<RelationCounts> {
+    let count_field = match from.iter().position(|pair| pair.parsed_field.name == "_count") {
+        Some(index) => from.remove(index).parsed_field,
+        None => return Ok(RelationCounts::default()),
+    };
+
+    let mut nested_fields = count_field.nested_fields.unwrap().fields;
+    let selection_order = collect_selection_order(&nested_fields);
+    let mut selections = Vec::with_capacity(nested_fields.len());
+
+    for pair in nested_fields.iter_mut() {
+        let field = model
+            .fields()
+            .find_from_relation_fields(&pair.parsed_field.name)
+            .unwrap();
+
+        let filter = match pair.parsed_field.arguments.lookup("where") {
+            Some(where_arg) => {
+                let arg: ParsedInputMap = where_arg.value.try_into()?;
+                Some(extractors::extract_filter(arg, &field.related_model())?)
+            }
+            None => None,
+        };
+
+        selections.push(RelationCountSelection { field, filter });
+    }
+
+    Ok(RelationCounts {
+        name: count_field.alias.unwrap_or(count_field.name),
+        selection_order,
+        selections,
+    })
+}
+
+/// Extracts the `relationLoadStrategy` argument, defaulting to the query strategy.
+pub fn extract_relation_load_strategy(
+    arguments: &mut Vec<ParsedArgument>,
+) -> QueryGraphBuilderResult<RelationLoadStrategy> {
+    let value: PrismaValue = match arguments.lookup("relationLoadStrategy") {
+        Some(arg) => arg.value.try_into()?,
+        None => return Ok(RelationLoadStrategy::default()),
+    };
+
+    let strategy = match value {
+        PrismaValue::Enum(s) if s == "join" => RelationLoadStrategy::Join,
+        _ => RelationLoadStrategy::Query,
+    };
+
+    Ok(strategy)
+}
+
 /// Performs a lookahead based on the nested queries and merges fields required
 /// to resolve the nested queries.
 /// A lookback on the parent is also performed to ensure that fields required for
@@ -54,13 +54,28 @@ pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
     data_map: ParsedInputMap,
+) -> QueryGraphBuilderResult<NodeRef> {
+    connect_or_create_record_node(graph, model, data_map, None)
+}
+
+/// Creates the create record node of a `connectOrCreate`, see `ConnectOrCreateRetry`.
+pub fn connect_or_create_record_node(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    data_map: ParsedInputMap,
+    connect_or_create: Option<ConnectOrCreateRetry>,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let create_args = WriteArgsParser::from(&model, data_map)?;
     let mut args = create_args.args;
 
     args.add_datetimes(Arc::clone(&model));
+    args.add_version(Arc::clone(&model));
 
-    let cr = CreateRecord { model, args };
+    let cr = CreateRecord {
+        model,
+        args,
+        connect_or_create,
+    };
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
 
     for (relation_field, data_map) in create_args.nested {
@@ -69,3 +84,26 @@ pub fn create_record_node(
 
     Ok(create_node)
 }
+
+/// Creates a single node creating all records in `data_maps` at once. The
+/// records must not contain nested writes, as no IDs are returned for them.
+pub fn create_many_records_node(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    data_maps: Vec<ParsedInputMap>,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let args = data_maps
+        .into_iter()
+        .map(|data_map| {
+            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+            args.add_datetimes(Arc::clone(&model));
+            args.add_version(Arc::clone(&model));
+
+            Ok(args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let cmr = CreateManyRecords { model, args };
+
+    Ok(graph.create_node(Query::Write(WriteQuery::CreateManyRecords(cmr))))
+}
@@ -2,9 +2,9 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, FilteredQuery, ParsedField,
+    ArgumentListLookup, FilteredQuery, ParsedField, ParsedInputMap,
 };
-use connector::filter::Filter;
+use connector::{filter::Filter, RecordFilter};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 
@@ -12,8 +12,9 @@ use std::{convert::TryInto, sync::Arc};
 pub fn delete_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
-    let where_arg = field.arguments.lookup("where").unwrap();
-    let filter = extract_unique_filter(where_arg.value.try_into()?, &model)?;
+    let mut where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let expected_version = extract_expected_version(&mut where_arg, &model)?;
+    let filter = extract_unique_filter(where_arg, &model)?;
 
     // Prefetch read query for the delete
     let mut read_query = read::find_one(field, Arc::clone(&model))?;
@@ -22,11 +23,12 @@ pub fn delete_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let read_node = graph.create_node(Query::Read(read_query));
     let delete_query = Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
         model: Arc::clone(&model),
-        record_filter: Some(filter.into()),
+        record_filter: Some(RecordFilter::from(filter).with_expected_version(expected_version)),
     }));
 
     let delete_node = graph.create_node(delete_query);
     utils::insert_deletion_checks(graph, &model, &read_node, &delete_node)?;
+    utils::insert_emulated_on_delete(graph, &model, &read_node, &delete_node)?;
 
     graph.create_edge(
         &read_node,
@@ -62,18 +64,25 @@ pub fn delete_many_records(
         None => Filter::empty(),
     };
 
-    let model_id = model.primary_identifier();
-    let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
-    let record_filter = filter.into();
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
-        record_filter,
+        record_filter: filter.clone().into(),
     });
 
+    // Without checks, the records are deleted by the filter without reading their IDs first.
+    if !utils::deletion_needs_checks(&model) {
+        graph.create_node(Query::Write(delete_many));
+        return Ok(());
+    }
+
+    let model_id = model.primary_identifier();
+    let read_query = utils::read_ids_infallible(model.clone(), model_id, filter);
+
     let read_query_node = graph.create_node(read_query);
     let delete_many_node = graph.create_node(Query::Write(delete_many));
 
     utils::insert_deletion_checks(graph, &model, &read_query_node, &delete_many_node)?;
+    utils::insert_emulated_on_delete(graph, &model, &read_query_node, &delete_many_node)?;
     graph.create_edge(
         &read_query_node,
         &delete_many_node,
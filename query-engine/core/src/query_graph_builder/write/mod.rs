@@ -15,6 +15,7 @@ use super::*;
 // Expose top level write operation builder functions.
 pub use create::create_record;
 pub use delete::{delete_many_records, delete_record};
+pub(crate) use raw::raw_query;
 pub use raw::{execute_raw, query_raw};
 pub use update::{update_many_records, update_record};
 pub use upsert::upsert_record;
@@ -5,7 +5,7 @@ use crate::{
     ParsedInputMap, ParsedInputValue,
 };
 use connector::{Filter, IdFilter};
-use prisma_models::{ModelRef, RelationFieldRef};
+use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 /// Handles nested connect or create cases.
@@ -81,6 +81,7 @@ fn handle_many_to_many(
         let create_arg = value.remove("create").unwrap();
         let create_map: ParsedInputMap = create_arg.try_into()?;
 
+        let retry = connect_or_create_retry(&where_map, child_model, None)?;
         let filter = extract_unique_filter(where_map, &child_model)?;
         let read_node = graph.create_node(utils::read_ids_infallible(
             child_model.clone(),
@@ -88,7 +89,7 @@ fn handle_many_to_many(
             filter,
         ));
 
-        let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+        let create_node = create::connect_or_create_record_node(graph, Arc::clone(child_model), create_map, retry)?;
         let if_node = graph.create_node(Flow::default_if());
 
         let connect_exists_node =
@@ -120,6 +121,22 @@ fn handle_many_to_many(
     Ok(())
 }
 
+/// Lets the create of a one-to-many or many-to-many `connectOrCreate` connect the existing record
+/// instead if a concurrent request created it in between, see `ConnectOrCreateRetry`. One-to-one
+/// relations need checks on the existing record that the interpreter can't make, so they fail.
+fn connect_or_create_retry(
+    where_map: &ParsedInputMap,
+    child_model: &ModelRef,
+    linking_fields: Option<ModelProjection>,
+) -> QueryGraphBuilderResult<Option<ConnectOrCreateRetry>> {
+    let selector = extract_unique_selector(where_map, child_model)?;
+
+    Ok(selector.map(|selector| ConnectOrCreateRetry {
+        selector,
+        linking_fields,
+    }))
+}
+
 /// Dispatcher for one-to-many relations.
 fn handle_one_to_many(
     graph: &mut QueryGraph,
@@ -223,6 +240,7 @@ fn one_to_many_inlined_child(
         let create_arg = value.remove("create").unwrap();
         let create_map: ParsedInputMap = create_arg.try_into()?;
 
+        let retry = connect_or_create_retry(&where_map, child_model, Some(child_link.clone()))?;
         let filter = extract_unique_filter(where_map, &child_model)?;
         let read_node = graph.create_node(utils::read_ids_infallible(
             child_model.clone(),
@@ -232,7 +250,7 @@ fn one_to_many_inlined_child(
 
         let if_node = graph.create_node(Flow::default_if());
         let update_child_node = utils::update_records_node_placeholder(graph, filter, Arc::clone(child_model));
-        let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+        let create_node = create::connect_or_create_record_node(graph, Arc::clone(child_model), create_map, retry)?;
 
         graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
         graph.create_edge(&if_node, &update_child_node, QueryGraphDependency::Then)?;
@@ -356,6 +374,7 @@ fn one_to_many_inlined_parent(
     let create_arg = value.remove("create").unwrap();
     let create_map: ParsedInputMap = create_arg.try_into()?;
 
+    let retry = connect_or_create_retry(&where_map, child_model, None)?;
     let filter = extract_unique_filter(where_map, &child_model)?;
     let read_node = graph.create_node(utils::read_ids_infallible(
         child_model.clone(),
@@ -367,7 +386,7 @@ fn one_to_many_inlined_parent(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::default_if());
-    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+    let create_node = create::connect_or_create_record_node(graph, Arc::clone(child_model), create_map, retry)?;
     let return_existing = graph.create_node(Flow::Return(None));
     let return_create = graph.create_node(Flow::Return(None));
 
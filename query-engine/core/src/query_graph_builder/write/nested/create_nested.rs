@@ -2,10 +2,10 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputValue,
+    ParsedInputMap, ParsedInputValue,
 };
 use connector::{Filter, IdFilter};
-use prisma_models::{ModelRef, RelationFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 /// Handles nested create cases.
@@ -19,11 +19,27 @@ pub fn nested_create(
     child_model: &ModelRef,
 ) -> QueryGraphBuilderResult<()> {
     let relation = parent_relation_field.relation();
+    let data_maps = utils::coerce_vec(value)
+        .into_iter()
+        .map(|value| Ok(value.try_into()?))
+        .collect::<QueryGraphBuilderResult<Vec<ParsedInputMap>>>()?;
+
+    // Sibling creates on the child side of a one-to-many relation only need the parent ID,
+    // which is the same for all of them. Without nested writes of their own, they can be batched.
+    if relation.is_one_to_many()
+        && !parent_relation_field.is_inlined_on_enclosing_model()
+        && data_maps.len() > 1
+        && data_maps
+            .iter()
+            .all(|data_map| !has_nested_writes(child_model, data_map))
+    {
+        return handle_one_to_many_batched(graph, parent_node, parent_relation_field, child_model, data_maps);
+    }
 
     // Build all create nodes upfront.
-    let creates: Vec<NodeRef> = utils::coerce_vec(value)
+    let creates: Vec<NodeRef> = data_maps
         .into_iter()
-        .map(|value| create::create_record_node(graph, Arc::clone(child_model), value.try_into()?))
+        .map(|data_map| create::create_record_node(graph, Arc::clone(child_model), data_map))
         .collect::<QueryGraphBuilderResult<Vec<NodeRef>>>()?;
 
     if relation.is_many_to_many() {
@@ -184,6 +200,62 @@ fn handle_one_to_many(
     Ok(())
 }
 
+/// Handles a one-to-many nested create of multiple children with the relation inlined on the child,
+/// where none of the children have nested writes of their own.
+/// All children are created by a single node, and the parent ID is injected into each of them.
+///
+/// Example graph:
+/// ```text
+///                 ┌ ─ ─ ─ ─ ─ ─
+///        ┌────────    Parent   │─ ─ ─ ─ ─
+///        │        └ ─ ─ ─ ─ ─ ─          │
+///        │
+///        │                               │
+///        │
+///        ▼                               ▼
+/// ┌─────────────────────┐         ┌ ─ ─ ─ ─ ─ ─
+/// │ Create Many Children│             Result   │
+/// └─────────────────────┘         └ ─ ─ ─ ─ ─ ─
+/// ```
+fn handle_one_to_many_batched(
+    graph: &mut QueryGraph,
+    parent_node: NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    child_model: &ModelRef,
+    data_maps: Vec<ParsedInputMap>,
+) -> QueryGraphBuilderResult<()> {
+    let create_many_node = create::create_many_records_node(graph, Arc::clone(child_model), data_maps)?;
+    let parent_link = parent_relation_field.linking_fields();
+    let child_link = parent_relation_field.related_field().linking_fields();
+
+    graph.create_edge(
+        &parent_node,
+        &create_many_node,
+        QueryGraphDependency::ParentProjection(parent_link, Box::new(move |mut create_many_node, mut parent_links| {
+            let parent_link = match parent_links.pop() {
+                Some(link) => Ok(link),
+                None => Err(QueryGraphBuilderError::AssertionError("[Query Graph] Expected a valid parent ID to be present for a nested create on a one-to-many relation.".to_string())),
+            }?;
+
+            if let Node::Query(Query::Write(ref mut wq)) = create_many_node {
+                wq.inject_projection_into_args(child_link.assimilate(parent_link)?);
+            }
+
+            Ok(create_many_node)
+        })),
+    )?;
+
+    Ok(())
+}
+
+/// Whether creating a record from `data_map` involves nested writes on relation fields.
+fn has_nested_writes(model: &ModelRef, data_map: &ParsedInputMap) -> bool {
+    data_map.iter().any(|(name, value)| {
+        matches!(model.fields().find_from_all(name), Ok(Field::Relation(_)))
+            && !matches!(value, ParsedInputValue::Single(PrismaValue::Null))
+    })
+}
+
 /// Handles a one-to-one nested create.
 /// Most complex case as there are edge cases where we need to make sure
 /// that we don't violate relation requirements.
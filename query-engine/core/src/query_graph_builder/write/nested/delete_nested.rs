@@ -50,6 +50,7 @@ pub fn nested_delete(
             utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, or_filter)?;
 
         utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+        utils::insert_emulated_on_delete(graph, child_model, &find_child_records_node, &delete_many_node)?;
 
         let relation_name = parent_relation_field.relation().name.clone();
         let parent_name = parent_relation_field.model().name.clone();
@@ -91,6 +92,7 @@ pub fn nested_delete(
             })));
 
             utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_record_node)?;
+            utils::insert_emulated_on_delete(graph, child_model, &find_child_records_node, &delete_record_node)?;
 
             graph.create_edge(
                  &find_child_records_node,
@@ -137,6 +139,7 @@ pub fn nested_delete_many(
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
         utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+        utils::insert_emulated_on_delete(graph, child_model, &find_child_records_node, &delete_many_node)?;
 
         graph.create_edge(
             &find_child_records_node,
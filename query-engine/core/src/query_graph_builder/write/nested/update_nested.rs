@@ -99,7 +99,8 @@ pub fn nested_update_many(
         let child_model_identifier = parent_relation_field.related_model().primary_identifier();
 
         let filter = extract_filter(where_map, child_model)?;
-        let update_args = WriteArgsParser::from(&child_model, data_map)?;
+        let mut args = WriteArgsParser::from(&child_model, data_map)?.args;
+        args.increment_version(Arc::clone(&child_model));
 
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
@@ -107,7 +108,7 @@ pub fn nested_update_many(
         let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
-            args: update_args.args,
+            args,
         });
 
         let update_many_node = graph.create_node(Query::Write(update_many));
@@ -3,21 +3,34 @@ use crate::{query_ast::*, query_graph::QueryGraph, ArgumentListLookup, ParsedFie
 use prisma_value::PrismaValue;
 use std::convert::TryInto;
 
-pub fn execute_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let raw_query = Query::Write(WriteQuery::ExecuteRaw(raw_query(field)?));
+/// Statements a read-only raw query may start with.
+const READ_ONLY_STATEMENTS: &[&str] = &[
+    "SELECT", "WITH", "VALUES", "TABLE", "SHOW", "EXPLAIN", "DESCRIBE", "DESC",
+];
+
+/// Keywords that can make an otherwise read-only statement write, e.g. a data-modifying CTE,
+/// `SELECT ... INTO` or `EXPLAIN ANALYZE DELETE ...`.
+const WRITE_KEYWORDS: &[&str] = &[
+    "INSERT", "UPDATE", "DELETE", "MERGE", "UPSERT", "INTO", "CREATE", "DROP", "ALTER", "TRUNCATE", "EXECUTE",
+    "REFRESH",
+];
+
+pub fn execute_raw(graph: &mut QueryGraph, mut field: ParsedField, read_only: bool) -> QueryGraphBuilderResult<()> {
+    let raw_query = Query::Write(WriteQuery::ExecuteRaw(raw_query(&mut field, read_only)?));
 
     graph.create_node(raw_query);
     Ok(())
 }
 
-pub fn query_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let raw_query = Query::Write(WriteQuery::QueryRaw(raw_query(field)?));
+pub fn query_raw(graph: &mut QueryGraph, mut field: ParsedField, read_only: bool) -> QueryGraphBuilderResult<()> {
+    let raw_query = Query::Write(WriteQuery::QueryRaw(raw_query(&mut field, read_only)?));
 
     graph.create_node(raw_query);
     Ok(())
 }
 
-fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
+/// Extracts the query and parameters of a raw query field, rejecting writes in read-only mode.
+pub(crate) fn raw_query(field: &mut ParsedField, read_only: bool) -> QueryGraphBuilderResult<RawQuery> {
     let query_arg = field.arguments.lookup("query").unwrap().value;
     let parameters_arg = field.arguments.lookup("parameters");
 
@@ -30,8 +43,127 @@ fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
         None => vec![],
     };
 
-    Ok(RawQuery {
-        query: query_value.into_string().unwrap(),
-        parameters,
-    })
+    let query = query_value.into_string().unwrap();
+
+    if read_only && !is_read_only_statement(&query) {
+        return Err(QueryGraphBuilderError::InputError(
+            "The query engine is in read-only mode and only accepts raw queries that don't write.".into(),
+        ));
+    }
+
+    Ok(RawQuery { query, parameters })
+}
+
+/// Conservatively classifies a raw SQL string as a single statement that doesn't write.
+///
+/// The check is lexical: the statement must start with a read-only keyword, must not contain any
+/// keyword from `WRITE_KEYWORDS` outside of string literals and quoted identifiers, and must not
+/// be followed by another statement. Comments are not skipped when looking for keywords, and
+/// anything the lexer can't reliably delimit across SQL dialects (backslash escapes, dollar
+/// quoting, nested or executable comments) is rejected. Some read-only queries are refused as a
+/// result. Functions with side effects (e.g. `nextval`) can't be detected lexically, so read-only
+/// deployments should still use a read-only database role or replica.
+fn is_read_only_statement(sql: &str) -> bool {
+    let mut statement: Option<String> = None;
+    let mut words: Vec<String> = Vec::new();
+    let mut chars = sql.chars().peekable();
+    let mut in_comment = None;
+
+    while let Some(c) = chars.next() {
+        match (in_comment, c) {
+            // Line and block comments only stop quotes from opening; their words are still checked.
+            (Some('-'), '\n') => in_comment = None,
+            (Some('*'), '*') if chars.peek() == Some(&'/') => {
+                chars.next();
+                in_comment = None;
+            }
+            // Nested block comments (Postgres) would end after the first `*/` here.
+            (Some('*'), '/') if chars.peek() == Some(&'*') => return false,
+            (Some(_), c) if c.is_ascii_alphabetic() || c == '_' => words.push(read_word(c, &mut chars)),
+            (Some(_), _) => (),
+            (None, '-') if chars.peek() == Some(&'-') => in_comment = Some('-'),
+            (None, '#') => in_comment = Some('-'),
+            (None, '/') if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                // MySQL executes the contents of `/*! ... */` comments.
+                if chars.peek() == Some(&'!') {
+                    return false;
+                }
+
+                in_comment = Some('*');
+            }
+            (None, quote @ '\'') | (None, quote @ '"') | (None, quote @ '`') => {
+                if !skip_quoted(quote, &mut chars) {
+                    return false;
+                }
+            }
+            (None, ';') => {
+                if chars.any(|c| !c.is_whitespace()) {
+                    return false;
+                }
+            }
+            (None, c) if c.is_ascii_alphabetic() || c == '_' => {
+                let word = read_word(c, &mut chars);
+                statement.get_or_insert_with(|| word.clone());
+                words.push(word);
+            }
+            // Dollar-quoted strings (Postgres) can contain unescaped quotes.
+            (None, '$') if !chars.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) => return false,
+            (None, c) if c.is_ascii_digit() || c == '$' => {
+                // Numbers and positional parameters are not keywords.
+                while chars
+                    .peek()
+                    .map(|c| c.is_ascii_alphanumeric() || *c == '_')
+                    .unwrap_or(false)
+                {
+                    chars.next();
+                }
+            }
+            (None, _) => (),
+        }
+    }
+
+    match statement {
+        Some(statement) if READ_ONLY_STATEMENTS.contains(&statement.as_str()) => {
+            !words.iter().any(|word| WRITE_KEYWORDS.contains(&word.as_str()))
+        }
+        _ => false,
+    }
+}
+
+fn read_word(first: char, chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut word = first.to_ascii_uppercase().to_string();
+
+    while let Some(c) = chars.peek().copied() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+            word.push(c.to_ascii_uppercase());
+            chars.next();
+        } else {
+            break;
+        }
+    }
+
+    word
+}
+
+/// Skips to the end of a quoted string or identifier. Returns false if it's unterminated or
+/// contains a backslash, as dialects disagree on whether it escapes the closing quote.
+fn skip_quoted(quote: char, chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> bool {
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            return false;
+        }
+
+        if c == quote {
+            // A doubled quote is an escaped quote.
+            if chars.peek() == Some(&quote) {
+                chars.next();
+            } else {
+                return true;
+            }
+        }
+    }
+
+    false
 }
@@ -5,21 +5,23 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputMap,
 };
-use connector::{Filter, IdFilter};
+use connector::{Filter, IdFilter, RecordFilter};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
 pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     // "where"
-    let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let mut where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let expected_version = extract_expected_version(&mut where_arg, &model)?;
     let filter = extract_unique_filter(where_arg, &model)?;
+    let record_filter = RecordFilter::from(filter).with_expected_version(expected_version);
 
     // "data"
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
-    let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
+    let update_node = update_record_node(graph, record_filter, Arc::clone(&model), data_map)?;
 
     let read_query = read::find_one(field, model.clone())?;
     let read_node = graph.create_node(Query::Read(read_query));
@@ -69,6 +71,7 @@ pub fn update_many_records(
 
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
     let record_filter = filter.into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
@@ -89,7 +92,7 @@ pub fn update_record_node<T>(
     data_map: ParsedInputMap,
 ) -> QueryGraphBuilderResult<NodeRef>
 where
-    T: Into<Filter>,
+    T: Into<RecordFilter>,
 {
     graph.flag_transactional();
 
@@ -97,8 +100,8 @@ where
     let mut args = update_args.args;
 
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
-    let filter = filter.into();
     let record_filter = filter.into();
     let ur = UpdateRecord {
         model,
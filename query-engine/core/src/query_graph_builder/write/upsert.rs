@@ -4,26 +4,54 @@ use crate::{
     query_graph::{Flow, Node, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputMap,
 };
-use connector::IdFilter;
+use connector::{IdFilter, WriteExpression};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
-
-pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
+use write_args_parser::*;
+
+/// Creates an upsert record query and adds it to the query graph. With `native_upsert`, qualifying
+/// upserts are a single `UpsertRecord` write (see `native_upsert_query`). Otherwise, the record is
+/// read first, and then either updated or created.
+pub fn upsert_record(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+    native_upsert: bool,
+) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
     let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let create_map: ParsedInputMap = field.arguments.lookup("create").unwrap().value.try_into()?;
+    let update_map: ParsedInputMap = field.arguments.lookup("update").unwrap().value.try_into()?;
+
+    if native_upsert {
+        if let Some(upsert) = native_upsert_query(&model, &where_arg, &create_map, &update_map)? {
+            let filter = upsert.selector.clone().filter();
+            let upsert_node = graph.create_node(Query::Write(WriteQuery::UpsertRecord(upsert)));
+
+            // The selector still identifies the record afterwards, as the update must not change it.
+            let mut read_query = read::find_one(field, Arc::clone(&model))?;
+            if let ReadQuery::RecordQuery(ref mut rq) = read_query {
+                rq.add_filter(filter);
+            }
+
+            let read_node = graph.create_node(Query::Read(read_query));
+
+            graph.add_result_node(&read_node);
+            graph.create_edge(&upsert_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
+
+            return Ok(());
+        }
+    }
 
     let filter = extract_unique_filter(where_arg, &model)?;
     let model_id = model.primary_identifier();
 
-    let create_argument = field.arguments.lookup("create").unwrap();
-    let update_argument = field.arguments.lookup("update").unwrap();
-
     let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
     let read_parent_records_node = graph.create_node(read_parent_records);
 
-    let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let create_node = create::create_record_node(graph, Arc::clone(&model), create_map)?;
+    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_map)?;
 
     let read_query = read::find_one(field, Arc::clone(&model))?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -98,3 +126,58 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     Ok(())
 }
+
+/// The upsert as a single write, if it qualifies: the `where` argument must be a single unique
+/// criterion whose values the create data repeats, the update data must leave these fields
+/// unchanged, and neither may contain nested writes. Updates cascaded by emulated referential
+/// actions need the previous values, so they are not pushed down either.
+fn native_upsert_query(
+    model: &ModelRef,
+    where_map: &ParsedInputMap,
+    create_map: &ParsedInputMap,
+    update_map: &ParsedInputMap,
+) -> QueryGraphBuilderResult<Option<UpsertRecord>> {
+    if model.internal_data_model().emulates_referential_actions() {
+        return Ok(None);
+    }
+
+    let selector = match extract_unique_selector(where_map, model)? {
+        Some(selector) => selector,
+        None => return Ok(None),
+    };
+
+    let create_args = WriteArgsParser::from(model, create_map.clone())?;
+    let update_args = WriteArgsParser::from(model, update_map.clone())?;
+
+    if !create_args.nested.is_empty() || !update_args.nested.is_empty() {
+        return Ok(None);
+    }
+
+    let qualifies = selector.pairs.iter().all(|(field, value)| {
+        let repeated = matches!(
+            create_args.args.get_field_value(field.db_name()),
+            Some(WriteExpression::Value(create_value)) if create_value == value
+        );
+
+        repeated && !update_args.args.has_arg_for(field.db_name())
+    });
+
+    if !qualifies {
+        return Ok(None);
+    }
+
+    let mut create = create_args.args;
+    create.add_datetimes(Arc::clone(model));
+    create.add_version(Arc::clone(model));
+
+    let mut update = update_args.args;
+    update.update_datetimes(Arc::clone(model));
+    update.increment_version(Arc::clone(model));
+
+    Ok(Some(UpsertRecord {
+        model: Arc::clone(model),
+        selector,
+        create,
+        update,
+    }))
+}
@@ -1,9 +1,9 @@
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+    FilteredQuery, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, WriteArgs};
+use connector::{Filter, IdFilter, WriteArgs};
 use itertools::Itertools;
 use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
 use std::sync::Arc;
@@ -41,6 +41,8 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: RelationCounts::default(),
+        relation_load_strategy: RelationLoadStrategy::default(),
     });
 
     Query::Read(read_query)
@@ -114,6 +116,8 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: RelationCounts::default(),
+        relation_load_strategy: RelationLoadStrategy::default(),
     })));
 
     graph.create_edge(
@@ -145,6 +149,7 @@ where
 {
     let mut args = WriteArgs::new();
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
     let filter = filter.into();
     let record_filter = filter.into();
@@ -354,3 +359,88 @@ pub fn insert_deletion_checks(
 
     Ok(())
 }
+
+/// Whether deleting records of `model` needs the nodes inserted by `insert_deletion_checks` and
+/// `insert_emulated_on_delete`, which depend on the identifiers of the deleted records.
+pub fn deletion_needs_checks(model: &ModelRef) -> bool {
+    let internal_model = model.internal_data_model();
+
+    !internal_model.fields_requiring_model(model).is_empty()
+        || (internal_model.emulates_referential_actions()
+            && !internal_model.fields_nullified_by_model(model).is_empty())
+}
+
+/// Emulates the referential actions of relations pointing to the given `model` if the database has
+/// no foreign keys to enforce them (`referentialIntegrity = "prisma"`). Complements `insert_deletion_checks`,
+/// which already prevents deleting records that are still required by other records.
+///
+/// For all optional, non-list relations pointing to `model` with the foreign key on the other model (OM),
+/// the connected OM records are disconnected by setting their foreign keys to null before `child_node`
+/// (the delete) runs. Rows of many-to-many relation tables are removed by the connector as part of the delete.
+///
+/// Expects `parent_node` to return one or more IDs (for records of `model`) to be deleted.
+///
+/// ```text
+///    ┌────────────────────┐
+///    │ Find Record IDs to │
+/// ┌──│       Delete       │
+/// │  └────────────────────┘
+/// │             │
+/// │             ▼
+/// │  ┌────────────────────┐
+/// │  │Find Connected Model│
+/// │  │         A          │
+/// │  └────────────────────┘
+/// │             │
+/// │             ▼
+/// │  ┌────────────────────┐
+/// │  │ Set Foreign Key of │
+/// │  │   A to null        │
+/// │  └────────────────────┘
+/// │             │
+/// │             ▼
+/// │  ┌────────────────────┐
+/// └─▶│       Delete       │
+///    └────────────────────┘
+/// ```
+pub fn insert_emulated_on_delete(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let internal_model = model.internal_data_model();
+
+    if !internal_model.emulates_referential_actions() {
+        return Ok(());
+    }
+
+    for rf in internal_model.fields_nullified_by_model(model) {
+        let relation_field = rf.related_field();
+        let child_model_identifier = rf.model().primary_identifier();
+        let null_record_id = rf.linking_fields().empty_record_projection();
+
+        let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, Filter::empty())?;
+        let update_node = update_records_node_placeholder(graph, Filter::empty(), rf.model());
+
+        graph.create_edge(
+            &read_node,
+            &update_node,
+            QueryGraphDependency::ParentProjection(
+                child_model_identifier,
+                Box::new(move |mut update_node, child_ids| {
+                    if let Node::Query(Query::Write(ref mut wq @ WriteQuery::UpdateManyRecords(_))) = update_node {
+                        wq.set_filter(child_ids.filter());
+                        wq.inject_projection_into_args(null_record_id);
+                    }
+
+                    Ok(update_node)
+                }),
+            ),
+        )?;
+
+        graph.create_edge(&update_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    Ok(())
+}
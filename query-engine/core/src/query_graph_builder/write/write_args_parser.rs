@@ -1,7 +1,7 @@
 use super::*;
 use crate::query_document::{ParsedInputMap, ParsedInputValue};
 use connector::{WriteArgs, WriteExpression};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{Field, JsonNullValue, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef, TypeIdentifier};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -32,7 +32,7 @@ impl WriteArgsParser {
 
                     Field::Scalar(sf) => {
                         let expr: WriteExpression = match v {
-                            ParsedInputValue::Single(v) => v.into(),
+                            ParsedInputValue::Single(v) => json_null_to_value(&sf, v).into(),
                             ParsedInputValue::Map(map) => {
                                 let (operation, value) = map.into_iter().next().unwrap();
                                 let value: PrismaValue = value.try_into()?;
@@ -63,3 +63,17 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Json fields take the `DbNull` and `JsonNull` placeholders, which stand for a database NULL and
+/// a JSON `null` respectively.
+fn json_null_to_value(field: &ScalarFieldRef, value: PrismaValue) -> PrismaValue {
+    if field.type_identifier != TypeIdentifier::Json {
+        return value;
+    }
+
+    match JsonNullValue::from_value(&value) {
+        Some(JsonNullValue::DbNull) => PrismaValue::Null,
+        Some(JsonNullValue::JsonNull) => PrismaValue::json_null(),
+        _ => value,
+    }
+}
@@ -1,10 +1,12 @@
 use super::*;
 use crate::{
+    encode_cursor,
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
     CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, RecordAggregations, RecordSelection,
+    RelationCounts,
 };
-use bigdecimal::ToPrimitive;
-use connector::AggregationResult;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use connector::{AggregationResult, Window};
 use indexmap::IndexMap;
 use prisma_models::{PrismaValue, RecordProjection};
 use std::{borrow::Borrow, collections::HashMap};
@@ -71,6 +73,9 @@ fn serialize_aggregations(
     for row in record_aggregations.results {
         let mut flattened = HashMap::with_capacity(ordering.len());
 
+        // Only the rows of a rollup are marked as subtotals by the connector.
+        flattened.insert("_subtotal".to_owned(), Item::Value(PrismaValue::Boolean(false)));
+
         for result in row {
             match result {
                 AggregationResult::Field(field, value) => {
@@ -111,6 +116,37 @@ fn serialize_aggregations(
                         serialize_scalar(&output_field, coerce_non_numeric(value, &output_field.field_type))?,
                     );
                 }
+
+                AggregationResult::StdDev(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, "stdDev", &field.name);
+                    flattened.insert(
+                        format!("stdDev_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::Variance(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, "variance", &field.name);
+                    flattened.insert(
+                        format!("variance_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::Percentile(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, "percentile", &field.name);
+                    flattened.insert(
+                        format!("percentile_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::Subtotal(subtotal) => {
+                    flattened.insert("_subtotal".to_owned(), Item::Value(PrismaValue::Boolean(subtotal)));
+                }
             }
         }
 
@@ -277,6 +313,28 @@ fn serialize_objects(
         .filter_map(|f| model.map_scalar_db_field_name(f).map(|x| x.name.clone()))
         .collect();
 
+    // Relation counts are selected after the scalar fields, under their own aliases.
+    let relation_counts = result.relation_counts;
+    let count_positions: Vec<usize> = relation_counts
+        .selections
+        .iter()
+        .map(|selection| {
+            let alias = selection.db_alias();
+            scalar_db_field_names.iter().position(|name| name == &alias).unwrap()
+        })
+        .collect();
+
+    // The numbers of the window of a find many query are selected last, under their own names.
+    // Without a window, they are null.
+    let window_positions: Vec<(&str, Option<usize>)> = [Window::ROW_NUMBER, Window::RANK]
+        .iter()
+        .filter(|name| result.fields.iter().any(|field_name| field_name == *name))
+        .map(|name| (*name, scalar_db_field_names.iter().position(|db_name| db_name == name)))
+        .collect();
+
+    // Cursors are derived from the record ids.
+    let select_cursor = result.fields.iter().any(|field_name| field_name == "_cursor");
+
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records.into_iter() {
@@ -290,6 +348,26 @@ fn serialize_objects(
         let values = record.values;
         let mut object = HashMap::with_capacity(values.len());
 
+        if result.fields.contains(&relation_counts.name) {
+            let counts = count_positions
+                .iter()
+                .map(|position| values[*position].clone())
+                .collect();
+            let item = serialize_relation_counts(&relation_counts, counts, &typ)?;
+
+            object.insert(relation_counts.name.clone(), item);
+        }
+
+        if select_cursor {
+            let cursor = encode_cursor(record_id.as_ref().unwrap());
+            object.insert("_cursor".to_owned(), Item::Value(PrismaValue::String(cursor)));
+        }
+
+        for (name, position) in window_positions.iter() {
+            let value = position.map_or(PrismaValue::Null, |position| values[position].clone());
+            object.insert((*name).to_owned(), Item::Value(value));
+        }
+
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
             let field = typ.find_field(scalar_field_name).unwrap();
 
@@ -324,6 +402,32 @@ fn serialize_objects(
     Ok(object_mapping)
 }
 
+/// Serializes the `_count` object of a record. Unwraps are safe due to query validation.
+fn serialize_relation_counts(
+    relation_counts: &RelationCounts,
+    counts: Vec<PrismaValue>,
+    enclosing_type: &ObjectTypeStrongRef,
+) -> crate::Result<Item> {
+    let count_type = enclosing_type
+        .find_field("_count")
+        .and_then(|field| field.field_type.as_object_type())
+        .unwrap();
+
+    let mut map = Map::with_capacity(counts.len());
+
+    for ((selection, name), count) in relation_counts
+        .selections
+        .iter()
+        .zip(relation_counts.selection_order.iter())
+        .zip(counts)
+    {
+        let field = count_type.find_field(&selection.field.name).unwrap();
+        map.insert(name.to_owned(), serialize_scalar(&field, count)?);
+    }
+
+    Ok(Item::Map(map))
+}
+
 /// Unwraps are safe due to query validation.
 fn write_nested_items(
     record_id: &Option<RecordProjection>,
@@ -428,7 +532,7 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::Json, PrismaValue::String(s)) => PrismaValue::Json(s),
         (ScalarType::Json, PrismaValue::Json(s)) => PrismaValue::Json(s),
 
-        (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f.to_i64().unwrap()),
+        (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(float_to_i64(&f)?),
         (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
 
         (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
@@ -438,16 +542,18 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
 
         (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::String(i.to_string()),
         (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::String(f.to_string()),
+        (ScalarType::Decimal, PrismaValue::BigInt(i)) => PrismaValue::String(i.to_string()),
 
         (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
         (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
-        (ScalarType::BigInt, PrismaValue::Float(f)) => PrismaValue::BigInt(f.to_i64().unwrap()),
+        (ScalarType::BigInt, PrismaValue::Float(f)) => float_to_bigint(&f)?,
 
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
         (ScalarType::Int, PrismaValue::Boolean(b)) => PrismaValue::Int(b as i64),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
         (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+        (ScalarType::Interval, PrismaValue::Interval(i)) => PrismaValue::Interval(i),
 
         (ScalarType::Xml, PrismaValue::Xml(b)) => PrismaValue::Xml(b),
         (ScalarType::String, PrismaValue::Xml(s)) => PrismaValue::String(s),
@@ -463,6 +569,21 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
     Ok(item_value)
 }
 
+/// Fails for values that are out of range, e.g. sums exceeding 64 bits, instead of truncating them.
+fn float_to_i64(f: &BigDecimal) -> Result<i64, CoreError> {
+    f.to_i64()
+        .ok_or_else(|| CoreError::SerializationError(format!("Value '{}' does not fit into a 64-bit integer.", f)))
+}
+
+/// Values of unsigned 64-bit columns beyond the signed range are serialized as exact strings, like
+/// all other BigInts.
+fn float_to_bigint(f: &BigDecimal) -> Result<PrismaValue, CoreError> {
+    match f.to_u64() {
+        Some(u) if u > i64::MAX as u64 && BigDecimal::from(u) == *f => Ok(PrismaValue::String(u.to_string())),
+        _ => float_to_i64(f).map(PrismaValue::BigInt),
+    }
+}
+
 fn convert_enum(value: PrismaValue, dbt: &DatabaseEnumType) -> Result<Item, CoreError> {
     match value {
         PrismaValue::String(s) | PrismaValue::Enum(s) => match dbt.map_output_value(&s) {
@@ -10,6 +10,7 @@
 //! and a restructure might be necessary (good example is the default value handling sprinkled all over the place).
 mod internal;
 mod ir_serializer;
+mod numeric_encoding;
 mod response;
 
 use crate::QueryValue;
@@ -19,6 +20,7 @@ use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 use std::{fmt, sync::Arc};
 
 pub use ir_serializer::*;
+pub use numeric_encoding::*;
 pub use response::*;
 
 /// A `key -> value` map to an IR item
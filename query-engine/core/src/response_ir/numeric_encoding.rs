@@ -0,0 +1,87 @@
+use super::*;
+use crate::schema::{IntoArc, OutputType, ScalarType};
+
+/// How Decimal and BigInt values are encoded in responses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericEncoding {
+    /// Decimals and BigInts are plain JSON strings, indistinguishable from other strings.
+    Legacy,
+
+    /// Decimals and BigInts are exact strings tagged with their type, e.g.
+    /// `{ "prisma__type": "decimal", "prisma__value": "1.10" }`.
+    Tagged,
+}
+
+impl Default for NumericEncoding {
+    fn default() -> Self {
+        Self::Legacy
+    }
+}
+
+impl NumericEncoding {
+    /// Re-encodes the Decimal and BigInt values of an item of the given output type.
+    pub fn encode(self, item: Item, typ: &OutputType) -> Item {
+        match self {
+            Self::Legacy => item,
+            Self::Tagged => tag_numerics(item, typ),
+        }
+    }
+}
+
+fn tag_numerics(item: Item, typ: &OutputType) -> Item {
+    match (item, typ) {
+        (Item::Ref(item_ref), typ) => {
+            let item = Arc::try_unwrap(item_ref).unwrap_or_else(|item_ref| (*item_ref).clone());
+            tag_numerics(item, typ)
+        }
+
+        (Item::List(list), OutputType::List(inner)) => {
+            Item::list(list.into_iter().map(|item| tag_numerics(item, inner)).collect())
+        }
+
+        (Item::Map(map), OutputType::Object(obj)) => {
+            let obj = obj.into_arc();
+
+            Item::Map(
+                map.into_iter()
+                    .map(|(key, item)| match obj.find_field(&key) {
+                        Some(field) => {
+                            let item = tag_numerics(item, &field.field_type);
+                            (key, item)
+                        }
+                        None => (key, item),
+                    })
+                    .collect(),
+            )
+        }
+
+        // Scalar lists are tagged element by element, e.g. the values of a `Decimal[]` field.
+        (Item::Value(PrismaValue::List(values)), OutputType::List(inner)) => match inner.as_ref() {
+            OutputType::Scalar(st) => Item::list(values.into_iter().map(|value| tag_value(value, st)).collect()),
+            _ => Item::Value(PrismaValue::List(values)),
+        },
+
+        (Item::Value(value), OutputType::Scalar(st)) => tag_value(value, st),
+        (item, _) => item,
+    }
+}
+
+fn tag_value(value: PrismaValue, st: &ScalarType) -> Item {
+    let tag = match st {
+        ScalarType::Decimal => "decimal",
+        ScalarType::BigInt => "bigint",
+        _ => return Item::Value(value),
+    };
+
+    let exact = match value {
+        PrismaValue::String(s) => s,
+        PrismaValue::Float(f) => f.to_string(),
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => i.to_string(),
+        other => return Item::Value(other),
+    };
+
+    Item::Json(serde_json::json!({
+        "prisma__type": tag,
+        "prisma__value": exact,
+    }))
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ResponseData {
     /// Top level serialization key to be used for the data.
     pub key: String,
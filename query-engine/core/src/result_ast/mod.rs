@@ -1,3 +1,4 @@
+use crate::RelationCounts;
 use connector::{AggregationRow, QueryArguments};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
@@ -20,9 +21,12 @@ pub struct RecordSelection {
     /// Holds an ordered list of selected field names for each contained record.
     pub fields: Vec<String>,
 
-    /// Scalar field results
+    /// Scalar field results, followed by the relation counts.
     pub scalars: ManyRecords,
 
+    /// Relation counts selected alongside the scalar fields.
+    pub relation_counts: RelationCounts,
+
     /// Nested query results
     // Todo this is only here because reads are still resolved in one go
     pub nested: Vec<QueryResult>,
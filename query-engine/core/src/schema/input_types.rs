@@ -18,6 +18,9 @@ pub struct InputObjectTypeConstraints {
 
     /// The minimum number of fields that must be provided.
     pub max_num_fields: Option<usize>,
+
+    /// The fields counted by the constraints, all fields if `None`.
+    pub fields: Option<Vec<String>>,
 }
 
 impl Debug for InputObjectType {
@@ -75,6 +78,11 @@ impl InputObjectType {
     pub fn set_min_fields(&mut self, min: usize) {
         self.constraints.min_num_fields = Some(min);
     }
+
+    /// Only count the given fields for the constraints, other fields are always allowed.
+    pub fn constrain_fields(&mut self, fields: Vec<String>) {
+        self.constraints.fields = Some(fields);
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -197,6 +205,10 @@ impl InputType {
         InputType::Scalar(ScalarType::BigInt)
     }
 
+    pub fn unsigned_bigint() -> InputType {
+        InputType::Scalar(ScalarType::UnsignedBigInt)
+    }
+
     pub fn float() -> InputType {
         InputType::Scalar(ScalarType::Float)
     }
@@ -233,6 +245,10 @@ impl InputType {
         InputType::Scalar(ScalarType::Bytes)
     }
 
+    pub fn interval() -> InputType {
+        InputType::Scalar(ScalarType::Interval)
+    }
+
     pub fn null() -> InputType {
         InputType::Scalar(ScalarType::Null)
     }
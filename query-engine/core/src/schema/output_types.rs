@@ -65,6 +65,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Bytes)
     }
 
+    pub fn interval() -> OutputType {
+        OutputType::Scalar(ScalarType::Interval)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
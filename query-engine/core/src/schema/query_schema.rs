@@ -1,8 +1,12 @@
 use super::*;
+use datamodel_connector::{ConnectorCapabilities, ConnectorCapability};
 use fmt::Debug;
 use prisma_models::{InternalDataModelRef, ModelRef};
 use std::{borrow::Borrow, fmt};
 
+/// The number of `connectOrCreate` retries if the engine is not configured otherwise.
+pub const DEFAULT_CONNECT_OR_CREATE_RETRIES: u32 = 2;
+
 /// The query schema.
 /// Defines which operations (query/mutations) are possible on a database, based on the (internal) data model.
 ///
@@ -28,6 +32,19 @@ pub struct QuerySchema {
     output_object_types: Vec<ObjectTypeStrongRef>,
 
     pub internal_data_model: InternalDataModelRef,
+
+    /// Read-only schemas expose no model mutations, and only accept raw queries that don't write.
+    pub read_only: bool,
+
+    /// Maximum number of groups returned by group by queries that don't specify `take`.
+    pub group_by_take_limit: Option<i64>,
+
+    /// How often `connectOrCreate` connects a record created concurrently by another request after
+    /// its own create failed, before giving up, see `ConnectOrCreateRetry`.
+    pub connect_or_create_retries: u32,
+
+    /// The capabilities of the connector the schema was built for.
+    capabilities: ConnectorCapabilities,
 }
 
 impl QuerySchema {
@@ -37,6 +54,8 @@ impl QuerySchema {
         input_object_types: Vec<InputObjectTypeStrongRef>,
         output_object_types: Vec<ObjectTypeStrongRef>,
         internal_data_model: InternalDataModelRef,
+        read_only: bool,
+        capabilities: ConnectorCapabilities,
     ) -> Self {
         QuerySchema {
             query,
@@ -44,9 +63,30 @@ impl QuerySchema {
             input_object_types,
             output_object_types,
             internal_data_model,
+            read_only,
+            group_by_take_limit: None,
+            connect_or_create_retries: DEFAULT_CONNECT_OR_CREATE_RETRIES,
+            capabilities,
         }
     }
 
+    pub fn has_capability(&self, capability: ConnectorCapability) -> bool {
+        self.capabilities.contains(capability)
+    }
+
+    pub fn with_group_by_take_limit(mut self, limit: Option<i64>) -> Self {
+        self.group_by_take_limit = limit;
+        self
+    }
+
+    pub fn with_connect_or_create_retries(mut self, retries: Option<u32>) -> Self {
+        if let Some(retries) = retries {
+            self.connect_or_create_retries = retries;
+        }
+
+        self
+    }
+
     pub fn find_mutation_field<T>(&self, name: T) -> Option<OutputFieldRef>
     where
         T: Into<String>,
@@ -63,6 +103,11 @@ impl QuerySchema {
         self.query().get_fields().iter().find(|f| f.name == name).cloned()
     }
 
+    /// All input object types of the schema, including ones only reachable as a union member.
+    pub fn input_object_types(&self) -> &[InputObjectTypeStrongRef] {
+        &self.input_object_types
+    }
+
     pub fn mutation(&self) -> ObjectTypeStrongRef {
         match self.mutation.borrow() {
             OutputType::Object(ref o) => o.into_arc(),
@@ -92,6 +137,7 @@ pub enum QueryTag {
     FindUnique,
     FindFirst,
     FindMany,
+    FindRaw,
     CreateOne,
     UpdateOne,
     UpdateMany,
@@ -111,6 +157,7 @@ impl fmt::Display for QueryTag {
             Self::FindUnique => "findUnique",
             Self::FindFirst => "findFirst",
             Self::FindMany => "findMany",
+            Self::FindRaw => "findRaw",
             Self::CreateOne => "createOne",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
@@ -165,6 +212,9 @@ pub enum ScalarType {
     String,
     Int,
     BigInt,
+    /// The input type of unsigned 64-bit columns, which also take the values beyond the signed
+    /// 64-bit range. Rendered as `BigInt`.
+    UnsignedBigInt,
     Float,
     Decimal,
     Boolean,
@@ -175,4 +225,5 @@ pub enum ScalarType {
     UUID,
     Xml,
     Bytes,
+    Interval,
 }
@@ -5,26 +5,28 @@
 //! The cache has two purposes:
 //! - First, break circular dependencies, as they can happen in recursive input / output types.
 //! - Second, it serves as a central list of build types of that builder, which are used later to
-//!   collect all types of the query schema.
+//!   collect all types of the query schema, in the order they were built, so the query schema
+//!   is identical for identical datamodels.
 //!
 //! The cached types are stored as Arcs, and the cache owns these (strong) Arcs,
 //! while the cache will only hand out weak arcs. Not only does this simplify the builder architecture,
 //! but also prevents issues with memory leaks in the schema, as well as issues that when all strong
 //! arcs are dropped due to visitor operations, the schema can't be traversed anymore due to invalid references.
 use super::*;
-use std::{collections::HashMap, fmt::Debug, sync::Weak};
+use indexmap::IndexMap;
+use std::{fmt::Debug, sync::Weak};
 
 /// Cache wrapper over Arc<T>.
 /// Caches keys at most once, and errors on repeated insertion of the same key
 /// to uphold schema building consistency guarantees.
 #[derive(Debug, Default)]
 pub struct TypeRefCache<T> {
-    cache: HashMap<Identifier, Arc<T>>,
+    cache: IndexMap<Identifier, Arc<T>>,
 }
 
 impl<T: Debug> TypeRefCache<T> {
     pub fn new() -> Self {
-        TypeRefCache { cache: HashMap::new() }
+        TypeRefCache { cache: IndexMap::new() }
     }
 
     // Retrieves a cached Arc if present, and hands out a weak reference to the contents.
@@ -1,4 +1,6 @@
 use super::*;
+use datamodel_connector::ConnectorCapability;
+use prisma_models::{dml, PrismaValue};
 
 /// Builds "where" argument.
 pub(crate) fn where_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
@@ -104,6 +106,8 @@ pub(crate) fn many_records_arguments(
         where_argument(ctx, &model),
         order_by_argument(ctx, &model),
         input_field("cursor", unique_input_type, None).optional(),
+        input_field("after", InputType::string(), None).optional(),
+        input_field("before", InputType::string(), None).optional(),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
     ];
@@ -122,6 +126,64 @@ pub(crate) fn many_records_arguments(
     args
 }
 
+/// Builds the "relationLoadStrategy" argument of read queries, which chooses between loading
+/// to-one relations with joins and with separate queries. Only built for connectors that can
+/// load relations with joins.
+pub(crate) fn relation_load_strategy_argument(ctx: &BuilderContext) -> Option<InputField> {
+    if !ctx.capabilities.contains(ConnectorCapability::RelationJoins) {
+        return None;
+    }
+
+    let enum_type = Arc::new(string_enum_type(
+        "RelationLoadStrategy",
+        vec!["join".to_owned(), "query".to_owned()],
+    ));
+
+    let field = input_field(
+        "relationLoadStrategy",
+        InputType::enum_type(enum_type),
+        Some(dml::DefaultValue::Single(PrismaValue::Enum("query".to_owned()))),
+    )
+    .optional();
+
+    Some(field)
+}
+
+/// Builds the "window" argument of find many queries, see `window_object_type`.
+pub(crate) fn window_argument(ctx: &mut BuilderContext, model: &ModelRef) -> Option<InputField> {
+    if !ctx.capabilities.contains(ConnectorCapability::WindowFunctions) {
+        return None;
+    }
+
+    let window_object_type = InputType::object(window_object_type(ctx, model));
+
+    Some(input_field("window", window_object_type, None).optional())
+}
+
+/// Builds the "distinct" argument of the count aggregation, which counts the distinct values of
+/// the fields instead, and the distinct combinations of their values for `_all`.
+pub(crate) fn count_distinct_argument() -> InputField {
+    input_field("distinct", InputType::boolean(), None).optional()
+}
+
+/// Builds the "p" argument of the percentile aggregation, the percentile between 0 and 1.
+pub(crate) fn percentile_argument() -> InputField {
+    input_field("p", InputType::float(), None)
+}
+
+/// Builds the arguments of raw queries: the query string and its parameters.
+pub(crate) fn raw_arguments() -> Vec<InputField> {
+    vec![
+        input_field("query", InputType::string(), None),
+        input_field(
+            "parameters",
+            InputType::json_list(),
+            Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
+        )
+        .optional(),
+    ]
+}
+
 // Builds "orderBy" argument.
 pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
     let order_object_type = InputType::object(order_by_object_type(ctx, model));
@@ -136,10 +198,16 @@ pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> I
 
 pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let field_enum_type = InputType::Enum(model_field_enum(model));
+    let order_object_type = InputType::object(group_by_order_by_object_type(ctx, model));
 
-    vec![
+    let mut args = vec![
         where_argument(ctx, &model),
-        order_by_argument(ctx, &model),
+        input_field(
+            "orderBy",
+            vec![InputType::list(order_object_type.clone()), order_object_type],
+            None,
+        )
+        .optional(),
         input_field(
             "by",
             vec![InputType::list(field_enum_type.clone()), field_enum_type],
@@ -151,7 +219,20 @@ pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) ->
             None,
         )
         .optional(),
+        input_field(
+            "cursor",
+            InputType::object(group_by_cursor_object_type(ctx, model)),
+            None,
+        )
+        .optional(),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
-    ]
+    ];
+
+    // Adds subtotal rows for each prefix of the `by` fields and a grand total.
+    if ctx.capabilities.contains(ConnectorCapability::GroupByRollup) {
+        args.push(input_field("rollup", InputType::boolean(), None).optional());
+    }
+
+    args
 }
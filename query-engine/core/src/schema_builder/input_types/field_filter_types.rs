@@ -1,6 +1,6 @@
 use super::*;
 use datamodel_connector::ConnectorCapability;
-use prisma_models::{dml::DefaultValue, PrismaValue};
+use prisma_models::{dml::DefaultValue, JsonNullValue, PrismaValue};
 
 /// Builds filter types for the given model field.
 pub(crate) fn get_field_filter_types(
@@ -16,12 +16,15 @@ pub(crate) fn get_field_filter_types(
         }
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
         ModelField::Scalar(sf) => {
+            // Case-insensitive columns (e.g. `citext`) get the nested filter type, which has no
+            // `mode` field, as they always compare insensitively.
             let mut types = vec![InputType::object(full_scalar_filter_type(
                 ctx,
                 &sf.type_identifier,
+                is_unsigned_bigint(sf),
                 sf.is_list,
                 !sf.is_required,
-                false,
+                sf.is_case_insensitive(),
                 include_aggregates,
             ))];
 
@@ -56,10 +59,29 @@ fn mto1_relation_filter_shorthand_types(ctx: &mut BuilderContext, rf: &RelationF
     types
 }
 
+/// Builds the `some`/`every`/`none` filter on the far side of a many-to-many relation that is
+/// backed by a join model: `where: { {field}_{join_field}: { some: { ... } } }`.
+pub(crate) fn join_table_filter_input_field(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Option<InputField> {
+    rf.join_table_field().map(|join_field| {
+        let name = join_table_filter_name(rf, &join_field);
+        let filter = relation_filter_object(ctx, &join_field.related_model(), true, false);
+
+        input_field(name, InputType::object(filter), None).optional()
+    })
+}
+
 fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> InputObjectTypeWeakRef {
-    let related_model = rf.related_model();
-    let related_input_type = filter_objects::where_object_type(ctx, &related_model);
-    let list = if rf.is_list { "List" } else { "" };
+    relation_filter_object(ctx, &rf.related_model(), rf.is_list, rf.is_required)
+}
+
+fn relation_filter_object(
+    ctx: &mut BuilderContext,
+    related_model: &ModelRef,
+    is_list: bool,
+    is_required: bool,
+) -> InputObjectTypeWeakRef {
+    let related_input_type = filter_objects::where_object_type(ctx, related_model);
+    let list = if is_list { "List" } else { "" };
     let ident = Identifier::new(
         format!("{}{}RelationFilter", capitalize(&related_model.name), list),
         PRISMA_NAMESPACE,
@@ -69,7 +91,7 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
     let object = Arc::new(init_input_object_type(ident.clone()));
     ctx.cache_input_type(ident, object.clone());
 
-    let fields = if rf.is_list {
+    let fields = if is_list {
         vec![
             input_field("every", InputType::object(related_input_type.clone()), None).optional(),
             input_field("some", InputType::object(related_input_type.clone()), None).optional(),
@@ -79,10 +101,10 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
         vec![
             input_field("is", InputType::object(related_input_type.clone()), None)
                 .optional()
-                .nullable_if(!rf.is_required),
+                .nullable_if(!is_required),
             input_field("isNot", InputType::object(related_input_type), None)
                 .optional()
-                .nullable_if(!rf.is_required),
+                .nullable_if(!is_required),
         ]
     };
 
@@ -92,7 +114,7 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
 
 fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
     let ident = Identifier::new(
-        scalar_filter_name(&sf.type_identifier, true, !sf.is_required, false, false),
+        scalar_filter_name(&sf.type_identifier, false, true, !sf.is_required, false, false),
         PRISMA_NAMESPACE,
     );
     return_cached_input!(ctx, &ident);
@@ -100,23 +122,29 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     let object = Arc::new(init_input_object_type(ident.clone()));
     ctx.cache_input_type(ident, object.clone());
 
-    let mapped_type = map_scalar_input_type_for_field(ctx, sf);
-    let fields = equality_filters(mapped_type, !sf.is_required).collect();
+    let mapped_list_type = map_scalar_input_type_for_field(ctx, sf);
+    let mapped_element_type = map_scalar_input_type(ctx, &sf.type_identifier, false);
+    let fields = equality_filters(mapped_list_type, !sf.is_required)
+        .chain(scalar_list_filters(mapped_element_type))
+        .collect();
+
     object.set_fields(fields);
 
     Arc::downgrade(&object)
 }
 
+/// `unsigned` is set for unsigned BigInt fields, see `is_unsigned_bigint`.
 fn full_scalar_filter_type(
     ctx: &mut BuilderContext,
     typ: &TypeIdentifier,
+    unsigned: bool,
     list: bool,
     nullable: bool,
     nested: bool,
     include_aggregates: bool,
 ) -> InputObjectTypeWeakRef {
     let ident = Identifier::new(
-        scalar_filter_name(typ, list, nullable, nested, include_aggregates),
+        scalar_filter_name(typ, unsigned, list, nullable, nested, include_aggregates),
         PRISMA_NAMESPACE,
     );
     return_cached_input!(ctx, &ident);
@@ -124,7 +152,11 @@ fn full_scalar_filter_type(
     let object = Arc::new(init_input_object_type(ident.clone()));
     ctx.cache_input_type(ident, object.clone());
 
-    let mapped_scalar_type = map_scalar_input_type(ctx, typ, list);
+    let mapped_scalar_type = if unsigned {
+        InputType::unsigned_bigint()
+    } else {
+        map_scalar_input_type(ctx, typ, list)
+    };
 
     let mut fields: Vec<_> = match typ {
         TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(mapped_scalar_type.clone(), nullable)
@@ -138,12 +170,15 @@ fn full_scalar_filter_type(
         | TypeIdentifier::BigInt
         | TypeIdentifier::Float
         | TypeIdentifier::DateTime
-        | TypeIdentifier::Decimal => equality_filters(mapped_scalar_type.clone(), nullable)
+        | TypeIdentifier::Decimal
+        | TypeIdentifier::Interval => equality_filters(mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
+        TypeIdentifier::Json => json_equality_filters(mapped_scalar_type.clone(), nullable).collect(),
+
+        TypeIdentifier::Boolean | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
             equality_filters(mapped_scalar_type.clone(), nullable).collect()
         }
 
@@ -152,14 +187,26 @@ fn full_scalar_filter_type(
             .collect(),
     };
 
+    if matches!(typ, TypeIdentifier::Int | TypeIdentifier::BigInt) && !list {
+        fields.extend(bitwise_filters(ctx, mapped_scalar_type.clone()));
+    }
+
+    if typ == &TypeIdentifier::DateTime && !list {
+        fields.extend(date_filters());
+        fields.push(relative_time_filter(ctx));
+    }
+
     // Shorthand `not equals` filter, skips the nested object filter.
     let mut not_types = vec![mapped_scalar_type.clone()];
 
-    if typ != &TypeIdentifier::Json {
+    if typ == &TypeIdentifier::Json {
+        not_types.push(json_null_filter_enum());
+    } else {
         // Full nested filter. Only available on non-JSON fields.
         not_types.push(InputType::object(full_scalar_filter_type(
             ctx,
             typ,
+            unsigned,
             list,
             nullable,
             true,
@@ -175,19 +222,21 @@ fn full_scalar_filter_type(
             ctx,
             "count",
             &TypeIdentifier::Int,
+            false,
             nullable,
             list,
         ));
 
         if typ.is_numeric() {
             let avg_type = map_avg_type_ident(typ.clone());
-            fields.push(aggregate_filter_field(ctx, "avg", &avg_type, nullable, list));
-            fields.push(aggregate_filter_field(ctx, "sum", typ, nullable, list));
+            let sum_type = map_sum_type_ident(typ.clone());
+            fields.push(aggregate_filter_field(ctx, "avg", &avg_type, false, nullable, list));
+            fields.push(aggregate_filter_field(ctx, "sum", &sum_type, false, nullable, list));
         }
 
         if !list {
-            fields.push(aggregate_filter_field(ctx, "min", typ, nullable, list));
-            fields.push(aggregate_filter_field(ctx, "max", typ, nullable, list));
+            fields.push(aggregate_filter_field(ctx, "min", typ, unsigned, nullable, list));
+            fields.push(aggregate_filter_field(ctx, "max", typ, unsigned, nullable, list));
         }
     }
 
@@ -202,6 +251,40 @@ fn equality_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Ite
     .into_iter()
 }
 
+/// Json equality also accepts the `DbNull`, `JsonNull` and `AnyNull` placeholders, as a JSON
+/// `null` is different from a database NULL.
+fn json_equality_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Item = InputField> {
+    vec![input_field("equals", vec![mapped_type, json_null_filter_enum()], None)
+        .optional()
+        .nullable_if(nullable)]
+    .into_iter()
+}
+
+fn json_null_filter_enum() -> InputType {
+    let enum_type = string_enum_type(
+        "JsonNullValueFilter",
+        vec![
+            JsonNullValue::DbNull.as_str().to_owned(),
+            JsonNullValue::JsonNull.as_str().to_owned(),
+            JsonNullValue::AnyNull.as_str().to_owned(),
+        ],
+    );
+
+    InputType::enum_type(Arc::new(enum_type))
+}
+
+fn scalar_list_filters(mapped_element_type: InputType) -> impl Iterator<Item = InputField> {
+    let list_type = InputType::list(mapped_element_type.clone());
+
+    vec![
+        input_field("has", mapped_element_type, None).optional(),
+        input_field("hasEvery", list_type.clone(), None).optional(),
+        input_field("hasSome", list_type, None).optional(),
+        input_field("isEmpty", InputType::boolean(), None).optional(),
+    ]
+    .into_iter()
+}
+
 fn inclusion_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Item = InputField> {
     let typ = InputType::list(mapped_type);
 
@@ -231,6 +314,64 @@ fn string_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+/// Flag-style filters on integer columns, e.g. `flags: { hasBit: 4 }`. `hasBit` requires all bits
+/// of the mask to be set, `hasAnyBit` at least one of them.
+fn bitwise_filters(ctx: &BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
+    let fields = if ctx.capabilities.contains(ConnectorCapability::BitwiseFilters) {
+        vec![
+            input_field("hasBit", mapped_type.clone(), None).optional(),
+            input_field("hasAnyBit", mapped_type, None).optional(),
+        ]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Compare the day of DateTime fields, ignoring the time: `createdAt: { dateEquals: "2021-05-01" }`.
+/// Take a `YYYY-MM-DD` date, or the day of a DateTime.
+fn date_filters() -> impl Iterator<Item = InputField> {
+    let date_type = vec![InputType::date_time(), InputType::string()];
+
+    vec![
+        input_field("dateEquals", date_type.clone(), None).optional(),
+        input_field("dateLt", date_type.clone(), None).optional(),
+        input_field("dateLte", date_type.clone(), None).optional(),
+        input_field("dateGt", date_type.clone(), None).optional(),
+        input_field("dateGte", date_type, None).optional(),
+    ]
+    .into_iter()
+}
+
+/// Rolling windows on DateTime fields: `createdAt: { inTheLast: { amount: 7, unit: days } }`.
+fn relative_time_filter(ctx: &mut BuilderContext) -> InputField {
+    input_field("inTheLast", InputType::object(relative_time_input_type(ctx)), None).optional()
+}
+
+fn relative_time_input_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new("RelativeTimeInput".to_owned(), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, object.clone());
+
+    let unit_enum = Arc::new(string_enum_type(
+        "TimeUnit",
+        ["seconds", "minutes", "hours", "days", "weeks", "months", "years"]
+            .iter()
+            .map(|unit| (*unit).to_owned())
+            .collect(),
+    ));
+
+    object.set_fields(vec![
+        input_field("amount", InputType::int(), None),
+        input_field("unit", InputType::enum_type(unit_enum), None),
+    ]);
+
+    Arc::downgrade(&object)
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
@@ -257,6 +398,7 @@ fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item =
 
 fn scalar_filter_name(
     typ: &TypeIdentifier,
+    unsigned: bool,
     list: bool,
     nullable: bool,
     nested: bool,
@@ -271,6 +413,9 @@ fn scalar_filter_name(
         TypeIdentifier::UUID => format!("{}Uuid{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::String => format!("{}String{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Int => format!("{}Int{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::BigInt if unsigned => {
+            format!("{}UnsignedBigInt{}{}{}Filter", nested, nullable, list, aggregates)
+        }
         TypeIdentifier::BigInt => format!("{}BigInt{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Float => format!("{}Float{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Decimal => format!("{}Decimal{}{}{}Filter", nested, nullable, list, aggregates),
@@ -280,6 +425,7 @@ fn scalar_filter_name(
         TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}{}Filter", nested, e, nullable, list, aggregates),
         TypeIdentifier::Xml => format!("{}Xml{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Bytes => format!("{}Bytes{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Interval => format!("{}Interval{}{}{}Filter", nested, nullable, list, aggregates),
     }
 }
 
@@ -287,10 +433,11 @@ fn aggregate_filter_field(
     ctx: &mut BuilderContext,
     aggregation: &str,
     typ: &TypeIdentifier,
+    unsigned: bool,
     nullable: bool,
     list: bool,
 ) -> InputField {
-    let filters = full_scalar_filter_type(ctx, typ, list, nullable, true, false);
+    let filters = full_scalar_filter_type(ctx, typ, unsigned, list, nullable, true, false);
     input_field(aggregation, InputType::object(filters), None).optional()
 }
 
@@ -300,3 +447,11 @@ fn map_avg_type_ident(typ: TypeIdentifier) -> TypeIdentifier {
         _ => typ,
     }
 }
+
+fn map_sum_type_ident(typ: TypeIdentifier) -> TypeIdentifier {
+    match &typ {
+        TypeIdentifier::Int => TypeIdentifier::BigInt,
+        TypeIdentifier::BigInt => TypeIdentifier::Decimal,
+        _ => typ,
+    }
+}
@@ -143,7 +143,12 @@ where
         .iter()
         .filter(|f| !f.is_list)
         .map(|f| {
-            let default = if with_defaults { f.default_value.clone() } else { None };
+            // The database sets the timestamps of `databaseTimestamps` with its column defaults.
+            let default = if with_defaults && !f.is_set_by_database() {
+                f.default_value.clone()
+            } else {
+                None
+            };
             non_list_field_mapper(ctx, f.clone(), default)
         })
         .collect();
@@ -7,7 +7,7 @@ mod objects;
 use super::*;
 use crate::schema::*;
 use objects::*;
-use prisma_models::{RelationFieldRef, ScalarFieldRef};
+use prisma_models::{JsonNullValue, RelationFieldRef, ScalarFieldRef};
 
 /// Builds "<Model>OrderByInput" object types.
 pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
@@ -22,9 +22,133 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(ident, input_object.clone());
 
-    let fields = model
-        .fields()
-        .scalar()
+    let fields = orderable_fields(model)
+        .iter()
+        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByWithAggregationInput" object types, used by group by queries.
+/// In addition to the scalar fields, it allows ordering by the aggregates of the fields. The
+/// aggregates are prefixed with an underscore, so they can't clash with the names of the fields.
+pub(crate) fn group_by_order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
+    let ident = Identifier::new(format!("{}OrderByWithAggregationInput", model.name), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let mut input_object = init_input_object_type(ident.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let scalar_fields = model.fields().scalar();
+    let orderable_fields = orderable_fields(model);
+    let non_list_fields: Vec<_> = orderable_fields.iter().filter(|sf| !sf.is_list).cloned().collect();
+    let numeric_fields: Vec<_> = scalar_fields.iter().filter(|sf| sf.is_numeric()).cloned().collect();
+
+    let mut fields: Vec<InputField> = orderable_fields
+        .iter()
+        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .collect();
+
+    let aggregations = vec![
+        ("count", scalar_fields.clone()),
+        ("avg", numeric_fields.clone()),
+        ("sum", numeric_fields),
+        ("min", non_list_fields.clone()),
+        ("max", non_list_fields),
+    ];
+
+    for (name, aggregation_fields) in aggregations {
+        if !aggregation_fields.is_empty() {
+            let object_type = order_by_aggregate_object_type(ctx, model, name, &aggregation_fields, &enum_type);
+            fields.push(input_field(format!("_{}", name), InputType::object(object_type), None).optional());
+        }
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>GroupByCursorInput" object types, used by group by queries.
+/// A group is identified by the values of the fields it is grouped by, so every non-list scalar field can be part of it.
+pub(crate) fn group_by_cursor_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}GroupByCursorInput", model.name), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = orderable_fields(model)
+        .iter()
+        .filter(|sf| !sf.is_list)
+        .map(|sf| input_field(sf.name.clone(), map_scalar_input_type_for_field(ctx, sf), None).optional())
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>WindowInput" object types, which number the records of find many queries within
+/// partitions. The ordering of the numbers is required.
+pub(crate) fn window_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}WindowInput", model.name), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let field_enum_type = InputType::Enum(model_field_enum(model));
+    let order_object_type = InputType::object(order_by_object_type(ctx, model));
+
+    let fields = vec![
+        input_field(
+            "partitionBy",
+            vec![InputType::list(field_enum_type.clone()), field_enum_type],
+            None,
+        )
+        .optional(),
+        input_field(
+            "orderBy",
+            vec![InputType::list(order_object_type.clone()), order_object_type],
+            None,
+        ),
+        input_field("take", InputType::int(), None).optional(),
+    ];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model><Aggregation>OrderByAggregateInput" object types.
+fn order_by_aggregate_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    aggregation: &str,
+    fields: &[ScalarFieldRef],
+    enum_type: &EnumTypeRef,
+) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(
+        format!("{}{}OrderByAggregateInput", model.name, capitalize(aggregation)),
+        PRISMA_NAMESPACE,
+    );
+
+    return_cached_input!(ctx, &ident);
+
+    let mut input_object = init_input_object_type(ident.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = fields
         .iter()
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
         .collect();
@@ -34,9 +158,47 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
 }
 
 fn map_scalar_input_type_for_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
+    if is_unsigned_bigint(field) {
+        return InputType::unsigned_bigint();
+    }
+
     map_scalar_input_type(ctx, &field.type_identifier, field.is_list)
 }
 
+/// Unsigned 64-bit columns take the values up to the unsigned maximum, see
+/// `ScalarType::UnsignedBigInt`.
+fn is_unsigned_bigint(field: &ScalarFieldRef) -> bool {
+    field.type_identifier == TypeIdentifier::BigInt && !field.is_list && field.is_unsigned()
+}
+
+/// Builds the input types for writing a value to the given scalar field. Json fields also accept
+/// the `JsonNull` placeholder, and `DbNull` if they are optional, to tell the two nulls apart.
+fn map_scalar_write_input_types(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> Vec<InputType> {
+    let typ = map_scalar_input_type_for_field(ctx, field);
+
+    if field.type_identifier == TypeIdentifier::Json && !field.is_list {
+        vec![typ, json_null_input_enum(!field.is_required)]
+    } else {
+        vec![typ]
+    }
+}
+
+fn json_null_input_enum(nullable: bool) -> InputType {
+    let enum_type = if nullable {
+        string_enum_type(
+            "NullableJsonNullValueInput",
+            vec![
+                JsonNullValue::DbNull.as_str().to_owned(),
+                JsonNullValue::JsonNull.as_str().to_owned(),
+            ],
+        )
+    } else {
+        string_enum_type("JsonNullValueInput", vec![JsonNullValue::JsonNull.as_str().to_owned()])
+    };
+
+    InputType::enum_type(Arc::new(enum_type))
+}
+
 fn map_scalar_input_type(ctx: &mut BuilderContext, typ: &TypeIdentifier, list: bool) -> InputType {
     let typ = match typ {
         TypeIdentifier::String => InputType::string(),
@@ -51,6 +213,7 @@ fn map_scalar_input_type(ctx: &mut BuilderContext, typ: &TypeIdentifier, list: b
         TypeIdentifier::Xml => InputType::xml(),
         TypeIdentifier::Bytes => InputType::bytes(),
         TypeIdentifier::BigInt => InputType::bigint(),
+        TypeIdentifier::Interval => InputType::interval(),
     };
 
     if list {
@@ -95,12 +258,21 @@ fn compound_object_name(alias: Option<&String>, from_fields: &[ScalarFieldRef])
     })
 }
 
+/// The scalar fields records can be ordered, grouped and partitioned by. Encrypted fields hold
+/// ciphertext in the database, so the database can't compare their values.
+fn orderable_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| !field.is_encrypted)
+        .collect()
+}
+
 fn model_field_enum(model: &ModelRef) -> EnumTypeRef {
     Arc::new(EnumType::FieldRef(FieldRefEnumType {
         name: format!("{}ScalarFieldEnum", capitalize(&model.name)),
-        values: model
-            .fields()
-            .scalar()
+        values: orderable_fields(model)
             .into_iter()
             .map(|field| (field.name.clone(), field))
             .collect(),
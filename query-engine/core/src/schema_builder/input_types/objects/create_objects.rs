@@ -59,10 +59,16 @@ fn checked_create_input_type(
         "Create",
         scalar_fields,
         |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type_for_field(ctx, &f);
-
-            input_field(f.name.clone(), typ, default)
-                .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
+            let types = map_scalar_write_input_types(ctx, &f);
+
+            input_field(f.name.clone(), types, default)
+                .optional_if(
+                    !f.is_required
+                        || f.default_value.is_some()
+                        || f.is_created_at()
+                        || f.is_updated_at()
+                        || f.is_version(),
+                )
                 .nullable_if(!f.is_required)
         },
         true,
@@ -194,10 +200,16 @@ fn unchecked_create_input_type(
         "Create",
         scalar_fields,
         |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type_for_field(ctx, &f);
-
-            input_field(f.name.clone(), typ, default)
-                .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
+            let types = map_scalar_write_input_types(ctx, &f);
+
+            input_field(f.name.clone(), types, default)
+                .optional_if(
+                    !f.is_required
+                        || f.default_value.is_some()
+                        || f.is_created_at()
+                        || f.is_updated_at()
+                        || f.is_version(),
+                )
                 .nullable_if(!f.is_required)
         },
         true,
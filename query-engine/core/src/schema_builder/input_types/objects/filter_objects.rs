@@ -32,9 +32,12 @@ pub(crate) fn scalar_filter_object_type(
         input_field("NOT", vec![object_type.clone(), InputType::list(object_type)], None).optional(),
     ];
 
+    // Encrypted fields hold ciphertext in the database, so they can't be filtered on.
     input_fields.extend(model.fields().all.iter().filter_map(|f| match f {
-        ModelField::Scalar(_) => Some(input_fields::filter_input_field(ctx, f, include_aggregates)),
-        ModelField::Relation(_) => None,
+        ModelField::Scalar(sf) if !sf.is_encrypted => {
+            Some(input_fields::filter_input_field(ctx, f, include_aggregates))
+        }
+        _ => None,
     }));
 
     input_object.set_fields(input_fields);
@@ -67,14 +70,25 @@ pub(crate) fn where_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> I
         input_field("NOT", vec![object_type.clone(), InputType::list(object_type)], None).optional(),
     ];
 
+    // Encrypted fields hold ciphertext in the database, so they can't be filtered on.
     fields.extend(
         model
             .fields()
             .all
             .iter()
+            .filter(|f| !matches!(f, ModelField::Scalar(sf) if sf.is_encrypted))
             .map(|f| input_fields::filter_input_field(ctx, f, false)),
     );
 
+    // Filters that traverse join models to the other side of many-to-many relations.
+    fields.extend(
+        model
+            .fields()
+            .relation()
+            .iter()
+            .filter_map(|rf| field_filter_types::join_table_filter_input_field(ctx, rf)),
+    );
+
     input_object.set_fields(fields);
     weak_ref
 }
@@ -83,9 +97,17 @@ pub(crate) fn where_unique_object_type(ctx: &mut BuilderContext, model: &ModelRe
     let ident = Identifier::new(format!("{}WhereUniqueInput", model.name), PRISMA_NAMESPACE);
     return_cached_input!(ctx, &ident);
 
+    // The `@version` field can be given in addition to the unique criteria, to only
+    // write the record if it still has that version.
+    let version_field = model.fields().version().clone().filter(|field| !field.unique());
+
     let mut x = init_input_object_type(ident.clone());
     x.require_exactly_one_field();
 
+    if version_field.is_some() {
+        x.constrain_fields(unique_criteria_names(model));
+    }
+
     let input_object = Arc::new(x);
     ctx.cache_input_type(ident, input_object.clone());
 
@@ -129,12 +151,37 @@ pub(crate) fn where_unique_object_type(ctx: &mut BuilderContext, model: &ModelRe
 
     fields.extend(compound_unique_fields);
     fields.extend(compound_id_field);
+    fields.extend(version_field.map(|sf| {
+        let typ = map_scalar_input_type_for_field(ctx, &sf);
+        input_field(sf.name.clone(), typ, None).optional()
+    }));
 
     input_object.set_fields(fields);
 
     Arc::downgrade(&input_object)
 }
 
+/// The names of the fields of a `WhereUniqueInput` that identify a record.
+fn unique_criteria_names(model: &ModelRef) -> Vec<String> {
+    let mut names: Vec<String> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|f| f.unique())
+        .map(|f| f.name.clone())
+        .collect();
+
+    names.extend(model.unique_indexes().into_iter().map(compound_index_field_name));
+
+    if let Some(id_fields) = model.fields().id().filter(|fields| fields.len() > 1) {
+        names.push(compound_id_field_name(
+            &id_fields.iter().map(|f| f.name.as_ref()).collect::<Vec<&str>>(),
+        ));
+    }
+
+    names
+}
+
 /// Generates and caches an input object type for a compound field.
 fn compound_field_unique_object_type(
     ctx: &mut BuilderContext,
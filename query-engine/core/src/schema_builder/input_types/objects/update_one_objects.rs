@@ -139,6 +139,9 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
         TypeIdentifier::Int => InputType::object(operations_object_type(ctx, "Int", field, true)),
+        TypeIdentifier::BigInt if is_unsigned_bigint(field) => {
+            InputType::object(operations_object_type(ctx, "UnsignedBigInt", field, true))
+        }
         TypeIdentifier::BigInt => InputType::object(operations_object_type(ctx, "BigInt", field, true)),
         TypeIdentifier::String => InputType::object(operations_object_type(ctx, "String", field, false)),
         TypeIdentifier::Boolean => InputType::object(operations_object_type(ctx, "Bool", field, false)),
@@ -148,13 +151,14 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
         TypeIdentifier::Xml => InputType::object(operations_object_type(ctx, "Xml", field, false)),
         TypeIdentifier::Bytes => InputType::object(operations_object_type(ctx, "Bytes", field, false)),
+        TypeIdentifier::Interval => InputType::object(operations_object_type(ctx, "Interval", field, false)),
     };
 
     let input_field = if field.type_identifier != TypeIdentifier::Json {
         let types = vec![map_scalar_input_type_for_field(ctx, field), base_update_type];
         input_field(field.name.clone(), types, default)
     } else {
-        input_field(field.name.clone(), base_update_type, default)
+        input_field(field.name.clone(), map_scalar_write_input_types(ctx, field), default)
     };
 
     input_field.optional().nullable_if(!field.is_required)
@@ -353,6 +357,6 @@ fn field_should_be_kept_for_update_input_type(field: &ScalarFieldRef) -> bool {
     !field.is_auto_generated_int_id
         && !matches!(
             (&field.type_identifier, field.unique(), field.is_autoincrement),
-            (TypeIdentifier::Int, true, true)
+            (TypeIdentifier::Int, true, true) | (TypeIdentifier::BigInt, true, true)
         )
 }
@@ -60,6 +60,7 @@ pub(crate) struct BuilderContext {
     mode: BuildMode,
     internal_data_model: InternalDataModelRef,
     enable_raw_queries: bool,
+    read_only: bool,
     cache: TypeCache,
     capabilities: ConnectorCapabilities,
     nested_create_inputs_queue: NestedInputsQueue,
@@ -71,12 +72,14 @@ impl BuilderContext {
         mode: BuildMode,
         internal_data_model: InternalDataModelRef,
         enable_raw_queries: bool,
+        read_only: bool,
         capabilities: ConnectorCapabilities,
     ) -> Self {
         Self {
             mode,
             internal_data_model,
             enable_raw_queries,
+            read_only,
             cache: TypeCache::new(),
             capabilities,
             nested_create_inputs_queue: Vec::new(),
@@ -143,9 +146,10 @@ pub fn build(
     internal_data_model: InternalDataModelRef,
     mode: BuildMode,
     enable_raw_queries: bool,
+    read_only: bool,
     capabilities: ConnectorCapabilities,
 ) -> QuerySchema {
-    let mut ctx = BuilderContext::new(mode, internal_data_model, enable_raw_queries, capabilities);
+    let mut ctx = BuilderContext::new(mode, internal_data_model, enable_raw_queries, read_only, capabilities);
     output_types::output_objects::initialize_model_object_type_cache(&mut ctx);
 
     let (query_type, query_object_ref) = output_types::query_type::build(&mut ctx);
@@ -165,6 +169,8 @@ pub fn build(
         input_objects,
         output_objects,
         ctx.internal_data_model,
+        ctx.read_only,
+        ctx.capabilities,
     )
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use datamodel_connector::ConnectorCapability;
 use std::convert::identity;
 
 /// Builds group by aggregation object type for given model (e.g. GroupByUserOutputType).
@@ -14,6 +15,11 @@ pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &Mode
     // Model fields that can be grouped by value.
     let mut object_fields = scalar_fields(ctx, model);
 
+    // Marks the subtotal rows of a rollup.
+    if ctx.capabilities.contains(ConnectorCapability::GroupByRollup) {
+        object_fields.push(field("_subtotal", vec![], OutputType::boolean(), None));
+    }
+
     // Fields used in aggregations
     let non_list_fields = collect_non_list_fields(model);
     let numeric_fields = collect_numeric_fields(model);
@@ -53,7 +59,7 @@ pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &Mode
             "sum",
             &model,
             numeric_fields.clone(),
-            map_scalar_output_type_for_field,
+            field_sum_output_type,
             identity,
         ),
     );
@@ -13,8 +13,24 @@ fn field_avg_output_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> Ou
     }
 }
 
+/// Sums are promoted to the next larger type, so that they can't overflow the type of the field.
+fn field_sum_output_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> OutputType {
+    match field.type_identifier {
+        TypeIdentifier::Int => OutputType::bigint(),
+        TypeIdentifier::BigInt | TypeIdentifier::Decimal => OutputType::decimal(),
+        _ => map_scalar_output_type_for_field(ctx, field),
+    }
+}
+
+/// The fields with a minimum and maximum. Encrypted fields are left out, because the database
+/// would compare their ciphertext.
 fn collect_non_list_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
-    model.fields().scalar().into_iter().filter(|f| !f.is_list).collect()
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|f| !f.is_list && !f.is_encrypted)
+        .collect()
 }
 
 fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
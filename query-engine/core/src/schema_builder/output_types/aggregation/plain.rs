@@ -1,4 +1,5 @@
 use super::*;
+use datamodel_connector::ConnectorCapability;
 use std::convert::identity;
 
 /// Builds plain aggregation object type for given model (e.g. AggregateUser).
@@ -12,7 +13,7 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
     let non_list_fields = collect_non_list_fields(model);
     let numeric_fields = collect_numeric_fields(model);
 
-    // Count is available on all fields.
+    // Count is available on all fields, optionally of distinct values only.
     append_opt(
         &mut object_fields,
         aggregation_field(
@@ -25,7 +26,13 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
                 obj.add_field(field("_all", vec![], OutputType::int(), None));
                 obj
             },
-        ),
+        )
+        .map(|mut count_field| {
+            count_field
+                .arguments
+                .push(Arc::new(arguments::count_distinct_argument()));
+            count_field
+        }),
     );
 
     append_opt(
@@ -47,7 +54,7 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
             "sum",
             &model,
             numeric_fields.clone(),
-            map_scalar_output_type_for_field,
+            field_sum_output_type,
             identity,
         ),
     );
@@ -76,6 +83,53 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
         ),
     );
 
+    if ctx.capabilities.contains(ConnectorCapability::StatisticalAggregations) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                "stdDev",
+                &model,
+                numeric_fields.clone(),
+                field_avg_output_type,
+                identity,
+            ),
+        );
+
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                "variance",
+                &model,
+                numeric_fields.clone(),
+                field_avg_output_type,
+                identity,
+            ),
+        );
+    }
+
+    // The median is the percentile 0.5.
+    if ctx.capabilities.contains(ConnectorCapability::PercentileAggregations) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                "percentile",
+                &model,
+                numeric_fields,
+                field_avg_output_type,
+                identity,
+            )
+            .map(|mut percentile_field| {
+                percentile_field
+                    .arguments
+                    .push(Arc::new(arguments::percentile_argument()));
+                percentile_field
+            }),
+        );
+    }
+
     object.set_fields(object_fields);
     ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
 
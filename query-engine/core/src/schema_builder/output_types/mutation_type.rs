@@ -1,10 +1,15 @@
 use super::*;
 use input_types::input_fields;
-use prisma_models::{dml, PrismaValue};
 
 /// Builds the root `Mutation` type.
+/// In read-only mode, only the raw query fields (if enabled) are exposed.
 pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRef) {
-    let non_embedded_models = ctx.internal_data_model.non_embedded_models();
+    let non_embedded_models = if ctx.read_only {
+        vec![]
+    } else {
+        ctx.internal_data_model.non_embedded_models()
+    };
+
     let mut fields: Vec<OutputField> = non_embedded_models
         .into_iter()
         .map(|model| {
@@ -78,15 +83,7 @@ fn create_nested_inputs(ctx: &mut BuilderContext) {
 fn create_execute_raw_field() -> OutputField {
     field(
         "executeRaw",
-        vec![
-            input_field("query", InputType::string(), None),
-            input_field(
-                "parameters",
-                InputType::json_list(),
-                Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
-            )
-            .optional(),
-        ],
+        arguments::raw_arguments(),
         OutputType::json(),
         Some(QueryInfo {
             tag: QueryTag::ExecuteRaw,
@@ -98,15 +95,7 @@ fn create_execute_raw_field() -> OutputField {
 fn create_query_raw_field() -> OutputField {
     field(
         "queryRaw",
-        vec![
-            input_field("query", InputType::string(), None),
-            input_field(
-                "parameters",
-                InputType::json_list(),
-                Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
-            )
-            .optional(),
-        ],
+        arguments::raw_arguments(),
         OutputType::json(),
         Some(QueryInfo {
             tag: QueryTag::QueryRaw,
@@ -1,4 +1,6 @@
 use super::*;
+use connector::Window;
+use datamodel_connector::ConnectorCapability;
 use prisma_models::ScalarFieldRef;
 
 /// Initializes model output object type cache on the context.
@@ -33,12 +35,60 @@ pub(crate) fn initialize_model_object_type_cache(ctx: &mut BuilderContext) {
 /// Computes model output type fields.
 /// Important: This requires that the cache has already been initialized.
 fn compute_model_object_type_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
-    model
+    let mut fields: Vec<OutputField> = model
         .fields()
         .all
         .iter()
         .map(|f| output_objects::map_field(ctx, f))
-        .collect()
+        .collect();
+
+    if let Some(count_type) = relation_count_object_type(ctx, model) {
+        fields.push(field("_count", vec![], OutputType::object(count_type), None).optional());
+    }
+
+    // The opaque cursor of the record, accepted by the `after` and `before` arguments.
+    fields.push(field("_cursor", vec![], OutputType::string(), None));
+
+    // The numbers of the record within its partition of the `window` of a find many query.
+    if ctx.capabilities.contains(ConnectorCapability::WindowFunctions) {
+        fields.push(field(Window::ROW_NUMBER, vec![], OutputType::int(), None).optional());
+        fields.push(field(Window::RANK, vec![], OutputType::int(), None).optional());
+    }
+
+    fields
+}
+
+/// Returns the `_count` output object type of the model, with one field per list relation that
+/// counts the related records, optionally filtered. Returns `None` if the model has no list relations.
+fn relation_count_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> Option<ObjectTypeWeakRef> {
+    let relations: Vec<RelationFieldRef> = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.is_list && !rf.related_model().is_embedded)
+        .collect();
+
+    if relations.is_empty() {
+        return None;
+    }
+
+    let ident = Identifier::new(format!("{}CountOutputType", model.name), PRISMA_NAMESPACE);
+    if let Some(existing) = ctx.get_output_type(&ident) {
+        return Some(existing);
+    }
+
+    let fields = relations
+        .iter()
+        .map(|rf| {
+            let args = vec![arguments::where_argument(ctx, &rf.related_model())];
+            field(rf.name.clone(), args, OutputType::int(), None)
+        })
+        .collect();
+
+    let object_type = Arc::new(object_type(ident.clone(), fields, None));
+
+    ctx.cache_output_type(ident, object_type.clone());
+    Some(Arc::downgrade(&object_type))
 }
 
 /// Returns an output object type for the given model.
@@ -84,6 +134,7 @@ pub(crate) fn map_scalar_output_type(ctx: &mut BuilderContext, typ: &TypeIdentif
         TypeIdentifier::Xml => OutputType::xml(),
         TypeIdentifier::Bytes => OutputType::bytes(),
         TypeIdentifier::BigInt => OutputType::bigint(),
+        TypeIdentifier::Interval => OutputType::interval(),
     };
 
     if list {
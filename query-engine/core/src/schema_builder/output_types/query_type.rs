@@ -16,6 +16,10 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
                 vec.push(group_by_aggregation_field(ctx, &model));
             }
 
+            if ctx.enable_raw_queries {
+                vec.push(find_raw_field(ctx, &model));
+            }
+
             append_opt(&mut vec, find_one_field(ctx, &model));
             append_opt(&mut vec, find_unique_field(ctx, &model));
             vec
@@ -33,10 +37,12 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
 fn find_one_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::where_unique_argument(ctx, model).map(|arg| {
         let field_name = ctx.pluralize_internal(camel_case(&model.name), format!("findOne{}", model.name));
+        let mut args = vec![arg];
+        append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
 
         field(
             field_name,
-            vec![arg],
+            args,
             OutputType::object(output_objects::map_model_object_type(ctx, &model)),
             Some(QueryInfo {
                 model: Some(Arc::clone(&model)),
@@ -57,10 +63,12 @@ fn find_one_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputFi
 fn find_unique_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::where_unique_argument(ctx, model).map(|arg| {
         let field_name = ctx.pluralize_internal(camel_case(&model.name), format!("findUnique{}", model.name));
+        let mut args = vec![arg];
+        append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
 
         field(
             field_name,
-            vec![arg],
+            args,
             OutputType::object(output_objects::map_model_object_type(ctx, &model)),
             Some(QueryInfo {
                 model: Some(Arc::clone(&model)),
@@ -73,7 +81,8 @@ fn find_unique_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 
 /// Builds a find first item field for given model.
 fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
     let field_name = format!("findFirst{}", model.name);
 
     field(
@@ -90,7 +99,9 @@ fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
 
 /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
 fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+    append_opt(&mut args, arguments::window_argument(ctx, &model));
     let field_name = ctx.pluralize_internal(camel_case(pluralize(&model.name)), format!("findMany{}", model.name));
 
     field(
@@ -104,6 +115,20 @@ fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     )
 }
 
+/// Builds a raw query field (e.g. "findRawUser") for given model, which maps the result columns
+/// of a raw query onto the model's fields.
+fn find_raw_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    field(
+        format!("findRaw{}", model.name),
+        arguments::raw_arguments(),
+        OutputType::list(OutputType::object(output_objects::map_model_object_type(ctx, &model))),
+        Some(QueryInfo {
+            model: Some(Arc::clone(&model)),
+            tag: QueryTag::FindRaw,
+        }),
+    )
+}
+
 /// Builds an "aggregate" query field (e.g. "aggregateUser") for given model.
 fn plain_aggregation_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     field(
@@ -125,6 +125,11 @@ pub fn append_opt<T>(vec: &mut Vec<T>, opt: Option<T>) {
     vec.extend(opt.into_iter())
 }
 
+/// Computes the name of the filter that traverses the join model of a many-to-many relation.
+pub fn join_table_filter_name(field: &RelationFieldRef, join_field: &RelationFieldRef) -> String {
+    format!("{}_{}", field.name, join_field.name)
+}
+
 /// Computes a compound field name based on an index.
 pub fn compound_index_field_name(index: &Index) -> String {
     index.name.clone().unwrap_or_else(|| {
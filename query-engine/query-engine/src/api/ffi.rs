@@ -0,0 +1,78 @@
+//! C ABI of the `api` functions. Strings are passed as NUL-terminated UTF-8, and the JSON strings
+//! returned by the engine must be released with `prisma_free_string`.
+
+use super::DmmfOptions;
+use crate::dmmf::DataModelMetaFormat;
+use datamodel::diagnostics::StructuredDiagnostic;
+use serde::Serialize;
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum DmmfResult {
+    Dmmf(DataModelMetaFormat),
+    Errors(Vec<StructuredDiagnostic>),
+}
+
+/// Validates the datamodel and returns its diagnostics as a JSON array, see `api::validate`.
+///
+/// # Safety
+///
+/// `datamodel` must be a valid pointer to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_validate(datamodel: *const c_char, ignore_env_var_errors: bool) -> *mut c_char {
+    let datamodel = CStr::from_ptr(datamodel).to_string_lossy();
+    let diagnostics = super::validate(&datamodel, ignore_env_var_errors);
+
+    into_json_ptr(&diagnostics)
+}
+
+/// Renders the DMMF of the datamodel as `{ "dmmf": ... }`, or its diagnostics as
+/// `{ "errors": [...] }` if it is invalid, see `api::dmmf`.
+///
+/// # Safety
+///
+/// `datamodel` must be a valid pointer to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_dmmf(
+    datamodel: *const c_char,
+    legacy: bool,
+    enable_raw_queries: bool,
+    read_only: bool,
+) -> *mut c_char {
+    let datamodel = CStr::from_ptr(datamodel).to_string_lossy();
+    let options = DmmfOptions {
+        legacy,
+        enable_raw_queries,
+        read_only,
+    };
+
+    let result = match super::dmmf(&datamodel, options) {
+        Ok(dmmf) => DmmfResult::Dmmf(dmmf),
+        Err(errors) => DmmfResult::Errors(errors),
+    };
+
+    into_json_ptr(&result)
+}
+
+/// Releases a string returned by the engine.
+///
+/// # Safety
+///
+/// `s` must have been returned by one of the functions of this module, and not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+fn into_json_ptr<T: Serialize>(value: &T) -> *mut c_char {
+    let json = serde_json::to_string(value).expect("Failed to render JSON");
+
+    // JSON escapes control characters, so the string never contains a NUL byte.
+    CString::new(json).unwrap().into_raw()
+}
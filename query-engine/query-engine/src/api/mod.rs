@@ -0,0 +1,47 @@
+//! Functions for clients that embed the engine, e.g. through the C ABI in `ffi`. They only parse
+//! and validate the datamodel, no connector is constructed and no database is connected to, so
+//! they are cheap enough to run on every change of a schema.
+
+pub mod ffi;
+
+use crate::{cli::DmmfRequest, dmmf::DataModelMetaFormat};
+use datamodel::diagnostics::StructuredDiagnostic;
+use query_core::BuildMode;
+
+/// Options for the DMMF, matching the flags of the `cli dmmf` command.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DmmfOptions {
+    pub legacy: bool,
+    pub enable_raw_queries: bool,
+    pub read_only: bool,
+}
+
+/// Validates the datamodel and returns its errors and warnings. The datamodel is valid if none of
+/// the diagnostics is an error.
+pub fn validate(datamodel: &str, ignore_env_var_errors: bool) -> Vec<StructuredDiagnostic> {
+    let result = if ignore_env_var_errors {
+        datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel)
+    } else {
+        datamodel::parse_datamodel(datamodel)
+    };
+
+    match result {
+        Ok(validated) => validated.warnings.iter().map(StructuredDiagnostic::from).collect(),
+        Err(diagnostics) => diagnostics.to_structured(),
+    }
+}
+
+/// Renders the DMMF of the datamodel, or returns its diagnostics if it is invalid. Datasource URLs
+/// are ignored, like in the `cli dmmf` command.
+pub fn dmmf(datamodel: &str, options: DmmfOptions) -> Result<DataModelMetaFormat, Vec<StructuredDiagnostic>> {
+    let build_mode = if options.legacy {
+        BuildMode::Legacy
+    } else {
+        BuildMode::Modern
+    };
+
+    let request = DmmfRequest::parse(datamodel, build_mode, options.enable_raw_queries, options.read_only)
+        .map_err(|diagnostics| diagnostics.to_structured())?;
+
+    Ok(request.render_dmmf())
+}
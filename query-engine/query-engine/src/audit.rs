@@ -0,0 +1,30 @@
+use connector::AuditEntry;
+use datamodel::Datasource;
+use query_core::{AuditLog, AuditSink, AuditSinkRef};
+use std::sync::Arc;
+
+/// Logs the audit entries as `prisma::audit` events, in the log format of the engine.
+struct TracingAuditSink;
+
+impl AuditSink for TracingAuditSink {
+    fn record(&self, entries: Vec<AuditEntry>) {
+        for entry in entries {
+            let entry = serde_json::to_string(&entry).expect("audit entries to serialize");
+            info!(target: "prisma::audit", "{}", entry);
+        }
+    }
+}
+
+/// The `_prisma_audit_log` table if the datasource enables `auditLog`, otherwise the `sink` of the
+/// embedder, or the log stream if `stream` is set.
+pub(crate) fn audit_log(source: &Datasource, stream: bool, sink: Option<AuditSinkRef>) -> Option<AuditLog> {
+    if source.audit_log {
+        Some(AuditLog::Table)
+    } else if let Some(sink) = sink {
+        Some(AuditLog::Stream(sink))
+    } else if stream {
+        Some(AuditLog::Stream(Arc::new(TracingAuditSink)))
+    } else {
+        None
+    }
+}
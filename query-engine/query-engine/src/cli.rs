@@ -1,17 +1,21 @@
-use crate::request_handlers::graphql::{self, GraphQlBody};
+use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
 
 use crate::{
     context::PrismaContext,
     dmmf,
     opt::{CliOpt, PrismaOpt, Subcommand},
-    PrismaResult,
+    PrismaError, PrismaResult,
 };
 
-use datamodel::diagnostics::ValidatedConfiguration;
+use connector::QueryHints;
+use datamodel::diagnostics::{Diagnostics, ValidatedConfiguration};
 use datamodel::{Configuration, Datamodel};
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode};
+use query_core::{
+    schema::{QuerySchemaRef, QuerySchemaRenderer},
+    schema_builder, BuildMode,
+};
 use std::sync::Arc;
 
 pub struct ExecuteRequest {
@@ -20,28 +24,65 @@ pub struct ExecuteRequest {
     datamodel: Datamodel,
     config: Configuration,
     enable_raw_queries: bool,
+    read_only: bool,
+    group_by_take_limit: Option<u32>,
+    connect_or_create_retries: Option<u32>,
 }
 
 pub struct DmmfRequest {
     datamodel: Datamodel,
     build_mode: BuildMode,
     enable_raw_queries: bool,
+    read_only: bool,
     config: Configuration,
 }
 
+impl DmmfRequest {
+    /// Parses the datamodel and configuration of `datamodel_str`, ignoring the datasource URLs.
+    pub(crate) fn parse(
+        datamodel_str: &str,
+        build_mode: BuildMode,
+        enable_raw_queries: bool,
+        read_only: bool,
+    ) -> Result<Self, Diagnostics> {
+        Ok(DmmfRequest {
+            datamodel: datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_str)?.subject,
+            build_mode,
+            enable_raw_queries,
+            read_only,
+            config: datamodel::parse_configuration_and_ignore_datasource_urls(datamodel_str)?.subject,
+        })
+    }
+
+    pub(crate) fn render_dmmf(&self) -> dmmf::DataModelMetaFormat {
+        dmmf::render_dmmf(&self.datamodel, CliCommand::query_schema(self))
+    }
+}
+
+pub struct DmmfDiffRequest {
+    previous: DmmfRequest,
+    next: DmmfRequest,
+}
+
 pub struct GetConfigRequest {
     config: ValidatedConfiguration,
 }
 
 pub enum CliCommand {
     Dmmf(DmmfRequest),
+    Sdl(DmmfRequest),
+    DmmfDiff(DmmfDiffRequest),
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
 }
 
 impl CliCommand {
     /// Create a CLI command from a `PrismaOpt` instance.
-    pub(crate) fn from_opt(opts: &PrismaOpt) -> crate::PrismaResult<Option<CliCommand>> {
+    pub fn from_opt(opts: &PrismaOpt) -> crate::PrismaResult<Option<CliCommand>> {
+        if opts.print_sdl {
+            return Ok(Some(CliCommand::Sdl(Self::dmmf_request(opts)?)));
+        }
+
         let subcommand = opts.subcommand.as_ref();
         let subcommand = match subcommand {
             Some(cmd) => cmd,
@@ -50,26 +91,20 @@ impl CliCommand {
 
         match subcommand {
             Subcommand::Cli(ref cliopts) => match cliopts {
-                CliOpt::Dmmf => {
-                    let build_mode = if opts.legacy {
-                        BuildMode::Legacy
-                    } else {
-                        BuildMode::Modern
-                    };
-
-                    Ok(Some(CliCommand::Dmmf(DmmfRequest {
-                        datamodel: opts.datamodel(true)?,
-                        build_mode,
-                        enable_raw_queries: opts.enable_raw_queries,
-                        config: opts.configuration(true)?.subject,
-                    })))
-                }
+                CliOpt::Dmmf => Ok(Some(CliCommand::Dmmf(Self::dmmf_request(opts)?))),
+                CliOpt::DmmfDiff(input) => Ok(Some(CliCommand::DmmfDiff(DmmfDiffRequest {
+                    previous: Self::dmmf_request_from_str(opts, &input.previous)?,
+                    next: Self::dmmf_request_from_str(opts, &input.next)?,
+                }))),
                 CliOpt::GetConfig(input) => Ok(Some(CliCommand::GetConfig(GetConfigRequest {
                     config: opts.configuration(input.ignore_env_var_errors)?,
                 }))),
                 CliOpt::ExecuteRequest(input) => Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
                     query: input.query.clone(),
                     enable_raw_queries: opts.enable_raw_queries,
+                    read_only: opts.read_only,
+                    group_by_take_limit: opts.group_by_take_limit,
+                    connect_or_create_retries: opts.connect_or_create_retries,
                     legacy: input.legacy,
                     datamodel: opts.datamodel(true)?,
                     config: opts.configuration(false)?.subject,
@@ -78,15 +113,76 @@ impl CliCommand {
         }
     }
 
+    fn dmmf_request(opts: &PrismaOpt) -> crate::PrismaResult<DmmfRequest> {
+        Ok(DmmfRequest {
+            datamodel: opts.datamodel(true)?,
+            build_mode: Self::build_mode(opts),
+            enable_raw_queries: opts.enable_raw_queries,
+            read_only: opts.read_only,
+            config: opts.configuration(true)?.subject,
+        })
+    }
+
+    /// Like `dmmf_request`, but for a datamodel other than the one the engine was started with.
+    fn dmmf_request_from_str(opts: &PrismaOpt, datamodel_str: &str) -> crate::PrismaResult<DmmfRequest> {
+        DmmfRequest::parse(
+            datamodel_str,
+            Self::build_mode(opts),
+            opts.enable_raw_queries,
+            opts.read_only,
+        )
+        .map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))
+    }
+
+    fn build_mode(opts: &PrismaOpt) -> BuildMode {
+        if opts.legacy {
+            BuildMode::Legacy
+        } else {
+            BuildMode::Modern
+        }
+    }
+
     pub async fn execute(self) -> PrismaResult<()> {
         match self {
             CliCommand::Dmmf(request) => Self::dmmf(request).await,
+            CliCommand::Sdl(request) => Self::sdl(request),
+            CliCommand::DmmfDiff(request) => Self::dmmf_diff(request),
             CliCommand::GetConfig(input) => Self::get_config(input.config),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
         }
     }
 
     async fn dmmf(request: DmmfRequest) -> PrismaResult<()> {
+        let dmmf = request.render_dmmf();
+        let serialized = serde_json::to_string_pretty(&dmmf)?;
+
+        println!("{}", serialized);
+
+        Ok(())
+    }
+
+    fn sdl(request: DmmfRequest) -> PrismaResult<()> {
+        let query_schema = Self::query_schema(&request);
+
+        println!("{}", GraphQLSchemaRenderer::render(query_schema));
+
+        Ok(())
+    }
+
+    fn dmmf_diff(request: DmmfDiffRequest) -> PrismaResult<()> {
+        let previous = request.previous.render_dmmf();
+        let next = request.next.render_dmmf();
+
+        let diff = dmmf::diff(&previous.schema, &next.schema);
+        let serialized = serde_json::to_string_pretty(&diff)?;
+
+        println!("{}", serialized);
+
+        Ok(())
+    }
+
+    /// Builds the query schema without connecting to the database.
+    fn query_schema(request: &DmmfRequest) -> QuerySchemaRef {
         let template = DatamodelConverter::convert(&request.datamodel);
 
         let capabilities = match request.config.datasources.first() {
@@ -96,19 +192,14 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
-        let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
+
+        Arc::new(schema_builder::build(
             internal_data_model,
             request.build_mode,
             request.enable_raw_queries,
+            request.read_only,
             capabilities,
-        ));
-
-        let dmmf = dmmf::render_dmmf(&request.datamodel, query_schema);
-        let serialized = serde_json::to_string_pretty(&dmmf)?;
-
-        println!("{}", serialized);
-
-        Ok(())
+        ))
     }
 
     fn get_config(config: ValidatedConfiguration) -> PrismaResult<()> {
@@ -130,12 +221,15 @@ impl CliCommand {
         )
         .legacy(request.legacy)
         .enable_raw_queries(request.enable_raw_queries)
+        .read_only(request.read_only)
+        .group_by_take_limit(request.group_by_take_limit)
+        .connect_or_create_retries(request.connect_or_create_retries)
         .build()
         .await?;
         let cx = Arc::new(cx);
 
         let body: GraphQlBody = serde_json::from_str(&decoded_request)?;
-        let res = graphql::handle(body, cx).await;
+        let res = graphql::handle(body, QueryHints::new(), cx).await;
         let res = serde_json::to_string(&res).unwrap();
 
         let encoded_response = base64::encode(&res);
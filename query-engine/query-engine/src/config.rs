@@ -0,0 +1,565 @@
+//! The configuration file of the query engine binary, passed with `--config engine.toml`.
+//!
+//! ```toml
+//! [server]
+//! host = "0.0.0.0"
+//! port = 4466
+//!
+//! [datasource]
+//! connection_limit = 10
+//! pool_timeout = 10
+//! connect_timeout = 5
+//! socket_timeout = 30
+//! statement_timeout = 5000
+//!
+//! [datasource.tls]
+//! mode = "require"
+//! root_cert = "/etc/ssl/ca.pem"
+//! accept_invalid_certs = false
+//!
+//! [log]
+//! format = "json"
+//! slow_query_threshold_ms = 500
+//!
+//! [features]
+//! experimental = ["microsoftSqlServer"]
+//! raw_queries = true
+//!
+//! [audit]
+//! stream = true
+//!
+//! [access.tokens]
+//! "secret-token" = "reporting"
+//!
+//! [[access.roles.reporting]]
+//! operations = ["findMany", "aggregate"]
+//! models = ["Order", "Product"]
+//! ```
+//!
+//! Flags and environment variables take precedence over the values of the file, and the
+//! parameters of the datasource URL take precedence over the `datasource` section.
+
+use crate::{error::PrismaError, PrismaResult};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// The sections of the file and the keys they accept.
+const KEYS: &[(&str, &[&str])] = &[
+    ("server", &["host", "port", "unix_path"]),
+    (
+        "datasource",
+        &[
+            "connection_limit",
+            "pool_timeout",
+            "connect_timeout",
+            "socket_timeout",
+            "statement_timeout",
+            "tls",
+        ],
+    ),
+    (
+        "datasource.tls",
+        &[
+            "mode",
+            "root_cert",
+            "identity",
+            "identity_password",
+            "accept_invalid_certs",
+        ],
+    ),
+    ("log", &["format", "slow_query_threshold_ms"]),
+    ("features", &["experimental", "raw_queries", "playground", "read_only"]),
+    ("audit", &["stream"]),
+    // The keys of `access.tokens` and `access.roles` are tokens and role names.
+    ("access", &["tokens", "roles"]),
+];
+
+const LOG_FORMATS: &[&str] = &["json", "devel"];
+
+/// The operation kinds access rules can name.
+const OPERATIONS: &[&str] = &[
+    "findOne",
+    "findUnique",
+    "findFirst",
+    "findMany",
+    "findRaw",
+    "createOne",
+    "updateOne",
+    "updateMany",
+    "deleteOne",
+    "deleteMany",
+    "upsertOne",
+    "aggregate",
+    "groupBy",
+    "executeRaw",
+    "queryRaw",
+    // Replacing the datamodel through the `/reload` endpoint, see `AccessRole::authorize_reload`.
+    "reload",
+    // Reading the `/tenants` and `/cache` endpoints, see `AccessRole::authorize_metrics`.
+    "metrics",
+];
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EngineConfig {
+    pub server: ServerConfig,
+    pub datasource: DatasourceConfig,
+    pub log: LogConfig,
+    pub features: FeaturesConfig,
+    pub audit: AuditConfig,
+    pub access: AccessConfig,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub unix_path: Option<String>,
+}
+
+/// Connection pool and TLS settings, added to the datasource URL as connection parameters.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DatasourceConfig {
+    pub connection_limit: Option<u32>,
+    /// Seconds to wait for a connection from the pool.
+    pub pool_timeout: Option<u64>,
+    /// Seconds to wait for a new connection to be opened.
+    pub connect_timeout: Option<u64>,
+    /// Seconds to wait for a query to respond.
+    pub socket_timeout: Option<u64>,
+    /// Milliseconds a statement may run before the database cancels it. Not supported on SQL
+    /// Server and SQLite. MySQL and MariaDB do not limit raw queries, MySQL limits `SELECT`
+    /// statements only.
+    pub statement_timeout: Option<u64>,
+    pub tls: TlsConfig,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct TlsConfig {
+    pub mode: Option<TlsMode>,
+    /// Path to the certificate of the certificate authority.
+    pub root_cert: Option<String>,
+    /// Path to the client identity, a PKCS #12 file.
+    pub identity: Option<String>,
+    pub identity_password: Option<String>,
+    pub accept_invalid_certs: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TlsMode {
+    Disable,
+    Prefer,
+    Require,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct LogConfig {
+    /// `json` or `devel`.
+    pub format: Option<String>,
+    pub slow_query_threshold_ms: Option<u64>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct FeaturesConfig {
+    pub experimental: Vec<String>,
+    pub raw_queries: Option<bool>,
+    pub playground: Option<bool>,
+    pub read_only: Option<bool>,
+}
+
+/// Where the creates, updates and deletes of the engine are recorded, unless the datasource enables
+/// `auditLog`, which records them in the `_prisma_audit_log` table.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// Log the entries as `prisma::audit` events.
+    pub stream: Option<bool>,
+}
+
+/// The operations the requests of each role may run. Requests carry a token that names their role
+/// in the `Authorization: Bearer <token>` header. Without roles, all requests may run all
+/// operations.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct AccessConfig {
+    /// Token -> role name.
+    pub tokens: BTreeMap<String, String>,
+    /// Role name -> the rules of the role. An operation is allowed if a rule allows it.
+    pub roles: BTreeMap<String, Vec<AccessRule>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccessRule {
+    /// Operation kinds, e.g. `findMany`, `executeRaw` or `reload`, or `*` for all.
+    pub operations: Vec<String>,
+    /// Model names, or `*` for all models and raw queries.
+    pub models: Vec<String>,
+}
+
+impl EngineConfig {
+    pub fn load(path: &Path) -> PrismaResult<Self> {
+        let contents = fs::read_to_string(path).map_err(|err| {
+            PrismaError::ConfigurationError(format!(
+                "Could not read the configuration file {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+
+        Self::parse(&contents).map_err(|errors| {
+            PrismaError::ConfigurationError(format!(
+                "Invalid configuration file {}:\n{}",
+                path.display(),
+                errors
+                    .iter()
+                    .map(|error| format!("  - {}", error))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ))
+        })
+    }
+
+    /// Parses and validates the contents of a configuration file. All offending keys are
+    /// reported, not only the first one.
+    pub fn parse(contents: &str) -> Result<Self, Vec<String>> {
+        let value: toml::Value = toml::from_str(contents).map_err(|err| vec![err.to_string()])?;
+        let mut errors = Vec::new();
+
+        unknown_keys(&value, "", &mut errors);
+
+        let config = EngineConfig {
+            server: section(&value, "server", &mut errors),
+            datasource: section(&value, "datasource", &mut errors),
+            log: section(&value, "log", &mut errors),
+            features: section(&value, "features", &mut errors),
+            audit: section(&value, "audit", &mut errors),
+            access: section(&value, "access", &mut errors),
+        };
+
+        if let Some(format) = &config.log.format {
+            if !LOG_FORMATS.contains(&format.as_str()) {
+                errors.push(format!(
+                    "`log.format`: unknown format `{}`, expected one of {}",
+                    format,
+                    LOG_FORMATS.join(", ")
+                ));
+            }
+        }
+
+        if config.datasource.connection_limit == Some(0) {
+            errors.push(String::from("`datasource.connection_limit`: must be at least 1"));
+        }
+
+        if config.datasource.tls.identity_password.is_some() && config.datasource.tls.identity.is_none() {
+            errors.push(String::from(
+                "`datasource.tls.identity_password`: requires `datasource.tls.identity`",
+            ));
+        }
+
+        for (token, role) in &config.access.tokens {
+            if !config.access.roles.contains_key(role) {
+                errors.push(format!("`access.tokens.{}`: unknown role `{}`", token, role));
+            }
+        }
+
+        for (role, rules) in &config.access.roles {
+            for operation in rules.iter().flat_map(|rule| rule.operations.iter()) {
+                if operation != "*" && !OPERATIONS.contains(&operation.as_str()) {
+                    errors.push(format!(
+                        "`access.roles.{}`: unknown operation `{}`, expected `*` or one of {}",
+                        role,
+                        operation,
+                        OPERATIONS.join(", ")
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl DatasourceConfig {
+    /// Adds the configured connection parameters to a datasource URL, unless the URL already
+    /// sets them.
+    pub fn apply_to_url(&self, url: &str) -> String {
+        let is_mssql = url.starts_with("sqlserver:");
+        let is_sqlite = url.starts_with("file:");
+        let is_postgres = url.starts_with("postgres:") || url.starts_with("postgresql:");
+        let is_mysql = url.starts_with("mysql:");
+
+        // SQL Server connection strings name their parameters in camel case.
+        let name = |name: &'static str, mssql_name: &'static str| if is_mssql { mssql_name } else { name };
+        let tls = &self.tls;
+
+        let mut params: Vec<(&str, Option<String>)> = vec![
+            (
+                name("connection_limit", "connectionLimit"),
+                self.connection_limit.map(|limit| limit.to_string()),
+            ),
+            (
+                name("socket_timeout", "socketTimeout"),
+                self.socket_timeout.map(|timeout| timeout.to_string()),
+            ),
+        ];
+
+        if !is_sqlite {
+            params.push((
+                name("pool_timeout", "poolTimeout"),
+                self.pool_timeout.map(|timeout| timeout.to_string()),
+            ));
+            params.push((
+                name("connect_timeout", "connectTimeout"),
+                self.connect_timeout.map(|timeout| timeout.to_string()),
+            ));
+        }
+
+        if is_postgres || is_mysql {
+            params.push((
+                "statement_timeout",
+                self.statement_timeout.map(|timeout| timeout.to_string()),
+            ));
+        }
+
+        if is_mssql {
+            params.push(("encrypt", tls.mode.map(|mode| (mode != TlsMode::Disable).to_string())));
+            params.push((
+                "trustServerCertificate",
+                tls.accept_invalid_certs.map(|accept| accept.to_string()),
+            ));
+        } else if !is_sqlite {
+            if is_postgres {
+                params.push(("sslmode", tls.mode.map(|mode| tls_mode_name(mode).to_owned())));
+            }
+
+            let accept = tls.accept_invalid_certs.map(|accept| match accept {
+                true => String::from("accept_invalid_certs"),
+                false => String::from("strict"),
+            });
+
+            params.push(("sslcert", tls.root_cert.clone()));
+            params.push(("sslidentity", tls.identity.clone()));
+            params.push(("sslpassword", tls.identity_password.clone()));
+            params.push(("sslaccept", accept));
+        }
+
+        let mut url = url.to_owned();
+
+        for (name, value) in params
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+        {
+            if is_mssql {
+                if !url.contains(&format!(";{}=", name)) {
+                    url.push_str(&format!(";{}={}", name, value));
+                }
+            } else if !has_query_param(&url, name) {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                let value = utf8_percent_encode(&value, NON_ALPHANUMERIC);
+
+                url.push_str(&format!("{}{}={}", separator, name, value));
+            }
+        }
+
+        url
+    }
+}
+
+fn tls_mode_name(mode: TlsMode) -> &'static str {
+    match mode {
+        TlsMode::Disable => "disable",
+        TlsMode::Prefer => "prefer",
+        TlsMode::Require => "require",
+    }
+}
+
+fn has_query_param(url: &str, name: &str) -> bool {
+    url.splitn(2, '?')
+        .nth(1)
+        .map(|query| query.split('&').any(|pair| pair.splitn(2, '=').next() == Some(name)))
+        .unwrap_or(false)
+}
+
+fn unknown_keys(value: &toml::Value, path: &str, errors: &mut Vec<String>) {
+    let table = match value.as_table() {
+        Some(table) => table,
+        None => return,
+    };
+
+    for (key, value) in table {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+
+        let known = if path.is_empty() {
+            KEYS.iter().any(|(section, _)| section == key)
+        } else {
+            KEYS.iter()
+                .any(|(section, keys)| *section == path && keys.contains(&key.as_str()))
+        };
+
+        if !known {
+            errors.push(format!("`{}`: unknown key", key_path));
+        } else if KEYS.iter().any(|(section, _)| *section == key_path) {
+            unknown_keys(value, &key_path, errors);
+        }
+    }
+}
+
+fn section<T: DeserializeOwned + Default>(value: &toml::Value, name: &str, errors: &mut Vec<String>) -> T {
+    match value.get(name) {
+        Some(section) => section.clone().try_into().unwrap_or_else(|err| {
+            errors.push(format!("`{}`: {}", name, err));
+            T::default()
+        }),
+        None => T::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_sections() {
+        let config = EngineConfig::parse(
+            r#"
+            [server]
+            port = 5000
+
+            [datasource]
+            connection_limit = 4
+
+            [datasource.tls]
+            mode = "require"
+
+            [log]
+            format = "devel"
+
+            [features]
+            experimental = ["all"]
+
+            [audit]
+            stream = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, Some(5000));
+        assert_eq!(config.datasource.connection_limit, Some(4));
+        assert_eq!(config.datasource.tls.mode, Some(TlsMode::Require));
+        assert_eq!(config.log.format.as_deref(), Some("devel"));
+        assert_eq!(config.features.experimental, vec!["all"]);
+        assert_eq!(config.audit.stream, Some(true));
+    }
+
+    #[test]
+    fn reports_all_offending_keys() {
+        let errors = EngineConfig::parse(
+            r#"
+            [server]
+            prot = 5000
+
+            [datasource]
+            connection_limit = 0
+
+            [log]
+            format = "xml"
+
+            [metrics]
+            enabled = true
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 4);
+        assert!(errors.iter().any(|error| error.starts_with("`server.prot`")));
+        assert!(errors
+            .iter()
+            .any(|error| error.starts_with("`datasource.connection_limit`")));
+        assert!(errors.iter().any(|error| error.starts_with("`log.format`")));
+        assert!(errors.iter().any(|error| error.starts_with("`metrics`")));
+    }
+
+    #[test]
+    fn parses_access_roles() {
+        let config = EngineConfig::parse(
+            r#"
+            [access.tokens]
+            "token-1" = "reporting"
+
+            [[access.roles.reporting]]
+            operations = ["findMany", "aggregate"]
+            models = ["Order"]
+
+            [[access.roles.admin]]
+            operations = ["*"]
+            models = ["*"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.access.tokens["token-1"], "reporting");
+        assert_eq!(config.access.roles.len(), 2);
+        assert_eq!(
+            config.access.roles["reporting"],
+            vec![AccessRule {
+                operations: vec!["findMany".to_owned(), "aggregate".to_owned()],
+                models: vec!["Order".to_owned()],
+            }]
+        );
+
+        let errors = EngineConfig::parse(
+            r#"
+            [access.tokens]
+            "token-1" = "auditor"
+
+            [[access.roles.reporting]]
+            operations = ["findAll"]
+            models = ["Order"]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|error| error.starts_with("`access.tokens.token-1`")));
+        assert!(errors.iter().any(|error| error.starts_with("`access.roles.reporting`")));
+    }
+
+    #[test]
+    fn adds_missing_parameters_to_datasource_urls() {
+        let config = DatasourceConfig {
+            connection_limit: Some(4),
+            pool_timeout: Some(10),
+            statement_timeout: Some(5000),
+            tls: TlsConfig {
+                mode: Some(TlsMode::Require),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.apply_to_url("postgresql://localhost/db?connection_limit=1"),
+            "postgresql://localhost/db?connection_limit=1&pool_timeout=10&statement_timeout=5000&sslmode=require"
+        );
+        assert_eq!(
+            config.apply_to_url("sqlserver://localhost:1433;database=db"),
+            "sqlserver://localhost:1433;database=db;connectionLimit=4;poolTimeout=10;encrypt=true"
+        );
+        assert_eq!(config.apply_to_url("file:./dev.db"), "file:./dev.db?connection_limit=4");
+    }
+}
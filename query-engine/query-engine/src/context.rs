@@ -1,18 +1,37 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use crate::{audit, exec_loader, PrismaError, PrismaResult};
+use datamodel::{Configuration, Datamodel, Datasource, ReferentialIntegrity};
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
+use query_core::{
+    schema::QuerySchemaRef, schema_builder, AuditSinkRef, BuildMode, FieldEncryptionRef, QueryCache, QueryCacheConfig,
+    QueryCacheRef, QueryExecutor, ValueTransformerRef,
+};
 use std::{fmt, sync::Arc};
 
 /// Prisma request context containing all immutable state of the process.
-/// There is usually only one context initialized per process.
+/// There is usually only one context initialized per process. Reloading the datamodel creates a
+/// new context that shares the executor, and with it the connection pool, of the previous one.
 pub struct PrismaContext {
     /// The api query schema.
     query_schema: QuerySchemaRef,
     /// DML-based v2 datamodel.
     dm: Datamodel,
     /// Central query executor.
-    pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    pub executor: Arc<dyn QueryExecutor + Send + Sync + 'static>,
+    /// The data source the executor is connected to.
+    data_source: Arc<Datasource>,
+    /// The name of the database the executor is connected to.
+    db_name: String,
+    /// Whether the database enforces foreign keys, see `Connector::enforces_foreign_keys`.
+    enforces_foreign_keys: bool,
+    /// The response cache of the executor, if enabled.
+    query_cache: Option<QueryCacheRef>,
+    /// The sink the audit entries are handed to, see `ContextBuilder::audit_sink`.
+    audit_sink: Option<AuditSinkRef>,
+    /// See `ContextBuilder::value_transformer`.
+    value_transformers: Vec<ValueTransformerRef>,
+    /// See `ContextBuilder::field_encryption`.
+    field_encryption: Option<FieldEncryptionRef>,
+    options: ContextOptions,
 }
 
 impl fmt::Debug for PrismaContext {
@@ -21,59 +40,139 @@ impl fmt::Debug for PrismaContext {
     }
 }
 
-pub struct ContextBuilder {
+/// The options the query schema is built with.
+#[derive(Debug, Clone, Copy, Default)]
+struct ContextOptions {
     legacy: bool,
     enable_raw_queries: bool,
+    read_only: bool,
+    group_by_take_limit: Option<u32>,
+    connect_or_create_retries: Option<u32>,
+    query_cache: Option<QueryCacheConfig>,
+    audit_stream: bool,
+}
+
+pub struct ContextBuilder {
+    options: ContextOptions,
+    audit_sink: Option<AuditSinkRef>,
+    value_transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
     datamodel: Datamodel,
     config: Configuration,
 }
 
 impl ContextBuilder {
     pub fn legacy(mut self, val: bool) -> Self {
-        self.legacy = val;
+        self.options.legacy = val;
         self
     }
 
     pub fn enable_raw_queries(mut self, val: bool) -> Self {
-        self.enable_raw_queries = val;
+        self.options.enable_raw_queries = val;
+        self
+    }
+
+    pub fn read_only(mut self, val: bool) -> Self {
+        self.options.read_only = val;
+        self
+    }
+
+    pub fn group_by_take_limit(mut self, val: Option<u32>) -> Self {
+        self.options.group_by_take_limit = val;
+        self
+    }
+
+    pub fn connect_or_create_retries(mut self, val: Option<u32>) -> Self {
+        self.options.connect_or_create_retries = val;
+        self
+    }
+
+    pub fn query_cache(mut self, val: Option<QueryCacheConfig>) -> Self {
+        self.options.query_cache = val;
+        self
+    }
+
+    pub fn audit_stream(mut self, val: bool) -> Self {
+        self.options.audit_stream = val;
+        self
+    }
+
+    /// Hands the audit entries to `sink` instead of logging them, unless the datasource enables
+    /// the `_prisma_audit_log` table.
+    pub fn audit_sink(mut self, sink: AuditSinkRef) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// Registers a transformer for the queries and results of all operations, applied in the
+    /// order of registration, see `ValueTransformer`.
+    pub fn value_transformer(mut self, transformer: ValueTransformerRef) -> Self {
+        self.value_transformers.push(transformer);
+        self
+    }
+
+    /// Registers the hooks that encrypt and decrypt the values of fields marked with `@encrypted`,
+    /// see `FieldEncryption`.
+    pub fn field_encryption(mut self, field_encryption: FieldEncryptionRef) -> Self {
+        self.field_encryption = Some(field_encryption);
         self
     }
 
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.options,
+            self.audit_sink,
+            self.value_transformers,
+            self.field_encryption,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        let template = DatamodelConverter::convert(&dm);
-
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        options: ContextOptions,
+        audit_sink: Option<AuditSinkRef>,
+        value_transformers: Vec<ValueTransformerRef>,
+        field_encryption: Option<FieldEncryptionRef>,
+    ) -> PrismaResult<Self> {
         // We only support one data source at the moment, so take the first one (default not exposed yet).
         let data_source = config
             .datasources
-            .first()
+            .into_iter()
+            .next()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
         // Load executor
-        let (db_name, executor) = exec_loader::load(&data_source).await?;
-
-        // Build internal data model
-        let internal_data_model = template.build(db_name);
-
-        // Construct query schema
-        let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
-            internal_data_model,
-            build_mode,
-            enable_raw_queries,
-            data_source.capabilities(),
-        ));
+        let query_cache = options.query_cache.map(|config| Arc::new(QueryCache::new(config)));
+        let audit_log = audit::audit_log(&data_source, options.audit_stream, audit_sink.clone());
+        let (db_name, executor) = exec_loader::load(
+            &data_source,
+            query_cache.clone(),
+            audit_log,
+            value_transformers.clone(),
+            field_encryption.clone(),
+        )
+        .await?;
+        let enforces_foreign_keys = executor.primary_connector().enforces_foreign_keys().await?;
 
         let context = Self {
-            query_schema,
+            query_schema: build_query_schema(&dm, &data_source, &db_name, enforces_foreign_keys, options),
             dm,
-            executor,
+            executor: Arc::from(executor),
+            data_source: Arc::new(data_source),
+            db_name,
+            enforces_foreign_keys,
+            query_cache,
+            audit_sink,
+            value_transformers,
+            field_encryption,
+            options,
         };
 
         context.verify_connection().await?;
@@ -81,6 +180,62 @@ impl PrismaContext {
         Ok(context)
     }
 
+    /// Creates a context for a new datamodel, with the executor of this one. Only the models and
+    /// enums can change: the datasource of the new schema must have the same provider, and its
+    /// URL is ignored because the existing connection pool is kept.
+    pub fn reload(&self, config: &Configuration, dm: Datamodel) -> PrismaResult<Self> {
+        match config.datasources.first() {
+            Some(data_source) if data_source.active_provider == self.data_source.active_provider => (),
+            _ => {
+                return Err(PrismaError::ConfigurationError(format!(
+                    "The reloaded datamodel must use the `{}` provider of the running engine. Changing the datasource requires a restart.",
+                    self.data_source.active_provider
+                )))
+            }
+        }
+
+        // Responses of the previous datamodel can have a different shape.
+        if let Some(query_cache) = &self.query_cache {
+            query_cache.clear();
+        }
+
+        Ok(Self {
+            query_schema: build_query_schema(
+                &dm,
+                &self.data_source,
+                &self.db_name,
+                self.enforces_foreign_keys,
+                self.options,
+            ),
+            dm,
+            executor: self.executor.clone(),
+            data_source: self.data_source.clone(),
+            db_name: self.db_name.clone(),
+            enforces_foreign_keys: self.enforces_foreign_keys,
+            query_cache: self.query_cache.clone(),
+            audit_sink: self.audit_sink.clone(),
+            value_transformers: self.value_transformers.clone(),
+            field_encryption: self.field_encryption.clone(),
+            options: self.options,
+        })
+    }
+
+    /// Creates a context with the datamodel and options of this one, connected to the datasource
+    /// of the given configuration, e.g. the database of another tenant. The new context gets its
+    /// own query cache with the configuration of this one, so that the responses read from one
+    /// database are never served to the requests of another.
+    pub async fn with_datasource(&self, config: Configuration) -> PrismaResult<Self> {
+        Self::new(
+            config,
+            self.dm.clone(),
+            self.options,
+            self.audit_sink.clone(),
+            self.value_transformers.clone(),
+            self.field_encryption.clone(),
+        )
+        .await
+    }
+
     async fn verify_connection(&self) -> PrismaResult<()> {
         self.executor.primary_connector().get_connection().await?;
         Ok(())
@@ -88,8 +243,10 @@ impl PrismaContext {
 
     pub fn builder(config: Configuration, datamodel: Datamodel) -> ContextBuilder {
         ContextBuilder {
-            legacy: false,
-            enable_raw_queries: false,
+            options: ContextOptions::default(),
+            audit_sink: None,
+            value_transformers: Vec::new(),
+            field_encryption: None,
             datamodel,
             config,
         }
@@ -103,7 +260,51 @@ impl PrismaContext {
         &self.dm
     }
 
+    /// The response cache of the executor, if enabled.
+    pub fn query_cache(&self) -> Option<&QueryCacheRef> {
+        self.query_cache.as_ref()
+    }
+
     pub fn primary_connector(&self) -> String {
         self.executor.primary_connector().name()
     }
 }
+
+/// Builds the internal data model and the query schema of a datamodel.
+fn build_query_schema(
+    dm: &Datamodel,
+    data_source: &Datasource,
+    db_name: &str,
+    enforces_foreign_keys: bool,
+    options: ContextOptions,
+) -> QuerySchemaRef {
+    let mut template = DatamodelConverter::convert(dm);
+
+    template.provider = data_source.active_provider.clone();
+    template.date_time_mode = data_source.date_time_mode;
+    template.referential_integrity = if enforces_foreign_keys {
+        data_source.referential_integrity
+    } else {
+        ReferentialIntegrity::Prisma
+    };
+    template.database_timestamps = data_source.database_timestamps;
+
+    let internal_data_model = template.build(db_name.to_owned());
+    let build_mode = if options.legacy {
+        BuildMode::Legacy
+    } else {
+        BuildMode::Modern
+    };
+
+    Arc::new(
+        schema_builder::build(
+            internal_data_model,
+            build_mode,
+            options.enable_raw_queries,
+            options.read_only,
+            data_source.capabilities(),
+        )
+        .with_group_by_take_limit(options.group_by_take_limit.map(i64::from))
+        .with_connect_or_create_retries(options.connect_or_create_retries),
+    )
+}
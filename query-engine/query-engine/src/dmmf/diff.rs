@@ -0,0 +1,225 @@
+use super::schema::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Structural diff between two DMMF schemas, e.g. to detect breaking API changes in CI.
+///
+/// A change is considered breaking if a client written against the previous schema can fail
+/// against the next one: removed types, fields or enum values, changed field signatures, and
+/// new required input fields.
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DmmfDiff {
+    pub breaking: bool,
+    pub input_object_types: TypeChanges,
+    pub output_object_types: TypeChanges,
+    pub enum_types: TypeChanges,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<TypeChange>,
+}
+
+/// Changes to the fields of a type. For enums, the fields are the enum values.
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeChange {
+    pub name: String,
+    pub breaking: bool,
+    pub added_fields: Vec<String>,
+    pub removed_fields: Vec<String>,
+    pub changed_fields: Vec<String>,
+}
+
+/// A field reduced to what the diff compares.
+struct FieldSignature {
+    signature: String,
+    is_required: bool,
+}
+
+pub fn diff(previous: &DmmfSchema, next: &DmmfSchema) -> DmmfDiff {
+    let input_object_types = diff_types(
+        input_types(&previous.input_object_types),
+        input_types(&next.input_object_types),
+        true,
+    );
+
+    let output_object_types = diff_types(
+        output_types(&previous.output_object_types),
+        output_types(&next.output_object_types),
+        false,
+    );
+
+    let enum_types = diff_types(enums(&previous.enum_types), enums(&next.enum_types), false);
+
+    let breaking = [&input_object_types, &output_object_types, &enum_types]
+        .iter()
+        .any(|changes| !changes.removed.is_empty() || changes.changed.iter().any(|change| change.breaking));
+
+    DmmfDiff {
+        breaking,
+        input_object_types,
+        output_object_types,
+        enum_types,
+    }
+}
+
+/// Matches types by name and compares their fields by signature. Added fields only break
+/// clients if they are required input fields.
+fn diff_types(
+    previous: BTreeMap<String, BTreeMap<String, FieldSignature>>,
+    mut next: BTreeMap<String, BTreeMap<String, FieldSignature>>,
+    is_input: bool,
+) -> TypeChanges {
+    let mut changes = TypeChanges::default();
+
+    for (name, previous_fields) in previous {
+        let mut next_fields = match next.remove(&name) {
+            Some(fields) => fields,
+            None => {
+                changes.removed.push(name);
+                continue;
+            }
+        };
+
+        let mut change = TypeChange {
+            name,
+            ..Default::default()
+        };
+
+        for (field_name, previous_field) in previous_fields {
+            match next_fields.remove(&field_name) {
+                Some(next_field) if next_field.signature != previous_field.signature => {
+                    change.changed_fields.push(field_name)
+                }
+                Some(_) => (),
+                None => change.removed_fields.push(field_name),
+            }
+        }
+
+        for (field_name, next_field) in next_fields {
+            change.breaking |= is_input && next_field.is_required;
+            change.added_fields.push(field_name);
+        }
+
+        change.breaking |= !change.removed_fields.is_empty() || !change.changed_fields.is_empty();
+
+        if !(change.added_fields.is_empty() && change.removed_fields.is_empty() && change.changed_fields.is_empty()) {
+            changes.changed.push(change);
+        }
+    }
+
+    changes.added = next.into_iter().map(|(name, _)| name).collect();
+    changes
+}
+
+fn input_types(types: &BTreeMap<String, Vec<DmmfInputType>>) -> BTreeMap<String, BTreeMap<String, FieldSignature>> {
+    types
+        .values()
+        .flatten()
+        .map(|typ| {
+            let fields = typ
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), input_field_signature(field)))
+                .collect();
+
+            (typ.name.clone(), fields)
+        })
+        .collect()
+}
+
+fn output_types(types: &BTreeMap<String, Vec<DmmfOutputType>>) -> BTreeMap<String, BTreeMap<String, FieldSignature>> {
+    types
+        .values()
+        .flatten()
+        .map(|typ| {
+            let fields = typ
+                .fields
+                .iter()
+                .map(|field| {
+                    let args: Vec<String> = field
+                        .args
+                        .iter()
+                        .map(|arg| format!("{}: {}", arg.name, input_field_signature(arg).signature))
+                        .collect();
+
+                    let signature = format!(
+                        "({}) {}{}",
+                        args.join(", "),
+                        type_reference(&field.output_type),
+                        nullability(field.is_required, field.is_nullable)
+                    );
+
+                    (
+                        field.name.clone(),
+                        FieldSignature {
+                            signature,
+                            is_required: field.is_required,
+                        },
+                    )
+                })
+                .collect();
+
+            (typ.name.clone(), fields)
+        })
+        .collect()
+}
+
+fn enums(types: &BTreeMap<String, Vec<DmmfEnum>>) -> BTreeMap<String, BTreeMap<String, FieldSignature>> {
+    types
+        .values()
+        .flatten()
+        .map(|typ| {
+            let values = typ
+                .values
+                .iter()
+                .map(|value| {
+                    let signature = FieldSignature {
+                        signature: value.clone(),
+                        is_required: false,
+                    };
+
+                    (value.clone(), signature)
+                })
+                .collect();
+
+            (typ.name.clone(), values)
+        })
+        .collect()
+}
+
+fn input_field_signature(field: &DmmfInputField) -> FieldSignature {
+    let mut types: Vec<String> = field.input_types.iter().map(type_reference).collect();
+    types.sort();
+
+    FieldSignature {
+        signature: format!(
+            "{}{}",
+            types.join(" | "),
+            nullability(field.is_required, field.is_nullable)
+        ),
+        is_required: field.is_required,
+    }
+}
+
+fn type_reference(reference: &DmmfTypeReference) -> String {
+    if reference.is_list {
+        format!("[{}]", reference.typ)
+    } else {
+        reference.typ.clone()
+    }
+}
+
+fn nullability(is_required: bool, is_nullable: bool) -> &'static str {
+    match (is_required, is_nullable) {
+        (true, false) => "!",
+        (true, true) => "! (nullable)",
+        (false, false) => "",
+        (false, true) => " (nullable)",
+    }
+}
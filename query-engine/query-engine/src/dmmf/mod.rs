@@ -1,5 +1,8 @@
+mod diff;
 mod schema;
 
+pub use diff::{diff, DmmfDiff};
+
 use query_core::schema::{QuerySchemaRef, QuerySchemaRenderer};
 use schema::*;
 use serde::{ser::SerializeMap, Serialize, Serializer};
@@ -23,6 +26,16 @@ pub struct DataModelMetaFormat {
 pub struct DmmfOperationMappings {
     pub model_operations: Vec<DmmfModelOperations>,
     pub other_operations: DmmfNonModelOperations,
+    pub implicit_order_by: Vec<DmmfImplicitOrderBy>,
+}
+
+/// Fields the engine appends (ascending) to the orderBy of paginated reads of a model if the requested
+/// ordering is not unique, e.g. `{ createdAt: desc }` is effectively sorted as `[{ createdAt: desc }, { id: asc }]`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DmmfImplicitOrderBy {
+    pub model: String,
+    pub fields: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Default)]
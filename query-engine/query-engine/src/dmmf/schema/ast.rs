@@ -1,14 +1,16 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use query_core::Deprecation;
 use serde::{Deserialize, Serialize};
 
+/// The types of the query schema by namespace. The namespaces and the types of a namespace are sorted
+/// by name, so the DMMF of a datamodel is byte-stable.
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfSchema {
-    pub input_object_types: HashMap<String, Vec<DmmfInputType>>,
-    pub output_object_types: HashMap<String, Vec<DmmfOutputType>>,
-    pub enum_types: HashMap<String, Vec<DmmfEnum>>,
+    pub input_object_types: BTreeMap<String, Vec<DmmfInputType>>,
+    pub output_object_types: BTreeMap<String, Vec<DmmfOutputType>>,
+    pub enum_types: BTreeMap<String, Vec<DmmfEnum>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,6 +39,8 @@ pub struct DmmfInputType {
 pub struct DmmfInputTypeConstraints {
     pub max_num_fields: Option<usize>,
     pub min_num_fields: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -6,14 +6,14 @@ mod object_renderer;
 mod schema_renderer;
 mod type_renderer;
 
-use super::{DmmfModelOperations, DmmfOperationMappings};
+use super::{DmmfImplicitOrderBy, DmmfModelOperations, DmmfOperationMappings};
 use enum_renderer::*;
 use field_renderer::*;
 use object_renderer::*;
 use query_core::schema::*;
 use schema_renderer::*;
 use std::{
-    collections::{hash_map::Entry, HashSet},
+    collections::{btree_map::Entry, HashSet},
     sync::{Arc, Weak},
 };
 use type_renderer::*;
@@ -65,7 +65,21 @@ impl RenderContext {
         }
     }
 
-    pub fn finalize(self) -> (DmmfSchema, DmmfOperationMappings) {
+    pub fn finalize(mut self) -> (DmmfSchema, DmmfOperationMappings) {
+        // Types are rendered in the order they are reached from the root types, which changes with
+        // unrelated changes of the datamodel.
+        for types in self.schema.input_object_types.values_mut() {
+            types.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        for types in self.schema.output_object_types.values_mut() {
+            types.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        for enums in self.schema.enum_types.values_mut() {
+            enums.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
         (self.schema, self.mappings)
     }
 
@@ -131,6 +145,10 @@ impl RenderContext {
 
                         new_mapping.add_operation(tag_str, name.clone());
                         self.mappings.model_operations.push(new_mapping);
+                        self.mappings.implicit_order_by.push(DmmfImplicitOrderBy {
+                            model: model.name.clone(),
+                            fields: model.primary_identifier().names().map(ToOwned::to_owned).collect(),
+                        });
                     }
                 };
             } else {
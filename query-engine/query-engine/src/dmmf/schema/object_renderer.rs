@@ -38,6 +38,7 @@ impl DmmfObjectRenderer {
             constraints: DmmfInputTypeConstraints {
                 max_num_fields: input_object.constraints.max_num_fields,
                 min_num_fields: input_object.constraints.min_num_fields,
+                fields: input_object.constraints.fields.clone(),
             },
             fields: rendered_fields,
         };
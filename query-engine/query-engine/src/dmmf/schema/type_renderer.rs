@@ -54,7 +54,7 @@ pub(super) fn render_output_type(output_type: &OutputType, ctx: &mut RenderConte
                 ScalarType::Null => "Null",
                 ScalarType::String => "String",
                 ScalarType::Int => "Int",
-                ScalarType::BigInt => "BigInt",
+                ScalarType::BigInt | ScalarType::UnsignedBigInt => "BigInt",
                 ScalarType::Boolean => "Boolean",
                 ScalarType::Float => "Float",
                 ScalarType::Decimal => "Decimal",
@@ -64,6 +64,7 @@ pub(super) fn render_output_type(output_type: &OutputType, ctx: &mut RenderConte
                 ScalarType::JsonList => "Json",
                 ScalarType::Xml => "Xml",
                 ScalarType::Bytes => "Bytes",
+                ScalarType::Interval => "Interval",
                 ScalarType::Enum(_) => unreachable!(), // Handled separately above.
             };
 
@@ -138,7 +139,7 @@ pub(super) fn render_input_type(input_type: &InputType, ctx: &mut RenderContext)
                 ScalarType::Null => "Null",
                 ScalarType::String => "String",
                 ScalarType::Int => "Int",
-                ScalarType::BigInt => "BigInt",
+                ScalarType::BigInt | ScalarType::UnsignedBigInt => "BigInt",
                 ScalarType::Boolean => "Boolean",
                 ScalarType::Float => "Float",
                 ScalarType::Decimal => "Decimal",
@@ -148,6 +149,7 @@ pub(super) fn render_input_type(input_type: &InputType, ctx: &mut RenderContext)
                 ScalarType::JsonList => "Json",
                 ScalarType::Xml => "Xml",
                 ScalarType::Bytes => "Bytes",
+                ScalarType::Interval => "Interval",
                 ScalarType::Enum(_) => unreachable!(), // Handled separately above.
             };
 
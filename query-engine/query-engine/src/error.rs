@@ -44,6 +44,10 @@ pub enum PrismaError {
 
     #[error("{}", _0)]
     FeatureError(String),
+
+    /// The request is not allowed to run an operation, see `config::AccessConfig`.
+    #[error("{}", _0.message)]
+    AccessDenied(user_facing_errors::KnownError),
 }
 
 impl From<PrismaError> for user_facing_errors::Error {
@@ -55,12 +59,16 @@ impl From<PrismaError> for user_facing_errors::Error {
                 user_facing_error: Some(err),
                 ..
             }) => err.into(),
+            PrismaError::AccessDenied(err) => err.into(),
             PrismaError::ConversionError(errors, dml_string) => {
                 let mut full_error = errors.to_pretty_string("schema.prisma", &dml_string);
                 write!(full_error, "\nValidation Error Count: {}", errors.errors.len()).unwrap();
 
                 user_facing_errors::Error::from(user_facing_errors::KnownError::new(
-                    user_facing_errors::common::SchemaParserError { full_error },
+                    user_facing_errors::common::SchemaParserError {
+                        full_error,
+                        diagnostics: serde_json::to_value(errors.to_structured()).unwrap(),
+                    },
                 ))
             }
             other => user_facing_errors::Error::new_non_panic_with_current_backtrace(other.to_string()),
@@ -69,7 +77,7 @@ impl From<PrismaError> for user_facing_errors::Error {
 }
 
 impl PrismaError {
-    pub(crate) fn render_as_json(self) -> Result<(), anyhow::Error> {
+    pub fn render_as_json(self) -> Result<(), anyhow::Error> {
         use std::io::Write as _;
 
         let error = user_facing_errors::Error::from(self);
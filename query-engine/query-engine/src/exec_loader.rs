@@ -7,23 +7,32 @@ use datamodel::{
     common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Datasource,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryCacheRef, QueryExecutor},
+    AuditLog, FieldEncryptionRef, ValueTransformerRef,
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
 #[cfg(feature = "sql")]
 use sql_connector::*;
 
-pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+pub async fn load(
+    source: &Datasource,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     match source.active_provider.as_str() {
         #[cfg(feature = "sql")]
-        SQLITE_SOURCE_NAME => sqlite(source).await,
+        SQLITE_SOURCE_NAME => sqlite(source, cache, audit_log, transformers, field_encryption).await,
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => mysql(source, cache, audit_log, transformers, field_encryption).await,
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => postgres(source, cache, audit_log, transformers, field_encryption).await,
 
         #[cfg(feature = "sql")]
         MSSQL_SOURCE_NAME => {
@@ -35,7 +44,7 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
                 return Err(PrismaError::CoreError(error));
             }
 
-            mssql(source).await
+            mssql(source, cache, audit_log, transformers, field_encryption).await
         }
 
         x => Err(PrismaError::ConfigurationError(format!(
@@ -46,7 +55,13 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
 }
 
 #[cfg(feature = "sql")]
-async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn sqlite(
+    source: &Datasource,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
 
     let sqlite = Sqlite::from_source(source).await?;
@@ -54,17 +69,25 @@ async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecu
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor(sqlite, false)))
+    Ok((
+        db_name,
+        sql_executor(sqlite, false, cache, audit_log, transformers, field_encryption),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn postgres(
+    source: &Datasource,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading Postgres connector...");
 
-    let database_str = &source.url().value;
     let psql = PostgreSql::from_source(source).await?;
 
-    let url = Url::parse(database_str)?;
+    let url = Url::parse(&database_url(source)?)?;
     let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
 
     let db_name = params
@@ -78,17 +101,32 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or(false);
 
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((
+        db_name,
+        sql_executor(
+            psql,
+            force_transactions,
+            cache,
+            audit_log,
+            transformers,
+            field_encryption,
+        ),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mysql(
+    source: &Datasource,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
-    let database_str = &source.url().value;
 
-    let url = Url::parse(database_str)?;
+    let url = Url::parse(&database_url(source)?)?;
     let err_str = "No database found in connection string";
 
     let mut db_name = url
@@ -98,11 +136,20 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = db_name.next().expect(err_str).to_owned();
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((
+        db_name,
+        sql_executor(mysql, false, cache, audit_log, transformers, field_encryption),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mssql(
+    source: &Datasource,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQL Server connector...");
 
     let mssql = Mssql::from_source(source).await?;
@@ -114,13 +161,49 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
         .unwrap_or_else(|| String::from("dbo"));
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor(mssql, false)))
+    Ok((
+        db_name,
+        sql_executor(mssql, false, cache, audit_log, transformers, field_encryption),
+    ))
 }
 
 #[cfg(feature = "sql")]
-fn sql_executor<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+fn sql_executor<T>(
+    connector: T,
+    force_transactions: bool,
+    cache: Option<QueryCacheRef>,
+    audit_log: Option<AuditLog>,
+    transformers: Vec<ValueTransformerRef>,
+    field_encryption: Option<FieldEncryptionRef>,
+) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    let mut executor = InterpretingExecutor::new(connector, force_transactions);
+
+    if let Some(audit_log) = audit_log {
+        executor = executor.with_audit_log(audit_log);
+    }
+
+    for transformer in transformers {
+        executor = executor.with_value_transformer(transformer);
+    }
+
+    if let Some(field_encryption) = field_encryption {
+        executor = executor.with_field_encryption(field_encryption);
+    }
+
+    match cache {
+        Some(cache) => Box::new(executor.with_cache(cache)),
+        None => Box::new(executor),
+    }
+}
+
+/// The datasource URL with a Unix domain socket in the form the connectors use, see
+/// `datasource_url::normalize`.
+#[cfg(feature = "sql")]
+fn database_url(source: &Datasource) -> PrismaResult<String> {
+    datasource_url::normalize(&source.url().value)
+        .map(|url| url.into_owned())
+        .map_err(|err| PrismaError::ConfigurationError(err.to_string()))
 }
@@ -0,0 +1,32 @@
+//! The query engine. The binary serves the engine over HTTP or runs its CLI commands, the library
+//! exposes the functions clients can call in-process, see `api`.
+
+#[macro_use]
+extern crate tracing;
+
+use error::PrismaError;
+use request_handlers::PrismaResponse;
+
+pub mod api;
+pub mod cli;
+pub mod config;
+pub mod dmmf;
+pub mod error;
+pub mod opt;
+pub mod server;
+
+mod audit;
+mod context;
+mod exec_loader;
+mod request_handlers;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+pub type PrismaResult<T> = Result<T, PrismaError>;
@@ -1,10 +1,7 @@
 #[macro_use]
 extern crate tracing;
 
-use cli::CliCommand;
-use error::PrismaError;
-use opt::PrismaOpt;
-use request_handlers::PrismaResponse;
+use query_engine::{cli::CliCommand, error::PrismaError, opt::PrismaOpt, server, LogFormat};
 use std::{error::Error, process};
 use structopt::StructOpt;
 use tracing::subscriber;
@@ -13,25 +10,6 @@ use tracing_subscriber::{EnvFilter, FmtSubscriber};
 use tide_server_timing::TimingLayer;
 use tracing_subscriber::layer::SubscriberExt;
 
-mod cli;
-mod context;
-mod dmmf;
-mod error;
-mod exec_loader;
-mod opt;
-mod request_handlers;
-mod server;
-
-#[cfg(test)]
-mod tests;
-
-#[derive(Debug, Clone, PartialEq, Copy)]
-pub enum LogFormat {
-    Text,
-    Json,
-}
-
-pub type PrismaResult<T> = Result<T, PrismaError>;
 type AnyError = Box<dyn Error + Send + Sync + 'static>;
 
 #[async_std::main]
@@ -43,7 +21,9 @@ async fn main() -> Result<(), AnyError> {
     });
 
     async fn main() -> Result<(), PrismaError> {
-        let opts = PrismaOpt::from_args();
+        let matches = PrismaOpt::clap().get_matches();
+        let mut opts = PrismaOpt::from_clap(&matches);
+        opts.apply_config_file(&matches)?;
 
         init_logger(opts.log_format());
         feature_flags::initialize(opts.raw_feature_flags.as_slice())?;
@@ -92,6 +72,7 @@ fn set_panic_hook(log_format: LogFormat) {
                 .downcast_ref::<String>()
                 .map(Clone::clone)
                 .unwrap_or_else(|| info.payload().downcast_ref::<&str>().unwrap().to_string());
+            let payload = datasource_url::redact(&payload).into_owned();
 
             match info.location() {
                 Some(location) => {
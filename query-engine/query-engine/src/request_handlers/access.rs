@@ -0,0 +1,266 @@
+use crate::{
+    config::{AccessConfig, AccessRule},
+    PrismaError, PrismaResult,
+};
+use prisma_models::ModelRef;
+use query_core::{
+    related_models, schema::QuerySchemaRef, BatchDocument, Operation, QueryDocument, QueryValue, Selection,
+};
+use std::collections::{BTreeSet, HashMap};
+use user_facing_errors::{
+    query_engine::{InvalidAccessToken, OperationNotAllowed},
+    KnownError,
+};
+
+/// The roles of the engine and the tokens naming them, see `config::AccessConfig`.
+pub(crate) struct AccessPolicy {
+    tokens: HashMap<String, String>,
+    roles: HashMap<String, AccessRole>,
+}
+
+/// The operations the requests of a role may run.
+pub(crate) struct AccessRole {
+    name: String,
+    rules: Vec<AccessRule>,
+}
+
+impl AccessPolicy {
+    /// `None` if the configuration has no roles, i.e. all requests may run all operations.
+    pub(crate) fn new(config: &AccessConfig) -> Option<Self> {
+        if config.roles.is_empty() {
+            return None;
+        }
+
+        let roles = config
+            .roles
+            .iter()
+            .map(|(name, rules)| {
+                let role = AccessRole {
+                    name: name.clone(),
+                    rules: rules.clone(),
+                };
+
+                (name.clone(), role)
+            })
+            .collect();
+
+        Some(Self {
+            tokens: config.tokens.clone().into_iter().collect(),
+            roles,
+        })
+    }
+
+    /// The role named by the token of an `Authorization: Bearer <token>` header.
+    pub(crate) fn role(&self, authorization: Option<&str>) -> PrismaResult<&AccessRole> {
+        authorization
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| self.tokens.get(token.trim()))
+            .and_then(|role| self.roles.get(role))
+            .ok_or_else(|| PrismaError::AccessDenied(KnownError::new(InvalidAccessToken)))
+    }
+}
+
+impl AccessRole {
+    /// Checks that the role may run all operations of the document. Batches are rejected as a
+    /// whole, none of their operations is executed if one of them is not allowed.
+    pub(crate) fn authorize(&self, document: &QueryDocument, query_schema: &QuerySchemaRef) -> PrismaResult<()> {
+        match document {
+            QueryDocument::Single(operation) => self.authorize_operation(operation, query_schema),
+            QueryDocument::Multi(BatchDocument::Multi(operations, _)) => operations
+                .iter()
+                .try_for_each(|operation| self.authorize_operation(operation, query_schema)),
+            QueryDocument::Multi(BatchDocument::Compact(compacted)) => {
+                self.authorize_operation(&compacted.operation, query_schema)
+            }
+        }
+    }
+
+    /// An operation is allowed if its model and all models it reaches through relations, in its
+    /// arguments or selections, are listed by the rules allowing its kind. Nested writes are
+    /// authorized with their own kind, e.g. `posts: { deleteMany: {} }` in the data of an
+    /// `updateOne` needs a rule allowing `deleteMany` on the posts. Raw queries require a rule
+    /// listing all models.
+    fn authorize_operation(&self, operation: &Operation, query_schema: &QuerySchemaRef) -> PrismaResult<()> {
+        let (selection, field) = match operation {
+            Operation::Read(selection) => (selection, query_schema.find_query_field(selection.name())),
+            Operation::Write(selection) => (selection, query_schema.find_mutation_field(selection.name())),
+        };
+
+        // Unknown fields are rejected when the query graph is built.
+        let field = match field {
+            Some(field) => field,
+            None => return Ok(()),
+        };
+
+        let info = match &field.query_info {
+            Some(info) => info,
+            None => return Err(self.not_allowed(selection.name(), "the database")),
+        };
+
+        let kind = info.tag.to_string();
+
+        let model = match &info.model {
+            Some(model) => model,
+            None if self.allows(&kind, "*") => return Ok(()),
+            None => return Err(self.not_allowed(&kind, "the database")),
+        };
+
+        if !self.allows(&kind, &model.name) {
+            return Err(self.not_allowed(&kind, &format!("the model `{}`", model.name)));
+        }
+
+        let mut operations = BTreeSet::new();
+        collect_selection_operations(selection, model, &kind, &mut operations);
+
+        match operations.into_iter().find(|(name, kind)| !self.allows(kind, name)) {
+            Some((name, kind)) => Err(self.not_allowed(kind, &format!("the related model `{}`", name))),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether a rule of the role allows the operation kind on the model, `*` for raw queries.
+    fn allows(&self, kind: &str, model: &str) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.operations
+                .iter()
+                .any(|operation| operation == "*" || operation == kind)
+                && rule.models.iter().any(|name| name == "*" || name == model)
+        })
+    }
+
+    /// Replacing the datamodel through `/reload` requires a rule allowing `reload` on all models.
+    pub(crate) fn authorize_reload(&self) -> PrismaResult<()> {
+        if self.allows("reload", "*") {
+            Ok(())
+        } else {
+            Err(self.not_allowed("reload", "the datamodel"))
+        }
+    }
+
+    /// Reading the tenant pools through `/tenants` and the query cache counters through `/cache`
+    /// requires a rule allowing `metrics` on all models.
+    pub(crate) fn authorize_metrics(&self) -> PrismaResult<()> {
+        if self.allows("metrics", "*") {
+            Ok(())
+        } else {
+            Err(self.not_allowed("metrics", "the engine"))
+        }
+    }
+
+    fn not_allowed(&self, operation: &str, target: &str) -> PrismaError {
+        PrismaError::AccessDenied(KnownError::new(OperationNotAllowed {
+            role: self.name.clone(),
+            operation: operation.to_owned(),
+            target: target.to_owned(),
+        }))
+    }
+}
+
+/// The models a selection on `model` reaches, with the kind of operation reaching them, ordered by
+/// model name.
+fn collect_selection_operations<'a>(
+    selection: &Selection,
+    model: &ModelRef,
+    kind: &'a str,
+    operations: &mut BTreeSet<(String, &'a str)>,
+) {
+    operations.insert((model.name.clone(), kind));
+
+    for (key, value) in selection.arguments() {
+        collect_field_operations(key, value, model, kind, operations);
+    }
+
+    for nested in selection.nested_selections() {
+        match related_models(model, nested.name()) {
+            Some(related) => {
+                operations.extend(related.iter().map(|model| (model.name.clone(), kind)));
+                collect_selection_operations(nested, &related[0], kind, operations);
+            }
+            // Not a relation, e.g. `_count`, which selects relations itself.
+            None => collect_selection_operations(nested, model, kind, operations),
+        }
+    }
+}
+
+/// The value of the field or argument `key` of `model`.
+fn collect_field_operations<'a>(
+    key: &str,
+    value: &QueryValue,
+    model: &ModelRef,
+    kind: &'a str,
+    operations: &mut BTreeSet<(String, &'a str)>,
+) {
+    match related_models(model, key) {
+        Some(related) => collect_relation_operations(value, &related, kind, operations),
+        None => collect_value_operations(value, model, kind, operations),
+    }
+}
+
+fn collect_value_operations<'a>(
+    value: &QueryValue,
+    model: &ModelRef,
+    kind: &'a str,
+    operations: &mut BTreeSet<(String, &'a str)>,
+) {
+    match value {
+        QueryValue::Object(map) => {
+            for (key, value) in map {
+                collect_field_operations(key, value, model, kind, operations);
+            }
+        }
+        QueryValue::List(values) => {
+            for value in values {
+                collect_value_operations(value, model, kind, operations);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// The value of a relation field: a filter of the related records, or the nested writes of the
+/// data of a write. Connects and disconnects write the relation, with the kind of the operation.
+fn collect_relation_operations<'a>(
+    value: &QueryValue,
+    related: &[ModelRef],
+    kind: &'a str,
+    operations: &mut BTreeSet<(String, &'a str)>,
+) {
+    let map = match value {
+        QueryValue::Object(map) => map,
+        _ => {
+            operations.extend(related.iter().map(|model| (model.name.clone(), kind)));
+            return collect_value_operations(value, &related[0], kind, operations);
+        }
+    };
+
+    for (key, value) in map {
+        match nested_write_kind(&related[0], key) {
+            Some(nested_kind) => {
+                operations.extend(related.iter().map(|model| (model.name.clone(), nested_kind)));
+                collect_value_operations(value, &related[0], nested_kind, operations);
+            }
+            None => {
+                operations.extend(related.iter().map(|model| (model.name.clone(), kind)));
+                collect_field_operations(key, value, &related[0], kind, operations);
+            }
+        }
+    }
+}
+
+/// The kind of the nested write `key` of a relation field, unless `key` is a field of the related
+/// model in a relation filter.
+fn nested_write_kind(related: &ModelRef, key: &str) -> Option<&'static str> {
+    if related.fields().find_from_all(key).is_ok() {
+        return None;
+    }
+
+    match key {
+        "create" | "connectOrCreate" => Some("createOne"),
+        "update" => Some("updateOne"),
+        "updateMany" => Some("updateMany"),
+        "upsert" => Some("upsertOne"),
+        "delete" => Some("deleteOne"),
+        "deleteMany" => Some("deleteMany"),
+        _ => None,
+    }
+}
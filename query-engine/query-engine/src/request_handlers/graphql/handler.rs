@@ -1,9 +1,13 @@
 use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use crate::{context::PrismaContext, request_handlers::access::AccessRole, PrismaError, PrismaResponse, PrismaResult};
+use connector::QueryHints;
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    schema::OutputType, BatchDocument, CompactedDocument, Item, NumericEncoding, Operation, OutputFieldRef,
+    QueryDocument, QueryValue, ResponseData,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -20,6 +24,8 @@ pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
     variables: HashMap<String, String>,
+    #[serde(default)]
+    protocol_version: Option<u32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -27,6 +33,8 @@ pub struct SingleQuery {
 pub struct MultiQuery {
     batch: Vec<SingleQuery>,
     transaction: bool,
+    #[serde(default)]
+    protocol_version: Option<u32>,
 }
 
 impl From<String> for SingleQuery {
@@ -35,6 +43,7 @@ impl From<String> for SingleQuery {
             query,
             operation_name: None,
             variables: HashMap::new(),
+            protocol_version: None,
         }
     }
 }
@@ -46,6 +55,25 @@ impl From<&str> for SingleQuery {
 }
 
 impl GraphQlBody {
+    /// The numeric encoding of the response, derived from the protocol version of the request.
+    /// Version 1 (the default) is the legacy encoding, version 2 tags Decimal and BigInt values.
+    /// For batches, only the version of the envelope is taken into account.
+    pub(crate) fn numeric_encoding(&self) -> PrismaResult<NumericEncoding> {
+        let protocol_version = match self {
+            GraphQlBody::Single(body) => body.protocol_version,
+            GraphQlBody::Multi(bodies) => bodies.protocol_version,
+        };
+
+        match protocol_version {
+            None | Some(1) => Ok(NumericEncoding::Legacy),
+            Some(2) => Ok(NumericEncoding::Tagged),
+            Some(version) => Err(PrismaError::QueryConversionError(format!(
+                "Unsupported protocol version {}. Supported versions are 1 and 2.",
+                version
+            ))),
+        }
+    }
+
     /// Convert a `GraphQlBody` into a `QueryDocument`.
     pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
         match self {
@@ -74,28 +102,64 @@ impl GraphQlBody {
     }
 }
 
-/// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a Graphql request. The query hints apply to all operations of the request.
+pub(crate) async fn handle(body: GraphQlBody, hints: QueryHints, cx: Arc<PrismaContext>) -> PrismaResponse {
+    handle_as(body, hints, None, cx).await
+}
+
+/// Handle a Graphql request of a role. The request is rejected before any of its operations is
+/// executed if the role is not allowed to run one of them.
+pub(crate) async fn handle_as(
+    body: GraphQlBody,
+    hints: QueryHints,
+    role: Option<&AccessRole>,
+    cx: Arc<PrismaContext>,
+) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
-    match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
+    let encoding = match body.numeric_encoding() {
+        Ok(encoding) => encoding,
+        Err(err) => return PrismaResponse::Single(err.into()),
+    };
+
+    let doc = body.into_doc().and_then(|doc| match role {
+        Some(role) => role.authorize(&doc, cx.query_schema()).map(|_| doc),
+        None => Ok(doc),
+    });
+
+    match doc {
+        Ok(QueryDocument::Single(query)) => handle_single_query(query, hints, encoding, cx.clone()).await,
+        Ok(QueryDocument::Multi(_)) if hints.query_plans().is_some() => PrismaResponse::Single(
+            PrismaError::InvocationError("Explaining queries is not supported for batch requests.".to_owned()).into(),
+        ),
         Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+            BatchDocument::Multi(batch, transactional) => {
+                handle_batch(batch, transactional, hints, encoding, &cx).await
+            }
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, hints, encoding, &cx).await,
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_single_query(
+    query: Operation,
+    hints: QueryHints,
+    encoding: NumericEncoding,
+    ctx: Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let output_field = find_output_field(&query, &ctx);
+    let query_plans = hints.query_plans().cloned();
+    let query_graph_traces = hints.query_graph_traces().cloned();
+    let index_advice = hints.index_advice().cloned();
+
+    let mut gql_response: GQLResponse = match AssertUnwindSafe(handle_graphql_query(query, hints, &*ctx))
         .catch_unwind()
         .await
     {
-        Ok(Ok(responses)) => responses.into(),
+        Ok(Ok(response)) => encode_response(response, output_field.as_ref(), encoding).into(),
         Ok(Err(err)) => err.into(),
         Err(err) => {
             // panicked
@@ -104,15 +168,38 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
         }
     };
 
+    if let Some(query_plans) = query_plans {
+        let plans = serde_json::to_value(query_plans.take()).expect("query plans to serialize");
+        gql_response.insert_extension("queryPlans", plans);
+    }
+
+    if let Some(traces) = query_graph_traces {
+        let traces = serde_json::to_value(traces.take()).expect("query graph traces to serialize");
+        gql_response.insert_extension("queryGraphTraces", traces);
+    }
+
+    if let Some(advice) = index_advice {
+        let missing_indexes = serde_json::to_value(advice.take()).expect("missing indexes to serialize");
+        gql_response.insert_extension("missingIndexes", missing_indexes);
+    }
+
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    hints: QueryHints,
+    encoding: NumericEncoding,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
+    let output_fields: Vec<_> = queries.iter().map(|query| find_output_field(query, ctx)).collect();
+
     match AssertUnwindSafe(
         ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
+            .execute_batch(queries, transactional, ctx.query_schema().clone(), hints),
     )
     .catch_unwind()
     .await
@@ -120,8 +207,9 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
         Ok(Ok(responses)) => {
             let gql_responses = responses
                 .into_iter()
-                .map(|response| match response {
-                    Ok(data) => PrismaResponse::Single(data.into()),
+                .zip(output_fields.iter())
+                .map(|(response, output_field)| match response {
+                    Ok(data) => PrismaResponse::Single(encode_response(data, output_field.as_ref(), encoding).into()),
                     Err(err) => PrismaResponse::Single(err.into()),
                 })
                 .collect();
@@ -139,16 +227,27 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    hints: QueryHints,
+    encoding: NumericEncoding,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
+    // The results are indexed by their unique keys before encoding, each record is encoded on its own afterwards.
+    let record_type = find_output_field(&document.operation, ctx).map(|field| match field.field_type.as_ref() {
+        OutputType::List(inner) => inner.clone(),
+        _ => field.field_type.clone(),
+    });
+
     let plural_name = document.plural_name();
     let singular_name = document.single_name();
     let keys = document.keys;
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, hints, ctx))
         .catch_unwind()
         .await
     {
@@ -182,7 +281,12 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
                                 .filter(|(k, _)| nested_selection.contains(k))
                                 .collect();
 
-                            responses.insert_data(&singular_name, Item::Map(result));
+                            let item = match record_type {
+                                Some(ref typ) => encoding.encode(Item::Map(result), typ),
+                                None => Item::Map(result),
+                            };
+
+                            responses.insert_data(&singular_name, item);
                         }
                         _ => {
                             responses.insert_data(&singular_name, Item::null());
@@ -206,6 +310,31 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    hints: QueryHints,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()), hints)
+        .await?)
+}
+
+fn find_output_field(operation: &Operation, ctx: &PrismaContext) -> Option<OutputFieldRef> {
+    match operation {
+        Operation::Read(selection) => ctx.query_schema().find_query_field(selection.name()),
+        Operation::Write(selection) => ctx.query_schema().find_mutation_field(selection.name()),
+    }
+}
+
+fn encode_response(
+    response: ResponseData,
+    output_field: Option<&OutputFieldRef>,
+    encoding: NumericEncoding,
+) -> ResponseData {
+    match output_field {
+        Some(field) => ResponseData::new(response.key, encoding.encode(response.data, &field.field_type)),
+        None => response,
+    }
 }
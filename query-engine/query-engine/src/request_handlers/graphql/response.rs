@@ -14,6 +14,9 @@ pub struct GQLResponse {
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     errors: Vec<GQLError>,
+
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    extensions: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, serde::Serialize, PartialEq)]
@@ -41,6 +44,10 @@ impl GQLResponse {
     pub fn take_data(&mut self, key: impl AsRef<str>) -> Option<Item> {
         self.data.remove(key.as_ref())
     }
+
+    pub fn insert_extension(&mut self, key: impl Into<String>, value: serde_json::Value) {
+        self.extensions.insert(key.into(), value);
+    }
 }
 
 impl From<PrismaError> for GQLResponse {
@@ -89,6 +96,7 @@ impl From<PrismaError> for GQLError {
     fn from(other: PrismaError) -> Self {
         match other {
             PrismaError::CoreError(core_error) => GQLError::from(core_error),
+            PrismaError::AccessDenied(err) => GQLError::from(user_facing_errors::Error::from(err)),
             err => GQLError::from(user_facing_errors::Error::from_dyn_error(&err)),
         }
     }
@@ -24,7 +24,20 @@ pub struct GqlSchemaRenderer {
 impl Renderer for GqlSchemaRenderer {
     fn render(&self, ctx: &mut RenderContext) -> String {
         let _ = self.query_schema.query.into_renderer().render(ctx);
-        self.query_schema.mutation.into_renderer().render(ctx)
+
+        // GraphQL doesn't allow empty object types, e.g. the mutation type of a read-only schema.
+        if !self.query_schema.mutation().is_empty() {
+            let _ = self.query_schema.mutation.into_renderer().render(ctx);
+        }
+
+        // Input fields only render one member of their type union, so not every input object (e.g.
+        // the filter types) is necessarily reachable from the root types.
+        for input_object in self.query_schema.input_object_types() {
+            let input_object = Arc::downgrade(input_object);
+            let _ = (&input_object).into_renderer().render(ctx);
+        }
+
+        String::new()
     }
 }
 
@@ -40,7 +53,15 @@ impl QuerySchemaRenderer<String> for GraphQLSchemaRenderer {
         query_schema.into_renderer().render(&mut context);
 
         // Add custom scalar types (required for graphql.js implementations)
-        format!("{}\n\nscalar DateTime\nscalar Json\nscalar UUID", context.format())
+        let scalars = [
+            "DateTime", "Json", "UUID", "BigInt", "Decimal", "Bytes", "Xml", "Interval",
+        ]
+        .iter()
+        .map(|scalar| format!("scalar {}", scalar))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        format!("{}\n\n{}", context.format(), scalars)
     }
 }
 
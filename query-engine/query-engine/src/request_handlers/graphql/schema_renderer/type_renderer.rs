@@ -43,16 +43,17 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
-                    ScalarType::BigInt => "BigInt",
+                    ScalarType::BigInt | ScalarType::UnsignedBigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
                     ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
                     ScalarType::Xml => "Xml",
                     ScalarType::Bytes => "Bytes",
+                    ScalarType::Interval => "Interval",
                     ScalarType::Enum(_) => unreachable!("Encountered enum type during GQL scalar rendering."), // Handled separately above.
                     ScalarType::Null => unreachable!("Null types should not be picked for GQL rendering."),
                 };
@@ -89,7 +90,7 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
-                    ScalarType::BigInt => "BigInt",
+                    ScalarType::BigInt | ScalarType::UnsignedBigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
                     ScalarType::Decimal => "Decimal",
@@ -99,6 +100,7 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::JsonList => "Json",
                     ScalarType::Xml => "Xml",
                     ScalarType::Bytes => "Bytes",
+                    ScalarType::Interval => "Interval",
                     ScalarType::Enum(_) => unreachable!("Encountered enum type during GQL scalar rendering."), // Handled separately above.
                     ScalarType::Null => unreachable!("Null types should not be picked for GQL rendering."),
                 };
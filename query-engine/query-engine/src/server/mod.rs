@@ -3,34 +3,133 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
+use crate::request_handlers::access::{AccessPolicy, AccessRole};
 use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
-use crate::PrismaResult;
+use crate::{PrismaError, PrismaResponse, PrismaResult};
+use connector::{optimizer_hint_name, IndexAdvice, QueryGraphTraces, QueryHints, QueryPlans, SlowQueryLog};
 use elapsed_middleware::ElapsedMiddleware;
+use readiness::{Readiness, ReadinessProbe};
+use tenants::TenantRouter;
+use websocket::ConnectionManager;
 
 use query_core::schema::QuerySchemaRenderer;
 use serde_json::json;
-use tide::http::{mime, StatusCode};
+use tide::http::{headers::AUTHORIZATION, mime, StatusCode};
 use tide::{prelude::*, Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
+use tide_websockets::WebSocket;
 
-use std::sync::Arc;
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
 
 mod elapsed_middleware;
+mod notifications;
+pub(crate) mod readiness;
+pub(crate) mod tenants;
+mod websocket;
 
 //// Shared application state.
 pub(crate) struct State {
-    cx: Arc<PrismaContext>,
+    /// The current context. Requests clone the `Arc` when they start, so they finish with the
+    /// datamodel they started with when it is reloaded in the meantime.
+    cx: Arc<RwLock<Arc<PrismaContext>>>,
+    connections: Arc<ConnectionManager>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    enable_datamodel_reload: bool,
+    allowed_query_hints: Arc<Vec<String>>,
+    slow_query_threshold: Option<Duration>,
+    readiness: Arc<ReadinessProbe>,
+    /// Routes requests to the database of their tenant in multi-tenant mode.
+    tenants: Option<Arc<TenantRouter>>,
+    /// Restricts the operations of requests to the ones allowed for their role, if roles are
+    /// configured.
+    access: Option<Arc<AccessPolicy>>,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        connections: Arc<ConnectionManager>,
+        opts: &PrismaOpt,
+        tenants: Option<TenantRouter>,
+    ) -> Self {
         Self {
-            cx: Arc::new(cx),
-            enable_playground,
-            enable_debug_mode,
+            cx: Arc::new(RwLock::new(Arc::new(cx))),
+            connections,
+            enable_playground: opts.enable_playground,
+            enable_debug_mode: opts.enable_debug_mode,
+            enable_datamodel_reload: opts.enable_datamodel_reload,
+            allowed_query_hints: Arc::new(opts.allowed_query_hints.clone()),
+            slow_query_threshold: opts.slow_query_threshold_ms.map(Duration::from_millis),
+            readiness: Arc::new(ReadinessProbe::new(Duration::from_millis(
+                opts.health_check_interval_ms,
+            ))),
+            tenants: tenants.map(Arc::new),
+            access: AccessPolicy::new(&opts.access_config).map(Arc::new),
+        }
+    }
+
+    /// The context of the current datamodel.
+    pub(crate) fn cx(&self) -> Arc<PrismaContext> {
+        self.cx.read().expect("context lock poisoned").clone()
+    }
+
+    /// The context a request is executed with: the context of its tenant in multi-tenant mode,
+    /// the context of the current datamodel otherwise.
+    async fn request_cx(&self, req: &Request<State>) -> PrismaResult<Arc<PrismaContext>> {
+        let tenants = match &self.tenants {
+            Some(tenants) => tenants,
+            None => return Ok(self.cx()),
+        };
+
+        let tenant = req.header(tenants.header()).ok_or_else(|| {
+            PrismaError::InvocationError(format!(
+                "The `{}` header naming the tenant is missing.",
+                tenants.header()
+            ))
+        })?;
+
+        tenants.context(tenant.last().as_str(), &self.cx()).await
+    }
+}
+
+/// The role of a request, from its `Authorization` header. `None` if no roles are configured.
+fn request_role<'a>(
+    access: Option<&'a AccessPolicy>,
+    authorization: Option<&str>,
+) -> PrismaResult<Option<&'a AccessRole>> {
+    access.map(|access| access.role(authorization)).transpose()
+}
+
+fn authorization_header(req: &Request<State>) -> Option<String> {
+    req.header(AUTHORIZATION)
+        .map(|values| values.last().as_str().to_owned())
+}
+
+/// The `403 Forbidden` response of a request to an administrative endpoint whose role is not
+/// allowed to use it. `None` if the request may proceed, or no roles are configured.
+fn forbidden_response(
+    req: &Request<State>,
+    authorize: fn(&AccessRole) -> PrismaResult<()>,
+) -> tide::Result<Option<Response>> {
+    let access = req.state().access.clone();
+    let authorization = authorization_header(req);
+
+    let authorized =
+        request_role(access.as_deref(), authorization.as_deref()).and_then(|role| role.map_or(Ok(()), authorize));
+
+    match authorized {
+        Ok(()) => Ok(None),
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Forbidden);
+            res.set_body(Body::from_json(
+                &json!({ "errors": [user_facing_errors::Error::from(err)] }),
+            )?);
+            Ok(Some(res))
         }
     }
 }
@@ -39,8 +138,15 @@ impl Clone for State {
     fn clone(&self) -> Self {
         Self {
             cx: self.cx.clone(),
+            connections: self.connections.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            enable_datamodel_reload: self.enable_datamodel_reload,
+            allowed_query_hints: self.allowed_query_hints.clone(),
+            slow_query_threshold: self.slow_query_threshold,
+            readiness: self.readiness.clone(),
+            tenants: self.tenants.clone(),
+            access: self.access.clone(),
         }
     }
 }
@@ -52,14 +158,30 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
         .subject
         .validate_that_one_datasource_is_provided()?;
 
+    let connections = Arc::new(ConnectionManager::default());
+
+    if let Some(datasource) = config.datasources.first() {
+        notifications::spawn_listener(datasource, opts.datamodel_directory(), connections.clone())?;
+    }
+
     let datamodel = opts.datamodel(true)?;
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .read_only(opts.read_only)
+        .group_by_take_limit(opts.group_by_take_limit)
+        .connect_or_create_retries(opts.connect_or_create_retries)
+        .query_cache(opts.query_cache_config())
+        .audit_stream(opts.audit_stream)
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let tenants = match opts.tenant_options()? {
+        Some(options) => Some(TenantRouter::new(options, opts.datamodel_str()?.to_owned())?),
+        None => None,
+    };
+
+    let mut app = tide::with_state(State::new(cx, connections, &opts, tenants));
     app.with(ElapsedMiddleware::new());
 
     if opts.enable_playground {
@@ -68,10 +190,16 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/ws").get(WebSocket::new(websocket::handle_connection));
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
+    app.at("/health").get(|_| async move { Ok(json!({"status": "ok"})) });
+    app.at("/ready").get(readiness_handler);
+    app.at("/reload").post(reload_handler);
+    app.at("/tenants").get(tenants_handler);
+    app.at("/cache").get(cache_handler);
 
     // Start the Tide server and log the server details.
     // NOTE: The `info!` statement is essential for the correct working of the client.
@@ -84,6 +212,10 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     Ok(())
 }
 
+/// Debug header that returns the slow queries of the request in the `warnings` extension of the
+/// response, if the engine was started with a slow query threshold.
+static SLOW_QUERY_WARNINGS_HEADER: &str = "x-debug-slow-queries";
+
 /// The main query handler. This handles incoming GraphQL queries and passes it
 /// to the query engine.
 async fn graphql_handler(mut req: Request<State>) -> tide::Result {
@@ -94,14 +226,210 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    let mut hints = match query_hints(&req) {
+        Ok(hints) => hints,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(Body::from_json(&PrismaResponse::Single(err.into()))?);
+            return Ok(res);
+        }
+    };
+
+    let slow_query_log = req.state().slow_query_threshold.map(SlowQueryLog::new);
+
+    if let Some(log) = &slow_query_log {
+        hints.log_slow_queries(log.clone());
+    }
+
+    let report_slow_queries = req.state().enable_debug_mode && req.header(SLOW_QUERY_WARNINGS_HEADER).is_some();
+
+    let access = req.state().access.clone();
+    let authorization = authorization_header(&req);
+
+    let role = match request_role(access.as_deref(), authorization.as_deref()) {
+        Ok(role) => role,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(Body::from_json(&PrismaResponse::Single(err.into()))?);
+            return Ok(res);
+        }
+    };
+
+    // The tenant is only resolved for authenticated requests, as it opens a connection pool to the
+    // database of the tenant.
+    let cx = match req.state().request_cx(&req).await {
+        Ok(cx) => cx,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(Body::from_json(&PrismaResponse::Single(err.into()))?);
+            return Ok(res);
+        }
+    };
+
     let body: GraphQlBody = req.body_json().await?;
-    let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let mut result = graphql::handle_as(body, hints, role, cx).await;
+
+    // Batch responses are plain lists, their slow queries are only logged.
+    if let (Some(log), true, PrismaResponse::Single(response)) = (&slow_query_log, report_slow_queries, &mut result) {
+        let warnings = serde_json::to_value(log.take()).expect("slow queries to serialize");
+        response.insert_extension("warnings", warnings);
+    }
+
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Read the query hints of a request from the `x-prisma-query-hints` header. The header value is
+/// a JSON object of setting names to values, e.g. `{"statement_timeout": "1000"}` or
+/// `{"app.user_id": "42"}` for row-level security policies reading `current_setting('app.user_id')`.
+///
+/// Only the settings listed with `--allowed-query-hints` are accepted, and never those of
+/// `BLOCKED_QUERY_HINTS`.
+///
+/// In debug mode, the `x-prisma-explain` header asks for the plans of the generated statements,
+/// which are returned in the `queryPlans` extension of the response, and the `x-debug-query-graph`
+/// header for the traces of its query graphs, returned in the `queryGraphTraces` extension. The
+/// `x-debug-index-advice` header lists the fields its queries filter or order by without an index
+/// in the `missingIndexes` extension, each with the `@@index` attribute to add to the schema.
+///
+/// On MySQL, the `x-prisma-optimizer-hints` header is a JSON array of optimizer hints added to the
+/// generated statements, e.g. `["MAX_EXECUTION_TIME(1000)"]`. Only the hints whose name is listed
+/// with `--allowed-query-hints` are accepted.
+///
+/// The `x-request-id` header is recorded with the audit entries of the request.
+fn query_hints(req: &Request<State>) -> PrismaResult<QueryHints> {
+    static QUERY_HINTS_HEADER: &str = "x-prisma-query-hints";
+    static OPTIMIZER_HINTS_HEADER: &str = "x-prisma-optimizer-hints";
+    static EXPLAIN_HEADER: &str = "x-prisma-explain";
+    static QUERY_GRAPH_TRACE_HEADER: &str = "x-debug-query-graph";
+    static INDEX_ADVICE_HEADER: &str = "x-debug-index-advice";
+    static REQUEST_ID_HEADER: &str = "x-request-id";
+
+    let mut hints = QueryHints::new();
+
+    if let Some(request_id) = req.header(REQUEST_ID_HEADER) {
+        hints.set_request_id(request_id.last().as_str());
+    }
+
+    if req.header(EXPLAIN_HEADER).is_some() {
+        if !req.state().enable_debug_mode {
+            return Err(PrismaError::InvocationError(format!(
+                "The `{}` header is only supported in debug mode.",
+                EXPLAIN_HEADER
+            )));
+        }
+
+        hints.explain(QueryPlans::new());
+    }
+
+    if req.header(QUERY_GRAPH_TRACE_HEADER).is_some() {
+        if !req.state().enable_debug_mode {
+            return Err(PrismaError::InvocationError(format!(
+                "The `{}` header is only supported in debug mode.",
+                QUERY_GRAPH_TRACE_HEADER
+            )));
+        }
+
+        hints.trace_query_graphs(QueryGraphTraces::new());
+    }
+
+    if req.header(INDEX_ADVICE_HEADER).is_some() {
+        if !req.state().enable_debug_mode {
+            return Err(PrismaError::InvocationError(format!(
+                "The `{}` header is only supported in debug mode.",
+                INDEX_ADVICE_HEADER
+            )));
+        }
+
+        hints.advise_indexes(IndexAdvice::new());
+    }
+
+    if let Some(header) = req.header(OPTIMIZER_HINTS_HEADER) {
+        let optimizer_hints: Vec<String> = serde_json::from_str(header.last().as_str())
+            .map_err(|err| PrismaError::InvocationError(format!("Invalid optimizer hints header: {}", err)))?;
+
+        for hint in optimizer_hints {
+            let name = optimizer_hint_name(&hint)
+                .ok_or_else(|| PrismaError::InvocationError(format!("`{}` is not a valid optimizer hint.", hint)))?;
+
+            if !is_allowed_query_hint(&req.state().allowed_query_hints, name) {
+                return Err(PrismaError::InvocationError(format!(
+                    "The optimizer hint `{}` is not allowed by the engine configuration.",
+                    name
+                )));
+            }
+
+            hints.add_optimizer_hint(hint).map_err(PrismaError::InvocationError)?;
+        }
+    }
+
+    let header = match req.header(QUERY_HINTS_HEADER) {
+        Some(header) => header,
+        None => return Ok(hints),
+    };
+
+    let settings: serde_json::Map<String, serde_json::Value> = serde_json::from_str(header.last().as_str())
+        .map_err(|err| PrismaError::InvocationError(format!("Invalid query hints header: {}", err)))?;
+
+    for (name, value) in settings {
+        let value = match value {
+            serde_json::Value::String(s) => s,
+            serde_json::Value::Number(n) => n.to_string(),
+            serde_json::Value::Bool(b) => b.to_string(),
+            other => {
+                return Err(PrismaError::InvocationError(format!(
+                    "Invalid value for the query hint `{}`: {}",
+                    name, other
+                )))
+            }
+        };
+
+        if !is_allowed_query_hint(&req.state().allowed_query_hints, &name) {
+            return Err(PrismaError::InvocationError(format!(
+                "The query hint `{}` is not allowed by the engine configuration.",
+                name
+            )));
+        }
+
+        hints.add_setting(name, value).map_err(PrismaError::InvocationError)?;
+    }
+
+    Ok(hints)
+}
+
+/// Settings that change the privileges of the session, how names resolve or which constraints and
+/// checks apply. They can't be set through query hints, even when the allowlist accepts them.
+static BLOCKED_QUERY_HINTS: &[&str] = &[
+    // Postgres
+    "role",
+    "session_authorization",
+    "search_path",
+    // MySQL, where the settings are session variables
+    "foreign_key_checks",
+    "unique_checks",
+    "sql_mode",
+    "sql_log_bin",
+    "autocommit",
+];
+
+/// Whether `name` matches the allowlist. Entries are either exact setting names or a namespace
+/// followed by `.*`, e.g. `app.*`. An empty allowlist rejects every setting.
+fn is_allowed_query_hint(allowlist: &[String], name: &str) -> bool {
+    let blocked = BLOCKED_QUERY_HINTS
+        .iter()
+        .any(|blocked| blocked.eq_ignore_ascii_case(name));
+
+    !blocked
+        && allowlist.iter().any(|allowed| match allowed.strip_suffix(".*") {
+            Some(namespace) => name
+                .strip_prefix(namespace)
+                .map(|rest| rest.starts_with('.'))
+                .unwrap_or(false),
+            None => allowed == name,
+        })
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -122,14 +450,15 @@ async fn playground_handler(req: Request<State>) -> tide::Result {
 /// Handler for the playground to work with the SDL-rendered query schema.
 /// Serves a raw SDL string created from the query schema.
 async fn sdl_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
-    let schema = Arc::clone(&req.state().cx.query_schema());
+    let schema = Arc::clone(&req.state().cx().query_schema());
     Ok(GraphQLSchemaRenderer::render(schema))
 }
 
 /// Renders the Data Model Meta Format.
 /// Only callable if prisma was initialized using a v2 data model.
 async fn dmmf_handler(req: Request<State>) -> tide::Result {
-    let result = dmmf::render_dmmf(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
+    let cx = req.state().cx();
+    let result = dmmf::render_dmmf(cx.datamodel(), Arc::clone(cx.query_schema()));
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
@@ -140,10 +469,132 @@ async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Resp
     Ok(json!({
         "commit": env!("GIT_HASH"),
         "version": env!("CARGO_PKG_VERSION"),
-        "primary_connector": req.state().cx.primary_connector(),
+        "primary_connector": req.state().cx().primary_connector(),
     }))
 }
 
+/// Readiness endpoint for orchestrators. Responds with `503 Service Unavailable` while the
+/// database cannot be reached, see `ReadinessProbe`.
+async fn readiness_handler(req: Request<State>) -> tide::Result {
+    let cx = req.state().cx();
+
+    let (status, body) = match req.state().readiness.check(cx.executor.primary_connector()).await {
+        Readiness::Ready => (StatusCode::Ok, json!({ "status": "ready" })),
+        Readiness::NotReady(reason) => (
+            StatusCode::ServiceUnavailable,
+            json!({ "status": "not_ready", "reason": reason }),
+        ),
+    };
+
+    let mut res = Response::new(status);
+    res.set_body(Body::from_json(&body)?);
+    Ok(res)
+}
+
+/// How long a datamodel reload waits for the requests using the previous datamodel to finish.
+const RELOAD_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Replaces the datamodel of the running engine with the schema in the request body, keeping the
+/// connection pool. The new query schema is built first, then swapped in atomically: requests
+/// that started before the swap finish with the previous datamodel, and the response is sent
+/// once they are drained.
+///
+/// Only enabled with `--enable-datamodel-reload`. If roles are configured, the request needs the
+/// token of a role allowed to `reload`.
+async fn reload_handler(mut req: Request<State>) -> tide::Result {
+    if !req.state().enable_datamodel_reload {
+        return Ok(Response::new(StatusCode::NotFound));
+    }
+
+    if let Some(res) = forbidden_response(&req, AccessRole::authorize_reload)? {
+        return Ok(res);
+    }
+
+    let schema = req.body_string().await?;
+
+    let parsed = datamodel::parse_configuration_and_ignore_datasource_urls(&schema)
+        .and_then(|config| datamodel::parse_datamodel_and_ignore_datasource_urls(&schema).map(|dm| (config, dm)));
+
+    let (config, dm) = match parsed {
+        Ok((config, dm)) => (config.subject, dm.subject),
+        Err(diagnostics) => {
+            let mut res = Response::new(StatusCode::UnprocessableEntity);
+            res.set_body(Body::from_json(&json!({ "errors": diagnostics.to_structured() }))?);
+            return Ok(res);
+        }
+    };
+
+    let previous = req.state().cx();
+
+    let next = match previous.reload(&config, dm) {
+        Ok(next) => next,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::UnprocessableEntity);
+            res.set_body(Body::from_json(&json!({ "errors": [err.to_string()] }))?);
+            return Ok(res);
+        }
+    };
+
+    let next = Arc::new(next);
+    *req.state().cx.write().expect("context lock poisoned") = next.clone();
+
+    if let Some(tenants) = &req.state().tenants {
+        if let Err(err) = tenants.reload(schema, &next).await {
+            warn!("Could not reload the datamodel of the tenants: {}", err);
+        }
+    }
+
+    info!("Reloaded the datamodel.");
+
+    // Every request using the previous context holds a clone of it.
+    let drain_started = Instant::now();
+    let mut drained = true;
+
+    while Arc::strong_count(&previous) > 1 {
+        if drain_started.elapsed() > RELOAD_DRAIN_TIMEOUT {
+            warn!("Requests using the previous datamodel did not finish within the drain timeout.");
+            drained = false;
+            break;
+        }
+
+        async_std::task::sleep(Duration::from_millis(10)).await;
+    }
+
+    Ok(json!({ "status": "reloaded", "drained": drained }).into())
+}
+
+/// The open tenant connection pools and their request counters, in multi-tenant mode.
+async fn tenants_handler(req: Request<State>) -> tide::Result {
+    let tenants = match &req.state().tenants {
+        Some(tenants) => tenants,
+        None => return Ok(Response::new(StatusCode::NotFound)),
+    };
+
+    if let Some(res) = forbidden_response(&req, AccessRole::authorize_metrics)? {
+        return Ok(res);
+    }
+
+    let (metrics, evictions) = tenants.metrics().await;
+
+    Ok(json!({ "tenants": metrics, "evictedPools": evictions }).into())
+}
+
+/// The hit and size counters of the query cache, if enabled.
+async fn cache_handler(req: Request<State>) -> tide::Result {
+    if let Some(res) = forbidden_response(&req, AccessRole::authorize_metrics)? {
+        return Ok(res);
+    }
+
+    let cx = req.state().cx();
+
+    let metrics = match cx.query_cache() {
+        Some(cache) => cache.metrics(),
+        None => return Ok(Response::new(StatusCode::NotFound)),
+    };
+
+    Ok(json!({ "queryCache": metrics }).into())
+}
+
 /// Handle debug headers inside the main GraphQL endpoint.
 async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl Into<Response>>> {
     /// Debug header that triggers a panic in the request thread.
@@ -164,3 +615,52 @@ async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowlist(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|entry| (*entry).to_owned()).collect()
+    }
+
+    #[test]
+    fn an_empty_allowlist_rejects_every_setting() {
+        assert!(!is_allowed_query_hint(&[], "app.user_id"));
+        assert!(!is_allowed_query_hint(&[], "statement_timeout"));
+    }
+
+    #[test]
+    fn exact_and_namespace_entries_are_matched() {
+        let allowlist = allowlist(&["statement_timeout", "app.*"]);
+
+        assert!(is_allowed_query_hint(&allowlist, "statement_timeout"));
+        assert!(is_allowed_query_hint(&allowlist, "app.user_id"));
+        assert!(is_allowed_query_hint(&allowlist, "app.tenant.id"));
+
+        assert!(!is_allowed_query_hint(&allowlist, "statement_timeouts"));
+        assert!(!is_allowed_query_hint(&allowlist, "app"));
+        assert!(!is_allowed_query_hint(&allowlist, "application.user_id"));
+        assert!(!is_allowed_query_hint(&allowlist, "lock_timeout"));
+    }
+
+    #[test]
+    fn blocked_settings_are_rejected_even_when_allowed() {
+        let allowlist = allowlist(&["role", "session_authorization", "search_path", "app.*"]);
+
+        assert!(!is_allowed_query_hint(&allowlist, "role"));
+        assert!(!is_allowed_query_hint(&allowlist, "ROLE"));
+        assert!(!is_allowed_query_hint(&allowlist, "session_authorization"));
+        assert!(!is_allowed_query_hint(&allowlist, "search_path"));
+        assert!(is_allowed_query_hint(&allowlist, "app.role"));
+    }
+
+    #[test]
+    fn mysql_session_variables_bypassing_checks_are_rejected() {
+        let allowlist = allowlist(&["foreign_key_checks", "sql_mode", "max_execution_time"]);
+
+        assert!(!is_allowed_query_hint(&allowlist, "foreign_key_checks"));
+        assert!(!is_allowed_query_hint(&allowlist, "SQL_MODE"));
+        assert!(is_allowed_query_hint(&allowlist, "max_execution_time"));
+    }
+}
@@ -0,0 +1,191 @@
+use super::websocket::ConnectionManager;
+use crate::{PrismaError, PrismaResult};
+use datamodel::{Datasource, SslAcceptMode, SslMode, TlsOptions};
+use futures::{channel::mpsc, stream, StreamExt};
+use native_tls::{Certificate, Identity, TlsConnector};
+use percent_encoding::percent_decode_str;
+use postgres_native_tls::MakeTlsConnector;
+use serde_json::json;
+use std::{path::Path, sync::Arc};
+use tokio_postgres::{config::SslMode as PostgresSslMode, AsyncMessage, Config};
+use url::Url;
+
+/// Prefix of the WebSocket topics notifications are published on, e.g. `postgres:events` for the
+/// `events` channel.
+const TOPIC_PREFIX: &str = "postgres:";
+
+/// The WebSocket topic the notifications of a channel are published on.
+pub(crate) fn topic_for_channel(channel: &str) -> String {
+    format!("{}{}", TOPIC_PREFIX, channel)
+}
+
+/// Registers a topic for each channel declared with `listenChannels` on the datasource, and spawns
+/// a task listening on these channels for the lifetime of the process. The task reconnects with
+/// the backoff of the datasource retry policy when the connection is lost. Relative certificate
+/// paths are resolved from `schema_directory`, the directory of the schema file.
+pub(crate) fn spawn_listener(
+    datasource: &Datasource,
+    schema_directory: Option<&Path>,
+    connections: Arc<ConnectionManager>,
+) -> PrismaResult<()> {
+    if datasource.listen_channels.is_empty() {
+        return Ok(());
+    }
+
+    // Notifications are not delivered through connection poolers in transaction mode, the
+    // listener connects to the database directly.
+    let tls_options = datasource.tls_options().map_err(PrismaError::ConfigurationError)?;
+    let tls_options = match schema_directory {
+        Some(directory) => tls_options.relative_to(directory),
+        None => tls_options,
+    };
+    let (url, tunnel) = ssh_tunnel::open(&datasource.direct_url_or_url().value)
+        .map_err(|err| PrismaError::ConfigurationError(err.to_string()))?;
+    let config = postgres_config(&url, &tls_options).map_err(PrismaError::ConfigurationError)?;
+    let tls = tls_connector(&tls_options).map_err(PrismaError::ConfigurationError)?;
+
+    for channel in datasource.listen_channels.iter() {
+        connections.register_topic(topic_for_channel(channel));
+    }
+
+    let channels = datasource.listen_channels.clone();
+    let retry_policy = datasource.retry_policy;
+
+    async_std::task::spawn(async move {
+        // The listening connection goes through the tunnel, if any, for the lifetime of the task.
+        let _tunnel = tunnel;
+        let mut attempt = 0;
+
+        loop {
+            match listen(&config, tls.clone(), &channels, &connections).await {
+                // The connection was established and lost afterwards, start over with the backoff.
+                Ok(()) => attempt = 0,
+                Err(err) => {
+                    warn!("Could not listen on the channels {:?}: {}", channels, err);
+                    attempt += 1;
+                }
+            }
+
+            async_std::task::sleep(retry_policy.delay_for_attempt(attempt)).await;
+        }
+    });
+
+    Ok(())
+}
+
+/// Listens on the channels and publishes every notification until the connection is lost.
+/// Only errors happening while establishing the connection are returned.
+async fn listen(
+    config: &Config,
+    tls: MakeTlsConnector,
+    channels: &[String],
+    connections: &ConnectionManager,
+) -> Result<(), tokio_postgres::Error> {
+    let (client, mut connection) = config.connect(tls).await?;
+
+    // Notifications are only delivered through the connection, which has to be polled for the
+    // client to make any progress.
+    let (sender, mut messages) = mpsc::unbounded();
+    let driver = stream::poll_fn(move |cx| connection.poll_message(cx))
+        .map(Ok)
+        .forward(sender);
+    async_std::task::spawn(driver);
+
+    let statements: String = channels
+        .iter()
+        .map(|channel| format!("LISTEN \"{}\";", channel.replace('"', "\"\"")))
+        .collect();
+
+    client.batch_execute(statements.as_str()).await?;
+    info!("Listening for notifications on the channels {:?}", channels);
+
+    while let Some(message) = messages.next().await {
+        match message {
+            Ok(AsyncMessage::Notification(notification)) => {
+                let payload = json!({
+                    "channel": notification.channel(),
+                    "payload": notification.payload(),
+                    "processId": notification.process_id(),
+                });
+
+                connections
+                    .publish(&topic_for_channel(notification.channel()), payload)
+                    .await;
+            }
+            Ok(_) => (),
+            Err(err) => {
+                warn!("Lost the connection listening for notifications: {}", err);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The connection string may contain parameters that are only meaningful to quaint, so the
+/// configuration is built from the URL components instead of handing it over as is.
+fn postgres_config(url: &str, tls_options: &TlsOptions) -> Result<Config, String> {
+    let url = datasource_url::normalize(url).map_err(|err| format!("Invalid datasource URL: {}", err))?;
+    let url = Url::parse(&url).map_err(|err| format!("Invalid datasource URL: {}", err))?;
+    let decode = |s: &str| percent_decode_str(s).decode_utf8_lossy().into_owned();
+
+    let mut config = Config::new();
+
+    // The `host` parameter is the directory of a Unix domain socket, tokio-postgres treats hosts
+    // starting with a slash as such.
+    match url.query_pairs().find(|(name, _)| name == "host") {
+        Some((_, socket_directory)) => config.host(&socket_directory),
+        None => config.host(url.host_str().unwrap_or("localhost")),
+    };
+    config.port(url.port().unwrap_or(5432));
+    config.user(&decode(url.username()));
+
+    if let Some(password) = url.password() {
+        config.password(decode(password));
+    }
+
+    match url.path().trim_start_matches('/') {
+        "" => config.dbname("postgres"),
+        dbname => config.dbname(&decode(dbname)),
+    };
+
+    config.ssl_mode(match tls_options.mode {
+        Some(SslMode::Disable) => PostgresSslMode::Disable,
+        Some(SslMode::Require) => PostgresSslMode::Require,
+        Some(SslMode::Prefer) | None => PostgresSslMode::Prefer,
+    });
+
+    Ok(config)
+}
+
+fn tls_connector(tls_options: &TlsOptions) -> Result<MakeTlsConnector, String> {
+    let read_file = |path: &str| std::fs::read(path).map_err(|err| format!("Could not read `{}`: {}", path, err));
+    let mut builder = TlsConnector::builder();
+
+    if let Some(ref path) = tls_options.root_cert_path {
+        let bytes = read_file(path)?;
+        let certificate = Certificate::from_pem(&bytes)
+            .or_else(|_| Certificate::from_der(&bytes))
+            .map_err(|err| format!("Invalid root certificate `{}`: {}", path, err))?;
+
+        builder.add_root_certificate(certificate);
+    }
+
+    if let Some(ref path) = tls_options.client_identity_path {
+        let bytes = read_file(path)?;
+        let password = tls_options.client_identity_password.as_deref().unwrap_or("");
+        let identity = Identity::from_pkcs12(&bytes, password)
+            .map_err(|err| format!("Invalid client identity `{}`: {}", path, err))?;
+
+        builder.identity(identity);
+    }
+
+    if tls_options.accept == Some(SslAcceptMode::AcceptInvalidCerts) {
+        builder.danger_accept_invalid_certs(true);
+    }
+
+    let connector = builder.build().map_err(|err| err.to_string())?;
+
+    Ok(MakeTlsConnector::new(connector))
+}
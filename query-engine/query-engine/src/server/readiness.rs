@@ -0,0 +1,58 @@
+use async_std::sync::Mutex;
+use connector::Connector;
+use std::time::{Duration, Instant};
+
+/// How long a readiness probe waits for the database before reporting it as unreachable.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether the database answered the last connectivity probe.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Readiness {
+    Ready,
+    NotReady(String),
+}
+
+/// Checks that the database is reachable for the `/ready` endpoint. The result of a probe is
+/// cached for the health check interval, so that frequent polling by an orchestrator does not
+/// put load on the database. Concurrent requests wait for the same probe.
+pub(crate) struct ReadinessProbe {
+    interval: Duration,
+    last_probe: Mutex<Option<(Instant, Readiness)>>,
+}
+
+impl ReadinessProbe {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_probe: Mutex::new(None),
+        }
+    }
+
+    /// The result of the last probe if it is recent enough, otherwise the result of a new one.
+    pub(crate) async fn check(&self, connector: &dyn Connector) -> Readiness {
+        let mut last_probe = self.last_probe.lock().await;
+
+        if let Some((probed_at, readiness)) = last_probe.as_ref() {
+            if probed_at.elapsed() < self.interval {
+                return readiness.clone();
+            }
+        }
+
+        let readiness = match async_std::future::timeout(PROBE_TIMEOUT, connector.ping()).await {
+            Ok(Ok(())) => Readiness::Ready,
+            Ok(Err(err)) => Readiness::NotReady(err.to_string()),
+            Err(_) => Readiness::NotReady(format!(
+                "The database did not respond within {} seconds.",
+                PROBE_TIMEOUT.as_secs()
+            )),
+        };
+
+        if let Readiness::NotReady(reason) = &readiness {
+            warn!("Readiness probe failed: {}", reason);
+        }
+
+        *last_probe = Some((Instant::now(), readiness.clone()));
+
+        readiness
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{context::PrismaContext, PrismaError, PrismaResult};
+use async_std::sync::Mutex;
+use query_core::QueryCacheMetrics;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// The placeholder of the tenant key in the tenant URL template.
+const TENANT_PLACEHOLDER: &str = "{tenant}";
+
+/// How the engine routes requests to the databases of its tenants.
+#[derive(Debug, Clone)]
+pub(crate) struct TenantOptions {
+    /// The request header holding the tenant key.
+    pub(crate) header: String,
+    /// The datasource URL of a tenant, with `{tenant}` in place of the tenant key.
+    pub(crate) url_template: String,
+    /// The maximum number of tenant connection pools kept open at the same time. The least
+    /// recently used pool is closed to make room for a new one.
+    pub(crate) max_pools: usize,
+    /// Pools of tenants without requests for this long are closed.
+    pub(crate) idle_timeout: Duration,
+}
+
+/// Request counters of a tenant, exposed on the `/tenants` endpoint.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TenantMetrics {
+    pub(crate) requests: u64,
+    pub(crate) pool_opened_ms_ago: u128,
+    pub(crate) last_request_ms_ago: u128,
+    /// The counters of the query cache of the tenant, if enabled. Each tenant has its own cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) query_cache: Option<QueryCacheMetrics>,
+}
+
+struct TenantEntry {
+    cx: Arc<PrismaContext>,
+    requests: u64,
+    opened_at: Instant,
+    last_used: Instant,
+}
+
+/// Holds a context, and with it a connection pool, for each tenant that had requests recently.
+/// All tenants share the datamodel of the engine, only the database differs. Contexts are created
+/// on the first request of a tenant and closed again by the eviction policies of `TenantOptions`.
+pub(crate) struct TenantRouter {
+    options: TenantOptions,
+    /// The schema of the engine, parsed with the URL of a tenant to connect to its database.
+    schema: RwLock<String>,
+    tenants: Mutex<HashMap<String, TenantEntry>>,
+    evictions: AtomicU64,
+}
+
+impl TenantRouter {
+    pub(crate) fn new(options: TenantOptions, schema: String) -> PrismaResult<Self> {
+        if !options.url_template.contains(TENANT_PLACEHOLDER) {
+            return Err(PrismaError::ConfigurationError(format!(
+                "The tenant URL template must contain the `{}` placeholder.",
+                TENANT_PLACEHOLDER
+            )));
+        }
+
+        Ok(Self {
+            options,
+            schema: RwLock::new(schema),
+            tenants: Mutex::new(HashMap::new()),
+            evictions: AtomicU64::new(0),
+        })
+    }
+
+    pub(crate) fn header(&self) -> &str {
+        &self.options.header
+    }
+
+    /// The context of the tenant, connecting to its database on its first request. `cx` is the
+    /// context of the engine, whose datamodel and options the tenant contexts share.
+    pub(crate) async fn context(&self, tenant: &str, cx: &PrismaContext) -> PrismaResult<Arc<PrismaContext>> {
+        validate_tenant_key(tenant)?;
+
+        if let Some(entry) = self.tenants.lock().await.get_mut(tenant) {
+            entry.requests += 1;
+            entry.last_used = Instant::now();
+
+            return Ok(entry.cx.clone());
+        }
+
+        // Connect without holding the lock, the requests of other tenants must not wait for it.
+        let tenant_cx = Arc::new(cx.with_datasource(self.tenant_configuration(tenant)?).await?);
+        let mut tenants = self.tenants.lock().await;
+
+        if !tenants.contains_key(tenant) {
+            self.evict(&mut tenants);
+        }
+
+        let now = Instant::now();
+        let entry = tenants.entry(tenant.to_owned()).or_insert_with(|| {
+            info!("Opened the connection pool of tenant `{}`.", tenant);
+
+            TenantEntry {
+                cx: tenant_cx,
+                requests: 0,
+                opened_at: now,
+                last_used: now,
+            }
+        });
+
+        entry.requests += 1;
+        entry.last_used = now;
+
+        Ok(entry.cx.clone())
+    }
+
+    /// Switches the tenant contexts to a reloaded datamodel, see `PrismaContext::reload`.
+    pub(crate) async fn reload(&self, schema: String, cx: &PrismaContext) -> PrismaResult<()> {
+        let mut tenants = self.tenants.lock().await;
+        let config = datamodel::parse_configuration_and_ignore_datasource_urls(&schema)
+            .map_err(|diagnostics| PrismaError::ConversionError(diagnostics, schema.clone()))?;
+
+        for entry in tenants.values_mut() {
+            entry.cx = Arc::new(entry.cx.reload(&config.subject, cx.datamodel().clone())?);
+        }
+
+        *self.schema.write().expect("tenant schema lock poisoned") = schema;
+
+        Ok(())
+    }
+
+    /// The metrics of the tenants with an open connection pool, and the number of pools closed
+    /// by the eviction policies so far.
+    pub(crate) async fn metrics(&self) -> (HashMap<String, TenantMetrics>, u64) {
+        let tenants = self.tenants.lock().await;
+
+        let metrics = tenants
+            .iter()
+            .map(|(tenant, entry)| {
+                let metrics = TenantMetrics {
+                    requests: entry.requests,
+                    pool_opened_ms_ago: entry.opened_at.elapsed().as_millis(),
+                    last_request_ms_ago: entry.last_used.elapsed().as_millis(),
+                    query_cache: entry.cx.query_cache().map(|cache| cache.metrics()),
+                };
+
+                (tenant.clone(), metrics)
+            })
+            .collect();
+
+        (metrics, self.evictions.load(Ordering::Relaxed))
+    }
+
+    /// Closes the pools of idle tenants, and the least recently used pool if there is no room for
+    /// a new one. In-flight requests of an evicted tenant finish with their clone of the context.
+    fn evict(&self, tenants: &mut HashMap<String, TenantEntry>) {
+        let idle_timeout = self.options.idle_timeout;
+        let before = tenants.len();
+
+        tenants.retain(|_, entry| entry.last_used.elapsed() < idle_timeout);
+
+        while tenants.len() >= self.options.max_pools.max(1) {
+            let least_recently_used = tenants
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(tenant, _)| tenant.clone());
+
+            match least_recently_used {
+                Some(tenant) => tenants.remove(&tenant),
+                None => break,
+            };
+        }
+
+        let evicted = before - tenants.len();
+
+        if evicted > 0 {
+            debug!("Closed the connection pools of {} tenants.", evicted);
+            self.evictions.fetch_add(evicted as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn tenant_configuration(&self, tenant: &str) -> PrismaResult<datamodel::Configuration> {
+        let schema = self.schema.read().expect("tenant schema lock poisoned");
+        let url = self.options.url_template.replace(TENANT_PLACEHOLDER, tenant);
+
+        let datasource_name = datamodel::parse_configuration_and_ignore_datasource_urls(&schema)
+            .ok()
+            .and_then(|config| config.subject.datasources.into_iter().next())
+            .map(|datasource| datasource.name)
+            .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
+
+        datamodel::parse_configuration_with_url_overrides(&schema, vec![(datasource_name, url)])
+            .map(|config| config.subject)
+            .map_err(|diagnostics| PrismaError::ConversionError(diagnostics, schema.clone()))
+    }
+}
+
+/// Tenant keys end up in connection strings, so only a conservative set of characters is allowed.
+fn validate_tenant_key(tenant: &str) -> PrismaResult<()> {
+    let is_valid = !tenant.is_empty()
+        && tenant.len() <= 63
+        && tenant
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(PrismaError::InvocationError(format!(
+            "Invalid tenant `{}`. Tenant keys consist of at most 63 letters, digits, `_` and `-`.",
+            tenant
+        )))
+    }
+}
@@ -0,0 +1,351 @@
+use super::{authorization_header, request_role, State};
+use crate::request_handlers::{
+    access::AccessRole,
+    graphql::{self, GraphQlBody},
+};
+use connector::QueryHints;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, RwLock,
+    },
+};
+use tide::Request;
+use tide_websockets::{Message, WebSocketConnection};
+
+/// Identifies a WebSocket connection for the lifetime of the process.
+pub(crate) type ConnectionId = u64;
+
+/// Messages sent by the client. The protocol is modelled after `graphql-ws`:
+/// a connection is initialized once, then operations are started and stopped by id.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    ConnectionInit,
+    /// Executes a query or mutation, answered with a single `data` message followed by `complete`.
+    Start {
+        id: String,
+        payload: GraphQlBody,
+    },
+    /// Subscribes to a topic registered on the server, answered with a `data` message per event.
+    Subscribe {
+        id: String,
+        payload: SubscribePayload,
+    },
+    /// Ends a subscription.
+    Stop {
+        id: String,
+    },
+    ConnectionTerminate,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribePayload {
+    topic: String,
+}
+
+/// Messages sent by the server.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage<'a> {
+    ConnectionAck,
+    ConnectionError { payload: String },
+    Data { id: &'a str, payload: serde_json::Value },
+    Error { id: &'a str, payload: String },
+    Complete { id: &'a str },
+}
+
+/// Keeps track of the open WebSocket connections and their subscriptions, and pushes events
+/// published on a topic to all the subscribers of that topic.
+#[derive(Default)]
+pub(crate) struct ConnectionManager {
+    next_connection_id: AtomicU64,
+    sockets: Mutex<HashMap<ConnectionId, WebSocketConnection>>,
+    subscriptions: RwLock<Subscriptions>,
+}
+
+impl ConnectionManager {
+    /// Makes a topic available for subscriptions.
+    pub(crate) fn register_topic(&self, topic: impl Into<String>) {
+        self.subscriptions.write().unwrap().register_topic(topic);
+    }
+
+    /// Sends the payload to every subscriber of the topic. Failing to reach a subscriber does
+    /// not affect the others, closed connections are cleaned up by their own handler.
+    pub(crate) async fn publish(&self, topic: &str, payload: serde_json::Value) {
+        let subscribers: Vec<(WebSocketConnection, String)> = {
+            let subscribers = self.subscriptions.read().unwrap().subscribers(topic);
+            let sockets = self.sockets.lock().unwrap();
+
+            subscribers
+                .into_iter()
+                .filter_map(|(connection_id, id)| sockets.get(&connection_id).map(|socket| (socket.clone(), id)))
+                .collect()
+        };
+
+        for (socket, id) in subscribers {
+            let message = ServerMessage::Data {
+                id: &id,
+                payload: payload.clone(),
+            };
+
+            if let Err(err) = socket.send_json(&message).await {
+                debug!("Failed to push an event on topic `{}`: {}", topic, err);
+            }
+        }
+    }
+
+    fn connect(&self, socket: WebSocketConnection) -> ConnectionId {
+        let id = self.next_connection_id.fetch_add(1, Ordering::SeqCst);
+
+        self.sockets.lock().unwrap().insert(id, socket);
+        id
+    }
+
+    fn disconnect(&self, connection_id: ConnectionId) {
+        self.sockets.lock().unwrap().remove(&connection_id);
+        self.subscriptions.write().unwrap().remove_connection(connection_id);
+    }
+
+    fn subscribe(&self, connection_id: ConnectionId, id: &str, topic: String) -> Result<(), String> {
+        if !self.sockets.lock().unwrap().contains_key(&connection_id) {
+            return Err("The connection is closed.".to_owned());
+        }
+
+        self.subscriptions.write().unwrap().subscribe(connection_id, id, topic)
+    }
+
+    /// Returns whether the subscription existed.
+    fn unsubscribe(&self, connection_id: ConnectionId, id: &str) -> bool {
+        self.subscriptions.write().unwrap().unsubscribe(connection_id, id)
+    }
+}
+
+/// The registered topics and the subscriptions of each connection to them.
+#[derive(Default)]
+struct Subscriptions {
+    /// The topics clients are allowed to subscribe to.
+    topics: HashSet<String>,
+    /// Connection -> subscription id -> topic.
+    by_connection: HashMap<ConnectionId, HashMap<String, String>>,
+}
+
+impl Subscriptions {
+    fn register_topic(&mut self, topic: impl Into<String>) {
+        self.topics.insert(topic.into());
+    }
+
+    fn subscribe(&mut self, connection_id: ConnectionId, id: &str, topic: String) -> Result<(), String> {
+        if !self.topics.contains(&topic) {
+            return Err(format!("Unknown topic `{}`.", topic));
+        }
+
+        let subscriptions = self.by_connection.entry(connection_id).or_default();
+
+        if subscriptions.contains_key(id) {
+            return Err(format!("A subscription with id `{}` already exists.", id));
+        }
+
+        subscriptions.insert(id.to_owned(), topic);
+
+        Ok(())
+    }
+
+    fn unsubscribe(&mut self, connection_id: ConnectionId, id: &str) -> bool {
+        self.by_connection
+            .get_mut(&connection_id)
+            .map(|subscriptions| subscriptions.remove(id).is_some())
+            .unwrap_or(false)
+    }
+
+    fn remove_connection(&mut self, connection_id: ConnectionId) {
+        self.by_connection.remove(&connection_id);
+    }
+
+    /// The connections and subscription ids subscribed to the topic.
+    fn subscribers(&self, topic: &str) -> Vec<(ConnectionId, String)> {
+        self.by_connection
+            .iter()
+            .flat_map(|(connection_id, subscriptions)| {
+                subscriptions
+                    .iter()
+                    .filter(|(_, subscribed_topic)| subscribed_topic.as_str() == topic)
+                    .map(move |(id, _)| (*connection_id, id.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Handles a WebSocket connection from the upgrade until it is closed. The role of the connection
+/// is determined by the `Authorization` header of the upgrade request.
+pub(crate) async fn handle_connection(req: Request<State>, socket: WebSocketConnection) -> tide::Result<()> {
+    let state = req.state().clone();
+    let authorization = authorization_header(&req);
+
+    let role = match request_role(state.access.as_deref(), authorization.as_deref()) {
+        Ok(role) => role,
+        Err(err) => {
+            let payload = err.to_string();
+            socket.send_json(&ServerMessage::ConnectionError { payload }).await?;
+
+            return Ok(());
+        }
+    };
+
+    let connection_id = state.connections.connect(socket.clone());
+
+    let result = connection_loop(&state, role, connection_id, socket).await;
+    state.connections.disconnect(connection_id);
+
+    result
+}
+
+/// Operations of a connection are executed one after the other, in the order they were received.
+async fn connection_loop(
+    state: &State,
+    role: Option<&AccessRole>,
+    connection_id: ConnectionId,
+    mut socket: WebSocketConnection,
+) -> tide::Result<()> {
+    let mut initialized = false;
+
+    while let Some(message) = socket.next().await {
+        let text = match message? {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let message: ClientMessage = match serde_json::from_str(&text) {
+            Ok(message) => message,
+            Err(err) => {
+                let payload = format!("Invalid message: {}", err);
+                socket.send_json(&ServerMessage::ConnectionError { payload }).await?;
+                continue;
+            }
+        };
+
+        match message {
+            ClientMessage::ConnectionInit => {
+                initialized = true;
+                socket.send_json(&ServerMessage::ConnectionAck).await?;
+            }
+
+            ClientMessage::ConnectionTerminate => break,
+
+            _ if !initialized => {
+                let payload = "The connection must be initialized with `connection_init` first.".to_owned();
+                socket.send_json(&ServerMessage::ConnectionError { payload }).await?;
+            }
+
+            ClientMessage::Start { id, payload } => {
+                let response = graphql::handle_as(payload, QueryHints::new(), role, state.cx()).await;
+                let payload = serde_json::to_value(&response)?;
+
+                socket.send_json(&ServerMessage::Data { id: &id, payload }).await?;
+                socket.send_json(&ServerMessage::Complete { id: &id }).await?;
+            }
+
+            ClientMessage::Subscribe { id, payload } => {
+                if let Err(payload) = state.connections.subscribe(connection_id, &id, payload.topic) {
+                    socket.send_json(&ServerMessage::Error { id: &id, payload }).await?;
+                }
+            }
+
+            ClientMessage::Stop { id } => {
+                if state.connections.unsubscribe(connection_id, &id) {
+                    socket.send_json(&ServerMessage::Complete { id: &id }).await?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_messages_follow_the_graphql_ws_protocol() {
+        let message: ClientMessage = serde_json::from_str(r#"{ "type": "connection_init" }"#).unwrap();
+        assert!(matches!(message, ClientMessage::ConnectionInit));
+
+        let message: ClientMessage =
+            serde_json::from_str(r#"{ "type": "subscribe", "id": "1", "payload": { "topic": "users" } }"#).unwrap();
+        assert!(
+            matches!(message, ClientMessage::Subscribe { ref id, ref payload } if id == "1" && payload.topic == "users")
+        );
+
+        let message: ClientMessage = serde_json::from_str(r#"{ "type": "stop", "id": "1" }"#).unwrap();
+        assert!(matches!(message, ClientMessage::Stop { ref id } if id == "1"));
+
+        assert!(serde_json::from_str::<ClientMessage>(r#"{ "type": "unknown" }"#).is_err());
+    }
+
+    #[test]
+    fn server_messages_follow_the_graphql_ws_protocol() {
+        let message = ServerMessage::Data {
+            id: "1",
+            payload: serde_json::json!({ "id": 1 }),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            serde_json::json!({ "type": "data", "id": "1", "payload": { "id": 1 } })
+        );
+
+        assert_eq!(
+            serde_json::to_value(&ServerMessage::ConnectionAck).unwrap(),
+            serde_json::json!({ "type": "connection_ack" })
+        );
+    }
+
+    #[test]
+    fn only_registered_topics_can_be_subscribed_to() {
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.register_topic("users");
+
+        assert!(subscriptions.subscribe(1, "a", "users".to_owned()).is_ok());
+        assert_eq!(
+            subscriptions.subscribe(1, "b", "posts".to_owned()),
+            Err("Unknown topic `posts`.".to_owned())
+        );
+    }
+
+    #[test]
+    fn subscription_ids_are_unique_per_connection() {
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.register_topic("users");
+
+        assert!(subscriptions.subscribe(1, "a", "users".to_owned()).is_ok());
+        assert!(subscriptions.subscribe(2, "a", "users".to_owned()).is_ok());
+        assert_eq!(
+            subscriptions.subscribe(1, "a", "users".to_owned()),
+            Err("A subscription with id `a` already exists.".to_owned())
+        );
+    }
+
+    #[test]
+    fn events_reach_the_current_subscribers_of_the_topic() {
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.register_topic("users");
+        subscriptions.register_topic("posts");
+
+        subscriptions.subscribe(1, "a", "users".to_owned()).unwrap();
+        subscriptions.subscribe(1, "b", "posts".to_owned()).unwrap();
+        subscriptions.subscribe(2, "c", "users".to_owned()).unwrap();
+        subscriptions.subscribe(3, "d", "users".to_owned()).unwrap();
+
+        assert!(subscriptions.unsubscribe(2, "c"));
+        assert!(!subscriptions.unsubscribe(2, "c"));
+        subscriptions.remove_connection(3);
+
+        assert_eq!(subscriptions.subscribers("users"), vec![(1, "a".to_owned())]);
+        assert_eq!(subscriptions.subscribers("posts"), vec![(1, "b".to_owned())]);
+    }
+}
@@ -0,0 +1,197 @@
+use super::test_api::*;
+use crate::{config::EngineConfig, request_handlers::access::AccessPolicy};
+use connector::QueryHints;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static DATAMODEL: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int     @id
+        title    String?
+        authorId Int
+        author   User    @relation(fields: [authorId], references: [id])
+    }
+"};
+
+fn access_policy() -> AccessPolicy {
+    let config = EngineConfig::parse(indoc! {r#"
+        [access.tokens]
+        "reporting-token" = "reporting"
+        "editor-token" = "editor"
+        "admin-token" = "admin"
+
+        [[access.roles.reporting]]
+        operations = ["findMany", "aggregate"]
+        models = ["User"]
+
+        [[access.roles.reporting]]
+        operations = ["findMany"]
+        models = ["Post"]
+
+        [[access.roles.editor]]
+        operations = ["updateOne"]
+        models = ["User", "Post"]
+
+        [[access.roles.admin]]
+        operations = ["*"]
+        models = ["*"]
+    "#})
+    .unwrap();
+
+    AccessPolicy::new(&config.access).unwrap()
+}
+
+#[test]
+fn requests_need_a_known_token() {
+    let policy = access_policy();
+
+    assert!(policy.role(Some("Bearer reporting-token")).is_ok());
+
+    for authorization in &[None, Some("reporting-token"), Some("Bearer other-token")] {
+        let error = user_facing_errors::Error::from(policy.role(*authorization).err().unwrap());
+        let error = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(error["error_code"], "P2026");
+    }
+}
+
+#[test]
+fn reloads_need_a_role_allowed_to_reload() {
+    let policy = access_policy();
+
+    assert!(policy
+        .role(Some("Bearer admin-token"))
+        .unwrap()
+        .authorize_reload()
+        .is_ok());
+
+    let error = policy.role(Some("Bearer editor-token")).unwrap().authorize_reload();
+    let error = serde_json::to_value(&user_facing_errors::Error::from(error.err().unwrap())).unwrap();
+
+    assert_eq!(error["error_code"], "P2025");
+    assert_eq!(
+        error["message"],
+        "The role `editor` is not allowed to run `reload` on the datamodel."
+    );
+}
+
+#[test]
+fn metrics_need_a_role_allowed_to_read_metrics() {
+    let policy = access_policy();
+
+    assert!(policy
+        .role(Some("Bearer admin-token"))
+        .unwrap()
+        .authorize_metrics()
+        .is_ok());
+
+    let error = policy.role(Some("Bearer reporting-token")).unwrap().authorize_metrics();
+    let error = serde_json::to_value(&user_facing_errors::Error::from(error.err().unwrap())).unwrap();
+
+    assert_eq!(error["error_code"], "P2025");
+    assert_eq!(
+        error["message"],
+        "The role `reporting` is not allowed to run `metrics` on the engine."
+    );
+}
+
+#[test_each_connector]
+async fn roles_only_run_the_operations_they_are_allowed_to(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(DATAMODEL).await?;
+    let policy = access_policy();
+    let role = policy.role(Some("Bearer reporting-token")).ok();
+
+    engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id } }"#)
+        .await;
+
+    let response = engine
+        .request_as(r#"query { findManyUser { id posts { id } } }"#, QueryHints::new(), role)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyUser"],
+        json!([{ "id": 1, "posts": [{ "id": 1 }] }])
+    );
+
+    let denied = &[
+        (
+            r#"mutation { createOneUser(data: { id: 2 }) { id } }"#,
+            "The role `reporting` is not allowed to run `createOne` on the model `User`.",
+        ),
+        (
+            r#"query { aggregatePost { count { _all } } }"#,
+            "The role `reporting` is not allowed to run `aggregate` on the model `Post`.",
+        ),
+        (
+            r#"query { aggregateUser(where: { posts: { some: { id: 1 } } }) { count { _all } } }"#,
+            "The role `reporting` is not allowed to run `aggregate` on the related model `Post`.",
+        ),
+    ];
+
+    for (query, message) in denied.iter() {
+        let response = engine.request_as(*query, QueryHints::new(), role).await;
+        let error = &response["errors"][0]["user_facing_error"];
+
+        assert_eq!(error["error_code"], "P2025");
+        assert_eq!(error["message"], *message);
+    }
+
+    // Nothing was written.
+    assert_eq!(
+        engine.request(r#"query { findManyUser { id } }"#).await["data"]["findManyUser"],
+        json!([{ "id": 1 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_writes_are_authorized_with_their_own_operation(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(DATAMODEL).await?;
+    let policy = access_policy();
+    let role = policy.role(Some("Bearer editor-token")).ok();
+
+    engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id } }"#)
+        .await;
+
+    let response = engine
+        .request_as(
+            r#"mutation { updateOneUser(where: { id: 1 }, data: { posts: { update: { where: { id: 1 }, data: { title: { set: "edited" } } } } }) { id } }"#,
+            QueryHints::new(),
+            role,
+        )
+        .await;
+
+    assert_eq!(response["data"]["updateOneUser"], json!({ "id": 1 }), "{}", response);
+
+    let response = engine
+        .request_as(
+            r#"mutation { updateOneUser(where: { id: 1 }, data: { posts: { deleteMany: {} } }) { id } }"#,
+            QueryHints::new(),
+            role,
+        )
+        .await;
+
+    let error = &response["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], "P2025");
+    assert_eq!(
+        error["message"],
+        "The role `editor` is not allowed to run `deleteMany` on the related model `Post`."
+    );
+
+    assert_eq!(
+        engine.request(r#"query { findManyPost { id title } }"#).await["data"]["findManyPost"],
+        json!([{ "id": 1, "title": "edited" }])
+    );
+
+    Ok(())
+}
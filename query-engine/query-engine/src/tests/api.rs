@@ -0,0 +1,77 @@
+use crate::api::{self, ffi, DmmfOptions};
+use serial_test::serial;
+use std::ffi::{CStr, CString};
+
+#[test]
+fn validate_returns_the_errors_of_an_invalid_datamodel() {
+    let dm = r#"
+        model Blog {
+            blogId String @id
+            author Author
+        }
+    "#;
+
+    let diagnostics = api::validate(dm, true);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "E023");
+    assert!(!diagnostics[0].is_warning);
+    assert!(diagnostics[0].text.contains("Author"));
+    assert!(dm[diagnostics[0].start..diagnostics[0].end].contains("Author"));
+}
+
+#[test]
+fn validate_ignores_missing_env_vars_only_if_asked_to() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = env("MISSING_ENV_VAR")
+        }
+
+        model Blog {
+            blogId String @id
+        }
+    "#;
+
+    assert!(api::validate(dm, true).is_empty());
+    assert!(api::validate(dm, false).iter().any(|diagnostic| !diagnostic.is_warning));
+}
+
+#[test]
+#[serial]
+fn dmmf_is_rendered_without_connecting_to_the_database() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:1/unreachable"
+        }
+
+        model Blog {
+            blogId String @id
+        }
+    "#;
+
+    let dmmf = api::dmmf(dm, DmmfOptions::default()).unwrap();
+
+    assert_eq!(dmmf.data_model["models"][0]["name"], "Blog");
+    assert!(!dmmf.schema.input_object_types.is_empty());
+}
+
+#[test]
+#[serial]
+fn dmmf_through_the_c_abi_returns_the_errors_of_an_invalid_datamodel() {
+    let dm = CString::new("model Blog { blogId String @id @unknown }").unwrap();
+
+    let json = unsafe {
+        let ptr = ffi::prisma_dmmf(dm.as_ptr(), false, false, false);
+        let json = CStr::from_ptr(ptr).to_str().unwrap().to_owned();
+        ffi::prisma_free_string(ptr);
+        json
+    };
+
+    let result: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let errors = result["errors"].as_array().unwrap();
+
+    assert!(!errors.is_empty());
+    assert_eq!(errors[0]["isWarning"], false);
+}
@@ -0,0 +1,114 @@
+use super::test_api::*;
+use connector::AuditEntry;
+use indoc::indoc;
+use query_core::AuditSink;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use test_macros::test_each_connector;
+
+static ITEMS: &str = indoc! {"
+    model Item {
+        id    Int    @id
+        name  String
+        stock Int
+    }
+"};
+
+#[derive(Default)]
+struct CollectingSink {
+    entries: Mutex<Vec<AuditEntry>>,
+}
+
+impl AuditSink for CollectingSink {
+    fn record(&self, entries: Vec<AuditEntry>) {
+        self.entries.lock().unwrap().extend(entries);
+    }
+}
+
+impl CollectingSink {
+    /// The operations and primary keys of the entries recorded since the last call, ordered by
+    /// primary key.
+    fn take(&self) -> Vec<(String, serde_json::Value)> {
+        let entries = std::mem::take(&mut *self.entries.lock().unwrap());
+        let mut entries: Vec<_> = entries
+            .into_iter()
+            .map(|entry| (entry.operation, entry.primary_key.unwrap_or(serde_json::Value::Null)))
+            .collect();
+
+        entries.sort_by_key(|(_, key)| key["id"].as_i64());
+        entries
+    }
+}
+
+async fn create_engine_with_items(api: &TestApi, sink: &Arc<CollectingSink>) -> anyhow::Result<QueryEngine> {
+    let query_engine = api.create_engine_with_audit_sink(&ITEMS, sink.clone()).await?;
+
+    for (id, stock) in [(1, 1), (2, 5), (3, 20)].iter() {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneItem(data: {{ id: {}, name: "item {}", stock: {} }}) {{ id }} }}"#,
+                id, id, stock
+            ))
+            .await;
+    }
+
+    assert_eq!(sink.take().len(), 3);
+
+    Ok(query_engine)
+}
+
+#[test_each_connector]
+async fn update_many_records_an_entry_per_updated_record(api: &TestApi) -> anyhow::Result<()> {
+    let sink = Arc::new(CollectingSink::default());
+    let query_engine = create_engine_with_items(api, &sink).await?;
+
+    let response = query_engine
+        .request(r#"mutation { updateManyItem(where: { stock: { lt: 10 } }, data: { stock: { set: 10 } }) { count } }"#)
+        .await;
+
+    assert_eq!(response["data"]["updateManyItem"]["count"], 2, "{}", response);
+    assert_eq!(
+        sink.take(),
+        vec![
+            ("update".to_owned(), json!({ "id": 1 })),
+            ("update".to_owned(), json!({ "id": 2 })),
+        ]
+    );
+
+    let response = query_engine
+        .request(r#"mutation { updateManyItem(where: { stock: { gt: 100 } }, data: { stock: { set: 0 } }) { count } }"#)
+        .await;
+
+    assert_eq!(response["data"]["updateManyItem"]["count"], 0, "{}", response);
+    assert!(sink.take().is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deletes_record_an_entry_per_deleted_record(api: &TestApi) -> anyhow::Result<()> {
+    let sink = Arc::new(CollectingSink::default());
+    let query_engine = create_engine_with_items(api, &sink).await?;
+
+    let response = query_engine
+        .request(r#"mutation { deleteManyItem(where: { stock: { gte: 5 } }) { count } }"#)
+        .await;
+
+    assert_eq!(response["data"]["deleteManyItem"]["count"], 2, "{}", response);
+    assert_eq!(
+        sink.take(),
+        vec![
+            ("delete".to_owned(), json!({ "id": 2 })),
+            ("delete".to_owned(), json!({ "id": 3 })),
+        ]
+    );
+
+    let response = query_engine
+        .request(r#"mutation { deleteOneItem(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(response["data"]["deleteOneItem"]["id"], 1, "{}", response);
+    assert_eq!(sink.take(), vec![("delete".to_owned(), json!({ "id": 1 }))]);
+
+    Ok(())
+}
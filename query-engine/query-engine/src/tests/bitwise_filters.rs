@@ -0,0 +1,145 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SETTINGS: &str = indoc! {"
+    model Setting {
+        id          Int    @id
+        flags       Int
+        permissions BigInt
+    }
+"};
+
+async fn create_engine_with_settings(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    let query_engine = api.create_engine(&SETTINGS).await?;
+
+    for (id, flags, permissions) in [
+        (1, 0b0001, 1i64 << 40),
+        (2, 0b0101, 0b0011),
+        (3, 0b0110, (1i64 << 40) | 0b0010),
+    ]
+    .iter()
+    {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneSetting(data: {{ id: {}, flags: {}, permissions: {} }}) {{ id }} }}"#,
+                id, flags, permissions
+            ))
+            .await;
+    }
+
+    Ok(query_engine)
+}
+
+async fn find_setting_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let response = query_engine
+        .request(format!(
+            r#"{{ findManySetting(where: {}, orderBy: {{ id: asc }}) {{ id }} }}"#,
+            filter
+        ))
+        .await;
+
+    response["data"]["findManySetting"].clone()
+}
+
+#[test_each_connector(tags("mysql", "postgres", "sqlite"))]
+async fn bitwise_filters_match_the_bits_of_the_mask(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_settings(api).await?;
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { hasBit: 4 } }").await,
+        json!([{ "id": 2 }, { "id": 3 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { hasBit: 5 } }").await,
+        json!([{ "id": 2 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { hasAnyBit: 3 } }").await,
+        json!([{ "id": 1 }, { "id": 2 }, { "id": 3 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { hasAnyBit: 8 } }").await,
+        json!([])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { not: { hasBit: 4 } } }").await,
+        json!([{ "id": 1 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ flags: { hasBit: 0 } }").await,
+        json!([{ "id": 1 }, { "id": 2 }, { "id": 3 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgres", "sqlite"))]
+async fn bitwise_filters_match_the_high_bits_of_big_ints(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_settings(api).await?;
+
+    assert_eq!(
+        find_setting_ids(
+            &query_engine,
+            &format!("{{ permissions: {{ hasBit: {} }} }}", 1i64 << 40)
+        )
+        .await,
+        json!([{ "id": 1 }, { "id": 3 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(
+            &query_engine,
+            &format!("{{ permissions: {{ hasBit: {} }} }}", (1i64 << 40) | 0b0010)
+        )
+        .await,
+        json!([{ "id": 3 }])
+    );
+
+    assert_eq!(
+        find_setting_ids(&query_engine, "{ permissions: { not: { hasAnyBit: 2 } } }").await,
+        json!([{ "id": 1 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn bitwise_filters_reject_negative_masks(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_settings(api).await?;
+
+    let response = query_engine
+        .request(r#"{ findManySetting(where: { flags: { hasAnyBit: -1 } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        response["errors"][0]["user_facing_error"]["error_code"], "P2019",
+        "{}",
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn bitwise_filters_are_not_available_on_sql_server(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SETTINGS).await?;
+
+    let response = query_engine
+        .request(r#"{ findManySetting(where: { flags: { hasBit: 1 } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        response["errors"][0]["user_facing_error"]["error_code"], "P2009",
+        "{}",
+        response
+    );
+
+    Ok(())
+}
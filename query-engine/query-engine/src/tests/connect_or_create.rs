@@ -0,0 +1,113 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static TAGGED_POSTS: &str = indoc! {"
+    model Post {
+        id   Int   @id
+        tags Tag[]
+    }
+
+    model Tag {
+        id    Int    @id @default(autoincrement())
+        name  String @unique
+        posts Post[]
+    }
+"};
+
+fn create_tagged_post(id: usize) -> String {
+    format!(
+        r#"mutation {{ createOnePost(data: {{ id: {}, tags: {{ connectOrCreate: {{ where: {{ name: "rust" }}, create: {{ name: "rust" }} }} }} }}) {{ id tags {{ name }} }} }}"#,
+        id
+    )
+}
+
+#[test_each_connector]
+async fn connect_or_create_creates_and_then_connects(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TAGGED_POSTS).await?;
+
+    for id in 1..=2 {
+        let response = query_engine.request(create_tagged_post(id)).await;
+
+        assert_eq!(
+            response["data"]["createOnePost"]["tags"],
+            serde_json::json!([{ "name": "rust" }])
+        );
+    }
+
+    let response = query_engine.request("query { findManyTag { name } }").await;
+    assert_eq!(response["data"]["findManyTag"], serde_json::json!([{ "name": "rust" }]));
+
+    Ok(())
+}
+
+// Concurrent transactions only see each other's records under read committed isolation, or the
+// read uncommitted isolation of the SQL Server tests.
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn concurrent_connect_or_create_connects_the_concurrently_created_record(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TAGGED_POSTS).await?;
+    let requests: Vec<String> = (1..=8).map(create_tagged_post).collect();
+    let responses = futures::future::join_all(requests.into_iter().map(|req| query_engine.request(req))).await;
+
+    for response in responses {
+        assert_eq!(
+            response["data"]["createOnePost"]["tags"],
+            serde_json::json!([{ "name": "rust" }]),
+            "{}",
+            response
+        );
+    }
+
+    let response = query_engine.request("query { findManyTag { name } }").await;
+    assert_eq!(response["data"]["findManyTag"], serde_json::json!([{ "name": "rust" }]));
+
+    Ok(())
+}
+
+// Under the repeatable read default of MySQL, the concurrently created record is not visible, and
+// SQLite can reject concurrent writes. The failed requests are rolled back, so they leave neither a
+// second tag nor posts without it behind.
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn concurrent_connect_or_create_creates_a_single_record(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TAGGED_POSTS).await?;
+    let requests: Vec<String> = (1..=8).map(create_tagged_post).collect();
+    let responses = futures::future::join_all(requests.into_iter().map(|req| query_engine.request(req))).await;
+
+    let mut created_posts = 0;
+
+    for response in responses {
+        if response["errors"].is_array() {
+            continue;
+        }
+
+        assert_eq!(
+            response["data"]["createOnePost"]["tags"],
+            serde_json::json!([{ "name": "rust" }]),
+            "{}",
+            response
+        );
+
+        created_posts += 1;
+    }
+
+    assert!(created_posts > 0);
+
+    let response = query_engine.request("query { findManyTag { name } }").await;
+    assert_eq!(response["data"]["findManyTag"], serde_json::json!([{ "name": "rust" }]));
+
+    let response = query_engine
+        .request(r#"query { findManyPost(where: { tags: { some: { name: "rust" } } }) { id } }"#)
+        .await;
+    assert_eq!(
+        response["data"]["findManyPost"].as_array().unwrap().len(),
+        created_posts
+    );
+
+    let response = query_engine.request("query { findManyPost { id } }").await;
+    assert_eq!(
+        response["data"]["findManyPost"].as_array().unwrap().len(),
+        created_posts
+    );
+
+    Ok(())
+}
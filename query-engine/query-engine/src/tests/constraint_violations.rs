@@ -0,0 +1,61 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static USERS: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        email String @unique @map("email_address")
+        age   Int    @default(0)
+
+        @@map("users")
+    }
+"#};
+
+fn create_user(id: u32, email: &str, age: i32) -> String {
+    format!(
+        r#"mutation {{ createOneUser(data: {{ id: {}, email: "{}", age: {} }}) {{ id }} }}"#,
+        id, email, age
+    )
+}
+
+#[test_each_connector]
+async fn unique_violations_report_the_fields_of_the_constraint(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USERS).await?;
+
+    query_engine.request(create_user(1, "a@prisma.io", 20)).await;
+    let response = query_engine.request(create_user(2, "a@prisma.io", 20)).await;
+
+    let error = &response["errors"][0]["user_facing_error"];
+    assert_eq!(error["error_code"], "P2002");
+
+    let details = &error["meta"]["constraint_details"];
+    assert_eq!(details["table"], "users");
+    assert_eq!(details["columns"], json!(["email_address"]));
+    assert_eq!(details["model_name"], "User");
+    assert_eq!(details["fields"], json!(["email"]));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_violations_report_the_violated_constraint(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USERS).await?;
+
+    let add_check = r#"mutation { executeRaw(query: "ALTER TABLE users ADD CONSTRAINT users_age_check CHECK (age >= 0)", parameters: "[]") }"#;
+    query_engine.request(add_check).await;
+
+    let response = query_engine.request(create_user(1, "a@prisma.io", -1)).await;
+
+    let error = &response["errors"][0]["user_facing_error"];
+    assert_eq!(error["error_code"], "P2024");
+    assert_eq!(error["meta"]["target"], "users_age_check");
+
+    let details = &error["meta"]["constraint_details"];
+    assert_eq!(details["name"], "users_age_check");
+    assert_eq!(details["table"], "users");
+    assert_eq!(details["model_name"], "User");
+
+    Ok(())
+}
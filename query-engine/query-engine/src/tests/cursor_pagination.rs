@@ -0,0 +1,83 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static ITEMS: &str = indoc! {"
+    model Item {
+        id    Int @id
+        score Int
+    }
+"};
+
+async fn create_items(query_engine: &QueryEngine) {
+    for (id, score) in [(1, 10), (2, 20), (3, 10), (4, 20), (5, 10), (6, 30)].iter() {
+        let mutation = format!(
+            r#"mutation {{ createOneItem(data: {{ id: {}, score: {} }}) {{ id }} }}"#,
+            id, score
+        );
+        query_engine.request(mutation).await;
+    }
+}
+
+fn ids(response: &serde_json::Value) -> Vec<i64> {
+    response["data"]["findManyItem"]
+        .as_array()
+        .unwrap_or_else(|| panic!("{}", response))
+        .iter()
+        .map(|item| item["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[test_each_connector]
+async fn pages_of_a_non_unique_ordering_are_disjoint(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEMS).await?;
+    create_items(&query_engine).await;
+
+    // Ties of the score are broken by the id: 1, 3, 5, 2, 4, 6.
+    let first_page = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: asc }, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&first_page), vec![1, 3]);
+
+    let second_page = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: asc }, cursor: { id: 3 }, skip: 1, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&second_page), vec![5, 2]);
+
+    let third_page = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: asc }, cursor: { id: 2 }, skip: 1, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&third_page), vec![4, 6]);
+
+    let previous_page = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: asc }, cursor: { id: 2 }, skip: 1, take: -2) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&previous_page), vec![3, 5]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn ties_of_a_descending_ordering_are_broken_in_ascending_order(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEMS).await?;
+    create_items(&query_engine).await;
+
+    // 6, then the ties 2, 4 and 1, 3, 5 by ascending id.
+    let response = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: desc }, cursor: { id: 4 }, skip: 1, take: 3) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&response), vec![1, 3, 5]);
+
+    let response = query_engine
+        .request(r#"query { findManyItem(orderBy: { score: desc }, cursor: { id: 2 }, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(ids(&response), vec![2, 4]);
+
+    Ok(())
+}
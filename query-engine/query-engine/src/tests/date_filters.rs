@@ -0,0 +1,150 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static EVENTS: &str = indoc! {"
+    model Event {
+        id        Int      @id
+        createdAt DateTime
+    }
+"};
+
+/// Events on both sides of the boundaries of May 2021 and of the year 2021.
+async fn create_engine_with_events(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    let query_engine = api.create_engine(&EVENTS).await?;
+
+    for (id, created_at) in [
+        (1, "2021-04-30T23:59:59.999Z"),
+        (2, "2021-05-01T00:00:00.000Z"),
+        (3, "2021-05-31T23:30:00.000Z"),
+        (4, "2021-06-01T00:00:00.000Z"),
+        (5, "2020-12-31T23:59:59.000Z"),
+        (6, "2021-01-01T00:00:00.000Z"),
+    ]
+    .iter()
+    {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneEvent(data: {{ id: {}, createdAt: "{}" }}) {{ id }} }}"#,
+                id, created_at
+            ))
+            .await;
+    }
+
+    Ok(query_engine)
+}
+
+async fn find_event_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let response = query_engine
+        .request(format!(
+            r#"{{ findManyEvent(where: {{ createdAt: {} }}, orderBy: {{ id: asc }}) {{ id }} }}"#,
+            filter
+        ))
+        .await;
+
+    response["data"]["findManyEvent"].clone()
+}
+
+#[test_each_connector]
+async fn date_equals_matches_the_whole_day_across_month_boundaries(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_events(api).await?;
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2021-04-30" }"#).await,
+        json!([{ "id": 1 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2021-05-01" }"#).await,
+        json!([{ "id": 2 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2021-05-31" }"#).await,
+        json!([{ "id": 3 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2021-05-31T12:00:00.000Z" }"#).await,
+        json!([{ "id": 3 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ not: { dateEquals: "2021-05-01" } }"#).await,
+        json!([{ "id": 1 }, { "id": 3 }, { "id": 4 }, { "id": 5 }, { "id": 6 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn date_equals_matches_the_whole_day_across_year_boundaries(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_events(api).await?;
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2020-12-31" }"#).await,
+        json!([{ "id": 5 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateEquals: "2021-01-01" }"#).await,
+        json!([{ "id": 6 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateLt: "2021-01-01" }"#).await,
+        json!([{ "id": 5 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn date_comparisons_include_or_exclude_whole_days(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_events(api).await?;
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateLt: "2021-05-01" }"#).await,
+        json!([{ "id": 1 }, { "id": 5 }, { "id": 6 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateLte: "2021-05-01" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }, { "id": 5 }, { "id": 6 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateGt: "2021-05-31" }"#).await,
+        json!([{ "id": 4 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ dateGte: "2021-05-31" }"#).await,
+        json!([{ "id": 3 }, { "id": 4 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, r#"{ not: { dateGte: "2021-05-31" } }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }, { "id": 5 }, { "id": 6 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn date_filters_reject_invalid_dates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine_with_events(api).await?;
+
+    let response = query_engine
+        .request(r#"{ findManyEvent(where: { createdAt: { dateEquals: "2021-02-30" } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        response["errors"][0]["user_facing_error"]["error_code"], "P2019",
+        "{}",
+        response
+    );
+
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use crate::{
     cli::CliCommand,
     opt::{CliOpt, PrismaOpt, Subcommand},
+    request_handlers::graphql::GraphQLSchemaRenderer,
     PrismaResult,
 };
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema_builder, BuildMode, QuerySchema};
+use query_core::{schema::QuerySchemaRenderer, schema_builder, BuildMode, QuerySchema};
 use serial_test::serial;
 use std::sync::Arc;
 
@@ -73,6 +74,178 @@ fn must_fail_if_the_schema_is_invalid() {
     assert!(test_dmmf_cli_command(schema).is_err());
 }
 
+#[test]
+#[serial]
+fn sdl_must_include_filter_input_objects_and_custom_scalars() {
+    let dm = r#"
+        model Blog {
+            blogId  String   @id
+            views   Int
+            created DateTime
+            meta    Json?
+        }
+    "#;
+
+    let (query_schema, _) = get_query_schema(dm);
+    let sdl = GraphQLSchemaRenderer::render(Arc::new(query_schema));
+
+    assert!(sdl.contains("input BlogWhereInput {"));
+    assert!(sdl.contains("input IntFilter {"));
+    assert!(sdl.contains("input NestedIntFilter {"));
+    assert!(sdl.contains("input DateTimeFilter {"));
+    assert!(sdl.contains("scalar DateTime"));
+    assert!(sdl.contains("scalar Json"));
+    assert!(sdl.contains("type Mutation {"));
+}
+
+#[test]
+#[serial]
+fn dmmf_diff_must_report_breaking_field_changes() {
+    let previous = r#"
+        model Blog {
+            blogId String @id
+            title  String
+        }
+    "#;
+
+    let next = r#"
+        model Blog {
+            blogId   String  @id
+            subtitle String?
+        }
+
+        model Post {
+            id Int @id
+        }
+    "#;
+
+    let (previous_schema, previous_dm) = get_query_schema(previous);
+    let (next_schema, next_dm) = get_query_schema(next);
+
+    let previous = crate::dmmf::render_dmmf(&previous_dm, Arc::new(previous_schema));
+    let next = crate::dmmf::render_dmmf(&next_dm, Arc::new(next_schema));
+    let diff = crate::dmmf::diff(&previous.schema, &next.schema);
+
+    assert!(diff.breaking);
+    assert!(diff.output_object_types.added.contains(&"Post".to_owned()));
+
+    let blog = diff
+        .output_object_types
+        .changed
+        .iter()
+        .find(|change| change.name == "Blog")
+        .unwrap();
+
+    assert!(blog.breaking);
+    assert_eq!(blog.removed_fields, vec!["title".to_owned()]);
+    assert_eq!(blog.added_fields, vec!["subtitle".to_owned()]);
+
+    let unchanged = crate::dmmf::diff(&previous.schema, &previous.schema);
+
+    assert!(!unchanged.breaking);
+    assert!(unchanged.output_object_types.changed.is_empty());
+}
+
+#[test]
+#[serial]
+fn dmmf_must_expose_the_implicit_order_by_of_models() {
+    let dm = r#"
+        model Blog {
+            blogId  String @id
+            created DateTime
+        }
+
+        model Post {
+            blogId String
+            number Int
+
+            @@id([blogId, number])
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let fields_of = |model: &str| {
+        dmmf.mappings
+            .implicit_order_by
+            .iter()
+            .find(|order_by| order_by.model == model)
+            .map(|order_by| order_by.fields.clone())
+            .unwrap()
+    };
+
+    assert_eq!(fields_of("Blog"), vec!["blogId".to_owned()]);
+    assert_eq!(fields_of("Post"), vec!["blogId".to_owned(), "number".to_owned()]);
+}
+
+#[test]
+#[serial]
+fn dmmf_and_sdl_must_be_byte_stable() {
+    let dm = r#"
+        model User {
+            id      Int      @id
+            role    Role
+            posts   Post[]
+            profile Profile?
+        }
+
+        model Post {
+            id       Int    @id
+            authorId Int
+            author   User   @relation(fields: [authorId], references: [id])
+            tags     Tag[]
+        }
+
+        model Profile {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }
+
+        model Tag {
+            id    Int    @id
+            posts Post[]
+        }
+
+        enum Role {
+            USER
+            ADMIN
+        }
+    "#;
+
+    let render = || {
+        let (query_schema, datamodel) = get_query_schema(dm);
+        let query_schema = Arc::new(query_schema);
+        let dmmf = crate::dmmf::render_dmmf(&datamodel, query_schema.clone());
+
+        (
+            serde_json::to_string(&dmmf).unwrap(),
+            GraphQLSchemaRenderer::render(query_schema),
+            dmmf,
+        )
+    };
+
+    let (dmmf, sdl, rendered) = render();
+
+    for _ in 0..5 {
+        let (next_dmmf, next_sdl, _) = render();
+
+        assert_eq!(dmmf, next_dmmf);
+        assert_eq!(sdl, next_sdl);
+    }
+
+    let names: Vec<&str> = rendered.schema.output_object_types["model"]
+        .iter()
+        .map(|typ| typ.name.as_str())
+        .collect();
+
+    let mut sorted = names.clone();
+    sorted.sort();
+
+    assert_eq!(names, sorted);
+}
+
 fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
     feature_flags::initialize(&[String::from("all")]).unwrap();
 
@@ -80,16 +253,34 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         host: "".to_string(),
         datamodel: Some(schema.to_string()),
         datamodel_path: None,
+        allowed_query_hints: vec![],
+        config: None,
+        connect_or_create_retries: None,
+        datasource_config: Default::default(),
+        access_config: Default::default(),
+        audit_stream: false,
+        enable_datamodel_reload: false,
         enable_debug_mode: false,
         enable_raw_queries: false,
         enable_playground: false,
+        group_by_take_limit: None,
+        health_check_interval_ms: 5000,
+        slow_query_threshold_ms: None,
         legacy: false,
+        max_tenant_pools: 100,
+        read_only: false,
         log_format: None,
         overwrite_datasources: None,
         port: 123,
+        print_sdl: false,
+        query_cache_max_bytes: None,
+        query_cache_ttl_ms: None,
         raw_feature_flags: vec![],
         unix_path: None,
         subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
+        tenant_header: None,
+        tenant_pool_idle_timeout_ms: 600000,
+        tenant_url_template: None,
     };
 
     let cli_cmd = CliCommand::from_opt(&prisma_opt)?.unwrap();
@@ -115,7 +306,7 @@ fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Dat
     let internal_ref = internal_dm_template.build("db".to_owned());
 
     (
-        schema_builder::build(internal_ref, BuildMode::Modern, false, capabilities),
+        schema_builder::build(internal_ref, BuildMode::Modern, false, false, capabilities),
         dm,
     )
 }
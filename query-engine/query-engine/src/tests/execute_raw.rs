@@ -379,3 +379,78 @@ async fn parameter_escaping(api: &TestApi) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn read_only_mode_rejects_writes(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_read_only_engine(&TODO).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneTodo(data: { title: "title1" }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(mutation).await;
+    assert!(result["errors"][0]["error"].is_string());
+
+    let insert = Insert::single_into("Todo").value("id", "id1").value("title", "title1");
+    let (query, params) = api.to_sql_string(insert)?;
+
+    let result = query_engine.request(execute_raw(&query, params)).await;
+    let error_code = result["errors"][0]["user_facing_error"]["error_code"].as_str();
+    assert_eq!(Some("P2019"), error_code);
+
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").value(asterisk()))?;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": []
+            }
+        }),
+        query_engine.request(query_raw(&query, vec![])).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn find_raw_maps_columns_onto_the_model(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let insert = Insert::single_into("Todo").value("id", "id1").value("title", "title1");
+    let (query, params) = api.to_sql_string(insert)?;
+    query_engine.request(execute_raw(&query, params)).await;
+
+    // Columns are matched by name, regardless of their order in the query.
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").column("title").column("id"))?;
+
+    let find_raw = format!(
+        r#"query {{ findRawTodo(query: "{}") {{ id title }} }}"#,
+        query.replace("\"", "\\\"")
+    );
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findRawTodo": [{"id": "id1", "title": "title1"}]
+            }
+        }),
+        query_engine.request(find_raw).await
+    );
+
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").column("id"))?;
+
+    let find_raw = format!(
+        r#"query {{ findRawTodo(query: "{}") {{ id title }} }}"#,
+        query.replace("\"", "\\\"")
+    );
+
+    let result = query_engine.request(find_raw).await;
+    let error_code = result["errors"][0]["user_facing_error"]["error_code"].as_str();
+    assert_eq!(Some("P2010"), error_code);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::test_api::*;
+use connector::{QueryHints, QueryPlans};
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+fn explain() -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.explain(QueryPlans::new());
+    hints
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn explained_queries_return_their_plans(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = r#"mutation { createOneTodo(data: { id: 1, title: "Write tests" }) { id } }"#;
+    let response = query_engine.request_with_hints(mutation, explain()).await;
+
+    assert_eq!(response["data"]["createOneTodo"]["id"], 1);
+    assert!(!response["extensions"]["queryPlans"].as_array().unwrap().is_empty());
+
+    let query = r#"query { findManyTodo(where: { title: "Write tests" }) { id } }"#;
+    let response = query_engine.request_with_hints(query, explain()).await;
+    let plans = response["extensions"]["queryPlans"].as_array().unwrap();
+
+    assert_eq!(response["data"]["findManyTodo"][0]["id"], 1);
+    assert_eq!(plans.len(), 1);
+    assert!(plans[0]["query"].as_str().unwrap().starts_with("SELECT"));
+    assert!(!plans[0]["plan"].is_null());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn queries_without_explain_have_no_extensions(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine.request(r#"query { findManyTodo { id } }"#).await;
+
+    assert!(response.get("extensions").is_none());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn explaining_queries_is_not_supported_on_sqlite(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo { id } }"#, explain())
+        .await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(
+        error.contains("Explaining queries is not supported on sqlite."),
+        "{}",
+        error
+    );
+
+    Ok(())
+}
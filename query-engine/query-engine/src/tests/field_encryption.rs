@@ -0,0 +1,132 @@
+use super::test_api::*;
+use indoc::indoc;
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use query_core::{FieldEncryption, FieldEncryptionResult};
+use serde_json::json;
+use std::sync::Arc;
+use test_macros::test_each_connector;
+
+static VAULTS: &str = indoc! {"
+    model Vault {
+        id     Int    @id
+        label  String
+        secret String @encrypted
+    }
+"};
+
+/// Reverses the values and prefixes them with `enc:`.
+struct Reverse;
+
+impl FieldEncryption for Reverse {
+    fn encrypt(&self, _field: &ScalarFieldRef, value: PrismaValue) -> FieldEncryptionResult {
+        match value {
+            PrismaValue::String(plain) => Ok(PrismaValue::String(format!(
+                "enc:{}",
+                plain.chars().rev().collect::<String>()
+            ))),
+            value => Err(format!("Unexpected value {:?}", value).into()),
+        }
+    }
+
+    fn decrypt(&self, _field: &ScalarFieldRef, value: PrismaValue) -> FieldEncryptionResult {
+        match value {
+            PrismaValue::String(cipher) if cipher.starts_with("enc:") => {
+                Ok(PrismaValue::String(cipher["enc:".len()..].chars().rev().collect()))
+            }
+            value => Err(format!("`{:?}` is not encrypted", value).into()),
+        }
+    }
+}
+
+#[test_each_connector]
+async fn encrypted_fields_are_stored_encrypted_and_read_decrypted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_field_encryption(VAULTS, Arc::new(Reverse))
+        .await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneVault(data: { id: 1, label: "a", secret: "hunter2" }) { id secret } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["createOneVault"],
+        json!({ "id": 1, "secret": "hunter2" }),
+        "{}",
+        response
+    );
+
+    query_engine
+        .request(r#"mutation { createOneVault(data: { id: 2, label: "b", secret: "swordfish" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneVault(where: { id: 2 }, data: { secret: "opensesame" }) { secret } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["updateOneVault"]["secret"], "opensesame",
+        "{}",
+        response
+    );
+
+    let response = query_engine
+        .request(r#"{ findManyVault(orderBy: { id: asc }) { id secret } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyVault"],
+        json!([{ "id": 1, "secret": "hunter2" }, { "id": 2, "secret": "opensesame" }]),
+        "{}",
+        response
+    );
+
+    // Without the hooks, the engine reads what is stored in the database.
+    let plain_engine = api.create_engine_without_migration(VAULTS).await?;
+
+    let response = plain_engine
+        .request(r#"{ findManyVault(orderBy: { id: asc }) { secret } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyVault"],
+        json!([{ "secret": "enc:2retnuh" }, { "secret": "enc:emasesnepo" }]),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn encrypted_fields_can_not_be_filtered_ordered_or_grouped_by(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_field_encryption(VAULTS, Arc::new(Reverse))
+        .await?;
+
+    let queries = [
+        r#"{ findManyVault(where: { secret: "hunter2" }) { id } }"#,
+        r#"{ findManyVault(orderBy: { secret: asc }) { id } }"#,
+        r#"{ findManyVault(distinct: [secret]) { id } }"#,
+        r#"{ groupByVault(by: [secret]) { secret } }"#,
+        r#"{ aggregateVault { min { secret } } }"#,
+        r#"mutation { deleteManyVault(where: { secret: { contains: "hunter" } }) { count } }"#,
+    ];
+
+    for query in queries.iter() {
+        let response = query_engine.request(*query).await;
+
+        assert_eq!(
+            response["errors"][0]["user_facing_error"]["error_code"], "P2009",
+            "{}: {}",
+            query, response
+        );
+    }
+
+    let response = query_engine
+        .request(r#"{ groupByVault(by: [label], orderBy: { label: asc }) { label count { secret } } }"#)
+        .await;
+
+    assert!(response["errors"].is_null(), "{}", response);
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static RELATIONS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int   @id
+        authorId Int
+        author   User  @relation(fields: [authorId], references: [id])
+        tags     Tag[]
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[]
+    }
+"};
+
+/// The tables of `RELATIONS`, without any foreign keys, like a database created before the
+/// relations were constrained.
+static UNCONSTRAINED: &str = indoc! {r#"
+    model User {
+        id Int @id
+    }
+
+    model Post {
+        id       Int @id
+        authorId Int
+    }
+
+    model Tag {
+        id Int @id
+    }
+
+    model PostToTag {
+        A Int
+        B Int
+
+        @@unique([A, B])
+        @@map("_PostToTag")
+    }
+"#};
+
+#[test_each_connector(tags("sqlite"))]
+async fn foreign_key_enforcement_is_decided_from_the_schema(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(RELATIONS).await?;
+
+    assert!(query_engine.connector().enforces_foreign_keys().await?);
+
+    let query_engine = api.create_engine(UNCONSTRAINED).await?;
+
+    assert!(!query_engine.connector().enforces_foreign_keys().await?);
+
+    Ok(())
+}
+
+/// Changes the ids of a user and of a post, and checks that the post and the relation table
+/// follow them.
+async fn assert_updates_of_referenced_fields_are_cascaded(query_engine: &QueryEngine) {
+    let mutation = r#"mutation { createOneUser(data: { id: 1, posts: { create: { id: 1, tags: { create: { id: 1 } } } } }) { id } }"#;
+    let response = query_engine.request(mutation).await;
+    assert_eq!(response["data"]["createOneUser"]["id"], 1, "{}", response);
+
+    // The foreign key of the post follows the id of its author.
+    let response = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { id: { set: 2 } }) { id } }"#)
+        .await;
+    assert_eq!(response["data"]["updateOneUser"]["id"], 2, "{}", response);
+
+    let response = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }) { authorId author { id } } }"#)
+        .await;
+    assert_eq!(
+        response["data"]["findUniquePost"],
+        json!({ "authorId": 2, "author": { "id": 2 } })
+    );
+
+    // The relation table rows follow the id of the post.
+    let response = query_engine
+        .request(r#"mutation { updateManyPost(where: { id: 1 }, data: { id: { set: 3 } }) { count } }"#)
+        .await;
+    assert_eq!(response["data"]["updateManyPost"]["count"], 1, "{}", response);
+
+    let response = query_engine
+        .request(r#"query { findUniqueTag(where: { id: 1 }) { posts { id } } }"#)
+        .await;
+    assert_eq!(response["data"]["findUniqueTag"]["posts"], json!([{ "id": 3 }]));
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn updates_of_referenced_fields_are_cascaded_without_foreign_keys(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(UNCONSTRAINED).await?;
+    let query_engine = api.create_engine_without_migration(RELATIONS).await?;
+
+    assert!(!query_engine.connector().enforces_foreign_keys().await?);
+    assert_updates_of_referenced_fields_are_cascaded(&query_engine).await;
+
+    // Referenced fields can only be set, the new values must be known up front.
+    let response = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 2 }, data: { id: { increment: 1 } }) { id } }"#)
+        .await;
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("can only be set to a new value"), "{}", error);
+
+    let response = query_engine
+        .request(r#"query { findUniqueUser(where: { id: 2 }) { posts { id } } }"#)
+        .await;
+    assert_eq!(response["data"]["findUniqueUser"]["posts"], json!([{ "id": 3 }]));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn updates_of_referenced_fields_are_cascaded_by_foreign_keys(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(RELATIONS).await?;
+
+    assert!(query_engine.connector().enforces_foreign_keys().await?);
+    assert_updates_of_referenced_fields_are_cascaded(&query_engine).await;
+
+    // The database cascades the update, so the new value doesn't need to be known up front.
+    let response = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 2 }, data: { id: { increment: 1 } }) { id } }"#)
+        .await;
+    assert_eq!(response["data"]["updateOneUser"]["id"], 3, "{}", response);
+
+    let response = query_engine
+        .request(r#"query { findUniqueUser(where: { id: 3 }) { posts { id } } }"#)
+        .await;
+    assert_eq!(response["data"]["findUniqueUser"]["posts"], json!([{ "id": 3 }]));
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+// The `count` field has the name of an aggregate.
+static SALES: &str = indoc! {r#"
+    model Sale {
+        id     Int    @id
+        region String
+        count  Int
+    }
+"#};
+
+#[test_each_connector]
+async fn group_by_can_order_by_aggregates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+
+    for (id, region, count) in [(1, "east", 1), (2, "east", 2), (3, "west", 5), (4, "north", 4)].iter() {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneSale(data: {{ id: {}, region: "{}", count: {} }}) {{ id }} }}"#,
+                id, region, count
+            ))
+            .await;
+    }
+
+    let response = query_engine
+        .request(indoc! {r#"
+            {
+                groupBySale(by: [region], orderBy: [{ _sum: { count: desc } }]) {
+                    region
+                    sum { count }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        response["data"]["groupBySale"],
+        json!([
+            { "region": "west", "sum": { "count": 5 } },
+            { "region": "north", "sum": { "count": 4 } },
+            { "region": "east", "sum": { "count": 3 } },
+        ]),
+        "{}",
+        response
+    );
+
+    let response = query_engine
+        .request(indoc! {r#"
+            {
+                groupBySale(by: [region], orderBy: [{ _count: { id: desc } }, { region: asc }]) {
+                    region
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        response["data"]["groupBySale"],
+        json!([{ "region": "east" }, { "region": "north" }, { "region": "west" }]),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
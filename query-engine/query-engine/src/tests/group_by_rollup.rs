@@ -0,0 +1,66 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SALES: &str = indoc! {r#"
+    model Sale {
+        id      Int    @id
+        region  String
+        product String @map("FROM")
+        amount  Int
+    }
+"#};
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn rollup_adds_subtotal_rows(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+
+    for (id, region, product, amount) in [
+        (1, "east", "a", 10),
+        (2, "east", "b", 20),
+        (3, "west", "a", 5),
+        (4, "west", "c", 1),
+    ]
+    .iter()
+    {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneSale(data: {{ id: {}, region: "{}", product: "{}", amount: {} }}) {{ id }} }}"#,
+                id, region, product, amount
+            ))
+            .await;
+    }
+
+    let response = query_engine
+        .request(indoc! {r#"
+            {
+                groupBySale(by: [region, product], rollup: true, where: { product: { not: "c" } }) {
+                    region
+                    product
+                    _subtotal
+                    count { _all }
+                    sum { amount }
+                }
+            }
+        "#})
+        .await;
+
+    // The databases sort the nulls of the subtotals differently.
+    let mut rows = response["data"]["groupBySale"].as_array().expect("rows").clone();
+    rows.sort_by_key(|row| row.to_string());
+
+    let mut expected = vec![
+        json!({ "region": "east", "product": "a", "_subtotal": false, "count": { "_all": 1 }, "sum": { "amount": 10 } }),
+        json!({ "region": "east", "product": "b", "_subtotal": false, "count": { "_all": 1 }, "sum": { "amount": 20 } }),
+        json!({ "region": "west", "product": "a", "_subtotal": false, "count": { "_all": 1 }, "sum": { "amount": 5 } }),
+        json!({ "region": "east", "product": null, "_subtotal": true, "count": { "_all": 2 }, "sum": { "amount": 30 } }),
+        json!({ "region": "west", "product": null, "_subtotal": true, "count": { "_all": 1 }, "sum": { "amount": 5 } }),
+        json!({ "region": null, "product": null, "_subtotal": true, "count": { "_all": 3 }, "sum": { "amount": 35 } }),
+    ];
+    expected.sort_by_key(|row| row.to_string());
+
+    assert_eq!(rows, expected, "{}", response);
+
+    Ok(())
+}
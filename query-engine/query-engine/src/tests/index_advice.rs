@@ -0,0 +1,81 @@
+use super::test_api::*;
+use connector::{IndexAdvice, QueryHints};
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        email String @unique
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id        Int      @id
+        title     String
+        published Boolean
+        createdAt DateTime
+        authorId  Int
+        author    User     @relation(fields: [authorId], references: [id])
+
+        @@index([published, createdAt])
+    }
+"};
+
+fn advise_indexes() -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.advise_indexes(IndexAdvice::new());
+    hints
+}
+
+#[test_each_connector]
+async fn unindexed_filters_and_orderings_are_reported(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let query = r#"query { findManyPost(where: { title: "a", published: true }, orderBy: { authorId: asc }) { id } }"#;
+    let response = query_engine.request_with_hints(query, advise_indexes()).await;
+
+    assert_eq!(response["data"]["findManyPost"], serde_json::json!([]));
+
+    let missing_indexes = response["extensions"]["missingIndexes"].as_array().unwrap();
+    assert_eq!(missing_indexes.len(), 2, "{:?}", missing_indexes);
+
+    assert_eq!(missing_indexes[0]["model"], "Post");
+    assert_eq!(missing_indexes[0]["fields"], serde_json::json!(["title"]));
+    assert_eq!(missing_indexes[0]["usage"], "filter");
+    assert_eq!(missing_indexes[0]["suggestion"], "@@index([title])");
+
+    assert_eq!(missing_indexes[1]["fields"], serde_json::json!(["authorId"]));
+    assert_eq!(missing_indexes[1]["usage"], "orderBy");
+    assert_eq!(missing_indexes[1]["suggestion"], "@@index([authorId])");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relation_filters_are_checked_against_the_related_model(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let query = r#"query { findManyPost(where: { author: { is: { name: "a" } } }) { id } }"#;
+    let response = query_engine.request_with_hints(query, advise_indexes()).await;
+
+    let missing_indexes = response["extensions"]["missingIndexes"].as_array().unwrap();
+    assert_eq!(missing_indexes.len(), 1, "{:?}", missing_indexes);
+    assert_eq!(missing_indexes[0]["model"], "User");
+    assert_eq!(missing_indexes[0]["suggestion"], "@@index([name])");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn indexed_fields_are_not_reported(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let query = r#"query { findManyUser(where: { email: "a" }, orderBy: { id: desc }) { id posts(orderBy: { published: asc }) { id } } }"#;
+    let response = query_engine.request_with_hints(query, advise_indexes()).await;
+
+    assert_eq!(response["extensions"]["missingIndexes"], serde_json::json!([]));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::test_api::*;
+use connector::{QueryHints, SlowQueryLog};
+use indoc::indoc;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static CATS: &str = indoc! {"
+    model Cat {
+        id   Int    @id @default(autoincrement())
+        name String
+    }
+"};
+
+fn create_cat(name: &str) -> String {
+    format!(
+        r#"mutation {{ createOneCat(data: {{ name: "{}" }}) {{ id name }} }}"#,
+        name
+    )
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn created_records_get_their_generated_ids(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&CATS).await?;
+
+    let response = query_engine.request(create_cat("Garfield")).await;
+    assert_eq!(
+        response["data"]["createOneCat"],
+        serde_json::json!({ "id": 1, "name": "Garfield" })
+    );
+
+    let response = query_engine.request(create_cat("Nyan")).await;
+    assert_eq!(
+        response["data"]["createOneCat"],
+        serde_json::json!({ "id": 2, "name": "Nyan" })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mariadb"))]
+async fn mariadb_reads_generated_ids_from_the_insert(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&CATS).await?;
+
+    let log = SlowQueryLog::new(Duration::from_millis(0));
+    let mut hints = QueryHints::new();
+    hints.log_slow_queries(log.clone());
+
+    let response = query_engine.request_with_hints(create_cat("Garfield"), hints).await;
+    assert_eq!(
+        response["data"]["createOneCat"],
+        serde_json::json!({ "id": 1, "name": "Garfield" })
+    );
+
+    let statements: Vec<String> = log.take().into_iter().map(|q| q.query).collect();
+    let insert = statements.iter().find(|s| s.starts_with("INSERT")).unwrap();
+
+    assert!(insert.ends_with("RETURNING `id`"), "{:?}", statements);
+
+    Ok(())
+}
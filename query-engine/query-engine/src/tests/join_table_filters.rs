@@ -0,0 +1,104 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TEAMS: &str = indoc! {r#"
+    model User {
+        id          Int          @id
+        memberships Membership[]
+    }
+
+    model Team {
+        id          Int          @id
+        name        String
+        memberships Membership[]
+    }
+
+    model Membership {
+        userId Int
+        teamId Int
+        role   String
+        user   User   @relation(fields: [userId], references: [id])
+        team   Team   @relation(fields: [teamId], references: [id])
+
+        @@id([userId, teamId])
+        @@joinTable
+    }
+"#};
+
+async fn seed(engine: &QueryEngine) {
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 1,
+                memberships: {
+                    create: [
+                        { role: "admin", team: { create: { id: 1, name: "core" } } },
+                        { role: "member", team: { create: { id: 2, name: "docs" } } }
+                    ]
+                }
+            }) { id }
+        }
+    "#};
+
+    engine.request(mutation).await;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 2,
+                memberships: { create: [{ role: "member", team: { connect: { id: 2 } } }] }
+            }) { id }
+        }
+    "#};
+
+    engine.request(mutation).await;
+    engine
+        .request(r#"mutation { createOneUser(data: { id: 3 }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector]
+async fn join_table_filters_traverse_the_join_model(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TEAMS).await?;
+    seed(&query_engine).await;
+
+    let query = |filter: &str| {
+        format!(
+            r#"query {{ findManyUser(where: {{ memberships_team: {{ {} }} }}, orderBy: {{ id: asc }}) {{ id }} }}"#,
+            filter
+        )
+    };
+
+    let some = query_engine.request(query(r#"some: { name: "core" }"#)).await;
+    assert_eq!(some["data"]["findManyUser"], json!([{ "id": 1 }]));
+
+    let every = query_engine.request(query(r#"every: { name: "docs" }"#)).await;
+    assert_eq!(every["data"]["findManyUser"], json!([{ "id": 2 }, { "id": 3 }]));
+
+    let none = query_engine.request(query(r#"none: { name: "docs" }"#)).await;
+    assert_eq!(none["data"]["findManyUser"], json!([{ "id": 3 }]));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn join_table_filters_can_be_combined_with_filters_on_the_join_model(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TEAMS).await?;
+    seed(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyTeam(where: {
+                memberships_user: { some: { id: 2 } },
+                memberships: { some: { role: "member" } }
+            }) { id }
+        }
+    "#};
+    let response = query_engine.request(query).await;
+
+    assert_eq!(response["data"]["findManyTeam"], json!([{ "id": 2 }]));
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static DOCUMENTS: &str = indoc! {r#"
+    model Document {
+        id   Int   @id
+        meta Json?
+    }
+"#};
+
+async fn seed(engine: &QueryEngine) {
+    engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, meta: DbNull }) { id } }"#)
+        .await;
+    engine
+        .request(r#"mutation { createOneDocument(data: { id: 2, meta: JsonNull }) { id } }"#)
+        .await;
+    engine
+        .request(r#"mutation { createOneDocument(data: { id: 3, meta: "{\"a\":1}" }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector(capabilities("json"))]
+async fn json_nulls_can_be_written_and_filtered_separately(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    seed(&query_engine).await;
+
+    let query = |filter: &str| {
+        format!(
+            r#"query {{ findManyDocument(where: {{ meta: {} }}, orderBy: {{ id: asc }}) {{ id }} }}"#,
+            filter
+        )
+    };
+
+    let db_null = query_engine.request(query("{ equals: DbNull }")).await;
+    assert_eq!(db_null["data"]["findManyDocument"], json!([{ "id": 1 }]));
+
+    let json_null = query_engine.request(query("{ equals: JsonNull }")).await;
+    assert_eq!(json_null["data"]["findManyDocument"], json!([{ "id": 2 }]));
+
+    let any_null = query_engine.request(query("{ equals: AnyNull }")).await;
+    assert_eq!(any_null["data"]["findManyDocument"], json!([{ "id": 1 }, { "id": 2 }]));
+
+    let not_any_null = query_engine.request(query("{ not: AnyNull }")).await;
+    assert_eq!(not_any_null["data"]["findManyDocument"], json!([{ "id": 3 }]));
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("json"))]
+async fn json_nulls_can_be_set_in_updates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    seed(&query_engine).await;
+
+    let mutation = r#"mutation { updateOneDocument(where: { id: 3 }, data: { meta: JsonNull }) { id } }"#;
+    query_engine.request(mutation).await;
+
+    let query = r#"query { findManyDocument(where: { meta: { equals: JsonNull } }, orderBy: { id: asc }) { id } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(response["data"]["findManyDocument"], json!([{ "id": 2 }, { "id": 3 }]));
+
+    Ok(())
+}
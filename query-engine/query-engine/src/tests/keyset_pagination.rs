@@ -0,0 +1,90 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static POSTS: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+    }
+"};
+
+async fn create_posts(query_engine: &QueryEngine) {
+    for id in 1..=5 {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, title: "post {}" }}) {{ id }} }}"#,
+            id, id
+        );
+        query_engine.request(mutation).await;
+    }
+}
+
+fn ids(response: &serde_json::Value) -> Vec<i64> {
+    response["data"]["findManyPost"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[test_each_connector]
+async fn after_and_before_page_from_an_opaque_cursor(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    create_posts(&query_engine).await;
+
+    let first_page = query_engine
+        .request(r#"query { findManyPost(take: 2) { id _cursor } }"#)
+        .await;
+
+    assert_eq!(ids(&first_page), vec![1, 2]);
+
+    let cursor = first_page["data"]["findManyPost"][1]["_cursor"].as_str().unwrap();
+    assert!(!cursor.contains('2'), "The cursor {} should not expose the id.", cursor);
+
+    let next_page = query_engine
+        .request(format!(
+            r#"query {{ findManyPost(take: 2, after: "{}") {{ id _cursor }} }}"#,
+            cursor
+        ))
+        .await;
+
+    assert_eq!(ids(&next_page), vec![3, 4]);
+
+    let cursor = next_page["data"]["findManyPost"][0]["_cursor"].as_str().unwrap();
+    let previous_page = query_engine
+        .request(format!(
+            r#"query {{ findManyPost(take: 2, before: "{}") {{ id }} }}"#,
+            cursor
+        ))
+        .await;
+
+    assert_eq!(ids(&previous_page), vec![1, 2]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_cursors_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+
+    let response = query_engine
+        .request(r#"query { findManyPost(after: "not a cursor") { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["user_facing_error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("Invalid cursor 'not a cursor' for model Post."),
+        "{}",
+        message
+    );
+
+    let response = query_engine
+        .request(r#"query { findManyPost(before: "WzFd") { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["user_facing_error"]["message"].as_str().unwrap();
+    assert!(message.contains("requires a positive `take`"), "{}", message);
+
+    Ok(())
+}
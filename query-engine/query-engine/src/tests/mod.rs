@@ -1,4 +1,38 @@
+mod access_roles;
+mod api;
+mod audit_log;
+mod bitwise_filters;
+mod connect_or_create;
+mod constraint_violations;
+mod cursor_pagination;
+mod date_filters;
 mod dmmf;
 mod errors;
 mod execute_raw;
+mod explain;
+mod field_encryption;
+mod foreign_key_emulation;
+mod group_by_order_by_aggregates;
+mod group_by_rollup;
+mod index_advice;
+mod insert_returning;
+mod join_table_filters;
+mod json_nulls;
+mod keyset_pagination;
+mod numeric_encoding;
+mod query_cache;
+mod query_graph_traces;
+mod readiness;
+mod relation_counts;
+mod relation_load_strategy;
+mod relative_time_filters;
+mod reload;
+mod slow_queries;
+mod statement_timeouts;
+mod tenants;
 mod test_api;
+mod upsert;
+mod value_transformers;
+mod versions;
+mod window_functions;
+mod write_many;
@@ -0,0 +1,130 @@
+use super::test_api::*;
+use crate::request_handlers::SingleQuery;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static INVOICES: &str = indoc! {"
+    model Invoice {
+        id    Int     @id
+        total Decimal
+        views BigInt
+    }
+"};
+
+static MEASUREMENTS: &str = indoc! {"
+    model Measurement {
+        id       Int       @id
+        readings Decimal[]
+        counters BigInt[]
+    }
+"};
+
+fn with_protocol_version(query: &str, version: u32) -> SingleQuery {
+    serde_json::from_value(json!({ "query": query, "variables": {}, "protocolVersion": version })).unwrap()
+}
+
+/// The exact string of a tagged value, with the trailing zeros the connectors pad decimals with
+/// removed.
+fn tagged_value<'a>(item: &'a serde_json::Value, tag: &str) -> &'a str {
+    assert_eq!(item["prisma__type"], tag, "{}", item);
+
+    let value = item["prisma__value"].as_str().unwrap();
+
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
+
+#[test_each_connector]
+async fn decimals_and_big_ints_are_tagged_in_protocol_version_2(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&INVOICES).await?;
+
+    query_engine
+        .request(r#"mutation { createOneInvoice(data: { id: 1, total: "12.5", views: 9007199254740993 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(with_protocol_version(
+            "{ findUniqueInvoice(where: { id: 1 }) { id total views } }",
+            2,
+        ))
+        .await;
+
+    let invoice = &response["data"]["findUniqueInvoice"];
+
+    assert_eq!(invoice["id"], 1, "{}", response);
+    assert_eq!(tagged_value(&invoice["total"], "decimal"), "12.5");
+    assert_eq!(tagged_value(&invoice["views"], "bigint"), "9007199254740993");
+
+    let response = query_engine
+        .request(with_protocol_version(
+            "{ findUniqueInvoice(where: { id: 1 }) { views } }",
+            1,
+        ))
+        .await;
+
+    assert_eq!(
+        response["data"]["findUniqueInvoice"]["views"],
+        json!("9007199254740993"),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("scalar_lists"))]
+async fn scalar_lists_of_decimals_and_big_ints_are_tagged_element_by_element(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&MEASUREMENTS).await?;
+
+    query_engine
+        .request(
+            r#"mutation { createOneMeasurement(data: { id: 1, readings: { set: ["0.5", "1.25"] }, counters: { set: [1, 9007199254740993] } }) { id } }"#,
+        )
+        .await;
+
+    let response = query_engine
+        .request(with_protocol_version(
+            "{ findUniqueMeasurement(where: { id: 1 }) { readings counters } }",
+            2,
+        ))
+        .await;
+
+    let measurement = &response["data"]["findUniqueMeasurement"];
+
+    let readings: Vec<_> = measurement["readings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| tagged_value(item, "decimal"))
+        .collect();
+
+    let counters: Vec<_> = measurement["counters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| tagged_value(item, "bigint"))
+        .collect();
+
+    assert_eq!(readings, vec!["0.5", "1.25"], "{}", response);
+    assert_eq!(counters, vec!["1", "9007199254740993"], "{}", response);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn unsupported_protocol_versions_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&INVOICES).await?;
+
+    let response = query_engine
+        .request(with_protocol_version("{ findManyInvoice { id } }", 3))
+        .await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("Unsupported protocol version 3"), "{}", response);
+
+    Ok(())
+}
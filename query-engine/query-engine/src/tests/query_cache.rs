@@ -0,0 +1,98 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::QueryCacheConfig;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static DATAMODEL: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+
+    model Tag {
+        id Int @id
+    }
+"};
+
+fn cache_config(ttl: Duration) -> QueryCacheConfig {
+    QueryCacheConfig {
+        ttl,
+        max_bytes: 1024 * 1024,
+    }
+}
+
+#[test_each_connector]
+async fn reads_are_served_from_the_cache_until_a_write_invalidates_them(api: &TestApi) -> anyhow::Result<()> {
+    let cached = api
+        .create_engine_with_query_cache(DATAMODEL, cache_config(Duration::from_secs(60)))
+        .await?;
+    let other = api.create_engine(DATAMODEL).await?;
+
+    let users = r#"query { findManyUser(where: { id: { gte: 0 } }, orderBy: { id: asc }) { id posts { id } } }"#;
+    let reordered = r#"query { findManyUser(orderBy: { id: asc }, where: { id: { gte: 0 } }) { id posts { id } } }"#;
+    let tags = r#"query { findManyTag { id } }"#;
+
+    assert_eq!(cached.request(users).await["data"]["findManyUser"], json!([]));
+    assert_eq!(cached.request(tags).await["data"]["findManyTag"], json!([]));
+
+    // Writes of other engines are not observed.
+    other
+        .request(r#"mutation { createOneUser(data: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(cached.request(reordered).await["data"]["findManyUser"], json!([]));
+
+    // A write to a related model invalidates the users, but not the tags.
+    cached
+        .request(r#"mutation { createOnePost(data: { id: 1, author: { connect: { id: 1 } } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        cached.request(users).await["data"]["findManyUser"],
+        json!([{ "id": 1, "posts": [{ "id": 1 }] }])
+    );
+    assert_eq!(cached.request(tags).await["data"]["findManyTag"], json!([]));
+
+    let metrics = cached.context().query_cache().unwrap().metrics();
+
+    assert_eq!(metrics.hits, 2);
+    assert_eq!(metrics.misses, 3);
+    assert_eq!(metrics.invalidations, 1);
+    assert_eq!(metrics.entries, 2);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cached_responses_expire_after_the_ttl(api: &TestApi) -> anyhow::Result<()> {
+    let cached = api
+        .create_engine_with_query_cache(DATAMODEL, cache_config(Duration::from_millis(100)))
+        .await?;
+    let other = api.create_engine(DATAMODEL).await?;
+
+    let tags = r#"query { findManyTag { id } }"#;
+
+    assert_eq!(cached.request(tags).await["data"]["findManyTag"], json!([]));
+
+    other
+        .request(r#"mutation { createOneTag(data: { id: 1 }) { id } }"#)
+        .await;
+    async_std::task::sleep(Duration::from_millis(150)).await;
+
+    assert_eq!(cached.request(tags).await["data"]["findManyTag"], json!([{ "id": 1 }]));
+
+    let metrics = cached.context().query_cache().unwrap().metrics();
+
+    assert_eq!(metrics.hits, 0);
+    assert_eq!(metrics.evictions, 1);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use super::test_api::*;
+use connector::{QueryGraphTraces, QueryHints};
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+fn trace_query_graphs() -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.trace_query_graphs(QueryGraphTraces::new());
+    hints
+}
+
+fn execution_order(trace: &serde_json::Value) -> Vec<&str> {
+    trace["executionOrder"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|step| step["content"].as_str().unwrap())
+        .collect()
+}
+
+#[test_each_connector]
+async fn nested_creates_of_the_inlined_side_are_swapped_before_their_parent(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let mutation = r#"mutation { createOnePost(data: { id: 1, author: { create: { id: 1 } } }) { id } }"#;
+    let response = query_engine.request_with_hints(mutation, trace_query_graphs()).await;
+
+    assert_eq!(response["data"]["createOnePost"]["id"], 1);
+
+    let traces = response["extensions"]["queryGraphTraces"].as_array().unwrap();
+    assert_eq!(traces.len(), 1);
+    assert_eq!(traces[0]["operation"], "createOnePost");
+
+    let events = traces[0]["events"].as_array().unwrap();
+    assert!(events
+        .iter()
+        .any(|event| event["stage"] == "build" && event["action"] == "markNodes"));
+    assert!(events
+        .iter()
+        .any(|event| event["stage"] == "swapMarked" && event["action"] == "createEdge"));
+
+    let creates: Vec<&str> = execution_order(&traces[0])
+        .into_iter()
+        .filter(|content| content.starts_with("CreateRecord"))
+        .collect();
+
+    assert_eq!(creates.len(), 2);
+    assert!(creates[0].contains("model: User"), "{:?}", creates);
+    assert!(creates[1].contains("model: Post"), "{:?}", creates);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn reads_are_traced_without_transformations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyUser { id } }"#, trace_query_graphs())
+        .await;
+
+    let trace = &response["extensions"]["queryGraphTraces"][0];
+    let events = trace["events"].as_array().unwrap();
+
+    assert_eq!(trace["operation"], "findManyUser");
+    assert!(events.iter().all(|event| event["stage"] == "build"));
+    assert_eq!(execution_order(trace).len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use super::test_api::*;
+use crate::server::readiness::{Readiness, ReadinessProbe};
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static SCHEMA: &str = r#"
+    model User {
+        id Int @id
+    }
+"#;
+
+#[test_each_connector]
+async fn the_readiness_probe_reports_a_reachable_database_as_ready(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(SCHEMA).await?;
+    let probe = ReadinessProbe::new(Duration::from_secs(60));
+
+    assert_eq!(probe.check(query_engine.connector()).await, Readiness::Ready);
+
+    // The cached result is returned without probing again.
+    assert_eq!(probe.check(query_engine.connector()).await, Readiness::Ready);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn the_connector_ping_round_trips_to_the_database(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(SCHEMA).await?;
+
+    query_engine.connector().ping().await?;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id         Int        @id
+        published  Boolean
+        authorId   Int
+        author     User       @relation(fields: [authorId], references: [id])
+        categories Category[]
+    }
+
+    model Category {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+"#};
+
+async fn seed(engine: &QueryEngine) {
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 1,
+                posts: {
+                    create: [
+                        { id: 1, published: true, categories: { create: [{ id: 1, name: "a" }, { id: 2, name: "b" }] } },
+                        { id: 2, published: false },
+                        { id: 3, published: true, categories: { connect: [{ id: 1 }] } }
+                    ]
+                }
+            }) { id }
+        }
+    "#};
+
+    engine.request(mutation).await;
+}
+
+#[test_each_connector]
+async fn counts_all_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let query = r#"query { findManyUser { id _count { posts } } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        response["data"]["findManyUser"],
+        json!([{ "id": 1, "_count": { "posts": 3 } }])
+    );
+
+    let query = r#"query { findManyPost(orderBy: { id: asc }) { id _count { categories } } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        json!([
+            { "id": 1, "_count": { "categories": 2 } },
+            { "id": 2, "_count": { "categories": 0 } },
+            { "id": 3, "_count": { "categories": 1 } },
+        ])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn counts_only_related_records_matching_the_filter(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findUniqueUser(where: { id: 1 }) {
+                _count { posts(where: { published: true }) }
+                posts(orderBy: { id: asc }) { id _count { categories(where: { name: "b" }) } }
+            }
+        }
+    "#};
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        response["data"]["findUniqueUser"],
+        json!({
+            "_count": { "posts": 2 },
+            "posts": [
+                { "id": 1, "_count": { "categories": 1 } },
+                { "id": 2, "_count": { "categories": 0 } },
+                { "id": 3, "_count": { "categories": 0 } },
+            ]
+        })
+    );
+
+    Ok(())
+}
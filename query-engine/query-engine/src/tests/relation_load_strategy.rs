@@ -0,0 +1,90 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {r#"
+    model User {
+        id      Int      @id
+        name    String
+        posts   Post[]
+        profile Profile?
+    }
+
+    model Profile {
+        id     Int    @id
+        bio    String
+        userId Int    @unique
+        user   User   @relation(fields: [userId], references: [id])
+    }
+
+    model Post {
+        id       Int   @id
+        authorId Int?
+        author   User? @relation(fields: [authorId], references: [id])
+    }
+"#};
+
+async fn seed(engine: &QueryEngine) {
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 1,
+                name: "Alice",
+                profile: { create: { id: 1, bio: "Hi" } },
+                posts: { create: [{ id: 1 }, { id: 2 }] }
+            }) { id }
+        }
+    "#};
+
+    engine.request(mutation).await;
+    engine
+        .request(r#"mutation { createOnePost(data: { id: 3 }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector]
+async fn join_and_query_strategies_return_the_same_result(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let query = |strategy: &str| {
+        format!(
+            r#"query {{ findManyPost(orderBy: {{ id: asc }}, relationLoadStrategy: {}) {{ id author {{ name profile {{ bio }} posts(orderBy: {{ id: asc }}) {{ id }} }} }} }}"#,
+            strategy
+        )
+    };
+
+    let joined = query_engine.request(query("join")).await;
+    let queried = query_engine.request(query("query")).await;
+
+    let author = json!({ "name": "Alice", "profile": { "bio": "Hi" }, "posts": [{ "id": 1 }, { "id": 2 }] });
+
+    assert_eq!(
+        joined["data"]["findManyPost"],
+        json!([
+            { "id": 1, "author": author },
+            { "id": 2, "author": author },
+            { "id": 3, "author": null },
+        ])
+    );
+    assert_eq!(joined, queried);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn join_strategy_loads_relations_of_a_single_record(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let query = r#"query { findUniqueProfile(where: { id: 1 }, relationLoadStrategy: join) { bio user { name } } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        response["data"]["findUniqueProfile"],
+        json!({ "bio": "Hi", "user": { "name": "Alice" } })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::test_api::*;
+use chrono::{Duration, SecondsFormat, Utc};
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static EVENTS: &str = indoc! {"
+    model Event {
+        id        Int      @id
+        createdAt DateTime
+    }
+"};
+
+async fn find_event_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let response = query_engine
+        .request(format!(
+            r#"{{ findManyEvent(where: {{ createdAt: {} }}, orderBy: {{ id: asc }}) {{ id }} }}"#,
+            filter
+        ))
+        .await;
+
+    response["data"]["findManyEvent"].clone()
+}
+
+#[test_each_connector]
+async fn in_the_last_matches_the_values_up_to_now(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&EVENTS).await?;
+    let now = Utc::now();
+
+    for (id, age) in [
+        (1, Duration::days(2)),
+        (2, Duration::days(10)),
+        (3, Duration::days(-1)),
+        (4, Duration::days(40)),
+    ]
+    .iter()
+    {
+        let created_at = (now - *age).to_rfc3339_opts(SecondsFormat::Millis, true);
+
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneEvent(data: {{ id: {}, createdAt: "{}" }}) {{ id }} }}"#,
+                id, created_at
+            ))
+            .await;
+    }
+
+    assert_eq!(
+        find_event_ids(&query_engine, "{ inTheLast: { amount: 7, unit: days } }").await,
+        json!([{ "id": 1 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, "{ inTheLast: { amount: 1, unit: months } }").await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, "{ not: { inTheLast: { amount: 7, unit: days } } }").await,
+        json!([{ "id": 2 }, { "id": 3 }, { "id": 4 }])
+    );
+
+    assert_eq!(
+        find_event_ids(&query_engine, "{ inTheLast: { amount: 0, unit: seconds } }").await,
+        json!([])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn in_the_last_rejects_negative_amounts(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&EVENTS).await?;
+
+    let response = query_engine
+        .request(r#"{ findManyEvent(where: { createdAt: { inTheLast: { amount: -1, unit: days } } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        response["errors"][0]["user_facing_error"]["error_code"], "P2019",
+        "{}",
+        response
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use super::test_api::*;
+use quaint::connector::SqlFamily;
+use std::sync::Arc;
+use test_macros::test_each_connector;
+
+static BEFORE: &str = r#"
+    model User {
+        id Int @id
+    }
+"#;
+
+static AFTER: &str = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"#;
+
+#[test_each_connector]
+async fn reloading_the_datamodel_keeps_the_executor(api: &TestApi) -> anyhow::Result<()> {
+    let before = api.create_engine(BEFORE).await?;
+    let after = api.reload_engine(&before, AFTER).await?;
+
+    assert!(Arc::ptr_eq(&before.context().executor, &after.context().executor));
+
+    let mutation = r#"mutation { createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id } }"#;
+    let response = after.request(mutation).await;
+
+    assert_eq!(response["data"]["createOneUser"]["id"], 1);
+
+    // The previous context still serves the previous datamodel.
+    let response = before.request(r#"query { findManyPost { id } }"#).await;
+
+    assert!(response["errors"].is_array());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn reloading_the_datamodel_with_another_provider_fails(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(BEFORE).await?;
+
+    let provider = if matches!(api.connection_info().sql_family(), SqlFamily::Sqlite) {
+        "postgresql"
+    } else {
+        "sqlite"
+    };
+
+    let schema = format!(
+        r#"
+        datasource db {{
+            provider = "{}"
+            url      = "file:dev.db"
+        }}
+
+        {}
+        "#,
+        provider, BEFORE
+    );
+
+    let config = datamodel::parse_configuration_and_ignore_datasource_urls(&schema).unwrap();
+    let dm = datamodel::parse_datamodel_and_ignore_datasource_urls(&schema).unwrap();
+
+    assert!(engine.context().reload(&config.subject, dm.subject).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use super::test_api::*;
+use connector::{QueryHints, SlowQueryLog};
+use indoc::indoc;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+fn log_slow_queries(log: &SlowQueryLog) -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.log_slow_queries(log.clone());
+    hints
+}
+
+#[test_each_connector]
+async fn queries_exceeding_the_threshold_are_recorded_with_their_field_path(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    let log = SlowQueryLog::new(Duration::from_millis(0));
+
+    let mutation = r#"mutation { createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id } }"#;
+    query_engine.request_with_hints(mutation, log_slow_queries(&log)).await;
+
+    let slow_queries = log.take();
+
+    assert!(!slow_queries.is_empty());
+    assert!(slow_queries.iter().all(|q| q.field_path == "createOneUser"));
+
+    let query = r#"query { findManyUser(where: { id: 1 }) { id posts { id } } }"#;
+    let response = query_engine.request_with_hints(query, log_slow_queries(&log)).await;
+
+    assert_eq!(response["data"]["findManyUser"][0]["posts"][0]["id"], 1);
+
+    let slow_queries = log.take();
+    let field_paths: Vec<&str> = slow_queries.iter().map(|q| q.field_path.as_str()).collect();
+
+    assert_eq!(field_paths, vec!["findManyUser", "findManyUser.posts"]);
+    assert!(slow_queries.iter().all(|q| q.query.starts_with("SELECT")));
+    assert!(slow_queries[1].parameter_count > 0);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn queries_below_the_threshold_are_not_recorded(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    let log = SlowQueryLog::new(Duration::from_secs(3600));
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyUser { id } }"#, log_slow_queries(&log))
+        .await;
+
+    assert!(log.take().is_empty());
+    assert!(response.get("extensions").is_none());
+
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use super::test_api::*;
+use connector::{QueryHints, QueryPlans};
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+fn query_raw(query: &str) -> String {
+    format!(r#"mutation {{ queryRaw(query: "{}") }}"#, query)
+}
+
+fn hint(name: &str, value: &str) -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.add_setting(name, value).unwrap();
+    hints
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn the_url_parameter_sets_the_statement_timeout_on_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await?;
+
+    let response = query_engine.request(query_raw("SHOW statement_timeout")).await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["statement_timeout"], "1500ms");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn query_hints_override_the_statement_timeout_on_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await?;
+
+    let response = query_engine
+        .request_with_hints(query_raw("SHOW statement_timeout"), hint("statement_timeout", "3000"))
+        .await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["statement_timeout"], "3s");
+
+    // The override ends with the transaction of the request.
+    let response = query_engine.request(query_raw("SHOW statement_timeout")).await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["statement_timeout"], "1500ms");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn statements_exceeding_the_timeout_are_cancelled(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=100")
+        .await?;
+
+    let response = query_engine.request(query_raw("SELECT pg_sleep(1)")).await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("statement timeout"), "{}", error);
+
+    Ok(())
+}
+
+fn explained_query(response: &serde_json::Value) -> &str {
+    response["extensions"]["queryPlans"][0]["query"].as_str().unwrap()
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn the_url_parameter_limits_the_generated_selects_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await?;
+
+    let mut hints = QueryHints::new();
+    hints.explain(QueryPlans::new());
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo { id } }"#, hints)
+        .await;
+
+    assert!(
+        explained_query(&response).starts_with("SELECT /*+ MAX_EXECUTION_TIME(1500) */ "),
+        "{}",
+        response
+    );
+
+    // The session of the connection is left alone.
+    let response = query_engine
+        .request(query_raw("SELECT @@SESSION.max_execution_time AS timeout"))
+        .await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["timeout"], 0);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn query_hints_override_the_max_execution_time_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await?;
+
+    let response = query_engine
+        .request_with_hints(
+            query_raw("SELECT @@SESSION.max_execution_time AS timeout"),
+            hint("max_execution_time", "3000"),
+        )
+        .await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["timeout"], 3000);
+
+    let mut hints = hint("max_execution_time", "3000");
+    hints.explain(QueryPlans::new());
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo { id } }"#, hints)
+        .await;
+
+    assert!(
+        !explained_query(&response).contains("MAX_EXECUTION_TIME"),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn session_settings_are_reset_when_applying_the_hints_fails(api: &TestApi) -> anyhow::Result<()> {
+    // A single connection, so the second request runs on the connection of the first.
+    let query_engine = api.create_engine_with_url_params(&TODO, "connection_limit=1").await?;
+
+    let mut hints = hint("innodb_lock_wait_timeout", "7");
+    hints.add_setting("max_execution_time", "not a number").unwrap();
+
+    let response = query_engine
+        .request_with_hints(query_raw("SELECT @@SESSION.innodb_lock_wait_timeout AS timeout"), hints)
+        .await;
+
+    assert!(response["errors"][0].is_object(), "{}", response);
+
+    let response = query_engine
+        .request(query_raw("SELECT @@SESSION.innodb_lock_wait_timeout AS timeout"))
+        .await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["timeout"], 50);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mariadb"))]
+async fn the_url_parameter_limits_the_generated_statements_on_mariadb(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id title } }"#)
+        .await;
+
+    assert_eq!(response["data"]["createOneTodo"]["title"], "a", "{}", response);
+
+    let response = query_engine
+        .request(query_raw("SELECT @@SESSION.max_statement_time AS timeout"))
+        .await;
+
+    assert_eq!(response["data"]["queryRaw"][0]["timeout"], 0.0);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite", "mssql_2017", "mssql_2019"))]
+async fn the_url_parameter_is_rejected_on_connectors_without_statement_timeouts(api: &TestApi) -> anyhow::Result<()> {
+    let error = api
+        .create_engine_with_url_params(&TODO, "statement_timeout=1500")
+        .await
+        .err()
+        .unwrap();
+
+    assert!(
+        format!("{:?}", error).contains("The `statement_timeout` parameter is not supported on"),
+        "{:?}",
+        error
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite", "mssql_2017", "mssql_2019"))]
+async fn query_hint_settings_are_rejected_on_connectors_without_settings(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo { id } }"#, hint("statement_timeout", "1000"))
+        .await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("Query hints are not supported on"), "{}", error);
+    assert!(response["data"].is_null(), "{}", response);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn optimizer_hints_are_added_to_the_generated_statements_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mut hints = QueryHints::new();
+    hints.add_optimizer_hint("MAX_EXECUTION_TIME(1000)").unwrap();
+    hints.explain(QueryPlans::new());
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo(where: { title: "a" }) { id } }"#, hints)
+        .await;
+
+    let plans = response["extensions"]["queryPlans"].as_array().unwrap();
+
+    assert_eq!(response["data"]["findManyTodo"], serde_json::json!([]), "{}", response);
+    assert!(
+        plans[0]["query"]
+            .as_str()
+            .unwrap()
+            .starts_with("SELECT /*+ MAX_EXECUTION_TIME(1000) */ "),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn optimizer_hints_are_rejected_on_other_connectors(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mut hints = QueryHints::new();
+    hints.add_optimizer_hint("MAX_EXECUTION_TIME(1000)").unwrap();
+
+    let response = query_engine
+        .request_with_hints(r#"query { findManyTodo { id } }"#, hints)
+        .await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("Optimizer hints are not supported on"), "{}", error);
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use super::test_api::*;
+use crate::server::tenants::{TenantOptions, TenantRouter};
+use enumflags2::BitFlags;
+use migration_core::{
+    api::{GenericApi, MigrationApi},
+    commands::SchemaPushInput,
+};
+use quaint::prelude::SqlFamily;
+use query_core::QueryCacheConfig;
+use serde_json::json;
+use sql_migration_connector::SqlMigrationConnector;
+use std::{sync::Arc, time::Duration};
+use test_macros::test_each_connector;
+
+static SCHEMA: &str = r#"
+    model User {
+        id Int @id
+    }
+"#;
+
+/// The URL of the test database, with the tenant key appended to the name of the database, or of
+/// the file on SQLite.
+fn url_template(api: &TestApi) -> String {
+    let info = api.connection_info();
+    let config = datamodel::parse_configuration(api.config()).unwrap().subject;
+    let url = config.datasources[0].url().value.clone();
+
+    match info.sql_family() {
+        SqlFamily::Sqlite => format!("{}_{{tenant}}", url),
+        SqlFamily::Mssql => {
+            let db_name = info.dbname().unwrap();
+            url.replacen(
+                &format!(";database={};", db_name),
+                &format!(";database={}_{{tenant}};", db_name),
+                1,
+            )
+        }
+        _ => {
+            let db_name = info.dbname().unwrap();
+            url.replacen(&format!("/{}?", db_name), &format!("/{}_{{tenant}}?", db_name), 1)
+        }
+    }
+}
+
+/// Creates the database of each tenant, with the tables of the schema.
+async fn create_tenant_databases(api: &TestApi, tenants: &[&str]) -> anyhow::Result<()> {
+    for tenant in tenants {
+        let url = url_template(api).replace("{tenant}", tenant);
+
+        let connector = match api.connection_info().sql_family() {
+            SqlFamily::Postgres => postgres_migration_connector(&url).await,
+            SqlFamily::Mysql => mysql_migration_connector(&url).await,
+            SqlFamily::Mssql => mssql_migration_connector(&url).await,
+            SqlFamily::Sqlite => {
+                // Truncate the file of a previous run.
+                std::fs::File::create(url.trim_start_matches("file:"))?;
+                SqlMigrationConnector::new(&url, BitFlags::all()).await?
+            }
+        };
+
+        MigrationApi::new(connector)
+            .schema_push(&SchemaPushInput {
+                schema: format!("{}\n\n{}", api.config(), SCHEMA),
+                force: true,
+                assume_empty: false,
+            })
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn router(api: &TestApi, max_pools: usize) -> TenantRouter {
+    let options = TenantOptions {
+        header: "x-prisma-tenant".to_owned(),
+        url_template: url_template(api),
+        max_pools,
+        idle_timeout: Duration::from_secs(600),
+    };
+
+    TenantRouter::new(options, format!("{}\n\n{}", api.config(), SCHEMA)).unwrap()
+}
+
+#[test_each_connector]
+async fn requests_of_the_same_tenant_share_a_context(api: &TestApi) -> anyhow::Result<()> {
+    create_tenant_databases(api, &["acme", "globex"]).await?;
+
+    let engine = api.create_engine(SCHEMA).await?;
+    let router = router(api, 10);
+
+    let first = router.context("acme", engine.context()).await?;
+    let second = router.context("acme", engine.context()).await?;
+    let other = router.context("globex", engine.context()).await?;
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first.executor, &other.executor));
+
+    let (metrics, evictions) = router.metrics().await;
+
+    assert_eq!(metrics["acme"].requests, 2);
+    assert_eq!(metrics["globex"].requests, 1);
+    assert_eq!(evictions, 0);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn the_least_recently_used_tenant_pool_is_evicted(api: &TestApi) -> anyhow::Result<()> {
+    create_tenant_databases(api, &["acme", "globex", "initech"]).await?;
+
+    let engine = api.create_engine(SCHEMA).await?;
+    let router = router(api, 2);
+
+    router.context("acme", engine.context()).await?;
+    router.context("globex", engine.context()).await?;
+    router.context("acme", engine.context()).await?;
+    router.context("initech", engine.context()).await?;
+
+    let (metrics, evictions) = router.metrics().await;
+    let mut tenants: Vec<&str> = metrics.keys().map(String::as_str).collect();
+    tenants.sort_unstable();
+
+    assert_eq!(tenants, &["acme", "initech"]);
+    assert_eq!(evictions, 1);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn tenants_have_their_own_query_cache(api: &TestApi) -> anyhow::Result<()> {
+    create_tenant_databases(api, &["acme", "globex"]).await?;
+
+    let query_cache = QueryCacheConfig {
+        ttl: Duration::from_secs(60),
+        max_bytes: 1024 * 1024,
+    };
+
+    let engine = api.create_engine_with_query_cache(SCHEMA, query_cache).await?;
+    let router = router(api, 10);
+
+    let acme = QueryEngine::with_context(router.context("acme", engine.context()).await?);
+    let globex = QueryEngine::with_context(router.context("globex", engine.context()).await?);
+
+    let users = r#"query { findManyUser { id } }"#;
+
+    acme.request(r#"mutation { createOneUser(data: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(acme.request(users).await["data"]["findManyUser"], json!([{ "id": 1 }]));
+    assert_eq!(acme.request(users).await["data"]["findManyUser"], json!([{ "id": 1 }]));
+    assert_eq!(globex.request(users).await["data"]["findManyUser"], json!([]));
+
+    let (metrics, _) = router.metrics().await;
+
+    assert_eq!(metrics["acme"].query_cache.unwrap().hits, 1);
+    assert_eq!(metrics["globex"].query_cache.unwrap().hits, 0);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn invalid_tenant_keys_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(SCHEMA).await?;
+    let router = router(api, 10);
+
+    for tenant in &["", "../other", "acme?mode=memory", "a b"] {
+        assert!(router.context(tenant, engine.context()).await.is_err());
+    }
+
+    Ok(())
+}
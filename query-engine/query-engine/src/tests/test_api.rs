@@ -1,8 +1,10 @@
 use crate::{
     context::PrismaContext,
-    request_handlers::{graphql, GraphQlBody, SingleQuery},
+    request_handlers::{access::AccessRole, graphql, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use connector::{Connector, QueryHints};
+use datamodel::{Configuration, Datamodel};
 use enumflags2::BitFlags;
 use migration_core::{
     api::{GenericApi, MigrationApi},
@@ -13,6 +15,7 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::{AuditSinkRef, FieldEncryptionRef, QueryCacheConfig, ValueTransformerRef};
 use sql_migration_connector::SqlMigrationConnector;
 use std::sync::Arc;
 use test_setup::*;
@@ -28,13 +31,40 @@ impl QueryEngine {
     }
 
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_hints(body, QueryHints::new()).await
+    }
+
+    pub async fn request_with_hints(&self, body: impl Into<SingleQuery>, hints: QueryHints) -> serde_json::Value {
+        self.request_as(body, hints, None).await
+    }
+
+    /// Like `request_with_hints`, restricted to the operations of a role.
+    pub async fn request_as(
+        &self,
+        body: impl Into<SingleQuery>,
+        hints: QueryHints,
+        role: Option<&AccessRole>,
+    ) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle_as(body, hints, role, cx).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    /// An engine executing the requests with the given context, e.g. the one of a tenant.
+    pub fn with_context(context: Arc<PrismaContext>) -> Self {
+        QueryEngine { context }
+    }
+
+    pub fn context(&self) -> &PrismaContext {
+        &self.context
+    }
+
+    pub fn connector(&self) -> &dyn Connector {
+        self.context.executor.primary_connector()
+    }
 }
 
 pub struct TestApi {
@@ -45,6 +75,65 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.build_engine(&self.config, datamodel, false, None, None, vec![])
+            .await
+    }
+
+    pub async fn create_read_only_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.build_engine(&self.config, datamodel, true, None, None, vec![])
+            .await
+    }
+
+    /// Like `create_engine`, with `params` added to the query string of the datasource URL. SQL
+    /// Server URLs have no query string, the parameters are separated with `;` there.
+    pub async fn create_engine_with_url_params(&self, datamodel: &str, params: &str) -> anyhow::Result<QueryEngine> {
+        let config = if self.config.contains("sqlserver://") {
+            let params = format!(";{};database=", params.replace('&', ";"));
+            self.config.replacen(";database=", &params, 1)
+        } else {
+            self.config.replacen('?', &format!("?{}&", params), 1)
+        };
+
+        self.build_engine(&config, datamodel, false, None, None, vec![]).await
+    }
+
+    /// Like `create_engine`, with the query cache enabled.
+    pub async fn create_engine_with_query_cache(
+        &self,
+        datamodel: &str,
+        query_cache: QueryCacheConfig,
+    ) -> anyhow::Result<QueryEngine> {
+        self.build_engine(&self.config, datamodel, false, Some(query_cache), None, vec![])
+            .await
+    }
+
+    /// Like `create_engine`, with the audit entries handed to `sink`.
+    pub async fn create_engine_with_audit_sink(
+        &self,
+        datamodel: &str,
+        sink: AuditSinkRef,
+    ) -> anyhow::Result<QueryEngine> {
+        self.build_engine(&self.config, datamodel, false, None, Some(sink), vec![])
+            .await
+    }
+
+    /// Like `create_engine`, with the value transformers registered in the given order.
+    pub async fn create_engine_with_value_transformers(
+        &self,
+        datamodel: &str,
+        transformers: Vec<ValueTransformerRef>,
+    ) -> anyhow::Result<QueryEngine> {
+        self.build_engine(&self.config, datamodel, false, None, None, transformers)
+            .await
+    }
+
+    /// Like `create_engine`, with the values of the `@encrypted` fields encrypted and decrypted by
+    /// `field_encryption`.
+    pub async fn create_engine_with_field_encryption(
+        &self,
+        datamodel: &str,
+        field_encryption: FieldEncryptionRef,
+    ) -> anyhow::Result<QueryEngine> {
         feature_flags::initialize(&[String::from("all")]).unwrap();
 
         let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
@@ -60,7 +149,7 @@ impl TestApi {
             .await?;
 
         let context = PrismaContext::builder(config.subject, dml)
-            .enable_raw_queries(true)
+            .field_encryption(field_encryption)
             .build()
             .await
             .unwrap();
@@ -70,6 +159,107 @@ impl TestApi {
         })
     }
 
+    /// Migrates the database to the new datamodel and reloads the engine with it, like the
+    /// `/reload` endpoint of the server.
+    pub async fn reload_engine(&self, engine: &QueryEngine, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
+        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
+        self.migration_api
+            .schema_push(&SchemaPushInput {
+                schema: datamodel_string,
+                force: true,
+                assume_empty: false,
+            })
+            .await?;
+
+        let context = engine.context.reload(&config.subject, dml)?;
+
+        Ok(QueryEngine {
+            context: Arc::new(context),
+        })
+    }
+
+    async fn build_engine(
+        &self,
+        config: &str,
+        datamodel: &str,
+        read_only: bool,
+        query_cache: Option<QueryCacheConfig>,
+        audit_sink: Option<AuditSinkRef>,
+        value_transformers: Vec<ValueTransformerRef>,
+    ) -> anyhow::Result<QueryEngine> {
+        feature_flags::initialize(&[String::from("all")]).unwrap();
+
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
+        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
+        self.migration_api
+            .schema_push(&SchemaPushInput {
+                schema: datamodel_string,
+                force: true,
+                assume_empty: true,
+            })
+            .await?;
+
+        self.start_engine(
+            config.subject,
+            dml,
+            read_only,
+            query_cache,
+            audit_sink,
+            value_transformers,
+        )
+        .await
+    }
+
+    /// Starts an engine on the database as it is, without migrating it to the datamodel first.
+    pub async fn create_engine_without_migration(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        feature_flags::initialize(&[String::from("all")]).unwrap();
+
+        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
+        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
+        self.start_engine(config.subject, dml, false, None, None, vec![]).await
+    }
+
+    async fn start_engine(
+        &self,
+        config: Configuration,
+        dml: Datamodel,
+        read_only: bool,
+        query_cache: Option<QueryCacheConfig>,
+        audit_sink: Option<AuditSinkRef>,
+        value_transformers: Vec<ValueTransformerRef>,
+    ) -> anyhow::Result<QueryEngine> {
+        let mut builder = PrismaContext::builder(config, dml)
+            .enable_raw_queries(true)
+            .read_only(read_only)
+            .query_cache(query_cache);
+
+        if let Some(sink) = audit_sink {
+            builder = builder.audit_sink(sink);
+        }
+
+        for transformer in value_transformers {
+            builder = builder.value_transformer(transformer);
+        }
+
+        let context = builder.build().await.unwrap();
+
+        Ok(QueryEngine {
+            context: Arc::new(context),
+        })
+    }
+
+    /// The datasource block of the test database.
+    pub fn config(&self) -> &str {
+        &self.config
+    }
+
     pub fn connection_info(&self) -> &ConnectionInfo {
         &self.connection_info
     }
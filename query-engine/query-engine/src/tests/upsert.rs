@@ -0,0 +1,70 @@
+use super::test_api::*;
+use connector::{QueryHints, SlowQueryLog};
+use indoc::indoc;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static COUNTERS: &str = indoc! {"
+    model Counter {
+        id   Int    @id
+        name String
+        hits Int    @default(0)
+    }
+"};
+
+fn upsert_counter(name: &str) -> String {
+    format!(
+        r#"mutation {{ upsertOneCounter(where: {{ id: 1 }}, create: {{ id: 1, name: "{}" }}, update: {{ hits: {{ increment: 1 }} }}) {{ id name hits }} }}"#,
+        name
+    )
+}
+
+#[test_each_connector]
+async fn upsert_creates_and_then_updates_the_record(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&COUNTERS).await?;
+
+    let response = query_engine.request(upsert_counter("first")).await;
+
+    assert_eq!(
+        response["data"]["upsertOneCounter"],
+        serde_json::json!({ "id": 1, "name": "first", "hits": 0 })
+    );
+
+    let response = query_engine.request(upsert_counter("second")).await;
+
+    assert_eq!(
+        response["data"]["upsertOneCounter"],
+        serde_json::json!({ "id": 1, "name": "first", "hits": 1 })
+    );
+
+    // Create data that doesn't repeat the selector can't be pushed down.
+    let upsert = r#"mutation { upsertOneCounter(where: { id: 2 }, create: { id: 3, name: "third" }, update: { name: "updated" }) { id name } }"#;
+    let response = query_engine.request(upsert).await;
+
+    assert_eq!(
+        response["data"]["upsertOneCounter"],
+        serde_json::json!({ "id": 3, "name": "third" })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn upsert_runs_a_single_statement(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&COUNTERS).await?;
+    query_engine.request(upsert_counter("first")).await;
+
+    let log = SlowQueryLog::new(Duration::from_millis(0));
+    let mut hints = QueryHints::new();
+    hints.log_slow_queries(log.clone());
+
+    let response = query_engine.request_with_hints(upsert_counter("first"), hints).await;
+
+    assert_eq!(response["data"]["upsertOneCounter"]["hits"], 1);
+
+    let statements: Vec<String> = log.take().into_iter().map(|q| q.query).collect();
+    assert!(statements[0].starts_with("INSERT"), "{:?}", statements);
+    assert_eq!(statements.iter().filter(|s| s.starts_with("UPDATE")).count(), 0);
+
+    Ok(())
+}
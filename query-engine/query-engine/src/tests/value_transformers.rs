@@ -0,0 +1,131 @@
+use super::test_api::*;
+use connector::{DatasourceFieldName, WriteExpression};
+use indoc::indoc;
+use prisma_models::{ManyRecords, ModelRef, PrismaValue};
+use query_core::{OperationMetadata, ValueTransformer, ValueTransformerRef, ValueTransformerResult, WriteQuery};
+use serde_json::json;
+use std::sync::Arc;
+use test_macros::test_each_connector;
+
+static VAULTS: &str = indoc! {"
+    model Vault {
+        id     Int    @id
+        secret String
+    }
+"};
+
+/// Appends `-<suffix>` to the written secrets, and strips it from the read ones.
+struct Suffix(&'static str);
+
+impl Suffix {
+    fn suffix(&self) -> String {
+        format!("-{}", self.0)
+    }
+}
+
+impl ValueTransformer for Suffix {
+    fn before_write(&self, _operation: &OperationMetadata, query: &mut WriteQuery) -> ValueTransformerResult {
+        let args = match query {
+            WriteQuery::CreateRecord(create) => &mut create.args,
+            WriteQuery::UpdateRecord(update) => &mut update.args,
+            _ => return Ok(()),
+        };
+
+        if let Some(WriteExpression::Value(PrismaValue::String(secret))) =
+            args.args.get_mut(&DatasourceFieldName("secret".to_owned()))
+        {
+            secret.push_str(&self.suffix());
+        }
+
+        Ok(())
+    }
+
+    fn after_read(
+        &self,
+        _operation: &OperationMetadata,
+        _model: &ModelRef,
+        records: &mut ManyRecords,
+    ) -> ValueTransformerResult {
+        let index = match records.field_names.iter().position(|name| name == "secret") {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+
+        for record in records.records.iter_mut() {
+            if let PrismaValue::String(secret) = &mut record.values[index] {
+                let suffix = self.suffix();
+
+                if !secret.ends_with(&suffix) {
+                    return Err(format!("`{}` does not end with `{}`", secret, suffix).into());
+                }
+
+                secret.truncate(secret.len() - suffix.len());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn suffixes(suffixes: &[&'static str]) -> Vec<ValueTransformerRef> {
+    suffixes
+        .iter()
+        .map(|suffix| Arc::new(Suffix(*suffix)) as ValueTransformerRef)
+        .collect()
+}
+
+#[test_each_connector]
+async fn transformed_values_round_trip(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_value_transformers(VAULTS, suffixes(&["a", "b"]))
+        .await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneVault(data: { id: 1, secret: "hidden" }) { secret } }"#)
+        .await;
+    assert_eq!(response["data"]["createOneVault"]["secret"], "hidden", "{}", response);
+
+    let response = query_engine.request(r#"query { findManyVault { secret } }"#).await;
+    assert_eq!(response["data"]["findManyVault"], json!([{ "secret": "hidden" }]));
+
+    let response = query_engine
+        .request(r#"mutation { updateOneVault(where: { id: 1 }, data: { secret: { set: "other" } }) { secret } }"#)
+        .await;
+    assert_eq!(response["data"]["updateOneVault"]["secret"], "other", "{}", response);
+
+    // The values are stored as the transformers wrote them, in registration order.
+    let plain_engine = api.create_engine_without_migration(VAULTS).await?;
+    let response = plain_engine.request(r#"query { findManyVault { secret } }"#).await;
+    assert_eq!(response["data"]["findManyVault"], json!([{ "secret": "other-a-b" }]));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn results_are_transformed_in_reverse_registration_order(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_value_transformers(VAULTS, suffixes(&["a", "b"]))
+        .await?;
+
+    query_engine
+        .request(r#"mutation { createOneVault(data: { id: 1, secret: "hidden" }) { id } }"#)
+        .await;
+
+    // Reading `hidden-a-b` strips `-a` first, which fails the operation.
+    let reversed_engine = api
+        .create_engine_with_value_transformers(VAULTS, suffixes(&["b", "a"]))
+        .await?;
+
+    let response = reversed_engine.request(r#"query { findManyVault { secret } }"#).await;
+    let error = response["errors"][0]["error"].as_str().unwrap();
+
+    assert!(
+        error.contains(
+            "A value transformer failed in the operation `findManyVault`: `hidden-a-b` does not end with `-a`"
+        ),
+        "{}",
+        error
+    );
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static DOCUMENTS: &str = indoc! {"
+    model Document {
+        id      Int    @id
+        title   String
+        version Int    @version
+    }
+"};
+
+fn update_document(title: &str, version: i64) -> String {
+    format!(
+        r#"mutation {{ updateOneDocument(where: {{ id: 1, version: {} }}, data: {{ title: {{ set: "{}" }} }}) {{ title version }} }}"#,
+        version, title
+    )
+}
+
+#[test_each_connector]
+async fn updates_with_a_stale_version_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "draft" }) { id } }"#)
+        .await;
+
+    // Both clients read the document at version 1.
+    let response = query_engine.request(update_document("first", 1)).await;
+
+    assert_eq!(
+        response["data"]["updateOneDocument"],
+        serde_json::json!({ "title": "first", "version": 2 })
+    );
+
+    let response = query_engine.request(update_document("second", 1)).await;
+    let error = &response["errors"][0]["user_facing_error"];
+    assert_eq!(error["error_code"], "P2023", "{}", response);
+
+    let response = query_engine
+        .request(r#"mutation { deleteOneDocument(where: { id: 1, version: 1 }) { id } }"#)
+        .await;
+    let error = &response["errors"][0]["user_facing_error"];
+    assert_eq!(error["error_code"], "P2023", "{}", response);
+
+    let response = query_engine
+        .request(r#"{ findUniqueDocument(where: { id: 1 }) { title version } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findUniqueDocument"],
+        serde_json::json!({ "title": "first", "version": 2 })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_without_data_do_not_conflict(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "draft" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1, version: 1 }, data: {}) { title version } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["updateOneDocument"],
+        serde_json::json!({ "title": "draft", "version": 1 })
+    );
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1 }, data: { title: { set: "final" } }) { version } }"#)
+        .await;
+
+    assert_eq!(response["data"]["updateOneDocument"]["version"], 2);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn only_one_of_two_concurrent_updates_of_a_version_succeeds(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "draft" }) { id } }"#)
+        .await;
+
+    let (first, second) = futures::join!(
+        query_engine.request(update_document("first", 1)),
+        query_engine.request(update_document("second", 1)),
+    );
+
+    let responses = [first, second];
+    let failed: Vec<&serde_json::Value> = responses.iter().filter(|r| !r["errors"].is_null()).collect();
+
+    assert_eq!(failed.len(), 1, "{:?}", responses);
+    assert_eq!(failed[0]["errors"][0]["user_facing_error"]["error_code"], "P2023");
+
+    let succeeded = responses.iter().find(|r| r["errors"].is_null()).unwrap();
+
+    let response = query_engine
+        .request(r#"{ findUniqueDocument(where: { id: 1 }) { title version } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findUniqueDocument"],
+        succeeded["data"]["updateOneDocument"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static POSTS: &str = indoc! {"
+    model Post {
+        id       Int @id
+        authorId Int
+        likes    Int
+    }
+"};
+
+async fn create_posts(query_engine: &QueryEngine) {
+    let posts = [(1, 1, 10), (2, 1, 20), (3, 1, 20), (4, 2, 5), (5, 2, 7), (6, 2, 1)];
+
+    for (id, author_id, likes) in posts.iter() {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, authorId: {}, likes: {} }}) {{ id }} }}"#,
+            id, author_id, likes
+        );
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn records_are_numbered_within_their_partition(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    create_posts(&query_engine).await;
+
+    let response = query_engine
+        .request(
+            r#"query { findManyPost(window: { partitionBy: [authorId], orderBy: { likes: desc }, take: 2 }, orderBy: { id: asc }) { id _rowNumber _rank } }"#,
+        )
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        serde_json::json!([
+            { "id": 2, "_rowNumber": 1, "_rank": 1 },
+            { "id": 3, "_rowNumber": 2, "_rank": 1 },
+            { "id": 4, "_rowNumber": 2, "_rank": 2 },
+            { "id": 5, "_rowNumber": 1, "_rank": 1 },
+        ])
+    );
+
+    let response = query_engine
+        .request(r#"query { findManyPost(take: 1) { id _rowNumber _rank } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        serde_json::json!([{ "id": 1, "_rowNumber": null, "_rank": null }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn windows_cannot_be_combined_with_cursors(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+
+    let response = query_engine
+        .request(r#"query { findManyPost(window: { orderBy: { likes: desc } }, cursor: { id: 1 }) { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["user_facing_error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("The `window` argument can't be used together with cursors or `distinct`."),
+        "{}",
+        message
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn window_functions_do_not_replace_columns_of_the_model(api: &TestApi) -> anyhow::Result<()> {
+    let datamodel = indoc! {r#"
+        model Post {
+            id    Int @id
+            likes Int @map("__prisma_rank__")
+        }
+    "#};
+
+    let query_engine = api.create_engine(datamodel).await?;
+
+    for (id, likes) in [(1, 3), (2, 5)].iter() {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOnePost(data: {{ id: {}, likes: {} }}) {{ id }} }}"#,
+                id, likes
+            ))
+            .await;
+    }
+
+    let response = query_engine
+        .request(
+            r#"query { findManyPost(window: { orderBy: { likes: desc } }, orderBy: { id: asc }) { id likes _rank } }"#,
+        )
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        serde_json::json!([
+            { "id": 1, "likes": 3, "_rank": 2 },
+            { "id": 2, "likes": 5, "_rank": 1 },
+        ]),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
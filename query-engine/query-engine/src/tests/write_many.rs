@@ -0,0 +1,176 @@
+use super::test_api::*;
+use connector::{QueryHints, SlowQueryLog};
+use indoc::indoc;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id        Int     @id
+        published Boolean @default(false)
+        authorId  Int
+        author    User    @relation(fields: [authorId], references: [id])
+    }
+"};
+
+async fn create_blog(query_engine: &QueryEngine) {
+    let users = [
+        r#"mutation { createOneUser(data: { id: 1, name: "Alice", posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 2, name: "Bob", posts: { create: [{ id: 3 }] } }) { id } }"#,
+    ];
+
+    for user in users.iter() {
+        query_engine.request(*user).await;
+    }
+}
+
+/// Records every statement the request runs.
+fn log_statements(log: &SlowQueryLog) -> QueryHints {
+    let mut hints = QueryHints::new();
+    hints.log_slow_queries(log.clone());
+    hints
+}
+
+#[test_each_connector]
+async fn update_many_and_delete_many_with_relation_filters_work(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_blog(&query_engine).await;
+
+    let update = r#"mutation { updateManyPost(where: { author: { is: { name: "Alice" } } }, data: { published: true }) { count } }"#;
+    let response = query_engine.request(update).await;
+
+    assert_eq!(response["data"]["updateManyPost"]["count"], 2);
+
+    let response = query_engine
+        .request(r#"query { findManyPost(where: { published: true }, orderBy: { id: asc }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        serde_json::json!([{ "id": 1 }, { "id": 2 }])
+    );
+
+    let delete = r#"mutation { deleteManyPost(where: { author: { is: { name: "Alice" } } }) { count } }"#;
+    let response = query_engine.request(delete).await;
+
+    assert_eq!(response["data"]["deleteManyPost"]["count"], 2);
+
+    let response = query_engine.request(r#"query { findManyPost { id } }"#).await;
+
+    assert_eq!(response["data"]["findManyPost"], serde_json::json!([{ "id": 3 }]));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql_2017", "mssql_2019"))]
+async fn update_many_and_delete_many_with_relation_filters_run_a_single_statement(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_blog(&query_engine).await;
+
+    let log = SlowQueryLog::new(Duration::from_millis(0));
+
+    let update = r#"mutation { updateManyPost(where: { author: { is: { name: "Bob" } } }, data: { published: true }) { count } }"#;
+    let response = query_engine.request_with_hints(update, log_statements(&log)).await;
+
+    assert_eq!(response["data"]["updateManyPost"]["count"], 1);
+
+    let statements: Vec<String> = log.take().into_iter().map(|q| q.query).collect();
+    assert!(statements.iter().all(|s| !s.starts_with("SELECT")), "{:?}", statements);
+    assert_eq!(statements.iter().filter(|s| s.starts_with("UPDATE")).count(), 1);
+
+    let delete = r#"mutation { deleteManyPost(where: { author: { is: { name: "Bob" } } }) { count } }"#;
+    let response = query_engine.request_with_hints(delete, log_statements(&log)).await;
+
+    assert_eq!(response["data"]["deleteManyPost"]["count"], 1);
+
+    let statements: Vec<String> = log.take().into_iter().map(|q| q.query).collect();
+    assert!(statements.iter().all(|s| !s.starts_with("SELECT")), "{:?}", statements);
+    assert_eq!(statements.iter().filter(|s| s.starts_with("DELETE")).count(), 1);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn updates_and_deletes_affect_exactly_the_selected_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_blog(&query_engine).await;
+
+    let update = r#"mutation { updateOnePost(where: { id: 2 }, data: { published: true }) { id published } }"#;
+    let response = query_engine.request(update).await;
+
+    assert_eq!(
+        response["data"]["updateOnePost"],
+        serde_json::json!({ "id": 2, "published": true })
+    );
+
+    let update = indoc! {r#"
+        mutation {
+            updateOneUser(
+                where: { id: 1 },
+                data: { posts: { updateMany: { where: { published: true }, data: { published: false } } } }
+            ) {
+                posts(orderBy: { id: asc }) { id published }
+            }
+        }
+    "#};
+    let response = query_engine.request(update).await;
+
+    assert_eq!(
+        response["data"]["updateOneUser"]["posts"],
+        serde_json::json!([{ "id": 1, "published": false }, { "id": 2, "published": false }])
+    );
+
+    let delete =
+        r#"mutation { updateOneUser(where: { id: 1 }, data: { posts: { deleteMany: { id: 1 } } }) { posts { id } } }"#;
+    let response = query_engine.request(delete).await;
+
+    assert_eq!(
+        response["data"]["updateOneUser"]["posts"],
+        serde_json::json!([{ "id": 2 }])
+    );
+
+    let response = query_engine
+        .request(r#"query { findManyPost(orderBy: { id: asc }) { id published } }"#)
+        .await;
+
+    assert_eq!(
+        response["data"]["findManyPost"],
+        serde_json::json!([{ "id": 2, "published": false }, { "id": 3, "published": false }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn update_one_returns_the_updated_identifier_from_the_update(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_blog(&query_engine).await;
+
+    let log = SlowQueryLog::new(Duration::from_millis(0));
+
+    let update = r#"mutation { updateOnePost(where: { id: 3 }, data: { published: true }) { id published } }"#;
+    let response = query_engine.request_with_hints(update, log_statements(&log)).await;
+
+    assert_eq!(
+        response["data"]["updateOnePost"],
+        serde_json::json!({ "id": 3, "published": true })
+    );
+
+    let statements: Vec<String> = log.take().into_iter().map(|q| q.query).collect();
+    let update = statements.iter().position(|s| s.starts_with("UPDATE")).unwrap();
+
+    assert!(statements[update].contains(" RETURNING "), "{:?}", statements);
+    assert!(
+        statements[..update].iter().all(|s| !s.starts_with("SELECT")),
+        "{:?}",
+        statements
+    );
+
+    Ok(())
+}